@@ -21,6 +21,14 @@ use crate::trusted_len::TrustedLen;
 const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
 const MAX_EXP_BLOCK_SIZE: usize = 16 * 1024 * 1024;
 
+// The largest offset a view can have into a buffer.
+#[cfg(not(test))]
+const MAX_BUFFER_OFFSET: usize = u32::MAX as usize;
+
+// To trigger the buffer rollover without allocating gigabytes.
+#[cfg(test)]
+const MAX_BUFFER_OFFSET: usize = u8::MAX as usize;
+
 // Invariants:
 //
 // - Each view must point to a valid slice of a buffer
@@ -275,8 +283,8 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
             let required_capacity = self.in_progress_buffer.len() + bytes.len();
             let does_not_fit_in_buffer = self.in_progress_buffer.capacity() < required_capacity;
 
-            // We can only save offsets that are below u32::MAX
-            let offset_will_not_fit = self.in_progress_buffer.len() > u32::MAX as usize;
+            // We can only save offsets that are below MAX_BUFFER_OFFSET
+            let offset_will_not_fit = self.in_progress_buffer.len() > MAX_BUFFER_OFFSET;
 
             if does_not_fit_in_buffer || offset_will_not_fit {
                 // Allocate a new buffer and flush the old buffer
@@ -645,9 +653,13 @@ impl MutableBinaryViewArray<[u8]> {
         assert!(sum_length <= buffer.len());
 
         let mut buffer_offset = 0;
-        if min_length > View::MAX_INLINE_SIZE as usize
-            && (num_items == 1 || sum_length + self.in_progress_buffer.len() <= u32::MAX as usize)
-        {
+        if min_length > View::MAX_INLINE_SIZE as usize && sum_length <= MAX_BUFFER_OFFSET {
+            // The offsets into the in-progress buffer must fit in a u32. Start a new buffer if
+            // appending these values would make them overflow.
+            if self.in_progress_buffer.len() + sum_length > MAX_BUFFER_OFFSET {
+                self.finish_in_progress();
+            }
+
             let buffer_idx = self.completed_buffers().len() as u32;
             let in_progress_buffer_offset = self.in_progress_buffer.len();
 
@@ -812,6 +824,7 @@ impl<T: ViewType + ?Sized, P: AsRef<T>> TryPush<Option<P>> for MutableBinaryView
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::array::ListArray;
 
     fn roundtrip(values: &[&[u8]]) -> bool {
         let buffer = values
@@ -851,6 +864,74 @@ mod tests {
         assert!(roundtrip(&[b"abc", b"def", b"hix", b"xyz"]));
     }
 
+    /// Assert that no view points past `MAX_BUFFER_OFFSET` and that `values` roundtrip.
+    fn assert_within_offsets(arr: &BinaryViewArrayGeneric<[u8]>, values: &[Vec<u8>]) {
+        assert!(arr.data_buffers().len() > 1);
+        for view in arr.views().iter() {
+            assert!(view.offset as usize <= MAX_BUFFER_OFFSET);
+        }
+        assert_eq!(arr.values_iter().collect::<Vec<_>>(), values);
+    }
+
+    fn long_values(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| vec![i as u8; 100]).collect()
+    }
+
+    #[test]
+    fn extend_with_lengths_beyond_buffer_offsets() {
+        let values = long_values(9);
+        let mut bv = MutableBinaryViewArray::<[u8]>::new();
+        for v in &values {
+            bv.extend_from_lengths(v, [v.len()].into_iter());
+        }
+        assert_eq!(bv.completed_buffers().len(), 4);
+        assert_within_offsets(&bv.freeze(), &values);
+
+        // Too long to fit in a single buffer.
+        let mut bv = MutableBinaryViewArray::<[u8]>::new();
+        bv.extend_from_lengths(&values.concat(), values.iter().map(Vec::len));
+        assert_within_offsets(&bv.freeze(), &values);
+    }
+
+    #[test]
+    fn push_beyond_buffer_offsets() {
+        // The CSV and JSON parsers build their string columns value by value.
+        let values = long_values(9);
+        let bv = MutableBinaryViewArray::<[u8]>::from_values_iter(values.iter());
+        assert_within_offsets(&bv.freeze(), &values);
+    }
+
+    #[test]
+    fn concatenate_beyond_buffer_offsets() {
+        let values = long_values(18);
+        let (left, right) = values.split_at(9);
+        let left = MutableBinaryViewArray::<[u8]>::from_values_iter(left.iter()).freeze();
+        let right = MutableBinaryViewArray::<[u8]>::from_values_iter(right.iter()).freeze();
+
+        let out = crate::compute::concatenate::concatenate(&[&left, &right]).unwrap();
+        let out = out.as_any().downcast_ref().unwrap();
+        assert_within_offsets(out, &values);
+    }
+
+    #[test]
+    fn concatenate_lists_beyond_buffer_offsets() {
+        // Aggregated lists of strings are gathered into 64-bit offset lists.
+        let values = long_values(18);
+        let list = |values: &[Vec<u8>]| {
+            let inner = MutableBinaryViewArray::<[u8]>::from_values_iter(values.iter()).freeze();
+            let dtype = ListArray::<i64>::default_datatype(inner.dtype().clone());
+            let offsets = vec![0, 4, values.len() as i64].try_into().unwrap();
+            ListArray::<i64>::new(dtype, offsets, inner.boxed(), None)
+        };
+        let (left, right) = values.split_at(9);
+
+        let out = crate::compute::concatenate::concatenate(&[&list(left), &list(right)]).unwrap();
+        let out = out.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        assert_eq!(out.offsets().as_slice(), &[0, 4, 9, 13, 18]);
+        let out = out.values().as_any().downcast_ref().unwrap();
+        assert_within_offsets(out, &values);
+    }
+
     #[test]
     fn extend_with_not_inlinable_fastpath() {
         assert!(roundtrip(&[
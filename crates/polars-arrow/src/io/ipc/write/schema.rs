@@ -54,9 +54,17 @@ fn key_value(key: impl Into<String>, val: impl Into<String>) -> arrow_format::ip
     }
 }
 
-fn write_metadata(metadata: &Metadata, kv_vec: &mut Vec<arrow_format::ipc::KeyValue>) {
+/// Writes the custom `metadata` of a field. The extension keys are skipped if the field has an
+/// extension type, as they are written from the type.
+fn write_metadata(
+    metadata: &Metadata,
+    has_extension: bool,
+    kv_vec: &mut Vec<arrow_format::ipc::KeyValue>,
+) {
     for (k, v) in metadata {
-        if k.as_str() != "ARROW:extension:name" && k.as_str() != "ARROW:extension:metadata" {
+        if !has_extension
+            || (k.as_str() != "ARROW:extension:name" && k.as_str() != "ARROW:extension:metadata")
+        {
             kv_vec.push(key_value(k.clone().into_string(), v.clone().into_string()));
         }
     }
@@ -78,7 +86,9 @@ fn write_extension(
 pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_format::ipc::Field {
     // custom metadata.
     let mut kv_vec = vec![];
+    let mut has_extension = false;
     if let ArrowDataType::Extension(name, _, metadata) = field.dtype() {
+        has_extension = true;
         write_extension(
             name.as_str(),
             metadata.as_ref().map(|x| x.as_str()),
@@ -92,6 +102,7 @@ pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_form
     let dictionary = if let ArrowDataType::Dictionary(index_type, inner, is_ordered) = field.dtype()
     {
         if let ArrowDataType::Extension(name, _, metadata) = inner.as_ref() {
+            has_extension = true;
             write_extension(
                 name.as_str(),
                 metadata.as_ref().map(|x| x.as_str()),
@@ -109,7 +120,7 @@ pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_form
         None
     };
 
-    write_metadata(&field.metadata, &mut kv_vec);
+    write_metadata(&field.metadata, has_extension, &mut kv_vec);
 
     let custom_metadata = if !kv_vec.is_empty() {
         Some(kv_vec)
@@ -43,6 +43,81 @@ impl From<CastOptions> for CastOptionsImpl {
     }
 }
 
+/// How [`Series::cast_with_args`] handles values that don't fit the target type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum CastOverflow {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Replace the value with null.
+    Null,
+    /// Replace the value with the minimum or maximum of an integer, decimal or temporal target
+    /// type. Other values that can't be converted are replaced with null.
+    Saturate,
+    /// Wrap around, like [`CastOptions::Overflowing`].
+    Wrap,
+}
+
+/// How [`Series::cast_with_args`] rounds when digits are dropped, e.g. from a float to an integer
+/// or from a decimal to a decimal with a smaller scale.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum RoundingMode {
+    /// Round towards zero.
+    #[default]
+    Truncate,
+    /// Round to the nearest value, ties to the even one.
+    HalfEven,
+    /// Round to the nearest value, ties away from zero.
+    HalfUp,
+}
+
+/// The separators of numbers written as strings, e.g. `1.234,5` in German.
+#[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    /// Separator between groups of digits, which is ignored when parsing.
+    pub thousands_separator: Option<char>,
+}
+
+impl NumberFormat {
+    pub fn new(decimal_separator: char, thousands_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            thousands_separator,
+        }
+    }
+}
+
+/// Options of [`Series::cast_with_args`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct CastArgs {
+    pub overflow: CastOverflow,
+    pub rounding: RoundingMode,
+    /// If set, strings cast to numbers are parsed with these separators.
+    pub number_format: Option<NumberFormat>,
+}
+
+impl CastArgs {
+    pub fn with_overflow(mut self, overflow: CastOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_number_format(mut self, number_format: Option<NumberFormat>) -> Self {
+        self.number_format = number_format;
+        self
+    }
+}
+
 pub(crate) fn cast_chunks(
     chunks: &[ArrayRef],
     dtype: &DataType,
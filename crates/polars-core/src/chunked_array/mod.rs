@@ -306,6 +306,25 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         self.metadata().get_distinct_count()
     }
 
+    /// Drop the cached statistics that depend on the values, because the values have changed.
+    ///
+    /// Clones that share the [`Metadata`] keep their statistics.
+    pub(crate) fn invalidate_value_statistics(&mut self) {
+        {
+            let md = self.metadata();
+            if md.get_min_value().is_none()
+                && md.get_max_value().is_none()
+                && md.get_distinct_count().is_none()
+            {
+                return;
+            }
+        }
+        let md = Arc::make_mut(self.metadata_mut()).get_mut();
+        md.set_min_value(None);
+        md.set_max_value(None);
+        md.set_distinct_count(None);
+    }
+
     pub fn merge_metadata(&mut self, md: Metadata<T>) {
         let self_md = self.metadata_mut();
         let self_md = self_md.as_ref();
@@ -581,6 +600,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     /// And the `null_count` remains correct.
     #[inline]
     pub unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.invalidate_value_statistics();
         &mut self.chunks
     }
 
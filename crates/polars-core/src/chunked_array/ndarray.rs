@@ -178,6 +178,57 @@ impl DataFrame {
             },
         }
     }
+
+    /// Compute the Gram matrix `Xᵀ·X` of the columns of this [`DataFrame`], as a square
+    /// [`DataFrame`] of `Float64` columns named after the columns of this [`DataFrame`].
+    ///
+    /// The columns are cast to `Float64`, where null values become `NaN`. The product is computed
+    /// by the blocked matrix multiplication kernels of [ndarray](https://docs.rs/ndarray/).
+    pub fn gram_matrix(&self) -> PolarsResult<DataFrame> {
+        let x = self.to_ndarray::<Float64Type>(IndexOrder::Fortran)?;
+        let gram = x.t().dot(&x);
+        matrix_to_df(gram.view(), self.get_column_names_owned())
+    }
+
+    /// Compute the matrix product of this [`DataFrame`] with `other`, as a [`DataFrame`] of
+    /// `Float64` columns named after the columns of `other`. The width of this [`DataFrame`] must
+    /// equal the height of `other`.
+    ///
+    /// The columns are cast to `Float64`, where null values become `NaN`. The product is computed
+    /// by the blocked matrix multiplication kernels of [ndarray](https://docs.rs/ndarray/).
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// # fn main() -> PolarsResult<()> {
+    /// let x = df!["a" => [1.0, 2.0], "b" => [3.0, 4.0]]?;
+    /// let weights = df!["w" => [0.5, 1.0]]?;
+    ///
+    /// let product = x.matmul(&weights)?;
+    /// assert_eq!(product, df!["w" => [3.5, 5.0]]?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matmul(&self, other: &DataFrame) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.width() == other.height(),
+            ShapeMismatch: "cannot multiply a matrix of shape {:?} with one of shape {:?}",
+            self.shape(), other.shape()
+        );
+        let lhs = self.to_ndarray::<Float64Type>(IndexOrder::Fortran)?;
+        let rhs = other.to_ndarray::<Float64Type>(IndexOrder::Fortran)?;
+        let product = lhs.dot(&rhs);
+        matrix_to_df(product.view(), other.get_column_names_owned())
+    }
+}
+
+fn matrix_to_df(matrix: ArrayView2<f64>, names: Vec<PlSmallStr>) -> PolarsResult<DataFrame> {
+    let columns = matrix
+        .columns()
+        .into_iter()
+        .zip(names)
+        .map(|(column, name)| Float64Chunked::from_vec(name, column.to_vec()).into_series())
+        .collect();
+    DataFrame::new(columns)
 }
 
 #[cfg(test)]
@@ -247,4 +298,29 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gram_matrix_and_matmul() -> PolarsResult<()> {
+        let df = df!["a"=> [1.0, 2.0, 3.0],
+            "b" => [2i32, 3, 4]
+        ]?;
+
+        let gram = df.gram_matrix()?;
+        let expected = df!["a" => [14.0, 20.0],
+            "b" => [20.0, 29.0]
+        ]?;
+        assert_eq!(gram, expected);
+
+        let weights = df!["x" => [1.0, -1.0],
+            "y" => [0.5, 0.0]
+        ]?;
+        let product = df.matmul(&weights)?;
+        let expected = df!["x" => [-1.0, -1.0, -1.0],
+            "y" => [0.5, 1.0, 1.5]
+        ]?;
+        assert_eq!(product, expected);
+
+        assert!(df.matmul(&df).is_err());
+        Ok(())
+    }
 }
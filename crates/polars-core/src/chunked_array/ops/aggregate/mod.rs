@@ -10,6 +10,7 @@ use arrow::types::NativeType;
 use num_traits::{Float, One, ToPrimitive, Zero};
 use polars_compute::float_sum;
 use polars_compute::min_max::MinMaxKernel;
+use polars_utils::float::IsFloat;
 use polars_utils::min_max::MinMax;
 pub use quantile::*;
 pub use var::*;
@@ -17,7 +18,6 @@ pub use var::*;
 use super::float_sorted_arg_max::{
     float_arg_max_sorted_ascending, float_arg_max_sorted_descending,
 };
-use crate::chunked_array::metadata::MetadataEnv;
 use crate::chunked_array::ChunkedArray;
 use crate::datatypes::{BooleanChunked, PolarsNumericType};
 use crate::prelude::*;
@@ -73,6 +73,23 @@ where
     }
 }
 
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Cache the minimum and maximum in the [`Metadata`], so that they are not computed again
+    /// for this array or its clones. NaN values are not cached, and neither is anything if
+    /// another thread holds the metadata.
+    fn cache_min_max(&self, min: Option<T::Native>, max: Option<T::Native>) {
+        let Some(mut md) = self.metadata_arc().try_write() else {
+            return;
+        };
+        if let Some(min) = min.filter(|v| !v.is_nan()) {
+            md.set_min_value(Some(min));
+        }
+        if let Some(max) = max.filter(|v| !v.is_nan()) {
+            md.set_max_value(Some(max));
+        }
+    }
+}
+
 impl<T> ChunkAgg<T::Native> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -96,6 +113,9 @@ where
         if self.null_count() == self.len() {
             return None;
         }
+        if let Some(min) = self.get_min_value() {
+            return Some(min);
+        }
 
         // There is at least one non-null value.
 
@@ -114,10 +134,7 @@ where
                 .reduce(MinMax::min_ignore_nan),
         };
 
-        if MetadataEnv::experimental_enabled() {
-            self.interior_mut_metadata().set_min_value(result);
-        }
-
+        self.cache_min_max(result, None);
         result
     }
 
@@ -125,6 +142,9 @@ where
         if self.null_count() == self.len() {
             return None;
         }
+        if let Some(max) = self.get_max_value() {
+            return Some(max);
+        }
         // There is at least one non-null value.
 
         let result = match self.is_sorted_flag() {
@@ -152,10 +172,7 @@ where
                 .reduce(MinMax::max_ignore_nan),
         };
 
-        if MetadataEnv::experimental_enabled() {
-            self.interior_mut_metadata().set_max_value(result);
-        }
-
+        self.cache_min_max(None, result);
         result
     }
 
@@ -163,6 +180,9 @@ where
         if self.null_count() == self.len() {
             return None;
         }
+        if let (Some(min), Some(max)) = (self.get_min_value(), self.get_max_value()) {
+            return Some((min, max));
+        }
         // There is at least one non-null value.
 
         let result = match self.is_sorted_flag() {
@@ -204,18 +224,9 @@ where
                 }),
         };
 
-        if MetadataEnv::experimental_enabled() {
-            let (min, max) = match result {
-                Some((min, max)) => (Some(min), Some(max)),
-                None => (None, None),
-            };
-
-            let mut md = self.interior_mut_metadata();
-
-            md.set_min_value(min);
-            md.set_max_value(max);
+        if let Some((min, max)) = result {
+            self.cache_min_max(Some(min), Some(max));
         }
-
         result
     }
 
@@ -986,4 +997,33 @@ mod test {
             Some(4.6)
         );
     }
+
+    #[test]
+    fn test_min_max_cache() {
+        let mut ca = Int32Chunked::new(PlSmallStr::EMPTY, &[3, 1, 2]);
+        assert_eq!(ca.min_max(), Some((1, 3)));
+        assert_eq!(ca.get_min_value(), Some(1));
+        assert_eq!(ca.get_max_value(), Some(3));
+
+        // Clones share the cache, rechunking keeps it.
+        let clone = ca.clone();
+        assert_eq!(clone.get_min_value(), Some(1));
+        ca.append(&Int32Chunked::new(PlSmallStr::EMPTY, &[0]))
+            .unwrap();
+        assert_eq!(ca.rechunk().get_max_value(), None);
+        assert_eq!(ca.min(), Some(0));
+        assert_eq!(ca.rechunk().get_min_value(), Some(0));
+
+        // Mutating the values invalidates the cache of the mutated array only.
+        ca.apply_mut(|v| v + 10);
+        assert_eq!(ca.get_min_value(), None);
+        assert_eq!(ca.max(), Some(13));
+        assert_eq!(clone.get_min_value(), Some(1));
+        assert_eq!(clone.get_max_value(), Some(3));
+
+        // NaN is not cached.
+        let ca = Float64Chunked::new(PlSmallStr::EMPTY, &[f64::NAN, f64::NAN]);
+        assert!(ca.min().unwrap().is_nan());
+        assert_eq!(ca.get_min_value(), None);
+    }
 }
@@ -97,6 +97,81 @@ fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     }
 }
 
+/// Partition `vals`, the values at `offset..` of a larger slice, such that the value at each of
+/// the sorted `positions` is the one that would be there if it was sorted.
+///
+/// Each selection only partitions the part of the slice between its neighbours, so the
+/// selections share their work.
+fn select_nth_many<T: TotalOrd>(vals: &mut [T], offset: usize, positions: &[usize]) {
+    let mid = positions.len() / 2;
+    let Some(&pos) = positions.get(mid) else {
+        return;
+    };
+    let (lhs, _, rhs) = vals.select_nth_unstable_by(pos - offset, TotalOrd::tot_cmp);
+    select_nth_many(lhs, offset, &positions[..mid]);
+    select_nth_many(rhs, pos + 1, &positions[mid + 1..]);
+}
+
+pub(crate) fn check_quantiles(quantiles: &[f64]) -> PolarsResult<()> {
+    for quantile in quantiles {
+        polars_ensure!(
+            (0.0..=1.0).contains(quantile),
+            ComputeError: "`quantile` should be between 0.0 and 1.0, got {}", quantile
+        );
+    }
+    Ok(())
+}
+
+// Like `quantile_slice` for many quantiles, partitioning the values once for all of them.
+fn quantiles_slice<T: ToPrimitive + TotalOrd + Copy>(
+    vals: &mut [T],
+    quantiles: &[f64],
+    interpol: QuantileInterpolOptions,
+    is_sorted: bool,
+) -> Vec<Option<f64>> {
+    if vals.is_empty() {
+        return vec![None; quantiles.len()];
+    }
+    let idxs = quantiles
+        .iter()
+        .map(|&quantile| quantile_idx(quantile, vals.len(), 0, interpol))
+        .collect::<Vec<_>>();
+
+    if !is_sorted {
+        // The interpolating methods also need the value after the lower one.
+        let mut positions = idxs
+            .iter()
+            .flat_map(|&(idx, _, top_idx)| [Some(idx), (top_idx != idx).then_some(idx + 1)])
+            .flatten()
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        positions.dedup();
+        select_nth_many(vals, 0, &positions);
+    }
+
+    idxs.into_iter()
+        .map(|(idx, float_idx, top_idx)| {
+            let lower = vals[idx].to_f64();
+            if idx == top_idx {
+                return lower;
+            }
+            let upper = vals[idx + 1].to_f64();
+            match interpol {
+                QuantileInterpolOptions::Midpoint => {
+                    Some(midpoint_interpol(lower.unwrap(), upper.unwrap()))
+                },
+                QuantileInterpolOptions::Linear => Some(linear_interpol(
+                    lower.unwrap(),
+                    upper.unwrap(),
+                    idx,
+                    float_idx,
+                )),
+                _ => lower,
+            }
+        })
+        .collect()
+}
+
 fn generic_quantile<T>(
     ca: ChunkedArray<T>,
     quantile: f64,
@@ -168,6 +243,39 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: TotalOrd,
+{
+    /// Aggregate the values at each of the `quantiles`, in order. Returns `None` for each
+    /// quantile if the array is empty or only contains null values.
+    ///
+    /// The values are copied and partitioned once for all quantiles, instead of once per
+    /// quantile as with [`ChunkQuantile::quantile`].
+    pub fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Vec<Option<f64>>> {
+        check_quantiles(quantiles)?;
+        let mut vals = Vec::with_capacity(self.len() - self.null_count());
+        for arr in self.downcast_iter() {
+            if arr.null_count() == 0 {
+                vals.extend_from_slice(arr.values());
+            } else {
+                vals.extend(arr.iter().flatten().copied());
+            }
+        }
+        Ok(quantiles_slice(
+            &mut vals,
+            quantiles,
+            interpol,
+            self.is_sorted_ascending_flag(),
+        ))
+    }
+}
+
 // Version of quantile/median that don't need a memcpy
 impl<T> ChunkedArray<T>
 where
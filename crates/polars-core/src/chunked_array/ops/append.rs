@@ -21,6 +21,7 @@ where
     T: PolarsDataType,
     for<'a> T::Physical<'a>: TotalOrd,
 {
+    ca.invalidate_value_statistics();
     // Note: Do not call (first|last)_non_null on an array here before checking
     // it is sorted, otherwise it will lead to quadratic behavior.
     let sorted_flag = match (
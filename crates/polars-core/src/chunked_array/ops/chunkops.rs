@@ -137,6 +137,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             .iter()
             .map(|arr| arr.null_count())
             .sum::<usize>() as IdxSize;
+        self.invalidate_value_statistics();
     }
 
     pub fn rechunk(&self) -> Self {
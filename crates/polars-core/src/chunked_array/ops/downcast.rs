@@ -87,6 +87,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     ///     * the flags (sorted, etc) remain correct.
     #[inline]
     pub unsafe fn downcast_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T::Array> {
+        self.invalidate_value_statistics();
         self.chunks.iter_mut().map(|arr| {
             // SAFETY: T::Array guarantees this is correct.
             let arr = &mut **arr;
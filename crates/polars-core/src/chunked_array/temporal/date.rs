@@ -33,12 +33,30 @@ impl DateChunked {
     /// Convert from Date into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
-        let datefmt_f = |ndt: NaiveDate| ndt.format(format);
-        self.try_apply_into_string_amortized(|val, buf| {
-            let ndt = date32_to_date(val);
-            write!(buf, "{}", datefmt_f(ndt))
-        })
-        .map_err(|_| polars_err!(ComputeError: "cannot format Date with format '{}'", format))
+        self.to_string_localized(format, DateLocale::default())
+    }
+
+    /// Convert from Date into String with the given format, writing month and weekday names
+    /// in `locale`. Besides the chrono directives, `%Q` is replaced by the quarter.
+    pub fn to_string_localized(
+        &self,
+        format: &str,
+        locale: DateLocale,
+    ) -> PolarsResult<StringChunked> {
+        let out = match LocalizedFormat::new(format, locale) {
+            Some(localized) => self.try_apply_into_string_amortized(|val, buf| {
+                let nd = date32_to_date(val);
+                localized.write(buf, &nd, |buf, format| write!(buf, "{}", nd.format(format)))
+            }),
+            None => {
+                let datefmt_f = |ndt: NaiveDate| ndt.format(format);
+                self.try_apply_into_string_amortized(|val, buf| {
+                    let ndt = date32_to_date(val);
+                    write!(buf, "{}", datefmt_f(ndt))
+                })
+            },
+        };
+        out.map_err(|_| polars_err!(ComputeError: "cannot format Date with format '{}'", format))
     }
 
     /// Convert from Date into String with the given format.
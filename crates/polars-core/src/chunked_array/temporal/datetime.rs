@@ -42,30 +42,47 @@ impl DatetimeChunked {
     /// Convert from Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        self.to_string_localized(format, DateLocale::default())
+    }
+
+    /// Convert from Datetime into String with the given format, writing month and weekday names
+    /// in `locale`. Besides the chrono directives, `%Q` is replaced by the quarter.
+    pub fn to_string_localized(
+        &self,
+        format: &str,
+        locale: DateLocale,
+    ) -> PolarsResult<StringChunked> {
         let conversion_f = match self.time_unit() {
             TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
             TimeUnit::Microseconds => timestamp_us_to_datetime,
             TimeUnit::Milliseconds => timestamp_ms_to_datetime,
         };
+        let localized = LocalizedFormat::new(format, locale);
 
         let mut ca: StringChunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
             Some(time_zone) => {
                 let parsed_time_zone = time_zone.parse::<Tz>().expect("already validated");
-                let datefmt_f = |ndt| parsed_time_zone.from_utc_datetime(&ndt).format(format);
                 self.try_apply_into_string_amortized(|val, buf| {
-                    let ndt = conversion_f(val);
-                    write!(buf, "{}", datefmt_f(ndt))
+                    let dt = parsed_time_zone.from_utc_datetime(&conversion_f(val));
+                    match &localized {
+                        Some(localized) => localized
+                            .write(buf, &dt, |buf, format| write!(buf, "{}", dt.format(format))),
+                        None => write!(buf, "{}", dt.format(format)),
+                    }
                     }
                 ).map_err(
                 |_| polars_err!(ComputeError: "cannot format timezone-aware Datetime with format '{}'", format),
                 )?
             },
             _ => {
-                let datefmt_f = |ndt: NaiveDateTime| ndt.format(format);
                 self.try_apply_into_string_amortized(|val, buf| {
                     let ndt = conversion_f(val);
-                    write!(buf, "{}", datefmt_f(ndt))
+                    match &localized {
+                        Some(localized) => localized
+                            .write(buf, &ndt, |buf, format| write!(buf, "{}", ndt.format(format))),
+                        None => write!(buf, "{}", ndt.format(format)),
+                    }
                     }
                 ).map_err(
                 |_| polars_err!(ComputeError: "cannot format timezone-naive Datetime with format '{}'", format),
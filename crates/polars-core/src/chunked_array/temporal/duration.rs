@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use crate::export::chrono::Duration as ChronoDuration;
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
@@ -10,6 +12,41 @@ impl DurationChunked {
         }
     }
 
+    /// Convert from Duration into String with the given format.
+    ///
+    /// Supported formats are `"iso"` for ISO 8601 durations such as `"P1DT2H3M4.5S"` and
+    /// `"humanized"` for the representation used when printing, e.g. `"1d 2h 3m 4s 500ms"`.
+    pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        let tu = self.time_unit();
+        let write_value: fn(&mut String, i64, TimeUnit) = match format {
+            "iso" => write_iso_duration,
+            "humanized" => |buf, v, tu| write!(buf, "{}", AnyValue::Duration(v, tu)).unwrap(),
+            _ => polars_bail!(
+                InvalidOperation: "unsupported format '{}' for Duration, expected 'iso' or 'humanized'",
+                format
+            ),
+        };
+        let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutablePlString::with_capacity(arr.len());
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        write_value(&mut buf, *v, tu);
+                        mutarr.push_value(&buf)
+                    },
+                }
+            }
+
+            mutarr.freeze().boxed()
+        });
+        ca.rename(self.name().clone());
+        Ok(ca)
+    }
+
     /// Change the underlying [`TimeUnit`]. And update the data accordingly.
     #[must_use]
     pub fn cast_time_unit(&self, tu: TimeUnit) -> Self {
@@ -90,3 +127,46 @@ impl DurationChunked {
         Int64Chunked::from_iter_options(name, vals).into_duration(tu)
     }
 }
+
+/// Write `v` as an ISO 8601 duration, using days as the largest unit, e.g. `"-P1DT2H3M4.5S"`.
+fn write_iso_duration(buf: &mut String, v: i64, tu: TimeUnit) {
+    let (units_per_second, width) = match tu {
+        TimeUnit::Nanoseconds => (1_000_000_000, 9),
+        TimeUnit::Microseconds => (1_000_000, 6),
+        TimeUnit::Milliseconds => (1_000, 3),
+    };
+    if v < 0 {
+        buf.push('-');
+    }
+    let v = v.unsigned_abs();
+    let subsec = v % units_per_second;
+    let secs = v / units_per_second;
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+
+    buf.push('P');
+    if days > 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && subsec == 0 {
+        if days == 0 {
+            buf.push_str("T0S");
+        }
+        return;
+    }
+    buf.push('T');
+    if hours > 0 {
+        write!(buf, "{hours}H").unwrap();
+    }
+    if minutes > 0 {
+        write!(buf, "{minutes}M").unwrap();
+    }
+    if seconds > 0 || subsec > 0 {
+        write!(buf, "{seconds}").unwrap();
+        if subsec > 0 {
+            let frac = format!("{subsec:0width$}");
+            write!(buf, ".{}", frac.trim_end_matches('0')).unwrap();
+        }
+        buf.push('S');
+    }
+}
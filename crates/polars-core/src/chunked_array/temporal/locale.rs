@@ -0,0 +1,440 @@
+//! Month and weekday names of the locales supported by `strftime` and `strptime`.
+use std::fmt::Write;
+use std::str::FromStr;
+
+use chrono::Datelike;
+#[cfg(any(feature = "serde-lazy", feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The language of textual date components, i.e. the `%B`, `%b`, `%h`, `%A` and `%a` directives.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    any(feature = "serde-lazy", feature = "serde"),
+    derive(Serialize, Deserialize)
+)]
+pub enum DateLocale {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Dutch,
+    Portuguese,
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_MONTHS_ABBREV: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const EN_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const EN_WEEKDAYS_ABBREV: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const DE_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+const DE_MONTHS_ABBREV: [&str; 12] = [
+    "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+];
+const DE_WEEKDAYS: [&str; 7] = [
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+    "Sonntag",
+];
+const DE_WEEKDAYS_ABBREV: [&str; 7] = ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"];
+
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const FR_MONTHS_ABBREV: [&str; 12] = [
+    "janv", "févr", "mars", "avr", "mai", "juin", "juil", "août", "sept", "oct", "nov", "déc",
+];
+const FR_WEEKDAYS: [&str; 7] = [
+    "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+];
+const FR_WEEKDAYS_ABBREV: [&str; 7] = ["lun", "mar", "mer", "jeu", "ven", "sam", "dim"];
+
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+const ES_MONTHS_ABBREV: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+];
+const ES_WEEKDAYS: [&str; 7] = [
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+    "domingo",
+];
+const ES_WEEKDAYS_ABBREV: [&str; 7] = ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"];
+
+const IT_MONTHS: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+const IT_MONTHS_ABBREV: [&str; 12] = [
+    "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+];
+const IT_WEEKDAYS: [&str; 7] = [
+    "lunedì",
+    "martedì",
+    "mercoledì",
+    "giovedì",
+    "venerdì",
+    "sabato",
+    "domenica",
+];
+const IT_WEEKDAYS_ABBREV: [&str; 7] = ["lun", "mar", "mer", "gio", "ven", "sab", "dom"];
+
+const NL_MONTHS: [&str; 12] = [
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+const NL_MONTHS_ABBREV: [&str; 12] = [
+    "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+];
+const NL_WEEKDAYS: [&str; 7] = [
+    "maandag",
+    "dinsdag",
+    "woensdag",
+    "donderdag",
+    "vrijdag",
+    "zaterdag",
+    "zondag",
+];
+const NL_WEEKDAYS_ABBREV: [&str; 7] = ["ma", "di", "wo", "do", "vr", "za", "zo"];
+
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+const PT_MONTHS_ABBREV: [&str; 12] = [
+    "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+];
+const PT_WEEKDAYS: [&str; 7] = [
+    "segunda-feira",
+    "terça-feira",
+    "quarta-feira",
+    "quinta-feira",
+    "sexta-feira",
+    "sábado",
+    "domingo",
+];
+const PT_WEEKDAYS_ABBREV: [&str; 7] = ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"];
+
+impl DateLocale {
+    /// The full month names, starting at January.
+    pub fn months(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::English => &EN_MONTHS,
+            Self::German => &DE_MONTHS,
+            Self::French => &FR_MONTHS,
+            Self::Spanish => &ES_MONTHS,
+            Self::Italian => &IT_MONTHS,
+            Self::Dutch => &NL_MONTHS,
+            Self::Portuguese => &PT_MONTHS,
+        }
+    }
+
+    /// The abbreviated month names, starting at January.
+    pub fn months_abbrev(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::English => &EN_MONTHS_ABBREV,
+            Self::German => &DE_MONTHS_ABBREV,
+            Self::French => &FR_MONTHS_ABBREV,
+            Self::Spanish => &ES_MONTHS_ABBREV,
+            Self::Italian => &IT_MONTHS_ABBREV,
+            Self::Dutch => &NL_MONTHS_ABBREV,
+            Self::Portuguese => &PT_MONTHS_ABBREV,
+        }
+    }
+
+    /// The full weekday names, starting at Monday.
+    pub fn weekdays(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::English => &EN_WEEKDAYS,
+            Self::German => &DE_WEEKDAYS,
+            Self::French => &FR_WEEKDAYS,
+            Self::Spanish => &ES_WEEKDAYS,
+            Self::Italian => &IT_WEEKDAYS,
+            Self::Dutch => &NL_WEEKDAYS,
+            Self::Portuguese => &PT_WEEKDAYS,
+        }
+    }
+
+    /// The abbreviated weekday names, starting at Monday.
+    pub fn weekdays_abbrev(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::English => &EN_WEEKDAYS_ABBREV,
+            Self::German => &DE_WEEKDAYS_ABBREV,
+            Self::French => &FR_WEEKDAYS_ABBREV,
+            Self::Spanish => &ES_WEEKDAYS_ABBREV,
+            Self::Italian => &IT_WEEKDAYS_ABBREV,
+            Self::Dutch => &NL_WEEKDAYS_ABBREV,
+            Self::Portuguese => &PT_WEEKDAYS_ABBREV,
+        }
+    }
+}
+
+impl FromStr for DateLocale {
+    type Err = PolarsError;
+
+    /// Parses a language code, optionally followed by a region, e.g. `"de"` or `"de_DE"`.
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let language = s.split(['_', '-']).next().unwrap_or_default();
+        let locale = match language.to_ascii_lowercase().as_str() {
+            "en" => Self::English,
+            "de" => Self::German,
+            "fr" => Self::French,
+            "es" => Self::Spanish,
+            "it" => Self::Italian,
+            "nl" => Self::Dutch,
+            "pt" => Self::Portuguese,
+            _ => {
+                polars_bail!(InvalidOperation: "unsupported locale '{}', expected one of 'en', 'de', 'fr', 'es', 'it', 'nl' or 'pt'", s)
+            },
+        };
+        Ok(locale)
+    }
+}
+
+/// The textual date components and format extensions that are not handled by chrono.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateNameDirective {
+    /// `%B`
+    Month,
+    /// `%b` and `%h`
+    MonthAbbrev,
+    /// `%A`
+    Weekday,
+    /// `%a`
+    WeekdayAbbrev,
+    /// `%Q`, the quarter of the year from 1 to 4.
+    Quarter,
+}
+
+#[derive(Clone, Debug)]
+enum FormatItem {
+    Chrono(String),
+    Directive(DateNameDirective),
+}
+
+/// A `strftime` format in which the textual date components are written in a [`DateLocale`].
+///
+/// The remaining directives are formatted by chrono.
+#[derive(Clone, Debug)]
+pub struct LocalizedFormat {
+    items: Vec<FormatItem>,
+    locale: DateLocale,
+}
+
+/// Returns the byte offsets and the [`DateNameDirective`]s of `format`, in order.
+pub fn date_name_directives(format: &str) -> impl Iterator<Item = (usize, DateNameDirective)> + '_ {
+    let mut chars = format.char_indices();
+    std::iter::from_fn(move || {
+        while let Some((i, c)) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            let directive = match chars.next()?.1 {
+                'B' => DateNameDirective::Month,
+                'b' | 'h' => DateNameDirective::MonthAbbrev,
+                'A' => DateNameDirective::Weekday,
+                'a' => DateNameDirective::WeekdayAbbrev,
+                'Q' => DateNameDirective::Quarter,
+                _ => continue,
+            };
+            return Some((i, directive));
+        }
+        None
+    })
+}
+
+impl LocalizedFormat {
+    /// Returns `None` if chrono can format `format` in `locale` on its own.
+    pub fn new(format: &str, locale: DateLocale) -> Option<Self> {
+        let mut items = vec![];
+        let mut start = 0;
+        for (i, directive) in date_name_directives(format) {
+            if locale == DateLocale::English && directive != DateNameDirective::Quarter {
+                continue;
+            }
+            if start < i {
+                items.push(FormatItem::Chrono(format[start..i].to_string()));
+            }
+            items.push(FormatItem::Directive(directive));
+            start = i + 2;
+        }
+        if items.is_empty() {
+            return None;
+        }
+        if start < format.len() {
+            items.push(FormatItem::Chrono(format[start..].to_string()));
+        }
+        Some(Self { items, locale })
+    }
+
+    /// Writes `date` to `buf`, formatting the chrono parts with `write_chrono`.
+    pub fn write<D: Datelike>(
+        &self,
+        buf: &mut String,
+        date: &D,
+        mut write_chrono: impl FnMut(&mut String, &str) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let month = date.month0() as usize;
+        let weekday = date.weekday().num_days_from_monday() as usize;
+        for item in &self.items {
+            match item {
+                FormatItem::Chrono(format) => write_chrono(buf, format)?,
+                FormatItem::Directive(DateNameDirective::Month) => {
+                    buf.push_str(self.locale.months()[month])
+                },
+                FormatItem::Directive(DateNameDirective::MonthAbbrev) => {
+                    buf.push_str(self.locale.months_abbrev()[month])
+                },
+                FormatItem::Directive(DateNameDirective::Weekday) => {
+                    buf.push_str(self.locale.weekdays()[weekday])
+                },
+                FormatItem::Directive(DateNameDirective::WeekdayAbbrev) => {
+                    buf.push_str(self.locale.weekdays_abbrev()[weekday])
+                },
+                FormatItem::Directive(DateNameDirective::Quarter) => {
+                    write!(buf, "{}", month / 3 + 1)?
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_localized_format() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 6).unwrap();
+        let format = |format: &str, locale: DateLocale| {
+            let mut buf = String::new();
+            LocalizedFormat::new(format, locale)
+                .unwrap()
+                .write(&mut buf, &date, |buf, f| write!(buf, "{}", date.format(f)))
+                .unwrap();
+            buf
+        };
+        assert_eq!(
+            format("%A %-d %B %Y", DateLocale::French),
+            "mardi 6 août 2024"
+        );
+        assert_eq!(
+            format("%a, %d. %b %Y", DateLocale::German),
+            "Di, 06. Aug 2024"
+        );
+        assert_eq!(
+            format("%Y-Q%Q (%%Q, day %j)", DateLocale::English),
+            "2024-Q3 (%Q, day 219)"
+        );
+
+        assert!(LocalizedFormat::new("%d %B %Y", DateLocale::English).is_none());
+        assert_eq!(
+            "pt_BR".parse::<DateLocale>().unwrap(),
+            DateLocale::Portuguese
+        );
+        assert!("xx".parse::<DateLocale>().is_err());
+    }
+}
@@ -6,6 +6,8 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "temporal")]
+mod locale;
 #[cfg(feature = "dtype-time")]
 mod time;
 #[cfg(feature = "dtype-date")]
@@ -25,6 +27,8 @@ use regex::Regex;
 pub use time::time_to_time64ns;
 
 pub use self::conversion::*;
+#[cfg(feature = "temporal")]
+pub use self::locale::{date_name_directives, DateLocale, DateNameDirective, LocalizedFormat};
 #[cfg(feature = "timezones")]
 use crate::prelude::{polars_bail, PolarsResult};
 
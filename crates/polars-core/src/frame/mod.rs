@@ -23,11 +23,13 @@ pub mod group_by;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
+mod selection;
 mod top_k;
 mod upstream_traits;
 
 use arrow::record_batch::RecordBatch;
 use polars_utils::pl_str::PlSmallStr;
+pub use selection::RowSelection;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -1637,6 +1639,29 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(new_col) })
     }
 
+    /// Take the [`DataFrame`] rows by a boolean mask, and return the [`RowSelection`] of the mask
+    /// as well, so that the same rows can be taken from other frames without evaluating the mask
+    /// again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn example(features: &DataFrame, labels: &DataFrame) -> PolarsResult<(DataFrame, DataFrame)> {
+    ///     let mask = features.column("sepal_width")?.is_not_null();
+    ///     let (features, selection) = features.filter_with_mask(&mask)?;
+    ///     Ok((features, selection.apply(labels)?))
+    /// }
+    /// ```
+    pub fn filter_with_mask(&self, mask: &BooleanChunked) -> PolarsResult<(Self, RowSelection)> {
+        let selection = if mask.len() == 1 && self.height() != 1 {
+            RowSelection::from_mask(&mask.new_from_index(0, self.height()))?
+        } else {
+            RowSelection::from_mask(mask)?
+        };
+        Ok((selection.apply(self)?, selection))
+    }
+
     /// Same as `filter` but does not parallelize.
     pub fn _filter_seq(&self, mask: &BooleanChunked) -> PolarsResult<Self> {
         let new_col = self.try_apply_columns(&|s| s.filter(mask))?;
@@ -2822,6 +2847,21 @@ impl DataFrame {
         unsafe { Self::new_no_checks(cols) }
     }
 
+    /// Aggregate the columns to their values at each of the `quantiles`, with one row per
+    /// quantile in the given order.
+    ///
+    /// Integer columns are converted to `Float64` and columns that are not numeric are null, see
+    /// [`Series::quantiles`]. The columns are aggregated in parallel, and the values of each
+    /// column are partitioned once for all quantiles instead of once per quantile.
+    pub fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Self> {
+        let columns = self.try_apply_columns_par(&|s| s.quantiles(quantiles, interpol))?;
+        Ok(unsafe { Self::new_no_checks(columns) })
+    }
+
     /// Hash and combine the row values
     #[cfg(feature = "row_hash")]
     pub fn hash_rows(
@@ -3145,6 +3185,57 @@ mod test {
         assert_eq!(new.len(), 0);
     }
 
+    #[test]
+    fn test_quantiles() -> PolarsResult<()> {
+        let mut sorted = Series::new("sorted".into(), (0..10).collect::<Vec<i32>>());
+        sorted.set_sorted_flag(IsSorted::Ascending);
+        let df = df![
+            "int" => [Some(7), None, Some(1), Some(9), Some(4), Some(4), None, Some(8), Some(2), Some(0)],
+            "float" => [0.5f32, 3.5, 1.0, f32::NAN, 2.5, 9.0, 7.5, 1.0, 4.0, 6.0],
+            "null" => [None::<f64>; 10],
+            "str" => ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"],
+        ]?
+        .hstack(&[sorted])?;
+
+        let quantiles = [0.0, 0.1, 0.25, 0.5, 0.9, 0.99, 1.0];
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            let out = df.quantiles(&quantiles, interpol)?;
+            assert_eq!(out.height(), quantiles.len());
+            for (i, &quantile) in quantiles.iter().enumerate() {
+                let row = out.slice(i as i64, 1);
+                for s in df.get_columns() {
+                    let out = row.column(s.name())?;
+                    if s.dtype() == &DataType::String {
+                        assert_eq!(out.null_count(), 1);
+                        continue;
+                    }
+                    let expected = s.quantile_reduce(quantile, interpol)?;
+                    assert_eq!(out.dtype(), expected.dtype());
+                    // The interpolation may differ in the last bits from that of `quantile`,
+                    // which sorts the nulls in front of the values.
+                    match (
+                        out.get(0)?.extract::<f64>(),
+                        expected.value().extract::<f64>(),
+                    ) {
+                        (Some(a), Some(b)) => assert!(a.tot_eq(&b) || (a - b).abs() < 1e-12),
+                        (a, b) => assert_eq!(a, b),
+                    }
+                }
+            }
+        }
+
+        assert!(df
+            .quantiles(&[0.5, 1.5], QuantileInterpolOptions::Linear)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn slice() {
         let df = create_frame();
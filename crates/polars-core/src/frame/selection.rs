@@ -0,0 +1,139 @@
+use arrow::array::BooleanArray;
+use arrow::bitmap::MutableBitmap;
+
+use crate::prelude::*;
+
+/// The rows of a [`DataFrame`] that are selected by a filter.
+///
+/// A selection can be applied to other frames and series with the same rows, without evaluating
+/// the predicate again. Rows for which the predicate is null are not selected.
+#[derive(Clone, Debug)]
+pub struct RowSelection {
+    /// Single chunk mask without nulls.
+    mask: BooleanChunked,
+}
+
+impl RowSelection {
+    /// Create a selection of the rows for which `mask` is `true`.
+    pub fn from_mask(mask: &BooleanChunked) -> PolarsResult<Self> {
+        let mask = if mask.null_count() > 0 {
+            mask.fill_null_with_values(false)?
+        } else {
+            mask.clone()
+        };
+        Ok(Self {
+            mask: mask.rechunk(),
+        })
+    }
+
+    /// Create a selection of the rows at `indices` out of `len` rows. The selected rows keep
+    /// their order when the selection is applied, regardless of the order of the indices.
+    pub fn from_indices(indices: &IdxCa, len: usize) -> PolarsResult<Self> {
+        polars_ensure!(
+            indices.null_count() == 0,
+            ComputeError: "the indices of a row selection cannot be null"
+        );
+        let mut values = MutableBitmap::from_len_zeroed(len);
+        for idx in indices.downcast_iter().flat_map(|arr| arr.values().iter()) {
+            let idx = *idx as usize;
+            polars_ensure!(idx < len, oob = idx, len);
+            values.set(idx, true);
+        }
+        let mask = BooleanArray::from_data_default(values.into(), None);
+        Ok(Self {
+            mask: BooleanChunked::with_chunk(indices.name().clone(), mask),
+        })
+    }
+
+    /// The number of rows that the selection is taken from.
+    pub fn len(&self) -> usize {
+        self.mask.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mask.is_empty()
+    }
+
+    /// The number of selected rows.
+    pub fn selected_len(&self) -> usize {
+        self.mask.sum().unwrap_or(0) as usize
+    }
+
+    /// The mask of the selected rows.
+    pub fn mask(&self) -> &BooleanChunked {
+        &self.mask
+    }
+
+    /// The indices of the selected rows, in ascending order.
+    pub fn indices(&self) -> IdxCa {
+        let mut offset = 0;
+        let mut indices = Vec::with_capacity(self.selected_len());
+        for arr in self.mask.downcast_iter() {
+            indices.extend(
+                arr.values()
+                    .true_idx_iter()
+                    .map(|idx| (offset + idx) as IdxSize),
+            );
+            offset += arr.len();
+        }
+        IdxCa::from_vec(self.mask.name().clone(), indices)
+    }
+
+    /// Take the selected rows of `df`, which must have as many rows as the selection.
+    pub fn apply(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            df.height() == self.len(),
+            ShapeMismatch: "cannot apply a selection of {} rows to a DataFrame of height {}",
+            self.len(), df.height()
+        );
+        df.filter(&self.mask)
+    }
+
+    /// Take the selected rows of `s`, which must have as many rows as the selection.
+    pub fn apply_series(&self, s: &Series) -> PolarsResult<Series> {
+        polars_ensure!(
+            s.len() == self.len(),
+            ShapeMismatch: "cannot apply a selection of {} rows to a Series of length {}",
+            self.len(), s.len()
+        );
+        s.filter(&self.mask)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_row_selection() -> PolarsResult<()> {
+        let df = df![
+            "price" => [10.0, 11.0, 9.0, 12.0, 13.0],
+            "signal" => [Some(1), None, Some(-1), Some(1), Some(0)],
+        ]?;
+        let returns = df!["return" => [0.1, -0.2, 0.3, 0.0, 0.5]]?;
+
+        let mask = df.column("signal")?.i32()?.gt(0);
+        let (filtered, selection) = df.filter_with_mask(&mask)?;
+        assert_eq!(filtered.height(), 2);
+        assert_eq!(selection.len(), 5);
+        assert_eq!(selection.selected_len(), 2);
+        assert_eq!(selection.indices().to_vec(), [Some(0), Some(3)]);
+
+        let out = selection.apply(&returns)?;
+        assert_eq!(
+            out.column("return")?.f64()?.to_vec(),
+            [Some(0.1), Some(0.0)]
+        );
+        let out = selection.apply_series(df.column("price")?)?;
+        assert_eq!(out.f64()?.to_vec(), [Some(10.0), Some(12.0)]);
+
+        let from_indices = RowSelection::from_indices(&IdxCa::new("".into(), [3, 0]), 5)?;
+        assert_eq!(
+            from_indices.indices().to_vec(),
+            selection.indices().to_vec()
+        );
+        assert!(RowSelection::from_indices(&IdxCa::new("".into(), [5]), 5).is_err());
+        assert!(selection.apply(&returns.head(Some(4))).is_err());
+        Ok(())
+    }
+}
@@ -30,6 +30,8 @@ pub use crate::chunked_array::ops::rolling_window::RollingOptionsFixedWindow;
 pub use crate::chunked_array::ops::*;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
+#[cfg(feature = "temporal")]
+pub use crate::chunked_array::temporal::DateLocale;
 pub(crate) use crate::chunked_array::ChunkLenIter;
 pub use crate::chunked_array::ChunkedArray;
 #[cfg(feature = "dtype-struct")]
@@ -45,7 +47,7 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
-pub use crate::frame::{DataFrame, UniqueKeepStrategy};
+pub use crate::frame::{DataFrame, RowSelection, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
 pub use crate::scalar::Scalar;
@@ -835,6 +835,35 @@ impl Series {
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
+    /// Get the values at each of the `quantiles` as a new Series with one value per quantile.
+    ///
+    /// Integer Series are converted to `Float64`, and Series that are not numeric are null. The
+    /// values are partitioned once for all quantiles.
+    pub fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        check_quantiles(quantiles)?;
+        let name = self.name().clone();
+        if !self.dtype().is_numeric() {
+            return Ok(Series::full_null(name, quantiles.len(), self.dtype()));
+        }
+
+        macro_rules! dispatch {
+            ($ca:expr) => {{
+                $ca.quantiles(quantiles, interpol)?
+            }};
+        }
+        let values = downcast_as_macro_arg_physical!(self, dispatch);
+        Ok(if self.dtype() == &DataType::Float32 {
+            Float32Chunked::from_iter_options(name, values.into_iter().map(|v| v.map(|v| v as f32)))
+                .into_series()
+        } else {
+            Float64Chunked::from_iter_options(name, values.into_iter()).into_series()
+        })
+    }
+
     /// Compute the unique elements, but maintain order. This requires more work
     /// than a naive [`Series::unique`](SeriesTrait::unique).
     pub fn unique_stable(&self) -> PolarsResult<Series> {
@@ -0,0 +1,331 @@
+use crate::chunked_array::cast::{CastArgs, CastOptions, CastOverflow, NumberFormat, RoundingMode};
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::ops::arity::unary_elementwise;
+use crate::prelude::*;
+use crate::utils::handle_casting_failures;
+
+impl Series {
+    /// Cast `[Series]` to another `[DataType]`, with control over how values that don't fit the
+    /// target type are handled, how digits are rounded and how strings are parsed to numbers.
+    pub fn cast_with_args(&self, dtype: &DataType, args: &CastArgs) -> PolarsResult<Series> {
+        let mut input = self.clone();
+        if let Some(format) = &args.number_format {
+            if self.dtype().is_string() && (dtype.is_numeric() || dtype.is_decimal()) {
+                input = normalize_numbers(self.str()?, format).into_series();
+            }
+        }
+        if let Some(rounded) = round_for_cast(&input, dtype, args.rounding)? {
+            input = rounded;
+        }
+
+        #[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+        let rescaled = rescale_temporal(&input, dtype, args.overflow)?;
+        #[cfg(not(any(feature = "dtype-datetime", feature = "dtype-duration")))]
+        let rescaled = None;
+        let mut out = match rescaled {
+            Some(out) => out,
+            None => {
+                let options = match args.overflow {
+                    CastOverflow::Wrap => CastOptions::Overflowing,
+                    _ => CastOptions::NonStrict,
+                };
+                input.cast_with_options(dtype, options)?
+            },
+        };
+        if args.overflow == CastOverflow::Saturate {
+            out = saturate(&input, out)?;
+        }
+        if args.overflow == CastOverflow::Error && out.null_count() != self.null_count() {
+            handle_casting_failures(self, &out)?;
+        }
+        Ok(out)
+    }
+}
+
+/// Rewrites the numbers to the format that the cast parses, e.g. `1.234,5` to `1234.5`.
+fn normalize_numbers(ca: &StringChunked, format: &NumberFormat) -> StringChunked {
+    ca.apply_into_string_amortized(|s, buf| {
+        for c in s.chars() {
+            if Some(c) == format.thousands_separator {
+                continue;
+            }
+            buf.push(if c == format.decimal_separator {
+                '.'
+            } else {
+                c
+            });
+        }
+    })
+}
+
+/// Returns `v / d` rounded to an integer with `mode`, for a positive `d`.
+#[cfg(feature = "dtype-decimal")]
+fn div_round(v: i128, d: i128, mode: RoundingMode) -> i128 {
+    let (q, r) = (v / d, (v % d).abs());
+    let round_away = match mode {
+        RoundingMode::Truncate => false,
+        RoundingMode::HalfUp => r >= d - r,
+        RoundingMode::HalfEven => r > d - r || (r == d - r && q % 2 != 0),
+    };
+    if round_away {
+        q + v.signum()
+    } else {
+        q
+    }
+}
+
+fn round_float(v: f64, mode: RoundingMode) -> f64 {
+    match mode {
+        RoundingMode::Truncate => v.trunc(),
+        RoundingMode::HalfUp => v.round(),
+        RoundingMode::HalfEven => v.round_ties_even(),
+    }
+}
+
+/// Rounds the values to the number of digits of `dtype` if that drops digits. The cast itself
+/// truncates.
+fn round_for_cast(
+    s: &Series,
+    dtype: &DataType,
+    mode: RoundingMode,
+) -> PolarsResult<Option<Series>> {
+    if mode == RoundingMode::Truncate {
+        return Ok(None);
+    }
+    let out = match (s.dtype(), dtype) {
+        (from, to) if from.is_float() && to.is_integer() => s
+            .cast(&DataType::Float64)?
+            .f64()?
+            .apply_values(|v| round_float(v, mode))
+            .into_series(),
+        #[cfg(feature = "dtype-decimal")]
+        (from, DataType::Decimal(_, Some(scale))) if from.is_float() => {
+            let factor = 10f64.powi(*scale as i32);
+            let ca = s.cast(&DataType::Float64)?;
+            let ca: Int128Chunked = unary_elementwise(ca.f64()?, |v: Option<f64>| {
+                let v = round_float(v? * factor, mode);
+                // Larger values don't fit any decimal.
+                (v.abs() < 1e38).then_some(v as i128)
+            });
+            ca.into_decimal_unchecked(None, *scale).into_series()
+        },
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(_, Some(from_scale)), to) => {
+            let to_scale = match to {
+                DataType::Decimal(_, Some(to_scale)) if to_scale < from_scale => *to_scale,
+                to if to.is_integer() && *from_scale > 0 => 0,
+                _ => return Ok(None),
+            };
+            let d = 10i128.pow((from_scale - to_scale) as u32);
+            s.decimal()?
+                .physical()
+                .apply_values(|v| div_round(v, d, mode))
+                .into_decimal_unchecked(None, to_scale)
+                .into_series()
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(out))
+}
+
+/// Casts between datetimes, durations and dates with the `overflow` policy if the time unit gets
+/// finer, which multiplies the values.
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn rescale_temporal(
+    s: &Series,
+    dtype: &DataType,
+    overflow: CastOverflow,
+) -> PolarsResult<Option<Series>> {
+    use DataType::*;
+    let units_per_day = |tu: &TimeUnit| match tu {
+        TimeUnit::Nanoseconds => NS_IN_DAY,
+        TimeUnit::Microseconds => US_IN_DAY,
+        TimeUnit::Milliseconds => MS_IN_DAY,
+    };
+    let factor = match (s.dtype(), dtype) {
+        #[cfg(feature = "dtype-datetime")]
+        (Datetime(from, _), Datetime(to, _)) => units_per_day(to) / units_per_day(from),
+        #[cfg(feature = "dtype-duration")]
+        (Duration(from), Duration(to)) => units_per_day(to) / units_per_day(from),
+        #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+        (Date, Datetime(to, _)) => units_per_day(to),
+        _ => return Ok(None),
+    };
+    if factor <= 1 {
+        return Ok(None);
+    }
+
+    let physical = s.to_physical_repr().cast(&Int64)?;
+    let physical = physical.i64()?;
+    let out = match overflow {
+        CastOverflow::Error | CastOverflow::Null => {
+            physical.apply(|v| v.and_then(|v| v.checked_mul(factor)))
+        },
+        CastOverflow::Saturate => physical.apply_values(|v| v.saturating_mul(factor)),
+        CastOverflow::Wrap => physical.apply_values(|v| v.wrapping_mul(factor)),
+    };
+    out.into_series().cast(dtype).map(Some)
+}
+
+/// Replaces the values of `out` that overflowed with the minimum or maximum of its type.
+fn saturate(input: &Series, out: Series) -> PolarsResult<Series> {
+    let dtype = out.dtype().clone();
+    #[cfg(feature = "dtype-decimal")]
+    let is_decimal = matches!(dtype, DataType::Decimal(_, Some(_)));
+    #[cfg(not(feature = "dtype-decimal"))]
+    let is_decimal = false;
+    if !dtype.is_integer() && !is_decimal {
+        return Ok(out);
+    }
+
+    let value = input
+        .to_physical_repr()
+        .cast_with_options(&DataType::Float64, CastOptions::NonStrict)?;
+    let (min, max) = match &dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, Some(scale)) => {
+            let max = 10f64.powi(precision.unwrap_or(38) as i32 - *scale as i32);
+            (-max, max)
+        },
+        dt => with_match_physical_integer_polars_type!(dt, |$T| {
+            type N = <$T as PolarsNumericType>::Native;
+            (N::MIN as f64, N::MAX as f64)
+        }),
+    };
+    // Values that failed for another reason, e.g. strings that aren't numbers, stay null.
+    let overflowed = input.is_not_null() & out.is_null() & (value.lt(min)? | value.gt(max)?);
+    if !overflowed.any() {
+        return Ok(out);
+    }
+    let positive = value.gt(0.0)?;
+
+    let out = match &dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, Some(scale)) => {
+            let max = 10i128.pow(precision.unwrap_or(38) as u32) - 1;
+            saturate_values(out.decimal()?.physical(), &overflowed, &positive, -max, max)
+                .into_decimal_unchecked(*precision, *scale)
+                .into_series()
+        },
+        dt => with_match_physical_integer_polars_type!(dt, |$T| {
+            type N = <$T as PolarsNumericType>::Native;
+            let ca: &ChunkedArray<$T> = out.as_ref().as_ref();
+            saturate_values(ca, &overflowed, &positive, N::MIN, N::MAX)
+                .into_series()
+        }),
+    };
+    Ok(out)
+}
+
+fn saturate_values<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    overflowed: &BooleanChunked,
+    positive: &BooleanChunked,
+    min: T::Native,
+    max: T::Native,
+) -> ChunkedArray<T> {
+    ca.iter()
+        .zip(overflowed)
+        .zip(positive)
+        .map(|((v, overflowed), positive)| match overflowed {
+            Some(true) if positive == Some(true) => Some(max),
+            Some(true) => Some(min),
+            _ => v,
+        })
+        .collect_ca_trusted(ca.name().clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cast_with_args() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1.5f64, 2.5, -2.5, 1e12]);
+        let args = CastArgs::default()
+            .with_rounding(RoundingMode::HalfEven)
+            .with_overflow(CastOverflow::Saturate);
+        let out = s.cast_with_args(&DataType::Int32, &args)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            [Some(2), Some(2), Some(-2), Some(i32::MAX)]
+        );
+        let args = args.with_rounding(RoundingMode::HalfUp);
+        let out = s.cast_with_args(&DataType::Int32, &args)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            [Some(2), Some(3), Some(-3), Some(i32::MAX)]
+        );
+
+        let args = args.with_overflow(CastOverflow::Null);
+        let out = s.cast_with_args(&DataType::Int32, &args)?;
+        assert_eq!(out.null_count(), 1);
+        let args = args.with_overflow(CastOverflow::Error);
+        assert!(s.cast_with_args(&DataType::Int32, &args).is_err());
+        assert!(s
+            .head(Some(3))
+            .cast_with_args(&DataType::Int32, &args)
+            .is_ok());
+
+        let s = Series::new("a".into(), ["1.234,5", "-0,25", "x"]);
+        let args = CastArgs::default()
+            .with_overflow(CastOverflow::Null)
+            .with_number_format(Some(NumberFormat::new(',', Some('.'))));
+        let out = s.cast_with_args(&DataType::Float64, &args)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(1234.5), Some(-0.25), None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_cast_with_args_decimal() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [0.125f64, 0.135, -0.125, 0.29]);
+        let dtype = DataType::Decimal(Some(10), Some(2));
+        let args = CastArgs::default().with_rounding(RoundingMode::HalfEven);
+        let out = s.cast_with_args(&dtype, &args)?;
+        assert_eq!(
+            Vec::from(out.decimal()?.physical()),
+            [Some(12), Some(14), Some(-12), Some(29)]
+        );
+
+        let s = Series::new("a".into(), [0.125f64, 0.135, -0.125])
+            .cast(&DataType::Decimal(None, Some(3)))?;
+        let out = s.cast_with_args(&dtype, &args)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(out.decimal()?.physical()),
+            [Some(12), Some(14), Some(-12)]
+        );
+
+        let s = Series::new("a".into(), [1234.5f64, -1234.5]);
+        let dtype = DataType::Decimal(Some(3), Some(0));
+        let args = args.with_overflow(CastOverflow::Saturate);
+        let out = s.cast_with_args(&dtype, &args)?;
+        assert_eq!(
+            Vec::from(out.decimal()?.physical()),
+            [Some(999), Some(-999)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_cast_with_args_temporal() -> PolarsResult<()> {
+        // A date in the year 3000 doesn't fit a datetime in nanoseconds.
+        let s = Series::new("a".into(), [0i32, 376200]).cast(&DataType::Date)?;
+        let dtype = DataType::Datetime(TimeUnit::Nanoseconds, None);
+        assert!(s.cast_with_args(&dtype, &CastArgs::default()).is_err());
+
+        let args = CastArgs::default().with_overflow(CastOverflow::Null);
+        let out = s.cast_with_args(&dtype, &args)?;
+        assert_eq!(Vec::from(out.datetime()?.physical()), [Some(0), None]);
+
+        let args = args.with_overflow(CastOverflow::Saturate);
+        let out = s.cast_with_args(&dtype, &args)?;
+        assert_eq!(
+            Vec::from(out.datetime()?.physical()),
+            [Some(0), Some(i64::MAX)]
+        );
+        Ok(())
+    }
+}
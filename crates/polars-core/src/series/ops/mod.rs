@@ -1,3 +1,4 @@
+mod cast_args;
 mod downcast;
 mod extend;
 mod null;
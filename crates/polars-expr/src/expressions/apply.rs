@@ -557,19 +557,25 @@ impl ApplyExpr {
                     #[allow(clippy::explicit_auto_deref)]
                     let input: &Series = &**input;
                     let st = stats.get_stats(&root).ok()?;
-                    let min = st.to_min()?;
-                    let max = st.to_max()?;
+                    let in_range = || -> Option<bool> {
+                        let min = st.to_min()?;
+                        let max = st.to_max()?;
+
+                        if max.get(0).unwrap() == min.get(0).unwrap() {
+                            let one_equals = |value: &Series| {
+                                Some(ChunkCompare::equal(input, value).ok()?.any())
+                            };
+                            return one_equals(min);
+                        }
 
-                    if max.get(0).unwrap() == min.get(0).unwrap() {
-                        let one_equals =
-                            |value: &Series| Some(ChunkCompare::equal(input, value).ok()?.any());
-                        return one_equals(min);
-                    }
+                        let smaller = ChunkCompare::lt(input, min).ok()?;
+                        let bigger = ChunkCompare::gt(input, max).ok()?;
 
-                    let smaller = ChunkCompare::lt(input, min).ok()?;
-                    let bigger = ChunkCompare::gt(input, max).ok()?;
+                        Some(!(smaller | bigger).all())
+                    };
 
-                    Some(!(smaller | bigger).all())
+                    // Bloom filters can rule out values within the min/max range.
+                    Some(in_range().unwrap_or(true) && st.may_contain_any(input))
                 };
 
                 Ok(should_read().unwrap_or(true))
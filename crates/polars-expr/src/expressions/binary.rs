@@ -266,7 +266,9 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::predicates::{BatchStats, StatsEvaluator};
+    use std::borrow::Cow;
+
+    use polars_io::predicates::{BatchStats, ColumnStats, StatsEvaluator};
 
     use super::*;
 
@@ -368,6 +370,33 @@ mod stats {
         }
     }
 
+    /// Returns the statistics of the column `name` that `expr` evaluates to. Fields of struct
+    /// columns use the statistics of their leaf column.
+    #[cfg_attr(not(feature = "dtype-struct"), allow(unused_variables))]
+    fn get_stats<'a>(
+        expr: &Arc<dyn PhysicalExpr>,
+        name: &str,
+        stats: &'a BatchStats,
+    ) -> PolarsResult<Cow<'a, ColumnStats>> {
+        #[cfg(feature = "dtype-struct")]
+        if let Some(Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(field)),
+            ..
+        }) = expr.as_expression()
+        {
+            let Some(Expr::Column(column)) = input.first() else {
+                polars_bail!(ColumnNotFound: "{}", name);
+            };
+            return stats
+                .get_stats(column)?
+                .struct_field(field)
+                .map(Cow::Owned)
+                .ok_or_else(|| polars_err!(ColumnNotFound: "{}.{}", column, field));
+        }
+        stats.get_stats(name).map(Cow::Borrowed)
+    }
+
     impl BinaryExpr {
         fn impl_should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
             // See: #5864 for the rationale behind this.
@@ -378,6 +407,11 @@ mod stats {
                     !matches!(op, Multiply | Divide | TrueDivide | FloorDivide | Modulus)
                 },
                 Column(_) | Literal(_) | Alias(_, _) => true,
+                #[cfg(feature = "dtype-struct")]
+                Function {
+                    function: FunctionExpr::StructExpr(StructFunction::FieldByName(_)),
+                    ..
+                } => true,
                 _ => false,
             }) {
                 return Ok(true);
@@ -407,28 +441,31 @@ mod stats {
 
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
-                    let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
+                    let l = get_stats(&self.left, fld_l.name(), stats)?;
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    let read = match l.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op)
                         },
-                    }
+                    };
+                    // Bloom filters can rule out values within the min/max range.
+                    Ok(read && (self.op != Operator::Eq || l.may_contain_any(&lit_s)))
                 },
                 (true, false) => {
-                    let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
+                    let r = get_stats(&self.right, fld_r.name(), stats)?;
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    let read = match r.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && (self.op != Operator::Eq || r.may_contain_any(&lit_s)))
                 },
                 // Default: read the file
                 _ => Ok(true),
@@ -1,5 +1,7 @@
 mod read;
+mod schema;
 mod write;
 
 pub use read::*;
+pub use schema::*;
 pub use write::*;
@@ -0,0 +1,49 @@
+use arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use arrow::io::avro::{read, write};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use crate::shared::schema_to_arrow_checked;
+
+/// Convert a [`Schema`] to the JSON of the Avro record schema named `name` that the
+/// [`AvroWriter`](super::AvroWriter) writes for it.
+pub fn schema_to_avro_json(schema: &Schema, name: &str) -> PolarsResult<String> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+    let record = write::to_record(&schema, name.to_string())?;
+    serde_json::to_string(&AvroSchema::Record(record)).map_err(to_compute_err)
+}
+
+/// Convert the JSON of an Avro record schema to the [`Schema`] of the [`DataFrame`] that the
+/// [`AvroReader`](super::AvroReader) reads from a file with that schema.
+pub fn avro_json_to_schema(avro_json: &str) -> PolarsResult<Schema> {
+    let avro_schema: AvroSchema = serde_json::from_str(avro_json).map_err(to_compute_err)?;
+    let AvroSchema::Record(record) = avro_schema else {
+        polars_bail!(ComputeError: "expected an Avro record schema, got {:?}", avro_schema)
+    };
+    let schema = read::infer_schema(&record)?;
+    Ok(Schema::from_arrow_schema(&schema))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_avro_json_roundtrip() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::List(Box::new(DataType::Float64))),
+            Field::new("d".into(), DataType::Boolean),
+        ]);
+
+        let avro_json = schema_to_avro_json(&schema, "test")?;
+        assert!(avro_json.starts_with(
+            r#"{"type":"record","name":"test","fields":[{"name":"a","type":["null","long"]}"#
+        ));
+        assert_eq!(avro_json_to_schema(&avro_json)?, schema);
+
+        assert!(avro_json_to_schema(r#"{"type": "array", "items": "long"}"#).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Listing of Azure Data Lake Storage Gen2 file systems through their DFS endpoint.
+//!
+//! With a hierarchical namespace, listing a directory through the DFS endpoint only touches that
+//! directory, while listing blobs always walks every object under the prefix.
+
+use object_store::azure::{AzureAuthorizer, AzureConfigKey};
+use object_store::path::Path;
+use polars_error::{polars_bail, to_compute_err, PolarsResult};
+use serde::Deserialize;
+use url::Url;
+
+use super::options::parse_config_bool;
+use super::{parse_url, CloudOptions};
+
+/// Continuation token for the next page of a listing.
+const CONTINUATION_HEADER: &str = "x-ms-continuation";
+
+#[derive(Deserialize)]
+struct PathList {
+    paths: Vec<PathEntry>,
+}
+
+/// A path of a listing, the numbers and booleans are sent as strings.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PathEntry {
+    name: String,
+    #[serde(default)]
+    is_directory: Option<String>,
+    #[serde(default)]
+    content_length: Option<String>,
+}
+
+impl PathEntry {
+    fn is_non_empty_file(&self) -> bool {
+        self.is_directory.as_deref() != Some("true")
+            && self
+                .content_length
+                .as_deref()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0)
+                > 0
+    }
+}
+
+/// The host and file system of a Hadoop-style url of the DFS endpoint,
+/// `abfs[s]://<file_system>@<account>.dfs.core.windows.net/<path>`.
+fn dfs_file_system(url: &Url) -> Option<(&str, &str)> {
+    if !matches!(url.scheme(), "abfs" | "abfss") || url.username().is_empty() {
+        return None;
+    }
+    let host = url.host_str()?;
+    (host.ends_with(".dfs.core.windows.net") || host.ends_with(".dfs.fabric.microsoft.com"))
+        .then_some((host, url.username()))
+}
+
+/// The directory that contains all keys starting with `prefix`.
+fn prefix_directory(prefix: &str) -> &str {
+    prefix.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+}
+
+/// List the non-empty files starting with `prefix` in the file system of `url`, only descending
+/// into subdirectories if `recursive` is set.
+///
+/// Returns `None` if `url` doesn't address the DFS endpoint or it can't be used, in which case
+/// the objects should be listed as blobs.
+pub(super) async fn list_files(
+    url: &str,
+    options: Option<&CloudOptions>,
+    prefix: &str,
+    recursive: bool,
+) -> PolarsResult<Option<Vec<Path>>> {
+    let parsed = parse_url(url).map_err(to_compute_err)?;
+    let Some((host, file_system)) = dfs_file_system(&parsed) else {
+        return Ok(None);
+    };
+    let account = host.split('.').next().unwrap();
+
+    let default_options = CloudOptions::default();
+    let options = options.map_or(&default_options, |options| options.for_url(url));
+    let builder = options.azure_builder(url)?;
+    // Emulators and custom endpoints don't serve the host of the url.
+    let use_emulator = match builder.get_config_value(&AzureConfigKey::UseEmulator) {
+        Some(v) => parse_config_bool(AzureConfigKey::UseEmulator.as_ref(), &v)?,
+        None => false,
+    };
+    if use_emulator
+        || builder
+            .get_config_value(&AzureConfigKey::Endpoint)
+            .is_some()
+    {
+        return Ok(None);
+    }
+    let skip_signature = match builder.get_config_value(&AzureConfigKey::SkipSignature) {
+        Some(v) => parse_config_bool(AzureConfigKey::SkipSignature.as_ref(), &v)?,
+        None => false,
+    };
+    let credential = if skip_signature {
+        None
+    } else {
+        let store = builder.build().map_err(to_compute_err)?;
+        Some(
+            store
+                .credentials()
+                .get_credential()
+                .await
+                .map_err(to_compute_err)?,
+        )
+    };
+
+    let client = options
+        .reqwest_client_builder()?
+        .build()
+        .map_err(to_compute_err)?;
+    let directory = prefix_directory(prefix);
+    let recursive = if recursive { "true" } else { "false" };
+
+    let mut out = vec![];
+    let mut continuation: Option<String> = None;
+    loop {
+        let mut query = vec![("resource", "filesystem"), ("recursive", recursive)];
+        if !directory.is_empty() {
+            query.push(("directory", directory));
+        }
+        if let Some(token) = &continuation {
+            query.push(("continuation", token.as_str()));
+        }
+        let mut request = client
+            .get(format!("https://{host}/{file_system}"))
+            .query(&query)
+            .build()
+            .map_err(to_compute_err)?;
+        if let Some(credential) = &credential {
+            AzureAuthorizer::new(credential, account).authorize(&mut request);
+        }
+
+        let response = client.execute(request).await.map_err(to_compute_err)?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            // Like an empty blob prefix.
+            break;
+        }
+        if matches!(
+            status,
+            reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::CONFLICT
+        ) {
+            // Accounts without a hierarchical namespace may not support the operation.
+            if polars_core::config::verbose() {
+                eprintln!("[adls] listing through the DFS endpoint failed with {status}, listing blobs instead");
+            }
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            polars_bail!(ComputeError: "failed to list '{}': {}: {}", url, status, body);
+        }
+
+        let next = response
+            .headers()
+            .get(CONTINUATION_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string);
+        let body = response.bytes().await.map_err(to_compute_err)?;
+        let list: PathList = serde_json::from_slice(&body).map_err(to_compute_err)?;
+        out.extend(
+            list.paths
+                .into_iter()
+                .filter(|entry| entry.is_non_empty_file() && entry.name.starts_with(prefix))
+                .map(|entry| Path::from(entry.name)),
+        );
+
+        match next {
+            Some(token) => continuation = Some(token),
+            None => break,
+        }
+    }
+    Ok(Some(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dfs_file_system() {
+        let url = Url::parse("abfss://data@account.dfs.core.windows.net/a/b.parquet").unwrap();
+        assert_eq!(
+            dfs_file_system(&url),
+            Some(("account.dfs.core.windows.net", "data"))
+        );
+        let url = Url::parse("abfss://data/a/b.parquet").unwrap();
+        assert_eq!(dfs_file_system(&url), None);
+        let url = Url::parse("az://data/a/b.parquet").unwrap();
+        assert_eq!(dfs_file_system(&url), None);
+
+        assert_eq!(prefix_directory("a/b/"), "a/b");
+        assert_eq!(prefix_directory("a/b/part-"), "a/b");
+        assert_eq!(prefix_directory("part-"), "");
+
+        let list: PathList = serde_json::from_str(
+            r#"{"paths":[
+                {"name":"a","isDirectory":"true","contentLength":"0"},
+                {"name":"a/b.parquet","contentLength":"10"},
+                {"name":"a/empty","contentLength":"0"}
+            ]}"#,
+        )
+        .unwrap();
+        let files = list
+            .paths
+            .iter()
+            .filter(|entry| entry.is_non_empty_file())
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(files, ["a/b.parquet"]);
+    }
+}
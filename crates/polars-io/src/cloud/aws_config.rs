@@ -0,0 +1,769 @@
+//! Profiles of the AWS shared config (`~/.aws/config`) and credentials (`~/.aws/credentials`)
+//! files, resolved like the AWS CLI and boto3 resolve them.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use futures::future::BoxFuture;
+use object_store::aws::{AwsAuthorizer, AwsCredential};
+use object_store::CredentialProvider;
+use polars_error::{
+    polars_bail, polars_ensure, polars_err, to_compute_err, PolarsError, PolarsResult,
+};
+use polars_utils::aliases::PlHashMap;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::path_utils::resolve_homedir;
+
+/// Credentials are fetched again this long before they expire.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(300);
+/// Region of the STS endpoint when the profile doesn't set one.
+const DEFAULT_STS_REGION: &str = "us-east-1";
+/// Limit on the length of `source_profile` chains, which also catches cycles.
+const MAX_SOURCE_PROFILE_DEPTH: usize = 16;
+
+type Section = PlHashMap<String, String>;
+
+/// Parse an AWS INI file into its sections. Nested properties (indented lines below a key
+/// without value, e.g. `s3 =`) are skipped.
+fn parse_ini(content: &str) -> PlHashMap<String, Section> {
+    let mut sections: PlHashMap<String, Section> = PlHashMap::default();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.trim().to_string();
+            sections.entry(name.clone()).or_default();
+            current = Some(name);
+        } else if let (Some(name), Some((key, value))) = (&current, line.split_once('=')) {
+            sections
+                .get_mut(name)
+                .unwrap()
+                .insert(key.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+    sections
+}
+
+/// The credentials of a profile.
+#[derive(Debug)]
+pub(super) enum ProfileCredentials {
+    Static(Arc<AwsCredential>),
+    /// Command that prints the credentials as JSON (`credential_process`).
+    Process(String),
+    /// Role credentials of IAM Identity Center, fetched with the token cached by `aws sso login`.
+    Sso {
+        start_url: String,
+        region: String,
+        account_id: String,
+        role_name: String,
+    },
+    /// Credentials of a role, assumed with the credentials of the source profile.
+    AssumeRole {
+        role_arn: String,
+        session_name: Option<String>,
+        external_id: Option<String>,
+        duration_seconds: Option<u64>,
+        region: Option<String>,
+        source: Box<ProfileCredentials>,
+    },
+}
+
+/// The settings of a profile polars uses to connect to S3.
+#[derive(Debug)]
+pub(super) struct AwsProfile {
+    pub(super) region: Option<String>,
+    pub(super) endpoint_url: Option<String>,
+    pub(super) credentials: Option<ProfileCredentials>,
+}
+
+/// The profiles of the shared config and credentials files.
+#[derive(Debug, Default)]
+pub(super) struct AwsSharedConfig {
+    config: PlHashMap<String, Section>,
+    credentials: PlHashMap<String, Section>,
+    sso_sessions: PlHashMap<String, Section>,
+}
+
+impl AwsSharedConfig {
+    /// Read the files at `AWS_CONFIG_FILE` and `AWS_SHARED_CREDENTIALS_FILE`, by default
+    /// `~/.aws/config` and `~/.aws/credentials`. Missing files have no profiles.
+    pub(super) fn load() -> Self {
+        let read = |var: &str, default: &str| {
+            let path = std::env::var(var).unwrap_or_else(|_| default.to_string());
+            std::fs::read_to_string(resolve_homedir(path.as_ref())).unwrap_or_default()
+        };
+        Self::from_strings(
+            &read("AWS_CONFIG_FILE", "~/.aws/config"),
+            &read("AWS_SHARED_CREDENTIALS_FILE", "~/.aws/credentials"),
+        )
+    }
+
+    /// In the config file profiles are named `[profile name]` (except `[default]`), in the
+    /// credentials file just `[name]`.
+    fn from_strings(config: &str, credentials: &str) -> Self {
+        let mut this = Self {
+            credentials: parse_ini(credentials),
+            ..Default::default()
+        };
+        for (name, section) in parse_ini(config) {
+            if name == "default" {
+                this.config.insert(name, section);
+            } else if let Some(name) = name.strip_prefix("profile ") {
+                this.config.insert(name.trim().to_string(), section);
+            } else if let Some(name) = name.strip_prefix("sso-session ") {
+                this.sso_sessions.insert(name.trim().to_string(), section);
+            }
+        }
+        this
+    }
+
+    /// Look up a setting of a profile, the credentials file takes precedence.
+    fn get(&self, profile: &str, key: &str) -> Option<&str> {
+        [&self.credentials, &self.config]
+            .into_iter()
+            .find_map(|sections| sections.get(profile)?.get(key))
+            .map(|v| v.as_str())
+    }
+
+    fn contains(&self, profile: &str) -> bool {
+        self.config.contains_key(profile) || self.credentials.contains_key(profile)
+    }
+
+    /// Resolve the selected profile: `name` if given, otherwise `AWS_PROFILE` (or the legacy
+    /// `AWS_DEFAULT_PROFILE`), otherwise `default`. A selected profile must exist, a missing
+    /// `default` profile gives `None`.
+    pub(super) fn profile(&self, name: Option<&str>) -> PolarsResult<Option<AwsProfile>> {
+        let selected = name.map(|name| name.to_string()).or_else(|| {
+            std::env::var("AWS_PROFILE")
+                .or_else(|_| std::env::var("AWS_DEFAULT_PROFILE"))
+                .ok()
+        });
+        match selected {
+            Some(name) => self.named_profile(&name).map(Some),
+            None if self.contains("default") => self.named_profile("default").map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn named_profile(&self, name: &str) -> PolarsResult<AwsProfile> {
+        polars_ensure!(
+            self.contains(name),
+            ComputeError: "AWS profile '{}' not found in the AWS config files", name
+        );
+        Ok(AwsProfile {
+            region: self.get(name, "region").map(|v| v.to_string()),
+            endpoint_url: self.get(name, "endpoint_url").map(|v| v.to_string()),
+            credentials: self.credentials(name, 0)?,
+        })
+    }
+
+    fn credentials(&self, name: &str, depth: usize) -> PolarsResult<Option<ProfileCredentials>> {
+        polars_ensure!(
+            depth < MAX_SOURCE_PROFILE_DEPTH,
+            ComputeError: "AWS profile '{}' has a cyclic 'source_profile' chain", name
+        );
+        let get = |key| self.get(name, key).map(|v| v.to_string());
+
+        // Like boto3, a role takes precedence over the keys of the same profile; those are only
+        // used if the profile is its own source.
+        if let Some(role_arn) = get("role_arn") {
+            polars_ensure!(
+                get("mfa_serial").is_none(),
+                ComputeError: "AWS profile '{}' requires an MFA token, which polars cannot prompt for; use `aws configure export-credentials --profile {} --format process` as `credential_process` instead",
+                name, name
+            );
+            let source = match (get("source_profile"), get("credential_source")) {
+                (Some(source), _) if source == name => self.base_credentials(name)?,
+                (Some(source), _) => {
+                    polars_ensure!(
+                        self.contains(&source),
+                        ComputeError: "source profile '{}' of AWS profile '{}' not found", source, name
+                    );
+                    self.credentials(&source, depth + 1)?
+                },
+                (None, Some(source)) if source == "Environment" => {
+                    Some(ProfileCredentials::Static(Arc::new(env_credentials()?)))
+                },
+                (None, Some(source)) => polars_bail!(
+                    ComputeError: "credential_source '{}' of AWS profile '{}' is not supported, only 'Environment' is", source, name
+                ),
+                // Web identity roles are resolved by object_store from the environment.
+                (None, None) => return Ok(None),
+            };
+            let source = source.ok_or_else(
+                || polars_err!(ComputeError: "source profile of AWS profile '{}' has no credentials", name),
+            )?;
+            let duration_seconds = get("duration_seconds")
+                .map(|v| {
+                    v.parse::<u64>().map_err(
+                        |_| polars_err!(ComputeError: "invalid duration_seconds in AWS profile '{}': {}", name, v),
+                    )
+                })
+                .transpose()?;
+
+            return Ok(Some(ProfileCredentials::AssumeRole {
+                role_arn,
+                session_name: get("role_session_name"),
+                external_id: get("external_id"),
+                duration_seconds,
+                region: get("region"),
+                source: Box::new(source),
+            }));
+        }
+        self.base_credentials(name)
+    }
+
+    /// The SSO, static or credential process credentials of a profile, in the order boto3 tries
+    /// them.
+    fn base_credentials(&self, name: &str) -> PolarsResult<Option<ProfileCredentials>> {
+        let get = |key| self.get(name, key).map(|v| v.to_string());
+        if let (Some(account_id), Some(role_name)) = (get("sso_account_id"), get("sso_role_name")) {
+            // The start url and region are either in the profile (legacy) or in the referenced
+            // `[sso-session name]` section.
+            let sso_get = |key| match &get("sso_session") {
+                Some(session) => self
+                    .sso_sessions
+                    .get(session)
+                    .and_then(|section| section.get(key))
+                    .cloned(),
+                None => get(key),
+            };
+            let (Some(start_url), Some(region)) = (sso_get("sso_start_url"), sso_get("sso_region"))
+            else {
+                polars_bail!(
+                    ComputeError: "AWS profile '{}' is missing 'sso_start_url' or 'sso_region'", name
+                );
+            };
+            return Ok(Some(ProfileCredentials::Sso {
+                start_url,
+                region,
+                account_id,
+                role_name,
+            }));
+        }
+        match (get("aws_access_key_id"), get("aws_secret_access_key")) {
+            (Some(key_id), Some(secret_key)) => {
+                return Ok(Some(ProfileCredentials::Static(Arc::new(AwsCredential {
+                    key_id,
+                    secret_key,
+                    token: get("aws_session_token"),
+                }))))
+            },
+            (Some(_), None) => polars_bail!(
+                ComputeError: "AWS profile '{}' has an 'aws_access_key_id' without 'aws_secret_access_key'", name
+            ),
+            _ => {},
+        }
+        Ok(get("credential_process").map(ProfileCredentials::Process))
+    }
+}
+
+fn env_credentials() -> PolarsResult<AwsCredential> {
+    match (
+        std::env::var("AWS_ACCESS_KEY_ID"),
+        std::env::var("AWS_SECRET_ACCESS_KEY"),
+    ) {
+        (Ok(key_id), Ok(secret_key)) => Ok(AwsCredential {
+            key_id,
+            secret_key,
+            token: std::env::var("AWS_SESSION_TOKEN").ok(),
+        }),
+        _ => polars_bail!(
+            ComputeError: "credential_source 'Environment' requires AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY"
+        ),
+    }
+}
+
+/// Output of a `credential_process`, see
+/// <https://docs.aws.amazon.com/cli/latest/userguide/cli-configure-sourcing-external.html>.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ProcessOutput {
+    version: u32,
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    expiration: Option<String>,
+}
+
+fn parse_expiration(expiration: &str) -> PolarsResult<SystemTime> {
+    chrono::DateTime::parse_from_rfc3339(expiration)
+        .map(SystemTime::from)
+        .map_err(
+            |e| polars_err!(ComputeError: "invalid credential expiration '{}': {}", expiration, e),
+        )
+}
+
+async fn run_credential_process(
+    command: &str,
+) -> PolarsResult<(AwsCredential, Option<SystemTime>)> {
+    let command = command.to_string();
+    let output = tokio::task::spawn_blocking(move || {
+        #[cfg(windows)]
+        let output = std::process::Command::new("cmd")
+            .args(["/C", &command])
+            .output();
+        #[cfg(not(windows))]
+        let output = std::process::Command::new("sh")
+            .args(["-c", &command])
+            .output();
+        output.map_err(
+            |e| polars_err!(ComputeError: "failed to run credential_process '{}': {}", command, e),
+        )
+    })
+    .await
+    .map_err(to_compute_err)??;
+
+    polars_ensure!(
+        output.status.success(),
+        ComputeError: "credential_process failed with {}: {}",
+        output.status, String::from_utf8_lossy(&output.stderr).trim()
+    );
+    let credentials: ProcessOutput =
+        serde_json::from_slice(&output.stdout).map_err(to_compute_err)?;
+    polars_ensure!(
+        credentials.version == 1,
+        ComputeError: "unsupported credential_process output version {}", credentials.version
+    );
+    let expiry = credentials
+        .expiration
+        .as_deref()
+        .map(parse_expiration)
+        .transpose()?;
+    Ok((
+        AwsCredential {
+            key_id: credentials.access_key_id,
+            secret_key: credentials.secret_access_key,
+            token: credentials.session_token,
+        },
+        expiry,
+    ))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SsoToken {
+    start_url: Option<String>,
+    access_token: Option<String>,
+    expires_at: Option<String>,
+}
+
+/// The access token for `start_url` that `aws sso login` cached in `cache_dir`, the files of
+/// which are named by a hash of the session name or start url. All of them are scanned for the
+/// start url instead.
+fn cached_sso_token(cache_dir: &Path, start_url: &str) -> PolarsResult<String> {
+    let not_logged_in = || {
+        polars_err!(
+            ComputeError: "no valid AWS SSO token found for '{}', run `aws sso login` to refresh it",
+            start_url
+        )
+    };
+    let now = SystemTime::now();
+    let mut best: Option<(SystemTime, String)> = None;
+    for entry in std::fs::read_dir(cache_dir).map_err(|_| not_logged_in())? {
+        let Ok(content) = std::fs::read_to_string(entry?.path()) else {
+            continue;
+        };
+        // The cache also holds client registrations, which have no access token.
+        let Ok(SsoToken {
+            start_url: Some(url),
+            access_token: Some(token),
+            expires_at: Some(expires_at),
+        }) = serde_json::from_str(&content)
+        else {
+            continue;
+        };
+        let Ok(expiry) = parse_expiration(&expires_at) else {
+            continue;
+        };
+        let newer = match &best {
+            Some((best_expiry, _)) => expiry > *best_expiry,
+            None => true,
+        };
+        if url == start_url && expiry > now && newer {
+            best = Some((expiry, token));
+        }
+    }
+    best.map(|(_, token)| token).ok_or_else(not_logged_in)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SsoRoleCredentials {
+    role_credentials: SsoRoleCredential,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SsoRoleCredential {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    /// Milliseconds since the Unix epoch.
+    expiration: u64,
+}
+
+/// The text of the first `<tag>` element of an STS response.
+fn xml_value<'a>(xml: &'a str, tag: &str) -> PolarsResult<&'a str> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    xml.find(&open)
+        .map(|start| &xml[start + open.len()..])
+        .and_then(|rest| rest.find(&close).map(|end| &rest[..end]))
+        .ok_or_else(|| polars_err!(ComputeError: "'{}' missing from the AssumeRole response", tag))
+}
+
+fn sts_endpoint(region: &str) -> String {
+    if region.starts_with("cn-") {
+        format!("https://sts.{region}.amazonaws.com.cn/")
+    } else {
+        format!("https://sts.{region}.amazonaws.com/")
+    }
+}
+
+/// Credential provider for the credentials of a profile that `object_store` cannot resolve by
+/// itself.
+#[derive(Debug)]
+pub(super) struct ProfileCredentialProvider {
+    credentials: ProfileCredentials,
+    client: reqwest::Client,
+    cache: Mutex<Option<(Arc<AwsCredential>, Option<SystemTime>)>>,
+}
+
+impl ProfileCredentialProvider {
+    pub(super) fn new(credentials: ProfileCredentials, client: reqwest::Client) -> Self {
+        Self {
+            credentials,
+            client,
+            cache: Default::default(),
+        }
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        credentials: &'a ProfileCredentials,
+    ) -> BoxFuture<'a, PolarsResult<(Arc<AwsCredential>, Option<SystemTime>)>> {
+        Box::pin(async move {
+            match credentials {
+                ProfileCredentials::Static(credential) => Ok((credential.clone(), None)),
+                ProfileCredentials::Process(command) => {
+                    let (credential, expiry) = run_credential_process(command).await?;
+                    Ok((Arc::new(credential), expiry))
+                },
+                ProfileCredentials::Sso {
+                    start_url,
+                    region,
+                    account_id,
+                    role_name,
+                } => {
+                    let cache_dir = resolve_homedir(Path::new("~/.aws/sso/cache"));
+                    let token = cached_sso_token(&cache_dir, start_url)?;
+                    let url = url::Url::parse_with_params(
+                        &format!(
+                            "https://portal.sso.{region}.amazonaws.com/federation/credentials"
+                        ),
+                        [("role_name", role_name), ("account_id", account_id)],
+                    )
+                    .map_err(to_compute_err)?;
+
+                    let response = self
+                        .client
+                        .get(url)
+                        .header("x-amz-sso_bearer_token", token)
+                        .send()
+                        .await
+                        .map_err(to_compute_err)?;
+                    let status = response.status();
+                    let body = response.bytes().await.map_err(to_compute_err)?;
+                    polars_ensure!(
+                        status.is_success(),
+                        ComputeError: "failed to get the AWS SSO credentials of role '{}' ({}): {}",
+                        role_name, status, String::from_utf8_lossy(&body)
+                    );
+                    let credential = serde_json::from_slice::<SsoRoleCredentials>(&body)
+                        .map_err(to_compute_err)?
+                        .role_credentials;
+
+                    let expiry =
+                        SystemTime::UNIX_EPOCH + Duration::from_millis(credential.expiration);
+                    let credential = AwsCredential {
+                        key_id: credential.access_key_id,
+                        secret_key: credential.secret_access_key,
+                        token: Some(credential.session_token),
+                    };
+                    Ok((Arc::new(credential), Some(expiry)))
+                },
+                ProfileCredentials::AssumeRole {
+                    role_arn,
+                    session_name,
+                    external_id,
+                    duration_seconds,
+                    region,
+                    source,
+                } => {
+                    let (source, _) = self.fetch(source).await?;
+                    let session_name = session_name.clone().unwrap_or_else(|| {
+                        let now = SystemTime::now()
+                            .duration_since(SystemTime::UNIX_EPOCH)
+                            .unwrap_or_default();
+                        format!("polars-{}", now.as_secs())
+                    });
+                    let region = region.as_deref().unwrap_or(DEFAULT_STS_REGION);
+
+                    let mut params = vec![
+                        ("Action", "AssumeRole".to_string()),
+                        ("Version", "2011-06-15".to_string()),
+                        ("RoleArn", role_arn.clone()),
+                        ("RoleSessionName", session_name),
+                    ];
+                    if let Some(external_id) = external_id {
+                        params.push(("ExternalId", external_id.clone()));
+                    }
+                    if let Some(duration_seconds) = duration_seconds {
+                        params.push(("DurationSeconds", duration_seconds.to_string()));
+                    }
+                    let url = url::Url::parse_with_params(&sts_endpoint(region), &params)
+                        .map_err(to_compute_err)?;
+
+                    let mut request = self.client.get(url).build().map_err(to_compute_err)?;
+                    AwsAuthorizer::new(&source, "sts", region).authorize(&mut request, None);
+                    let response = self.client.execute(request).await.map_err(to_compute_err)?;
+                    let status = response.status();
+                    let body = response.text().await.map_err(to_compute_err)?;
+                    polars_ensure!(
+                        status.is_success(),
+                        ComputeError: "failed to assume role '{}' ({}): {}", role_arn, status, body
+                    );
+
+                    let credential = AwsCredential {
+                        key_id: xml_value(&body, "AccessKeyId")?.to_string(),
+                        secret_key: xml_value(&body, "SecretAccessKey")?.to_string(),
+                        token: Some(xml_value(&body, "SessionToken")?.to_string()),
+                    };
+                    let expiry = parse_expiration(xml_value(&body, "Expiration")?)?;
+                    Ok((Arc::new(credential), Some(expiry)))
+                },
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialProvider for ProfileCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<AwsCredential>> {
+        let mut cache = self.cache.lock().await;
+        if let Some((credential, expiry)) = cache.as_ref() {
+            let valid = match expiry {
+                Some(expiry) => SystemTime::now() + EXPIRY_MARGIN < *expiry,
+                None => true,
+            };
+            if valid {
+                return Ok(credential.clone());
+            }
+        }
+
+        let (credential, expiry) =
+            self.fetch(&self.credentials)
+                .await
+                .map_err(|e: PolarsError| object_store::Error::Generic {
+                    store: "S3",
+                    source: Box::new(e),
+                })?;
+        *cache = Some((credential.clone(), expiry));
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use object_store::CredentialProvider;
+
+    use super::{cached_sso_token, AwsSharedConfig, ProfileCredentialProvider, ProfileCredentials};
+
+    const CONFIG: &str = "
+# comment
+[default]
+region = eu-west-1
+
+[profile dev]
+region=us-west-2
+endpoint_url = http://localhost:9000
+s3 =
+    max_concurrent_requests = 20
+credential_process = echo dev
+
+[profile admin]
+role_arn = arn:aws:iam::123456789012:role/admin
+source_profile = dev
+role_session_name = polars-test
+
+[profile loop-a]
+role_arn = arn:aws:iam::123456789012:role/a
+source_profile = loop-b
+
+[profile loop-b]
+role_arn = arn:aws:iam::123456789012:role/b
+source_profile = loop-a
+
+[profile sso-legacy]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = eu-west-1
+sso_account_id = 123456789012
+sso_role_name = ReadOnly
+
+[profile sso]
+sso_session = corp
+sso_account_id = 123456789012
+sso_role_name = Admin
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = us-east-1
+";
+
+    const CREDENTIALS: &str = "
+[default]
+aws_access_key_id = AKIDDEFAULT
+aws_secret_access_key = secret
+; comment
+[dev]
+aws_access_key_id = AKIDDEV
+aws_secret_access_key = dev-secret
+aws_session_token = dev-token
+";
+
+    #[test]
+    fn test_aws_profiles() {
+        let config = AwsSharedConfig::from_strings(CONFIG, CREDENTIALS);
+
+        let default = config.profile(Some("default")).unwrap().unwrap();
+        assert_eq!(default.region.as_deref(), Some("eu-west-1"));
+        let Some(ProfileCredentials::Static(credential)) = default.credentials else {
+            panic!("expected static credentials")
+        };
+        assert_eq!(credential.key_id, "AKIDDEFAULT");
+        assert_eq!(credential.token, None);
+
+        // Static keys take precedence over the credential process, nested properties are skipped.
+        let dev = config.profile(Some("dev")).unwrap().unwrap();
+        assert_eq!(dev.region.as_deref(), Some("us-west-2"));
+        assert_eq!(dev.endpoint_url.as_deref(), Some("http://localhost:9000"));
+        let Some(ProfileCredentials::Static(credential)) = dev.credentials else {
+            panic!("expected static credentials")
+        };
+        assert_eq!(credential.token.as_deref(), Some("dev-token"));
+
+        let admin = config.profile(Some("admin")).unwrap().unwrap();
+        let Some(ProfileCredentials::AssumeRole {
+            role_arn,
+            session_name,
+            source,
+            ..
+        }) = admin.credentials
+        else {
+            panic!("expected a role")
+        };
+        assert_eq!(role_arn, "arn:aws:iam::123456789012:role/admin");
+        assert_eq!(session_name.as_deref(), Some("polars-test"));
+        assert!(matches!(*source, ProfileCredentials::Static(_)));
+
+        let sso = config.profile(Some("sso")).unwrap().unwrap();
+        let Some(ProfileCredentials::Sso {
+            start_url,
+            region,
+            role_name,
+            ..
+        }) = sso.credentials
+        else {
+            panic!("expected SSO credentials")
+        };
+        assert_eq!(start_url, "https://corp.awsapps.com/start");
+        assert_eq!(region, "us-east-1");
+        assert_eq!(role_name, "Admin");
+        let sso_legacy = config.profile(Some("sso-legacy")).unwrap().unwrap();
+        assert!(matches!(
+            sso_legacy.credentials,
+            Some(ProfileCredentials::Sso { region, .. }) if region == "eu-west-1"
+        ));
+
+        assert!(config.profile(Some("loop-a")).is_err());
+        assert!(config.profile(Some("corp")).is_err());
+        assert!(config.profile(Some("missing")).is_err());
+        assert!(AwsSharedConfig::default().profile(Some("default")).is_err());
+    }
+
+    #[test]
+    fn test_cached_sso_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = "https://corp.awsapps.com/start";
+        for (file, content) in [
+            ("registration.json", r#"{"clientId": "id", "clientSecret": "secret"}"#.to_string()),
+            (
+                "expired.json",
+                format!(r#"{{"startUrl": "{url}", "accessToken": "old", "expiresAt": "2020-01-01T00:00:00Z"}}"#),
+            ),
+            (
+                "valid.json",
+                format!(r#"{{"startUrl": "{url}", "accessToken": "new", "expiresAt": "2100-01-01T00:00:00Z"}}"#),
+            ),
+            (
+                "other.json",
+                r#"{"startUrl": "https://other.awsapps.com/start", "accessToken": "other", "expiresAt": "2100-01-01T00:00:00Z"}"#.to_string(),
+            ),
+        ] {
+            std::fs::write(dir.path().join(file), content).unwrap();
+        }
+
+        assert_eq!(cached_sso_token(dir.path(), url).unwrap(), "new");
+        assert!(cached_sso_token(dir.path(), "https://missing.awsapps.com/start").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_aws_credential_process() {
+        let config = AwsSharedConfig::from_strings(
+            r#"
+[profile process]
+credential_process = echo '{"Version": 1, "AccessKeyId": "AKIDPROCESS", "SecretAccessKey": "secret", "SessionToken": "token", "Expiration": "2100-01-01T00:00:00Z"}'
+
+[profile failing]
+credential_process = echo 'no credentials' >&2; exit 1
+"#,
+            "",
+        );
+        let runtime = crate::pl_async::get_runtime();
+        let provider = |name| {
+            let credentials = config
+                .profile(Some(name))
+                .unwrap()
+                .unwrap()
+                .credentials
+                .unwrap();
+            ProfileCredentialProvider::new(credentials, reqwest::Client::new())
+        };
+
+        let credential = runtime
+            .block_on(provider("process").get_credential())
+            .unwrap();
+        assert_eq!(credential.key_id, "AKIDPROCESS");
+        assert_eq!(credential.token.as_deref(), Some("token"));
+
+        let err = runtime
+            .block_on(provider("failing").get_credential())
+            .unwrap_err();
+        assert!(err.to_string().contains("no credentials"));
+    }
+}
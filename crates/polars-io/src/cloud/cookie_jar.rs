@@ -0,0 +1,126 @@
+//! Cookies of a Netscape `cookies.txt` file, as written by curl and browser extensions.
+
+use std::time::SystemTime;
+
+use polars_error::{polars_err, PolarsResult};
+use url::Url;
+
+use crate::path_utils::resolve_homedir;
+
+struct Cookie<'a> {
+    domain: &'a str,
+    include_subdomains: bool,
+    path: &'a str,
+    secure: bool,
+    /// Unix timestamp, 0 for session cookies.
+    expires: u64,
+    name: &'a str,
+    value: &'a str,
+}
+
+impl<'a> Cookie<'a> {
+    fn parse(line: &'a str) -> Option<Self> {
+        // curl marks HttpOnly cookies with a prefix, other lines starting with `#` are comments.
+        let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+        if line.starts_with('#') || line.trim().is_empty() {
+            return None;
+        }
+        let fields = line.split('\t').collect::<Vec<_>>();
+        let [domain, include_subdomains, path, secure, expires, name, value] = fields[..] else {
+            return None;
+        };
+        Some(Self {
+            domain,
+            include_subdomains: include_subdomains.eq_ignore_ascii_case("TRUE"),
+            path,
+            secure: secure.eq_ignore_ascii_case("TRUE"),
+            expires: expires.trim().parse().ok()?,
+            name,
+            value: value.trim_end_matches(['\r', '\n']),
+        })
+    }
+
+    fn matches(&self, url: &Url, now: u64) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let domain = self.domain.trim_start_matches('.');
+        let domain_matches = host.eq_ignore_ascii_case(domain)
+            || (self.include_subdomains
+                && host.len() > domain.len()
+                && host[host.len() - domain.len()..].eq_ignore_ascii_case(domain)
+                && host.as_bytes()[host.len() - domain.len() - 1] == b'.');
+
+        let path = url.path();
+        let path_matches = path == self.path
+            || (path.starts_with(self.path)
+                && (self.path.ends_with('/') || path.as_bytes()[self.path.len()] == b'/'));
+
+        domain_matches
+            && path_matches
+            && (!self.secure || url.scheme() == "https")
+            && (self.expires == 0 || self.expires > now)
+    }
+}
+
+/// The `Cookie` header value with the cookies in `content` that would be sent to `url`, if any.
+fn cookie_header(content: &str, url: &Url) -> Option<String> {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let cookies = content
+        .lines()
+        .filter_map(Cookie::parse)
+        .filter(|cookie| cookie.matches(url, now))
+        .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+        .collect::<Vec<_>>();
+    (!cookies.is_empty()).then(|| cookies.join("; "))
+}
+
+/// Read the cookie jar at `path` and return the `Cookie` header for `url`.
+pub(super) fn read_cookie_header(path: &str, url: &str) -> PolarsResult<Option<String>> {
+    let content = std::fs::read_to_string(resolve_homedir(path.as_ref()))
+        .map_err(|e| polars_err!(ComputeError: "failed to read cookie jar '{}': {}", path, e))?;
+    let url =
+        Url::parse(url).map_err(|e| polars_err!(ComputeError: "invalid url '{}': {}", url, e))?;
+    Ok(cookie_header(&content, &url))
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::cookie_header;
+
+    #[test]
+    fn test_cookie_header() {
+        let jar = "# Netscape HTTP Cookie File
+.example.com\tTRUE\t/\tFALSE\t0\tsession\tabc
+#HttpOnly_data.example.com\tFALSE\t/exports\tTRUE\t4102444800\ttoken\tsecret
+data.example.com\tFALSE\t/exports\tFALSE\t1\texpired\tx
+other.com\tTRUE\t/\tFALSE\t0\tother\ty
+";
+        let header = |url: &str| cookie_header(jar, &Url::parse(url).unwrap());
+
+        assert_eq!(
+            header("https://data.example.com/exports/file.parquet").as_deref(),
+            Some("session=abc; token=secret")
+        );
+        // Secure cookies are only sent over https.
+        assert_eq!(
+            header("http://data.example.com/exports/file.parquet").as_deref(),
+            Some("session=abc")
+        );
+        // The path must match whole segments, the domain whole labels.
+        assert_eq!(
+            header("https://data.example.com/exports2/file.parquet").as_deref(),
+            Some("session=abc")
+        );
+        assert_eq!(header("https://notexample.com/file.parquet"), None);
+        assert_eq!(
+            header("https://sub.data.example.com/exports/file.parquet").as_deref(),
+            Some("session=abc")
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! User supplied credentials that are refreshed while a query runs.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+#[cfg(feature = "aws")]
+use object_store::aws::AwsCredential;
+#[cfg(feature = "azure")]
+use object_store::azure::{AzureAccessKey, AzureCredential};
+#[cfg(feature = "gcp")]
+use object_store::gcp::GcpCredential;
+#[cfg(feature = "azure")]
+use percent_encoding::percent_decode_str;
+#[cfg(feature = "azure")]
+use polars_error::to_compute_err;
+use polars_error::{polars_bail, PolarsError, PolarsResult};
+#[cfg(feature = "azure")]
+use polars_error::{polars_ensure, polars_err};
+use tokio::sync::Mutex;
+
+/// Credentials are fetched again this long before they expire.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(300);
+
+/// A source of short-lived credentials, e.g. Vault, AWS STS or an internal token service.
+///
+/// Attach a provider with [`CloudOptions::with_credential_provider`]. The object store asks the
+/// provider for new credentials whenever the previous ones are about to expire, so long running
+/// scans keep working after the first token has expired.
+///
+/// [`CloudOptions::with_credential_provider`]: super::CloudOptions::with_credential_provider
+#[async_trait]
+pub trait CredentialProvider: fmt::Debug + Send + Sync {
+    async fn get_credentials(&self) -> PolarsResult<Credentials>;
+}
+
+/// Credentials returned by a [`CredentialProvider`].
+#[derive(Clone)]
+pub struct Credentials {
+    secret: Secret,
+    expiry: Option<SystemTime>,
+}
+
+#[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
+enum Secret {
+    #[cfg(feature = "aws")]
+    Aws {
+        key_id: String,
+        secret_key: String,
+        token: Option<String>,
+    },
+    #[cfg(feature = "azure")]
+    AzureAccessKey(String),
+    #[cfg(feature = "azure")]
+    AzureBearer(String),
+    #[cfg(feature = "azure")]
+    AzureSas(Vec<(String, String)>),
+    #[cfg(feature = "gcp")]
+    GcpBearer(String),
+}
+
+impl Credentials {
+    /// AWS credentials, with a session token for temporary credentials.
+    #[cfg(feature = "aws")]
+    pub fn aws(
+        key_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        session_token: Option<String>,
+    ) -> Self {
+        Self::new(Secret::Aws {
+            key_id: key_id.into(),
+            secret_key: secret_key.into(),
+            token: session_token,
+        })
+    }
+
+    /// A (base64 encoded) Azure storage account access key.
+    #[cfg(feature = "azure")]
+    pub fn azure_access_key(access_key: impl Into<String>) -> Self {
+        Self::new(Secret::AzureAccessKey(access_key.into()))
+    }
+
+    /// An Azure (Entra ID) bearer token.
+    #[cfg(feature = "azure")]
+    pub fn azure_bearer(token: impl Into<String>) -> Self {
+        Self::new(Secret::AzureBearer(token.into()))
+    }
+
+    /// An Azure shared access signature (SAS) token, e.g. a user delegation SAS. The credentials
+    /// expire at the signed expiry (`se`) of the token, unless another expiry is set with
+    /// [`Credentials::with_expiry`], so a provider that generates SAS tokens is asked for a new
+    /// token before the previous one expires.
+    #[cfg(feature = "azure")]
+    pub fn azure_sas(token: &str) -> PolarsResult<Self> {
+        let token = percent_decode_str(token)
+            .decode_utf8()
+            .map_err(to_compute_err)?;
+        let pairs = token
+            .trim_start_matches('?')
+            .split('&')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| match pair.trim().split_once('=') {
+                Some((key, value)) => Ok((key.to_string(), value.to_string())),
+                None => polars_bail!(ComputeError: "invalid azure SAS token component '{}'", pair),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            pairs.iter().any(|(key, _)| key == "sig"),
+            ComputeError: "azure SAS token has no signature ('sig')"
+        );
+        let expiry = pairs
+            .iter()
+            .find(|(key, _)| key == "se")
+            .map(|(_, value)| parse_sas_time(value))
+            .transpose()?;
+        Ok(Self {
+            secret: Secret::AzureSas(pairs),
+            expiry,
+        })
+    }
+
+    /// A GCP OAuth2 access token.
+    #[cfg(feature = "gcp")]
+    pub fn gcp_bearer(token: impl Into<String>) -> Self {
+        Self::new(Secret::GcpBearer(token.into()))
+    }
+
+    /// Set the moment these credentials expire. Credentials without expiry are never refreshed.
+    pub fn with_expiry(mut self, expiry: SystemTime) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    pub fn expiry(&self) -> Option<SystemTime> {
+        self.expiry
+    }
+
+    fn new(secret: Secret) -> Self {
+        Self {
+            secret,
+            expiry: None,
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the secret into logs.
+        f.debug_struct("Credentials")
+            .field("expiry", &self.expiry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parse a time of a SAS token, which is an ISO 8601 UTC date or time, with optional seconds.
+#[cfg(feature = "azure")]
+fn parse_sas_time(value: &str) -> PolarsResult<SystemTime> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+    let time = DateTime::parse_from_rfc3339(value)
+        .map(|time| time.to_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%MZ").map(|t| t.and_utc()))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+        })
+        .map_err(|_| polars_err!(ComputeError: "invalid azure SAS token time '{}'", value))?;
+    Ok(time.into())
+}
+
+/// Conversion into the credential type of an `object_store` implementation.
+pub(super) trait FromCredentials: Sized {
+    const STORE: &'static str;
+
+    fn from_credentials(credentials: Credentials) -> PolarsResult<Self>;
+}
+
+fn credential_mismatch<T>(store: &str) -> PolarsResult<T> {
+    polars_bail!(ComputeError: "credential provider returned credentials that cannot be used for {}", store)
+}
+
+#[cfg(feature = "aws")]
+impl FromCredentials for AwsCredential {
+    const STORE: &'static str = "S3";
+
+    fn from_credentials(credentials: Credentials) -> PolarsResult<Self> {
+        match credentials.secret {
+            Secret::Aws {
+                key_id,
+                secret_key,
+                token,
+            } => Ok(AwsCredential {
+                key_id,
+                secret_key,
+                token,
+            }),
+            #[allow(unreachable_patterns)]
+            _ => credential_mismatch(Self::STORE),
+        }
+    }
+}
+
+#[cfg(feature = "azure")]
+impl FromCredentials for AzureCredential {
+    const STORE: &'static str = "MicrosoftAzure";
+
+    fn from_credentials(credentials: Credentials) -> PolarsResult<Self> {
+        match credentials.secret {
+            Secret::AzureAccessKey(key) => Ok(AzureCredential::AccessKey(
+                AzureAccessKey::try_new(&key).map_err(to_compute_err)?,
+            )),
+            Secret::AzureBearer(token) => Ok(AzureCredential::BearerToken(token)),
+            Secret::AzureSas(pairs) => Ok(AzureCredential::SASToken(pairs)),
+            #[allow(unreachable_patterns)]
+            _ => credential_mismatch(Self::STORE),
+        }
+    }
+}
+
+#[cfg(feature = "gcp")]
+impl FromCredentials for GcpCredential {
+    const STORE: &'static str = "GCS";
+
+    fn from_credentials(credentials: Credentials) -> PolarsResult<Self> {
+        match credentials.secret {
+            Secret::GcpBearer(bearer) => Ok(GcpCredential { bearer }),
+            #[allow(unreachable_patterns)]
+            _ => credential_mismatch(Self::STORE),
+        }
+    }
+}
+
+/// A [`CredentialProvider`] attached to `CloudOptions`. Providers are compared by identity, so
+/// clones of the same options share the cached object store.
+#[derive(Clone, Debug)]
+pub(crate) struct SharedCredentialProvider(pub(crate) Arc<dyn CredentialProvider>);
+
+impl SharedCredentialProvider {
+    fn addr(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+
+    /// Key that distinguishes object stores built with different providers.
+    pub(super) fn cache_key(&self) -> String {
+        format!("{:x}", self.addr())
+    }
+
+    /// Wrap the provider for use as the credential provider of an object store builder.
+    pub(super) fn to_object_store<T>(&self) -> Arc<ObjectStoreCredentialProvider<T>> {
+        Arc::new(ObjectStoreCredentialProvider {
+            provider: self.0.clone(),
+            cache: Default::default(),
+        })
+    }
+}
+
+impl PartialEq for SharedCredentialProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl Eq for SharedCredentialProvider {}
+
+impl Hash for SharedCredentialProvider {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state)
+    }
+}
+
+/// Adapts a [`CredentialProvider`] to `object_store`, caching the credentials until they are
+/// about to expire.
+#[derive(Debug)]
+pub(super) struct ObjectStoreCredentialProvider<T> {
+    provider: Arc<dyn CredentialProvider>,
+    cache: Mutex<Option<(Arc<T>, Option<SystemTime>)>>,
+}
+
+#[async_trait]
+impl<T> object_store::CredentialProvider for ObjectStoreCredentialProvider<T>
+where
+    T: FromCredentials + fmt::Debug + Send + Sync + 'static,
+{
+    type Credential = T;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<T>> {
+        let mut cache = self.cache.lock().await;
+        if let Some((credential, expiry)) = cache.as_ref() {
+            let expired = expiry.is_some_and(|expiry| SystemTime::now() + EXPIRY_MARGIN >= expiry);
+            if !expired {
+                return Ok(credential.clone());
+            }
+        }
+
+        let credentials = self
+            .provider
+            .get_credentials()
+            .await
+            .and_then(|credentials| {
+                let expiry = credentials.expiry;
+                Ok((Arc::new(T::from_credentials(credentials)?), expiry))
+            })
+            .map_err(|e: PolarsError| object_store::Error::Generic {
+                store: T::STORE,
+                source: Box::new(e),
+            })?;
+        let credential = credentials.0.clone();
+        *cache = Some(credentials);
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "aws", feature = "azure"))]
+mod tests {
+    #[cfg(feature = "aws")]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[cfg(feature = "aws")]
+    use object_store::CredentialProvider as _;
+
+    use super::*;
+
+    #[cfg(feature = "aws")]
+    #[derive(Debug)]
+    struct CountingProvider {
+        calls: AtomicUsize,
+        lifetime: Duration,
+    }
+
+    #[cfg(feature = "aws")]
+    #[async_trait]
+    impl CredentialProvider for CountingProvider {
+        async fn get_credentials(&self) -> PolarsResult<Credentials> {
+            let call = self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok(Credentials::aws("key", format!("secret-{call}"), None)
+                .with_expiry(SystemTime::now() + self.lifetime))
+        }
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_credentials_are_refreshed_before_expiry() {
+        for (lifetime, expected_calls) in [(Duration::from_secs(3600), 1), (EXPIRY_MARGIN, 2)] {
+            let provider = Arc::new(CountingProvider {
+                calls: AtomicUsize::new(0),
+                lifetime,
+            });
+            let shared = SharedCredentialProvider(provider.clone());
+            let adapter = shared.to_object_store::<AwsCredential>();
+
+            let secrets = crate::pl_async::get_runtime().block_on(async {
+                let first = adapter.get_credential().await.unwrap();
+                let second = adapter.get_credential().await.unwrap();
+                (first.secret_key.clone(), second.secret_key.clone())
+            });
+            assert_eq!(provider.calls.load(Ordering::Relaxed), expected_calls);
+            assert_eq!(secrets.0 == secrets.1, expected_calls == 1);
+        }
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn test_azure_sas_credentials() {
+        let credentials = Credentials::azure_sas(
+            "?sv=2022-11-02&se=2024-01-02T03%3A04%3A05Z&skoid=abc&sr=c&sp=rl&sig=a%2Bb%3D",
+        )
+        .unwrap();
+        let expiry = chrono::DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z").unwrap();
+        assert_eq!(credentials.expiry(), Some(expiry.into()));
+        let AzureCredential::SASToken(pairs) =
+            AzureCredential::from_credentials(credentials).unwrap()
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            pairs.last().unwrap(),
+            &("sig".to_string(), "a+b=".to_string())
+        );
+
+        let credentials = Credentials::azure_sas("sv=2022-11-02&se=2024-01-02&sig=x").unwrap();
+        let expiry = chrono::DateTime::parse_from_rfc3339("2024-01-02T00:00:00Z").unwrap();
+        assert_eq!(credentials.expiry(), Some(expiry.into()));
+
+        assert!(Credentials::azure_sas("sv=2022-11-02&se=2024-01-02").is_err());
+        assert!(Credentials::azure_sas("se=tomorrow&sig=x").is_err());
+    }
+}
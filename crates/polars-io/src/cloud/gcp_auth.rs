@@ -0,0 +1,351 @@
+//! Google Cloud credentials that `object_store` cannot resolve by itself: external account
+//! credentials (workload identity federation) and service account impersonation.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use object_store::gcp::{GcpCredential, GcpCredentialProvider};
+use object_store::CredentialProvider;
+use polars_error::{polars_bail, polars_err, to_compute_err, PolarsError, PolarsResult};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+const CLOUD_PLATFORM_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+const TOKEN_EXCHANGE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+/// Lifetime requested for impersonated access tokens, unless configured otherwise.
+const DEFAULT_IMPERSONATION_LIFETIME: Duration = Duration::from_secs(3600);
+/// Tokens are refreshed this long before they expire.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(300);
+
+/// An `external_account` credential configuration, as produced by
+/// `gcloud iam workload-identity-pools create-cred-config`.
+#[derive(Debug, Deserialize)]
+pub(super) struct ExternalAccount {
+    audience: String,
+    subject_token_type: String,
+    token_url: String,
+    credential_source: CredentialSource,
+    service_account_impersonation_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CredentialSource {
+    file: Option<String>,
+    url: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    format: Option<CredentialSourceFormat>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CredentialSourceFormat {
+    #[serde(rename = "type")]
+    type_: String,
+    subject_token_field_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StsTokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateAccessTokenResponse {
+    access_token: String,
+}
+
+impl ExternalAccount {
+    /// Parse `json` if it holds an `external_account` credential configuration.
+    pub(super) fn try_from_json(json: &str) -> PolarsResult<Option<Self>> {
+        #[derive(Deserialize)]
+        struct CredentialType {
+            #[serde(rename = "type")]
+            type_: Option<String>,
+        }
+
+        let Ok(CredentialType { type_ }) = serde_json::from_str::<CredentialType>(json) else {
+            // Not JSON, e.g. a path; leave it to object_store.
+            return Ok(None);
+        };
+        if type_.as_deref() != Some("external_account") {
+            return Ok(None);
+        }
+        serde_json::from_str(json).map(Some).map_err(to_compute_err)
+    }
+
+    /// Like [`ExternalAccount::try_from_json`], but reads the configuration from a file.
+    pub(super) fn try_from_file(path: &str) -> PolarsResult<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Self::try_from_json(&json),
+            // Missing files are reported by object_store.
+            Err(_) => Ok(None),
+        }
+    }
+
+    async fn subject_token(&self, client: &reqwest::Client) -> PolarsResult<String> {
+        let source = &self.credential_source;
+        let raw = if let Some(path) = &source.file {
+            tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| polars_err!(ComputeError: "failed to read subject token file '{}': {}", path, e))?
+        } else if let Some(url) = &source.url {
+            let mut request = client.get(url);
+            for (k, v) in source.headers.iter() {
+                request = request.header(k, v);
+            }
+            let response = request.send().await.map_err(to_compute_err)?;
+            let response = response.error_for_status().map_err(to_compute_err)?;
+            response.text().await.map_err(to_compute_err)?
+        } else {
+            polars_bail!(
+                ComputeError: "only file and url credential sources are supported for external account credentials"
+            )
+        };
+
+        match &source.format {
+            Some(CredentialSourceFormat {
+                type_,
+                subject_token_field_name: Some(field),
+            }) if type_ == "json" => {
+                let value: serde_json::Value =
+                    serde_json::from_str(&raw).map_err(to_compute_err)?;
+                value
+                    .get(field)
+                    .and_then(|v| v.as_str())
+                    .map(|v| v.to_string())
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "subject token field '{}' not found", field),
+                    )
+            },
+            _ => Ok(raw.trim().to_string()),
+        }
+    }
+
+    /// Exchange the subject token for a federated access token at the security token service.
+    async fn exchange(&self, client: &reqwest::Client) -> PolarsResult<(String, Instant)> {
+        let subject_token = self.subject_token(client).await?;
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", TOKEN_EXCHANGE_GRANT_TYPE)
+            .append_pair("audience", &self.audience)
+            .append_pair("scope", CLOUD_PLATFORM_SCOPE)
+            .append_pair("requested_token_type", ACCESS_TOKEN_TYPE)
+            .append_pair("subject_token", &subject_token)
+            .append_pair("subject_token_type", &self.subject_token_type)
+            .finish();
+
+        let requested_at = Instant::now();
+        let response = client
+            .post(&self.token_url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await
+            .map_err(to_compute_err)?;
+        let response = response.error_for_status().map_err(to_compute_err)?;
+        let bytes = response.bytes().await.map_err(to_compute_err)?;
+        let token: StsTokenResponse = serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+
+        let lifetime = Duration::from_secs(token.expires_in.unwrap_or(3600));
+        Ok((token.access_token, requested_at + lifetime))
+    }
+}
+
+#[derive(Debug)]
+enum TokenSource {
+    ExternalAccount(ExternalAccount),
+    /// Credentials resolved by object_store, e.g. a service account key or the metadata server.
+    Provider(GcpCredentialProvider),
+}
+
+/// Credential provider that obtains (and refreshes) tokens for the sources object_store
+/// doesn't support.
+#[derive(Debug)]
+pub(super) struct GcpTokenProvider {
+    source: TokenSource,
+    impersonation_url: Option<String>,
+    impersonation_lifetime: Duration,
+    client: reqwest::Client,
+    cache: Mutex<Option<(Arc<GcpCredential>, Instant)>>,
+}
+
+impl GcpTokenProvider {
+    pub(super) fn new(
+        external_account: Option<ExternalAccount>,
+        fallback: impl FnOnce() -> PolarsResult<GcpCredentialProvider>,
+        impersonate_service_account: Option<&str>,
+        impersonation_lifetime: Option<Duration>,
+        client: reqwest::Client,
+    ) -> PolarsResult<Self> {
+        let impersonation_url = match impersonate_service_account {
+            Some(service_account) => Some(format!(
+                "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/{service_account}:generateAccessToken"
+            )),
+            None => external_account
+                .as_ref()
+                .and_then(|account| account.service_account_impersonation_url.clone()),
+        };
+        let source = match external_account {
+            Some(account) => TokenSource::ExternalAccount(account),
+            None => TokenSource::Provider(fallback()?),
+        };
+
+        Ok(Self {
+            source,
+            impersonation_url,
+            impersonation_lifetime: impersonation_lifetime
+                .unwrap_or(DEFAULT_IMPERSONATION_LIFETIME),
+            client,
+            cache: Default::default(),
+        })
+    }
+
+    async fn source_token(&self) -> PolarsResult<(String, Option<Instant>)> {
+        match &self.source {
+            TokenSource::ExternalAccount(account) => account
+                .exchange(&self.client)
+                .await
+                .map(|(token, expiry)| (token, Some(expiry))),
+            TokenSource::Provider(provider) => {
+                let credential = provider.get_credential().await?;
+                Ok((credential.bearer.clone(), None))
+            },
+        }
+    }
+
+    /// Trade `token` for an access token of the impersonated service account.
+    async fn impersonate(&self, url: &str, token: &str) -> PolarsResult<(String, Instant)> {
+        let body = serde_json::json!({
+            "scope": [CLOUD_PLATFORM_SCOPE],
+            "lifetime": format!("{}s", self.impersonation_lifetime.as_secs()),
+        });
+
+        let requested_at = Instant::now();
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(token)
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .map_err(to_compute_err)?;
+        let response = response.error_for_status().map_err(to_compute_err)?;
+        let bytes = response.bytes().await.map_err(to_compute_err)?;
+        let token: GenerateAccessTokenResponse =
+            serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+
+        Ok((
+            token.access_token,
+            requested_at + self.impersonation_lifetime,
+        ))
+    }
+
+    async fn fetch_token(&self) -> PolarsResult<(String, Instant)> {
+        let (token, expiry) = self.source_token().await?;
+        match &self.impersonation_url {
+            Some(url) => self.impersonate(url, &token).await,
+            // Tokens of object_store providers are refreshed by the provider itself.
+            None => Ok((token, expiry.unwrap_or_else(Instant::now))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialProvider for GcpTokenProvider {
+    type Credential = GcpCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<GcpCredential>> {
+        let mut cache = self.cache.lock().await;
+        if let Some((credential, expiry)) = cache.as_ref() {
+            if Instant::now() + EXPIRY_MARGIN < *expiry {
+                return Ok(credential.clone());
+            }
+        }
+
+        let (bearer, expiry) =
+            self.fetch_token()
+                .await
+                .map_err(|e: PolarsError| object_store::Error::Generic {
+                    store: "GCS",
+                    source: Box::new(e),
+                })?;
+        let credential = Arc::new(GcpCredential { bearer });
+        *cache = Some((credential.clone(), expiry));
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use object_store::CredentialProvider;
+
+    use super::{ExternalAccount, GcpTokenProvider};
+    use crate::cloud::CloudOptions;
+
+    #[test]
+    fn test_parse_external_account() {
+        let json = r#"{
+            "type": "external_account",
+            "audience": "//iam.googleapis.com/projects/1/locations/global/workloadIdentityPools/pool/providers/aks",
+            "subject_token_type": "urn:ietf:params:oauth:token-type:jwt",
+            "token_url": "https://sts.googleapis.com/v1/token",
+            "credential_source": {"file": "/var/run/secrets/tokens/gcp-ksa/token"},
+            "service_account_impersonation_url": "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/sa@p.iam.gserviceaccount.com:generateAccessToken"
+        }"#;
+        let account = ExternalAccount::try_from_json(json).unwrap().unwrap();
+        assert_eq!(
+            account.credential_source.file.as_deref(),
+            Some("/var/run/secrets/tokens/gcp-ksa/token")
+        );
+        assert!(account.service_account_impersonation_url.is_some());
+
+        let json = r#"{"type": "service_account", "client_email": "sa@p.iam.gserviceaccount.com"}"#;
+        assert!(ExternalAccount::try_from_json(json).unwrap().is_none());
+        assert!(ExternalAccount::try_from_json("/path/to/key.json")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_token_request_timeout() {
+        // A token endpoint that accepts the connection but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let mut connections = vec![];
+            for connection in listener.incoming() {
+                connections.push(connection);
+            }
+        });
+
+        let json = format!(
+            r#"{{
+                "type": "external_account",
+                "audience": "//iam.googleapis.com/projects/1/locations/global/workloadIdentityPools/pool/providers/p",
+                "subject_token_type": "urn:ietf:params:oauth:token-type:jwt",
+                "token_url": "http://127.0.0.1:{port}/token",
+                "credential_source": {{"url": "http://127.0.0.1:{port}/subject"}}
+            }}"#
+        );
+        let account = ExternalAccount::try_from_json(&json).unwrap().unwrap();
+        let options = CloudOptions::default().with_timeouts(
+            Some(Duration::from_secs(1)),
+            Some(Duration::from_millis(200)),
+            None,
+        );
+        let client = options.reqwest_client_builder().unwrap().build().unwrap();
+        let provider =
+            GcpTokenProvider::new(Some(account), || unreachable!(), None, None, client).unwrap();
+
+        let start = std::time::Instant::now();
+        let result = crate::pl_async::get_runtime().block_on(provider.get_credential());
+        assert!(result.is_err());
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,187 @@
+//! Server-side concatenation of Google Cloud Storage objects with the compose API.
+
+use futures::future::try_join_all;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use polars_error::{polars_bail, polars_ensure, to_compute_err, PolarsResult};
+use url::Url;
+
+use super::{parse_url, CloudOptions};
+
+/// The maximum number of sources of a single compose request.
+const MAX_COMPOSE_SOURCES: usize = 32;
+
+/// The bucket and object name of a `gs://` url.
+fn bucket_and_name(url: &str) -> PolarsResult<(String, String)> {
+    let parsed = parse_url(url).map_err(to_compute_err)?;
+    polars_ensure!(
+        matches!(parsed.scheme(), "gs" | "gcs" | "gcp"),
+        ComputeError: "composing objects is only supported for GCS, got '{}'", url
+    );
+    let bucket = parsed.host_str().unwrap_or_default().to_string();
+    let name = percent_encoding::percent_decode_str(parsed.path().trim_start_matches('/'))
+        .decode_utf8()
+        .map_err(to_compute_err)?
+        .into_owned();
+    polars_ensure!(
+        !bucket.is_empty() && !name.is_empty(),
+        ComputeError: "expected a url of an object, got '{}'", url
+    );
+    Ok((bucket, name))
+}
+
+/// The names of the objects of the next round of composition: sources are combined in groups
+/// of [`MAX_COMPOSE_SOURCES`] into intermediate objects next to the destination, until a single
+/// request can compose the destination.
+fn compose_round(destination: &str, sources: &[String], round: usize) -> Vec<String> {
+    (0..sources.len().div_ceil(MAX_COMPOSE_SOURCES))
+        .map(|i| format!("{destination}.compose-{round}-{i:05}"))
+        .collect()
+}
+
+/// Concatenate the objects at `sources` into a new object at `destination`, in order, without
+/// downloading them. All objects must be in the same bucket. With `delete_sources`, the sources
+/// are deleted after the destination is written.
+///
+/// The bytes are concatenated as is, so this is only meaningful for formats whose parts can be
+/// appended, like csv files written without a header or ndjson, not for parquet or ipc files.
+pub async fn compose_gcs_objects(
+    destination: &str,
+    sources: &[String],
+    cloud_options: Option<&CloudOptions>,
+    delete_sources: bool,
+) -> PolarsResult<()> {
+    polars_ensure!(!sources.is_empty(), ComputeError: "no objects to compose into '{}'", destination);
+    let (bucket, destination_name) = bucket_and_name(destination)?;
+    let mut source_names = Vec::with_capacity(sources.len());
+    for source in sources {
+        let (source_bucket, name) = bucket_and_name(source)?;
+        polars_ensure!(
+            source_bucket == bucket,
+            ComputeError: "composed objects must be in the bucket of the destination '{}', got '{}'",
+            destination, source
+        );
+        source_names.push(name);
+    }
+
+    let default_options = CloudOptions::default();
+    let options = cloud_options.map_or(&default_options, |options| options.for_url(destination));
+    #[cfg(feature = "gcp_hmac")]
+    polars_ensure!(
+        options.gcp_hmac_key.is_none(),
+        ComputeError: "composing objects is not supported with a GCS HMAC key"
+    );
+    let store = options
+        .gcp_builder(destination)?
+        .build()
+        .map_err(to_compute_err)?;
+    let client = options
+        .reqwest_client_builder()?
+        .build()
+        .map_err(to_compute_err)?;
+
+    let compose = |names: Vec<String>, target: String| {
+        let client = &client;
+        let store = &store;
+        let bucket = &bucket;
+        async move {
+            let credential = store
+                .credentials()
+                .get_credential()
+                .await
+                .map_err(to_compute_err)?;
+            let mut url = Url::parse("https://storage.googleapis.com/storage/v1/b/").unwrap();
+            url.path_segments_mut().unwrap().pop_if_empty().extend([
+                bucket.as_str(),
+                "o",
+                target.as_str(),
+                "compose",
+            ]);
+            let body = serde_json::json!({
+                "sourceObjects": names.iter().map(|name| serde_json::json!({ "name": name })).collect::<Vec<_>>(),
+                "destination": {},
+            });
+            let response = client
+                .post(url)
+                .bearer_auth(&credential.bearer)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string())
+                .send()
+                .await
+                .map_err(to_compute_err)?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                polars_bail!(ComputeError: "failed to compose 'gs://{}/{}': {}: {}", bucket, target, status, text);
+            }
+            PolarsResult::Ok(())
+        }
+    };
+
+    let mut names = source_names.clone();
+    let mut intermediates = vec![];
+    let mut round = 0;
+    while names.len() > MAX_COMPOSE_SOURCES {
+        let targets = compose_round(&destination_name, &names, round);
+        try_join_all(
+            names
+                .chunks(MAX_COMPOSE_SOURCES)
+                .zip(&targets)
+                .map(|(chunk, target)| compose(chunk.to_vec(), target.clone())),
+        )
+        .await?;
+        intermediates.extend(targets.iter().cloned());
+        names = targets;
+        round += 1;
+    }
+    compose(names, destination_name).await?;
+
+    let mut to_delete = intermediates;
+    if delete_sources {
+        to_delete.extend(source_names);
+    }
+    try_join_all(
+        to_delete
+            .iter()
+            .map(|name| async { store.delete(&Path::from(name.as_str())).await }),
+    )
+    .await
+    .map_err(to_compute_err)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_round() {
+        assert_eq!(
+            bucket_and_name("gs://bucket/dir/part 1.csv").unwrap(),
+            ("bucket".to_string(), "dir/part 1.csv".to_string())
+        );
+        assert!(bucket_and_name("s3://bucket/file.csv").is_err());
+        assert!(bucket_and_name("gs://bucket/").is_err());
+
+        let sources = (0..70).map(|i| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            compose_round("out.csv", &sources, 0),
+            [
+                "out.csv.compose-0-00000",
+                "out.csv.compose-0-00001",
+                "out.csv.compose-0-00002"
+            ]
+        );
+        assert_eq!(compose_round("out.csv", &sources[..32], 1).len(), 1);
+
+        let err = crate::pl_async::get_runtime()
+            .block_on(compose_gcs_objects(
+                "gs://a/out.csv",
+                &["gs://b/part.csv".to_string()],
+                None,
+                false,
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("must be in the bucket"));
+    }
+}
@@ -1,73 +1,208 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
 use futures::TryStreamExt;
 use object_store::path::Path;
+use object_store::ObjectStore;
+use once_cell::sync::Lazy;
 use polars_core::error::to_compute_err;
-use polars_core::prelude::{polars_ensure, polars_err};
+use polars_core::prelude::{polars_bail, polars_ensure, polars_err};
 use polars_error::PolarsResult;
+use polars_utils::aliases::PlHashMap;
 use regex::Regex;
 use url::Url;
 
+use super::metrics::{record_cache_lookup, record_request, IoCache, IoRequestKind};
+use super::object_store_setup::url_and_creds_to_key;
 use super::{parse_url, CloudOptions};
 
 const DELIMITER: char = '/';
 
+/// Object store key, prefix and whether the listing is recursive.
+type ListingKey = (String, String, bool);
+
+/// Listings of globbed prefixes. Only used for the options that set a
+/// [`CloudOptions::listing_cache_ttl`].
+static LISTING_CACHE: Lazy<Mutex<PlHashMap<ListingKey, CachedListing>>> =
+    Lazy::new(Default::default);
+/// Expired listings are dropped once the cache holds this many.
+const LISTING_CACHE_SIZE: usize = 64;
+
+struct CachedListing {
+    scheme: String,
+    bucket: String,
+    prefix: String,
+    expires_at: Instant,
+    /// The non-empty objects under the prefix.
+    locations: Arc<Vec<Path>>,
+}
+
+/// List the non-empty objects under `prefix`, only in its directory unless `recursive` is set.
+/// Directories can only be listed on their own on hierarchical storage, other stores always
+/// return all objects under the prefix.
+pub(crate) async fn list_non_empty(
+    store: &dyn ObjectStore,
+    url: &str,
+    cloud_options: Option<&CloudOptions>,
+    prefix: &str,
+    #[cfg_attr(not(feature = "azure"), allow(unused_variables))] recursive: bool,
+) -> PolarsResult<Vec<Path>> {
+    let start = Instant::now();
+    let result = async {
+        #[cfg(feature = "azure")]
+        if let Some(locations) =
+            super::adls::list_files(url, cloud_options, prefix, recursive).await?
+        {
+            return Ok(locations);
+        }
+        #[cfg(not(feature = "azure"))]
+        let _ = (url, cloud_options);
+
+        store
+            .list(Some(&Path::from(prefix)))
+            .try_filter_map(|x| async move { Ok((x.size > 0).then_some(x.location)) })
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(to_compute_err)
+    }
+    .await;
+    record_request(IoRequestKind::List, start, 0, result.is_ok());
+    result
+}
+
+/// Remove all cached listings, see [`CloudOptions::listing_cache_ttl`].
+pub fn clear_listing_cache() {
+    LISTING_CACHE.lock().unwrap().clear();
+}
+
+/// Remove the cached listings that contain objects under `url`, e.g. after writing to it. A
+/// glob pattern in `url` is ignored, so `s3://bucket/data/*.parquet` invalidates the listings
+/// of `s3://bucket/data/` and all prefixes above and below it.
+pub fn invalidate_listing_cache(url: &str) -> PolarsResult<()> {
+    let CloudLocation {
+        scheme,
+        bucket,
+        prefix,
+        ..
+    } = CloudLocation::new(url, true)?;
+    LISTING_CACHE.lock().unwrap().retain(|_, listing| {
+        listing.scheme != scheme
+            || listing.bucket != bucket
+            || !(listing.prefix.starts_with(&prefix) || prefix.starts_with(&listing.prefix))
+    });
+    Ok(())
+}
+
+/// Whether a path component contains glob syntax.
+fn is_glob_component(component: &str) -> bool {
+    component
+        .bytes()
+        .any(|b| matches!(b, b'*' | b'?' | b'[' | b'{'))
+}
+
+/// Translate the glob syntax of a single path component to a regular expression:
+/// `*`, `?`, `[...]`, `[!...]` and `{a,b}`, where the alternatives may contain globs themselves.
+fn component_to_regex(component: &str, url: &str) -> PolarsResult<String> {
+    let mut out = String::with_capacity(component.len());
+    let mut brace_depth = 0usize;
+    let mut rest = component;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '*' => out.push_str("([^/]*)"),
+            '?' => out.push_str("[^/]"),
+            '[' => {
+                let negated = rest.starts_with('!');
+                let set = &rest[usize::from(negated)..];
+                // A ']' directly after the opening bracket is part of the set.
+                let Some(end) = set
+                    .char_indices()
+                    .skip(1)
+                    .find_map(|(i, c)| (c == ']').then_some(i))
+                else {
+                    polars_bail!(ComputeError: "unclosed '[' in glob pattern: {}", url);
+                };
+                out.push('[');
+                if negated {
+                    out.push('^');
+                }
+                for c in set[..end].chars() {
+                    if c == '-' {
+                        out.push(c);
+                    } else {
+                        out.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+                    }
+                }
+                out.push(']');
+                rest = &set[end + 1..];
+            },
+            '{' => {
+                brace_depth += 1;
+                out.push('(');
+            },
+            ',' if brace_depth > 0 => out.push('|'),
+            '}' if brace_depth > 0 => {
+                brace_depth -= 1;
+                out.push(')');
+            },
+            c => out.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    polars_ensure!(
+        brace_depth == 0,
+        ComputeError: "expected '{{' and '}}' in the same path component, got {}", url
+    );
+    Ok(out)
+}
+
 /// Split the url in
-/// 1. the prefix part (all path components until the first one with '*')
+/// 1. the prefix part (all path components until the first one with glob syntax)
 /// 2. a regular expression representation of the rest.
+///
+/// `**` matches any number of path components, including none.
 pub(crate) fn extract_prefix_expansion(url: &str) -> PolarsResult<(String, Option<String>)> {
-    let splits = url.split(DELIMITER);
+    let mut splits = url.split(DELIMITER).peekable();
     let mut prefix = String::new();
-    let mut expansion = String::new();
-    let mut last_split_was_wildcard = false;
-    for split in splits {
-        if expansion.is_empty() && memchr::memchr2(b'*', b'[', split.as_bytes()).is_none() {
-            // We are still gathering splits in the prefix.
-            if !prefix.is_empty() {
-                prefix.push(DELIMITER);
-            }
-            prefix.push_str(split);
-            continue;
+    while let Some(split) = splits.next_if(|split| !is_glob_component(split)) {
+        if !prefix.is_empty() {
+            prefix.push(DELIMITER);
+        }
+        prefix.push_str(split);
+    }
+    if splits.peek().is_none() {
+        return Ok((prefix, None));
+    }
+
+    let mut expansion = String::from("^");
+    let mut after_separator = true;
+    while let Some(split) = splits.next() {
+        if !after_separator {
+            expansion.push(DELIMITER);
         }
-        // We are gathering splits for the expansion.
-        //
+        after_separator = false;
         // Handle '**', we expect them to be by themselves in a split.
         if split == "**" {
-            last_split_was_wildcard = true;
-            expansion.push_str(".*");
+            if splits.peek().is_some() {
+                expansion.push_str("(.*/)?");
+                after_separator = true;
+            } else {
+                expansion.push_str(".*");
+            }
             continue;
         }
         polars_ensure!(
             !split.contains("**"),
             ComputeError: "expected '**' by itself in path component, got {}", url
         );
-        if !last_split_was_wildcard && !expansion.is_empty() {
-            expansion.push(DELIMITER);
-        }
-        // Handle '.' inside a split.
-        if memchr::memchr2(b'.', b'*', split.as_bytes()).is_some() {
-            let processed = split.replace('.', "\\.");
-            expansion.push_str(&processed.replace('*', "([^/]*)"));
-            continue;
-        }
-        last_split_was_wildcard = false;
-        expansion.push_str(split);
+        expansion.push_str(&component_to_regex(split, url)?);
     }
-    // Prefix post-processing: when present, prefix should end with '/' in order to simplify matching.
-    if !prefix.is_empty() && !expansion.is_empty() {
+    expansion.push('$');
+
+    // When present, the prefix should end with '/' in order to simplify matching.
+    if !prefix.is_empty() {
         prefix.push(DELIMITER);
     }
-    // Expansion post-processing: when present, expansion should cover the whole input.
-    if !expansion.is_empty() {
-        expansion.insert(0, '^');
-        expansion.push('$');
-    }
-    Ok((
-        prefix,
-        if !expansion.is_empty() {
-            Some(expansion)
-        } else {
-            None
-        },
-    ))
+    Ok((prefix, Some(expansion)))
 }
 
 /// A location on cloud storage, may have wildcards.
@@ -97,12 +232,14 @@ impl CloudLocation {
             }
 
             let key = parsed.path();
-            let bucket = parsed
-                .host()
-                .ok_or_else(
-                    || polars_err!(ComputeError: "cannot parse bucket (host) from url: {}", parsed),
-                )?
-                .to_string();
+            let host = parsed.host().ok_or_else(
+                || polars_err!(ComputeError: "cannot parse bucket (host) from url: {}", parsed),
+            )?;
+            // Keep the file system of `abfss://<file_system>@<account>.dfs.core.windows.net`.
+            let bucket = match parsed.username() {
+                "" => host.to_string(),
+                file_system => format!("{file_system}@{host}"),
+            };
             (bucket, key)
         };
 
@@ -190,17 +327,72 @@ pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResu
         expansion.as_deref(),
     )?;
 
-    let mut locations = store
-        .list(Some(&Path::from(prefix)))
-        .try_filter_map(|x| async move {
-            let out =
-                (x.size > 0 && matcher.is_matching(x.location.as_ref())).then_some(x.location);
-            Ok(out)
-        })
-        .try_collect::<Vec<_>>()
-        .await
-        .map_err(to_compute_err)?;
+    // Subdirectories only need to be listed if the pattern spans them.
+    let recursive = match &expansion {
+        Some(expansion) => expansion.contains(".*") || expansion.replace("[^/]", "").contains('/'),
+        None => true,
+    };
+
+    let ttl = cloud_options.and_then(|options| options.for_url(url).listing_cache_ttl);
+    let cache_key = match ttl {
+        Some(_) if scheme != "file" => {
+            let parsed = parse_url(url).map_err(to_compute_err)?;
+            let options = cloud_options.map(|options| options.for_url(url));
+            Some((
+                url_and_creds_to_key(&parsed, options),
+                prefix.clone(),
+                recursive,
+            ))
+        },
+        _ => None,
+    };
+    let cached = cache_key.as_ref().and_then(|key| {
+        let cache = LISTING_CACHE.lock().unwrap();
+        cache
+            .get(key)
+            .filter(|listing| listing.expires_at > Instant::now())
+            .map(|listing| listing.locations.clone())
+    });
+    if cache_key.is_some() {
+        record_cache_lookup(IoCache::Listing, cached.is_some());
+    }
+
+    let listed = match cached {
+        Some(locations) => locations,
+        None => {
+            let locations =
+                list_non_empty(store.as_ref(), url, cloud_options, &prefix, recursive).await?;
+            let locations = Arc::new(locations);
 
+            if let (Some(key), Some(ttl)) = (cache_key, ttl) {
+                let mut cache = LISTING_CACHE.lock().unwrap();
+                if cache.len() >= LISTING_CACHE_SIZE {
+                    let now = Instant::now();
+                    cache.retain(|_, listing| listing.expires_at > now);
+                    if cache.len() >= LISTING_CACHE_SIZE {
+                        cache.clear();
+                    }
+                }
+                cache.insert(
+                    key,
+                    CachedListing {
+                        scheme: scheme.clone(),
+                        bucket: bucket.clone(),
+                        prefix: prefix.clone(),
+                        expires_at: Instant::now() + ttl,
+                        locations: locations.clone(),
+                    },
+                );
+            }
+            locations
+        },
+    };
+
+    let mut locations = listed
+        .iter()
+        .filter(|location| matcher.is_matching(location.as_ref()))
+        .cloned()
+        .collect::<Vec<_>>();
     locations.sort_unstable();
     Ok(locations
         .into_iter()
@@ -232,6 +424,15 @@ mod test {
                 expansion: Some("^([^/]*)\\.c$".into()),
             }
         );
+        assert_eq!(
+            CloudLocation::new("abfss://fs@account.dfs.core.windows.net/b/*.c", true).unwrap(),
+            CloudLocation {
+                scheme: "abfss".into(),
+                bucket: "fs@account.dfs.core.windows.net".into(),
+                prefix: "b/".into(),
+                expansion: Some("^([^/]*)\\.c$".into()),
+            }
+        );
         assert_eq!(
             CloudLocation::new("file:///a/b", true).unwrap(),
             CloudLocation {
@@ -256,20 +457,30 @@ mod test {
         );
         assert_eq!(
             extract_prefix_expansion("a/**/b").unwrap(),
-            ("a/".into(), Some("^.*b$".into()))
+            ("a/".into(), Some("^(.*/)?b$".into()))
         );
         assert_eq!(
             extract_prefix_expansion("a/**/*b").unwrap(),
-            ("a/".into(), Some("^.*([^/]*)b$".into()))
+            ("a/".into(), Some("^(.*/)?([^/]*)b$".into()))
         );
         assert_eq!(
             extract_prefix_expansion("a/**/data/*b").unwrap(),
-            ("a/".into(), Some("^.*data/([^/]*)b$".into()))
+            ("a/".into(), Some("^(.*/)?data/([^/]*)b$".into()))
         );
         assert_eq!(
             extract_prefix_expansion("a/*b").unwrap(),
             ("a/".into(), Some("^([^/]*)b$".into()))
         );
+        assert_eq!(
+            extract_prefix_expansion("a/{b,c*}/d?.[!x-z]").unwrap(),
+            ("a/".into(), Some("^(b|c([^/]*))/d[^/]\\.[^x-z]$".into()))
+        );
+        assert_eq!(
+            extract_prefix_expansion("a/[]b]/**").unwrap(),
+            ("a/".into(), Some("^[\\]b]/.*$".into()))
+        );
+        assert!(extract_prefix_expansion("a/{b/c}").is_err());
+        assert!(extract_prefix_expansion("a/[b").is_err());
     }
 
     #[test]
@@ -301,6 +512,20 @@ mod test {
         assert!(a.is_matching(Path::from("folder/data/1.parquet").as_ref()));
         // Required folder is present and additional folders are allowed.
         assert!(a.is_matching(Path::from("folder/other/data/1.parquet").as_ref()));
+        // The folder must match as a whole.
+        assert!(!a.is_matching(Path::from("folder/mydata/1.parquet").as_ref()));
+    }
+
+    #[test]
+    fn test_matcher_braces() {
+        let cloud_location =
+            CloudLocation::new("gs://bucket/folder/{2023,2024}/*.{csv,csv.gz}", true).unwrap();
+        assert_eq!(cloud_location.prefix, "folder/");
+        let a = Matcher::new(cloud_location.prefix, cloud_location.expansion.as_deref()).unwrap();
+        assert!(a.is_matching(Path::from("folder/2023/1.csv").as_ref()));
+        assert!(a.is_matching(Path::from("folder/2024/1.csv.gz").as_ref()));
+        assert!(!a.is_matching(Path::from("folder/2022/1.csv").as_ref()));
+        assert!(!a.is_matching(Path::from("folder/2024/1.parquet").as_ref()));
     }
 
     #[test]
@@ -347,4 +572,42 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn test_invalidate_listing_cache() {
+        let listing = |bucket: &str, prefix: &str| CachedListing {
+            scheme: "s3".into(),
+            bucket: bucket.into(),
+            prefix: prefix.into(),
+            expires_at: Instant::now() + std::time::Duration::from_secs(60),
+            locations: Default::default(),
+        };
+        let key =
+            |bucket: &str, prefix: &str| (format!("test-listing/{bucket}"), prefix.into(), true);
+        {
+            let mut cache = LISTING_CACHE.lock().unwrap();
+            for (bucket, prefix) in [
+                ("listing-a", ""),
+                ("listing-a", "data/"),
+                ("listing-a", "data/year=2024/"),
+                ("listing-a", "other/"),
+                ("listing-b", "data/"),
+            ] {
+                cache.insert(key(bucket, prefix), listing(bucket, prefix));
+            }
+        }
+
+        invalidate_listing_cache("s3://listing-a/data/*.parquet").unwrap();
+        let cache = LISTING_CACHE.lock().unwrap();
+        let mut remaining = cache
+            .keys()
+            .filter(|(store, _, _)| store.starts_with("test-listing/"))
+            .cloned()
+            .collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            [key("listing-a", "other/"), key("listing-b", "data/")]
+        );
+    }
 }
@@ -0,0 +1,75 @@
+//! Hooks to export telemetry of the IO polars does against cloud storage, e.g. to Prometheus.
+
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// The kind of a request to cloud storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IoRequestKind {
+    /// A request for a whole object.
+    Get,
+    /// A request for a byte range of an object.
+    GetRange,
+    /// A request for the metadata of an object.
+    Head,
+    /// A listing of the objects under a prefix, which can take multiple requests.
+    List,
+}
+
+/// The caches that avoid requests to cloud storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IoCache {
+    /// Downloaded files, see the `file_cache` feature.
+    File,
+    /// Glob listings, see [`CloudOptions::listing_cache_ttl`](super::CloudOptions).
+    Listing,
+}
+
+/// Receives the IO events of the cloud readers. The methods are called on the IO threads, so they
+/// should only update counters and histograms.
+pub trait IoMetrics: Send + Sync {
+    /// A request finished after `latency`, having received `bytes` bytes of data.
+    fn on_request(&self, kind: IoRequestKind, latency: Duration, bytes: u64, success: bool) {
+        let _ = (kind, latency, bytes, success);
+    }
+
+    /// A read that failed or stalled midway is resumed with another request. Requests that fail
+    /// before a response is received are retried inside the object store and not reported.
+    fn on_retry(&self, kind: IoRequestKind) {
+        let _ = kind;
+    }
+
+    /// A lookup in one of the caches, `hit` if it avoided the request.
+    fn on_cache_lookup(&self, cache: IoCache, hit: bool) {
+        let _ = (cache, hit);
+    }
+}
+
+static IO_METRICS: RwLock<Option<Arc<dyn IoMetrics>>> = RwLock::new(None);
+
+/// Report the IO events of all cloud readers to `metrics`, or stop reporting them with `None`.
+pub fn set_io_metrics(metrics: Option<Arc<dyn IoMetrics>>) {
+    *IO_METRICS.write().unwrap() = metrics;
+}
+
+pub(crate) fn io_metrics() -> Option<Arc<dyn IoMetrics>> {
+    IO_METRICS.read().unwrap().clone()
+}
+
+pub(crate) fn record_request(kind: IoRequestKind, start: Instant, bytes: u64, success: bool) {
+    if let Some(metrics) = io_metrics() {
+        metrics.on_request(kind, start.elapsed(), bytes, success);
+    }
+}
+
+pub(crate) fn record_retry(kind: IoRequestKind) {
+    if let Some(metrics) = io_metrics() {
+        metrics.on_retry(kind);
+    }
+}
+
+pub(crate) fn record_cache_lookup(cache: IoCache, hit: bool) {
+    if let Some(metrics) = io_metrics() {
+        metrics.on_cache_lookup(cache, hit);
+    }
+}
@@ -2,20 +2,50 @@
 
 #[cfg(feature = "cloud")]
 mod adaptors;
+#[cfg(feature = "azure")]
+mod adls;
+#[cfg(feature = "aws")]
+mod aws_config;
+#[cfg(feature = "http")]
+mod cookie_jar;
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+mod credential_provider;
+#[cfg(feature = "gcp")]
+mod gcp_auth;
+#[cfg(feature = "gcp")]
+mod gcp_compose;
 #[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
+mod metrics;
+#[cfg(feature = "cloud")]
 mod object_store_setup;
 pub mod options;
 #[cfg(feature = "cloud")]
+mod options_json;
+#[cfg(feature = "cloud")]
 mod polars_object_store;
+#[cfg(feature = "cloud")]
+mod user_store;
 
 #[cfg(feature = "cloud")]
 pub use adaptors::*;
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+pub use credential_provider::{CredentialProvider, Credentials};
+#[cfg(feature = "gcp")]
+pub use gcp_compose::compose_gcs_objects;
 #[cfg(feature = "cloud")]
 pub use glob::*;
 #[cfg(feature = "cloud")]
+pub(crate) use metrics::record_cache_lookup;
+#[cfg(feature = "cloud")]
+pub use metrics::{set_io_metrics, IoCache, IoMetrics, IoRequestKind};
+#[cfg(feature = "cloud")]
+pub use object_store::ObjectStore;
+#[cfg(feature = "cloud")]
 pub use object_store_setup::*;
 pub use options::*;
 #[cfg(feature = "cloud")]
 pub use polars_object_store::*;
+#[cfg(feature = "cloud")]
+pub use user_store::{user_store_url, AsyncReaderStore, USER_STORE_SCHEME};
@@ -29,9 +29,15 @@ fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
 
 /// Get the key of a url for object store registration.
 /// The credential info will be removed
-fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> String {
+pub(super) fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> String {
     // We include credentials as they can expire, so users will send new credentials for the same url.
-    let creds = serde_json::to_string(&options).unwrap_or_else(|_| "".into());
+    #[allow(unused_mut)]
+    let mut creds = serde_json::to_string(&options).unwrap_or_else(|_| "".into());
+    // Credential providers aren't serialized, but every provider needs its own store.
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    if let Some(provider) = options.and_then(|options| options.credential_provider.as_ref()) {
+        creds.push_str(&provider.cache_key());
+    }
     format!(
         "{}://{}<\\creds\\>{}",
         url.scheme(),
@@ -57,6 +63,10 @@ pub async fn build_object_store(
 ) -> BuildResult {
     let parsed = parse_url(url).map_err(to_compute_err)?;
     let cloud_location = CloudLocation::from_url(&parsed, glob)?;
+    let options = options.map(|options| options.for_url(url));
+    if let Some(store) = options.and_then(|options| options.object_store.as_ref()) {
+        return Ok((cloud_location, store.0.clone()));
+    }
 
     let key = url_and_creds_to_key(&parsed, options);
     let mut allow_cache = true;
@@ -84,8 +94,7 @@ pub async fn build_object_store(
         CloudType::Gcp => {
             #[cfg(feature = "gcp")]
             {
-                let store = options.build_gcp(url)?;
-                Ok::<_, PolarsError>(Arc::new(store) as Arc<dyn ObjectStore>)
+                options.build_gcp_store(url)
             }
             #[cfg(not(feature = "gcp"))]
             return err_missing_feature("gcp", &cloud_location.scheme);
@@ -0,0 +1,466 @@
+//! A stable, versioned JSON format of [`CloudOptions`].
+//!
+//! The serde derives of [`CloudOptions`] mirror its fields and change between releases, this
+//! format is meant to be written by users and orchestration systems instead. It is built on the
+//! untyped configuration keys of [`CloudOptions::from_untyped_config`], which are stable.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use serde::{Deserialize, Serialize};
+
+use super::options::*;
+
+/// Environment variable read by [`CloudOptions::from_env_json`].
+const CLOUD_OPTIONS_ENV_VAR: &str = "POLARS_CLOUD_OPTIONS";
+
+/// The version of the format that is written, and the only one that is read.
+const FORMAT_VERSION: u32 = 1;
+
+/// Version 1 of the format. Nested options of a prefix have the same fields, without `version`
+/// and with `prefix`.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CloudOptionsV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provider: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    config: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_retries: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retry_backoff: Option<RetryBackoffV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file_cache_ttl: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    prefixes: Vec<CloudOptionsV1>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RetryBackoffV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    initial_backoff: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_backoff: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    backoff_base: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retry_timeout: Option<String>,
+}
+
+/// Format a duration so that [`parse_config_duration`] reads it back exactly.
+fn format_duration(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        format!("{}s", duration.as_secs())
+    } else if duration.subsec_millis() * 1_000_000 == duration.subsec_nanos() {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}s", duration.as_secs_f64())
+    }
+}
+
+fn provider_name(cloud_type: CloudType) -> &'static str {
+    match cloud_type {
+        CloudType::Aws => "aws",
+        CloudType::Azure => "azure",
+        CloudType::File => "file",
+        CloudType::Gcp => "gcp",
+        CloudType::Http => "http",
+        CloudType::Hf => "hf",
+    }
+}
+
+fn parse_provider(name: &str) -> PolarsResult<CloudType> {
+    Ok(match name {
+        "aws" => CloudType::Aws,
+        "azure" => CloudType::Azure,
+        "file" => CloudType::File,
+        "gcp" => CloudType::Gcp,
+        "http" => CloudType::Http,
+        "hf" => CloudType::Hf,
+        _ => polars_bail!(ComputeError: "unknown cloud provider: '{}'", name),
+    })
+}
+
+/// Set the provider the options are for, options of multiple providers can't be represented.
+fn set_provider(provider: &mut Option<CloudType>, cloud_type: CloudType) -> PolarsResult<()> {
+    match provider {
+        Some(current) if *current != cloud_type => polars_bail!(
+            ComputeError: "cloud options of both '{}' and '{}' can't be serialized",
+            provider_name(*current), provider_name(cloud_type)
+        ),
+        _ => *provider = Some(cloud_type),
+    }
+    Ok(())
+}
+
+impl CloudOptions {
+    /// Serialize the options to the format of [`CloudOptions::from_json`].
+    ///
+    /// Fails for options that can't be represented in it: options with a credential provider, or
+    /// with the configuration of more than one provider.
+    pub fn to_json(&self) -> PolarsResult<String> {
+        let mut options = self.to_v1()?;
+        options.version = Some(FORMAT_VERSION);
+        serde_json::to_string(&options).map_err(to_compute_err)
+    }
+
+    /// Deserialize options from a JSON document in the stable format, e.g.
+    ///
+    /// ```json
+    /// {
+    ///     "version": 1,
+    ///     "provider": "aws",
+    ///     "config": {"aws_region": "eu-west-1", "aws_profile": "etl", "timeout": "5m"},
+    ///     "max_retries": 5,
+    ///     "retry_backoff": {"initial_backoff": "200ms", "max_backoff": "30s", "backoff_base": 2.0},
+    ///     "prefixes": [
+    ///         {"prefix": "s3://other-bucket/", "provider": "aws", "config": {"anonymous": "true"}}
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// - `version` is required and must be `1`.
+    /// - `provider` is one of `aws`, `azure`, `gcp`, `http`, `hf` or `file` and selects how the
+    ///   keys of `config` are interpreted. It may be left out if `config` only has HTTP client
+    ///   keys.
+    /// - `config` holds string values for the keys accepted by
+    ///   [`CloudOptions::from_untyped_config`] for the urls of the provider.
+    /// - `max_retries`, `retry_backoff` (see [`RetryBackoff`]) and `file_cache_ttl` (in seconds)
+    ///   are optional and default to the values of [`CloudOptions::default`]. Durations are
+    ///   strings like `500ms`, `30s` or `5m`.
+    /// - `prefixes` are options for the urls starting with `prefix`, see
+    ///   [`CloudOptions::with_prefix_options`]. They have the same fields, except `version`.
+    ///
+    /// Unknown fields are rejected, new fields are only added with a new version.
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        let options: CloudOptionsV1 = serde_json::from_str(json)
+            .map_err(|err| polars_err!(ComputeError: "invalid cloud options: {}", err))?;
+        polars_ensure!(
+            options.version == Some(FORMAT_VERSION),
+            ComputeError: "unsupported cloud options version: {:?}, expected {}",
+            options.version, FORMAT_VERSION
+        );
+        polars_ensure!(
+            options.prefix.is_none(),
+            ComputeError: "'prefix' is only allowed in 'prefixes'"
+        );
+        Self::from_v1(options)
+    }
+
+    /// Read the options from the `POLARS_CLOUD_OPTIONS` environment variable, in the format of
+    /// [`CloudOptions::from_json`]. Returns `None` if the variable isn't set.
+    ///
+    /// This lets the whole cloud configuration of a job be injected as a single variable instead
+    /// of the provider specific ones.
+    pub fn from_env_json() -> PolarsResult<Option<Self>> {
+        match std::env::var(CLOUD_OPTIONS_ENV_VAR) {
+            Ok(json) => Self::from_json(&json).map(Some).map_err(|err| {
+                err.wrap_msg(|msg| format!("failed to read {CLOUD_OPTIONS_ENV_VAR}: {msg}"))
+            }),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(err) => {
+                polars_bail!(ComputeError: "failed to read {}: {}", CLOUD_OPTIONS_ENV_VAR, err)
+            },
+        }
+    }
+
+    fn to_v1(&self) -> PolarsResult<CloudOptionsV1> {
+        let mut provider = None;
+        let mut config = BTreeMap::new();
+
+        match &self.config {
+            #[cfg(feature = "aws")]
+            Some(CloudConfig::Aws(configs)) => {
+                provider = Some(CloudType::Aws);
+                config.extend(
+                    configs
+                        .iter()
+                        .map(|(k, v)| (k.as_ref().to_string(), v.clone())),
+                );
+            },
+            #[cfg(feature = "azure")]
+            Some(CloudConfig::Azure(configs)) => {
+                provider = Some(CloudType::Azure);
+                config.extend(
+                    configs
+                        .iter()
+                        .map(|(k, v)| (k.as_ref().to_string(), v.clone())),
+                );
+            },
+            #[cfg(feature = "gcp")]
+            Some(CloudConfig::Gcp(configs)) => {
+                provider = Some(CloudType::Gcp);
+                config.extend(
+                    configs
+                        .iter()
+                        .map(|(k, v)| (k.as_ref().to_string(), v.clone())),
+                );
+            },
+            Some(CloudConfig::Http { headers }) => {
+                provider = Some(CloudType::Http);
+                config.extend(headers.iter().cloned());
+            },
+            None => {},
+        }
+
+        #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+        polars_ensure!(
+            self.credential_provider.is_none(),
+            ComputeError: "cloud options with a credential provider can't be serialized"
+        );
+        polars_ensure!(
+            self.object_store.is_none(),
+            ComputeError: "cloud options with an object store can't be serialized"
+        );
+        #[cfg(feature = "aws")]
+        if let Some(profile) = &self.aws_profile {
+            set_provider(&mut provider, CloudType::Aws)?;
+            config.insert(AWS_PROFILE_CONFIG_KEYS[0].to_string(), profile.clone());
+        }
+        #[cfg(feature = "aws")]
+        if let Some(account_id) = &self.r2_account_id {
+            set_provider(&mut provider, CloudType::Aws)?;
+            config.insert(R2_ACCOUNT_ID_CONFIG_KEY.to_string(), account_id.clone());
+        }
+        #[cfg(feature = "aws")]
+        if self.skip_region_detection {
+            set_provider(&mut provider, CloudType::Aws)?;
+            config.insert(REGION_DETECTION_CONFIG_KEY.to_string(), "false".to_string());
+        }
+        #[cfg(feature = "aws")]
+        if let Some(region) = &self.fallback_region {
+            set_provider(&mut provider, CloudType::Aws)?;
+            config.insert(FALLBACK_REGION_CONFIG_KEY.to_string(), region.clone());
+        }
+        #[cfg(feature = "azure")]
+        if let Some(source) = &self.azure_credential_source {
+            set_provider(&mut provider, CloudType::Azure)?;
+            let source = match source {
+                AzureCredentialSource::ManagedIdentity => "managed_identity",
+                AzureCredentialSource::WorkloadIdentity => "workload_identity",
+            };
+            config.insert(
+                AzureCredentialSource::CONFIG_KEY.to_string(),
+                source.to_string(),
+            );
+        }
+        #[cfg(feature = "gcp")]
+        if let Some(account) = &self.gcp_impersonate_service_account {
+            set_provider(&mut provider, CloudType::Gcp)?;
+            config.insert(
+                GCP_IMPERSONATION_CONFIG_KEYS[0].to_string(),
+                account.clone(),
+            );
+        }
+        #[cfg(feature = "gcp")]
+        if let Some(lifetime) = self.gcp_impersonation_lifetime {
+            set_provider(&mut provider, CloudType::Gcp)?;
+            config.insert(
+                GCP_IMPERSONATION_LIFETIME_CONFIG_KEYS[0].to_string(),
+                format_duration(lifetime),
+            );
+        }
+        #[cfg(feature = "gcp_hmac")]
+        if let Some((access_id, secret)) = &self.gcp_hmac_key {
+            set_provider(&mut provider, CloudType::Gcp)?;
+            config.insert(
+                GCP_HMAC_ACCESS_ID_CONFIG_KEYS[0].to_string(),
+                access_id.clone(),
+            );
+            config.insert(GCP_HMAC_SECRET_CONFIG_KEYS[0].to_string(), secret.clone());
+        }
+        if let Some(cookie_jar) = &self.http_cookie_jar {
+            set_provider(&mut provider, CloudType::Http)?;
+            config.insert(COOKIE_JAR_CONFIG_KEY.to_string(), cookie_jar.clone());
+        }
+        #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+        if self.skip_signature {
+            polars_ensure!(
+                matches!(provider, Some(CloudType::Aws | CloudType::Azure)),
+                ComputeError: "unsigned requests can only be serialized for AWS and Azure"
+            );
+            #[cfg(any(feature = "aws", feature = "azure"))]
+            config.insert(ANONYMOUS_CONFIG_KEY.to_string(), "true".to_string());
+        }
+
+        if let Some(proxy) = &self.proxy {
+            config.insert(PROXY_URL_CONFIG_KEY.to_string(), proxy.url.clone());
+            if let Some(no_proxy) = &proxy.no_proxy {
+                config.insert(PROXY_EXCLUDES_CONFIG_KEY.to_string(), no_proxy.clone());
+            }
+            if let Some(pem) = &proxy.ca_certificate {
+                config.insert(PROXY_CA_CERTIFICATE_CONFIG_KEY.to_string(), pem.clone());
+            }
+        }
+        if let Some(pem) = &self.ca_certificates {
+            config.insert(CA_CERTIFICATES_CONFIG_KEY.to_string(), pem.clone());
+        }
+        for (key, duration) in [
+            (CONNECT_TIMEOUT_CONFIG_KEY, self.connect_timeout),
+            (REQUEST_TIMEOUT_CONFIG_KEY, self.request_timeout),
+            (POOL_IDLE_TIMEOUT_CONFIG_KEY, self.pool_idle_timeout),
+            (READ_TIMEOUT_CONFIG_KEY, self.read_timeout),
+            (LISTING_CACHE_TTL_CONFIG_KEY, self.listing_cache_ttl),
+        ] {
+            if let Some(duration) = duration {
+                config.insert(key.to_string(), format_duration(duration));
+            }
+        }
+
+        let backoff = &self.retry_backoff;
+        Ok(CloudOptionsV1 {
+            version: None,
+            prefix: None,
+            provider: provider.map(|provider| provider_name(provider).to_string()),
+            config,
+            max_retries: Some(self.max_retries),
+            retry_backoff: Some(RetryBackoffV1 {
+                initial_backoff: Some(format_duration(backoff.initial_backoff)),
+                max_backoff: Some(format_duration(backoff.max_backoff)),
+                backoff_base: Some(backoff.backoff_base),
+                retry_timeout: Some(format_duration(backoff.retry_timeout)),
+            }),
+            file_cache_ttl: Some(self.file_cache_ttl),
+            prefixes: self
+                .prefix_options
+                .iter()
+                .map(|(prefix, options)| {
+                    let mut options = options.to_v1()?;
+                    options.prefix = Some(prefix.clone());
+                    Ok(options)
+                })
+                .collect::<PolarsResult<_>>()?,
+        })
+    }
+
+    fn from_v1(options: CloudOptionsV1) -> PolarsResult<Self> {
+        let cloud_type = match &options.provider {
+            Some(name) => parse_provider(name)?,
+            None => {
+                if let Some(key) = options
+                    .config
+                    .keys()
+                    .find(|key| !CLIENT_CONFIG_KEYS.contains(&key.as_str()))
+                {
+                    polars_bail!(
+                        ComputeError: "configuration key '{}' requires a 'provider'", key
+                    );
+                }
+                CloudType::File
+            },
+        };
+
+        let mut this = Self::from_untyped_config_for(cloud_type, options.config)?;
+        if let Some(max_retries) = options.max_retries {
+            this = this.with_max_retries(max_retries);
+        }
+        if let Some(backoff) = options.retry_backoff {
+            let default = RetryBackoff::default();
+            let duration = |key: &str, value: Option<String>, default: Duration| match value {
+                Some(value) => parse_config_duration(key, &value),
+                None => Ok(default),
+            };
+            this = this.with_retry_backoff(RetryBackoff {
+                initial_backoff: duration(
+                    "initial_backoff",
+                    backoff.initial_backoff,
+                    default.initial_backoff,
+                )?,
+                max_backoff: duration("max_backoff", backoff.max_backoff, default.max_backoff)?,
+                backoff_base: backoff.backoff_base.unwrap_or(default.backoff_base),
+                retry_timeout: duration(
+                    "retry_timeout",
+                    backoff.retry_timeout,
+                    default.retry_timeout,
+                )?,
+            })?;
+        }
+        if let Some(ttl) = options.file_cache_ttl {
+            this.file_cache_ttl = ttl;
+        }
+        for nested in options.prefixes {
+            polars_ensure!(
+                nested.version.is_none(),
+                ComputeError: "'version' is only allowed at the top level"
+            );
+            let Some(prefix) = nested.prefix.clone() else {
+                polars_bail!(ComputeError: "options in 'prefixes' require a 'prefix'");
+            };
+            this = this.with_prefix_options(prefix, Self::from_v1(nested)?);
+        }
+        Ok(this)
+    }
+}
+
+#[cfg(all(test, feature = "aws"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloud_options_json_roundtrip() {
+        let json = r#"{
+            "version": 1,
+            "provider": "aws",
+            "config": {
+                "aws_region": "eu-west-1",
+                "aws_profile": "etl",
+                "timeout": "5m",
+                "region_detection": "false",
+                "fallback_region": "eu-west-3"
+            },
+            "max_retries": 5,
+            "retry_backoff": {"initial_backoff": "200ms", "backoff_base": 3.0},
+            "prefixes": [
+                {"prefix": "s3://public/", "provider": "aws", "config": {"anonymous": "true"}},
+                {"prefix": "https://example.com/", "provider": "http", "config": {"bearer_token": "abc"}}
+            ]
+        }"#;
+        let options = CloudOptions::from_json(json).unwrap();
+        assert_eq!(options.max_retries, 5);
+        assert_eq!(options.aws_profile.as_deref(), Some("etl"));
+        assert!(options.skip_region_detection);
+        assert_eq!(options.fallback_region.as_deref(), Some("eu-west-3"));
+        assert_eq!(options.request_timeout, Some(Duration::from_secs(300)));
+        assert_eq!(
+            options.retry_backoff.initial_backoff,
+            Duration::from_millis(200)
+        );
+        assert_eq!(options.retry_backoff.backoff_base, 3.0);
+        assert_eq!(
+            options.retry_backoff.max_backoff,
+            RetryBackoff::default().max_backoff
+        );
+        assert!(options.for_url("s3://public/file.parquet").skip_signature);
+
+        let json = options.to_json().unwrap();
+        assert!(json.contains(r#""timeout":"300s""#));
+        assert_eq!(CloudOptions::from_json(&json).unwrap(), options);
+    }
+
+    #[test]
+    fn test_cloud_options_json_errors() {
+        let err = |json: &str| CloudOptions::from_json(json).unwrap_err().to_string();
+        assert!(err(r#"{"provider": "aws"}"#).contains("unsupported cloud options version"));
+        assert!(err(r#"{"version": 2}"#).contains("unsupported cloud options version"));
+        assert!(err(r#"{"version": 1, "retries": 3}"#).contains("unknown field"));
+        assert!(err(r#"{"version": 1, "config": {"aws_region": "a"}}"#).contains("'provider'"));
+        assert!(err(r#"{"version": 1, "prefixes": [{"provider": "aws"}]}"#).contains("'prefix'"));
+
+        let options = CloudOptions::from_json(r#"{"version": 1, "config": {"timeout": "1s"}}"#)
+            .unwrap()
+            .with_aws([(AmazonS3ConfigKey::Region, "a")])
+            .with_http_cookie_jar("cookies.txt");
+        assert!(options
+            .to_json()
+            .unwrap_err()
+            .to_string()
+            .contains("can't be serialized"));
+    }
+}
@@ -1,13 +1,18 @@
+use std::future::Future;
 use std::ops::Range;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use bytes::Bytes;
 use futures::StreamExt;
 use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
-use polars_error::{to_compute_err, PolarsResult};
+use polars_core::config::verbose;
+use polars_error::{polars_err, to_compute_err, PolarsResult};
 use tokio::io::AsyncWriteExt;
 
+use super::metrics::{record_request, record_retry, IoRequestKind};
+use super::CloudOptions;
 use crate::pl_async::{
     self, tune_with_concurrency_budget, with_concurrency_budget, MAX_BUDGET_PER_REQUEST,
 };
@@ -15,31 +20,49 @@ use crate::pl_async::{
 /// Polars specific wrapper for `Arc<dyn ObjectStore>` that limits the number of
 /// concurrent requests for the entire application.
 #[derive(Debug, Clone)]
-pub struct PolarsObjectStore(Arc<dyn ObjectStore>);
+pub struct PolarsObjectStore {
+    store: Arc<dyn ObjectStore>,
+    /// How often a ranged read that fails while receiving the body is resumed.
+    max_resumes: usize,
+    read_timeout: Option<Duration>,
+}
 pub type ObjectStorePath = object_store::path::Path;
 
 impl PolarsObjectStore {
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self(store)
+        Self {
+            store,
+            max_resumes: CloudOptions::default().max_retries,
+            read_timeout: None,
+        }
+    }
+
+    /// Resume and time out ranged reads as configured in `options`, see
+    /// [`CloudOptions::read_timeout`].
+    pub fn with_cloud_options(mut self, options: Option<&CloudOptions>) -> Self {
+        if let Some(options) = options {
+            self.max_resumes = options.max_retries;
+            #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
+            {
+                self.read_timeout = options.read_timeout;
+            }
+        }
+        self
     }
 
     pub async fn get(&self, path: &Path) -> PolarsResult<Bytes> {
         tune_with_concurrency_budget(1, || async {
-            self.0
-                .get(path)
-                .await
-                .map_err(to_compute_err)?
-                .bytes()
-                .await
-                .map_err(to_compute_err)
+            let start = Instant::now();
+            let result = async { self.store.get(path).await?.bytes().await }.await;
+            let bytes = result.as_ref().map_or(0, |bytes| bytes.len() as u64);
+            record_request(IoRequestKind::Get, start, bytes, result.is_ok());
+            result.map_err(to_compute_err)
         })
         .await
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
-        tune_with_concurrency_budget(1, || self.0.get_range(path, range))
-            .await
-            .map_err(to_compute_err)
+        tune_with_concurrency_budget(1, || self.get_range_resumable(path, range)).await
     }
 
     pub async fn get_ranges(
@@ -49,10 +72,109 @@ impl PolarsObjectStore {
     ) -> PolarsResult<Vec<Bytes>> {
         tune_with_concurrency_budget(
             (ranges.len() as u32).clamp(0, MAX_BUDGET_PER_REQUEST as u32),
-            || self.0.get_ranges(path, ranges),
+            || {
+                object_store::coalesce_ranges(
+                    ranges,
+                    |range| self.get_range_resumable(path, range),
+                    object_store::OBJECT_STORE_COALESCE_DEFAULT,
+                )
+            },
         )
         .await
-        .map_err(to_compute_err)
+    }
+
+    /// Fetch `range`, resuming from the last received byte if the body fails or stalls midway,
+    /// instead of fetching the whole range again. Failed requests are already retried by the
+    /// object store.
+    async fn get_range_resumable(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        let mut buf = Vec::with_capacity(range.len());
+        let mut resumes = 0;
+        loop {
+            let start = range.start + buf.len();
+            let Err(err) = self
+                .read_range_into(path, start..range.end, &mut buf)
+                .await?
+            else {
+                return Ok(buf.into());
+            };
+            if resumes == self.max_resumes {
+                return Err(err);
+            }
+            resumes += 1;
+            record_retry(IoRequestKind::GetRange);
+            if verbose() {
+                eprintln!(
+                    "resuming read of '{}' at byte {} of {}..{}: {}",
+                    path, start, range.start, range.end, err
+                );
+            }
+        }
+    }
+
+    /// Append the bytes of `range` to `buf`. A failed request is returned as the outer error,
+    /// a failure after the response started as the inner one, as only those can be resumed.
+    async fn read_range_into(
+        &self,
+        path: &Path,
+        range: Range<usize>,
+        buf: &mut Vec<u8>,
+    ) -> PolarsResult<PolarsResult<()>> {
+        let start = Instant::now();
+        let mut received = 0;
+        let result = self
+            .read_range_into_inner(path, range, buf, &mut received)
+            .await;
+        let success = matches!(result, Ok(Ok(())));
+        record_request(IoRequestKind::GetRange, start, received as u64, success);
+        result
+    }
+
+    async fn read_range_into_inner(
+        &self,
+        path: &Path,
+        range: Range<usize>,
+        buf: &mut Vec<u8>,
+        received: &mut usize,
+    ) -> PolarsResult<PolarsResult<()>> {
+        let options = object_store::GetOptions {
+            range: Some(range.clone().into()),
+            ..Default::default()
+        };
+        let response = match self
+            .with_read_timeout(self.store.get_opts(path, options))
+            .await
+        {
+            Ok(response) => response.map_err(to_compute_err)?,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let mut stream = response.into_stream();
+        loop {
+            match self.with_read_timeout(stream.next()).await {
+                Ok(Some(Ok(bytes))) => {
+                    *received += bytes.len();
+                    buf.extend_from_slice(&bytes);
+                },
+                Ok(Some(Err(err))) => return Ok(Err(to_compute_err(err))),
+                Ok(None) => break,
+                Err(err) => return Ok(Err(err)),
+            }
+        }
+        if *received < range.len() {
+            return Ok(Err(polars_err!(
+                ComputeError: "response body ended after {} of {} bytes", *received, range.len()
+            )));
+        }
+        Ok(Ok(()))
+    }
+
+    async fn with_read_timeout<F: Future>(&self, future: F) -> PolarsResult<F::Output> {
+        match self.read_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future).await.map_err(|_| {
+                polars_err!(ComputeError: "no data received within the read timeout of {:?}", timeout)
+            }),
+            None => Ok(future.await),
+        }
     }
 
     pub async fn download<F: tokio::io::AsyncWrite + std::marker::Unpin>(
@@ -61,21 +183,28 @@ impl PolarsObjectStore {
         file: &mut F,
     ) -> PolarsResult<()> {
         tune_with_concurrency_budget(1, || async {
-            let mut stream = self
-                .0
-                .get(path)
-                .await
-                .map_err(to_compute_err)?
-                .into_stream();
-
+            let start = Instant::now();
             let mut len = 0;
-            while let Some(bytes) = stream.next().await {
-                let bytes = bytes.map_err(to_compute_err)?;
-                len += bytes.len();
-                file.write_all(bytes.as_ref())
+            let result = async {
+                let mut stream = self
+                    .store
+                    .get(path)
                     .await
-                    .map_err(to_compute_err)?;
+                    .map_err(to_compute_err)?
+                    .into_stream();
+
+                while let Some(bytes) = stream.next().await {
+                    let bytes = bytes.map_err(to_compute_err)?;
+                    len += bytes.len();
+                    file.write_all(bytes.as_ref())
+                        .await
+                        .map_err(to_compute_err)?;
+                }
+                PolarsResult::Ok(())
             }
+            .await;
+            record_request(IoRequestKind::Get, start, len as u64, result.is_ok());
+            result?;
 
             PolarsResult::Ok(pl_async::Size::from(len as u64))
         })
@@ -86,13 +215,16 @@ impl PolarsObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     pub async fn head(&self, path: &Path) -> PolarsResult<ObjectMeta> {
         with_concurrency_budget(1, || async {
-            let head_result = self.0.head(path).await;
+            let start = Instant::now();
+            let head_result = self.store.head(path).await;
+            record_request(IoRequestKind::Head, start, 0, head_result.is_ok());
 
             if head_result.is_err() {
                 // Pre-signed URLs forbid the HEAD method, but we can still retrieve the header
                 // information with a range 0-0 request.
+                let start = Instant::now();
                 let get_range_0_0_result = self
-                    .0
+                    .store
                     .get_opts(
                         path,
                         object_store::GetOptions {
@@ -101,6 +233,12 @@ impl PolarsObjectStore {
                         },
                     )
                     .await;
+                record_request(
+                    IoRequestKind::GetRange,
+                    start,
+                    0,
+                    get_range_0_0_result.is_ok(),
+                );
 
                 if let Ok(v) = get_range_0_0_result {
                     return Ok(v.meta);
@@ -113,3 +251,165 @@ impl PolarsObjectStore {
         .map_err(to_compute_err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::stream::BoxStream;
+    use object_store::memory::InMemory;
+    use object_store::{
+        GetOptions, GetResult, GetResultPayload, ListResult, MultipartUpload, PutMultipartOpts,
+        PutOptions, PutPayload, PutResult,
+    };
+
+    use super::*;
+
+    /// Store whose first `failures` bodies fail after half of the bytes.
+    #[derive(Debug)]
+    struct FlakyStore {
+        inner: InMemory,
+        failures: AtomicUsize,
+    }
+
+    impl fmt::Display for FlakyStore {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "FlakyStore")
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ObjectStore for FlakyStore {
+        async fn put_opts(
+            &self,
+            location: &Path,
+            payload: PutPayload,
+            opts: PutOptions,
+        ) -> object_store::Result<PutResult> {
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOpts,
+        ) -> object_store::Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            options: GetOptions,
+        ) -> object_store::Result<GetResult> {
+            let mut result = self.inner.get_opts(location, options).await?;
+            let remaining = self.failures.load(Ordering::Relaxed);
+            if remaining > 0 {
+                self.failures.store(remaining - 1, Ordering::Relaxed);
+                let bytes = result.bytes().await?;
+                let half = bytes.slice(0..bytes.len() / 2);
+                let error = object_store::Error::Generic {
+                    store: "FlakyStore",
+                    source: "connection reset".into(),
+                };
+                result = GetResult {
+                    payload: GetResultPayload::Stream(
+                        futures::stream::iter([Ok(half), Err(error)]).boxed(),
+                    ),
+                    meta: self.inner.head(location).await?,
+                    range: 0..0,
+                    attributes: Default::default(),
+                };
+            }
+            Ok(result)
+        }
+
+        async fn delete(&self, location: &Path) -> object_store::Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+            self.inner.list(prefix)
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            prefix: Option<&Path>,
+        ) -> object_store::Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
+
+    #[derive(Default)]
+    struct CountingMetrics {
+        range_requests: AtomicUsize,
+        failed_range_requests: AtomicUsize,
+        range_bytes: AtomicUsize,
+        retries: AtomicUsize,
+    }
+
+    impl crate::cloud::IoMetrics for CountingMetrics {
+        fn on_request(&self, kind: IoRequestKind, _latency: Duration, bytes: u64, success: bool) {
+            if kind == IoRequestKind::GetRange {
+                self.range_requests.fetch_add(1, Ordering::Relaxed);
+                self.range_bytes
+                    .fetch_add(bytes as usize, Ordering::Relaxed);
+                if !success {
+                    self.failed_range_requests.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+
+        fn on_retry(&self, _kind: IoRequestKind) {
+            self.retries.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_get_range_resumes() {
+        let data = Bytes::from((0..=255).collect::<Vec<u8>>());
+        let path = Path::from("file.bin");
+        let store = Arc::new(FlakyStore {
+            inner: InMemory::new(),
+            failures: AtomicUsize::new(0),
+        });
+        let store_ref = store.clone();
+
+        pl_async::get_runtime().block_on(async move {
+            store.put(&path, data.clone().into()).await.unwrap();
+            let polars_store = PolarsObjectStore::new(store.clone());
+
+            // Two failures are resumed, each from the byte where the body broke off.
+            let metrics = Arc::new(CountingMetrics::default());
+            crate::cloud::set_io_metrics(Some(metrics.clone()));
+            store_ref.failures.store(2, Ordering::Relaxed);
+            let bytes = polars_store.get_range(&path, 16..200).await.unwrap();
+            crate::cloud::set_io_metrics(None);
+            assert_eq!(bytes, data.slice(16..200));
+            assert_eq!(metrics.range_requests.load(Ordering::Relaxed), 3);
+            assert_eq!(metrics.failed_range_requests.load(Ordering::Relaxed), 2);
+            assert_eq!(metrics.range_bytes.load(Ordering::Relaxed), 184);
+            assert_eq!(metrics.retries.load(Ordering::Relaxed), 2);
+
+            let bytes = polars_store
+                .get_ranges(&path, &[0..10, 100..256])
+                .await
+                .unwrap();
+            assert_eq!(bytes, [data.slice(0..10), data.slice(100..256)]);
+
+            // More failures than resumes.
+            store_ref.failures.store(3, Ordering::Relaxed);
+            let err = polars_store.get_range(&path, 16..200).await.unwrap_err();
+            assert!(err.to_string().contains("connection reset"));
+        });
+    }
+}
@@ -0,0 +1,220 @@
+//! Object stores and readers supplied by the user instead of built from a url.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetRange, GetResult, GetResultPayload, ListResult, MultipartUpload, ObjectMeta,
+    ObjectStore, PutMultipartOpts, PutOptions, PutPayload, PutResult,
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use tokio::sync::Mutex;
+
+/// Scheme of the urls of the paths in an object store attached with
+/// [`CloudOptions::with_object_store`].
+///
+/// [`CloudOptions::with_object_store`]: super::CloudOptions::with_object_store
+pub const USER_STORE_SCHEME: &str = "store";
+
+/// Returns the url of `path` in an object store attached with
+/// [`CloudOptions::with_object_store`], e.g. `store://object-store/data/file.parquet`.
+///
+/// [`CloudOptions::with_object_store`]: super::CloudOptions::with_object_store
+pub fn user_store_url(path: &str) -> String {
+    format!(
+        "{USER_STORE_SCHEME}://object-store/{}",
+        path.trim_start_matches('/')
+    )
+}
+
+/// An [`ObjectStore`] attached to `CloudOptions`. Stores are compared by identity.
+#[derive(Clone, Debug)]
+pub(crate) struct SharedObjectStore(pub(crate) Arc<dyn ObjectStore>);
+
+impl SharedObjectStore {
+    fn addr(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl PartialEq for SharedObjectStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl Eq for SharedObjectStore {}
+
+impl Hash for SharedObjectStore {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state)
+    }
+}
+
+/// A read-only [`ObjectStore`] that serves the bytes of an async reader at every path, e.g. to
+/// scan a file from a storage client that is not supported by `object_store`.
+///
+/// Reads are serialized, as they seek the reader.
+pub struct AsyncReaderStore<R> {
+    reader: Mutex<R>,
+}
+
+impl<R> AsyncReaderStore<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: Mutex::new(reader),
+        }
+    }
+}
+
+impl<R> fmt::Debug for AsyncReaderStore<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncReaderStore").finish_non_exhaustive()
+    }
+}
+
+impl<R> fmt::Display for AsyncReaderStore<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AsyncReaderStore")
+    }
+}
+
+fn not_supported<T>() -> object_store::Result<T> {
+    Err(object_store::Error::NotSupported {
+        source: "the store of an async reader is read-only".into(),
+    })
+}
+
+fn to_store_err(err: std::io::Error) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "AsyncReaderStore",
+        source: Box::new(err),
+    }
+}
+
+/// Resolve the requested `range` of an object of `len` bytes.
+fn resolve_range(range: Option<&GetRange>, len: usize) -> object_store::Result<Range<usize>> {
+    let range = match range {
+        None => 0..len,
+        Some(GetRange::Bounded(range)) => range.start..range.end.min(len),
+        Some(GetRange::Offset(offset)) => *offset..len,
+        Some(GetRange::Suffix(n)) => len.saturating_sub(*n)..len,
+    };
+    if range.start > range.end || (range.start >= len && len > 0) {
+        return Err(object_store::Error::Generic {
+            store: "AsyncReaderStore",
+            source: format!("range {range:?} is out of bounds of {len} bytes").into(),
+        });
+    }
+    Ok(range)
+}
+
+#[async_trait::async_trait]
+impl<R> ObjectStore for AsyncReaderStore<R>
+where
+    R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+{
+    async fn put_opts(
+        &self,
+        _location: &Path,
+        _payload: PutPayload,
+        _opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        not_supported()
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        _location: &Path,
+        _opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        not_supported()
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        let mut reader = self.reader.lock().await;
+        let len = reader.seek(SeekFrom::End(0)).await.map_err(to_store_err)? as usize;
+        let meta = ObjectMeta {
+            location: location.clone(),
+            last_modified: Default::default(),
+            size: len,
+            e_tag: None,
+            version: None,
+        };
+        let range = resolve_range(options.range.as_ref(), len)?;
+
+        let mut buf = vec![0; if options.head { 0 } else { range.len() }];
+        if !buf.is_empty() {
+            reader
+                .seek(SeekFrom::Start(range.start as u64))
+                .await
+                .map_err(to_store_err)?;
+            reader.read_exact(&mut buf).await.map_err(to_store_err)?;
+        }
+        let bytes = Bytes::from(buf);
+        Ok(GetResult {
+            payload: GetResultPayload::Stream(futures::stream::once(async { Ok(bytes) }).boxed()),
+            meta,
+            range,
+            attributes: Default::default(),
+        })
+    }
+
+    async fn delete(&self, _location: &Path) -> object_store::Result<()> {
+        not_supported()
+    }
+
+    fn list(&self, _prefix: Option<&Path>) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        futures::stream::once(async { not_supported() }).boxed()
+    }
+
+    async fn list_with_delimiter(
+        &self,
+        _prefix: Option<&Path>,
+    ) -> object_store::Result<ListResult> {
+        not_supported()
+    }
+
+    async fn copy(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
+        not_supported()
+    }
+
+    async fn copy_if_not_exists(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
+        not_supported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_async_reader_store() {
+        let data = (0..=255).collect::<Vec<u8>>();
+        let store = AsyncReaderStore::new(Cursor::new(data));
+        let path = Path::from("file.bin");
+
+        crate::pl_async::get_runtime().block_on_potential_spawn(async {
+            assert_eq!(store.head(&path).await.unwrap().size, 256);
+            let bytes = store.get_range(&path, 16..200).await.unwrap();
+            assert_eq!(bytes.as_ref(), &(16..200).collect::<Vec<u8>>());
+            let bytes = store.get_range(&path, 250..300).await.unwrap();
+            assert_eq!(bytes.as_ref(), &(250..=255).collect::<Vec<u8>>());
+            assert!(store.get_range(&path, 300..310).await.is_err());
+            assert!(store.delete(&path).await.is_err());
+        });
+    }
+}
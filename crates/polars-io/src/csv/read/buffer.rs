@@ -5,7 +5,7 @@ use polars_error::to_compute_err;
 use polars_time::chunkedarray::string::Pattern;
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 use polars_time::prelude::string::infer::{
-    infer_pattern_single, DatetimeInfer, StrpTimeParser, TryFromWithUnit,
+    infer_pattern_single, DatetimeFormat, DatetimeInfer, StrpTimeParser, TryFromWithUnit,
 };
 use polars_utils::vec::PushUnchecked;
 
@@ -358,15 +358,18 @@ impl ParsedBuffer for BooleanChunkedBuilder {
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 pub struct DatetimeField<T: PolarsNumericType> {
     compiled: Option<DatetimeInfer<T>>,
+    /// The format given for the column, which is used instead of inferring one.
+    format: Option<DatetimeFormat<T>>,
     builder: PrimitiveChunkedBuilder<T>,
 }
 
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 impl<T: PolarsNumericType> DatetimeField<T> {
-    fn new(name: PlSmallStr, capacity: usize) -> Self {
+    fn new(name: PlSmallStr, capacity: usize, format: Option<DatetimeFormat<T>>) -> Self {
         let builder = PrimitiveChunkedBuilder::<T>::new(name, capacity);
         Self {
             compiled: None,
+            format,
             builder,
         }
     }
@@ -467,6 +470,18 @@ where
             return Ok(());
         }
 
+        if let Some(format) = &mut self.format {
+            match format.parse_bytes(bytes, time_unit) {
+                Some(parsed) => self.builder.append_value(parsed),
+                None if ignore_errors => self.builder.append_null(),
+                None => polars_bail!(
+                    ComputeError: "could not parse '{}' with format '{}'",
+                    String::from_utf8_lossy(bytes), format.format(),
+                ),
+            }
+            return Ok(());
+        }
+
         match &mut self.compiled {
             None => slow_datetime_parser(self, bytes, time_unit, ignore_errors),
             Some(compiled) => {
@@ -492,11 +507,18 @@ pub fn init_buffers(
     quote_char: Option<u8>,
     encoding: CsvEncoding,
     decimal_comma: bool,
+    datetime_formats: &[(PlSmallStr, PlSmallStr)],
 ) -> PolarsResult<Vec<Buffer>> {
     projection
         .iter()
         .map(|&i| {
             let (name, dtype) = schema.get_at_index(i).unwrap();
+            let format = datetime_formats
+                .iter()
+                .find_map(|(column, format)| (column == name).then_some(format.as_str()));
+            if let Some(format) = format {
+                return init_datetime_buffer(name.clone(), capacity, dtype, format);
+            }
             let name = name.clone();
             let builder = match dtype {
                 &DataType::Boolean => Buffer::Boolean(BooleanChunkedBuilder::new(name, capacity)),
@@ -537,12 +559,12 @@ pub fn init_buffers(
                 },
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
-                    buf: DatetimeField::new(name, capacity),
+                    buf: DatetimeField::new(name, capacity, None),
                     time_unit: *time_unit,
                     time_zone: time_zone.clone(),
                 },
                 #[cfg(feature = "dtype-date")]
-                &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
+                &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity, None)),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
                     name, capacity, quote_char, *ordering,
@@ -557,6 +579,41 @@ pub fn init_buffers(
         .collect()
 }
 
+/// Creates the buffer of a `Date` or `Datetime` column that is parsed with the given `format`.
+#[allow(unused_variables)]
+fn init_datetime_buffer(
+    name: PlSmallStr,
+    capacity: usize,
+    dtype: &DataType,
+    format: &str,
+) -> PolarsResult<Buffer> {
+    let buffer = match dtype {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
+            buf: DatetimeField::new(
+                name,
+                capacity,
+                Some(DatetimeFormat::try_from_with_unit(
+                    format,
+                    Some(*time_unit),
+                )?),
+            ),
+            time_unit: *time_unit,
+            time_zone: time_zone.clone(),
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => Buffer::Date(DatetimeField::new(
+            name,
+            capacity,
+            Some(DatetimeFormat::try_from_with_unit(format, None)?),
+        )),
+        dt => polars_bail!(
+            ComputeError: "a datetime format is given for CSV column '{}' of dtype {}, which is not a Date or Datetime", name, dt,
+        ),
+    };
+    Ok(buffer)
+}
+
 #[allow(clippy::large_enum_variant)]
 pub enum Buffer {
     Boolean(BooleanChunkedBuilder),
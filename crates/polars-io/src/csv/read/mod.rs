@@ -25,8 +25,13 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
-pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub use options::{
+    CommentPrefix, CsvEncoding, CsvOnError, CsvParseOptions, CsvReadOptions, NullValues,
+};
 pub use parser::count_rows;
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
-pub use schema_inference::infer_file_schema;
+pub use schema_inference::{
+    infer_file_schema, ColumnInference, CsvSchemaInference, InferenceSample,
+};
+pub use utils::normalize_fields;
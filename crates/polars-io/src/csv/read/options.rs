@@ -27,6 +27,10 @@ pub struct CsvReadOptions {
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
     pub dtype_overwrite: Option<Arc<Vec<DataType>>>,
+    /// Formats of `Date` and `Datetime` columns by column name, which are parsed with them
+    /// while reading.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
     // CSV-specific options
     pub parse_options: Arc<CsvParseOptions>,
     pub has_header: bool,
@@ -37,6 +41,8 @@ pub struct CsvReadOptions {
     pub infer_schema_length: Option<usize>,
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub on_error: CsvOnError,
     pub fields_to_cast: Vec<Field>,
 }
 
@@ -46,6 +52,14 @@ pub struct CsvParseOptions {
     pub separator: u8,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
+    /// Character that makes the character after it literal, e.g. an escaped separator, quote or
+    /// end-of-line character. Requires a `quote_char`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub escape_char: Option<u8>,
+    /// Separator of more than one byte, e.g. `||`. Fields are separated by it instead of
+    /// `separator`, which must then be quoted where it occurs in a field.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub multi_byte_separator: Option<PlSmallStr>,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
     pub missing_is_null: bool,
@@ -72,6 +86,7 @@ impl Default for CsvReadOptions {
             schema: None,
             schema_overwrite: None,
             dtype_overwrite: None,
+            datetime_formats: vec![],
 
             parse_options: Default::default(),
             has_header: true,
@@ -82,6 +97,7 @@ impl Default for CsvReadOptions {
             infer_schema_length: Some(100),
             raise_if_empty: true,
             ignore_errors: false,
+            on_error: CsvOnError::Raise,
             fields_to_cast: vec![],
         }
     }
@@ -94,6 +110,8 @@ impl Default for CsvParseOptions {
             separator: b',',
             quote_char: Some(b'"'),
             eol_char: b'\n',
+            escape_char: None,
+            multi_byte_separator: None,
             encoding: Default::default(),
             null_values: None,
             missing_is_null: true,
@@ -180,6 +198,17 @@ impl CsvReadOptions {
         self
     }
 
+    /// Parse the columns with the given `strftime` formats, by column name, while reading. A
+    /// column is read as a `Datetime` if its format has time directives and as a `Date`
+    /// otherwise, unless its dtype is set to `Date` or `Datetime` in the schema.
+    pub fn with_datetime_formats(
+        mut self,
+        datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
+    ) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
     /// Sets the CSV parsing options. See [map_parse_options][Self::map_parse_options]
     /// for an easier way to mutate them in-place.
     pub fn with_parse_options(mut self, parse_options: CsvParseOptions) -> Self {
@@ -237,6 +266,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// Set what happens with rows that fail to parse. With `ignore_errors`, fields that fail to
+    /// parse are null instead and don't fail the row.
+    pub fn with_on_error(mut self, on_error: CsvOnError) -> Self {
+        self.on_error = on_error;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -269,6 +305,21 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set the character used to escape the character after it, for dialects that escape
+    /// separators and end-of-line characters instead of quoting the field. Escaped fields are
+    /// read as if they were quoted. Set this to [None] to disable escaping.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
+    /// Set a separator of more than one byte, e.g. `||` or `\t|\t`, which is used instead of
+    /// the single byte `separator`. Set this to [None] to use the single byte separator.
+    pub fn with_multi_byte_separator(mut self, multi_byte_separator: Option<PlSmallStr>) -> Self {
+        self.multi_byte_separator = multi_byte_separator;
+        self
+    }
+
     /// Set the encoding used by the file.
     pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
         self.encoding = encoding;
@@ -320,6 +371,21 @@ impl CsvParseOptions {
     }
 }
 
+/// What to do with a row that fails to parse, e.g. because a field cannot be parsed as the dtype
+/// of its column or the row has more fields than the schema.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvOnError {
+    /// Fail the read.
+    #[default]
+    Raise,
+    /// Leave the row out of the output.
+    Skip,
+    /// Leave the row out of the output and collect it with its line number and error, see
+    /// [`CsvReader::finish_with_bad_rows`](super::CsvReader::finish_with_bad_rows).
+    Capture,
+}
+
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CsvEncoding {
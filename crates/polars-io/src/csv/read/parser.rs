@@ -11,18 +11,21 @@ use rayon::prelude::*;
 use super::buffer::Buffer;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
-use super::utils::get_file_chunks;
+use super::utils::{get_file_chunks, normalize_fields};
 use crate::path_utils::is_cloud_url;
 use crate::utils::maybe_decompress_bytes;
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
+#[allow(clippy::too_many_arguments)]
 pub fn count_rows(
     path: &PathBuf,
     separator: u8,
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    escape_char: Option<u8>,
+    multi_byte_separator: Option<&[u8]>,
     has_header: bool,
 ) -> PolarsResult<usize> {
     let file = if is_cloud_url(path) || config::force_async() {
@@ -45,6 +48,18 @@ pub fn count_rows(
     let mmap = unsafe { memmap::Mmap::map(&file).unwrap() };
     let owned = &mut vec![];
     let mut reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let quoted = normalize_fields(
+        reader_bytes,
+        separator,
+        quote_char,
+        eol_char,
+        escape_char,
+        multi_byte_separator,
+        comment_prefix,
+    )?;
+    if let Some(quoted) = &quoted {
+        reader_bytes = quoted;
+    }
 
     for _ in 0..reader_bytes.len() {
         if reader_bytes[0] != eol_char {
@@ -79,6 +94,7 @@ pub fn count_rows(
         separator,
         quote_char,
         eol_char,
+        escape_char.is_some(),
     );
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
@@ -132,6 +148,31 @@ pub(super) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
     Some(pos)
 }
 
+/// Find the position after the first end-of-line character at or after `offset` that is not
+/// embedded in a quoted field. Unlike [`next_line_position`] this scans `input` from its start,
+/// which must be the start of a line, so the position is exact if every quote character in
+/// `input` quotes a field, e.g. after escaped fields have been quoted.
+pub(super) fn next_unquoted_line_position(
+    input: &[u8],
+    offset: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> Option<usize> {
+    let Some(quote_char) = quote_char else {
+        return next_line_position_naive(input.get(offset..)?, eol_char).map(|pos| offset + pos);
+    };
+    let mut in_quotes = memchr::memchr_iter(quote_char, input.get(..offset)?).count() % 2 == 1;
+    for pos in memchr2_iter(quote_char, eol_char, &input[offset..]) {
+        let pos = offset + pos;
+        if input[pos] == quote_char {
+            in_quotes = !in_quotes;
+        } else if !in_quotes {
+            return (pos + 1 < input.len()).then_some(pos + 1);
+        }
+    }
+    None
+}
+
 /// Find the nearest next line position that is not embedded in a String field.
 pub(super) fn next_line_position(
     mut input: &[u8],
@@ -421,6 +462,67 @@ pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &
     }
 }
 
+/// A row that failed to parse with [`CsvOnError::Skip`] or [`CsvOnError::Capture`].
+///
+/// [`CsvOnError::Skip`]: super::CsvOnError::Skip
+/// [`CsvOnError::Capture`]: super::CsvOnError::Capture
+pub(super) struct BadRow {
+    /// Address of the first byte of the line, from which its line number is determined.
+    pub(super) address: usize,
+    pub(super) line: String,
+    pub(super) error: String,
+}
+
+/// The rows that failed to parse into a set of buffers. These rows are filled with nulls in the
+/// buffers and removed once the buffers are turned into a [`DataFrame`].
+#[derive(Default)]
+pub(super) struct BadRows {
+    /// Number of rows written to the buffers.
+    n_rows: usize,
+    /// Index of each bad row in the buffers.
+    indices: Vec<usize>,
+    rows: Vec<BadRow>,
+}
+
+impl BadRows {
+    fn push(&mut self, mut line: &[u8], rest: &[u8], eol_char: u8, error: PolarsError) {
+        // `rest` is empty and not part of `line` at the end of the input.
+        if !rest.is_empty() {
+            line = &line[..rest.as_ptr() as usize - line.as_ptr() as usize];
+        }
+        if line.last() == Some(&eol_char) {
+            line = &line[..line.len() - 1];
+        }
+        if line.last() == Some(&b'\r') {
+            line = &line[..line.len() - 1];
+        }
+        self.indices.push(self.n_rows);
+        self.rows.push(BadRow {
+            address: line.as_ptr() as usize,
+            line: String::from_utf8_lossy(line).into_owned(),
+            error: error.to_string(),
+        });
+    }
+
+    /// Removes the bad rows from `df`, which holds the rows of the buffers, and moves them to
+    /// `out`.
+    pub(super) fn remove_from(
+        self,
+        df: DataFrame,
+        out: &mut Vec<BadRow>,
+    ) -> PolarsResult<DataFrame> {
+        if self.indices.is_empty() {
+            return Ok(df);
+        }
+        let mut mask = vec![true; self.n_rows];
+        for i in self.indices {
+            mask[i] = false;
+        }
+        out.extend(self.rows);
+        df.filter(&BooleanChunked::from_slice(PlSmallStr::EMPTY, &mask))
+    }
+}
+
 /// Parse CSV.
 ///
 /// # Arguments
@@ -430,6 +532,7 @@ pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `bad_rows` - If set, rows that fail to parse are collected here instead of raising an error.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn parse_lines(
     mut bytes: &[u8],
@@ -448,6 +551,7 @@ pub(super) fn parse_lines(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    mut bad_rows: Option<&mut BadRows>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
@@ -481,6 +585,8 @@ pub(super) fn parse_lines(
             continue;
         }
 
+        let line = bytes;
+
         // Every line we only need to parse the columns that are projected.
         // Therefore we check if the idx of the field is in our projected columns.
         // If it is not, we skip the field.
@@ -538,7 +644,8 @@ pub(super) fn parse_lines(
                         if add_null {
                             buf.add_null(!missing_is_null && field.is_empty())
                         } else {
-                            buf.add(field, ignore_errors, needs_escaping, missing_is_null)
+                            let result = buf
+                                .add(field, ignore_errors, needs_escaping, missing_is_null)
                                 .map_err(|e| {
                                     let bytes_offset = offset + field.as_ptr() as usize - start;
                                     let unparsable = String::from_utf8_lossy(field);
@@ -562,7 +669,19 @@ pub(super) fn parse_lines(
                                         &unparsable,
                                         e
                                     )
-                                })?;
+                                });
+                            if let Err(err) = result {
+                                let Some(bad_rows) = bad_rows.as_deref_mut() else {
+                                    return Err(err);
+                                };
+                                // Fill the rest of the row with nulls and skip the line.
+                                buf.add_null(false);
+                                processed_fields += 1;
+                                let rest = bytes.get(read_sol - 1..).unwrap_or_default();
+                                bytes = skip_this_line(rest, quote_char, eol_char);
+                                bad_rows.push(line, bytes, eol_char, err);
+                                break;
+                            }
                         }
                         processed_fields += 1;
 
@@ -573,10 +692,14 @@ pub(super) fn parse_lines(
                                 if bytes.get(read_sol - 1) == Some(&eol_char) {
                                     bytes = &bytes[read_sol..];
                                 } else {
-                                    if !truncate_ragged_lines && read_sol < bytes.len() {
-                                        polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
+                                    let ragged = !truncate_ragged_lines && read_sol < bytes.len();
+                                    let ragged_err = || {
+                                        polars_err!(ComputeError: r#"found more fields than defined in 'Schema'
 
 Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
+                                    };
+                                    if ragged && bad_rows.is_none() {
+                                        return Err(ragged_err());
                                     }
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked_release(read_sol - 1..) },
@@ -584,6 +707,11 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
                                         eol_char,
                                     );
                                     bytes = bytes_rem;
+                                    if let (true, Some(bad_rows)) =
+                                        (ragged, bad_rows.as_deref_mut())
+                                    {
+                                        bad_rows.push(line, bytes, eol_char, ragged_err());
+                                    }
                                 }
                                 break;
                             },
@@ -607,6 +735,9 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
             processed_fields += 1;
         }
         line_count += 1;
+        if let Some(bad_rows) = bad_rows.as_deref_mut() {
+            bad_rows.n_rows += 1;
+        }
     }
 }
 
@@ -12,15 +12,15 @@ use polars_utils::flatten;
 use rayon::prelude::*;
 
 use super::buffer::init_buffers;
-use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
+use super::options::{CommentPrefix, CsvEncoding, CsvOnError, NullValues, NullValuesCompiled};
 use super::parser::{
     get_line_stats, is_comment_line, next_line_position, next_line_position_naive, parse_lines,
-    skip_bom, skip_line_ending, skip_this_line,
+    skip_bom, skip_line_ending, skip_this_line, BadRow, BadRows,
 };
-use super::schema_inference::{check_decimal_comma, infer_file_schema};
+use super::schema_inference::{apply_datetime_formats, check_decimal_comma, infer_file_schema};
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::utils::decompress;
-use super::utils::get_file_chunks;
+use super::utils::{get_file_chunks, normalize_fields};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
@@ -120,12 +120,22 @@ pub(crate) struct CoreReader<'a> {
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
     eol_char: u8,
+    /// Fields with escapes have been quoted, so lines can be split by tracking the quotes.
+    escape_char: Option<u8>,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
+    /// Formats of the `Date` and `Datetime` columns that are parsed with a fixed format.
+    datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
     row_index: Option<RowIndex>,
     truncate_ragged_lines: bool,
+    on_error: CsvOnError,
+    /// The rows that failed to parse with [`CsvOnError::Skip`] or [`CsvOnError::Capture`].
+    bad_rows: Vec<BadRow>,
+    /// The columns to output, including the row index, if the projection is applied after
+    /// parsing all columns.
+    output_columns: Option<Vec<PlSmallStr>>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -155,12 +165,15 @@ impl<'a> CoreReader<'a> {
         mut n_threads: Option<usize>,
         schema_overwrite: Option<SchemaRef>,
         dtype_overwrite: Option<Arc<Vec<DataType>>>,
+        datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
         eol_char: u8,
+        escape_char: Option<u8>,
+        multi_byte_separator: Option<PlSmallStr>,
         null_values: Option<NullValues>,
         missing_is_null: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
@@ -171,11 +184,11 @@ impl<'a> CoreReader<'a> {
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
         decimal_comma: bool,
+        on_error: CsvOnError,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = separator.unwrap_or(b',');
 
         check_decimal_comma(decimal_comma, separator)?;
-        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
 
         #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
@@ -198,11 +211,22 @@ impl<'a> CoreReader<'a> {
                 reader_bytes = ReaderBytes::Owned(b);
             }
         }
+        if let Some(b) = normalize_fields(
+            &reader_bytes,
+            separator,
+            quote_char,
+            eol_char,
+            escape_char,
+            multi_byte_separator.as_deref().map(str::as_bytes),
+            comment_prefix.as_ref(),
+        )? {
+            reader_bytes = ReaderBytes::Owned(b);
+        }
 
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
-                let (inferred_schema, _, _) = infer_file_schema(
+                let (mut inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
                     separator,
                     max_records,
@@ -219,6 +243,11 @@ impl<'a> CoreReader<'a> {
                     &mut n_threads,
                     decimal_comma,
                 )?;
+                apply_datetime_formats(
+                    &mut inferred_schema,
+                    &datetime_formats,
+                    schema_overwrite.as_deref(),
+                )?;
                 Arc::new(inferred_schema)
             },
         };
@@ -241,6 +270,26 @@ impl<'a> CoreReader<'a> {
             projection = Some(prj);
         }
 
+        // A row fails to parse if any of its fields does, so all fields are parsed to find the
+        // bad rows and the projection is applied afterwards.
+        let mut output_columns = None;
+        if on_error != CsvOnError::Raise {
+            if let Some(mut projection) = projection.take() {
+                projection.sort_unstable();
+                let columns = projection
+                    .iter()
+                    .map(|&i| {
+                        schema
+                            .get_at_index(i)
+                            .map(|(name, _)| name.clone())
+                            .ok_or_else(|| polars_err!(oob = i, schema.len()))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let row_index = row_index.as_ref().map(|row_index| row_index.name.clone());
+                output_columns = Some(row_index.into_iter().chain(columns).collect());
+            }
+        }
+
         Ok(CoreReader {
             reader_bytes: Some(reader_bytes),
             schema,
@@ -260,16 +309,75 @@ impl<'a> CoreReader<'a> {
             comment_prefix,
             quote_char,
             eol_char,
+            escape_char,
             null_values,
             missing_is_null,
             predicate,
             to_cast,
+            datetime_formats,
             row_index,
             truncate_ragged_lines,
             decimal_comma,
+            on_error,
+            bad_rows: vec![],
+            output_columns,
         })
     }
 
+    /// Selects the projected columns if all columns were parsed to find the bad rows.
+    pub(super) fn select_output_columns(
+        output_columns: Option<&[PlSmallStr]>,
+        df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        match output_columns {
+            Some(columns) => df.select(columns.iter().cloned()),
+            None => Ok(df),
+        }
+    }
+
+    /// Collects the rows that fail to parse instead of raising an error, unless the mode is
+    /// [`CsvOnError::Raise`].
+    fn bad_rows_sink(&self) -> Option<BadRows> {
+        (self.on_error != CsvOnError::Raise).then(BadRows::default)
+    }
+
+    /// Turns the collected bad rows into a [`DataFrame`] with their line numbers in `bytes`, which
+    /// holds the parsed lines.
+    fn take_bad_rows(&mut self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+        let mut bad_rows = std::mem::take(&mut self.bad_rows);
+        bad_rows.sort_unstable_by_key(|row| row.address);
+
+        let start = bytes.as_ptr() as usize;
+        let mut eols = memchr::memchr_iter(self.eol_char, bytes).peekable();
+        let mut line_number: IdxSize = 1;
+        let mut line_numbers = Vec::with_capacity(bad_rows.len());
+        for row in &bad_rows {
+            let offset = row.address - start;
+            while eols.next_if(|&pos| pos < offset).is_some() {
+                line_number += 1;
+            }
+            line_numbers.push(line_number);
+        }
+
+        DataFrame::new(vec![
+            Series::new(PlSmallStr::from_static("line_number"), line_numbers),
+            Series::new(
+                PlSmallStr::from_static("line"),
+                bad_rows
+                    .iter()
+                    .map(|row| row.line.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                PlSmallStr::from_static("error"),
+                bad_rows
+                    .iter()
+                    .map(|row| row.error.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+        ])
+    }
+
     fn find_starting_point<'b>(
         &self,
         mut bytes: &'b [u8],
@@ -434,6 +542,7 @@ impl<'a> CoreReader<'a> {
             self.separator,
             self.quote_char,
             self.eol_char,
+            self.escape_char.is_some(),
         );
 
         if logging {
@@ -515,6 +624,7 @@ impl<'a> CoreReader<'a> {
 
                         let mut read = bytes_offset_thread;
                         let mut dfs = Vec::with_capacity(256);
+                        let mut bad_rows = vec![];
                         let mut last_read = usize::MAX;
                         loop {
                             if read >= stop_at_nbytes || read == last_read {
@@ -528,9 +638,11 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.decimal_comma,
+                                &self.datetime_formats,
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
+                            let mut sink = self.bad_rows_sink();
 
                             last_read = read;
                             let offset = read + starting_point_offset.unwrap();
@@ -550,6 +662,7 @@ impl<'a> CoreReader<'a> {
                                 chunk_size,
                                 self.schema.len(),
                                 &self.schema,
+                                sink.as_mut(),
                             )?;
 
                             let columns = buffers
@@ -557,6 +670,9 @@ impl<'a> CoreReader<'a> {
                                 .map(|buf| buf.into_series())
                                 .collect::<PolarsResult<_>>()?;
                             let mut local_df = unsafe { DataFrame::new_no_checks(columns) };
+                            if let Some(sink) = sink {
+                                local_df = sink.remove_from(local_df, &mut bad_rows)?;
+                            }
                             let current_row_count = local_df.height() as IdxSize;
                             if let Some(rc) = &self.row_index {
                                 local_df.with_row_index_mut(rc.name.clone(), Some(rc.offset));
@@ -569,10 +685,12 @@ impl<'a> CoreReader<'a> {
 
                             dfs.push((local_df, current_row_count));
                         }
-                        Ok(dfs)
+                        Ok((dfs, bad_rows))
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            let (dfs, bad_rows): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            self.bad_rows.extend(bad_rows.into_iter().flatten());
             let mut dfs = flatten(&dfs, None);
             if self.row_index.is_some() {
                 update_row_counts(&mut dfs, 0)
@@ -590,10 +708,11 @@ impl<'a> CoreReader<'a> {
                 std::cmp::min(rows_per_thread, max_proxy)
             };
 
-            let mut dfs = POOL.install(|| {
+            let dfs = POOL.install(|| {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
+                        let mut bad_rows = vec![];
                         let mut df = read_chunk(
                             bytes,
                             self.separator,
@@ -613,6 +732,8 @@ impl<'a> CoreReader<'a> {
                             stop_at_nbytes,
                             starting_point_offset,
                             self.decimal_comma,
+                            &self.datetime_formats,
+                            (self.on_error != CsvOnError::Raise).then_some(&mut bad_rows),
                         )?;
 
                         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -620,10 +741,12 @@ impl<'a> CoreReader<'a> {
                             df.with_row_index_mut(rc.name.clone(), Some(rc.offset));
                         }
                         let n_read = df.height() as IdxSize;
-                        Ok((df, n_read))
+                        Ok(((df, n_read), bad_rows))
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            let (mut dfs, bad_rows): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            self.bad_rows.extend(bad_rows.into_iter().flatten());
             if let (Some(n_rows), Some(remaining_bytes)) = (self.n_rows, remaining_bytes) {
                 let rows_already_read: usize = dfs.iter().map(|x| x.1 as usize).sum();
                 if rows_already_read < n_rows {
@@ -637,7 +760,9 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.decimal_comma,
+                                &self.datetime_formats,
                             )?;
+                            let mut sink = self.bad_rows_sink();
 
                             parse_lines(
                                 remaining_bytes,
@@ -655,13 +780,18 @@ impl<'a> CoreReader<'a> {
                                 remaining_rows - 1,
                                 self.schema.len(),
                                 self.schema.as_ref(),
+                                sink.as_mut(),
                             )?;
 
                             let columns = buffers
                                 .into_iter()
                                 .map(|buf| buf.into_series())
                                 .collect::<PolarsResult<_>>()?;
-                            unsafe { DataFrame::new_no_checks(columns) }
+                            let df = unsafe { DataFrame::new_no_checks(columns) };
+                            match sink {
+                                Some(sink) => sink.remove_from(df, &mut self.bad_rows)?,
+                                None => df,
+                            }
                         };
 
                         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -681,13 +811,17 @@ impl<'a> CoreReader<'a> {
     }
 
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
-    pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
+    ///
+    /// Also returns a DataFrame with the line number, line and error of the rows that failed to
+    /// parse, which is only non-empty with [`CsvOnError::Capture`].
+    pub fn as_df_with_bad_rows(&mut self) -> PolarsResult<(DataFrame, DataFrame)> {
         let predicate = self.predicate.take();
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
         let reader_bytes = self.reader_bytes.take().unwrap();
 
-        let mut df = self.parse_csv(n_threads, &reader_bytes, predicate.as_ref())?;
+        let df = self.parse_csv(n_threads, &reader_bytes, predicate.as_ref())?;
+        let mut df = Self::select_output_columns(self.output_columns.as_deref(), df)?;
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -696,7 +830,8 @@ impl<'a> CoreReader<'a> {
                 df = df.slice(0, n_rows)
             }
         }
-        Ok(df)
+        let bad_rows = self.take_bad_rows(&reader_bytes)?;
+        Ok((df, bad_rows))
     }
 }
 
@@ -720,8 +855,11 @@ fn read_chunk(
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
     decimal_comma: bool,
+    datetime_formats: &[(PlSmallStr, PlSmallStr)],
+    bad_rows: Option<&mut Vec<BadRow>>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
+    let mut sink = bad_rows.is_some().then(BadRows::default);
     // There's an off-by-one error somewhere in the reading code, where it reads
     // one more item than the requested capacity. Given the batch sizes are
     // approximate (sometimes they're smaller), this isn't broken, but it does
@@ -734,6 +872,7 @@ fn read_chunk(
         quote_char,
         encoding,
         decimal_comma,
+        datetime_formats,
     )?;
 
     let mut last_read = usize::MAX;
@@ -761,6 +900,7 @@ fn read_chunk(
             chunk_size,
             schema.len(),
             schema,
+            sink.as_mut(),
         )?;
     }
 
@@ -768,5 +908,9 @@ fn read_chunk(
         .into_iter()
         .map(|buf| buf.into_series())
         .collect::<PolarsResult<_>>()?;
-    Ok(unsafe { DataFrame::new_no_checks(columns) })
+    let df = unsafe { DataFrame::new_no_checks(columns) };
+    match (sink, bad_rows) {
+        (Some(sink), Some(bad_rows)) => sink.remove_from(df, bad_rows),
+        _ => Ok(df),
+    }
 }
@@ -5,13 +5,14 @@ use polars_core::datatypes::Field;
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_core::POOL;
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::{cast_columns, read_chunk, CoreReader};
-use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
-use crate::csv::read::parser::next_line_position;
+use crate::csv::read::options::{CommentPrefix, CsvEncoding, CsvOnError, NullValuesCompiled};
+use crate::csv::read::parser::{next_line_position, next_unquoted_line_position};
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
@@ -28,6 +29,7 @@ pub(crate) fn get_file_chunks_iterator(
     separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
+    exact: bool,
 ) {
     for _ in 0..n_chunks {
         let search_pos = *last_pos + chunk_size;
@@ -36,17 +38,21 @@ pub(crate) fn get_file_chunks_iterator(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
+        let end_pos = if exact {
+            next_unquoted_line_position(&bytes[*last_pos..], chunk_size, quote_char, eol_char)
+                .map(|pos| *last_pos + pos)
+        } else {
+            next_line_position(
+                &bytes[search_pos..],
+                Some(expected_fields),
+                separator,
+                quote_char,
+                eol_char,
+            )
+            .map(|pos| search_pos + pos)
+        };
+        let Some(end_pos) = end_pos else {
+            break;
         };
         offsets.push_back((*last_pos, end_pos));
         *last_pos = end_pos;
@@ -64,6 +70,8 @@ struct ChunkOffsetIter<'a> {
     separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
+    /// Split at the exact line ends, see [`get_file_chunks_iterator`].
+    exact: bool,
 }
 
 impl<'a> Iterator for ChunkOffsetIter<'a> {
@@ -99,6 +107,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.separator,
                     self.quote_char,
                     self.eol_char,
+                    self.exact,
                 );
                 match self.offsets.pop_front() {
                     Some(offsets) => Some(offsets),
@@ -117,6 +126,10 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
 impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses mmap to load data.
     pub fn batched(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReader<'a>> {
+        polars_ensure!(
+            self.on_error != CsvOnError::Capture,
+            InvalidOperation: "the batched CSV reader cannot capture malformed rows, use `CsvOnError::Skip` instead"
+        );
         let reader_bytes = self.reader_bytes.take().unwrap();
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
@@ -139,6 +152,7 @@ impl<'a> CoreReader<'a> {
             separator: self.separator,
             quote_char: self.quote_char,
             eol_char: self.eol_char,
+            exact: self.escape_char.is_some(),
         };
 
         let projection = self.get_projection()?;
@@ -177,6 +191,9 @@ impl<'a> CoreReader<'a> {
             rows_read: 0,
             _cat_lock,
             decimal_comma: self.decimal_comma,
+            datetime_formats: self.datetime_formats,
+            skip_bad_rows: self.on_error == CsvOnError::Skip,
+            output_columns: self.output_columns,
         })
     }
 }
@@ -207,6 +224,11 @@ pub struct BatchedCsvReader<'a> {
     #[cfg(not(feature = "dtype-categorical"))]
     _cat_lock: Option<u8>,
     decimal_comma: bool,
+    datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
+    /// Leave out the rows that fail to parse, see [`CsvOnError::Skip`].
+    skip_bad_rows: bool,
+    /// See `CoreReader::output_columns`.
+    output_columns: Option<Vec<PlSmallStr>>,
 }
 
 impl<'a> BatchedCsvReader<'a> {
@@ -234,6 +256,7 @@ impl<'a> BatchedCsvReader<'a> {
                 .into_par_iter()
                 .copied()
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
+                    let mut bad_rows = vec![];
                     let mut df = read_chunk(
                         bytes,
                         self.separator,
@@ -253,6 +276,8 @@ impl<'a> BatchedCsvReader<'a> {
                         stop_at_nbytes,
                         self.starting_point_offset,
                         self.decimal_comma,
+                        &self.datetime_formats,
+                        self.skip_bad_rows.then_some(&mut bad_rows),
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -260,7 +285,7 @@ impl<'a> BatchedCsvReader<'a> {
                     if let Some(rc) = &self.row_index {
                         df.with_row_index_mut(rc.name.clone(), Some(rc.offset));
                     }
-                    Ok(df)
+                    CoreReader::select_output_columns(self.output_columns.as_deref(), df)
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
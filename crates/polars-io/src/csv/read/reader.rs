@@ -7,15 +7,16 @@ use polars_time::prelude::*;
 #[cfg(feature = "temporal")]
 use rayon::prelude::*;
 
-use super::options::CsvReadOptions;
+use super::options::{CsvOnError, CsvReadOptions};
 use super::read_impl::batched::to_batched_owned;
 use super::read_impl::CoreReader;
-use super::{infer_file_schema, BatchedCsvReader, OwnedBatchedCsvReader};
-use crate::mmap::MmapBytesReader;
+use super::schema_inference::{apply_datetime_formats, CsvSchemaInference};
+use super::{infer_file_schema, normalize_fields, BatchedCsvReader, OwnedBatchedCsvReader};
+use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::path_utils::resolve_homedir;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::SerReader;
-use crate::utils::get_reader_bytes;
+use crate::utils::{get_reader_bytes, maybe_decompress_bytes};
 
 /// Create a new DataFrame by reading a csv file.
 ///
@@ -134,12 +135,15 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.n_threads,
             self.options.schema_overwrite.clone(),
             self.options.dtype_overwrite.clone(),
+            self.options.datetime_formats.clone(),
             self.options.sample_size,
             self.options.chunk_size,
             self.options.low_memory,
             parse_options.comment_prefix.clone(),
             parse_options.quote_char,
             parse_options.eol_char,
+            parse_options.escape_char,
+            parse_options.multi_byte_separator.clone(),
             parse_options.null_values.clone(),
             parse_options.missing_is_null,
             self.predicate.clone(),
@@ -150,6 +154,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.raise_if_empty,
             parse_options.truncate_ragged_lines,
             parse_options.decimal_comma,
+            self.options.on_error,
         )
     }
 
@@ -205,6 +210,17 @@ impl<R: MmapBytesReader> CsvReader<R> {
         Ok(_has_categorical)
     }
 
+    /// Infers the schema without parsing the data, see [`CsvReadOptions::infer_schema_only`].
+    pub fn infer_schema_only(mut self) -> PolarsResult<CsvSchemaInference> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut decompressed = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut decompressed)?;
+        CsvSchemaInference::try_from_reader_bytes_and_options(
+            &ReaderBytes::Borrowed(bytes),
+            &self.options,
+        )
+    }
+
     pub fn batched_borrowed(&mut self) -> PolarsResult<BatchedCsvReader> {
         let has_cat = match self.options.schema_overwrite.as_deref() {
             Some(_) => self.prepare_schema()?,
@@ -222,9 +238,23 @@ impl CsvReader<Box<dyn MmapBytesReader>> {
             Some(schema) => Ok(to_batched_owned(self.with_schema(schema))),
             None => {
                 let parse_options = self.options.get_parse_options();
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                let mut reader_bytes = get_reader_bytes(&mut self.reader)?;
+                if let Some(bytes) = normalize_fields(
+                    &reader_bytes,
+                    parse_options.separator,
+                    parse_options.quote_char,
+                    parse_options.eol_char,
+                    parse_options.escape_char,
+                    parse_options
+                        .multi_byte_separator
+                        .as_deref()
+                        .map(str::as_bytes),
+                    parse_options.comment_prefix.as_ref(),
+                )? {
+                    reader_bytes = ReaderBytes::Owned(bytes);
+                }
 
-                let (inferred_schema, _, _) = infer_file_schema(
+                let (mut inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
                     parse_options.separator,
                     self.options.infer_schema_length,
@@ -241,6 +271,7 @@ impl CsvReader<Box<dyn MmapBytesReader>> {
                     &mut self.options.n_threads,
                     parse_options.decimal_comma,
                 )?;
+                apply_datetime_formats(&mut inferred_schema, &self.options.datetime_formats, None)?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned(self.with_schema(schema)))
             },
@@ -264,7 +295,24 @@ where
     }
 
     /// Read the file and create the DataFrame.
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    fn finish(self) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.options.on_error != CsvOnError::Capture,
+            InvalidOperation: "capturing malformed CSV rows requires `CsvReader::finish_with_bad_rows`"
+        );
+        self.finish_impl().map(|(df, _)| df)
+    }
+}
+
+impl<R: MmapBytesReader> CsvReader<R> {
+    /// Read the file and create the DataFrame, together with a DataFrame of the rows that failed
+    /// to parse with [`CsvOnError::Capture`]. This has the columns `line_number`, the 1-based
+    /// line in the file, `line`, the line without its end-of-line character, and `error`.
+    pub fn finish_with_bad_rows(self) -> PolarsResult<(DataFrame, DataFrame)> {
+        self.finish_impl()
+    }
+
+    fn finish_impl(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
         let rechunk = self.options.rechunk;
         let schema_overwrite = self.options.schema_overwrite.clone();
         let low_memory = self.options.low_memory;
@@ -279,7 +327,7 @@ where
         };
 
         let mut csv_reader = self.core_reader()?;
-        let mut df = csv_reader.as_df()?;
+        let (mut df, bad_rows) = csv_reader.as_df_with_bad_rows()?;
 
         // Important that this rechunk is never done in parallel.
         // As that leads to great memory overhead.
@@ -315,7 +363,7 @@ where
             }
         }
 
-        Ok(df)
+        Ok((df, bad_rows))
     }
 }
 
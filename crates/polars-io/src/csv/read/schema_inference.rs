@@ -12,6 +12,7 @@ use polars_utils::slice::GetSaferUnchecked;
 use super::options::{CommentPrefix, CsvEncoding, NullValues};
 use super::parser::{is_comment_line, skip_bom, skip_line_ending, SplitLines};
 use super::splitfields::SplitFields;
+use super::utils::normalize_fields;
 use super::CsvReadOptions;
 use crate::mmap::ReaderBytes;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
@@ -49,10 +50,23 @@ impl SchemaInferenceResult {
         let decimal_comma = parse_options.decimal_comma;
 
         let bytes_total = reader_bytes.len();
-
-        let (inferred_schema, rows_read, bytes_read) = infer_file_schema(
+        let quoted = normalize_fields(
             reader_bytes,
             separator,
+            quote_char,
+            eol_char,
+            parse_options.escape_char,
+            parse_options
+                .multi_byte_separator
+                .as_deref()
+                .map(str::as_bytes),
+            comment_prefix,
+        )?
+        .map(ReaderBytes::Owned);
+
+        let (mut inferred_schema, rows_read, bytes_read) = infer_file_schema(
+            quoted.as_ref().unwrap_or(reader_bytes),
+            separator,
             infer_schema_length,
             has_header,
             schema_overwrite,
@@ -67,6 +81,11 @@ impl SchemaInferenceResult {
             &mut n_threads,
             decimal_comma,
         )?;
+        apply_datetime_formats(
+            &mut inferred_schema,
+            &options.datetime_formats,
+            schema_overwrite,
+        )?;
 
         let this = Self {
             inferred_schema: Arc::new(inferred_schema),
@@ -93,7 +112,131 @@ impl SchemaInferenceResult {
     }
 }
 
+/// The schema of a CSV file with a report of how the dtype of each column was inferred, see
+/// [`CsvReadOptions::infer_schema_only`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvSchemaInference {
+    pub schema: SchemaRef,
+    /// The report of each column of `schema`.
+    pub columns: Vec<ColumnInference>,
+    /// Number of rows read to infer the schema.
+    pub rows_read: usize,
+}
+
+/// How the dtype of a column of a CSV file was inferred.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInference {
+    pub name: PlSmallStr,
+    pub dtype: DataType,
+    /// Whether `dtype` is set by the schema overwrite or a datetime format instead of inferred.
+    pub overwritten: bool,
+    /// The dtypes the values of the column were inferred as, in the order in which they were
+    /// found. If there are several, the column is a `String`, unless they are `Int64` and
+    /// `Float64`.
+    pub samples: Vec<InferenceSample>,
+    /// Number of empty, missing and null values, which are not used for the inference.
+    pub null_count: usize,
+}
+
+impl ColumnInference {
+    /// The samples of dtypes that conflict with each other, which made the column a `String`.
+    pub fn conflicts(&self) -> &[InferenceSample] {
+        if !self.overwritten && self.samples.len() > 1 && self.dtype == DataType::String {
+            &self.samples
+        } else {
+            &[]
+        }
+    }
+}
+
+/// The first value of a column that was inferred as `dtype`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InferenceSample {
+    pub dtype: DataType,
+    pub value: String,
+    /// The 1-based line of `value` in the file.
+    pub line_number: usize,
+    /// Number of values of the column that were inferred as `dtype`.
+    pub count: usize,
+}
+
+impl CsvSchemaInference {
+    pub(super) fn try_from_reader_bytes_and_options(
+        reader_bytes: &ReaderBytes,
+        options: &CsvReadOptions,
+    ) -> PolarsResult<Self> {
+        let parse_options = options.get_parse_options();
+        check_decimal_comma(parse_options.decimal_comma, parse_options.separator)?;
+        let quoted = normalize_fields(
+            reader_bytes,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+            parse_options.escape_char,
+            parse_options
+                .multi_byte_separator
+                .as_deref()
+                .map(str::as_bytes),
+            parse_options.comment_prefix.as_ref(),
+        )?
+        .map(ReaderBytes::Owned);
+
+        let mut columns = vec![];
+        let (mut schema, rows_read, _) = infer_file_schema_inner(
+            quoted.as_ref().unwrap_or(reader_bytes),
+            parse_options.separator,
+            options.infer_schema_length,
+            options.has_header,
+            options.schema_overwrite.as_deref(),
+            options.skip_rows,
+            options.skip_rows_after_header,
+            parse_options.comment_prefix.as_ref(),
+            parse_options.quote_char,
+            parse_options.eol_char,
+            parse_options.null_values.as_ref(),
+            parse_options.try_parse_dates,
+            0,
+            options.raise_if_empty,
+            &mut None,
+            parse_options.decimal_comma,
+            Some(&mut columns),
+        )?;
+        apply_datetime_formats(
+            &mut schema,
+            &options.datetime_formats,
+            options.schema_overwrite.as_deref(),
+        )?;
+        for column in columns.iter_mut() {
+            if let Some(dtype) = schema.get(&column.name) {
+                if dtype != &column.dtype {
+                    column.dtype = dtype.clone();
+                    column.overwritten = true;
+                }
+            }
+        }
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            columns,
+            rows_read,
+        })
+    }
+}
+
 impl CsvReadOptions {
+    /// Infers the schema of the file at `path` without parsing its data, and reports how the
+    /// dtype of each column was inferred: the first value of each dtype that was found and
+    /// the values that conflict. This reads `infer_schema_length` rows and uses the
+    /// `schema_overwrite`, but not the `schema`.
+    pub fn infer_schema_only(self) -> PolarsResult<CsvSchemaInference> {
+        polars_ensure!(
+            self.path.is_some(),
+            InvalidOperation: "inferring the schema of a CSV file requires a path, use `CsvReader::infer_schema_only` for other sources"
+        );
+        self.try_into_reader_with_file_path(None)?
+            .infer_schema_only()
+    }
+
     /// Note: This does not update the schema from the inference result.
     pub fn update_with_inference_result(&mut self, si_result: &SchemaInferenceResult) {
         self.n_threads = si_result.n_threads;
@@ -211,6 +354,7 @@ fn infer_file_schema_inner(
     raise_if_empty: bool,
     n_threads: &mut Option<usize>,
     decimal_comma: bool,
+    report: Option<&mut Vec<ColumnInference>>,
 ) -> PolarsResult<(Schema, usize, usize)> {
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
@@ -312,6 +456,7 @@ fn infer_file_schema_inner(
             raise_if_empty,
             n_threads,
             decimal_comma,
+            report,
         );
     } else if !raise_if_empty {
         return Ok((Schema::default(), 0, 0));
@@ -329,6 +474,12 @@ fn infer_file_schema_inner(
         vec![PlHashSet::with_capacity(4); header_length];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; header_length];
+    // the first value of each inferred type and the line numbers, if a report is requested
+    let mut samples: Option<Vec<Vec<InferenceSample>>> =
+        report.is_some().then(|| vec![vec![]; header_length]);
+    let mut null_counts = vec![0usize; header_length];
+    let mut line_number = 1;
+    let mut counted_to = 0;
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(header_length);
@@ -356,6 +507,12 @@ fn infer_file_schema_inner(
         rows_count += 1;
         // keep track so that we can determine the amount of bytes read
         end_ptr = line.as_ptr() as usize + line.len();
+        if samples.is_some() {
+            let line_offset = line.as_ptr() as usize - start_ptr;
+            line_number +=
+                memchr::memchr_iter(eol_char, &reader_bytes[counted_to..line_offset]).count();
+            counted_to = line_offset;
+        }
 
         if line.is_empty() {
             continue;
@@ -381,6 +538,7 @@ fn infer_file_schema_inner(
             if let Some((slice, needs_escaping)) = record.next() {
                 if slice.is_empty() {
                     unsafe { *nulls.get_unchecked_release_mut(i) = true };
+                    null_counts[i] += 1;
                 } else {
                     let slice_escaped = if needs_escaping && (slice.len() >= 2) {
                         &slice[1..(slice.len() - 1)]
@@ -438,20 +596,29 @@ fn infer_file_schema_inner(
                                 *n_threads = Some(1);
                             }
                         }
+                        if let Some(samples) = samples.as_mut() {
+                            add_inference_sample(&mut samples[i], &dtype, &s, line_number);
+                        }
                         unsafe { column_types.get_unchecked_release_mut(i).insert(dtype) };
+                    } else {
+                        null_counts[i] += 1;
                     }
                 }
+            } else {
+                null_counts[i] += 1;
             }
         }
     }
 
     // build schema from inference results
+    let mut overwritten = vec![false; header_length];
     for i in 0..header_length {
         let field_name = &headers[i];
 
         if let Some(schema_overwrite) = schema_overwrite {
             if let Some((_, name, dtype)) = schema_overwrite.get_full(field_name) {
                 fields.push(Field::new(name.clone(), dtype.clone()));
+                overwritten[i] = true;
                 continue;
             }
 
@@ -460,6 +627,7 @@ fn infer_file_schema_inner(
             if schema_overwrite.len() == header_length {
                 if let Some((name, dtype)) = schema_overwrite.get_at_index(i) {
                     fields.push(Field::new(name.clone(), dtype.clone()));
+                    overwritten[i] = true;
                     continue;
                 }
             }
@@ -497,12 +665,49 @@ fn infer_file_schema_inner(
             raise_if_empty,
             n_threads,
             decimal_comma,
+            report,
+        );
+    }
+
+    if let (Some(report), Some(samples)) = (report, samples) {
+        report.extend(
+            fields
+                .iter()
+                .zip(overwritten)
+                .zip(samples)
+                .zip(null_counts)
+                .map(
+                    |(((field, overwritten), samples), null_count)| ColumnInference {
+                        name: field.name.clone(),
+                        dtype: field.dtype.clone(),
+                        overwritten,
+                        samples,
+                        null_count,
+                    },
+                ),
         );
     }
 
     Ok((Schema::from_iter(fields), rows_count, end_ptr - start_ptr))
 }
 
+fn add_inference_sample(
+    samples: &mut Vec<InferenceSample>,
+    dtype: &DataType,
+    value: &str,
+    line_number: usize,
+) {
+    match samples.iter_mut().find(|sample| &sample.dtype == dtype) {
+        Some(sample) => sample.count += 1,
+        None => samples.push(InferenceSample {
+            dtype: dtype.clone(),
+            value: value.to_string(),
+            line_number,
+            count: 1,
+        }),
+    }
+}
+
 pub(super) fn check_decimal_comma(decimal_comma: bool, separator: u8) -> PolarsResult<()> {
     if decimal_comma {
         polars_ensure!(b',' != separator, InvalidOperation: "'decimal_comma' argument cannot be combined with ',' separator")
@@ -510,6 +715,30 @@ pub(super) fn check_decimal_comma(decimal_comma: bool, separator: u8) -> PolarsR
     Ok(())
 }
 
+/// Sets the dtype of the columns with a datetime format to the dtype the format parses, unless
+/// the dtype is set by the `schema_overwrite`. See [`CsvReadOptions::with_datetime_formats`].
+pub(super) fn apply_datetime_formats(
+    schema: &mut Schema,
+    datetime_formats: &[(PlSmallStr, PlSmallStr)],
+    schema_overwrite: Option<&Schema>,
+) -> PolarsResult<()> {
+    for (name, _fmt) in datetime_formats {
+        if !schema.contains(name) || schema_overwrite.is_some_and(|s| s.contains(name)) {
+            continue;
+        }
+        #[cfg(feature = "polars-time")]
+        {
+            let dtype = date_infer::format_dtype(_fmt, TimeUnit::Microseconds)?;
+            schema.set_dtype(name, dtype);
+        }
+        #[cfg(not(feature = "polars-time"))]
+        polars_bail!(
+            ComputeError: "activate the 'temporal' feature to parse CSV column '{}' with a datetime format", name
+        );
+    }
+    Ok(())
+}
+
 /// Infer the schema of a CSV file by reading through the first n rows of the file,
 /// with `max_read_rows` controlling the maximum number of rows to read.
 ///
@@ -557,5 +786,6 @@ pub fn infer_file_schema(
         raise_if_empty,
         n_threads,
         decimal_comma,
+        None,
     )
 }
@@ -2,11 +2,16 @@
 use std::io::Read;
 use std::mem::MaybeUninit;
 
-use super::parser::next_line_position;
+use polars_core::prelude::*;
+
+use super::options::CommentPrefix;
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::parser::next_line_position_naive;
+use super::parser::{is_comment_line, next_line_position, next_unquoted_line_position};
 use super::splitfields::SplitFields;
 
+/// Splits `bytes` into about `n_chunks` chunks of whole lines. If `exact` is set, the quotes are
+/// tracked from the start of `bytes` instead of guessing the line ends from the fields.
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
@@ -14,6 +19,7 @@ pub(crate) fn get_file_chunks(
     separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
+    exact: bool,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
     let total_len = bytes.len();
@@ -26,17 +32,21 @@ pub(crate) fn get_file_chunks(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            expected_fields,
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
+        let end_pos = if exact {
+            next_unquoted_line_position(&bytes[last_pos..], chunk_size, quote_char, eol_char)
+                .map(|pos| last_pos + pos)
+        } else {
+            next_line_position(
+                &bytes[search_pos..],
+                expected_fields,
+                separator,
+                quote_char,
+                eol_char,
+            )
+            .map(|pos| search_pos + pos)
+        };
+        let Some(end_pos) = end_pos else {
+            break;
         };
         offsets.push((last_pos, end_pos));
         last_pos = end_pos;
@@ -144,6 +154,139 @@ pub(crate) fn decompress(
     }
 }
 
+/// Rewrites the fields that contain `escape_char` as quoted fields with the escapes removed, so
+/// that escaped separators, quotes and end-of-line characters are read literally and the file can
+/// be split into chunks at unquoted end-of-line characters. Fields without escapes and comment
+/// lines are copied as is.
+///
+/// If a `multi_byte_separator` is given, it is replaced by the single byte `separator`, and fields
+/// that contain `separator` are quoted.
+///
+/// Returns `None` if the bytes can be parsed as they are.
+pub fn normalize_fields(
+    bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    escape_char: Option<u8>,
+    multi_byte_separator: Option<&[u8]>,
+    comment_prefix: Option<&CommentPrefix>,
+) -> PolarsResult<Option<Vec<u8>>> {
+    if let Some(escape) = escape_char {
+        polars_ensure!(
+            quote_char.is_some(),
+            InvalidOperation: "reading CSV with an escape character requires a quote character"
+        );
+        polars_ensure!(
+            Some(escape) != quote_char && escape != separator && escape != eol_char,
+            InvalidOperation: "the CSV escape character must differ from the quote, separator and end-of-line characters"
+        );
+    }
+    if let Some(multi_byte_separator) = multi_byte_separator {
+        polars_ensure!(
+            !multi_byte_separator.is_empty()
+                && !multi_byte_separator.contains(&eol_char)
+                && !quote_char.is_some_and(|quote| multi_byte_separator.contains(&quote))
+                && !escape_char.is_some_and(|escape| multi_byte_separator.contains(&escape)),
+            InvalidOperation: "the CSV separator must not be empty or contain the quote, escape or end-of-line characters"
+        );
+    } else {
+        match escape_char {
+            Some(escape) if memchr::memchr(escape, bytes).is_some() => {},
+            _ => return Ok(None),
+        }
+    }
+
+    let is_separator = |bytes: &[u8]| match multi_byte_separator {
+        Some(multi_byte_separator) => bytes.starts_with(multi_byte_separator),
+        None => bytes[0] == separator,
+    };
+
+    let n = bytes.len();
+    let mut out = Vec::with_capacity(n + n / 16);
+    let mut field = Vec::new();
+    let mut i = 0;
+    let mut line_start = true;
+    while i < n {
+        if line_start && is_comment_line(&bytes[i..], comment_prefix) {
+            let end = memchr::memchr(eol_char, &bytes[i..]).map_or(n, |pos| i + pos + 1);
+            out.extend_from_slice(&bytes[i..end]);
+            i = end;
+            continue;
+        }
+
+        let start = i;
+        let mut needs_quotes = false;
+        let mut in_quotes = false;
+        field.clear();
+        while i < n {
+            let c = bytes[i];
+            if Some(c) == escape_char && i + 1 < n {
+                field.push(bytes[i + 1]);
+                needs_quotes = true;
+                i += 2;
+            } else if Some(c) == quote_char {
+                if in_quotes && bytes.get(i + 1) == Some(&c) {
+                    field.push(c);
+                    i += 2;
+                } else {
+                    in_quotes = !in_quotes;
+                    i += 1;
+                }
+            } else if !in_quotes
+                && (is_separator(&bytes[i..])
+                    || c == eol_char
+                    || (c == b'\r' && bytes.get(i + 1) == Some(&eol_char)))
+            {
+                break;
+            } else {
+                needs_quotes |= c == separator && multi_byte_separator.is_some();
+                field.push(c);
+                i += 1;
+            }
+        }
+
+        if needs_quotes {
+            let Some(quote) = quote_char else {
+                polars_bail!(
+                    InvalidOperation:
+                    "a CSV field contains the byte {:?}, which requires a quote character to read it with a multi-byte separator",
+                    separator as char
+                )
+            };
+            out.push(quote);
+            for &c in &field {
+                if c == quote {
+                    out.push(quote);
+                }
+                out.push(c);
+            }
+            out.push(quote);
+        } else {
+            out.extend_from_slice(&bytes[start..i]);
+        }
+
+        // Copy the separator or end-of-line that ends the field.
+        if i < n {
+            let end = match multi_byte_separator {
+                Some(multi_byte_separator) if bytes[i..].starts_with(multi_byte_separator) => {
+                    i + multi_byte_separator.len()
+                },
+                _ if bytes[i] == b'\r' => i + 2,
+                _ => i + 1,
+            };
+            line_start = !is_separator(&bytes[i..]);
+            if line_start {
+                out.extend_from_slice(&bytes[i..end]);
+            } else {
+                out.push(separator);
+            }
+            i = end;
+        }
+    }
+    Ok(Some(out))
+}
+
 /// replace double quotes by single ones
 ///
 /// This function assumes that bytes is wrapped in the quoting character.
@@ -179,7 +322,7 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
 
 #[cfg(test)]
 mod test {
-    use super::get_file_chunks;
+    use super::{get_file_chunks, normalize_fields};
 
     #[test]
     fn test_get_file_chunks() {
@@ -188,10 +331,44 @@ mod test {
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n', false).len() as i32 - 10).abs()
+                <= 1
         );
         assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n', false).len() as i32 - 8).abs()
+                <= 1
+        );
+    }
+
+    #[test]
+    fn test_normalize_escaped_fields() {
+        let quote = |bytes: &[u8]| {
+            normalize_fields(bytes, b',', Some(b'"'), b'\x1e', Some(b'\\'), None, None).unwrap()
+        };
+        assert_eq!(quote(b"a,b\x1e1,2\x1e"), None);
+        assert_eq!(
+            quote(b"a,b\x1e1\\,2,\"x\"\x1e3\\\x1e4,5\\\"\x1e").as_deref(),
+            Some(&b"a,b\x1e\"1,2\",\"x\"\x1e\"3\x1e4\",\"5\"\"\"\x1e"[..])
+        );
+        // Escapes within quotes are removed too.
+        assert_eq!(
+            quote(b"\"a\\\"b\",c").as_deref(),
+            Some(&b"\"a\"\"b\",c"[..])
+        );
+    }
+
+    #[test]
+    fn test_normalize_multi_byte_separator() {
+        let normalize = |bytes: &[u8], quote_char: Option<u8>| {
+            normalize_fields(bytes, b',', quote_char, b'\n', None, Some(b"||"), None)
+        };
+        assert_eq!(
+            normalize(b"a||b\n1,5||\"x||y\"\n|||2\n", Some(b'"'))
+                .unwrap()
+                .as_deref(),
+            Some(&b"a,b\n\"1,5\",\"x||y\"\n,|2\n"[..])
         );
+        assert!(normalize(b"a||b\n1,5||2\n", None).is_err());
+        assert!(normalize(b"a||b\n", Some(b'|')).is_err());
     }
 }
@@ -12,6 +12,19 @@ pub struct CsvWriterOptions {
     pub batch_size: NonZeroUsize,
     pub maintain_order: bool,
     pub serialize_options: SerializeOptions,
+    /// If set, the streaming sink writes a directory of files of about this many bytes instead
+    /// of a single file. Every file has a header if `include_header` is set.
+    pub target_file_size: Option<usize>,
+    /// If set, the streaming sink writes a directory of files of at most this many rows instead
+    /// of a single file.
+    pub max_rows_per_file: Option<usize>,
+}
+
+impl CsvWriterOptions {
+    /// Whether the streaming sink writes a directory of files instead of a single file.
+    pub fn is_multi_file(&self) -> bool {
+        self.target_file_size.is_some() || self.max_rows_per_file.is_some()
+    }
 }
 
 impl Default for CsvWriterOptions {
@@ -22,6 +35,8 @@ impl Default for CsvWriterOptions {
             batch_size: NonZeroUsize::new(1024).unwrap(),
             maintain_order: false,
             serialize_options: SerializeOptions::default(),
+            target_file_size: None,
+            max_rows_per_file: None,
         }
     }
 }
@@ -44,6 +59,9 @@ pub struct SerializeOptions {
     pub float_precision: Option<usize>,
     /// Used as separator.
     pub separator: u8,
+    /// Used as separator instead of `separator` if set, e.g. `||`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub multi_byte_separator: Option<String>,
     /// Quoting character.
     pub quote_char: u8,
     /// Null value representation.
@@ -52,6 +70,11 @@ pub struct SerializeOptions {
     pub line_terminator: String,
     /// When to insert quotes.
     pub quote_style: QuoteStyle,
+    /// Character written before quote characters and itself in quoted fields, instead of
+    /// doubling the quote character. With [`QuoteStyle::Never`] it is also written before
+    /// separators and line terminators.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub escape_char: Option<u8>,
 }
 
 impl Default for SerializeOptions {
@@ -63,10 +86,12 @@ impl Default for SerializeOptions {
             float_scientific: None,
             float_precision: None,
             separator: b',',
+            multi_byte_separator: None,
             quote_char: b'"',
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
+            escape_char: None,
         }
     }
 }
@@ -39,11 +39,19 @@ pub(crate) fn write<W: Write>(
         );
     }
 
+    let separator = separator(options)?;
+
     // Check that the double quote is valid UTF-8.
     polars_ensure!(
         std::str::from_utf8(&[options.quote_char, options.quote_char]).is_ok(),
         ComputeError: "quote char results in invalid utf-8",
     );
+    if let Some(escape_char) = options.escape_char {
+        polars_ensure!(
+            escape_char.is_ascii(),
+            ComputeError: "escape char results in invalid utf-8",
+        );
+    }
 
     let (datetime_formats, time_zones): (Vec<&str>, Vec<Option<Tz>>) = df
         .get_columns()
@@ -114,6 +122,13 @@ pub(crate) fn write<W: Write>(
         .unzip();
 
     let len = df.height();
+    // Frames with fewer rows than `n_threads * chunk_size` would otherwise be serialized by only a
+    // few threads. Spread their rows over all threads, this matters most for wide frames.
+    let chunk_size = if n_threads > 1 {
+        std::cmp::min(chunk_size, len.div_ceil(n_threads)).max(1)
+    } else {
+        chunk_size
+    };
     let total_rows_per_pool_iter = n_threads * chunk_size;
     let serializer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
     let write_buffer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
@@ -176,7 +191,7 @@ pub(crate) fn write<W: Write>(
             for _ in 0..len {
                 serializers[0].serialize(&mut write_buffer, options);
                 for serializer in &mut serializers[1..] {
-                    write_buffer.push(options.separator);
+                    write_buffer.extend_from_slice(separator);
                     serializer.serialize(&mut write_buffer, options);
                 }
 
@@ -208,12 +223,27 @@ pub(crate) fn write<W: Write>(
     Ok(())
 }
 
+/// The bytes that separate the fields of a row.
+fn separator(options: &SerializeOptions) -> PolarsResult<&[u8]> {
+    match options.multi_byte_separator.as_deref() {
+        Some(separator) => {
+            polars_ensure!(
+                !separator.is_empty(),
+                InvalidOperation: "the CSV separator must not be empty"
+            );
+            Ok(separator.as_bytes())
+        },
+        None => Ok(std::slice::from_ref(&options.separator)),
+    }
+}
+
 /// Writes a CSV header to `writer`.
 pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
 ) -> PolarsResult<()> {
+    let separator = separator(options)?;
     let mut header = Vec::new();
 
     // A hack, but it works for this case.
@@ -227,7 +257,7 @@ pub(crate) fn write_header<W: Write>(
     for i in 0..names.len() {
         names_serializer.serialize(&mut header, options);
         if i != names.len() - 1 {
-            header.push(options.separator);
+            header.extend_from_slice(separator);
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
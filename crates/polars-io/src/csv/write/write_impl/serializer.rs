@@ -23,6 +23,10 @@
 //! we have an option to statically disable it with a const generic flag `QUOTE_NON_NULL`. Numbers (that should never
 //! be quoted with `NonNumeric`) just always disable this flag.
 //!
+//! Inside quoted fields, quotes are escaped by doubling them, or by prefixing them with the escape
+//! character if one is set. With [`QuoteStyle::Never`] the escape character is also written before
+//! separators and line terminators, as these fields cannot be quoted.
+//!
 //! So we have three possibilities:
 //!
 //!  1. A serializer that never quotes. This is a bare serializer with `QUOTE_NON_NULL = false`.
@@ -38,7 +42,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr3, memchr_iter};
+use memchr::{memchr2_iter, memchr3};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 
@@ -375,8 +379,27 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
-    fn serialize_str_escaped(buf: &mut Vec<u8>, s: &[u8], quote_char: u8, quoted: bool) {
-        let mut iter = memchr_iter(quote_char, s);
+    /// The first byte of the separator, a field that contains it must be quoted or escaped.
+    fn separator_byte(options: &SerializeOptions) -> u8 {
+        // A field that contains the first byte of a multi-byte separator could run into the
+        // separator that follows it.
+        options
+            .multi_byte_separator
+            .as_ref()
+            .and_then(|separator| separator.as_bytes().first().copied())
+            .unwrap_or(options.separator)
+    }
+
+    fn serialize_str_escaped(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        quote_char: u8,
+        escape_char: Option<u8>,
+        quoted: bool,
+    ) {
+        // Without an escape character, quotes are escaped by doubling them.
+        let escape_char = escape_char.unwrap_or(quote_char);
+        let mut iter = memchr2_iter(quote_char, escape_char, s);
         let first_quote = iter.next();
         match first_quote {
             None => buf.extend_from_slice(s),
@@ -387,7 +410,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                 let mut start_pos = 0;
                 loop {
                     buf.extend_from_slice(&s[start_pos..quote_pos]);
-                    buf.extend_from_slice(&[quote_char, quote_char]);
+                    buf.extend_from_slice(&[escape_char, s[quote_pos]]);
                     match iter.next() {
                         Some(quote) => {
                             start_pos = quote_pos + 1;
@@ -406,6 +429,25 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
+    /// Writes `s` with the escape character before every byte that would end the field, for
+    /// fields that are never quoted.
+    fn serialize_str_unquoted_escaped(buf: &mut Vec<u8>, s: &[u8], options: &SerializeOptions) {
+        let Some(escape_char) = options.escape_char else {
+            buf.extend_from_slice(s);
+            return;
+        };
+        let separator = separator_byte(options);
+        let mut start_pos = 0;
+        for (pos, &b) in s.iter().enumerate() {
+            if b == separator || b == LF || b == CR || b == options.quote_char || b == escape_char {
+                buf.extend_from_slice(&s[start_pos..pos]);
+                buf.push(escape_char);
+                start_pos = pos;
+            }
+        }
+        buf.extend_from_slice(&s[start_pos..]);
+    }
+
     let iter = update(array);
     match options.quote_style {
         QuoteStyle::Always => {
@@ -418,7 +460,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.push(quote_char);
                         return;
                     };
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, options.escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -436,7 +478,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     };
                     let quote_char = options.quote_char;
                     buf.push(quote_char);
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, options.escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -458,11 +500,18 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.extend_from_slice(&[quote_char, quote_char]);
                         return;
                     }
-                    let needs_quote = memchr3(options.separator, LF, CR, s.as_bytes()).is_some();
+                    let separator = separator_byte(options);
+                    let needs_quote = memchr3(separator, LF, CR, s.as_bytes()).is_some();
                     if needs_quote {
                         buf.push(quote_char);
                     }
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, needs_quote);
+                    serialize_str_escaped(
+                        buf,
+                        s.as_bytes(),
+                        quote_char,
+                        options.escape_char,
+                        needs_quote,
+                    );
                     if needs_quote {
                         buf.push(quote_char);
                     }
@@ -480,7 +529,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.extend_from_slice(options.null.as_bytes());
                         return;
                     };
-                    buf.extend_from_slice(s.as_bytes());
+                    serialize_str_unquoted_escaped(buf, s.as_bytes(), options);
                 };
             Box::new(StringSerializer {
                 serialize,
@@ -776,5 +825,37 @@ mod test {
         check_string_serialization(&non_numeric_quote, Some("a,b"), r#""a,b""#);
         check_string_serialization(&non_numeric_quote, Some("a\nb"), "\"a\nb\"");
         check_string_serialization(&non_numeric_quote, Some("a\rb"), "\"a\rb\"");
+
+        let always_quote_escaped = SerializeOptions {
+            quote_style: QuoteStyle::Always,
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&always_quote_escaped, Some("a"), r#""a""#);
+        check_string_serialization(&always_quote_escaped, Some("a\"b"), r#""a\"b""#);
+        check_string_serialization(&always_quote_escaped, Some(r"a\b"), r#""a\\b""#);
+        check_string_serialization(&always_quote_escaped, Some("a,b\n"), "\"a,b\n\"");
+
+        let necessary_quote_escaped = SerializeOptions {
+            quote_style: QuoteStyle::Necessary,
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&necessary_quote_escaped, Some("a b"), "a b");
+        check_string_serialization(&necessary_quote_escaped, Some("a\"b"), r#""a\"b""#);
+        check_string_serialization(&necessary_quote_escaped, Some(r"a\b"), r#""a\\b""#);
+        check_string_serialization(&necessary_quote_escaped, Some("a,b"), r#""a,b""#);
+
+        let never_quote_escaped = SerializeOptions {
+            quote_style: QuoteStyle::Never,
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&never_quote_escaped, None, "");
+        check_string_serialization(&never_quote_escaped, Some("a b"), "a b");
+        check_string_serialization(&never_quote_escaped, Some("a,b"), r"a\,b");
+        check_string_serialization(&never_quote_escaped, Some("a\"b"), r#"a\"b"#);
+        check_string_serialization(&never_quote_escaped, Some(r"a\b"), r"a\\b");
+        check_string_serialization(&never_quote_escaped, Some("a\nb\r"), "a\\\nb\\\r");
     }
 }
@@ -89,6 +89,13 @@ where
         self
     }
 
+    /// Set a column separator of more than one byte, e.g. `||`, which is used instead of the
+    /// single byte separator. Fields that contain its first byte are quoted.
+    pub fn with_multi_byte_separator(mut self, separator: Option<String>) -> Self {
+        self.options.multi_byte_separator = separator;
+        self
+    }
+
     /// Set the batch size to use while writing the CSV.
     pub fn with_batch_size(mut self, batch_size: NonZeroUsize) -> Self {
         self.batch_size = batch_size;
@@ -160,6 +167,14 @@ where
         self
     }
 
+    /// Set the single byte character used to escape quotes, e.g. `\` for loaders that expect
+    /// `\"` instead of `""`. With [`QuoteStyle::Never`] it also escapes separators and line
+    /// terminators. Set this to [None] to escape quotes by doubling them.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.options.escape_char = escape_char;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
@@ -4,11 +4,12 @@ use std::sync::{Arc, RwLock};
 
 use once_cell::sync::Lazy;
 use polars_core::config;
-use polars_error::PolarsResult;
+use polars_error::{polars_bail, PolarsResult};
 use polars_utils::aliases::PlHashMap;
 
-use super::entry::{FileCacheEntry, DATA_PREFIX, METADATA_PREFIX};
-use super::eviction::EvictionManager;
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
+use super::entry::{FileCacheEntry, DATA_PREFIX, METADATA_PREFIX, TEMP_PREFIX};
+use super::eviction::{list_data_files, try_remove_file, EvictionManager};
 use super::file_fetcher::FileFetcher;
 use super::utils::FILE_CACHE_PREFIX;
 use crate::path_utils::{ensure_directory_init, is_cloud_url};
@@ -22,6 +23,7 @@ pub static FILE_CACHE: Lazy<FileCache> = Lazy::new(|| {
     }
 
     let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
+    let max_size = Arc::new(AtomicU64::from(get_env_file_cache_max_size().unwrap_or(0)));
     let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
 
     let metadata_dir = prefix
@@ -36,10 +38,11 @@ pub static FILE_CACHE: Lazy<FileCache> = Lazy::new(|| {
         )
     }
 
-    let data_dir = prefix
-        .as_ref()
-        .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap())
-        .into_boxed_path();
+    let data_dir = Arc::<Path>::from(
+        prefix
+            .as_ref()
+            .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap()),
+    );
 
     if let Err(err) = ensure_directory_init(&data_dir) {
         panic!(
@@ -49,23 +52,48 @@ pub static FILE_CACHE: Lazy<FileCache> = Lazy::new(|| {
         )
     }
 
+    let temp_dir = prefix
+        .as_ref()
+        .join(std::str::from_utf8(&[TEMP_PREFIX]).unwrap())
+        .into_boxed_path();
+
+    if let Err(err) = ensure_directory_init(&temp_dir) {
+        panic!(
+            "failed to create file cache temp directory: path = {}, err = {}",
+            temp_dir.to_str().unwrap(),
+            err
+        )
+    }
+
     EvictionManager {
-        data_dir,
+        data_dir: data_dir.as_ref().into(),
         metadata_dir,
+        temp_dir,
         files_to_remove: None,
         min_ttl: min_ttl.clone(),
+        max_size: max_size.clone(),
         notify_ttl_updated: notify_ttl_updated.clone(),
     }
     .run_in_background();
 
     // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    unsafe { FileCache::new_unchecked(prefix, data_dir, min_ttl, max_size, notify_ttl_updated) }
 });
 
+/// Disk usage of the cached data files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FileCacheUsage {
+    pub n_files: usize,
+    /// Total size in bytes.
+    pub total_size: u64,
+}
+
 pub struct FileCache {
     prefix: Arc<Path>,
+    data_dir: Arc<Path>,
     entries: Arc<RwLock<PlHashMap<Arc<str>, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
+    max_size: Arc<AtomicU64>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
 }
 
@@ -74,15 +102,20 @@ impl FileCache {
     /// The following directories exist:
     /// * `{prefix}/{METADATA_PREFIX}/`
     /// * `{prefix}/{DATA_PREFIX}/`
+    /// * `{prefix}/{TEMP_PREFIX}/`
     unsafe fn new_unchecked(
         prefix: Arc<Path>,
+        data_dir: Arc<Path>,
         min_ttl: Arc<AtomicU64>,
+        max_size: Arc<AtomicU64>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
     ) -> Self {
         Self {
             prefix,
+            data_dir,
             entries: Default::default(),
             min_ttl,
+            max_size,
             notify_ttl_updated,
         }
     }
@@ -176,6 +209,58 @@ impl FileCache {
                 .map(Arc::clone)
         }
     }
+
+    /// Maximum total size of the cached data files in bytes. Defaults to the
+    /// `POLARS_FILE_CACHE_MAX_SIZE` environment variable, or no limit.
+    pub fn max_size(&self) -> Option<u64> {
+        match self.max_size.load(std::sync::atomic::Ordering::Relaxed) {
+            0 => None,
+            v => Some(v),
+        }
+    }
+
+    /// Set the maximum total size of the cached data files in bytes. When it is exceeded, the
+    /// least recently accessed files are evicted in the background, regardless of their TTL.
+    /// Files that are in use are never evicted.
+    pub fn set_max_size(&self, max_size: Option<u64>) {
+        self.max_size
+            .store(max_size.unwrap_or(0), std::sync::atomic::Ordering::Relaxed);
+        self.notify_ttl_updated.notify_one();
+    }
+
+    /// The disk usage of the cached data files of all processes sharing the cache directory.
+    pub fn usage(&self) -> PolarsResult<FileCacheUsage> {
+        let files = list_data_files(self.data_dir.as_ref())?;
+        Ok(FileCacheUsage {
+            n_files: files.len(),
+            total_size: files.iter().map(|file| file.size).sum(),
+        })
+    }
+
+    /// Remove all cached data files that are not in use and return the usage that was freed.
+    /// Removed files are downloaded again on their next access.
+    pub fn clear(&self) -> PolarsResult<FileCacheUsage> {
+        let mut attempts = 0;
+        let guard = loop {
+            if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_exclusive() {
+                break guard;
+            }
+            attempts += 1;
+            if attempts == 100 {
+                polars_bail!(ComputeError: "failed to clear the file cache: the cache is in use");
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        };
+
+        let mut freed = FileCacheUsage::default();
+        for file in list_data_files(self.data_dir.as_ref())? {
+            if try_remove_file(&file.path, &guard)? {
+                freed.n_files += 1;
+                freed.total_size += file.size;
+            }
+        }
+        Ok(freed)
+    }
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
@@ -183,3 +268,10 @@ pub fn get_env_file_cache_ttl() -> u64 {
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
+
+pub fn get_env_file_cache_max_size() -> Option<u64> {
+    std::env::var("POLARS_FILE_CACHE_MAX_SIZE")
+        .ok()
+        .map(|x| x.parse::<u64>().expect("integer"))
+        .filter(|&x| x > 0)
+}
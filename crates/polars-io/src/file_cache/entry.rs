@@ -13,9 +13,11 @@ use super::file_fetcher::{FileFetcher, RemoteMetadata};
 use super::file_lock::{FileLock, FileLockAnyGuard};
 use super::metadata::{EntryMetadata, FileVersion};
 use super::utils::update_last_accessed;
+use crate::cloud::{record_cache_lookup, IoCache};
 
 pub(super) const DATA_PREFIX: u8 = b'd';
 pub(super) const METADATA_PREFIX: u8 = b'm';
+pub(super) const TEMP_PREFIX: u8 = b't';
 
 struct CachedData {
     last_modified: u64,
@@ -65,6 +67,7 @@ impl Inner {
                     if verbose {
                         eprintln!("[file_cache::entry] try_open_assume_latest: opening already fetched file for uri = {}", self.uri.clone());
                     }
+                    record_cache_lookup(IoCache::File, true);
                     return Ok(finish_open(data_file_path, metadata_file));
                 }
             }
@@ -97,6 +100,7 @@ impl Inner {
                         if verbose {
                             eprintln!("[file_cache::entry] try_open_check_latest: opening already fetched file for uri = {}", self.uri.clone());
                         }
+                        record_cache_lookup(IoCache::File, true);
                         return Ok(finish_open(data_file_path, metadata_file));
                     }
                 }
@@ -124,6 +128,7 @@ impl Inner {
                         self.uri.clone()
                     );
                 }
+                record_cache_lookup(IoCache::File, true);
                 return Ok(finish_open(data_file_path, metadata_file));
             }
         }
@@ -137,6 +142,8 @@ impl Inner {
             );
         }
 
+        record_cache_lookup(IoCache::File, false);
+
         let data_file_path = &get_data_file_path(
             self.path_prefix.to_str().unwrap().as_bytes(),
             self.uri_hash.as_bytes(),
@@ -145,42 +152,25 @@ impl Inner {
         // Remove the file if it exists, since it doesn't match the metadata.
         // This could be left from an aborted process.
         let _ = std::fs::remove_file(data_file_path);
-        if !self.file_fetcher.fetches_as_symlink() {
-            let file = std::fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(data_file_path)
-                .map_err(PolarsError::from)?;
-            file.lock_exclusive().unwrap();
-            if file.allocate(remote_metadata.size).is_err() {
-                polars_bail!(
-                    ComputeError: "failed to allocate {} bytes to download uri = {}",
-                    remote_metadata.size,
-                    self.uri.as_ref()
-                );
-            }
-        }
-        self.file_fetcher.fetch(data_file_path)?;
 
-        // Don't do this on windows as it will break setting last accessed times.
-        #[cfg(target_family = "unix")]
-        if !self.file_fetcher.fetches_as_symlink() {
-            let mut perms = std::fs::metadata(data_file_path.clone())
-                .unwrap()
-                .permissions();
-            perms.set_readonly(true);
-            std::fs::set_permissions(data_file_path, perms).unwrap();
+        // Download into a file of this process and move it into place once it is complete, so
+        // that other processes never open a partially written data file. Concurrent downloads
+        // of the same uri by other processes are blocked by our exclusive metadata lock.
+        let temp_file_path = &get_temp_file_path(
+            self.path_prefix.to_str().unwrap().as_bytes(),
+            self.uri_hash.as_bytes(),
+        );
+        let _ = std::fs::remove_file(temp_file_path);
+        if let Err(err) = self.fetch_to(temp_file_path, remote_metadata) {
+            let _ = std::fs::remove_file(temp_file_path);
+            return Err(err);
         }
+        std::fs::rename(temp_file_path, data_file_path).map_err(PolarsError::from)?;
 
         let data_file_metadata = std::fs::metadata(data_file_path).unwrap();
         let local_last_modified = super::utils::last_modified_u64(&data_file_metadata);
         let local_size = data_file_metadata.len();
 
-        if local_size != remote_metadata.size {
-            polars_bail!(ComputeError: "downloaded file size ({}) does not match expected size ({})", local_size, remote_metadata.size);
-        }
-
         let mut metadata = metadata;
         let metadata = Arc::make_mut(&mut metadata);
         metadata.local_last_modified = local_last_modified;
@@ -202,6 +192,41 @@ impl Inner {
         Ok(data_file)
     }
 
+    /// Fetch the remote file to `path` and check its size.
+    fn fetch_to(&self, path: &Path, remote_metadata: &RemoteMetadata) -> PolarsResult<()> {
+        if !self.file_fetcher.fetches_as_symlink() {
+            let file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)
+                .map_err(PolarsError::from)?;
+            file.lock_exclusive().unwrap();
+            if file.allocate(remote_metadata.size).is_err() {
+                polars_bail!(
+                    ComputeError: "failed to allocate {} bytes to download uri = {}",
+                    remote_metadata.size,
+                    self.uri.as_ref()
+                );
+            }
+        }
+        self.file_fetcher.fetch(path)?;
+
+        // Don't do this on windows as it will break setting last accessed times.
+        #[cfg(target_family = "unix")]
+        if !self.file_fetcher.fetches_as_symlink() {
+            let mut perms = std::fs::metadata(path).unwrap().permissions();
+            perms.set_readonly(true);
+            std::fs::set_permissions(path, perms).unwrap();
+        }
+
+        let local_size = std::fs::metadata(path).map_err(PolarsError::from)?.len();
+        if local_size != remote_metadata.size {
+            polars_bail!(ComputeError: "downloaded file size ({}) does not match expected size ({})", local_size, remote_metadata.size);
+        }
+        Ok(())
+    }
+
     /// Try to read the metadata from disk. If `F` is an exclusive guard, this
     /// will update the TTL stored in the metadata file if it does not match.
     fn try_get_metadata<F: FileLockAnyGuard>(
@@ -390,6 +415,22 @@ fn get_data_file_path(
     PathBuf::from(std::str::from_utf8(&path).unwrap())
 }
 
+/// `[prefix]/t/[uri hash].[process id]`
+fn get_temp_file_path(path_prefix: &[u8], uri_hash: &[u8]) -> PathBuf {
+    let pid = std::process::id().to_string();
+    let bytes = flatten(
+        &[
+            path_prefix,
+            &[b'/', TEMP_PREFIX, b'/'],
+            uri_hash,
+            b".",
+            pid.as_bytes(),
+        ],
+        None,
+    );
+    PathBuf::from(std::str::from_utf8(bytes.as_slice()).unwrap())
+}
+
 /// `[prefix]/m/[uri hash]`
 fn get_metadata_file_path(path_prefix: &[u8], uri_hash: &[u8]) -> PathBuf {
     let bytes = flatten(
@@ -16,16 +16,80 @@ pub(super) struct EvictionCandidate {
     metadata_path: PathBuf,
     metadata_last_modified: SystemTime,
     ttl: u64,
+    /// Set for files that are evicted to bring the cache below its maximum size. These are
+    /// removed regardless of their TTL, unless they were accessed after this time.
+    lru_last_accessed: Option<SystemTime>,
 }
 
 pub(super) struct EvictionManager {
     pub(super) data_dir: Box<Path>,
     pub(super) metadata_dir: Box<Path>,
+    /// Downloads in progress, see [`TEMP_FILE_MAX_AGE`].
+    pub(super) temp_dir: Box<Path>,
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
+    /// Maximum total size of the data files in bytes, 0 if unlimited.
+    pub(super) max_size: Arc<AtomicU64>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
 }
 
+/// Downloads write to their file continuously, so an older file was left by a process that
+/// exited during the download.
+const TEMP_FILE_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// A file in the data directory of the cache.
+pub(super) struct DataFile {
+    pub(super) path: PathBuf,
+    pub(super) size: u64,
+    pub(super) last_accessed: SystemTime,
+}
+
+fn last_accessed(metadata: &std::fs::Metadata) -> SystemTime {
+    metadata
+        .accessed()
+        .unwrap_or_else(|_| metadata.modified().unwrap())
+}
+
+/// List the files in `data_dir`, skipping those that were removed while listing.
+pub(super) fn list_data_files(data_dir: &Path) -> PolarsResult<Vec<DataFile>> {
+    let iter = std::fs::read_dir(data_dir).map_err(|e| {
+        let msg = format!("failed to read data directory: {}", e);
+        PolarsError::IO {
+            error: e.into(),
+            msg: Some(msg.into()),
+        }
+    })?;
+
+    let mut out = vec![];
+    for file in iter {
+        let file = file?;
+        let Ok(metadata) = file.metadata() else {
+            continue;
+        };
+        out.push(DataFile {
+            path: file.path(),
+            size: metadata.len(),
+            last_accessed: last_accessed(&metadata),
+        });
+    }
+    Ok(out)
+}
+
+/// Try to remove a file of the cache, this does nothing if the file is locked by a cache entry.
+pub(super) fn try_remove_file(
+    path: &Path,
+    _guard: &GlobalFileCacheGuardExclusive,
+) -> std::io::Result<bool> {
+    {
+        let file = std::fs::OpenOptions::new().read(true).open(path)?;
+        if file.try_lock_exclusive().is_err() {
+            return Ok(false);
+        }
+    }
+    std::fs::remove_file(path)?;
+    Ok(true)
+}
+
 impl EvictionCandidate {
     fn update_ttl(&mut self) {
         let Ok(metadata_last_modified) =
@@ -60,11 +124,7 @@ impl EvictionCandidate {
             return false;
         };
 
-        if let Ok(duration) = now.duration_since(
-            metadata
-                .accessed()
-                .unwrap_or_else(|_| metadata.modified().unwrap()),
-        ) {
+        if let Ok(duration) = now.duration_since(last_accessed(&metadata)) {
             duration.as_secs() >= self.ttl
         } else {
             false
@@ -92,57 +152,64 @@ impl EvictionCandidate {
 
         let metadata = std::fs::metadata(path).unwrap();
 
-        let since_last_accessed = match now.duration_since(
-            metadata
-                .accessed()
-                .unwrap_or_else(|_| metadata.modified().unwrap()),
-        ) {
-            Ok(v) => v.as_secs(),
-            Err(_) => {
+        if let Some(lru_last_accessed) = self.lru_last_accessed {
+            if last_accessed(&metadata) > lru_last_accessed {
                 if verbose {
-                    eprintln!("[EvictionManager] evict_files: skipping {} (last accessed time was updated)", path.to_str().unwrap());
+                    eprintln!(
+                        "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                        path.to_str().unwrap()
+                    );
                 }
                 return;
-            },
-        };
-
-        if since_last_accessed < self.ttl {
-            if verbose {
-                eprintln!(
-                    "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
-                    path.to_str().unwrap()
-                );
             }
-            return;
-        }
-
-        {
-            let file = std::fs::OpenOptions::new().read(true).open(path).unwrap();
+        } else {
+            let since_last_accessed = match now.duration_since(last_accessed(&metadata)) {
+                Ok(v) => v.as_secs(),
+                Err(_) => {
+                    if verbose {
+                        eprintln!("[EvictionManager] evict_files: skipping {} (last accessed time was updated)", path.to_str().unwrap());
+                    }
+                    return;
+                },
+            };
 
-            if file.try_lock_exclusive().is_err() {
+            if since_last_accessed < self.ttl {
                 if verbose {
                     eprintln!(
-                        "[EvictionManager] evict_files: skipping {} (file is locked)",
-                        self.path.to_str().unwrap()
+                        "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                        path.to_str().unwrap()
                     );
                 }
                 return;
             }
         }
 
-        if let Err(err) = std::fs::remove_file(path) {
-            if verbose {
-                eprintln!(
-                    "[EvictionManager] evict_files: error removing file: {} ({})",
-                    path.to_str().unwrap(),
-                    err
-                );
-            }
-        } else if verbose {
-            eprintln!(
-                "[EvictionManager] evict_files: removed file at {}",
-                path.to_str().unwrap()
-            );
+        match try_remove_file(path, _guard) {
+            Ok(false) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_files: skipping {} (file is locked)",
+                        self.path.to_str().unwrap()
+                    );
+                }
+            },
+            Err(err) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_files: error removing file: {} ({})",
+                        path.to_str().unwrap(),
+                        err
+                    );
+                }
+            },
+            Ok(true) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_files: removed file at {}",
+                        path.to_str().unwrap()
+                    );
+                }
+            },
         }
     }
 }
@@ -152,6 +219,7 @@ impl EvictionManager {
     /// The following directories exist:
     /// * `self.data_dir`
     /// * `self.metadata_dir`
+    /// * `self.temp_dir`
     pub(super) fn run_in_background(mut self) {
         let verbose = false;
 
@@ -201,7 +269,7 @@ impl EvictionManager {
 
                 loop {
                     let min_ttl = self.min_ttl.load(std::sync::atomic::Ordering::Relaxed);
-                    let sleep_interval = std::cmp::max(min_ttl / 4, {
+                    let min_sleep_interval = {
                         #[cfg(debug_assertions)]
                         {
                             3
@@ -210,7 +278,15 @@ impl EvictionManager {
                         {
                             60
                         }
-                    });
+                    };
+                    // The size limit can be exceeded at any time, so check it at the shortest
+                    // interval.
+                    let sleep_interval =
+                        if self.max_size.load(std::sync::atomic::Ordering::Relaxed) > 0 {
+                            min_sleep_interval
+                        } else {
+                            std::cmp::max(min_ttl / 4, min_sleep_interval)
+                        };
 
                     let since_last_eviction =
                         Instant::now().duration_since(last_eviction_time).as_secs();
@@ -230,18 +306,25 @@ impl EvictionManager {
         });
     }
 
-    fn update_file_list(&mut self) -> PolarsResult<()> {
-        let data_files_iter = match std::fs::read_dir(self.data_dir.as_ref()) {
-            Ok(v) => v,
-            Err(e) => {
-                let msg = format!("failed to read data directory: {}", e);
+    fn remove_stale_temp_files(&self, now: &SystemTime) -> PolarsResult<()> {
+        for file in std::fs::read_dir(self.temp_dir.as_ref())? {
+            let file = file?;
+            let Ok(modified) = file.metadata().and_then(|md| md.modified()) else {
+                continue;
+            };
+            if now
+                .duration_since(modified)
+                .is_ok_and(|age| age > TEMP_FILE_MAX_AGE)
+            {
+                let _ = std::fs::remove_file(file.path());
+            }
+        }
+        Ok(())
+    }
 
-                return Err(PolarsError::IO {
-                    error: e.into(),
-                    msg: Some(msg.into()),
-                });
-            },
-        };
+    fn update_file_list(&mut self) -> PolarsResult<()> {
+        self.remove_stale_temp_files(&SystemTime::now())?;
+        let data_files = list_data_files(self.data_dir.as_ref())?;
 
         let metadata_files_iter = match std::fs::read_dir(self.metadata_dir.as_ref()) {
             Ok(v) => v,
@@ -256,10 +339,7 @@ impl EvictionManager {
         };
 
         let mut files_to_remove = Vec::with_capacity(
-            data_files_iter
-                .size_hint()
-                .1
-                .unwrap_or(data_files_iter.size_hint().0)
+            data_files.len()
                 + metadata_files_iter
                     .size_hint()
                     .1
@@ -268,9 +348,11 @@ impl EvictionManager {
 
         let now = SystemTime::now();
 
-        for file in data_files_iter {
-            let file = file?;
-            let path = file.path();
+        // Files that are kept after the TTL check, these are candidates for the size limit.
+        let mut retained = vec![];
+
+        for file in data_files {
+            let path = file.path.clone();
 
             let hash = path
                 .file_name()
@@ -286,11 +368,29 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
             eviction_candidate.update_ttl();
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
+            } else {
+                retained.push((eviction_candidate, file));
+            }
+        }
+
+        let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+        if max_size > 0 {
+            let mut total_size = retained.iter().map(|(_, file)| file.size).sum::<u64>();
+            retained.sort_unstable_by_key(|(_, file)| file.last_accessed);
+
+            for (mut eviction_candidate, file) in retained {
+                if total_size <= max_size {
+                    break;
+                }
+                total_size -= file.size;
+                eviction_candidate.lru_last_accessed = Some(file.last_accessed);
+                files_to_remove.push(eviction_candidate);
             }
         }
 
@@ -304,6 +404,7 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
 
             eviction_candidate.update_ttl();
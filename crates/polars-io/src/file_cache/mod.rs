@@ -6,6 +6,8 @@ mod file_fetcher;
 mod file_lock;
 mod metadata;
 mod utils;
-pub use cache::{get_env_file_cache_ttl, FILE_CACHE};
+pub use cache::{
+    get_env_file_cache_max_size, get_env_file_cache_ttl, FileCache, FileCacheUsage, FILE_CACHE,
+};
 pub use entry::FileCacheEntry;
 pub use utils::{init_entries_from_uri_list, FILE_CACHE_PREFIX};
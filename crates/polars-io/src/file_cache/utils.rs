@@ -66,20 +66,21 @@ pub fn init_entries_from_uri_list(
         .unwrap_or_else(get_env_file_cache_ttl);
 
     if is_cloud_url(first_uri) {
+        // Object stores for http are tied to the path, and prefix options can select other
+        // options for every path.
+        let store_per_uri = first_uri.starts_with("http")
+            || cloud_options.is_some_and(|options| !options.prefix_options.is_empty());
         let object_stores = pl_async::get_runtime().block_on_potential_spawn(async {
-            futures::future::try_join_all(
-                (0..if first_uri.starts_with("http") {
-                    // Object stores for http are tied to the path.
-                    uri_list.len()
-                } else {
-                    1
-                })
-                    .map(|i| async move {
-                        let (_, object_store) =
-                            build_object_store(&uri_list[i], cloud_options, false).await?;
-                        PolarsResult::Ok(PolarsObjectStore::new(object_store))
-                    }),
-            )
+            futures::future::try_join_all((0..if store_per_uri { uri_list.len() } else { 1 }).map(
+                |i| async move {
+                    let uri = uri_list[i].as_ref();
+                    let (_, object_store) = build_object_store(uri, cloud_options, false).await?;
+                    PolarsResult::Ok(
+                        PolarsObjectStore::new(object_store)
+                            .with_cloud_options(cloud_options.map(|options| options.for_url(uri))),
+                    )
+                },
+            ))
             .await
         })?;
 
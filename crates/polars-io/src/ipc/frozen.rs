@@ -0,0 +1,149 @@
+use std::io::Cursor;
+use std::sync::OnceLock;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use super::{IpcCompression, IpcReader, IpcWriter};
+use crate::{SerReader, SerWriter};
+
+/// A [`DataFrame`] whose columns are kept compressed in memory.
+///
+/// A column is decompressed the first time it is accessed and kept until
+/// [`FrozenDataFrame::release`] is called, so only the columns that are used take up their full
+/// size.
+pub struct FrozenDataFrame {
+    height: usize,
+    schema: SchemaRef,
+    columns: Vec<FrozenColumn>,
+}
+
+struct FrozenColumn {
+    /// The column as an IPC file with compressed buffers.
+    compressed: Vec<u8>,
+    decompressed: OnceLock<Series>,
+}
+
+impl FrozenColumn {
+    fn new(s: &Series, compression: IpcCompression) -> PolarsResult<Self> {
+        // SAFETY: a single column.
+        let mut df = unsafe { DataFrame::new_no_checks(vec![s.clone()]) };
+        let mut compressed = vec![];
+        IpcWriter::new(&mut compressed)
+            .with_compression(Some(compression))
+            .with_compat_level(CompatLevel::newest())
+            .finish(&mut df)?;
+        compressed.shrink_to_fit();
+        Ok(Self {
+            compressed,
+            decompressed: OnceLock::new(),
+        })
+    }
+
+    fn get(&self) -> PolarsResult<&Series> {
+        if let Some(s) = self.decompressed.get() {
+            return Ok(s);
+        }
+        let df = IpcReader::new(Cursor::new(self.compressed.as_slice())).finish()?;
+        let s = df.get_columns()[0].clone();
+        Ok(self.decompressed.get_or_init(|| s))
+    }
+}
+
+impl FrozenDataFrame {
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Get the column `name`, decompressing it if it has not been accessed yet.
+    pub fn column(&self, name: &str) -> PolarsResult<&Series> {
+        let (idx, _, _) = self.schema.try_get_full(name)?;
+        self.columns[idx].get()
+    }
+
+    /// Get the columns `names` as a [`DataFrame`], decompressing the ones that have not been
+    /// accessed yet.
+    pub fn select<I, S>(&self, names: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = names
+            .into_iter()
+            .map(|name| self.column(name.as_ref()).cloned())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    /// Decompress all columns into a [`DataFrame`].
+    pub fn thaw(&self) -> PolarsResult<DataFrame> {
+        let columns = POOL.install(|| {
+            self.columns
+                .par_iter()
+                .map(|column| column.get().cloned())
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        // SAFETY: the columns are those of a DataFrame.
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
+    /// Drop the decompressed columns, such that only the compressed buffers are kept.
+    pub fn release(&mut self) {
+        for column in &mut self.columns {
+            column.decompressed.take();
+        }
+    }
+
+    /// The size in bytes of the compressed buffers.
+    pub fn compressed_size(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| column.compressed.len())
+            .sum()
+    }
+
+    /// An estimation of the size in bytes of the compressed buffers and the columns that are
+    /// decompressed.
+    pub fn estimated_size(&self) -> usize {
+        self.compressed_size()
+            + self
+                .columns
+                .iter()
+                .filter_map(|column| column.decompressed.get())
+                .map(|s| s.estimated_size())
+                .sum::<usize>()
+    }
+}
+
+pub trait FreezeDataFrame {
+    /// Compress the columns of the [`DataFrame`] in memory, to keep it resident at a fraction of
+    /// its size. Columns are decompressed on access.
+    fn freeze(&self, compression: IpcCompression) -> PolarsResult<FrozenDataFrame>;
+}
+
+impl FreezeDataFrame for DataFrame {
+    fn freeze(&self, compression: IpcCompression) -> PolarsResult<FrozenDataFrame> {
+        let columns = POOL.install(|| {
+            self.get_columns()
+                .par_iter()
+                .map(|s| FrozenColumn::new(s, compression))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(FrozenDataFrame {
+            height: self.height(),
+            schema: Arc::new(self.schema()),
+            columns,
+        })
+    }
+}
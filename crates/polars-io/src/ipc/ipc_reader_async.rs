@@ -74,7 +74,8 @@ impl IpcReaderAsync {
         let path = object_path_from_str(&prefix)?;
 
         Ok(Self {
-            store: PolarsObjectStore::new(store),
+            store: PolarsObjectStore::new(store)
+                .with_cloud_options(cloud_options.map(|options| options.for_url(uri))),
             cache_entry,
             path,
         })
@@ -1,4 +1,6 @@
 #[cfg(feature = "ipc")]
+mod frozen;
+#[cfg(feature = "ipc")]
 mod ipc_file;
 #[cfg(feature = "cloud")]
 mod ipc_reader_async;
@@ -6,14 +8,20 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+#[cfg(feature = "ipc")]
+mod ooc;
 mod write;
 #[cfg(all(feature = "async", feature = "ipc"))]
 mod write_async;
 
+#[cfg(feature = "ipc")]
+pub use frozen::{FreezeDataFrame, FrozenDataFrame};
 #[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
 #[cfg(feature = "cloud")]
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+#[cfg(feature = "ipc")]
+pub use ooc::{OocDataFrame, OocOptions};
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOptions};
@@ -0,0 +1,453 @@
+//! Out-of-core DataFrames.
+//!
+//! An [`OocDataFrame`] keeps its rows in IPC files in a spill directory, one file per chunk of
+//! rows. Operations read the chunks one at a time and only the columns they need, so a dataset
+//! that is much larger than memory can be explored with a bounded amount of memory. Recently
+//! read columns of chunks are kept in an LRU cache.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+
+use super::{IpcCompression, IpcReader, IpcWriter};
+use crate::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use crate::{SerReader, SerWriter};
+
+/// Options of an [`OocDataFrame`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OocOptions {
+    /// The maximum number of rows of a chunk.
+    pub chunk_size: usize,
+    /// The maximum size in bytes of the cached columns of chunks.
+    pub cache_size: usize,
+    /// The compression of the spill files.
+    pub compression: Option<IpcCompression>,
+    /// The directory to create the spill directory in. Defaults to the Polars temporary
+    /// directory, which can be set with `POLARS_TEMP_DIR`.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for OocOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1 << 20,
+            cache_size: 1 << 30,
+            compression: Some(IpcCompression::LZ4),
+            spill_dir: None,
+        }
+    }
+}
+
+impl OocOptions {
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    pub fn with_cache_size(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    pub fn with_compression(mut self, compression: Option<IpcCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn with_spill_dir(mut self, spill_dir: Option<PathBuf>) -> Self {
+        self.spill_dir = spill_dir;
+        self
+    }
+}
+
+/// A column of a chunk, by the index of the chunk and of the column in the spill files.
+type ChunkColumn = (usize, usize);
+
+/// Least recently used columns of chunks.
+#[derive(Default)]
+struct ChunkCache {
+    columns: PlHashMap<ChunkColumn, Series>,
+    /// The keys of the columns, from least to most recently used.
+    order: VecDeque<ChunkColumn>,
+    size: usize,
+}
+
+impl ChunkCache {
+    fn get(&mut self, key: ChunkColumn) -> Option<Series> {
+        let s = self.columns.get(&key)?.clone();
+        let pos = self.order.iter().position(|k| *k == key).unwrap();
+        self.order.remove(pos);
+        self.order.push_back(key);
+        Some(s)
+    }
+
+    fn insert(&mut self, key: ChunkColumn, s: Series, capacity: usize) {
+        if self.columns.contains_key(&key) {
+            return;
+        }
+        self.size += s.estimated_size();
+        self.columns.insert(key, s);
+        self.order.push_back(key);
+        while self.size > capacity {
+            let Some(key) = self.order.pop_front() else {
+                break;
+            };
+            let s = self.columns.remove(&key).unwrap();
+            self.size -= s.estimated_size();
+        }
+    }
+}
+
+/// The spill files of the chunks, which are removed when the last frame using them is dropped.
+struct Spill {
+    dir: PathBuf,
+    schema: SchemaRef,
+    /// The number of rows of each chunk.
+    chunk_heights: Vec<usize>,
+    cache: Mutex<ChunkCache>,
+    cache_size: usize,
+    compression: Option<IpcCompression>,
+}
+
+impl Spill {
+    fn chunk_path(&self, chunk: usize) -> PathBuf {
+        self.dir.join(format!("{chunk}.ipc"))
+    }
+
+    /// Read the columns `columns` of chunk `chunk`, from the cache or else from its spill file.
+    fn read(&self, chunk: usize, columns: &[usize]) -> PolarsResult<Vec<Series>> {
+        let mut out = Vec::with_capacity(columns.len());
+        let mut missing = vec![];
+        {
+            let mut cache = self.cache.lock().unwrap();
+            for &column in columns {
+                let s = cache.get((chunk, column));
+                if s.is_none() {
+                    missing.push(column);
+                }
+                out.push(s);
+            }
+        }
+        if !missing.is_empty() {
+            missing.sort_unstable();
+            missing.dedup();
+            let df = IpcReader::new(File::open(self.chunk_path(chunk))?)
+                .with_projection(Some(missing.clone()))
+                .finish()?;
+            let mut cache = self.cache.lock().unwrap();
+            for (column, s) in columns.iter().zip(&mut out) {
+                if s.is_none() {
+                    let name = self.schema.get_at_index(*column).unwrap().0;
+                    let read = df.column(name)?.clone();
+                    cache.insert((chunk, *column), read.clone(), self.cache_size);
+                    *s = Some(read);
+                }
+            }
+        }
+        Ok(out.into_iter().map(Option::unwrap).collect())
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn create_spill_dir(base: Option<&Path>) -> PolarsResult<PathBuf> {
+    static SPILL_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let base = base.unwrap_or(&**POLARS_TEMP_DIR_BASE_PATH);
+    let dir = base.join(format!(
+        "ooc/{}-{}",
+        std::process::id(),
+        SPILL_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir).map_err(|err| {
+        polars_err!(ComputeError: "failed to create spill directory {}: {}", dir.display(), err)
+    })?;
+    Ok(dir)
+}
+
+/// A [`DataFrame`] whose rows are kept in spill files on disk instead of in memory.
+///
+/// The frame is read-only: operations read it chunk by chunk and return a [`DataFrame`] when
+/// their result is small, or a new [`OocDataFrame`] when it is not. Frames created by
+/// [`OocDataFrame::select`] share the spill files of the frame they are selected from.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+///
+/// fn example(batches: impl Iterator<Item = PolarsResult<DataFrame>>) -> PolarsResult<()> {
+///     let df = OocDataFrame::from_batches(batches, OocOptions::default())?;
+///     let trades = df.filter(|chunk| Ok(chunk.column("qty")?.i64()?.gt(100)))?;
+///     println!("{}", trades.head(10)?);
+///     println!("{:?}", trades.max("price")?.value());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OocDataFrame {
+    spill: Arc<Spill>,
+    schema: SchemaRef,
+    /// The indices of the columns of the frame in the spill files.
+    projection: Vec<usize>,
+}
+
+impl OocDataFrame {
+    /// Spill the batches to disk, splitting them into chunks of at most `chunk_size` rows. All
+    /// batches must have the same schema.
+    pub fn from_batches<I>(batches: I, options: OocOptions) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = PolarsResult<DataFrame>>,
+    {
+        polars_ensure!(
+            options.chunk_size > 0,
+            InvalidOperation: "the chunk size of an out-of-core DataFrame must be positive"
+        );
+        let dir = create_spill_dir(options.spill_dir.as_deref())?;
+        let mut spill = Spill {
+            dir,
+            schema: Default::default(),
+            chunk_heights: vec![],
+            cache: Default::default(),
+            cache_size: options.cache_size,
+            compression: options.compression,
+        };
+
+        let mut schema = None;
+        for batch in batches {
+            let batch = batch?;
+            match &schema {
+                None => schema = Some(batch.schema()),
+                Some(schema) => {
+                    polars_ensure!(
+                        batch.schema() == *schema,
+                        SchemaMismatch: "the batches of an out-of-core DataFrame must have the same schema"
+                    );
+                },
+            }
+            let mut offset = 0;
+            while offset < batch.height() {
+                let mut chunk = batch.slice(offset as i64, options.chunk_size);
+                offset += chunk.height();
+                let mut file = File::create(spill.chunk_path(spill.chunk_heights.len()))?;
+                IpcWriter::new(&mut file)
+                    .with_compression(options.compression)
+                    .with_compat_level(CompatLevel::newest())
+                    .finish(&mut chunk)?;
+                spill.chunk_heights.push(chunk.height());
+            }
+        }
+
+        let schema = Arc::new(schema.unwrap_or_default());
+        spill.schema = schema.clone();
+        Ok(Self {
+            spill: Arc::new(spill),
+            projection: (0..schema.len()).collect(),
+            schema,
+        })
+    }
+
+    /// Spill `df` to disk.
+    pub fn from_dataframe(df: &DataFrame, options: OocOptions) -> PolarsResult<Self> {
+        Self::from_batches([Ok(df.clone())], options)
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.spill.chunk_heights.iter().sum()
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.schema.len()
+    }
+
+    /// The number of chunks.
+    pub fn n_chunks(&self) -> usize {
+        self.spill.chunk_heights.len()
+    }
+
+    pub fn get_column_names(&self) -> Vec<&PlSmallStr> {
+        self.schema.iter_names().collect()
+    }
+
+    /// Select the columns `names`, without reading any data.
+    pub fn select<I, S>(&self, names: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut schema = Schema::default();
+        let mut projection = vec![];
+        for name in names {
+            let (idx, name, dtype) = self.schema.try_get_full(name.as_ref())?;
+            polars_ensure!(
+                schema.insert(name.clone(), dtype.clone()).is_none(),
+                Duplicate: "column with name '{}' has more than one occurrence", name
+            );
+            projection.push(self.projection[idx]);
+        }
+        Ok(Self {
+            spill: self.spill.clone(),
+            schema: Arc::new(schema),
+            projection,
+        })
+    }
+
+    /// Read chunk `chunk` of the frame.
+    pub fn chunk(&self, chunk: usize) -> PolarsResult<DataFrame> {
+        polars_ensure!(chunk < self.n_chunks(), oob = chunk, self.n_chunks());
+        let columns = self.spill.read(chunk, &self.projection)?;
+        // SAFETY: the columns of a chunk have the same length and unique names.
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
+    /// Iterate over the chunks of the frame, reading one chunk at a time.
+    pub fn iter_chunks(&self) -> impl Iterator<Item = PolarsResult<DataFrame>> + '_ {
+        (0..self.n_chunks()).map(|chunk| self.chunk(chunk))
+    }
+
+    /// Read the rows in the slice into memory. Only the chunks that overlap the slice are read.
+    pub fn slice(&self, offset: i64, length: usize) -> PolarsResult<DataFrame> {
+        let height = self.height();
+        let (offset, length) = slice_offsets(offset, length, height);
+        let mut out = DataFrame::empty_with_schema(&self.schema);
+        let mut chunk_offset = 0;
+        for (chunk, chunk_height) in self.spill.chunk_heights.iter().enumerate() {
+            let chunk_end = chunk_offset + chunk_height;
+            if chunk_end > offset && chunk_offset < offset + length {
+                let start = offset.saturating_sub(chunk_offset);
+                let end = (offset + length).min(chunk_end) - chunk_offset;
+                out.vstack_mut(&self.chunk(chunk)?.slice(start as i64, end - start))?;
+            }
+            chunk_offset = chunk_end;
+        }
+        out.as_single_chunk_par();
+        Ok(out)
+    }
+
+    /// Read the first `length` rows into memory.
+    pub fn head(&self, length: usize) -> PolarsResult<DataFrame> {
+        self.slice(0, length)
+    }
+
+    /// Read the last `length` rows into memory.
+    pub fn tail(&self, length: usize) -> PolarsResult<DataFrame> {
+        self.slice(-(length.min(self.height()) as i64), length)
+    }
+
+    /// Read the whole frame into memory.
+    pub fn collect(&self) -> PolarsResult<DataFrame> {
+        self.slice(0, self.height())
+    }
+
+    /// Keep the rows for which `predicate` is true, evaluating it chunk by chunk. The rows that
+    /// are kept are spilled to new files with the same options as this frame.
+    pub fn filter<F>(&self, predicate: F) -> PolarsResult<Self>
+    where
+        F: Fn(&DataFrame) -> PolarsResult<BooleanChunked>,
+    {
+        let options = OocOptions {
+            chunk_size: self.spill.chunk_heights.iter().copied().max().unwrap_or(1),
+            cache_size: self.spill.cache_size,
+            compression: self.spill.compression,
+            spill_dir: self.spill.dir.parent().map(Path::to_path_buf),
+        };
+        let batches = self.iter_chunks().map(|chunk| {
+            let chunk = chunk?;
+            chunk.filter(&predicate(&chunk)?)
+        });
+        let out = Self::from_batches(
+            std::iter::once(Ok(DataFrame::empty_with_schema(&self.schema))).chain(batches),
+            options,
+        )?;
+        Ok(out)
+    }
+
+    /// The number of null values of column `name`.
+    pub fn null_count(&self, name: &str) -> PolarsResult<usize> {
+        let ooc = self.select([name])?;
+        ooc.iter_chunks()
+            .map(|chunk| Ok(chunk?.get_columns()[0].null_count()))
+            .sum()
+    }
+
+    /// The minimum of column `name`.
+    pub fn min(&self, name: &str) -> PolarsResult<Scalar> {
+        self.reduce(name, |s| s.min_reduce())
+    }
+
+    /// The maximum of column `name`.
+    pub fn max(&self, name: &str) -> PolarsResult<Scalar> {
+        self.reduce(name, |s| s.max_reduce())
+    }
+
+    /// The sum of column `name`.
+    pub fn sum(&self, name: &str) -> PolarsResult<Scalar> {
+        self.reduce(name, |s| s.sum_reduce())
+    }
+
+    /// The mean of the numeric column `name`, which is `None` if it has no values.
+    pub fn mean(&self, name: &str) -> PolarsResult<Option<f64>> {
+        let ooc = self.select([name])?;
+        let mut sum = 0.0;
+        let mut count = 0;
+        for chunk in ooc.iter_chunks() {
+            let s = chunk?.get_columns()[0].cast(&DataType::Float64)?;
+            sum += s.f64()?.sum().unwrap_or(0.0);
+            count += s.len() - s.null_count();
+        }
+        Ok((count > 0).then(|| sum / count as f64))
+    }
+
+    /// Reduce column `name` by reducing its chunks with `f`, and then the results of the chunks.
+    fn reduce<F>(&self, name: &str, f: F) -> PolarsResult<Scalar>
+    where
+        F: Fn(&Series) -> PolarsResult<Scalar>,
+    {
+        let ooc = self.select([name])?;
+        let (name, dtype) = ooc.schema.get_at_index(0).unwrap();
+        let partials = ooc
+            .iter_chunks()
+            .map(|chunk| f(&chunk?.get_columns()[0]))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let Some(first) = partials.first() else {
+            return f(&Series::new_empty(name.clone(), dtype));
+        };
+        let dtype = first.dtype().clone();
+        let values = partials
+            .iter()
+            .map(|scalar| scalar.as_any_value())
+            .collect::<Vec<_>>();
+        f(&Series::from_any_values_and_dtype(
+            name.clone(),
+            &values,
+            &dtype,
+            true,
+        )?)
+    }
+}
+
+/// The offset and length of a slice of a frame of `height` rows, within its bounds.
+fn slice_offsets(offset: i64, length: usize, height: usize) -> (usize, usize) {
+    let offset = if offset < 0 {
+        height.saturating_sub(offset.unsigned_abs() as usize)
+    } else {
+        (offset as usize).min(height)
+    };
+    (offset, length.min(height - offset))
+}
@@ -16,9 +16,20 @@ pub struct IpcWriterOptions {
     pub compression: Option<IpcCompression>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// If set, the streaming sink writes a directory of files of about this many bytes instead
+    /// of a single file.
+    pub target_file_size: Option<usize>,
+    /// If set, the streaming sink writes a directory of files of at most this many rows instead
+    /// of a single file.
+    pub max_rows_per_file: Option<usize>,
 }
 
 impl IpcWriterOptions {
+    /// Whether the streaming sink writes a directory of files instead of a single file.
+    pub fn is_multi_file(&self) -> bool {
+        self.target_file_size.is_some() || self.max_rows_per_file.is_some()
+    }
+
     pub fn to_writer<W: Write>(&self, writer: W) -> IpcWriter<W> {
         IpcWriter::new(writer).with_compression(self.compression)
     }
@@ -0,0 +1,297 @@
+//! Conversion between a [`Schema`] and a [JSON Schema] describing the rows of a [`DataFrame`] as
+//! JSON objects.
+//!
+//! All columns are nullable, so every type also allows `null`. Integer and float widths and
+//! temporal types are given by the `format` keyword, binary data by a `base64` content encoding
+//! and the categories of an `Enum` by the `enum` keyword. Lists are arrays, `Array`s are arrays
+//! with a fixed number of items and structs are objects.
+//!
+//! The time unit and time zone of temporal types are not part of the JSON Schema, so they are
+//! read back as microseconds without a time zone.
+//!
+//! [JSON Schema]: https://json-schema.org
+use std::fmt;
+
+#[cfg(feature = "dtype-categorical")]
+use arrow::array::Utf8ViewArray;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonSchemaNode {
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    draft: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    types: Option<JsonTypes>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_encoding: Option<String>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<Option<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    items: Option<Box<JsonSchemaNode>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_items: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_items: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonTypes {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// The `properties` of an object, in the order of the columns or struct fields.
+struct Properties(Vec<(String, JsonSchemaNode)>);
+
+impl Serialize for Properties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, node) in &self.0 {
+            map.serialize_entry(name, node)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Properties {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PropertiesVisitor;
+
+        impl<'de> Visitor<'de> for PropertiesVisitor {
+            type Value = Properties;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of JSON schemas")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Properties, A::Error> {
+                let mut properties = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(entry) = map.next_entry()? {
+                    properties.push(entry);
+                }
+                Ok(Properties(properties))
+            }
+        }
+
+        deserializer.deserialize_map(PropertiesVisitor)
+    }
+}
+
+fn nullable(ty: &str) -> Option<JsonTypes> {
+    Some(JsonTypes::Many(vec![ty.to_string(), "null".to_string()]))
+}
+
+fn fields_to_properties<'a>(
+    fields: impl Iterator<Item = (&'a PlSmallStr, &'a DataType)>,
+) -> PolarsResult<Properties> {
+    let properties = fields
+        .map(|(name, dtype)| Ok((name.to_string(), dtype_to_node(dtype)?)))
+        .collect::<PolarsResult<_>>()?;
+    Ok(Properties(properties))
+}
+
+fn dtype_to_node(dtype: &DataType) -> PolarsResult<JsonSchemaNode> {
+    use DataType::*;
+    let (ty, format) = match dtype {
+        Boolean => ("boolean", None),
+        UInt8 => ("integer", Some("uint8")),
+        UInt16 => ("integer", Some("uint16")),
+        UInt32 => ("integer", Some("uint32")),
+        UInt64 => ("integer", Some("uint64")),
+        Int8 => ("integer", Some("int8")),
+        Int16 => ("integer", Some("int16")),
+        Int32 => ("integer", Some("int32")),
+        Int64 => ("integer", Some("int64")),
+        Float32 => ("number", Some("float")),
+        Float64 => ("number", Some("double")),
+        String => ("string", None),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => ("string", None),
+        Date => ("string", Some("date")),
+        Datetime(_, _) => ("string", Some("date-time")),
+        Time => ("string", Some("time")),
+        Duration(_) => ("string", Some("duration")),
+        Binary => {
+            return Ok(JsonSchemaNode {
+                types: nullable("string"),
+                content_encoding: Some("base64".to_string()),
+                ..Default::default()
+            })
+        },
+        #[cfg(feature = "dtype-categorical")]
+        Enum(Some(rev_map), _) => {
+            let categories = rev_map
+                .get_categories()
+                .values_iter()
+                .map(|category| Some(category.to_string()))
+                .chain(std::iter::once(None))
+                .collect();
+            return Ok(JsonSchemaNode {
+                types: nullable("string"),
+                categories: Some(categories),
+                ..Default::default()
+            });
+        },
+        List(inner) => {
+            return Ok(JsonSchemaNode {
+                types: nullable("array"),
+                items: Some(Box::new(dtype_to_node(inner)?)),
+                ..Default::default()
+            })
+        },
+        #[cfg(feature = "dtype-array")]
+        Array(inner, width) => {
+            return Ok(JsonSchemaNode {
+                types: nullable("array"),
+                items: Some(Box::new(dtype_to_node(inner)?)),
+                min_items: Some(*width),
+                max_items: Some(*width),
+                ..Default::default()
+            })
+        },
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => {
+            let fields = fields.iter().map(|field| (field.name(), field.dtype()));
+            return Ok(JsonSchemaNode {
+                types: nullable("object"),
+                properties: Some(fields_to_properties(fields)?),
+                ..Default::default()
+            });
+        },
+        Null => {
+            return Ok(JsonSchemaNode {
+                types: Some(JsonTypes::One("null".to_string())),
+                ..Default::default()
+            })
+        },
+        dt => polars_bail!(ComputeError: "cannot convert dtype {} to a JSON schema", dt),
+    };
+    Ok(JsonSchemaNode {
+        types: nullable(ty),
+        format: format.map(|format| format.to_string()),
+        ..Default::default()
+    })
+}
+
+/// Convert a [`Schema`] to a [JSON Schema] of the rows of a [`DataFrame`] with that schema.
+///
+/// [JSON Schema]: https://json-schema.org
+pub fn schema_to_json_schema(schema: &Schema) -> PolarsResult<String> {
+    let node = JsonSchemaNode {
+        draft: Some(JSON_SCHEMA_DRAFT.to_string()),
+        types: Some(JsonTypes::One("object".to_string())),
+        properties: Some(fields_to_properties(schema.iter())?),
+        ..Default::default()
+    };
+    serde_json::to_string(&node).map_err(to_compute_err)
+}
+
+fn properties_to_fields(properties: Option<Properties>) -> PolarsResult<Vec<Field>> {
+    let Some(properties) = properties else {
+        polars_bail!(ComputeError: "a JSON schema of an object must have 'properties'")
+    };
+    properties
+        .0
+        .into_iter()
+        .map(|(name, node)| Ok(Field::new(name.into(), node_to_dtype(node)?)))
+        .collect()
+}
+
+fn node_to_dtype(node: JsonSchemaNode) -> PolarsResult<DataType> {
+    let types = match &node.types {
+        Some(JsonTypes::One(ty)) => vec![ty.as_str()],
+        Some(JsonTypes::Many(types)) => types.iter().map(|ty| ty.as_str()).collect(),
+        None => polars_bail!(ComputeError: "a JSON schema must have a 'type'"),
+    };
+    let non_null = types
+        .iter()
+        .copied()
+        .filter(|ty| *ty != "null")
+        .collect::<Vec<_>>();
+    let ty = match non_null.as_slice() {
+        [] => return Ok(DataType::Null),
+        [ty] => *ty,
+        _ => {
+            polars_bail!(ComputeError: "cannot convert a JSON schema with types {:?} to a dtype", types)
+        },
+    };
+
+    let format = node.format.as_deref();
+    let dtype = match (ty, format) {
+        ("boolean", _) => DataType::Boolean,
+        ("integer", Some("uint8")) => DataType::UInt8,
+        ("integer", Some("uint16")) => DataType::UInt16,
+        ("integer", Some("uint32")) => DataType::UInt32,
+        ("integer", Some("uint64")) => DataType::UInt64,
+        ("integer", Some("int8")) => DataType::Int8,
+        ("integer", Some("int16")) => DataType::Int16,
+        ("integer", Some("int32")) => DataType::Int32,
+        ("integer", _) => DataType::Int64,
+        ("number", Some("float")) => DataType::Float32,
+        ("number", _) => DataType::Float64,
+        ("string", Some("date")) => DataType::Date,
+        ("string", Some("date-time")) => DataType::Datetime(TimeUnit::Microseconds, None),
+        ("string", Some("time")) => DataType::Time,
+        ("string", Some("duration")) => DataType::Duration(TimeUnit::Microseconds),
+        ("string", _) if node.content_encoding.as_deref() == Some("base64") => DataType::Binary,
+        #[cfg(feature = "dtype-categorical")]
+        ("string", _) if node.categories.is_some() => {
+            let categories = node
+                .categories
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            create_enum_dtype(Utf8ViewArray::from_slice_values(categories))
+        },
+        ("string", _) => DataType::String,
+        ("array", _) => {
+            let Some(items) = node.items else {
+                polars_bail!(ComputeError: "a JSON schema of an array must have 'items'")
+            };
+            let inner = Box::new(node_to_dtype(*items)?);
+            match (node.min_items, node.max_items) {
+                #[cfg(feature = "dtype-array")]
+                (Some(min_items), Some(max_items)) if min_items == max_items => {
+                    DataType::Array(inner, min_items)
+                },
+                _ => DataType::List(inner),
+            }
+        },
+        #[cfg(feature = "dtype-struct")]
+        ("object", _) => DataType::Struct(properties_to_fields(node.properties)?),
+        _ => polars_bail!(ComputeError: "cannot convert a JSON schema of type '{}' to a dtype", ty),
+    };
+    Ok(dtype)
+}
+
+/// Convert a [JSON Schema] of JSON objects to the [`Schema`] of a [`DataFrame`] with a column
+/// per property, in the order of the properties.
+///
+/// [JSON Schema]: https://json-schema.org
+pub fn json_schema_to_schema(json_schema: &str) -> PolarsResult<Schema> {
+    let node: JsonSchemaNode = serde_json::from_str(json_schema).map_err(to_compute_err)?;
+    let is_object = match &node.types {
+        Some(JsonTypes::One(ty)) => ty == "object",
+        Some(JsonTypes::Many(types)) => types.iter().any(|ty| ty == "object"),
+        None => false,
+    };
+    polars_ensure!(
+        is_object,
+        ComputeError: "expected a JSON schema of an object"
+    );
+    Ok(Schema::from_iter(properties_to_fields(node.properties)?))
+}
@@ -63,6 +63,7 @@
 //! ```
 //!
 pub(crate) mod infer;
+mod json_schema;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
@@ -77,6 +78,7 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+pub use self::json_schema::{json_schema_to_schema, schema_to_json_schema};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
@@ -343,6 +345,7 @@ where
                     NonZeroUsize::new(1 << 18).unwrap(),
                     false,
                     self.infer_schema_len,
+                    JsonUnionResolution::default(),
                     self.ignore_errors,
                     None,
                     None,
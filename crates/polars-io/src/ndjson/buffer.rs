@@ -8,6 +8,7 @@ use polars_core::prelude::*;
 use polars_time::prelude::string::infer::{infer_pattern_single, DatetimeInfer, TryFromWithUnit};
 #[cfg(any(feature = "dtype-datetime", feature = "dtype-date"))]
 use polars_time::prelude::string::Pattern;
+use simd_json::prelude::Writable;
 use simd_json::{BorrowedValue as Value, KnownKey, StaticNode};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,6 +24,7 @@ impl<'a> Hash for BufferKey<'a> {
 pub(crate) struct Buffer<'a> {
     name: &'a str,
     ignore_errors: bool,
+    string_fallback: bool,
     buf: AnyValueBuffer<'a>,
 }
 
@@ -96,6 +98,9 @@ impl Buffer<'_> {
             String(buf) => {
                 match value {
                     Value::String(v) => buf.append_value(v),
+                    // See `deserialize_all`.
+                    Value::Static(StaticNode::Null) => buf.append_null(),
+                    _ if self.string_fallback => buf.append_value(value.encode()),
                     _ => buf.append_null(),
                 }
                 Ok(())
@@ -113,7 +118,7 @@ impl Buffer<'_> {
                 Ok(())
             },
             All(dtype, buf) => {
-                let av = deserialize_all(value, dtype, self.ignore_errors)?;
+                let av = deserialize_all(value, dtype, self.ignore_errors, self.string_fallback)?;
                 buf.push(av);
                 Ok(())
             },
@@ -132,6 +137,7 @@ pub(crate) fn init_buffers(
     schema: &Schema,
     capacity: usize,
     ignore_errors: bool,
+    string_fallback: bool,
 ) -> PolarsResult<PlIndexMap<BufferKey, Buffer>> {
     schema
         .iter()
@@ -144,6 +150,7 @@ pub(crate) fn init_buffers(
                     name,
                     buf: av_buf,
                     ignore_errors,
+                    string_fallback,
                 },
             ))
         })
@@ -182,14 +189,30 @@ fn deserialize_all<'a>(
     json: &Value,
     dtype: &DataType,
     ignore_errors: bool,
+    string_fallback: bool,
 ) -> PolarsResult<AnyValue<'a>> {
     let out = match json {
+        Value::Static(StaticNode::Null) => AnyValue::Null,
+        Value::Static(_) | Value::String(_) | Value::Object(_)
+            if matches!(dtype, DataType::List(_)) =>
+        {
+            // A scalar in a list column is read as a list of one element.
+            let inner_dtype = dtype.inner_dtype().unwrap();
+            let val = deserialize_all(json, inner_dtype, ignore_errors, string_fallback)?;
+            let s =
+                Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &[val], inner_dtype, false)?;
+            AnyValue::List(s)
+        },
+        Value::String(s) => AnyValue::StringOwned(s.as_ref().into()),
+        // Under `JsonUnionResolution::StringFallback` values of other types end up in a string
+        // column when their type conflicts between rows, keep them as JSON text.
+        _ if string_fallback && dtype == &DataType::String => {
+            AnyValue::StringOwned(json.encode().into())
+        },
         Value::Static(StaticNode::Bool(b)) => AnyValue::Boolean(*b),
         Value::Static(StaticNode::I64(i)) => AnyValue::Int64(*i),
         Value::Static(StaticNode::U64(u)) => AnyValue::UInt64(*u),
         Value::Static(StaticNode::F64(f)) => AnyValue::Float64(*f),
-        Value::Static(StaticNode::Null) => AnyValue::Null,
-        Value::String(s) => AnyValue::StringOwned(s.as_ref().into()),
         Value::Array(arr) => {
             let Some(inner_dtype) = dtype.inner_dtype() else {
                 if ignore_errors {
@@ -199,7 +222,7 @@ fn deserialize_all<'a>(
             };
             let vals: Vec<AnyValue> = arr
                 .iter()
-                .map(|val| deserialize_all(val, inner_dtype, ignore_errors))
+                .map(|val| deserialize_all(val, inner_dtype, ignore_errors, string_fallback))
                 .collect::<PolarsResult<_>>()?;
             let s =
                 Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &vals, inner_dtype, false)?;
@@ -214,7 +237,7 @@ fn deserialize_all<'a>(
                     .iter()
                     .map(|field| {
                         if let Some(value) = document.get(field.name.as_str()) {
-                            deserialize_all(value, &field.dtype, ignore_errors)
+                            deserialize_all(value, &field.dtype, ignore_errors, string_fallback)
                         } else {
                             Ok(AnyValue::Null)
                         }
@@ -28,6 +28,7 @@ where
     n_rows: Option<usize>,
     n_threads: Option<usize>,
     infer_schema_len: Option<NonZeroUsize>,
+    union_resolution: JsonUnionResolution,
     chunk_size: NonZeroUsize,
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
@@ -82,6 +83,15 @@ where
         self
     }
 
+    /// Set how values whose type differs between rows are resolved during schema inference.
+    ///
+    /// With [`JsonUnionResolution::StringFallback`] values that aren't strings are also read
+    /// into `String` columns as their JSON text instead of as null.
+    pub fn with_union_resolution(mut self, union_resolution: JsonUnionResolution) -> Self {
+        self.union_resolution = union_resolution;
+        self
+    }
+
     pub fn with_n_threads(mut self, n: Option<usize>) -> Self {
         self.n_threads = n;
         self
@@ -123,6 +133,7 @@ where
             self.chunk_size,
             self.low_memory,
             self.infer_schema_len,
+            self.union_resolution,
             self.ignore_errors,
             self.row_index,
             self.predicate,
@@ -153,6 +164,7 @@ where
             n_rows: None,
             n_threads: None,
             infer_schema_len: Some(NonZeroUsize::new(100).unwrap()),
+            union_resolution: JsonUnionResolution::default(),
             schema: None,
             schema_overwrite: None,
             path: None,
@@ -177,6 +189,7 @@ where
             self.chunk_size,
             self.low_memory,
             self.infer_schema_len,
+            self.union_resolution,
             self.ignore_errors,
             self.row_index,
             self.predicate,
@@ -200,6 +213,7 @@ pub(crate) struct CoreJsonReader<'a> {
     chunk_size: NonZeroUsize,
     low_memory: bool,
     ignore_errors: bool,
+    string_fallback: bool,
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
@@ -216,6 +230,7 @@ impl<'a> CoreJsonReader<'a> {
         chunk_size: NonZeroUsize,
         low_memory: bool,
         infer_schema_len: Option<NonZeroUsize>,
+        union_resolution: JsonUnionResolution,
         ignore_errors: bool,
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
@@ -228,7 +243,11 @@ impl<'a> CoreJsonReader<'a> {
             None => {
                 let bytes: &[u8] = &reader_bytes;
                 let mut cursor = Cursor::new(bytes);
-                Arc::new(crate::ndjson::infer_schema(&mut cursor, infer_schema_len)?)
+                Arc::new(crate::ndjson::infer_schema(
+                    &mut cursor,
+                    infer_schema_len,
+                    union_resolution,
+                )?)
             },
         };
         if let Some(overwriting_schema) = schema_overwrite {
@@ -245,6 +264,7 @@ impl<'a> CoreJsonReader<'a> {
             chunk_size,
             low_memory,
             ignore_errors,
+            string_fallback: union_resolution == JsonUnionResolution::StringFallback,
             row_index,
             predicate,
             projection,
@@ -304,7 +324,12 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
+                    let mut buffers = init_buffers(
+                        &self.schema,
+                        capacity,
+                        self.ignore_errors,
+                        self.string_fallback,
+                    )?;
                     parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
                     let mut local_df = DataFrame::new(
                         buffers
@@ -0,0 +1,95 @@
+use polars_core::prelude::*;
+use simd_json::{BorrowedValue, StaticNode};
+
+use super::JsonUnionResolution;
+
+/// Infers the [`DataType`] of a JSON value. Conflicting array elements are resolved with
+/// `resolution`; `name` is the field the value belongs to and is only used in error messages.
+pub(super) fn infer_dtype(
+    value: &BorrowedValue,
+    resolution: JsonUnionResolution,
+    name: &str,
+) -> PolarsResult<DataType> {
+    Ok(match value {
+        BorrowedValue::Static(StaticNode::Bool(_)) => DataType::Boolean,
+        BorrowedValue::Static(StaticNode::U64(_) | StaticNode::I64(_)) => DataType::Int64,
+        BorrowedValue::Static(StaticNode::F64(_)) => DataType::Float64,
+        BorrowedValue::Static(StaticNode::Null) => DataType::Null,
+        BorrowedValue::String(_) => DataType::String,
+        BorrowedValue::Array(values) => {
+            let inner = values.iter().try_fold(DataType::Null, |acc, value| {
+                let dtype = infer_dtype(value, resolution, name)?;
+                merge_dtypes(acc, dtype, resolution, name)
+            })?;
+            DataType::List(Box::new(inner))
+        },
+        BorrowedValue::Object(object) => {
+            let fields = object
+                .iter()
+                .map(|(key, value)| {
+                    let dtype = infer_dtype(value, resolution, key)?;
+                    Ok(Field::new(key.as_ref().into(), dtype))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            DataType::Struct(fields)
+        },
+    })
+}
+
+/// Merges the inferred types of two values of the field `name` into a type that can hold both.
+pub(super) fn merge_dtypes(
+    lhs: DataType,
+    rhs: DataType,
+    resolution: JsonUnionResolution,
+    name: &str,
+) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => lhs,
+        (Null, dtype) | (dtype, Null) => dtype,
+        (Struct(lhs), Struct(rhs)) => Struct(merge_fields(lhs, rhs, resolution)?),
+        (List(lhs), List(rhs)) => List(Box::new(merge_dtypes(*lhs, *rhs, resolution, name)?)),
+        (Int64, Float64) | (Float64, Int64) => Float64,
+        (lhs, rhs) => match resolution {
+            JsonUnionResolution::Widen => match (lhs, rhs) {
+                (List(inner), scalar) | (scalar, List(inner)) => {
+                    List(Box::new(merge_dtypes(*inner, scalar, resolution, name)?))
+                },
+                (Boolean, Int64) | (Int64, Boolean) => Int64,
+                _ => String,
+            },
+            JsonUnionResolution::StringFallback => String,
+            JsonUnionResolution::Error => polars_bail!(
+                SchemaMismatch: "conflicting JSON types for field '{}': {} and {}",
+                name, lhs, rhs
+            ),
+        },
+    })
+}
+
+fn merge_fields(
+    lhs: Vec<Field>,
+    rhs: Vec<Field>,
+    resolution: JsonUnionResolution,
+) -> PolarsResult<Vec<Field>> {
+    let mut fields = lhs
+        .into_iter()
+        .map(|field| (field.name, field.dtype))
+        .collect::<PlIndexMap<_, _>>();
+    for field in rhs {
+        match fields.get_mut(&field.name) {
+            Some(dtype) => {
+                let lhs = std::mem::replace(dtype, DataType::Null);
+                *dtype = merge_dtypes(lhs, field.dtype, resolution, &field.name)?;
+            },
+            None => {
+                fields.insert(field.name, field.dtype);
+            },
+        }
+    }
+    Ok(fields
+        .into_iter()
+        .map(|(name, dtype)| Field::new(name, dtype))
+        .collect())
+}
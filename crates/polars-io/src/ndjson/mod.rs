@@ -1,20 +1,60 @@
 use std::num::NonZeroUsize;
 
-use arrow::array::StructArray;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 pub(crate) mod buffer;
 pub mod core;
+mod infer;
 
+/// How to resolve a JSON value whose type differs between rows (or between the elements of an
+/// array) during schema inference.
+///
+/// Whatever the resolution, `null` never conflicts with another type, integers and floats are
+/// inferred as `Float64`, and objects with different keys are inferred as a struct containing
+/// all of the keys.
+///
+/// With [`StringFallback`](Self::StringFallback), values that are read into a `String` column
+/// but aren't strings are kept as their JSON text, e.g. `1` as `"1"` and `{"x": 1}` as
+/// `"{"x":1}"`. With the other resolutions, and for the `String` columns of a schema that is
+/// given to a lazy scan, they are read as null.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonUnionResolution {
+    /// Widen to a type that can hold both values: booleans to integers and scalars to lists of
+    /// that scalar. Types that cannot be widened into each other are inferred as `String`.
+    #[default]
+    Widen,
+    /// Infer any conflict as `String`. Nested values are kept as their JSON text.
+    StringFallback,
+    /// Raise an error on conflicting types.
+    Error,
+}
+
+/// Infer the schema of an NDJSON file from its first `infer_schema_len` rows, or from all rows
+/// if `None`.
 pub fn infer_schema<R: std::io::BufRead>(
     reader: &mut R,
     infer_schema_len: Option<NonZeroUsize>,
+    union_resolution: JsonUnionResolution,
 ) -> PolarsResult<Schema> {
-    let dtypes = polars_json::ndjson::iter_unique_dtypes(reader, infer_schema_len)?;
-    let dtype = crate::json::infer::dtypes_to_supertype(dtypes.map(|dt| DataType::from(&dt)))?;
-    let schema = StructArray::get_fields(&dtype.to_arrow(CompatLevel::newest()))
-        .iter()
-        .map(Into::<Field>::into)
-        .collect();
-    Ok(schema)
+    let mut dtype = DataType::Null;
+    polars_json::ndjson::try_for_each_value(reader, infer_schema_len, |value| {
+        let row_dtype = infer::infer_dtype(value, union_resolution, "")?;
+        dtype = infer::merge_dtypes(
+            std::mem::replace(&mut dtype, DataType::Null),
+            row_dtype,
+            union_resolution,
+            "",
+        )?;
+        Ok(())
+    })?;
+
+    match dtype {
+        DataType::Struct(fields) => Ok(Schema::from_iter(fields)),
+        dtype => {
+            polars_bail!(ComputeError: "expected NDJSON rows to be JSON objects, got {}", dtype)
+        },
+    }
 }
@@ -2,7 +2,256 @@
 
 use std::sync::Arc;
 
+use arrow::datatypes::{ArrowSchema, Metadata};
+use polars_error::{polars_bail, PolarsResult};
+use polars_parquet::arrow::ARROW_SCHEMA_META_KEY;
 pub use polars_parquet::parquet::metadata::FileMetaData;
+use polars_parquet::parquet::metadata::{KeyValue, SchemaDescriptor};
 pub use polars_parquet::read::statistics::{deserialize, Statistics as ParquetStatistics};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 pub type FileMetaDataRef = Arc<FileMetaData>;
+
+/// Key-value metadata of a Parquet file and of its columns, such as lineage or tags.
+///
+/// The metadata of a column is written to all of its column chunks. Keys without a value are
+/// not read, and neither is the Arrow schema that is stored in the metadata of the file.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyValueMetadata {
+    /// The key-value pairs of the file.
+    pub file: Vec<(PlSmallStr, PlSmallStr)>,
+    /// The key-value pairs of each (top-level) column.
+    pub columns: Vec<(PlSmallStr, Vec<(PlSmallStr, PlSmallStr)>)>,
+}
+
+impl KeyValueMetadata {
+    /// Add the pair `key` and `value` to the metadata of the file.
+    pub fn with_file(mut self, key: impl Into<PlSmallStr>, value: impl Into<PlSmallStr>) -> Self {
+        self.file.push((key.into(), value.into()));
+        self
+    }
+
+    /// Add the pair `key` and `value` to the metadata of the column `name`.
+    pub fn with_column(
+        mut self,
+        name: impl Into<PlSmallStr>,
+        key: impl Into<PlSmallStr>,
+        value: impl Into<PlSmallStr>,
+    ) -> Self {
+        let name = name.into();
+        let pair = (key.into(), value.into());
+        match self.columns.iter_mut().find(|(column, _)| *column == name) {
+            Some((_, pairs)) => pairs.push(pair),
+            None => self.columns.push((name, vec![pair])),
+        }
+        self
+    }
+
+    /// Get the value of `key` in the metadata of the file.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        find(&self.file, key)
+    }
+
+    /// Get the value of `key` in the metadata of the column `name`.
+    pub fn get_column(&self, name: &str, key: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .and_then(|(_, pairs)| find(pairs, key))
+    }
+
+    /// Reads the key-value metadata of a file, where the metadata of a column is that of its
+    /// column chunks in the first row group.
+    pub fn from_file_metadata(metadata: &FileMetaData) -> Self {
+        let file = metadata
+            .key_value_metadata()
+            .iter()
+            .flatten()
+            .filter(|kv| kv.key != ARROW_SCHEMA_META_KEY)
+            .filter_map(to_pair)
+            .collect();
+
+        let mut columns: Vec<(PlSmallStr, Vec<(PlSmallStr, PlSmallStr)>)> = vec![];
+        for column in metadata
+            .row_groups
+            .first()
+            .iter()
+            .flat_map(|rg| rg.columns())
+        {
+            // The metadata of encrypted columns is missing if they are not decrypted.
+            let meta_data = column.column_chunk().meta_data.as_ref();
+            let Some(key_values) = meta_data.and_then(|m| m.key_value_metadata.as_ref()) else {
+                continue;
+            };
+            let name = &column.descriptor().path_in_schema[0];
+            let pairs = match columns.iter_mut().find(|(column, _)| column == name) {
+                Some((_, pairs)) => pairs,
+                None => {
+                    columns.push((name.clone(), vec![]));
+                    &mut columns.last_mut().unwrap().1
+                },
+            };
+            // The leaves of a nested column all hold the metadata of the column.
+            for pair in key_values.iter().filter_map(to_pair) {
+                if !pairs.contains(&pair) {
+                    pairs.push(pair);
+                }
+            }
+        }
+        Self { file, columns }
+    }
+
+    /// Returns the key-value pairs of the file to write.
+    pub(crate) fn file_key_values(&self) -> PolarsResult<Option<Vec<KeyValue>>> {
+        if self.file.is_empty() {
+            return Ok(None);
+        }
+        self.file
+            .iter()
+            .map(|(key, value)| {
+                if key == ARROW_SCHEMA_META_KEY {
+                    polars_bail!(InvalidOperation: "cannot write the key '{}' to the metadata of a parquet file, it holds the schema of the file", key);
+                }
+                Ok(KeyValue::new(key.to_string(), value.to_string()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Returns the key-value pairs to write to the column chunks of each leaf column of
+    /// `parquet_schema`.
+    pub(crate) fn leaf_key_values(
+        &self,
+        schema: &ArrowSchema,
+        parquet_schema: &SchemaDescriptor,
+    ) -> PolarsResult<Vec<Option<Vec<KeyValue>>>> {
+        for (name, _) in &self.columns {
+            if !schema.contains(name) {
+                polars_bail!(ColumnNotFound: "cannot write metadata for column '{}', it is not in the schema", name);
+            }
+        }
+        Ok(parquet_schema
+            .columns()
+            .iter()
+            .map(|column| {
+                let name = &column.path_in_schema[0];
+                self.columns
+                    .iter()
+                    .find(|(column, _)| column == name)
+                    .map(|(_, pairs)| {
+                        pairs
+                            .iter()
+                            .map(|(key, value)| KeyValue::new(key.to_string(), value.to_string()))
+                            .collect()
+                    })
+            })
+            .collect())
+    }
+}
+
+/// Arrow metadata of the (top-level) columns of a Parquet file, such as the name of an extension
+/// type or geospatial metadata.
+///
+/// The metadata is stored with the fields of the Arrow schema in the metadata of the file, so
+/// that Arrow readers attach it to the schema. Columns with an `ARROW:extension:name` key are
+/// read by Arrow readers as that extension type, and by Polars as their storage type.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldMetadata {
+    /// The metadata of each column.
+    pub columns: Vec<(PlSmallStr, Metadata)>,
+}
+
+impl FieldMetadata {
+    /// Add the pair `key` and `value` to the metadata of the column `name`.
+    pub fn with_column(
+        mut self,
+        name: impl Into<PlSmallStr>,
+        key: impl Into<PlSmallStr>,
+        value: impl Into<PlSmallStr>,
+    ) -> Self {
+        let name = name.into();
+        let metadata = match self.columns.iter_mut().find(|(column, _)| *column == name) {
+            Some((_, metadata)) => metadata,
+            None => {
+                self.columns.push((name, Metadata::new()));
+                &mut self.columns.last_mut().unwrap().1
+            },
+        };
+        metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Mark the column `name` as the Arrow extension type `extension_name`, with the serialized
+    /// `extension_metadata` of the type.
+    pub fn with_extension(
+        self,
+        name: impl Into<PlSmallStr>,
+        extension_name: impl Into<PlSmallStr>,
+        extension_metadata: Option<PlSmallStr>,
+    ) -> Self {
+        let name = name.into();
+        let this = self.with_column(name.clone(), EXTENSION_NAME_KEY, extension_name);
+        match extension_metadata {
+            Some(metadata) => this.with_column(name, EXTENSION_METADATA_KEY, metadata),
+            None => this,
+        }
+    }
+
+    /// Get the metadata of the column `name`.
+    pub fn get_column(&self, name: &str) -> Option<&Metadata> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, metadata)| metadata)
+    }
+
+    /// Get the name of the Arrow extension type of the column `name`.
+    pub fn extension_name(&self, name: &str) -> Option<&str> {
+        self.get_column(name)?
+            .get(EXTENSION_NAME_KEY)
+            .map(|value| value.as_str())
+    }
+
+    /// Reads the metadata of the fields of `schema` that have any.
+    pub fn from_schema(schema: &ArrowSchema) -> Self {
+        let columns = schema
+            .iter_values()
+            .filter(|field| !field.metadata.is_empty())
+            .map(|field| (field.name.clone(), field.metadata.clone()))
+            .collect();
+        Self { columns }
+    }
+
+    /// Adds the metadata to the fields of `schema`.
+    pub(crate) fn apply(&self, schema: &mut ArrowSchema) -> PolarsResult<()> {
+        for (name, metadata) in &self.columns {
+            let Some(i) = schema.index_of(name) else {
+                polars_bail!(ColumnNotFound: "cannot write field metadata for column '{}', it is not in the schema", name);
+            };
+            let (_, field) = schema.get_at_index_mut(i).unwrap();
+            field
+                .metadata
+                .extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        Ok(())
+    }
+}
+
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+fn find<'a>(pairs: &'a [(PlSmallStr, PlSmallStr)], key: &str) -> Option<&'a str> {
+    pairs
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.as_str())
+}
+
+fn to_pair(kv: &KeyValue) -> Option<(PlSmallStr, PlSmallStr)> {
+    let value = kv.value.as_ref()?;
+    Some((kv.key.as_str().into(), value.as_str().into()))
+}
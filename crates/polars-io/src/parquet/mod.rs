@@ -2,4 +2,5 @@
 
 pub mod metadata;
 pub mod read;
+pub mod summary;
 pub mod write;
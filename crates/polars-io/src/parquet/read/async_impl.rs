@@ -1,8 +1,12 @@
 //! Read parquet files in parallel from the Object Store without a third party crate.
 use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use arrow::datatypes::ArrowSchemaRef;
 use bytes::Bytes;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
 use object_store::path::Path as ObjectPath;
 use polars_core::config::{get_rg_prefetch_size, verbose};
 use polars_core::prelude::*;
@@ -10,10 +14,11 @@ use polars_parquet::read::RowGroupMetaData;
 use polars_parquet::write::FileMetaData;
 use polars_utils::pl_str::PlSmallStr;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 
 use super::mmap::ColumnStore;
 use super::predicates::read_this_row_group;
+use super::PrefetchOptions;
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, PolarsObjectStore,
 };
@@ -43,7 +48,8 @@ impl ParquetObjectStore {
         let path = object_path_from_str(&prefix)?;
 
         Ok(ParquetObjectStore {
-            store: PolarsObjectStore::new(store),
+            store: PolarsObjectStore::new(store)
+                .with_cloud_options(options.map(|options| options.for_url(uri))),
             path,
             length: None,
             metadata,
@@ -170,7 +176,7 @@ async fn download_projection(
     async_reader: Arc<ParquetObjectStore>,
     sender: QueueSend,
     rg_index: usize,
-) -> bool {
+) -> Option<Duration> {
     let async_reader = &async_reader;
     let row_group = &row_group;
     let fields = fields.as_ref();
@@ -196,6 +202,7 @@ async fn download_projection(
         }
     });
 
+    let start = Instant::now();
     let result = async_reader.get_ranges(&ranges).await.map(|bytes| {
         (
             rg_index,
@@ -206,7 +213,8 @@ async fn download_projection(
                 .collect::<Vec<_>>(),
         )
     });
-    sender.send(result).await.is_ok()
+    let latency = start.elapsed();
+    sender.send(result).await.is_ok().then_some(latency)
 }
 
 async fn download_row_group(
@@ -214,9 +222,9 @@ async fn download_row_group(
     async_reader: Arc<ParquetObjectStore>,
     sender: QueueSend,
     rg_index: usize,
-) -> bool {
+) -> Option<Duration> {
     if rg.columns().is_empty() {
-        return true;
+        return Some(Duration::ZERO);
     }
     let offset = rg.columns().iter().map(|c| c.byte_range().0).min().unwrap();
     let (max_offset, len) = rg
@@ -226,6 +234,7 @@ async fn download_row_group(
         .max_by_key(|k| k.0)
         .unwrap();
 
+    let start = Instant::now();
     let result = async_reader
         .get_range(offset as usize, (max_offset - offset + len) as usize)
         .await
@@ -247,13 +256,131 @@ async fn download_row_group(
                     .collect::<DownloadedRowGroup>(),
             )
         });
+    let latency = start.elapsed();
+    sender.send(result).await.is_ok().then_some(latency)
+}
+
+/// The number of bytes of the columns of `rg` that are downloaded.
+fn download_size(rg: &RowGroupMetaData, fields: Option<&[PlSmallStr]>) -> usize {
+    rg.columns()
+        .iter()
+        .filter(|meta| match fields {
+            Some(fields) => fields
+                .iter()
+                .any(|name| meta.descriptor().path_in_schema[0] == name),
+            None => true,
+        })
+        .map(|meta| meta.byte_range().1 as usize)
+        .sum()
+}
+
+/// The bytes of the row groups that are downloaded, or being downloaded, but not read yet.
+#[derive(Default)]
+struct PrefetchBudget {
+    buffered: AtomicUsize,
+    released: Notify,
+}
+
+impl PrefetchBudget {
+    fn buffered(&self) -> usize {
+        self.buffered.load(Ordering::Relaxed)
+    }
+
+    fn reserve(&self, bytes: usize) {
+        self.buffered.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn release(&self, bytes: usize) {
+        self.buffered.fetch_sub(bytes, Ordering::Relaxed);
+        self.released.notify_one();
+    }
+}
+
+/// Sizes the number of concurrent row group downloads by the observed throughput.
+///
+/// The concurrency is adjusted once per window of as many downloads as the concurrency. It
+/// doubles as long as that increases the throughput, and backs off when the throughput drops or
+/// when the latency of the requests grows without a gain in throughput.
+struct PrefetchScheduler {
+    min_concurrency: usize,
+    max_concurrency: usize,
+    memory_budget: usize,
+    concurrency: usize,
+    window_start: Instant,
+    window_bytes: usize,
+    window_downloads: usize,
+    window_latency: Duration,
+    last_throughput: f64,
+    min_latency: Duration,
+}
+
+impl PrefetchScheduler {
+    fn new(options: &PrefetchOptions, now: Instant) -> Self {
+        let max_concurrency = options
+            .max_concurrency
+            .unwrap_or_else(get_rg_prefetch_size)
+            .max(1);
+        let min_concurrency = options.min_concurrency.clamp(1, max_concurrency);
+        Self {
+            min_concurrency,
+            max_concurrency,
+            memory_budget: options.memory_budget.unwrap_or(usize::MAX),
+            concurrency: min_concurrency,
+            window_start: now,
+            window_bytes: 0,
+            window_downloads: 0,
+            window_latency: Duration::ZERO,
+            last_throughput: 0.0,
+            min_latency: Duration::MAX,
+        }
+    }
+
+    /// Whether a download of `bytes` can start while `in_flight` downloads are running and
+    /// `buffered` bytes are not read yet. A download can always start if nothing is buffered, so
+    /// that row groups larger than the memory budget are read as well.
+    fn can_start(&self, in_flight: usize, buffered: usize, bytes: usize) -> bool {
+        if in_flight == 0 && buffered == 0 {
+            return true;
+        }
+        in_flight < self.concurrency && buffered.saturating_add(bytes) <= self.memory_budget
+    }
 
-    sender.send(result).await.is_ok()
+    /// Record a finished download of `bytes`. Returns whether the concurrency changed.
+    fn on_complete(&mut self, bytes: usize, latency: Duration, now: Instant) -> bool {
+        self.window_bytes += bytes;
+        self.window_downloads += 1;
+        self.window_latency += latency;
+        self.min_latency = self.min_latency.min(latency);
+        if self.window_downloads < self.concurrency {
+            return false;
+        }
+
+        let elapsed = now
+            .saturating_duration_since(self.window_start)
+            .max(Duration::from_micros(1));
+        let throughput = self.window_bytes as f64 / elapsed.as_secs_f64();
+        let mean_latency = self.window_latency / self.window_downloads as u32;
+        let previous = self.concurrency;
+        if throughput > self.last_throughput * 1.1 {
+            self.concurrency = (self.concurrency * 2).min(self.max_concurrency);
+        } else if throughput < self.last_throughput * 0.9 || mean_latency > self.min_latency * 4 {
+            self.concurrency =
+                (self.concurrency - (self.concurrency / 4).max(1)).max(self.min_concurrency);
+        }
+
+        self.last_throughput = throughput;
+        self.window_start = now;
+        self.window_bytes = 0;
+        self.window_downloads = 0;
+        self.window_latency = Duration::ZERO;
+        self.concurrency != previous
+    }
 }
 
 pub struct FetchRowGroupsFromObjectStore {
     rg_q: Arc<Mutex<Receiver<PolarsResult<QueuePayload>>>>,
     prefetched_rg: PlHashMap<usize, DownloadedRowGroup>,
+    budget: Arc<PrefetchBudget>,
 }
 
 impl FetchRowGroupsFromObjectStore {
@@ -264,6 +391,7 @@ impl FetchRowGroupsFromObjectStore {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         row_group_range: Range<usize>,
         row_groups: &[RowGroupMetaData],
+        prefetch: PrefetchOptions,
     ) -> PolarsResult<Self> {
         let projected_fields: Option<Arc<[PlSmallStr]>> = projection.map(|projection| {
             projection
@@ -304,60 +432,66 @@ impl FetchRowGroupsFromObjectStore {
             row_groups.iter().cloned().enumerate().collect()
         };
         let reader = Arc::new(reader);
-        let msg_limit = get_rg_prefetch_size();
-
-        if verbose() {
-            eprintln!("POLARS ROW_GROUP PREFETCH_SIZE: {}", msg_limit)
+        let mut scheduler = PrefetchScheduler::new(&prefetch, Instant::now());
+        let verbose = verbose();
+        if verbose {
+            eprintln!(
+                "POLARS ROW_GROUP PREFETCH_SIZE: {}..{}",
+                scheduler.min_concurrency, scheduler.max_concurrency
+            )
         }
 
-        let (snd, rcv) = channel(msg_limit);
+        let (snd, rcv) = channel(scheduler.max_concurrency);
         let snd = Arc::new(snd);
+        let budget = Arc::new(PrefetchBudget::default());
+        let download_budget = budget.clone();
 
         get_runtime().spawn(async move {
-            let chunk_len = msg_limit;
-            let mut handles = Vec::with_capacity(chunk_len.clamp(0, row_groups.len()));
-            for chunk in row_groups.chunks_mut(chunk_len) {
-                // Start downloads concurrently
-                for (i, rg) in chunk {
-                    let rg = std::mem::take(rg);
-
-                    match &projected_fields {
-                        Some(projected_fields) => {
-                            let handle = tokio::spawn(download_projection(
-                                projected_fields.clone(),
-                                rg,
-                                reader.clone(),
-                                snd.clone(),
-                                *i,
-                            ));
-                            handles.push(handle)
-                        },
+            let budget = download_budget;
+            let mut downloads = FuturesUnordered::new();
+            let mut row_groups = row_groups.into_iter().peekable();
+            loop {
+                // Start as many downloads as the concurrency and the memory budget allow.
+                while let Some((_, rg)) = row_groups.peek() {
+                    let bytes = download_size(rg, projected_fields.as_deref());
+                    if !scheduler.can_start(downloads.len(), budget.buffered(), bytes) {
+                        break;
+                    }
+                    let (i, rg) = row_groups.next().unwrap();
+                    budget.reserve(bytes);
+
+                    let handle = match &projected_fields {
+                        Some(projected_fields) => tokio::spawn(download_projection(
+                            projected_fields.clone(),
+                            rg,
+                            reader.clone(),
+                            snd.clone(),
+                            i,
+                        )),
                         None => {
-                            let handle = tokio::spawn(download_row_group(
-                                rg,
-                                reader.clone(),
-                                snd.clone(),
-                                *i,
-                            ));
-                            handles.push(handle)
+                            tokio::spawn(download_row_group(rg, reader.clone(), snd.clone(), i))
                         },
-                    }
+                    };
+                    downloads.push(handle.map(move |latency| (latency, bytes)));
                 }
 
-                // Wait n - 3 tasks, so we already start the next downloads earlier.
-                for task in handles.drain(..handles.len().saturating_sub(3)) {
-                    let succeeded = task.await.unwrap();
-                    if !succeeded {
+                if downloads.is_empty() {
+                    if row_groups.peek().is_none() {
                         return;
                     }
+                    // Wait until row groups are read, unless the reader is dropped.
+                    tokio::select! {
+                        _ = budget.released.notified() => continue,
+                        _ = snd.closed() => return,
+                    }
                 }
-            }
 
-            // Drain remaining tasks.
-            for task in handles.drain(..) {
-                let succeeded = task.await.unwrap();
-                if !succeeded {
+                let (latency, bytes) = downloads.next().await.unwrap();
+                let Some(latency) = latency.unwrap() else {
                     return;
+                };
+                if scheduler.on_complete(bytes, latency, Instant::now()) && verbose {
+                    eprintln!("POLARS ROW_GROUP PREFETCH_SIZE: {}", scheduler.concurrency)
                 }
             }
         });
@@ -365,6 +499,7 @@ impl FetchRowGroupsFromObjectStore {
         Ok(FetchRowGroupsFromObjectStore {
             rg_q: Arc::new(Mutex::new(rcv)),
             prefetched_rg: Default::default(),
+            budget,
         })
     }
 
@@ -390,7 +525,65 @@ impl FetchRowGroupsFromObjectStore {
             .flat_map(|i| self.prefetched_rg.remove(&i))
             .flat_map(|rg| rg.into_iter())
             .collect::<PlHashMap<_, _>>();
+        let bytes = received.values().map(|bytes| bytes.len()).sum::<usize>();
+        if bytes > 0 {
+            self.budget.release(bytes);
+        }
 
         Ok(ColumnStore::Fetched(received))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Run windows of downloads of 1MB on a link where requests take 100ms and that saturates at
+    /// 16 concurrent requests, after which the requests take proportionally longer.
+    fn simulate(scheduler: &mut PrefetchScheduler, now: &mut Instant, windows: usize) {
+        for _ in 0..windows {
+            let concurrency = scheduler.concurrency;
+            let latency = Duration::from_millis(100) * (concurrency as u32).div_ceil(16);
+            *now += latency;
+            for _ in 0..concurrency {
+                scheduler.on_complete(1 << 20, latency, *now);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefetch_scheduler() {
+        let mut now = Instant::now();
+        let options = PrefetchOptions::default().with_max_concurrency(Some(128));
+        let mut scheduler = PrefetchScheduler::new(&options, now);
+        assert_eq!(scheduler.concurrency, 4);
+
+        // The concurrency grows while the throughput does, and stops when the link saturates.
+        simulate(&mut scheduler, &mut now, 10);
+        assert!((16..=32).contains(&scheduler.concurrency));
+
+        // The concurrency backs off when the requests slow down.
+        let concurrency = scheduler.concurrency;
+        let latency = Duration::from_secs(2);
+        now += latency;
+        for _ in 0..concurrency {
+            scheduler.on_complete(1 << 20, latency, now);
+        }
+        assert!(scheduler.concurrency < concurrency);
+        assert!(scheduler.concurrency >= 4);
+    }
+
+    #[test]
+    fn test_prefetch_memory_budget() {
+        let options = PrefetchOptions::default()
+            .with_min_concurrency(8)
+            .with_memory_budget(Some(100));
+        let scheduler = PrefetchScheduler::new(&options, Instant::now());
+        assert!(scheduler.can_start(1, 40, 60));
+        assert!(!scheduler.can_start(1, 50, 60));
+        assert!(!scheduler.can_start(8, 0, 10));
+        // A row group larger than the budget is downloaded once nothing else is buffered.
+        assert!(scheduler.can_start(0, 0, 1000));
+        assert!(!scheduler.can_start(0, 10, 1000));
+    }
+}
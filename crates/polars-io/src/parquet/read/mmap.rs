@@ -1,12 +1,13 @@
 use arrow::array::Array;
-use arrow::datatypes::Field;
+use arrow::compute::cast::{cast, CastOptionsImpl};
+use arrow::datatypes::{ArrowDataType, Field};
 #[cfg(feature = "async")]
 use bytes::Bytes;
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    column_iter_to_arrays, BasicDecompressor, ColumnChunkMetaData, Filter, PageReader,
+    column_iter_to_arrays, take_buffer, BasicDecompressor, ColumnChunkMetaData, Filter, PageReader,
 };
 use polars_utils::mmap::{MemReader, MemSlice};
 
@@ -27,6 +28,17 @@ pub enum ColumnStore {
     Fetched(PlHashMap<u64, Bytes>),
 }
 
+impl ColumnStore {
+    /// The bytes of the whole file, if it is local.
+    pub(super) fn local_file(&self) -> Option<&MemSlice> {
+        match self {
+            Self::Local(bytes) => Some(bytes),
+            #[cfg(feature = "async")]
+            Self::Fetched(_) => None,
+        }
+    }
+}
+
 /// For local files memory maps all columns that are part of the parquet field `field_name`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
@@ -66,15 +78,39 @@ pub fn to_deserializer(
     field: Field,
     filter: Option<Filter>,
 ) -> PolarsResult<Box<dyn Array>> {
+    // Dictionary arrays are decoded from the dictionary pages, which requires all data pages to
+    // be dictionary-encoded. Otherwise the values are decoded and encoded again.
+    if let ArrowDataType::Dictionary(_, values, _) = field.dtype() {
+        if !columns
+            .iter()
+            .all(|(column_meta, _)| column_meta.is_fully_dictionary_encoded())
+        {
+            let dtype = field.dtype().clone();
+            let values_field = Field::new(
+                field.name.clone(),
+                values.as_ref().clone(),
+                field.is_nullable,
+            );
+            let array = to_deserializer(columns, values_field, filter)?;
+            return cast(array.as_ref(), &dtype, CastOptionsImpl::default());
+        }
+    }
+
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
         .map(|(column_meta, chunk)| {
             // Advise fetching the data for the column chunk
             chunk.prefetch();
 
-            let pages = PageReader::new(MemReader::new(chunk), column_meta, vec![], usize::MAX);
+            // Reuse the scratch buffers of column chunks that were decoded earlier on this thread.
+            let pages = PageReader::new(
+                MemReader::new(chunk),
+                column_meta,
+                take_buffer(),
+                usize::MAX,
+            );
             (
-                BasicDecompressor::new(pages, vec![]),
+                BasicDecompressor::new(pages, take_buffer()),
                 &column_meta.descriptor().descriptor.primitive_type,
             )
         })
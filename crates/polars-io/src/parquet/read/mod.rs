@@ -32,8 +32,9 @@ consider compiling with polars-bigidx feature (polars-u64-idx package on python)
 or set 'streaming'",
 ));
 
-pub use options::{ParallelStrategy, ParquetOptions};
+pub use options::{ParallelStrategy, ParquetOptions, PrefetchOptions};
 use polars_error::{ErrString, PolarsError};
+pub use polars_parquet::parquet::encryption::{FileDecryptionProperties, KeyRetriever};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
@@ -42,5 +43,5 @@ pub use utils::materialize_empty_df;
 pub mod _internal {
     pub use super::metadata::PartitionedColumnChunkMD;
     pub use super::mmap::to_deserializer;
-    pub use super::predicates::read_this_row_group;
+    pub use super::predicates::{read_this_row_group, read_this_row_group_with_bloom_filters};
 }
@@ -7,6 +7,78 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Read the metadata of the files from the `_metadata` summary file of the dataset, if it
+    /// has one, instead of from the footer of every file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub use_metadata_summary: bool,
+    /// Prefetching of row groups from cloud storage.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prefetch: PrefetchOptions,
+    /// The streaming engine decodes row groups whose projected columns take more than this many
+    /// bytes uncompressed in morsels of rows, instead of materializing the whole row group. If
+    /// `None`, this is only done with `low_memory`, for row groups of more than 64 MiB.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub large_row_group_bytes: Option<usize>,
+    /// Read the string columns as `Categorical`, decoding dictionary-encoded column chunks
+    /// directly into categoricals.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dictionary_as_categorical: bool,
+}
+
+impl ParquetOptions {
+    /// The size above which row groups are decoded in morsels, if any.
+    pub fn large_row_group_bytes(&self) -> Option<usize> {
+        self.large_row_group_bytes
+            .or(self.low_memory.then_some(LOW_MEMORY_ROW_GROUP_BYTES))
+    }
+}
+
+/// The size above which row groups are decoded in morsels with `low_memory`.
+const LOW_MEMORY_ROW_GROUP_BYTES: usize = 64 << 20;
+
+/// Settings of the prefetching of row groups from cloud storage.
+///
+/// The number of concurrent downloads starts at `min_concurrency` and adapts to the observed
+/// throughput and latency of the requests, up to `max_concurrency`. Downloads wait while the row
+/// groups that are downloaded but not read yet take more than `memory_budget` bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrefetchOptions {
+    pub min_concurrency: usize,
+    /// If `None`, `POLARS_ROW_GROUP_PREFETCH_SIZE` or 128.
+    pub max_concurrency: Option<usize>,
+    /// If `None`, the memory of the prefetched row groups is not limited.
+    pub memory_budget: Option<usize>,
+}
+
+impl Default for PrefetchOptions {
+    fn default() -> Self {
+        Self {
+            min_concurrency: 4,
+            max_concurrency: None,
+            memory_budget: None,
+        }
+    }
+}
+
+impl PrefetchOptions {
+    /// Download at most `max_concurrency` row groups at the same time.
+    pub fn with_max_concurrency(mut self, max_concurrency: Option<usize>) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Start with `min_concurrency` concurrent downloads and never use fewer.
+    pub fn with_min_concurrency(mut self, min_concurrency: usize) -> Self {
+        self.min_concurrency = min_concurrency;
+        self
+    }
+
+    /// Limit the bytes of the row groups that are downloaded but not read yet.
+    pub fn with_memory_budget(mut self, memory_budget: Option<usize>) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -1,8 +1,16 @@
+use std::io::Cursor;
+use std::sync::OnceLock;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::prelude::*;
-use polars_parquet::read::statistics::{deserialize, Statistics};
+use polars_parquet::parquet::bloom_filter;
+use polars_parquet::read::statistics::{deserialize, deserialize_column_index, Statistics};
+use polars_parquet::read::{read_column_index, read_offset_index, ColumnChunkMetaData};
+use polars_parquet::write::hash_array;
+use polars_utils::mmap::MemSlice;
 
 use crate::parquet::read::metadata::PartitionedColumnChunkMD;
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, ValueSetFilter};
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -15,10 +23,90 @@ impl ColumnStats {
     }
 }
 
+/// The bloom filter of a column chunk, read from the file the first time it is used.
+struct ColumnChunkBloomFilter {
+    dtype: DataType,
+    metadata: ColumnChunkMetaData,
+    file: MemSlice,
+    bitset: OnceLock<Vec<u8>>,
+}
+
+impl std::fmt::Debug for ColumnChunkBloomFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnChunkBloomFilter")
+            .field("dtype", &self.dtype)
+            .field("offset", &self.metadata.metadata().bloom_filter_offset)
+            .finish()
+    }
+}
+
+impl ColumnChunkBloomFilter {
+    /// Returns the bloom filter of a flat, unencrypted column if it has one.
+    fn new(field: &ArrowField, md: &[&ColumnChunkMetaData], file: &MemSlice) -> Option<Self> {
+        let [metadata] = md else {
+            return None;
+        };
+        if metadata.metadata().bloom_filter_offset.is_none()
+            || metadata.column_chunk().crypto_metadata.is_some()
+        {
+            return None;
+        }
+        Some(Self {
+            dtype: DataType::from_arrow(&field.dtype, true),
+            metadata: (*metadata).clone(),
+            file: file.clone(),
+            bitset: OnceLock::new(),
+        })
+    }
+
+    /// An empty bitset if the bloom filter can't be read or is not supported.
+    fn bitset(&self) -> &[u8] {
+        self.bitset.get_or_init(|| {
+            let mut bitset = vec![];
+            let mut reader = Cursor::new(self.file.as_ref());
+            if bloom_filter::read(&self.metadata, &mut reader, &mut bitset).is_err() {
+                bitset.clear();
+            }
+            // A valid bitset consists of 32 byte blocks.
+            if bitset.len() % 32 != 0 {
+                bitset.clear();
+            }
+            bitset
+        })
+    }
+}
+
+impl ValueSetFilter for ColumnChunkBloomFilter {
+    fn may_contain_any(&self, values: &Series) -> bool {
+        if values.null_count() > 0 {
+            return true;
+        }
+        let Ok(values) = values.strict_cast(&self.dtype) else {
+            return true;
+        };
+        let values = values.rechunk().to_arrow(0, CompatLevel::newest());
+        let Some((physical_type, hashes)) = hash_array(values.as_ref()) else {
+            return true;
+        };
+        if physical_type != self.metadata.physical_type() {
+            return true;
+        }
+        let bitset = self.bitset();
+        bitset.is_empty()
+            || hashes
+                .into_iter()
+                .any(|hash| bloom_filter::is_in_set(bitset, hash))
+    }
+}
+
 /// Collect the statistics in a row-group
+///
+/// If the bytes of the `file` are given, the bloom filters in the file are attached to the
+/// statistics of their columns.
 pub(crate) fn collect_statistics(
     part_md: &PartitionedColumnChunkMD,
     schema: &ArrowSchema,
+    file: Option<&MemSlice>,
 ) -> PolarsResult<Option<BatchStats>> {
     // TODO! fix this performance. This is a full sequential scan.
     let stats = schema
@@ -26,7 +114,13 @@ pub(crate) fn collect_statistics(
         .map(|field| match part_md.get_partitions(&field.name) {
             Some(md) => {
                 let st = deserialize(field, &md)?;
-                Ok(ColumnStats::from_arrow_stats(st, field))
+                let stats = ColumnStats::from_arrow_stats(st, field);
+                let bloom_filter =
+                    file.and_then(|file| ColumnChunkBloomFilter::new(field, md.as_slice(), file));
+                Ok(match bloom_filter {
+                    Some(bloom_filter) => stats.with_value_filter(Arc::new(bloom_filter)),
+                    None => stats,
+                })
             },
             None => Ok(ColumnStats::new(field.into(), None, None, None)),
         })
@@ -47,10 +141,21 @@ pub fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     part_md: &PartitionedColumnChunkMD,
     schema: &ArrowSchema,
+) -> PolarsResult<bool> {
+    read_this_row_group_with_bloom_filters(predicate, part_md, schema, None)
+}
+
+/// Like [`read_this_row_group`], but equality and `is_in` predicates can also use the bloom
+/// filters in the bytes of the `file`.
+pub fn read_this_row_group_with_bloom_filters(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    part_md: &PartitionedColumnChunkMD,
+    schema: &ArrowSchema,
+    file: Option<&MemSlice>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(part_md, schema)? {
+            if let Some(stats) = collect_statistics(part_md, schema, file)? {
                 let should_read = pred.should_read(&stats);
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
@@ -63,3 +168,161 @@ pub fn read_this_row_group(
     }
     Ok(true)
 }
+
+/// The statistics of the data pages of a flat column chunk, read from its page index.
+struct PageStatistics {
+    /// The first row of every page, followed by the number of rows in the row group.
+    page_starts: Vec<usize>,
+    null_count: Series,
+    min_value: Series,
+    max_value: Series,
+}
+
+impl PageStatistics {
+    /// Returns `None` if the column chunk has no (readable) page index.
+    fn new(
+        field: &ArrowField,
+        md: &[&ColumnChunkMetaData],
+        file: &MemSlice,
+        num_rows: usize,
+    ) -> Option<Self> {
+        let [metadata] = md else {
+            return None;
+        };
+        if field.dtype.is_nested() {
+            return None;
+        }
+        let mut reader = Cursor::new(file.as_ref());
+        let column_index = read_column_index(metadata, &mut reader).ok()??;
+        let offset_index = read_offset_index(metadata, &mut reader).ok()??;
+        if column_index.null_pages.len() != offset_index.page_locations.len() {
+            return None;
+        }
+
+        let mut page_starts = offset_index
+            .page_locations
+            .iter()
+            .map(|location| usize::try_from(location.first_row_index).ok())
+            .collect::<Option<Vec<_>>>()?;
+        page_starts.push(num_rows);
+        if page_starts.first() != Some(&0) || page_starts.windows(2).any(|w| w[0] > w[1]) {
+            return None;
+        }
+
+        let stats = deserialize_column_index(field, metadata, &column_index).ok()?;
+        let series = |array| Series::try_from((PlSmallStr::EMPTY, array)).ok();
+        Some(Self {
+            page_starts,
+            null_count: series(stats.null_count)?,
+            min_value: series(stats.min_value)?,
+            max_value: series(stats.max_value)?,
+        })
+    }
+
+    /// The statistics of the rows `start..end`, which lie within page `page`.
+    fn column_stats(
+        &self,
+        field: &ArrowField,
+        page: usize,
+        start: usize,
+        end: usize,
+    ) -> ColumnStats {
+        let page_len = self.page_starts[page + 1] - self.page_starts[page];
+        let null_count = self.null_count.slice(page as i64, 1);
+        // The null count of a part of a page is only known if the page has no or only nulls.
+        let null_count = match null_count.u64().ok().and_then(|ca| ca.get(0)) {
+            _ if end - start == page_len => Some(null_count),
+            Some(0) => Some(null_count),
+            Some(n) if n as usize == page_len => {
+                Some(Series::new(PlSmallStr::EMPTY, [(end - start) as u64]))
+            },
+            _ => None,
+        };
+        ColumnStats::new(
+            field.into(),
+            null_count,
+            Some(self.min_value.slice(page as i64, 1)),
+            Some(self.max_value.slice(page as i64, 1)),
+        )
+    }
+}
+
+/// Returns which rows of a row group lie in data pages that may contain rows that satisfy the
+/// predicate, according to the page indexes in the bytes of the `file`.
+///
+/// Returns `None` if all rows have to be read.
+pub(crate) fn read_these_pages(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    part_md: &PartitionedColumnChunkMD,
+    schema: &ArrowSchema,
+    file: Option<&MemSlice>,
+) -> PolarsResult<Option<Bitmap>> {
+    let (Some(predicate), Some(file)) = (predicate, file) else {
+        return Ok(None);
+    };
+    let (Some(evaluator), Some(live_variables)) =
+        (predicate.as_stats_evaluator(), predicate.live_variables())
+    else {
+        return Ok(None);
+    };
+
+    let num_rows = part_md.num_rows();
+    let pages = schema
+        .iter_values()
+        .map(|field| {
+            if !live_variables.contains(&field.name) {
+                return None;
+            }
+            let md = part_md.get_partitions(&field.name)?;
+            PageStatistics::new(field, md.as_slice(), file, num_rows)
+        })
+        .collect::<Vec<_>>();
+
+    // Split the row group at the start of every page, so that every part lies within a single
+    // page of each column.
+    let mut boundaries = pages
+        .iter()
+        .flatten()
+        .flat_map(|pages| pages.page_starts.iter().copied())
+        .collect::<Vec<_>>();
+    if boundaries.is_empty() {
+        return Ok(None);
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let stats_schema = Arc::new(Schema::from_arrow_schema(schema));
+    let mut current_pages = vec![0; pages.len()];
+    let mut mask = MutableBitmap::with_capacity(num_rows);
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let stats = schema
+            .iter_values()
+            .zip(&pages)
+            .zip(&mut current_pages)
+            .map(|((field, pages), page)| match pages {
+                Some(pages) => {
+                    while pages.page_starts[*page + 1] <= start {
+                        *page += 1;
+                    }
+                    pages.column_stats(field, *page, start, end)
+                },
+                None => ColumnStats::new(field.into(), None, None, None),
+            })
+            .collect();
+        let stats = BatchStats::new(stats_schema.clone(), stats, Some(end - start));
+
+        let should_read = evaluator.should_read(&stats);
+        // a parquet file may not have statistics of all columns
+        let should_read = match should_read {
+            Err(PolarsError::ColumnNotFound(_)) => true,
+            should_read => should_read?,
+        };
+        mask.extend_constant(end - start, should_read);
+    }
+
+    if mask.unset_bits() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(mask.freeze()))
+}
@@ -3,10 +3,11 @@ use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
 use arrow::array::BooleanArray;
-use arrow::bitmap::MutableBitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::chunked_array::builder::NullChunkedBuilder;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
 use polars_parquet::parquet::error::ParquetResult;
@@ -20,7 +21,7 @@ use rayon::prelude::*;
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
-use super::predicates::read_this_row_group;
+use super::predicates::{read_these_pages, read_this_row_group_with_bloom_filters};
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
@@ -136,6 +137,72 @@ fn column_idx_to_series(
     Ok(series)
 }
 
+/// Restricts the rows that lie in pages that are not pruned by their page index to the rows in
+/// `slice`.
+fn slice_page_mask(page_mask: &Bitmap, slice: (usize, usize)) -> Bitmap {
+    let mut slice_mask = MutableBitmap::with_capacity(page_mask.len());
+    slice_mask.extend_constant(slice.0, false);
+    slice_mask.extend_constant(slice.1, true);
+    slice_mask.extend_constant(page_mask.len() - slice.0 - slice.1, false);
+    &slice_mask.freeze() & page_mask
+}
+
+/// Adds a row index to a `df` with the rows of a row group that are set in `mask`, where
+/// `offset` is the row index of the row `first_row` of the row group.
+fn with_masked_row_index(
+    df: &mut DataFrame,
+    row_index: &RowIndex,
+    offset: IdxSize,
+    mask: &Bitmap,
+    first_row: usize,
+) -> PolarsResult<()> {
+    let mut ca = IdxCa::from_vec(
+        row_index.name.clone(),
+        mask.true_idx_iter()
+            .map(|i| offset + (i - first_row) as IdxSize)
+            .collect(),
+    );
+    ca.set_sorted_flag(IsSorted::Ascending);
+    df.insert_column(0, ca)?;
+    Ok(())
+}
+
+/// Marks the column that the rows of the row group `md` are sorted by first as sorted in `df`.
+fn set_sorted_flag(df: &mut DataFrame, md: &RowGroupMetaData) {
+    let Some(sorting_column) = md.sorting_columns().and_then(|columns| columns.first()) else {
+        return;
+    };
+    let Some(column) = md.columns().get(sorting_column.column_idx) else {
+        return;
+    };
+    let path = &column.descriptor().path_in_schema;
+    let Some(idx) = (path.len() == 1)
+        .then(|| df.get_column_index(&path[0]))
+        .flatten()
+    else {
+        return;
+    };
+    let sorted = if sorting_column.descending {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    };
+    // SAFETY: the flag does not change the length or the name of the column.
+    unsafe { df.get_columns_mut()[idx].set_sorted_flag(sorted) };
+}
+
+/// Spreads the bits of `mask`, which has a bit for every row set in `page_mask`, over the rows
+/// of `page_mask`.
+fn unmask_pages(page_mask: &Bitmap, mask: &Bitmap) -> Bitmap {
+    let mut out = MutableBitmap::from_len_zeroed(page_mask.len());
+    for (i, row) in page_mask.true_idx_iter().enumerate() {
+        if mask.get_bit(i) {
+            out.set(row, true);
+        }
+    }
+    out.freeze()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -338,13 +405,28 @@ fn rg_to_dfs_prefiltered(
                 let part_md = &part_mds[rg_idx];
 
                 if use_statistics {
-                    match read_this_row_group(Some(predicate), part_md, schema) {
+                    match read_this_row_group_with_bloom_filters(
+                        Some(predicate),
+                        part_md,
+                        schema,
+                        store.local_file(),
+                    ) {
                         Ok(false) => return Ok(None),
                         Ok(true) => {},
                         Err(e) => return Err(e),
                     }
                 }
 
+                // Only read the live columns in pages that may contain matching rows.
+                let page_mask = if use_statistics {
+                    read_these_pages(Some(predicate), part_md, schema, store.local_file())?
+                } else {
+                    None
+                };
+                if page_mask.as_ref().is_some_and(|m| m.set_bits() == 0) {
+                    return Ok(None);
+                }
+
                 // Collect the data for the live columns
                 let live_columns = (0..num_live_columns)
                     .into_par_iter()
@@ -354,7 +436,13 @@ fn rg_to_dfs_prefiltered(
                         let name = schema.get_at_index(col_idx).unwrap().0;
                         let field_md = part_mds[rg_idx].get_partitions(name).unwrap();
 
-                        column_idx_to_series(col_idx, field_md.as_slice(), None, schema, store)
+                        column_idx_to_series(
+                            col_idx,
+                            field_md.as_slice(),
+                            page_mask.clone().map(Filter::new_masked),
+                            schema,
+                            store,
+                        )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -366,13 +454,21 @@ fn rg_to_dfs_prefiltered(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    md.num_rows(),
+                    page_mask.as_ref().map_or(md.num_rows(), |m| m.set_bits()),
                 );
                 let s = predicate.evaluate_io(&df)?;
                 let mask = s.bool().expect("filter predicates was not of type boolean");
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(rc.name.clone(), Some(rg_offsets[rg_idx] + rc.offset));
+                    let offset = rg_offsets[rg_idx] + rc.offset;
+                    match &page_mask {
+                        Some(page_mask) => {
+                            with_masked_row_index(&mut df, rc, offset, page_mask, 0)?
+                        },
+                        None => {
+                            df.with_row_index_mut(rc.name.clone(), Some(offset));
+                        },
+                    }
                 }
                 df = df.filter(mask)?;
 
@@ -388,7 +484,10 @@ fn rg_to_dfs_prefiltered(
                     }
                 }
 
-                let filter_mask = filter_mask.freeze();
+                let filter_mask = match &page_mask {
+                    Some(page_mask) => unmask_pages(page_mask, &filter_mask.freeze()),
+                    None => filter_mask.freeze(),
+                };
 
                 debug_assert_eq!(md.num_rows(), filter_mask.len());
                 debug_assert_eq!(df.height(), filter_mask.set_bits());
@@ -399,6 +498,7 @@ fn rg_to_dfs_prefiltered(
 
                 // We don't need to do any further work if there are no dead columns
                 if num_dead_columns == 0 {
+                    set_sorted_flag(&mut df, md);
                     return Ok(Some(df));
                 }
 
@@ -436,7 +536,7 @@ fn rg_to_dfs_prefiltered(
                         #[cfg(debug_assertions)]
                         {
                             let md = &file_metadata.row_groups[rg_idx];
-                            debug_assert_eq!(md.num_rows(), mask.len());
+                            debug_assert_eq!(md.num_rows(), filter_mask.len());
                         }
                         let field_md = part_mds[rg_idx].get_partitions(name).unwrap();
 
@@ -458,7 +558,7 @@ fn rg_to_dfs_prefiltered(
                                 store,
                             )?;
 
-                            debug_assert_eq!(array.len(), mask.len());
+                            debug_assert_eq!(array.len(), filter_mask.len());
 
                             let mask_arr = BooleanArray::new(
                                 ArrowDataType::Boolean,
@@ -504,7 +604,8 @@ fn rg_to_dfs_prefiltered(
                 // We first add the columns with the live columns at the start. Then, we do a
                 // projections that puts the columns at the right spot.
                 df._add_columns(rg_columns, &rearranged_schema)?;
-                let df = df.select(schema.get_names_owned())?;
+                let mut df = df.select(schema.get_names_owned())?;
+                set_sorted_flag(&mut df, &file_metadata.row_groups[rg_idx]);
 
                 PolarsResult::Ok(Some(df))
             })
@@ -558,10 +659,34 @@ fn rg_to_dfs_optionally_par_over_columns(
             split_slice_at_file(&mut n_rows_processed, md.num_rows(), slice.0, slice_end);
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics && !read_this_row_group(predicate, &part_md, schema)? {
+        if use_statistics
+            && !read_this_row_group_with_bloom_filters(
+                predicate,
+                &part_md,
+                schema,
+                store.local_file(),
+            )?
+        {
             *previous_row_count += rg_slice.1 as IdxSize;
             continue;
         }
+        let page_mask = if use_statistics {
+            read_these_pages(predicate, &part_md, schema, store.local_file())?
+        } else {
+            None
+        };
+        let rows = page_mask.map(|page_mask| slice_page_mask(&page_mask, rg_slice));
+        let (filter, height) = match &rows {
+            Some(rows) if rows.set_bits() == 0 => {
+                *previous_row_count += rg_slice.1 as IdxSize;
+                continue;
+            },
+            Some(rows) => (Filter::new_masked(rows.clone()), rows.set_bits()),
+            None => (
+                Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1),
+                rg_slice.1,
+            ),
+        };
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
@@ -579,7 +704,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         column_idx_to_series(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )
@@ -596,7 +721,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                     column_idx_to_series(
                         *column_i,
                         part.as_slice(),
-                        Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                        Some(filter.clone()),
                         schema,
                         store,
                     )
@@ -605,11 +730,18 @@ fn rg_to_dfs_optionally_par_over_columns(
         };
 
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
+        set_sorted_flag(&mut df, md);
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(rc.name.clone(), Some(*previous_row_count + rc.offset));
+            let offset = *previous_row_count + rc.offset;
+            match &rows {
+                Some(rows) => with_masked_row_index(&mut df, rc, offset, rows, rg_slice.0)?,
+                None => {
+                    df.with_row_index_mut(rc.name.clone(), Some(offset));
+                },
+            }
         }
 
-        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, rg_slice.1);
+        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, height);
         apply_predicate(&mut df, predicate, true)?;
 
         *previous_row_count = previous_row_count.checked_add(current_row_count).ok_or_else(||
@@ -687,14 +819,31 @@ fn rg_to_dfs_par_over_rg(
         row_groups
             .into_par_iter()
             .enumerate()
-            .map(|(iter_idx, (_rg_idx, _md, slice, row_count_start))| {
+            .map(|(iter_idx, (_rg_idx, md, slice, row_count_start))| {
                 let part_md = &part_mds[iter_idx];
 
                 if slice.1 == 0
-                    || use_statistics && !read_this_row_group(predicate, part_md, schema)?
+                    || use_statistics
+                        && !read_this_row_group_with_bloom_filters(
+                            predicate,
+                            part_md,
+                            schema,
+                            store.local_file(),
+                        )?
                 {
                     return Ok(None);
                 }
+                let page_mask = if use_statistics {
+                    read_these_pages(predicate, part_md, schema, store.local_file())?
+                } else {
+                    None
+                };
+                let rows = page_mask.map(|page_mask| slice_page_mask(&page_mask, slice));
+                let (filter, height) = match &rows {
+                    Some(rows) if rows.set_bits() == 0 => return Ok(None),
+                    Some(rows) => (Filter::new_masked(rows.clone()), rows.set_bits()),
+                    None => (Filter::new_ranged(slice.0, slice.0 + slice.1), slice.1),
+                };
                 // test we don't read the parquet file if this env var is set
                 #[cfg(debug_assertions)]
                 {
@@ -710,7 +859,7 @@ fn rg_to_dfs_par_over_rg(
                         column_idx_to_series(
                             *column_i,
                             field_md.as_slice(),
-                            Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )
@@ -718,19 +867,23 @@ fn rg_to_dfs_par_over_rg(
                     .collect::<PolarsResult<Vec<_>>>()?;
 
                 let mut df = unsafe { DataFrame::new_no_checks(columns) };
+                set_sorted_flag(&mut df, md);
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(
-                        rc.name.clone(),
-                        Some(row_count_start as IdxSize + rc.offset),
-                    );
+                    let offset = row_count_start as IdxSize + rc.offset;
+                    match &rows {
+                        Some(rows) => with_masked_row_index(&mut df, rc, offset, rows, slice.0)?,
+                        None => {
+                            df.with_row_index_mut(rc.name.clone(), Some(offset));
+                        },
+                    }
                 }
 
                 materialize_hive_partitions(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    slice.1,
+                    height,
                 );
                 apply_predicate(&mut df, predicate, false)?;
 
@@ -961,6 +1114,24 @@ pub struct BatchedParquetReader {
     include_file_path: Option<StringChunked>,
     /// Has returned at least one materialized frame.
     has_returned: bool,
+    /// Row groups whose projected columns are larger than this are decoded in morsels.
+    large_row_group_bytes: Option<usize>,
+    /// The row group that is being decoded in morsels.
+    large_row_group: Option<LargeRowGroup>,
+}
+
+/// A row group that is decoded in morsels of rows, because it is too large to be decoded at once.
+struct LargeRowGroup {
+    store: Arc<ColumnStore>,
+    row_group: usize,
+    /// The offset of the first row of the row group in the file.
+    start: usize,
+    /// The rows of the row group that are read.
+    slice: (usize, usize),
+    /// The offset of the next morsel in the row group.
+    offset: usize,
+    /// The row count before the row group.
+    rows_read: IdxSize,
 }
 
 impl BatchedParquetReader {
@@ -1018,9 +1189,130 @@ impl BatchedParquetReader {
             include_file_path: include_file_path
                 .map(|(col, path)| StringChunked::full(col, &path, 1)),
             has_returned: false,
+            large_row_group_bytes: None,
+            large_row_group: None,
         })
     }
 
+    /// Decode the row groups whose projected columns take more than `bytes` bytes uncompressed
+    /// in morsels of `chunk_size` rows, one page range at a time, instead of materializing the
+    /// whole row group. This bounds the memory that is used for files with very large row groups.
+    pub fn with_large_row_group_bytes(mut self, bytes: Option<usize>) -> Self {
+        self.large_row_group_bytes = bytes;
+        self
+    }
+
+    /// The uncompressed size of the projected columns of row group `rg`.
+    fn projected_size(&self, rg: usize) -> usize {
+        let projected_columns = projected_columns_set(&self.schema, &self.projection);
+        self.metadata.row_groups[rg]
+            .columns()
+            .iter()
+            .filter(|column| match &projected_columns {
+                Some(projected) => {
+                    projected.contains(column.descriptor().path_in_schema[0].as_str())
+                },
+                None => true,
+            })
+            .map(|column| column.uncompressed_size() as usize)
+            .sum()
+    }
+
+    /// Decode the next morsels of the large row group, fetching it first if decoding has not
+    /// started yet. The row group is finished once all of its morsels are decoded.
+    async fn next_morsels(&mut self, n: usize) -> PolarsResult<Vec<DataFrame>> {
+        if self.large_row_group.is_none() {
+            let row_group = self.row_group_offset;
+            let store = self
+                .row_group_fetcher
+                .fetch_row_groups(row_group..row_group + 1)
+                .await?;
+            let start = self.metadata.row_groups[..row_group]
+                .iter()
+                .map(|md| md.num_rows())
+                .sum::<usize>();
+            let num_rows = self.metadata.row_groups[row_group].num_rows();
+            let slice = split_slice_at_file(
+                &mut start.clone(),
+                num_rows,
+                self.slice.0,
+                self.slice.0.saturating_add(self.slice.1),
+            );
+            self.large_row_group = Some(LargeRowGroup {
+                store: Arc::new(store),
+                row_group,
+                start,
+                slice,
+                offset: slice.0,
+                rows_read: self.rows_read,
+            });
+        }
+        let large = self.large_row_group.as_ref().unwrap();
+
+        let store = large.store.clone();
+        let row_group = large.row_group;
+        let start = large.start;
+        let slice = large.slice;
+        let offset = large.offset;
+        let rows_read = large.rows_read;
+        let chunk_size = self.chunk_size;
+        let metadata = self.metadata.clone();
+        let schema = self.schema.clone();
+        let predicate = self.predicate.clone();
+        let row_index = self.row_index.clone();
+        let projection = self.projection.clone();
+        let use_statistics = self.use_statistics;
+        let hive_partition_columns = self.hive_partition_columns.clone();
+
+        let f = move || {
+            let end = slice.0 + slice.1;
+            let mut offset = offset;
+            let mut dfs = vec![];
+            for _ in 0..n {
+                if offset >= end {
+                    break;
+                }
+                let len = chunk_size.min(end - offset);
+                let mut rows_read = rows_read + (offset - slice.0) as IdxSize;
+                dfs.extend(rg_to_dfs(
+                    &store,
+                    &mut rows_read,
+                    row_group,
+                    row_group + 1,
+                    (start + offset, len),
+                    &metadata,
+                    &schema,
+                    predicate.as_deref(),
+                    row_index.clone(),
+                    ParallelStrategy::Columns,
+                    &projection,
+                    use_statistics,
+                    hive_partition_columns.as_deref(),
+                )?);
+                offset += len;
+            }
+            PolarsResult::Ok((dfs, offset))
+        };
+        let (dfs, offset) = match large.store.as_ref() {
+            ColumnStore::Local(_) => f(),
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(_) => spawn_decode(f).await,
+        }?;
+
+        let large = self.large_row_group.as_mut().unwrap();
+        large.offset = offset;
+        if offset >= large.slice.0 + large.slice.1 {
+            let num_rows = self.metadata.row_groups[large.row_group].num_rows() as IdxSize;
+            self.rows_read = large
+                .rows_read
+                .checked_add(num_rows)
+                .ok_or(ROW_COUNT_OVERFLOW_ERR)?;
+            self.row_group_offset = large.row_group + 1;
+            self.large_row_group = None;
+        }
+        Ok(dfs)
+    }
+
     pub fn schema(&self) -> &ArrowSchemaRef {
         &self.schema
     }
@@ -1051,91 +1343,99 @@ impl BatchedParquetReader {
             && self.chunks_fifo.len() < n
         {
             // Ensure we apply the limit on the metadata, before we download the row-groups.
-            let row_group_range = compute_row_group_range(
+            let mut row_group_range = compute_row_group_range(
                 self.row_group_offset,
                 self.row_group_offset + n,
                 self.slice,
                 &self.metadata.row_groups,
             );
+            let mut next_row_group_offset = self.row_group_offset + n;
+            let large_row_group = match self.large_row_group_bytes {
+                Some(bytes) if self.large_row_group.is_none() => row_group_range
+                    .clone()
+                    .find(|rg| self.projected_size(*rg) > bytes),
+                _ => None,
+            };
 
-            let store = self
-                .row_group_fetcher
-                .fetch_row_groups(row_group_range.clone())
-                .await?;
-
-            let mut dfs = match store {
-                ColumnStore::Local(_) => rg_to_dfs(
-                    &store,
-                    &mut self.rows_read,
-                    row_group_range.start,
-                    row_group_range.end,
-                    self.slice,
-                    &self.metadata,
-                    &self.schema,
-                    self.predicate.as_deref(),
-                    self.row_index.clone(),
-                    self.parallel,
-                    &self.projection,
-                    self.use_statistics,
-                    self.hive_partition_columns.as_deref(),
-                ),
-                #[cfg(feature = "async")]
-                ColumnStore::Fetched(b) => {
-                    // This branch we spawn the decoding and decompression of the bytes on a rayon task.
-                    // This will ensure we don't block the async thread.
-
-                    // Reconstruct as that makes it a 'static.
-                    let store = ColumnStore::Fetched(b);
-                    let (tx, rx) = tokio::sync::oneshot::channel();
-
-                    // Make everything 'static.
-                    let mut rows_read = self.rows_read;
-                    let row_index = self.row_index.clone();
-                    let predicate = self.predicate.clone();
-                    let schema = self.schema.clone();
-                    let metadata = self.metadata.clone();
-                    let parallel = self.parallel;
-                    let projection = self.projection.clone();
-                    let use_statistics = self.use_statistics;
-                    let hive_partition_columns = self.hive_partition_columns.clone();
-                    let slice = self.slice;
-
-                    let f = move || {
-                        let dfs = rg_to_dfs(
-                            &store,
-                            &mut rows_read,
-                            row_group_range.start,
-                            row_group_range.end,
-                            slice,
-                            &metadata,
-                            &schema,
-                            predicate.as_deref(),
-                            row_index,
-                            parallel,
-                            &projection,
-                            use_statistics,
-                            hive_partition_columns.as_deref(),
-                        );
-
-                        // Don't unwrap send attempt - async task could be cancelled.
-                        let _ = tx.send((dfs, rows_read));
-                    };
+            let mut dfs = if self.large_row_group.is_some()
+                || large_row_group == Some(row_group_range.start)
+            {
+                let dfs = self.next_morsels(n).await?;
+                next_row_group_offset = self.row_group_offset;
+                dfs
+            } else {
+                if let Some(rg) = large_row_group {
+                    // Read the row groups before the large row group at once.
+                    row_group_range.end = rg;
+                    next_row_group_offset = rg;
+                }
 
-                    // Spawn the task and wait on it asynchronously.
-                    if POOL.current_thread_index().is_some() {
-                        // We are a rayon thread, so we can't use POOL.spawn as it would mean we spawn a task and block until
-                        // another rayon thread executes it - we would deadlock if all rayon threads did this.
-                        // Safety: The tokio runtime flavor is multi-threaded.
-                        tokio::task::block_in_place(f);
-                    } else {
-                        POOL.spawn(f);
-                    };
+                let store = self
+                    .row_group_fetcher
+                    .fetch_row_groups(row_group_range.clone())
+                    .await?;
+
+                match store {
+                    ColumnStore::Local(_) => rg_to_dfs(
+                        &store,
+                        &mut self.rows_read,
+                        row_group_range.start,
+                        row_group_range.end,
+                        self.slice,
+                        &self.metadata,
+                        &self.schema,
+                        self.predicate.as_deref(),
+                        self.row_index.clone(),
+                        self.parallel,
+                        &self.projection,
+                        self.use_statistics,
+                        self.hive_partition_columns.as_deref(),
+                    ),
+                    #[cfg(feature = "async")]
+                    ColumnStore::Fetched(b) => {
+                        // This branch we spawn the decoding and decompression of the bytes on a rayon task.
+                        // This will ensure we don't block the async thread.
+
+                        // Reconstruct as that makes it a 'static.
+                        let store = ColumnStore::Fetched(b);
+
+                        // Make everything 'static.
+                        let mut rows_read = self.rows_read;
+                        let row_index = self.row_index.clone();
+                        let predicate = self.predicate.clone();
+                        let schema = self.schema.clone();
+                        let metadata = self.metadata.clone();
+                        let parallel = self.parallel;
+                        let projection = self.projection.clone();
+                        let use_statistics = self.use_statistics;
+                        let hive_partition_columns = self.hive_partition_columns.clone();
+                        let slice = self.slice;
+
+                        let f = move || {
+                            let dfs = rg_to_dfs(
+                                &store,
+                                &mut rows_read,
+                                row_group_range.start,
+                                row_group_range.end,
+                                slice,
+                                &metadata,
+                                &schema,
+                                predicate.as_deref(),
+                                row_index,
+                                parallel,
+                                &projection,
+                                use_statistics,
+                                hive_partition_columns.as_deref(),
+                            );
+                            (dfs, rows_read)
+                        };
 
-                    let (dfs, rows_read) = rx.await.unwrap();
-                    self.rows_read = rows_read;
-                    dfs
-                },
-            }?;
+                        let (dfs, rows_read) = spawn_decode(f).await;
+                        self.rows_read = rows_read;
+                        dfs
+                    },
+                }?
+            };
 
             if let Some(ca) = self.include_file_path.as_mut() {
                 let mut max_len = 0;
@@ -1170,7 +1470,7 @@ impl BatchedParquetReader {
                 }
             }
 
-            self.row_group_offset += n;
+            self.row_group_offset = next_row_group_offset;
 
             // case where there is no data in the file
             // the streaming engine needs at least a single chunk
@@ -1257,6 +1557,32 @@ impl BatchedParquetReader {
     }
 }
 
+/// Run the decoding `f` on a rayon task and wait on it asynchronously, so that it doesn't block
+/// the async thread.
+#[cfg(feature = "async")]
+async fn spawn_decode<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let f = move || {
+        // Don't unwrap send attempt - async task could be cancelled.
+        let _ = tx.send(f());
+    };
+
+    if POOL.current_thread_index().is_some() {
+        // We are a rayon thread, so we can't use POOL.spawn as it would mean we spawn a task and block until
+        // another rayon thread executes it - we would deadlock if all rayon threads did this.
+        // Safety: The tokio runtime flavor is multi-threaded.
+        tokio::task::block_in_place(f);
+    } else {
+        POOL.spawn(f);
+    };
+
+    rx.await.unwrap()
+}
+
 #[cfg(feature = "async")]
 pub struct BatchedParquetIter {
     batches_per_iter: usize,
@@ -5,6 +5,7 @@ use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_parquet::parquet::encryption::FileDecryptionProperties;
 use polars_parquet::read;
 
 #[cfg(feature = "cloud")]
@@ -15,10 +16,11 @@ pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{compute_row_group_range, read_parquet, FetchRowGroupsFromMmapReader};
 #[cfg(feature = "cloud")]
 use super::utils::materialize_empty_df;
+use super::utils::strings_to_categorical;
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
 use crate::mmap::MmapBytesReader;
-use crate::parquet::metadata::FileMetaDataRef;
+use crate::parquet::metadata::{FieldMetadata, FileMetaDataRef, KeyValueMetadata};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::RowIndex;
@@ -40,6 +42,8 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     use_statistics: bool,
+    dictionary_as_categorical: bool,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -105,7 +109,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             Some(schema) => schema.clone(),
             None => {
                 let metadata = self.get_metadata()?;
-                Arc::new(read::infer_schema(metadata)?)
+                let schema = read::infer_schema(metadata)?;
+                if self.dictionary_as_categorical {
+                    Arc::new(strings_to_categorical(schema)?)
+                } else {
+                    Arc::new(schema)
+                }
             },
         });
 
@@ -125,6 +134,18 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
+    /// Key-value metadata of the file and its columns.
+    pub fn key_value_metadata(&mut self) -> PolarsResult<KeyValueMetadata> {
+        let metadata = self.get_metadata()?;
+        Ok(KeyValueMetadata::from_file_metadata(metadata))
+    }
+
+    /// Arrow metadata of the columns of the file, such as the name of an extension type.
+    pub fn field_metadata(&mut self) -> PolarsResult<FieldMetadata> {
+        let metadata = self.get_metadata()?;
+        Ok(FieldMetadata::from_schema(&read::infer_schema(metadata)?))
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -138,9 +159,38 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Read the string columns as `Categorical`. Column chunks whose data pages are all
+    /// dictionary-encoded are decoded directly into categoricals that reuse the dictionary,
+    /// instead of decoding the strings and encoding them again.
+    ///
+    /// This must be set before the schema is read.
+    pub fn with_dictionary_as_categorical(mut self, toggle: bool) -> Self {
+        self.dictionary_as_categorical = toggle;
+        self
+    }
+
+    /// Decrypt files written with Parquet modular encryption with these keys.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
+    /// Use `metadata` instead of reading it from the footer of the file, e.g. when it was read
+    /// from the summary file of a dataset.
+    pub fn with_metadata(mut self, metadata: Option<FileMetaDataRef>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let metadata = match &self.decryption {
+                Some(decryption) => {
+                    read::read_metadata_with_decryption(&mut self.reader, decryption)?
+                },
+                None => read::read_metadata(&mut self.reader)?,
+            };
+            self.metadata = Some(Arc::new(metadata));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -194,6 +244,8 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             use_statistics: true,
             hive_partition_columns: None,
             include_file_path: None,
+            dictionary_as_categorical: false,
+            decryption: None,
         }
     }
 
@@ -260,6 +312,8 @@ pub struct ParquetAsyncReader {
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    prefetch: PrefetchOptions,
+    dictionary_as_categorical: bool,
 }
 
 #[cfg(feature = "cloud")]
@@ -281,6 +335,8 @@ impl ParquetAsyncReader {
             include_file_path: None,
             schema: None,
             parallel: Default::default(),
+            prefetch: Default::default(),
+            dictionary_as_categorical: false,
         })
     }
 
@@ -306,7 +362,11 @@ impl ParquetAsyncReader {
             None => {
                 let metadata = self.reader.get_metadata().await?;
                 let arrow_schema = polars_parquet::arrow::read::infer_schema(metadata)?;
-                Arc::new(arrow_schema)
+                if self.dictionary_as_categorical {
+                    Arc::new(strings_to_categorical(arrow_schema)?)
+                } else {
+                    Arc::new(arrow_schema)
+                }
             },
         });
 
@@ -317,6 +377,19 @@ impl ParquetAsyncReader {
         self.reader.num_rows().await
     }
 
+    /// Key-value metadata of the file and its columns.
+    pub async fn key_value_metadata(&mut self) -> PolarsResult<KeyValueMetadata> {
+        let metadata = self.reader.get_metadata().await?;
+        Ok(KeyValueMetadata::from_file_metadata(metadata))
+    }
+
+    /// Arrow metadata of the columns of the file, such as the name of an extension type.
+    pub async fn field_metadata(&mut self) -> PolarsResult<FieldMetadata> {
+        let metadata = self.reader.get_metadata().await?;
+        let arrow_schema = polars_parquet::arrow::read::infer_schema(metadata)?;
+        Ok(FieldMetadata::from_schema(&arrow_schema))
+    }
+
     /// Only positive offsets are supported for simplicity - the caller should
     /// translate negative offsets into the positive equivalent.
     pub fn with_slice(mut self, slice: Option<(usize, usize)>) -> Self {
@@ -369,6 +442,19 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Read the string columns as `Categorical`, see
+    /// [`ParquetReader::with_dictionary_as_categorical`].
+    pub fn with_dictionary_as_categorical(mut self, toggle: bool) -> Self {
+        self.dictionary_as_categorical = toggle;
+        self
+    }
+
+    /// Set the concurrency and memory budget of the row group downloads.
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -388,6 +474,7 @@ impl ParquetAsyncReader {
                 &metadata.row_groups,
             ),
             &metadata.row_groups,
+            self.prefetch,
         )?
         .into();
         BatchedParquetReader::new(
@@ -1,6 +1,8 @@
 use std::borrow::Cow;
 
+use arrow::datatypes::{ArrowDataType, IntegerType};
 use polars_core::prelude::{ArrowSchema, DataFrame, Series, IDX_DTYPE};
+use polars_error::{polars_ensure, PolarsResult};
 
 use crate::hive::materialize_hive_partitions;
 use crate::utils::apply_projection;
@@ -28,3 +30,29 @@ pub fn materialize_empty_df(
 
     df
 }
+
+/// Converts the string columns of `schema` into dictionaries, which are read as `Categorical`.
+/// Column chunks that are fully dictionary-encoded are decoded into dictionary arrays that reuse
+/// the dictionary of the chunk.
+pub(super) fn strings_to_categorical(schema: ArrowSchema) -> PolarsResult<ArrowSchema> {
+    polars_ensure!(
+        cfg!(feature = "dtype-categorical"),
+        InvalidOperation: "reading strings as categoricals requires the 'dtype-categorical' feature"
+    );
+    Ok(schema
+        .into_iter()
+        .map(|(name, mut field)| {
+            if matches!(
+                field.dtype,
+                ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View
+            ) {
+                field.dtype = ArrowDataType::Dictionary(
+                    IntegerType::UInt32,
+                    Box::new(ArrowDataType::Utf8View),
+                    false,
+                );
+            }
+            (name, field)
+        })
+        .collect())
+}
@@ -0,0 +1,222 @@
+//! Summary files of Parquet datasets.
+//!
+//! The `_metadata` file of a dataset contains the footers of all of its files, so that the
+//! statistics of all row groups can be read at once instead of opening every file. Its column
+//! chunks point to the files that hold them. The `_common_metadata` file only contains the
+//! schema of the dataset.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchema;
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+use polars_parquet::parquet::metadata::{FileMetaData, ThriftFileMetaData};
+use polars_parquet::read::{infer_schema, read_metadata};
+use polars_parquet::write::write_metadata_sidecar;
+use polars_utils::aliases::PlHashMap;
+
+use super::metadata::FileMetaDataRef;
+
+/// Name of the summary file with the metadata of all files of a dataset.
+pub const METADATA_FILE_NAME: &str = "_metadata";
+/// Name of the summary file with only the schema of a dataset.
+pub const COMMON_METADATA_FILE_NAME: &str = "_common_metadata";
+
+/// The metadata of the files of a dataset, read from its `_metadata` file.
+#[derive(Debug, Clone)]
+pub struct MetadataSummary {
+    root: PathBuf,
+    /// The metadata of each file, by its path relative to `root`.
+    files: PlHashMap<PathBuf, FileMetaDataRef>,
+}
+
+impl MetadataSummary {
+    /// Read the `_metadata` file in the directory `root` of a dataset.
+    pub fn read(root: impl AsRef<Path>) -> PolarsResult<Self> {
+        let root = root.as_ref();
+        let path = root.join(METADATA_FILE_NAME);
+        let metadata = read_metadata(&mut File::open(&path)?)?;
+        let FileMetaData {
+            version,
+            created_by,
+            row_groups,
+            key_value_metadata,
+            schema_descr,
+            column_orders,
+            ..
+        } = metadata;
+
+        let mut files = PlHashMap::<PathBuf, FileMetaData>::default();
+        for row_group in row_groups {
+            let file_path = row_group
+                .columns()
+                .first()
+                .and_then(|column| column.file_path().as_deref())
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "row group in {} does not refer to a file", path.display()
+                    )
+                })?;
+            let file = files
+                .entry(file_path.split('/').collect())
+                .or_insert_with(|| FileMetaData {
+                    version,
+                    num_rows: 0,
+                    created_by: created_by.clone(),
+                    row_groups: vec![],
+                    key_value_metadata: key_value_metadata.clone(),
+                    schema_descr: schema_descr.clone(),
+                    column_orders: column_orders.clone(),
+                });
+            file.num_rows += row_group.num_rows();
+            file.row_groups.push(row_group);
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            files: files
+                .into_iter()
+                .map(|(path, metadata)| (path, Arc::new(metadata)))
+                .collect(),
+        })
+    }
+
+    /// Find the `_metadata` file of the dataset with the files at `paths`, in the directory that
+    /// contains all of them or in one of its parents.
+    pub fn find(paths: &[PathBuf]) -> PolarsResult<Option<Self>> {
+        let Some(mut dir) = paths.first().and_then(|path| path.parent()) else {
+            return Ok(None);
+        };
+        for path in &paths[1..] {
+            while !path.starts_with(dir) {
+                let Some(parent) = dir.parent() else {
+                    return Ok(None);
+                };
+                dir = parent;
+            }
+        }
+        dir.ancestors()
+            .find(|dir| dir.join(METADATA_FILE_NAME).is_file())
+            .map(Self::read)
+            .transpose()
+    }
+
+    /// The directory of the dataset.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The number of files in the dataset.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// The total number of rows of the files in the dataset.
+    pub fn num_rows(&self) -> usize {
+        self.files.values().map(|metadata| metadata.num_rows).sum()
+    }
+
+    /// The metadata of the file at `path`, which is `None` if the file is not in the dataset.
+    pub fn get(&self, path: &Path) -> Option<&FileMetaDataRef> {
+        let path = path.strip_prefix(&self.root).ok()?;
+        self.files.get(path)
+    }
+}
+
+/// Read the schema of the dataset in the directory `root` from its `_common_metadata` file, or
+/// from its `_metadata` file if it has no `_common_metadata`.
+pub fn read_summary_schema(root: impl AsRef<Path>) -> PolarsResult<ArrowSchema> {
+    let root = root.as_ref();
+    let path = [COMMON_METADATA_FILE_NAME, METADATA_FILE_NAME]
+        .into_iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| polars_err!(ComputeError: "no summary file found in {}", root.display()))?;
+    let metadata = read_metadata(&mut File::open(path)?)?;
+    infer_schema(&metadata)
+}
+
+/// Write the `_metadata` and `_common_metadata` files of the dataset in the directory `root`,
+/// from the footers of the Parquet files in it and its subdirectories. Files whose name starts
+/// with `_` or `.` are skipped. Returns the number of files in the summary.
+///
+/// All files must have the same schema. The summary must be written again when files are added
+/// or changed.
+pub fn write_metadata_summary(root: impl AsRef<Path>) -> PolarsResult<usize> {
+    let root = root.as_ref();
+    let mut paths = vec![];
+    collect_data_files(root, &mut paths)?;
+    paths.sort_unstable();
+    polars_ensure!(
+        !paths.is_empty(),
+        ComputeError: "no parquet files found in {}", root.display()
+    );
+
+    let mut summary: Option<ThriftFileMetaData> = None;
+    for path in &paths {
+        let mut metadata = read_metadata(&mut File::open(path)?)?.into_thrift();
+        let file_path = path
+            .strip_prefix(root)
+            .unwrap()
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        for column in metadata
+            .row_groups
+            .iter_mut()
+            .flat_map(|row_group| row_group.columns.iter_mut())
+        {
+            column.file_path = Some(file_path.clone());
+        }
+
+        match &mut summary {
+            None => summary = Some(metadata),
+            Some(summary) => {
+                if summary.schema != metadata.schema {
+                    polars_bail!(
+                        SchemaMismatch: "the schema of {} differs from that of {}",
+                        path.display(), paths[0].display()
+                    );
+                }
+                summary.num_rows += metadata.num_rows;
+                summary.row_groups.extend(metadata.row_groups);
+            },
+        }
+    }
+    let summary = summary.unwrap();
+
+    let mut file = File::create(root.join(METADATA_FILE_NAME))?;
+    write_metadata_sidecar(&mut file, &summary)?;
+
+    let common = ThriftFileMetaData {
+        num_rows: 0,
+        row_groups: vec![],
+        ..summary
+    };
+    let mut file = File::create(root.join(COMMON_METADATA_FILE_NAME))?;
+    write_metadata_sidecar(&mut file, &common)?;
+
+    Ok(paths.len())
+}
+
+fn collect_data_files(dir: &Path, paths: &mut Vec<PathBuf>) -> PolarsResult<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap().to_string_lossy();
+        if name.starts_with('_') || name.starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_data_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
@@ -1,44 +1,81 @@
+use std::borrow::Cow;
 use std::io::Write;
 use std::sync::Mutex;
 
+use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::datatypes::PhysicalType;
 use arrow::record_batch::RecordBatch;
+use arrow::with_match_primitive_type;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
+use polars_parquet::parquet::metadata::KeyValue;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    array_to_bloom_filter, array_to_columns, to_parquet_leaves, CompressedPage, Compressor,
+    DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, Page,
+    ParquetType, RowGroupIterColumns, SchemaDescriptor, SortingColumn, WriteOptions,
 };
 use rayon::prelude::*;
 
+use super::writer::delta_encoding;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
-    pub(super) options: WriteOptions,
+    /// The write options of each column.
+    pub(super) column_options: Vec<WriteOptions>,
     pub(super) parallel: bool,
+    /// The bloom filter false positive probability of each column, empty if none are written.
+    pub(super) bloom_filter_fpps: Vec<Option<f64>>,
+    /// Whether the encoding of each column is chosen per row group from its values.
+    pub(super) auto_encoding: Vec<bool>,
+    /// The key-value metadata of the file, written to the footer.
+    pub(super) key_value_metadata: Option<Vec<KeyValue>>,
+    /// The declared columns that the rows are sorted by.
+    pub(super) sorting_columns: Option<Vec<SortingColumn>>,
+}
+
+/// A row group that is encoded and compressed in memory, ready to be written.
+pub struct EncodedRowGroup {
+    columns: RowGroupIterColumns<'static, PolarsError>,
+    bloom_filters: Vec<Option<Vec<u8>>>,
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
+        let sorting_columns =
+            get_sorting_columns(df, self.parquet_schema.fields(), &self.sorting_columns);
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let bloom_filters = create_bloom_filters(
+                    &batch,
+                    self.parquet_schema.fields(),
+                    &self.bloom_filter_fpps,
+                );
+                let encodings =
+                    resolve_auto_encodings(&batch, &self.encodings, &self.auto_encoding);
                 let row_group = create_eager_serializer(
                     batch,
                     self.parquet_schema.fields(),
-                    self.encodings.as_ref(),
-                    self.options,
+                    &encodings,
+                    &self.column_options,
                 );
 
-                Some(row_group)
+                Some(row_group.map(|columns| EncodedRowGroup {
+                    columns,
+                    bloom_filters,
+                    sorting_columns: sorting_columns.clone(),
+                }))
             },
         })
     }
@@ -52,13 +89,21 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
-            self.options,
+            &self.column_options,
             self.parallel,
+            &self.bloom_filter_fpps,
+            &self.auto_encoding,
+            get_sorting_columns(df, self.parquet_schema.fields(), &self.sorting_columns),
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let group = group?;
+            writer.write_with_metadata(
+                group.columns,
+                group.bloom_filters,
+                group.sorting_columns,
+            )?;
         }
         Ok(())
     }
@@ -67,14 +112,15 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write_with_metadata(
+                group.columns,
+                group.bloom_filters,
+                group.sorting_columns,
+            )?;
         }
         Ok(())
     }
@@ -82,31 +128,179 @@ impl<W: Write> BatchedWriter<W> {
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&self) -> PolarsResult<u64> {
         let mut writer = self.writer.lock().unwrap();
-        let size = writer.end(None)?;
+        let size = writer.end(self.key_value_metadata.clone())?;
         Ok(size)
     }
 }
 
 // Note that the df should be rechunked
+#[allow(clippy::too_many_arguments)]
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &'a [WriteOptions],
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    bloom_filter_fpps: &'a [Option<f64>],
+    auto_encoding: &'a [bool],
+    sorting_columns: Option<Vec<SortingColumn>>,
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let bloom_filters =
+                create_bloom_filters(&batch, parquet_schema.fields(), bloom_filter_fpps);
+            let encodings = resolve_auto_encodings(&batch, encodings, auto_encoding);
+            let row_group = create_serializer(
+                batch,
+                parquet_schema.fields(),
+                &encodings,
+                column_options,
+                parallel,
+            );
 
-            Some(row_group)
+            Some(row_group.map(|columns| EncodedRowGroup {
+                columns,
+                bloom_filters,
+                sorting_columns: sorting_columns.clone(),
+            }))
         },
     })
 }
 
+/// Returns the columns that the rows of `df` are sorted by, which are the `declared` ones if any
+/// and otherwise the first column that is not nested and flagged as sorted.
+fn get_sorting_columns(
+    df: &DataFrame,
+    fields: &[ParquetType],
+    declared: &Option<Vec<SortingColumn>>,
+) -> Option<Vec<SortingColumn>> {
+    if declared.is_some() {
+        return declared.clone();
+    }
+    let mut column_idx = 0;
+    for (s, type_) in df.get_columns().iter().zip(fields) {
+        let ParquetType::PrimitiveType(_) = type_ else {
+            column_idx += to_parquet_leaves(type_.clone()).len();
+            continue;
+        };
+        let descending = match s.is_sorted_flag() {
+            IsSorted::Ascending => false,
+            IsSorted::Descending => true,
+            IsSorted::Not => {
+                column_idx += 1;
+                continue;
+            },
+        };
+        let nulls_first = s.null_count() > 0 && s.get(0).is_ok_and(|v| v.is_null());
+        return Some(vec![SortingColumn {
+            column_idx,
+            descending,
+            nulls_first,
+        }]);
+    }
+    None
+}
+
+/// Builds the bloom filter of each leaf column, empty if no bloom filters are written.
+///
+/// Only columns that are not nested get a bloom filter.
+fn create_bloom_filters(
+    batch: &RecordBatch,
+    fields: &[ParquetType],
+    fpps: &[Option<f64>],
+) -> Vec<Option<Vec<u8>>> {
+    if fpps.is_empty() {
+        return vec![];
+    }
+    let mut bloom_filters = vec![];
+    for ((array, type_), fpp) in batch.columns().iter().zip(fields).zip(fpps) {
+        match (type_, fpp) {
+            (ParquetType::PrimitiveType(_), Some(fpp)) => {
+                bloom_filters.push(array_to_bloom_filter(array.as_ref(), *fpp))
+            },
+            _ => {
+                let num_leaves = to_parquet_leaves(type_.clone()).len();
+                bloom_filters.extend(std::iter::repeat_with(|| None).take(num_leaves))
+            },
+        }
+    }
+    bloom_filters
+}
+
+/// Delta encodes the columns with an automatic encoding whose values in this row group are
+/// sorted, as their deltas and shared prefixes are small.
+fn resolve_auto_encodings<'a>(
+    batch: &RecordBatch,
+    encodings: &'a [Vec<Encoding>],
+    auto_encoding: &[bool],
+) -> Cow<'a, [Vec<Encoding>]> {
+    if !auto_encoding.contains(&true) {
+        return Cow::Borrowed(encodings);
+    }
+    let mut encodings = encodings.to_vec();
+    for ((array, encoding), auto) in batch
+        .columns()
+        .iter()
+        .zip(&mut encodings)
+        .zip(auto_encoding)
+    {
+        if *auto && is_monotonic(array.as_ref()) {
+            *encoding = vec![delta_encoding(array.dtype())];
+        }
+    }
+    Cow::Owned(encodings)
+}
+
+/// Whether the non-null values of a column that supports delta encoding are sorted in either
+/// direction.
+fn is_monotonic(array: &dyn Array) -> bool {
+    fn check<T: PartialOrd>(mut values: impl Iterator<Item = T>) -> bool {
+        let Some(mut previous) = values.next() else {
+            return true;
+        };
+        let (mut ascending, mut descending) = (true, true);
+        for value in values {
+            ascending &= previous <= value;
+            descending &= previous >= value;
+            if !ascending && !descending {
+                return false;
+            }
+            previous = value;
+        }
+        true
+    }
+
+    let any = array.as_any();
+    match array.dtype().to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            check(any.downcast_ref::<PrimitiveArray<$T>>().unwrap().non_null_values_iter())
+        }),
+        PhysicalType::LargeBinary => check(
+            any.downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .non_null_values_iter(),
+        ),
+        PhysicalType::LargeUtf8 => check(
+            any.downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .non_null_values_iter(),
+        ),
+        PhysicalType::BinaryView => check(
+            any.downcast_ref::<BinaryViewArray>()
+                .unwrap()
+                .non_null_values_iter(),
+        ),
+        PhysicalType::Utf8View => check(
+            any.downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .non_null_values_iter(),
+        ),
+        _ => false,
+    }
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
@@ -147,12 +341,13 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &[WriteOptions],
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = if parallel {
         POOL.install(|| {
@@ -161,6 +356,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(column_options)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -170,6 +366,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(column_options)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -185,17 +382,19 @@ fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    column_options: &[WriteOptions],
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *options) };
 
     let columns = batch
         .columns()
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(column_options)
         .flat_map(func)
         .collect::<Vec<_>>();
 
@@ -4,7 +4,12 @@ mod batched_writer;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
-pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
+pub use batched_writer::{BatchedWriter, EncodedRowGroup};
+pub use options::{
+    BloomFilterOptions, BrotliLevel, GzipLevel, ParquetColumnOptions, ParquetCompression,
+    ParquetEncoding, ParquetEncodingOptions, ParquetSortingColumn, ParquetWriteOptions, ZstdLevel,
+    DEFAULT_MAX_OPEN_FILES,
+};
+pub use polars_parquet::parquet::encryption::{EncryptionKey, FileEncryptionProperties};
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
 pub use writer::ParquetWriter;
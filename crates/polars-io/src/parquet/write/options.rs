@@ -1,12 +1,18 @@
-use polars_error::PolarsResult;
+use arrow::datatypes::{ArrowDataType, ArrowSchema, PhysicalType};
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+use polars_parquet::parquet::encryption::FileEncryptionProperties;
 use polars_parquet::write::{
     BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    SchemaDescriptor, SortingColumn, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
+use polars_utils::pl_str::PlSmallStr;
+use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+use crate::parquet::metadata::{FieldMetadata, KeyValueMetadata};
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -15,10 +21,418 @@ pub struct ParquetWriteOptions {
     pub statistics: StatisticsOptions,
     /// If `None` will be all written to a single row group.
     pub row_group_size: Option<usize>,
-    /// if `None` will be 1024^2 bytes
+    /// If set, row groups hold about this many bytes of uncompressed data, and at most
+    /// `row_group_size` rows if that is set as well.
+    pub row_group_bytes: Option<usize>,
+    /// The target size of a data page in bytes, if `None` will be 1024^2 bytes
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// If set, the streaming sink writes a directory of files of about this many bytes instead
+    /// of a single file.
+    pub target_file_size: Option<usize>,
+    /// If set, the streaming sink writes a directory of files of at most this many rows instead
+    /// of a single file.
+    pub max_rows_per_file: Option<usize>,
+    /// If set, the file is encrypted with Parquet modular encryption. Columns with a key in
+    /// the properties are encrypted with that key, otherwise all columns are encrypted with the
+    /// footer key.
+    pub encryption: Option<FileEncryptionProperties>,
+    /// If set, split block bloom filters are written for these columns.
+    pub bloom_filter: Option<BloomFilterOptions>,
+    /// If set, the encoding of the values of each column. Otherwise integer, temporal and
+    /// string columns are dictionary encoded.
+    pub encoding: Option<ParquetEncodingOptions>,
+    /// If set, key-value metadata written to the file and its column chunks.
+    pub key_value_metadata: Option<KeyValueMetadata>,
+    /// If set, Arrow metadata of the columns, such as the name of an extension type, written to
+    /// the Arrow schema that is stored in the file.
+    pub field_metadata: Option<FieldMetadata>,
+    /// The columns that the rows are sorted by, in order of precedence. If `None`, the rows of
+    /// each row group are marked as sorted by the first column that is known to be sorted.
+    pub sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    /// Settings of columns that override those of the file, by column name or by a regular
+    /// expression that starts with `^` and ends with `$`.
+    pub column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    /// If not empty, the streaming sink writes a hive partitioned directory tree, e.g.
+    /// `year=2024/month=1/00000000.parquet`, with the rows of every partition of these columns.
+    pub partition_by: Vec<PlSmallStr>,
+    /// Maximum number of files that the streaming sink keeps open when `partition_by` is set. If
+    /// a partition needs a file beyond that, the least recently written file is finished and a
+    /// new one is started for it later. Defaults to [`DEFAULT_MAX_OPEN_FILES`].
+    pub max_open_files: Option<usize>,
+}
+
+impl ParquetWriteOptions {
+    /// Whether the streaming sink writes a directory of files instead of a single file.
+    pub fn is_multi_file(&self) -> bool {
+        self.target_file_size.is_some() || self.max_rows_per_file.is_some()
+    }
+
+    /// Whether the streaming sink writes a hive partitioned directory tree.
+    pub fn is_partitioned(&self) -> bool {
+        !self.partition_by.is_empty()
+    }
+
+    /// Returns the maximum number of rows of a row group, for rows of about `bytes_per_row`
+    /// bytes in memory.
+    pub fn max_row_group_rows(&self, bytes_per_row: f64) -> usize {
+        max_row_group_rows(self.row_group_size, self.row_group_bytes, bytes_per_row)
+    }
+}
+
+/// The number of files that the partitioned streaming sink keeps open if `max_open_files` is not
+/// set.
+pub const DEFAULT_MAX_OPEN_FILES: usize = 128;
+
+/// The number of rows of a row group if neither its rows nor its bytes are given.
+pub(super) const DEFAULT_ROW_GROUP_SIZE: usize = 512 * 512;
+
+pub(super) fn max_row_group_rows(
+    row_group_size: Option<usize>,
+    row_group_bytes: Option<usize>,
+    bytes_per_row: f64,
+) -> usize {
+    let by_bytes = row_group_bytes.map(|bytes| (bytes as f64 / bytes_per_row.max(1.0)) as usize);
+    match (row_group_size, by_bytes) {
+        (Some(rows), Some(by_bytes)) => rows.min(by_bytes),
+        (rows, by_bytes) => rows.or(by_bytes).unwrap_or(DEFAULT_ROW_GROUP_SIZE),
+    }
+    .max(1)
+}
+
+/// A column that the rows of a Parquet file are sorted by.
+///
+/// The sorting columns are written to the metadata of every row group, and readers mark the
+/// first of them as sorted. Only columns that are not nested can be sorting columns.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetSortingColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    pub nulls_last: bool,
+}
+
+impl ParquetSortingColumn {
+    /// The column `name` in ascending order with the nulls first.
+    pub fn new(name: impl Into<PlSmallStr>) -> Self {
+        Self {
+            name: name.into(),
+            descending: false,
+            nulls_last: false,
+        }
+    }
+
+    /// Set whether the values are in descending order.
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    /// Set whether the nulls come after the values.
+    pub fn with_nulls_last(mut self, nulls_last: bool) -> Self {
+        self.nulls_last = nulls_last;
+        self
+    }
+
+    /// Returns the sorting column of the leaf of this column in `parquet_schema`.
+    pub(crate) fn to_leaf(
+        &self,
+        schema: &ArrowSchema,
+        parquet_schema: &SchemaDescriptor,
+    ) -> PolarsResult<SortingColumn> {
+        polars_ensure!(
+            schema.contains(&self.name),
+            ColumnNotFound: "cannot sort the parquet file by column '{}', it is not in the schema", self.name
+        );
+        let Some(column_idx) = parquet_schema
+            .columns()
+            .iter()
+            .position(|column| column.path_in_schema.as_slice() == [self.name.clone()])
+        else {
+            polars_bail!(InvalidOperation: "cannot sort the parquet file by nested column '{}'", self.name);
+        };
+        Ok(SortingColumn {
+            column_idx,
+            descending: self.descending,
+            nulls_first: !self.nulls_last,
+        })
+    }
+}
+
+/// The columns to write split block bloom filters for.
+///
+/// Bloom filters let readers skip row groups for equality and `is_in` predicates, which min/max
+/// statistics rarely allow for columns with many distinct values, such as IDs. They are
+/// supported for integer, temporal, string and binary columns that are not nested, and are not
+/// written for encrypted columns.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BloomFilterOptions {
+    /// The false positive probability of columns without their own.
+    pub fpp: f64,
+    /// The columns to write a bloom filter for, optionally with their own false positive
+    /// probability. If empty, all supported columns get a bloom filter.
+    pub columns: Vec<(PlSmallStr, Option<f64>)>,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: 0.05,
+            columns: vec![],
+        }
+    }
+}
+
+impl PartialEq for BloomFilterOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.fpp.to_bits() == other.fpp.to_bits()
+            && self.columns.len() == other.columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(&other.columns)
+                .all(|((l, l_fpp), (r, r_fpp))| {
+                    l == r && l_fpp.map(f64::to_bits) == r_fpp.map(f64::to_bits)
+                })
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl std::hash::Hash for BloomFilterOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.fpp.to_bits().hash(state);
+        for (name, fpp) in &self.columns {
+            name.hash(state);
+            fpp.map(f64::to_bits).hash(state);
+        }
+    }
+}
+
+impl BloomFilterOptions {
+    /// Set the false positive probability of columns without their own.
+    pub fn with_fpp(mut self, fpp: f64) -> Self {
+        self.fpp = fpp;
+        self
+    }
+
+    /// Write a bloom filter for the column `name`.
+    pub fn with_column(mut self, name: impl Into<PlSmallStr>) -> Self {
+        self.columns.push((name.into(), None));
+        self
+    }
+
+    /// Write a bloom filter with false positive probability `fpp` for the column `name`.
+    pub fn with_column_fpp(mut self, name: impl Into<PlSmallStr>, fpp: f64) -> Self {
+        self.columns.push((name.into(), Some(fpp)));
+        self
+    }
+
+    /// Returns the false positive probability of each column in `schema`, `None` for columns
+    /// without a bloom filter.
+    pub(crate) fn column_fpps(&self, schema: &ArrowSchema) -> PolarsResult<Vec<Option<f64>>> {
+        let check_fpp = |fpp: f64| {
+            polars_ensure!(
+                fpp > 0.0 && fpp < 1.0,
+                InvalidOperation: "bloom filter false positive probability must be between 0 and 1, got {}",
+                fpp
+            );
+            Ok(())
+        };
+        check_fpp(self.fpp)?;
+
+        if self.columns.is_empty() {
+            return Ok(vec![Some(self.fpp); schema.len()]);
+        }
+        let mut fpps = vec![None; schema.len()];
+        for (name, fpp) in &self.columns {
+            let Some(i) = schema.index_of(name) else {
+                polars_bail!(ColumnNotFound: "cannot write a bloom filter for column '{}', it is not in the schema", name);
+            };
+            let fpp = fpp.unwrap_or(self.fpp);
+            check_fpp(fpp)?;
+            fpps[i] = Some(fpp);
+        }
+        Ok(fpps)
+    }
+}
+
+/// How the values of a column are encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    /// Dictionary encoding for integer, temporal, string and binary columns, falling back to
+    /// plain encoding if a column chunk has too many distinct values.
+    #[default]
+    Dictionary,
+    /// Plain encoding.
+    Plain,
+    /// `DELTA_BINARY_PACKED` for integer and temporal columns and `DELTA_BYTE_ARRAY` for string
+    /// and binary columns. This is much smaller than dictionary encoding for sorted or
+    /// incrementing data, such as IDs and timestamps.
+    Delta,
+    /// Delta encoding for the column chunks whose values are sorted, dictionary encoding for
+    /// the others.
+    Auto,
+}
+
+impl ParquetEncoding {
+    /// Whether this encoding is supported for columns of `dtype`.
+    pub(crate) fn supports(self, dtype: &ArrowDataType) -> bool {
+        match self {
+            Self::Dictionary | Self::Plain => true,
+            Self::Delta | Self::Auto => supports_delta(dtype),
+        }
+    }
+}
+
+/// Whether columns of `dtype` can be delta encoded.
+pub(crate) fn supports_delta(dtype: &ArrowDataType) -> bool {
+    use arrow::types::PrimitiveType::*;
+    matches!(
+        dtype.to_physical_type(),
+        PhysicalType::Primitive(Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64)
+            | PhysicalType::LargeBinary
+            | PhysicalType::LargeUtf8
+            | PhysicalType::BinaryView
+            | PhysicalType::Utf8View
+    )
+}
+
+/// The encodings of the columns of a Parquet file.
+///
+/// Columns that are not supported by the encoding of all columns keep their default encoding.
+/// Nested columns are always plain encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetEncodingOptions {
+    /// The encoding of columns without their own.
+    pub default: ParquetEncoding,
+    /// The columns with their own encoding.
+    pub columns: Vec<(PlSmallStr, ParquetEncoding)>,
+}
+
+impl ParquetEncodingOptions {
+    /// Set the encoding of columns without their own.
+    pub fn with_default(mut self, encoding: ParquetEncoding) -> Self {
+        self.default = encoding;
+        self
+    }
+
+    /// Encode the column `name` with `encoding`.
+    pub fn with_column(mut self, name: impl Into<PlSmallStr>, encoding: ParquetEncoding) -> Self {
+        self.columns.push((name.into(), encoding));
+        self
+    }
+
+    /// Returns the encoding of each column in `schema`.
+    pub(crate) fn column_encodings(
+        &self,
+        schema: &ArrowSchema,
+    ) -> PolarsResult<Vec<ParquetEncoding>> {
+        let mut encodings = schema
+            .iter_values()
+            .map(|field| {
+                if self.default.supports(&field.dtype) {
+                    self.default
+                } else {
+                    ParquetEncoding::default()
+                }
+            })
+            .collect::<Vec<_>>();
+        for (name, encoding) in &self.columns {
+            let Some((i, _, field)) = schema.get_full(name) else {
+                polars_bail!(ColumnNotFound: "cannot set the encoding of column '{}', it is not in the schema", name);
+            };
+            polars_ensure!(
+                encoding.supports(&field.dtype),
+                InvalidOperation: "{:?} encoding is not supported for column '{}' of type {:?}",
+                encoding, name, field.dtype
+            );
+            encodings[i] = *encoding;
+        }
+        Ok(encodings)
+    }
+}
+
+/// Settings of a column that override those of the file, such as a different compression for a
+/// column that is rarely read, or plain encoding for a string column with mostly unique values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// The compression of the column, if not that of the file.
+    pub compression: Option<ParquetCompression>,
+    /// The statistics of the column, if not those of the file.
+    pub statistics: Option<StatisticsOptions>,
+    /// The encoding of the column, if not that of the encoding options.
+    pub encoding: Option<ParquetEncoding>,
+}
+
+impl ParquetColumnOptions {
+    /// Compress the column with `compression`.
+    pub fn with_compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Write the `statistics` of the column.
+    pub fn with_statistics(mut self, statistics: StatisticsOptions) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+
+    /// Encode the column with `encoding`.
+    pub fn with_encoding(mut self, encoding: ParquetEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Turn dictionary encoding of the column on or off, which is the same as the dictionary
+    /// or the plain encoding.
+    pub fn with_dictionary(self, dictionary: bool) -> Self {
+        self.with_encoding(if dictionary {
+            ParquetEncoding::Dictionary
+        } else {
+            ParquetEncoding::Plain
+        })
+    }
+
+    /// Override the settings of `self` with those that are set in `other`.
+    fn update(&mut self, other: &Self) {
+        self.compression = other.compression.or(self.compression);
+        self.statistics = other.statistics.or(self.statistics);
+        self.encoding = other.encoding.or(self.encoding);
+    }
+}
+
+/// Returns the options of each column in `schema`. The options are given by column name, or by
+/// a regular expression if the name starts with `^` and ends with `$`, and the settings of later
+/// options override those of earlier ones that match the same column.
+pub(crate) fn resolve_column_options(
+    column_options: &[(PlSmallStr, ParquetColumnOptions)],
+    schema: &ArrowSchema,
+) -> PolarsResult<Vec<ParquetColumnOptions>> {
+    let mut resolved = vec![ParquetColumnOptions::default(); schema.len()];
+    for (name, options) in column_options {
+        if name.starts_with('^') && name.ends_with('$') {
+            let regex = Regex::new(name).map_err(|err| {
+                polars_err!(ComputeError: "invalid regex '{}' in parquet column options: {}", name, err)
+            })?;
+            for (i, column) in schema.iter_names().enumerate() {
+                if regex.is_match(column) {
+                    resolved[i].update(options);
+                }
+            }
+        } else {
+            let Some(i) = schema.index_of(name) else {
+                polars_bail!(ColumnNotFound: "cannot set the options of column '{}', it is not in the schema", name);
+            };
+            resolved[i].update(options);
+        }
+    }
+    Ok(resolved)
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -3,14 +3,20 @@ use std::sync::Mutex;
 
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
+use polars_parquet::parquet::encryption::FileEncryptionProperties;
 use polars_parquet::write::{
     to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, StatisticsOptions,
     Version, WriteOptions,
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{
+    max_row_group_rows, resolve_column_options, supports_delta, BloomFilterOptions,
+    ParquetColumnOptions, ParquetCompression, ParquetEncoding, ParquetEncodingOptions,
+    ParquetSortingColumn,
+};
 use super::ParquetWriteOptions;
+use crate::parquet::metadata::{FieldMetadata, KeyValueMetadata};
 use crate::prelude::chunk_df_for_writing;
 use crate::shared::schema_to_arrow_checked;
 
@@ -23,7 +29,15 @@ impl ParquetWriteOptions {
             .with_compression(self.compression)
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
+            .with_row_group_bytes(self.row_group_bytes)
             .with_data_page_size(self.data_page_size)
+            .with_encryption(self.encryption.clone())
+            .with_bloom_filter(self.bloom_filter.clone())
+            .with_encoding(self.encoding.clone())
+            .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_metadata(self.field_metadata.clone())
+            .with_sorting_columns(self.sorting_columns.clone())
+            .with_column_options(self.column_options.clone())
     }
 }
 
@@ -37,10 +51,26 @@ pub struct ParquetWriter<W> {
     statistics: StatisticsOptions,
     /// if `None` will be 512^2 rows
     row_group_size: Option<usize>,
+    /// Target uncompressed size of a row group in bytes
+    row_group_bytes: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Encrypt the file with these keys
+    encryption: Option<FileEncryptionProperties>,
+    /// Write bloom filters for these columns
+    bloom_filter: Option<BloomFilterOptions>,
+    /// Encode the columns with these encodings
+    encoding: Option<ParquetEncodingOptions>,
+    /// Key-value metadata of the file and its column chunks
+    key_value_metadata: Option<KeyValueMetadata>,
+    /// Arrow metadata of the columns
+    field_metadata: Option<FieldMetadata>,
+    /// The columns that the rows are sorted by
+    sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    /// Settings of columns that override those of the file
+    column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
 }
 
 impl<W> ParquetWriter<W>
@@ -57,8 +87,16 @@ where
             compression: ParquetCompression::default().into(),
             statistics: StatisticsOptions::default(),
             row_group_size: None,
+            row_group_bytes: None,
             data_page_size: None,
             parallel: true,
+            encryption: None,
+            bloom_filter: None,
+            encoding: None,
+            key_value_metadata: None,
+            field_metadata: None,
+            sorting_columns: None,
+            column_options: vec![],
         }
     }
 
@@ -84,12 +122,73 @@ where
         self
     }
 
+    /// Set the target size of a row group in bytes of uncompressed data, such that row groups
+    /// are about the same size regardless of the width of the rows. If the row group size is
+    /// set as well, row groups have at most that many rows.
+    pub fn with_row_group_bytes(mut self, bytes: Option<usize>) -> Self {
+        self.row_group_bytes = bytes;
+        self
+    }
+
     /// Sets the maximum bytes size of a data page. If `None` will be 1024^2 bytes.
     pub fn with_data_page_size(mut self, limit: Option<usize>) -> Self {
         self.data_page_size = limit;
         self
     }
 
+    /// Encrypt the file with Parquet modular encryption.
+    pub fn with_encryption(mut self, encryption: Option<FileEncryptionProperties>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Write split block bloom filters for the columns given in the options.
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<BloomFilterOptions>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
+    /// Set the encoding of the values of each column. By default integer, temporal and string
+    /// columns are dictionary encoded.
+    pub fn with_encoding(mut self, encoding: Option<ParquetEncodingOptions>) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Write key-value metadata to the file and its column chunks.
+    pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+
+    /// Write Arrow metadata of the columns, such as the name of an extension type, to the
+    /// Arrow schema that is stored in the file.
+    pub fn with_field_metadata(mut self, field_metadata: Option<FieldMetadata>) -> Self {
+        self.field_metadata = field_metadata;
+        self
+    }
+
+    /// Declare the columns that the rows are sorted by, in order of precedence. By default the
+    /// rows of each row group are marked as sorted by the first column that is flagged as sorted.
+    pub fn with_sorting_columns(
+        mut self,
+        sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    ) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
+    /// Override the compression, statistics or encoding of columns, given by name or by a regular
+    /// expression that starts with `^` and ends with `$`. Later options override the settings of
+    /// earlier ones for the same column.
+    pub fn with_column_options(
+        mut self,
+        column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -97,18 +196,90 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        let mut schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        if let Some(field_metadata) = &self.field_metadata {
+            field_metadata.apply(&mut schema)?;
+        }
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let column_options = resolve_column_options(&self.column_options, &schema)?;
+        let mut column_encodings = match &self.encoding {
+            Some(encoding) => encoding.column_encodings(&schema)?,
+            None => vec![ParquetEncoding::default(); schema.len()],
+        };
+        for ((name, field), (options, encoding)) in schema
+            .iter()
+            .zip(column_options.iter().zip(&mut column_encodings))
+        {
+            if let Some(column_encoding) = options.encoding {
+                polars_ensure!(
+                    column_encoding.supports(&field.dtype),
+                    InvalidOperation: "{:?} encoding is not supported for column '{}' of type {:?}",
+                    column_encoding, name, field.dtype
+                );
+                *encoding = column_encoding;
+            }
+        }
+        let encodings = get_encodings(&schema, &column_encodings);
+        let auto_encoding = column_encodings
+            .iter()
+            .map(|encoding| *encoding == ParquetEncoding::Auto)
+            .collect::<Vec<_>>();
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let column_options = column_options
+            .iter()
+            .map(|column| WriteOptions {
+                compression: column.compression.map_or(options.compression, Into::into),
+                statistics: column.statistics.unwrap_or(options.statistics),
+                ..options
+            })
+            .collect::<Vec<_>>();
+        // Column indexes are written for the columns with statistics if any column has them.
+        let file_options = WriteOptions {
+            statistics: column_options
+                .iter()
+                .map(|column| column.statistics)
+                .find(|statistics| !statistics.is_empty())
+                .unwrap_or(options.statistics),
+            ..options
+        };
+        let bloom_filter_fpps = match &self.bloom_filter {
+            Some(bloom_filter) => bloom_filter.column_fpps(&schema)?,
+            None => vec![],
+        };
+        let (file_key_values, leaf_key_values) = match &self.key_value_metadata {
+            Some(metadata) => (
+                metadata.file_key_values()?,
+                metadata.leaf_key_values(&schema, &parquet_schema)?,
+            ),
+            None => (None, vec![]),
+        };
+        let sorting_columns = self
+            .sorting_columns
+            .as_ref()
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|column| column.to_leaf(&schema, &parquet_schema))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .transpose()?;
+        let mut writer = FileWriter::try_new(self.writer, schema, file_options)?
+            .with_column_key_value_metadata(leaf_key_values);
+        if let Some(encryption) = self.encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
-            options,
+            column_options,
             parallel: self.parallel,
+            bloom_filter_fpps,
+            auto_encoding,
+            key_value_metadata: file_key_values,
+            sorting_columns,
         })
     }
 
@@ -123,20 +294,46 @@ where
 
     /// Write the given DataFrame in the writer `W`. Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
-        let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
+        let bytes_per_row = df.estimated_size() as f64 / df.height().max(1) as f64;
+        let row_group_size =
+            max_row_group_rows(self.row_group_size, self.row_group_bytes, bytes_per_row);
+        let chunked_df = chunk_df_for_writing(df, row_group_size)?;
         let mut batched = self.batched(&chunked_df.schema())?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+fn get_encodings(schema: &ArrowSchema, encodings: &[ParquetEncoding]) -> Vec<Vec<Encoding>> {
     schema
         .iter_values()
-        .map(|f| transverse(&f.dtype, encoding_map))
+        .zip(encodings)
+        .map(|(f, encoding)| transverse(&f.dtype, |dtype| leaf_encoding(dtype, *encoding)))
         .collect()
 }
 
+/// The encoding of a leaf column of type `dtype`. Columns with [`ParquetEncoding::Auto`] start
+/// with their default encoding, which is replaced for the column chunks with sorted values.
+fn leaf_encoding(dtype: &ArrowDataType, encoding: ParquetEncoding) -> Encoding {
+    match encoding {
+        ParquetEncoding::Plain
+            if !matches!(dtype.to_physical_type(), PhysicalType::Dictionary(_)) =>
+        {
+            Encoding::Plain
+        },
+        ParquetEncoding::Delta if supports_delta(dtype) => delta_encoding(dtype),
+        _ => encoding_map(dtype),
+    }
+}
+
+/// The delta encoding of a leaf column of type `dtype`.
+pub(super) fn delta_encoding(dtype: &ArrowDataType) -> Encoding {
+    match dtype.to_physical_type() {
+        PhysicalType::Primitive(_) => Encoding::DeltaBinaryPacked,
+        _ => Encoding::DeltaByteArray,
+    }
+}
+
 /// Declare encodings
 fn encoding_map(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
@@ -10,7 +10,7 @@ use rayon::prelude::*;
 use crate::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "ipc")]
 use crate::prelude::IpcWriterOptions;
-use crate::prelude::URL_ENCODE_CHAR_SET;
+use crate::utils::hive_partition_path;
 use crate::{SerWriter, WriteDataFrameToFile};
 
 impl WriteDataFrameToFile for ParquetWriteOptions {
@@ -63,27 +63,11 @@ where
 
         move |df: &DataFrame| {
             let cols = df.get_columns();
-
-            partition_by_col_idx
+            let keys = partition_by_col_idx
                 .iter()
-                .map(|&i| {
-                    let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
-
-                    format!(
-                        "{}={}",
-                        s.name(),
-                        percent_encoding::percent_encode(
-                            s.str()
-                                .unwrap()
-                                .get(0)
-                                .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-                                .as_bytes(),
-                            URL_ENCODE_CHAR_SET
-                        )
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("/")
+                .map(|&i| cols[i].clone())
+                .collect::<Vec<_>>();
+            hive_partition_path(&keys)
         }
     };
 
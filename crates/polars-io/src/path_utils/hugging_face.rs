@@ -218,7 +218,11 @@ pub(super) async fn expand_paths_hf(
 ) -> PolarsResult<(usize, Vec<PathBuf>)> {
     assert!(!paths.is_empty());
 
-    let client = reqwest::ClientBuilder::new().http1_only().https_only(true);
+    let client = match cloud_options {
+        Some(options) => options.reqwest_client_builder()?,
+        None => reqwest::ClientBuilder::new(),
+    };
+    let client = client.http1_only().https_only(true);
 
     let client = if let Some(CloudOptions {
         config: Some(CloudConfig::Http { headers }),
@@ -46,8 +46,9 @@ pub fn resolve_homedir(path: &Path) -> PathBuf {
     path.into()
 }
 
-static CLOUD_URL: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf)://").unwrap());
+static CLOUD_URL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf|store)://").unwrap()
+});
 
 /// Check if the path is a cloud url.
 pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
@@ -59,7 +60,43 @@ pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
 
 /// Get the index of the first occurrence of a glob symbol.
 pub fn get_glob_start_idx(path: &[u8]) -> Option<usize> {
-    memchr::memchr3(b'*', b'?', b'[', path)
+    path.iter()
+        .position(|b| matches!(b, b'*' | b'?' | b'[' | b'{'))
+}
+
+/// Expand the `{a,b}` alternatives of a glob pattern, which the `glob` crate doesn't support.
+/// Unbalanced braces are kept as is.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+    let mut depth = 0;
+    let mut alternatives = vec![];
+    let mut start = open + 1;
+    for (i, c) in pattern[open..].char_indices().map(|(i, c)| (i + open, c)) {
+        match c {
+            '{' => depth += 1,
+            ',' if depth == 1 => {
+                alternatives.push(&pattern[start..i]);
+                start = i + 1;
+            },
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    alternatives.push(&pattern[start..i]);
+                    let (head, tail) = (&pattern[..open], &pattern[i + 1..]);
+                    return alternatives
+                        .into_iter()
+                        .flat_map(|alternative| {
+                            expand_braces(&format!("{head}{alternative}{tail}"))
+                        })
+                        .collect();
+                }
+            },
+            _ => {},
+        }
+    }
+    vec![pattern.to_string()]
 }
 
 /// Returns `true` if `expanded_paths` were expanded from a single directory
@@ -83,6 +120,12 @@ pub fn expanded_from_single_directory<P: AsRef<std::path::Path>>(
     }
 }
 
+/// Whether `path` is a summary file of a dataset rather than a data file.
+fn is_summary_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "_metadata" || name == "_common_metadata")
+}
+
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 pub fn expand_paths(
     paths: &[PathBuf],
@@ -196,8 +239,6 @@ pub fn expand_paths_hive(
                             ))],
                         )
                     } else {
-                        use futures::TryStreamExt;
-
                         if !is_cloud {
                             // FORCE_ASYNC in the test suite wants us to raise a proper error message
                             // for non-existent file paths. Note we can't do this for cloud paths as
@@ -210,25 +251,23 @@ pub fn expand_paths_hive(
                             }
                         }
 
-                        let cloud_location = &cloud_location;
-
-                        let mut paths = store
-                            .list(Some(&prefix))
-                            .try_filter_map(|x| async move {
-                                let out = (x.size > 0).then(|| {
-                                    PathBuf::from({
-                                        format_path(
-                                            &cloud_location.scheme,
-                                            &cloud_location.bucket,
-                                            x.location.as_ref(),
-                                        )
-                                    })
-                                });
-                                Ok(out)
-                            })
-                            .try_collect::<Vec<_>>()
-                            .await
-                            .map_err(to_compute_err)?;
+                        let mut paths = crate::cloud::list_non_empty(
+                            store.as_ref(),
+                            path,
+                            cloud_options,
+                            prefix.as_ref(),
+                            true,
+                        )
+                        .await?
+                        .into_iter()
+                        .map(|location| {
+                            PathBuf::from(format_path(
+                                &cloud_location.scheme,
+                                &cloud_location.bucket,
+                                location.as_ref(),
+                            ))
+                        })
+                        .collect::<Vec<_>>();
 
                         paths.sort_unstable();
                         (
@@ -306,7 +345,7 @@ pub fn expand_paths_hive(
                     for path in paths {
                         if path.is_dir() {
                             stack.push_back(path);
-                        } else if path.metadata()?.len() > 0 {
+                        } else if path.metadata()?.len() > 0 && !is_summary_file(&path) {
                             out_paths.push(path);
                         }
                     }
@@ -320,16 +359,25 @@ pub fn expand_paths_hive(
             if glob && i.is_some() {
                 hive_idx_tracker.update(0, path_idx)?;
 
-                let Ok(paths) = glob::glob(path.to_str().unwrap()) else {
-                    polars_bail!(ComputeError: "invalid glob pattern given")
-                };
+                let patterns = expand_braces(path.to_str().unwrap());
+                let mut matched = vec![];
+                for pattern in &patterns {
+                    let Ok(paths) = glob::glob(pattern) else {
+                        polars_bail!(ComputeError: "invalid glob pattern given")
+                    };
 
-                for path in paths {
-                    let path = path.map_err(to_compute_err)?;
-                    if !path.is_dir() && path.metadata()?.len() > 0 {
-                        out_paths.push(path);
+                    for path in paths {
+                        let path = path.map_err(to_compute_err)?;
+                        if !path.is_dir() && path.metadata()?.len() > 0 {
+                            matched.push(path);
+                        }
                     }
                 }
+                if patterns.len() > 1 {
+                    matched.sort_unstable();
+                    matched.dedup();
+                }
+                out_paths.extend(matched);
             } else {
                 hive_idx_tracker.update(0, path_idx)?;
                 out_paths.push(path.clone());
@@ -380,7 +428,24 @@ pub(crate) fn ensure_directory_init(path: &Path) -> std::io::Result<()> {
 mod tests {
     use std::path::PathBuf;
 
-    use super::resolve_homedir;
+    use super::{expand_braces, resolve_homedir};
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(expand_braces("a/*.csv"), ["a/*.csv"]);
+        assert_eq!(
+            expand_braces("a/{b,c{1,2}}/*.{csv,json}"),
+            [
+                "a/b/*.csv",
+                "a/b/*.json",
+                "a/c1/*.csv",
+                "a/c1/*.json",
+                "a/c2/*.csv",
+                "a/c2/*.json"
+            ]
+        );
+        assert_eq!(expand_braces("a/{b,c"), ["a/{b,c"]);
+    }
 
     #[cfg(not(target_os = "windows"))]
     #[test]
@@ -41,12 +41,21 @@ pub fn apply_predicate(
     Ok(())
 }
 
+/// A probabilistic set of the values in a column, such as a parquet bloom filter.
+pub trait ValueSetFilter: Send + Sync + std::fmt::Debug {
+    /// Returns `false` if none of the `values` are in the column.
+    ///
+    /// May return `true` even if none of them are.
+    fn may_contain_any(&self, values: &Series) -> bool;
+}
+
 /// Statistics of the values in a column.
 ///
 /// The following statistics are tracked for each row group:
 /// - Null count
 /// - Minimum value
 /// - Maximum value
+/// - Optionally a [`ValueSetFilter`] of the values
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -55,6 +64,8 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value_filter: Option<Arc<dyn ValueSetFilter>>,
 }
 
 impl ColumnStats {
@@ -70,9 +81,16 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            value_filter: None,
         }
     }
 
+    /// Attaches a [`ValueSetFilter`] of the values in the column.
+    pub fn with_value_filter(mut self, value_filter: Arc<dyn ValueSetFilter>) -> Self {
+        self.value_filter = Some(value_filter);
+        self
+    }
+
     /// Constructs a new [`ColumnStats`] with only the [`Field`] information and no statistics.
     pub fn from_field(field: Field) -> Self {
         Self {
@@ -80,6 +98,7 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            value_filter: None,
         }
     }
 
@@ -91,6 +110,7 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            value_filter: None,
         }
     }
 
@@ -135,6 +155,15 @@ impl ColumnStats {
         }
     }
 
+    /// Returns whether any of the `values` might be in the column.
+    ///
+    /// This is `true` unless a [`ValueSetFilter`] rules all of them out.
+    pub fn may_contain_any(&self, values: &Series) -> bool {
+        self.value_filter
+            .as_ref()
+            .map_or(true, |filter| filter.may_contain_any(values))
+    }
+
     /// Returns the minimum and maximum values of the column as a single [`Series`].
     pub fn to_min_max(&self) -> Option<Series> {
         let min_val = self.get_min_state()?;
@@ -189,6 +218,24 @@ impl ColumnStats {
             Some(max_val)
         }
     }
+
+    /// Returns the statistics of the field `name` of a struct column.
+    ///
+    /// Returns `None` if the column is not a struct or has no field `name`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn struct_field(&self, name: &str) -> Option<Self> {
+        let DataType::Struct(fields) = self.dtype() else {
+            return None;
+        };
+        let field = fields.iter().find(|field| field.name() == name)?.clone();
+        let get_field = |s: &Option<Series>| s.as_ref()?.struct_().ok()?.field_by_name(name).ok();
+        Some(Self::new(
+            field,
+            get_field(&self.null_count),
+            get_field(&self.min_value),
+            get_field(&self.max_value),
+        ))
+    }
 }
 
 /// Returns whether the [`DataType`] supports minimum/maximum operations.
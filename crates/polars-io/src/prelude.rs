@@ -7,8 +7,10 @@ pub use crate::ipc::*;
 pub use crate::json::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "json")]
+pub use crate::ndjson::JsonUnionResolution;
 #[cfg(feature = "parquet")]
-pub use crate::parquet::{metadata::*, read::*, write::*};
+pub use crate::parquet::{metadata::*, read::*, summary::*, write::*};
 #[cfg(feature = "parquet")]
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
@@ -70,8 +70,9 @@ impl ObjectStoreByteSource {
     ) -> PolarsResult<Self> {
         let (CloudLocation { prefix, .. }, store) =
             build_object_store(path, cloud_options, false).await?;
+        let store = PolarsObjectStore::new(store)
+            .with_cloud_options(cloud_options.map(|options| options.for_url(path)));
         let path = object_path_from_str(&prefix)?;
-        let store = PolarsObjectStore::new(store);
 
         Ok(Self { store, path })
     }
@@ -3,6 +3,7 @@ mod other;
 
 pub use compression::is_compressed;
 pub use other::*;
+use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 pub mod byte_source;
 pub mod slice;
@@ -13,3 +14,27 @@ pub const URL_ENCODE_CHAR_SET: &percent_encoding::AsciiSet = &percent_encoding::
     .add(b':')
     .add(b' ')
     .add(b'%');
+
+/// Directory of a hive partition relative to the root of the dataset, e.g. `a=1/b=x`, formatted
+/// from the first value of each partition column.
+pub fn hive_partition_path(columns: &[Series]) -> String {
+    columns
+        .iter()
+        .map(|s| {
+            let s = s.slice(0, 1).cast(&DataType::String).unwrap();
+            format!(
+                "{}={}",
+                s.name(),
+                percent_encoding::percent_encode(
+                    s.str()
+                        .unwrap()
+                        .get(0)
+                        .unwrap_or("__HIVE_DEFAULT_PARTITION__")
+                        .as_bytes(),
+                    URL_ENCODE_CHAR_SET
+                )
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
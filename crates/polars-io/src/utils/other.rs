@@ -82,6 +82,46 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
     }
 }
 
+/// Decompress `reader` while it is read if its data starts with the magic number of a supported
+/// compression, or return it unchanged otherwise.
+///
+/// Unlike [`maybe_decompress_bytes`], the data is never inflated in memory at once. Gzip streams
+/// may consist of multiple members, which also covers bgzf, and zstd streams of multiple
+/// (skippable) frames, which covers the seekable zstd format.
+pub fn maybe_decompress_reader<R: std::io::BufRead + Send + 'static>(
+    mut reader: R,
+) -> PolarsResult<Box<dyn Read + Send>> {
+    use crate::prelude::is_compressed;
+    let magic = reader.fill_buf()?;
+    let is_compressed = magic.len() >= 4 && is_compressed(magic);
+
+    if is_compressed {
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            use crate::utils::compression::magic::*;
+
+            if magic.starts_with(&GZIP) {
+                Ok(Box::new(flate2::bufread::MultiGzDecoder::new(reader)))
+            } else if magic.starts_with(&ZLIB0)
+                || magic.starts_with(&ZLIB1)
+                || magic.starts_with(&ZLIB2)
+            {
+                Ok(Box::new(flate2::bufread::ZlibDecoder::new(reader)))
+            } else if magic.starts_with(&ZSTD) {
+                Ok(Box::new(zstd::Decoder::with_buffer(reader)?))
+            } else {
+                polars_bail!(ComputeError: "unimplemented compression format")
+            }
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            panic!("cannot decompress without 'decompress' or 'decompress-fast' feature")
+        }
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
 #[cfg(any(
     feature = "ipc",
     feature = "ipc_streaming",
@@ -332,4 +372,41 @@ mod tests {
         assert!(FLOAT_RE.is_match("7e-05"));
         assert!(FLOAT_RE.is_match("+7e+05"));
     }
+
+    #[test]
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn test_maybe_decompress_reader() {
+        use std::io::{Cursor, Read, Write};
+
+        use super::maybe_decompress_reader;
+
+        let decompress = |bytes: Vec<u8>| {
+            let mut out = vec![];
+            maybe_decompress_reader(Cursor::new(bytes))
+                .unwrap()
+                .read_to_end(&mut out)
+                .unwrap();
+            out
+        };
+        let members = [&b"a,b\n1,2\n"[..], b"3,4\n", b"5,6\n"];
+        let expected = members.concat();
+
+        // Multiple gzip members, like bgzf.
+        let mut gzip = vec![];
+        for member in members {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(member).unwrap();
+            gzip.extend(encoder.finish().unwrap());
+        }
+        assert_eq!(decompress(gzip), expected);
+
+        // Multiple zstd frames with a skippable frame in between, like seekable zstd.
+        let mut zstd = zstd::encode_all(members[0], 0).unwrap();
+        zstd.extend([0x50, 0x2A, 0x4D, 0x18, 2, 0, 0, 0, 0, 0]);
+        zstd.extend(zstd::encode_all(&members[1..].concat()[..], 0).unwrap());
+        assert_eq!(decompress(zstd), expected);
+
+        assert_eq!(decompress(expected.clone()), expected);
+    }
 }
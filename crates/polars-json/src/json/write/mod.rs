@@ -10,9 +10,25 @@ use arrow::io::iterator::StreamingIterator;
 use arrow::record_batch::RecordBatchT;
 pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
-pub(crate) use serialize::new_serializer;
 use serialize::serialize;
-pub use utf8::serialize_to_utf8;
+pub(crate) use serialize::{new_serializer, new_serializer_with_options};
+pub use utf8::{serialize_to_utf8, serialize_to_utf8_with_options};
+
+/// Options that control how values are written by [`serialize_to_utf8_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerializeOptions {
+    /// Write the fields of structs, including nested ones, ordered by name instead of in the
+    /// order of the schema.
+    pub sort_fields: bool,
+    /// Leave out the fields of structs whose value is null instead of writing `null`.
+    pub strip_nulls: bool,
+    /// The chrono format of dates. Dates are written as `%Y-%m-%d` if not set.
+    pub date_format: Option<String>,
+    /// Write decimals as JSON numbers instead of strings.
+    pub decimal_as_number: bool,
+    /// Whether trailing zeros of decimals are removed. Follows the global setting if not set.
+    pub decimal_trim_zeros: Option<bool>,
+}
 
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
@@ -1,3 +1,4 @@
+use std::fmt::Write as _;
 use std::io::Write;
 
 use arrow::array::*;
@@ -15,10 +16,19 @@ use arrow::temporal_conversions::{
     timestamp_s_to_datetime, timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
+use chrono::format::StrftimeItems;
 use chrono::{Duration, NaiveDate, NaiveDateTime};
 use streaming_iterator::StreamingIterator;
 
-use super::utf8;
+use super::{utf8, SerializeOptions};
+
+static DEFAULT_OPTIONS: SerializeOptions = SerializeOptions {
+    sort_fields: false,
+    strip_nulls: false,
+    date_format: None,
+    decimal_as_number: false,
+    decimal_trim_zeros: None,
+};
 
 fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
@@ -120,11 +130,20 @@ fn decimal_serializer<'a>(
     scale: usize,
     offset: usize,
     take: usize,
+    options: &SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let trim_zeros = get_trim_decimal_zeros();
+    let trim_zeros = options
+        .decimal_trim_zeros
+        .unwrap_or_else(get_trim_decimal_zeros);
+    let as_number = options.decimal_as_number;
     let f = move |x: Option<&i128>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
-            utf8::write_str(buf, format_decimal(*x, scale, trim_zeros).as_str()).unwrap()
+            let formatted = format_decimal(*x, scale, trim_zeros);
+            if as_number {
+                buf.extend_from_slice(formatted.as_str().as_bytes())
+            } else {
+                utf8::write_str(buf, formatted.as_str()).unwrap()
+            }
         } else {
             buf.extend(b"null")
         }
@@ -183,6 +202,7 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -191,27 +211,37 @@ fn struct_serializer<'a>(
     //  {"a": 3, "b": c, "c": {"a": 3}},
     // ]
     //
-    let mut serializers = array
-        .values()
+    let mut fields = array
+        .fields()
         .iter()
-        .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .zip(array.values())
+        .map(|(field, arr)| {
+            (
+                field.name.as_str(),
+                new_serializer_with_options(arr.as_ref(), offset, take, options),
+            )
+        })
         .collect::<Vec<_>>();
+    if options.sort_fields {
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+    let (names, mut serializers): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+    let strip_nulls = options.strip_nulls;
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new_with_validity(0..array.len(), array.validity()),
         move |maybe, buf| {
             if maybe.is_some() {
-                let names = array.fields().iter().map(|f| f.name.as_str());
-                serialize_item(
-                    buf,
-                    names.zip(
+                let record = names
+                    .iter()
+                    .copied()
+                    .zip(
                         serializers
                             .iter_mut()
                             .map(|serializer| serializer.next().unwrap()),
-                    ),
-                    true,
-                );
+                    )
+                    .filter(|(_, value)| !(strip_nulls && *value == b"null"));
+                serialize_item(buf, record, true);
             } else {
                 serializers.iter_mut().for_each(|iter| {
                     let _ = iter.next();
@@ -227,6 +257,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -237,7 +268,8 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer =
+        new_serializer_with_options(array.values().as_ref(), start, end - start, options);
 
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
         if let Some(offset) = offset {
@@ -266,8 +298,10 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
+    let mut serializer =
+        new_serializer_with_options(array.values().as_ref(), offset, take, options);
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
@@ -297,11 +331,29 @@ fn date_serializer<'a, T, F>(
     convert: F,
     offset: usize,
     take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
 where
     T: NativeType,
     F: Fn(T) -> NaiveDate + 'static + Send + Sync,
 {
+    if let Some(format) = &options.date_format {
+        // The format is validated before serializing, see `serialize_to_utf8_with_options`.
+        let items = StrftimeItems::new(format).parse().unwrap();
+        let mut formatted = String::new();
+        let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
+            if let Some(x) = x {
+                let nd = convert(*x);
+                formatted.clear();
+                write!(formatted, "{}", nd.format_with_items(items.iter())).unwrap();
+                utf8::write_str(buf, &formatted).unwrap();
+            } else {
+                buf.extend_from_slice(b"null")
+            }
+        };
+        return materialize_serializer(f, array.iter(), offset, take);
+    }
+
     let f = move |x: Option<&T>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let nd = convert(*x);
@@ -405,6 +457,15 @@ pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    new_serializer_with_options(array, offset, take, &DEFAULT_OPTIONS)
+}
+
+pub(crate) fn new_serializer_with_options<'a>(
+    array: &'a dyn Array,
+    offset: usize,
+    take: usize,
+    options: &'a SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.dtype().to_logical_type() {
         ArrowDataType::Boolean => {
@@ -442,7 +503,8 @@ pub(crate) fn new_serializer<'a>(
         },
         #[cfg(feature = "dtype-decimal")]
         ArrowDataType::Decimal(_, scale) => {
-            decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale, offset, take)
+            let array = array.as_any().downcast_ref().unwrap();
+            decimal_serializer(array, *scale, offset, take, options)
         },
         ArrowDataType::LargeUtf8 => {
             utf8_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
@@ -450,14 +512,19 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
+            let array = array.as_any().downcast_ref().unwrap();
+            fixed_size_list_serializer(array, offset, take, options)
         },
         ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
+            let array = array.as_any().downcast_ref().unwrap();
+            list_serializer::<i64>(array, offset, take, options)
         },
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt32, ArrowDataType::Utf8View) => {
@@ -477,6 +544,7 @@ pub(crate) fn new_serializer<'a>(
             date32_to_date,
             offset,
             take,
+            options,
         ),
         ArrowDataType::Timestamp(tu, None) => {
             let convert = match tu {
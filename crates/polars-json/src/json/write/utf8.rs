@@ -2,8 +2,9 @@
 use std::io;
 
 use arrow::array::{Array, MutableBinaryViewArray, Utf8ViewArray};
+use polars_error::{polars_err, PolarsResult};
 
-use crate::json::write::new_serializer;
+use crate::json::write::{new_serializer, new_serializer_with_options, SerializeOptions};
 
 pub fn write_str<W>(writer: &mut W, value: &str) -> io::Result<()>
 where
@@ -150,3 +151,29 @@ pub fn serialize_to_utf8(array: &dyn Array) -> Utf8ViewArray {
     }
     values.into()
 }
+
+/// Serialize every value of `array` to a JSON string like [`serialize_to_utf8`], formatted
+/// according to `options`.
+pub fn serialize_to_utf8_with_options(
+    array: &dyn Array,
+    options: &SerializeOptions,
+) -> PolarsResult<Utf8ViewArray> {
+    if let Some(format) = &options.date_format {
+        // Fail on invalid formats here, so that the serializers don't have to.
+        use std::fmt::Write;
+        let items = chrono::format::StrftimeItems::new(format)
+            .parse()
+            .map_err(|_| polars_err!(ComputeError: "invalid date format '{format}'"))?;
+        let sample = chrono::NaiveDate::default();
+        write!(String::new(), "{}", sample.format_with_items(items.iter()))
+            .map_err(|_| polars_err!(ComputeError: "cannot format dates with '{format}'"))?;
+    }
+
+    let mut values = MutableBinaryViewArray::with_capacity(array.len());
+    let mut serializer = new_serializer_with_options(array, 0, usize::MAX, options);
+
+    while let Some(v) = serializer.next() {
+        unsafe { values.push_value(std::str::from_utf8_unchecked(v)) }
+    }
+    Ok(values.into())
+}
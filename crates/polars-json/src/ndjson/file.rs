@@ -104,6 +104,25 @@ pub fn iter_unique_dtypes<R: std::io::BufRead>(
     reader: &mut R,
     number_of_rows: Option<NonZeroUsize>,
 ) -> PolarsResult<impl Iterator<Item = ArrowDataType>> {
+    let mut dtypes = PlIndexSet::default();
+    try_for_each_value(reader, number_of_rows, |value| {
+        dtypes.insert(crate::json::infer(value)?);
+        Ok(())
+    })?;
+    Ok(dtypes.into_iter())
+}
+
+/// Calls `f` on the parsed JSON value of every row of an NDJSON file, optionally only using
+/// `number_of_rows` rows.
+pub fn try_for_each_value<R, F>(
+    reader: &mut R,
+    number_of_rows: Option<NonZeroUsize>,
+    mut f: F,
+) -> PolarsResult<()>
+where
+    R: std::io::BufRead,
+    F: FnMut(&BorrowedValue) -> PolarsResult<()>,
+{
     if reader.fill_buf().map(|b| b.is_empty())? {
         return Err(PolarsError::ComputeError(
             "Cannot infer NDJSON types on empty reader because empty string is not a valid JSON value".into(),
@@ -113,15 +132,13 @@ pub fn iter_unique_dtypes<R: std::io::BufRead>(
     let rows = vec!["".to_string(); 1]; // 1 <=> read row by row
     let mut reader = FileReader::new(reader, rows, number_of_rows.map(|v| v.into()));
 
-    let mut dtypes = PlIndexSet::default();
     let mut buf = vec![];
     while let Some(rows) = reader.next()? {
         // 0 because it is row by row
         let value = parse_value(&mut buf, rows[0].as_bytes())?;
-        let dtype = crate::json::infer(&value)?;
-        dtypes.insert(dtype);
+        f(&value)?;
     }
-    Ok(dtypes.into_iter())
+    Ok(())
 }
 
 /// Infers the [`ArrowDataType`] from an iterator of JSON strings. A limited number of
@@ -4,4 +4,4 @@ use polars_error::*;
 pub mod deserialize;
 mod file;
 pub mod write;
-pub use file::{infer_iter, iter_unique_dtypes};
+pub use file::{infer_iter, iter_unique_dtypes, try_for_each_value};
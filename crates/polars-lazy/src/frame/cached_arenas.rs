@@ -1,8 +1,8 @@
 use super::*;
 
 pub(crate) struct CachedArena {
-    lp_arena: Arena<IR>,
-    expr_arena: Arena<AExpr>,
+    pub(super) lp_arena: Arena<IR>,
+    pub(super) expr_arena: Arena<AExpr>,
 }
 
 impl LazyFrame {
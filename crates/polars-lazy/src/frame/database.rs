@@ -0,0 +1,354 @@
+//! Stream query results into a database table, see [`LazyFrame::sink_database`].
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::prelude::*;
+use crate::scan::database::{quote_column, quote_identifier, write_sql_literal};
+
+/// A connection to the database that a query result is written to, e.g. an ADBC connection.
+///
+/// Only [`execute`](Self::execute) is required. Connections that support bulk ingestion, like
+/// `COPY` or the ingest of ADBC, should implement [`insert`](Self::insert) with it. By default,
+/// rows are inserted with multi-row `INSERT` statements.
+pub trait DatabaseConnection: Send {
+    /// Execute a SQL statement that returns no rows.
+    fn execute(&mut self, sql: &str) -> PolarsResult<()>;
+
+    /// Insert the rows of `df` into the existing `table`, whose columns are named like the
+    /// columns of `df`. `table` is the unquoted, possibly schema qualified, name of the table.
+    fn insert(&mut self, table: &str, df: &DataFrame) -> PolarsResult<()> {
+        let sql = insert_statement(table, df)?;
+        self.execute(&sql)
+    }
+
+    /// The SQL type of the columns of tables that are created for a column of `dtype`.
+    fn sql_type(&self, dtype: &DataType) -> PolarsResult<String> {
+        default_sql_type(dtype)
+    }
+
+    fn begin(&mut self) -> PolarsResult<()> {
+        self.execute("BEGIN")
+    }
+
+    fn commit(&mut self) -> PolarsResult<()> {
+        self.execute("COMMIT")
+    }
+
+    fn rollback(&mut self) -> PolarsResult<()> {
+        self.execute("ROLLBACK")
+    }
+}
+
+/// How [`LazyFrame::sink_database`] writes to the table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DatabaseWriteMode {
+    /// Insert the rows into an existing table.
+    #[default]
+    Append,
+    /// Replace the table by a new table with the columns of the query result.
+    Overwrite,
+    /// Insert the rows into an existing table, rows with the same values of the `keys` columns
+    /// as an existing row update that row. The rows are first inserted into a temporary table,
+    /// which is merged with `INSERT ... ON CONFLICT`, as supported by PostgreSQL, SQLite and
+    /// DuckDB. The key columns need a unique constraint.
+    Upsert { keys: Vec<PlSmallStr> },
+}
+
+#[derive(Clone, Debug)]
+pub struct DatabaseSinkOptions {
+    pub mode: DatabaseWriteMode,
+    /// Number of rows that are inserted at once.
+    pub batch_size: usize,
+    /// SQL types of the columns of created tables, instead of the type of their dtype.
+    pub column_types: PlHashMap<PlSmallStr, String>,
+    /// Write all rows in a single transaction, that is rolled back if the query or a write fails.
+    pub transaction: bool,
+    /// Number of times that a failed insert is retried. Retries need `transaction` to be
+    /// disabled, as a failed statement aborts the transaction in most databases.
+    pub max_retries: usize,
+}
+
+impl Default for DatabaseSinkOptions {
+    fn default() -> Self {
+        Self {
+            mode: DatabaseWriteMode::default(),
+            batch_size: 10_000,
+            column_types: Default::default(),
+            transaction: true,
+            max_retries: 0,
+        }
+    }
+}
+
+fn column_list(names: impl Iterator<Item = PlSmallStr>) -> String {
+    names
+        .map(|name| quote_column(&name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The type mapping of [`DatabaseConnection::sql_type`], with the types of the SQL standard.
+pub fn default_sql_type(dtype: &DataType) -> PolarsResult<String> {
+    let sql_type = match dtype {
+        DataType::Boolean => "BOOLEAN",
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "SMALLINT",
+        DataType::Int32 | DataType::UInt16 => "INTEGER",
+        DataType::Int64 | DataType::UInt32 => "BIGINT",
+        DataType::UInt64 => "NUMERIC(20)",
+        DataType::Float32 => "REAL",
+        DataType::Float64 => "DOUBLE PRECISION",
+        DataType::String => "TEXT",
+        DataType::Binary => "BYTEA",
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => "DATE",
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => "TIME",
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => "TIMESTAMP",
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, Some(_)) => "TIMESTAMP WITH TIME ZONE",
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(precision), scale) => {
+            return Ok(format!("NUMERIC({}, {})", precision, scale.unwrap_or(0)))
+        },
+        dt => polars_bail!(
+            InvalidOperation: "no SQL type for dtype {}, set it in the `column_types` of the sink", dt
+        ),
+    };
+    Ok(sql_type.to_string())
+}
+
+/// A multi-row `INSERT` statement of the rows of `df`, see [`DatabaseConnection::insert`].
+pub fn insert_statement(table: &str, df: &DataFrame) -> PolarsResult<String> {
+    let mut sql = format!(
+        "INSERT INTO {} ({}) VALUES ",
+        quote_identifier(table),
+        column_list(df.get_column_names_owned().into_iter())
+    );
+    for row in 0..df.height() {
+        sql.push_str(if row == 0 { "(" } else { ", (" });
+        for (i, column) in df.get_columns().iter().enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            write_sql_literal(&mut sql, column.get(row)?)?;
+        }
+        sql.push(')');
+    }
+    Ok(sql)
+}
+
+struct DatabaseWriter {
+    conn: Box<dyn DatabaseConnection>,
+    table: String,
+    /// The table that the rows are inserted into, a temporary table for upserts.
+    target: String,
+    schema: SchemaRef,
+    options: DatabaseSinkOptions,
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+    in_transaction: bool,
+}
+
+impl DatabaseWriter {
+    fn new(
+        conn: Box<dyn DatabaseConnection>,
+        table: &str,
+        schema: SchemaRef,
+        options: DatabaseSinkOptions,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.batch_size > 0,
+            InvalidOperation: "the batch size of a database sink must be positive"
+        );
+        polars_ensure!(
+            !options.transaction || options.max_retries == 0,
+            InvalidOperation: "a database sink can't retry inserts within a transaction"
+        );
+        let target = match &options.mode {
+            DatabaseWriteMode::Upsert { keys } => {
+                polars_ensure!(
+                    !keys.is_empty(),
+                    InvalidOperation: "an upsert requires at least one key column"
+                );
+                for key in keys {
+                    schema.try_get(key)?;
+                }
+                let name = table.rsplit('.').next().unwrap();
+                format!("polars_staging_{name}")
+            },
+            _ => table.to_string(),
+        };
+        Ok(Self {
+            conn,
+            table: table.to_string(),
+            target,
+            schema,
+            options,
+            buffer: vec![],
+            buffered_rows: 0,
+            in_transaction: false,
+        })
+    }
+
+    fn create_table(&mut self, table: &str, temporary: bool) -> PolarsResult<()> {
+        let columns = self
+            .schema
+            .iter()
+            .map(|(name, dtype)| {
+                let sql_type = match self.options.column_types.get(name) {
+                    Some(sql_type) => sql_type.clone(),
+                    None => self.conn.sql_type(dtype)?,
+                };
+                Ok(format!("{} {}", quote_column(name), sql_type))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let sql = format!(
+            "CREATE {}TABLE {} ({})",
+            if temporary { "TEMPORARY " } else { "" },
+            quote_identifier(table),
+            columns.join(", ")
+        );
+        self.conn.execute(&sql)
+    }
+
+    fn start(&mut self) -> PolarsResult<()> {
+        if self.options.transaction {
+            self.conn.begin()?;
+            self.in_transaction = true;
+        }
+        match &self.options.mode {
+            DatabaseWriteMode::Append => Ok(()),
+            DatabaseWriteMode::Overwrite => {
+                let sql = format!("DROP TABLE IF EXISTS {}", quote_identifier(&self.table));
+                self.conn.execute(&sql)?;
+                self.create_table(&self.table.clone(), false)
+            },
+            DatabaseWriteMode::Upsert { .. } => self.create_table(&self.target.clone(), true),
+        }
+    }
+
+    fn insert(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut retries = 0;
+        loop {
+            match self.conn.insert(&self.target, df) {
+                Err(_) if retries < self.options.max_retries => retries += 1,
+                out => return out,
+            }
+        }
+    }
+
+    /// Insert the buffered rows in batches, the rows that don't fill a batch stay buffered unless
+    /// `all` is set.
+    fn flush(&mut self, all: bool) -> PolarsResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.buffer));
+        let batch_size = self.options.batch_size;
+        let mut offset = 0;
+        while df.height() - offset >= batch_size {
+            self.insert(&df.slice(offset as i64, batch_size))?;
+            offset += batch_size;
+        }
+        let rest = df.slice(offset as i64, df.height() - offset);
+        self.buffered_rows = rest.height();
+        if all {
+            if rest.height() > 0 {
+                self.insert(&rest)?;
+            }
+            self.buffered_rows = 0;
+        } else if rest.height() > 0 {
+            self.buffer.push(rest);
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, df: DataFrame) -> PolarsResult<()> {
+        self.buffered_rows += df.height();
+        self.buffer.push(df);
+        if self.buffered_rows >= self.options.batch_size {
+            self.flush(false)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        self.flush(true)?;
+        if let DatabaseWriteMode::Upsert { keys } = &self.options.mode {
+            let columns = column_list(self.schema.iter_names().cloned());
+            let updates = self
+                .schema
+                .iter_names()
+                .filter(|name| !keys.contains(name))
+                .map(|name| {
+                    let name = quote_column(name);
+                    format!("{name} = excluded.{name}")
+                })
+                .collect::<Vec<_>>();
+            let action = if updates.is_empty() {
+                "NOTHING".to_string()
+            } else {
+                format!("UPDATE SET {}", updates.join(", "))
+            };
+            // The `WHERE true` disambiguates the conflict clause in SQLite.
+            let sql = format!(
+                "INSERT INTO {} ({columns}) SELECT {columns} FROM {} WHERE true \
+                ON CONFLICT ({}) DO {action}",
+                quote_identifier(&self.table),
+                quote_identifier(&self.target),
+                column_list(keys.iter().cloned()),
+            );
+            self.conn.execute(&sql)?;
+            let sql = format!("DROP TABLE {}", quote_identifier(&self.target));
+            self.conn.execute(&sql)?;
+        }
+        if self.in_transaction {
+            self.in_transaction = false;
+            self.conn.commit()?;
+        }
+        Ok(())
+    }
+
+    fn abort(&mut self) {
+        if self.in_transaction {
+            self.in_transaction = false;
+            // The error of the write is more useful than that of the rollback.
+            let _ = self.conn.rollback();
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Stream a query result into the database `table` through `conn`, in batches of
+    /// `options.batch_size` rows. See [`DatabaseWriteMode`] for how the table is written.
+    ///
+    /// By default all rows are written in one transaction, so a failing query leaves the table
+    /// unchanged. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    pub fn sink_database(
+        self,
+        conn: impl DatabaseConnection + 'static,
+        table: &str,
+        options: DatabaseSinkOptions,
+    ) -> PolarsResult<()> {
+        let schema = self.clone().collect_schema()?;
+        let writer = DatabaseWriter::new(Box::new(conn), table, schema, options)?;
+        let writer = Arc::new(Mutex::new(writer));
+
+        let batch_writer = writer.clone();
+        let started = writer.lock().unwrap().start();
+        let out = started
+            .and_then(|()| self.sink_batches(move |df| batch_writer.lock().unwrap().write(df)));
+        let mut writer = writer.lock().unwrap();
+        match out.and_then(|()| writer.finish()) {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                writer.abort();
+                Err(err)
+            },
+        }
+    }
+}
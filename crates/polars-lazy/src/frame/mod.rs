@@ -3,11 +3,20 @@
 mod python;
 
 mod cached_arenas;
+#[cfg(feature = "streaming")]
+mod database;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod paginate;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "cloud")]
+mod scan_cost;
+mod schema_contract;
+mod schema_origin;
+#[cfg(feature = "substrait")]
+mod substrait;
 
 #[cfg(any(
     feature = "parquet",
@@ -19,8 +28,14 @@ use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "parquet")]
+pub use cast_policy::CastPolicy;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "streaming")]
+pub use database::{
+    default_sql_type, insert_statement, DatabaseConnection, DatabaseSinkOptions, DatabaseWriteMode,
+};
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
@@ -28,6 +43,7 @@ pub use file_list_reader::*;
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+pub use paginate::PageCursor;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_core::prelude::*;
@@ -35,14 +51,23 @@ use polars_expr::{create_physical_expr, ExpressionConversionState};
 use polars_io::RowIndex;
 use polars_mem_engine::{create_physical_plan, Executor};
 use polars_ops::frame::JoinCoalesce;
-pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
+pub use polars_plan::frame::{AllowedOptimizations, NanOrder, NanPolicy, OptFlags};
 use polars_plan::global::FETCH_ROWS;
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub use position::ScanPosition;
+#[cfg(feature = "cloud")]
+pub use scan_cost::ScanCost;
+pub use schema_contract::SchemaContract;
+pub use schema_origin::{ColumnInfo, ColumnOrigin};
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
 use crate::prelude::*;
+use crate::scan::database::database_pushdown;
+pub use crate::scan::database::DatabaseSource;
 
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
@@ -123,14 +148,20 @@ impl LazyFrame {
     }
 
     /// Set allowed optimizations.
+    ///
+    /// This keeps the [`NanPolicy`] of the query, as it changes the result. Set it with
+    /// [`LazyFrame::with_nan_policy`] instead.
     pub fn with_optimizations(mut self, opt_state: OptFlags) -> Self {
-        self.opt_state = opt_state;
+        let nan_policy = self.opt_state & OptFlags::nan_policy_flags();
+        self.opt_state = opt_state.difference(OptFlags::nan_policy_flags()) | nan_policy;
         self
     }
 
     /// Turn off all optimizations.
+    ///
+    /// This keeps the [`NanPolicy`] of the query, as it changes the result.
     pub fn without_optimizations(self) -> Self {
-        self.with_optimizations(OptFlags::from_bits_truncate(0) | OptFlags::TYPE_COERCION)
+        self.with_optimizations(OptFlags::TYPE_COERCION)
     }
 
     /// Toggle projection pushdown optimization.
@@ -183,6 +214,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle the rewrite of the operations on top of database scans into their query.
+    pub fn with_database_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::DATABASE_PUSHDOWN, toggle);
+        self
+    }
+
+    /// Set how this query treats floating point NaN, see [`NanPolicy`].
+    pub fn with_nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.opt_state.set_nan_policy(policy);
+        self
+    }
+
     /// Run nodes that are capably of doing so on the streaming engine.
     pub fn with_streaming(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::STREAMING, toggle);
@@ -254,6 +297,18 @@ impl LazyFrame {
         }
     }
 
+    /// Estimate the IO of the file scans of the optimized query per storage provider, without
+    /// running it.
+    ///
+    /// The metadata of parquet files is fetched to only account for the projected columns and the
+    /// row groups that can't be skipped based on the slice and the statistics. Other formats are
+    /// fetched whole. File caches are assumed to be empty.
+    #[cfg(feature = "cloud")]
+    pub fn estimate_scan_cost(self) -> PolarsResult<Vec<(polars_io::cloud::CloudType, ScanCost)>> {
+        let plan = self.to_alp_optimized()?;
+        scan_cost::estimate_scan_cost(&plan)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -616,6 +671,10 @@ impl LazyFrame {
             }),
         )?;
 
+        if opt_state.contains(OptFlags::DATABASE_PUSHDOWN) {
+            database_pushdown(lp_top, lp_arena, expr_arena);
+        }
+
         if streaming {
             #[cfg(feature = "streaming")]
             {
@@ -774,6 +833,13 @@ impl LazyFrame {
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
+    ///
+    /// If [`ParquetWriteOptions::target_file_size`] or [`ParquetWriteOptions::max_rows_per_file`]
+    /// is set, `path` is a directory and the result is written to numbered files in it, rolling
+    /// over to a new file once the current one is full.
+    ///
+    /// If [`ParquetWriteOptions::partition_by`] is set, `path` is the root of a hive partitioned
+    /// directory tree with one or more files per partition, see [`LazyFrame::sink_partitioned`].
     #[cfg(feature = "parquet")]
     pub fn sink_parquet(
         self,
@@ -813,6 +879,10 @@ impl LazyFrame {
     /// Stream a query result into an ipc/arrow file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
+    ///
+    /// If [`IpcWriterOptions::target_file_size`] or [`IpcWriterOptions::max_rows_per_file`] is
+    /// set, `path` is a directory and the result is written to numbered files in it, rolling over
+    /// to a new file once the current one is full.
     #[cfg(feature = "ipc")]
     pub fn sink_ipc(self, path: impl AsRef<Path>, options: IpcWriterOptions) -> PolarsResult<()> {
         self.sink(
@@ -858,6 +928,10 @@ impl LazyFrame {
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
+    ///
+    /// If [`CsvWriterOptions::target_file_size`] or [`CsvWriterOptions::max_rows_per_file`] is
+    /// set, `path` is a directory and the result is written to numbered files in it, rolling over
+    /// to a new file once the current one is full.
     #[cfg(feature = "csv")]
     pub fn sink_csv(self, path: impl AsRef<Path>, options: CsvWriterOptions) -> PolarsResult<()> {
         self.sink(
@@ -883,12 +957,118 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into a hive partitioned directory tree at `path`, e.g.
+    /// `path/year=2024/month=1/00000000.parquet`. The partition keys can be arbitrary
+    /// expressions, their output names are used as the partition column names. Keys that are
+    /// computed don't replace the columns of the same name and are not written to the files.
+    ///
+    /// At most `max_open_files` files are open at the same time. If a partition needs a file
+    /// beyond that, the least recently written file is finished and later rows of its partition
+    /// go to a new file in the same directory, so high cardinality keys don't exhaust the file
+    /// descriptors. This methods will return an error if the query cannot be completely done in
+    /// a streaming fashion.
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "csv",
+        feature = "json"
+    ))]
+    pub fn sink_partitioned<E: AsRef<[Expr]>>(
+        self,
+        path: impl AsRef<Path>,
+        partition_by: E,
+        file_type: FileType,
+        max_open_files: usize,
+    ) -> PolarsResult<()> {
+        let partition_by = partition_by.as_ref().to_vec();
+        polars_ensure!(
+            !partition_by.is_empty(),
+            InvalidOperation: "sink_partitioned requires at least one partition key"
+        );
+        let key_names = self
+            .clone()
+            .select(&partition_by)
+            .collect_schema()?
+            .iter_names()
+            .cloned()
+            .collect::<Vec<_>>();
+        // Computed keys go in temporary columns, so they don't replace source columns.
+        let mut computed = vec![];
+        let partition_by = partition_by
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| match key {
+                Expr::Column(name) => name,
+                key => {
+                    let name = format_pl_smallstr!("__POLARS_PARTITION_KEY_{i}");
+                    computed.push(key.alias(name.clone()));
+                    name
+                },
+            })
+            .collect();
+        let options = PartitionedSinkOptions {
+            partition_by,
+            key_names,
+            max_open_files,
+        };
+        let lf = if computed.is_empty() {
+            self
+        } else {
+            self.with_columns(computed)
+        };
+        lf.sink(
+            SinkType::Partitioned {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type,
+                options,
+            },
+            "collect()",
+        )
+    }
+
+    /// Stream a query result into multiple sinks in a single pass, e.g. a parquet archive and a
+    /// csv export. The query runs once and every chunk is passed to all sinks. This methods will
+    /// return an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "cloud_write",
+        feature = "csv",
+        feature = "json",
+    ))]
+    pub fn sink_multiple(self, sinks: impl IntoIterator<Item = SinkType>) -> PolarsResult<()> {
+        let sinks = sinks.into_iter().collect::<Vec<_>>();
+        polars_ensure!(
+            !sinks.is_empty(),
+            InvalidOperation: "sink_multiple requires at least one sink"
+        );
+        polars_ensure!(
+            !sinks.iter().any(|sink| matches!(sink, SinkType::Memory)),
+            InvalidOperation: "memory sinks are not supported in sink_multiple"
+        );
+        self.sink(SinkType::Multiple(sinks), "collect()")
+    }
+
+    /// Stream a query result into `callback`, batch by batch and in order, e.g. to forward the
+    /// result over a websocket without collecting it. The query waits for the callback, so a slow
+    /// consumer slows down the query instead of buffering its output. An error returned by the
+    /// callback stops the query. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "streaming")]
+    pub fn sink_batches(
+        self,
+        callback: impl FnMut(DataFrame) -> PolarsResult<()> + Send + 'static,
+    ) -> PolarsResult<()> {
+        self.sink(SinkType::Batches(SinkCallback::new(callback)), "collect()")
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
         feature = "cloud_write",
         feature = "csv",
         feature = "json",
+        feature = "streaming",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
         self.opt_state |= OptFlags::STREAMING;
@@ -0,0 +1,124 @@
+use super::*;
+
+impl LazyFrame {
+    /// Run the query once and return a cursor over its result in pages of `page_size` rows, e.g.
+    /// to serve a result page by page without running the query for every page.
+    ///
+    /// The pages are only stable across runs of the query if the order of its rows is, so this
+    /// returns an error if the query contains a `group_by` or `unique` without `maintain_order`
+    /// that isn't followed by a sort.
+    pub fn paginate(self, page_size: usize) -> PolarsResult<PageCursor> {
+        polars_ensure!(page_size > 0, InvalidOperation: "'page_size' must be at least 1");
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect_post_opt(false, |node, lp_arena, _| {
+                polars_ensure!(
+                    has_stable_order(node, lp_arena),
+                    InvalidOperation: "cannot paginate a query without a stable row order; \
+                                       set `maintain_order` or sort the query"
+                );
+                Ok(())
+            })?;
+        let df = physical_plan.execute(&mut state)?;
+        Ok(PageCursor {
+            df,
+            page_size,
+            offset: 0,
+        })
+    }
+}
+
+/// Whether the order of the rows of `node` is the same for every run of the query.
+fn has_stable_order(node: Node, lp_arena: &Arena<IR>) -> bool {
+    match lp_arena.get(node) {
+        IR::Sort { .. } | IR::Reduce { .. } => true,
+        IR::GroupBy {
+            maintain_order: false,
+            ..
+        } => false,
+        IR::Distinct { options, .. } if !options.maintain_order => false,
+        // The streaming engine replaces the plan, check the original one.
+        IR::MapFunction {
+            function:
+                FunctionIR::Pipeline {
+                    original: Some(original),
+                    ..
+                },
+            ..
+        } => has_stable_order(original.lp_top, &original.lp_arena),
+        ir => {
+            let mut inputs = vec![];
+            ir.copy_inputs(&mut inputs);
+            inputs
+                .into_iter()
+                .all(|input| has_stable_order(input, lp_arena))
+        },
+    }
+}
+
+/// A cursor over the pages of a query result, see [`LazyFrame::paginate`].
+#[derive(Clone)]
+pub struct PageCursor {
+    df: DataFrame,
+    page_size: usize,
+    /// Row offset of the next page.
+    offset: usize,
+}
+
+impl PageCursor {
+    /// The number of rows of a page, except for the last one.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// The number of rows of the result.
+    pub fn height(&self) -> usize {
+        self.df.height()
+    }
+
+    /// The number of pages of the result.
+    pub fn n_pages(&self) -> usize {
+        self.df.height().div_ceil(self.page_size)
+    }
+
+    /// The row offset of the next page.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Move the cursor such that the next page starts at row `offset`. An `offset` equal to the
+    /// number of rows moves the cursor to the end.
+    pub fn seek(&mut self, offset: usize) -> PolarsResult<()> {
+        polars_ensure!(
+            offset <= self.df.height(),
+            OutOfBounds: "cannot seek to row {} of a result of {} rows", offset, self.df.height()
+        );
+        self.offset = offset;
+        Ok(())
+    }
+
+    /// Get the next page and advance the cursor, or `None` if all rows are returned.
+    pub fn next_page(&mut self) -> Option<DataFrame> {
+        if self.offset >= self.df.height() {
+            return None;
+        }
+        let page = self.df.slice(self.offset as i64, self.page_size);
+        self.offset += page.height();
+        Some(page)
+    }
+
+    /// Get page `index` without moving the cursor, or `None` if it is out of bounds.
+    pub fn page(&self, index: usize) -> Option<DataFrame> {
+        (index < self.n_pages()).then(|| {
+            self.df
+                .slice((index * self.page_size) as i64, self.page_size)
+        })
+    }
+}
+
+impl Iterator for PageCursor {
+    type Item = DataFrame;
+
+    fn next(&mut self) -> Option<DataFrame> {
+        self.next_page()
+    }
+}
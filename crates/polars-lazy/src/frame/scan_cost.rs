@@ -0,0 +1,336 @@
+//! Estimates of the IO of the scans of a query, see [`LazyFrame::estimate_scan_cost`].
+use std::ops::AddAssign;
+#[cfg(feature = "parquet")]
+use std::ops::Range;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+use polars_io::cloud::{
+    build_object_store, object_path_from_str, CloudOptions, CloudType, PolarsObjectStore,
+};
+#[cfg(feature = "parquet")]
+use polars_io::parquet::metadata::FileMetaDataRef;
+use polars_io::path_utils::{is_cloud_url, resolve_homedir};
+use polars_io::predicates::PhysicalIoExpr;
+
+use crate::prelude::*;
+
+/// Byte ranges of a file that are at most this far apart are fetched with a single request, like
+/// the object store does.
+#[cfg(feature = "parquet")]
+const COALESCE_DISTANCE: usize = 1024 * 1024;
+
+/// The estimated IO of the scans of a query on one storage provider.
+///
+/// Requests that fail and are retried are not accounted for. For local files, the requests are
+/// the reads of the file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanCost {
+    /// Number of files that are read.
+    pub n_files: u64,
+    /// Bytes that are fetched, including the metadata of the files.
+    pub bytes: u64,
+    /// Number of GET requests, one per fetched byte range.
+    pub get_requests: u64,
+    /// Number of HEAD requests for the sizes of the files.
+    pub head_requests: u64,
+}
+
+impl AddAssign for ScanCost {
+    fn add_assign(&mut self, rhs: Self) {
+        self.n_files += rhs.n_files;
+        self.bytes += rhs.bytes;
+        self.get_requests += rhs.get_requests;
+        self.head_requests += rhs.head_requests;
+    }
+}
+
+/// The arguments of a file scan that determine its IO.
+#[cfg_attr(not(feature = "parquet"), allow(dead_code))]
+struct ScanArgs {
+    paths: Arc<Vec<PathBuf>>,
+    scan_type: FileScan,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    slice: Option<(i64, usize)>,
+}
+
+impl ScanArgs {
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        match &self.scan_type {
+            #[cfg(feature = "csv")]
+            FileScan::Csv { cloud_options, .. } => cloud_options.as_ref(),
+            #[cfg(feature = "parquet")]
+            FileScan::Parquet { cloud_options, .. } => cloud_options.as_ref(),
+            #[cfg(feature = "ipc")]
+            FileScan::Ipc { cloud_options, .. } => cloud_options.as_ref(),
+            #[cfg(feature = "json")]
+            FileScan::NDJson { cloud_options, .. } => cloud_options.as_ref(),
+            FileScan::Anonymous { .. } => None,
+        }
+    }
+}
+
+/// Collect the file scans of the plan, scans below a cache are only executed once.
+fn collect_scans(plan: &IRPlan) -> PolarsResult<Vec<ScanArgs>> {
+    let mut scans = vec![];
+    let mut stack = vec![plan.lp_top];
+    let mut seen_caches = PlHashSet::new();
+    while let Some(node) = stack.pop() {
+        let ir = plan.lp_arena.get(node);
+        match ir {
+            IR::Cache { id, .. } if !seen_caches.insert(*id) => continue,
+            IR::Scan {
+                paths,
+                scan_type,
+                predicate,
+                output_schema,
+                file_options,
+                ..
+            } if !matches!(scan_type, FileScan::Anonymous { .. }) => {
+                let predicate = predicate
+                    .as_ref()
+                    .map(|predicate| {
+                        create_physical_expr(
+                            predicate,
+                            Context::Default,
+                            &plan.expr_arena,
+                            output_schema.as_ref(),
+                            &mut ExpressionConversionState::new(true, 0),
+                        )
+                    })
+                    .transpose()?
+                    .map(phys_expr_to_io_expr);
+                scans.push(ScanArgs {
+                    paths: paths.clone(),
+                    scan_type: scan_type.clone(),
+                    with_columns: file_options.with_columns.clone(),
+                    predicate,
+                    slice: file_options.slice,
+                });
+            },
+            _ => {},
+        }
+        // Visit the inputs in order.
+        let n = stack.len();
+        ir.copy_inputs(&mut stack);
+        stack[n..].reverse();
+    }
+    Ok(scans)
+}
+
+/// Estimate the IO of the file scans of `plan` per storage provider, in the order in which the
+/// providers are first scanned.
+pub(crate) fn estimate_scan_cost(plan: &IRPlan) -> PolarsResult<Vec<(CloudType, ScanCost)>> {
+    let scans = collect_scans(plan)?;
+    polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
+        let mut out: Vec<(CloudType, ScanCost)> = vec![];
+        for scan in &scans {
+            for (cloud_type, cost) in estimate_scan(scan).await? {
+                add_cost(&mut out, cloud_type, cost);
+            }
+        }
+        Ok(out)
+    })
+}
+
+fn add_cost(costs: &mut Vec<(CloudType, ScanCost)>, cloud_type: CloudType, cost: ScanCost) {
+    match costs.iter_mut().find(|(t, _)| *t == cloud_type) {
+        Some((_, total)) => *total += cost,
+        None => costs.push((cloud_type, cost)),
+    }
+}
+
+async fn file_size(path: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<u64> {
+    let (location, store) = build_object_store(path, cloud_options, false).await?;
+    let store = PolarsObjectStore::new(store)
+        .with_cloud_options(cloud_options.map(|options| options.for_url(path)));
+    let meta = store.head(&object_path_from_str(&location.prefix)?).await?;
+    Ok(meta.size as u64)
+}
+
+async fn estimate_scan(scan: &ScanArgs) -> PolarsResult<Vec<(CloudType, ScanCost)>> {
+    let cloud_options = scan.cloud_options();
+    let paths = scan
+        .paths
+        .iter()
+        .map(|path| {
+            if is_cloud_url(path) {
+                return Ok(path.to_string_lossy().into_owned());
+            }
+            // Object store paths must be absolute without `..` segments.
+            let path = std::fs::canonicalize(resolve_homedir(path)).map_err(
+                |err| polars_err!(ComputeError: "failed to resolve {:?}: {}", path, err),
+            )?;
+            Ok(path.to_string_lossy().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let cloud_types = paths
+        .iter()
+        .map(|path| path.parse::<CloudType>())
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let costs = match &scan.scan_type {
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { metadata, .. } => {
+            estimate_parquet(scan, &paths, cloud_options, metadata.clone()).await?
+        },
+        // The other readers fetch the whole file.
+        _ => {
+            futures::future::try_join_all(paths.iter().map(|path| async move {
+                let size = file_size(path, cloud_options).await?;
+                PolarsResult::Ok(ScanCost {
+                    n_files: 1,
+                    bytes: size,
+                    get_requests: 1,
+                    head_requests: 1,
+                })
+            }))
+            .await?
+        },
+    };
+
+    let mut out: Vec<(CloudType, ScanCost)> = vec![];
+    for (cloud_type, cost) in cloud_types.into_iter().zip(costs) {
+        add_cost(&mut out, cloud_type, cost);
+    }
+    Ok(out)
+}
+
+/// Merge the byte ranges that are close to each other, as they are fetched with one request.
+#[cfg(feature = "parquet")]
+fn coalesce_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_unstable_by_key(|range| range.start);
+    let mut out: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match out.last_mut() {
+            Some(last) if range.start <= last.end + COALESCE_DISTANCE => {
+                last.end = last.end.max(range.end)
+            },
+            _ => out.push(range),
+        }
+    }
+    out
+}
+
+/// Account for the metadata of all files and the row groups that are read: those that overlap
+/// with the slice and may match the predicate according to their statistics. Only the column
+/// chunks of the projected columns are fetched.
+#[cfg(feature = "parquet")]
+async fn estimate_parquet(
+    scan: &ScanArgs,
+    paths: &[String],
+    cloud_options: Option<&CloudOptions>,
+    first_metadata: Option<FileMetaDataRef>,
+) -> PolarsResult<Vec<ScanCost>> {
+    use polars_io::parquet::read::ParquetAsyncReader;
+    use polars_io::parquet::read::_internal::{read_this_row_group, PartitionedColumnChunkMD};
+
+    let files = futures::future::try_join_all(paths.iter().enumerate().map(|(i, path)| {
+        let metadata = if i == 0 { first_metadata.clone() } else { None };
+        async move {
+            let cached = metadata.is_some();
+            let size = file_size(path, cloud_options).await?;
+            let mut reader = ParquetAsyncReader::from_uri(path, cloud_options, metadata).await?;
+            let schema = reader.schema().await?;
+            let metadata = reader.get_metadata().await?.clone();
+            PolarsResult::Ok((size, cached, schema, metadata))
+        }
+    }))
+    .await?;
+
+    // Slices with a predicate apply to the filtered rows.
+    let slice = match scan.slice {
+        Some((offset, len)) if offset >= 0 && scan.predicate.is_none() => {
+            Some(offset as usize..(offset as usize).saturating_add(len))
+        },
+        _ => None,
+    };
+    let live_columns = scan
+        .predicate
+        .as_ref()
+        .and_then(|predicate| predicate.live_variables());
+
+    let mut row_offset = 0usize;
+    let mut out = Vec::with_capacity(files.len());
+    for (size, cached, schema, metadata) in files {
+        let mut cost = ScanCost {
+            n_files: 1,
+            ..Default::default()
+        };
+        if !cached {
+            // The size of the footer, then the footer.
+            let data_end = metadata
+                .row_groups
+                .iter()
+                .flat_map(|rg| rg.columns())
+                .map(|column| {
+                    let (offset, len) = column.byte_range();
+                    offset + len
+                })
+                .max()
+                .unwrap_or(4);
+            cost.head_requests += 1;
+            cost.get_requests += 2;
+            cost.bytes += size.saturating_sub(data_end) + 8;
+        }
+
+        for rg in &metadata.row_groups {
+            let rows = row_offset..row_offset + rg.num_rows();
+            row_offset = rows.end;
+            if let Some(slice) = &slice {
+                if rows.end <= slice.start || rows.start >= slice.end {
+                    continue;
+                }
+            }
+            if let Some(predicate) = scan.predicate.as_deref() {
+                let mut part_md = PartitionedColumnChunkMD::new(rg);
+                part_md.set_partitions(
+                    live_columns
+                        .as_ref()
+                        .map(|columns| columns.iter().map(|c| c.as_str()).collect::<PlHashSet<_>>())
+                        .as_ref(),
+                );
+                if !matches!(
+                    read_this_row_group(Some(predicate), &part_md, &schema),
+                    Ok(true)
+                ) {
+                    continue;
+                }
+            }
+
+            let ranges = rg
+                .columns()
+                .iter()
+                .filter(|column| match &scan.with_columns {
+                    Some(columns) => {
+                        let name = &column.descriptor().path_in_schema[0];
+                        columns.iter().any(|c| c == name)
+                    },
+                    None => true,
+                })
+                .map(|column| {
+                    let (offset, len) = column.byte_range();
+                    offset as usize..(offset + len) as usize
+                })
+                .collect::<Vec<_>>();
+            let ranges = match scan.with_columns {
+                Some(_) => coalesce_ranges(ranges),
+                // The whole row group is fetched at once.
+                None => ranges
+                    .iter()
+                    .map(|range| range.start)
+                    .min()
+                    .zip(ranges.iter().map(|range| range.end).max())
+                    .map(|(start, end)| start..end)
+                    .into_iter()
+                    .collect(),
+            };
+            cost.get_requests += ranges.len() as u64;
+            cost.bytes += ranges.iter().map(|range| range.len() as u64).sum::<u64>();
+        }
+        out.push(cost);
+    }
+    Ok(out)
+}
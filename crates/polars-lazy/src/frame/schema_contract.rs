@@ -0,0 +1,110 @@
+use std::fmt::Write;
+
+use super::*;
+
+/// The schema that the result of a query must have, see [`LazyFrame::with_schema_contract`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaContract {
+    schema: SchemaRef,
+    coerce_order: bool,
+    coerce_dtypes: bool,
+}
+
+impl SchemaContract {
+    pub fn new(schema: SchemaRef) -> Self {
+        Self {
+            schema,
+            coerce_order: false,
+            coerce_dtypes: false,
+        }
+    }
+
+    /// Reorder the columns to the order of the schema instead of returning an error if it
+    /// differs.
+    pub fn with_coerce_order(mut self, coerce_order: bool) -> Self {
+        self.coerce_order = coerce_order;
+        self
+    }
+
+    /// Cast the columns to the data types of the schema instead of returning an error if they
+    /// differ. Values that can't be cast return an error when the query runs.
+    pub fn with_coerce_dtypes(mut self, coerce_dtypes: bool) -> Self {
+        self.coerce_dtypes = coerce_dtypes;
+        self
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the differences between `schema` and the contract that are not coerced, one per
+    /// line.
+    fn diff(&self, schema: &Schema) -> String {
+        let mut diff = String::new();
+        for (name, expected) in self.schema.iter() {
+            match schema.get(name) {
+                None => writeln!(diff, "- missing column '{name}': {expected}"),
+                Some(dtype) if dtype != expected && !self.coerce_dtypes => {
+                    writeln!(diff, "- column '{name}' is {dtype}, expected {expected}")
+                },
+                _ => Ok(()),
+            }
+            .unwrap();
+        }
+        for (name, dtype) in schema.iter() {
+            if !self.schema.contains(name) {
+                writeln!(diff, "- unexpected column '{name}': {dtype}").unwrap();
+            }
+        }
+        // The order is only comparable if the columns are the same.
+        if diff.is_empty()
+            && !self.coerce_order
+            && !schema.iter_names().eq(self.schema.iter_names())
+        {
+            writeln!(
+                diff,
+                "- columns are in order {:?}, expected {:?}",
+                schema.iter_names().collect::<Vec<_>>(),
+                self.schema.iter_names().collect::<Vec<_>>()
+            )
+            .unwrap();
+        }
+        diff
+    }
+}
+
+impl LazyFrame {
+    /// Check that the result of the query has the schema of `contract`, e.g. before one of the
+    /// `sink_*` methods, so that a change upstream can't silently change the schema of a written
+    /// dataset. The check runs on the plan, so nothing is written if it fails.
+    ///
+    /// Returns a [`PolarsError::SchemaMismatch`] listing the missing, unexpected and mistyped
+    /// columns and the column order, unless the contract coerces them. Missing and unexpected
+    /// columns are never coerced.
+    pub fn with_schema_contract(mut self, contract: &SchemaContract) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        let diff = contract.diff(&schema);
+        polars_ensure!(
+            diff.is_empty(),
+            SchemaMismatch: "the result of the query doesn't match the schema contract:\n{}",
+            diff.trim_end()
+        );
+
+        if schema.as_ref() == contract.schema.as_ref() {
+            return Ok(self);
+        }
+        let exprs = contract
+            .schema
+            .iter()
+            .map(|(name, dtype)| {
+                let expr = col(name.clone());
+                if schema.get(name) == Some(dtype) {
+                    expr
+                } else {
+                    expr.strict_cast(dtype.clone())
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(self.select(exprs))
+    }
+}
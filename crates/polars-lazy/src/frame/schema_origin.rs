@@ -0,0 +1,211 @@
+use std::ops::ControlFlow::{self, Break, Continue};
+
+use super::*;
+
+/// Where a column of a query comes from, see [`LazyFrame::collect_schema_verbose`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnOrigin {
+    /// The column is read by a scan. `source` describes the scan, e.g. `csv` and its first path,
+    /// and `name` is the name of the column in the source.
+    Source { source: String, name: PlSmallStr },
+    /// The column is computed by the expression `expr` of a node, e.g. a `projection` or an
+    /// `aggregate`.
+    Expr { node: &'static str, expr: String },
+    /// The column is created by a node that doesn't evaluate expressions, e.g. a map function or
+    /// a row index.
+    Node { node: String },
+}
+
+/// A column of the schema of a query and its [`ColumnOrigin`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnInfo {
+    pub name: PlSmallStr,
+    pub dtype: DataType,
+    pub origin: ColumnOrigin,
+}
+
+impl LazyFrame {
+    /// Get the schema of the query like [`LazyFrame::collect_schema`], with the origin of every
+    /// column. Renames and columns that are selected unchanged are followed back to the node
+    /// that created the column.
+    pub fn collect_schema_verbose(&mut self) -> PolarsResult<Vec<ColumnInfo>> {
+        let schema = self.collect_schema()?;
+        let DslPlan::IR {
+            node: Some(root), ..
+        } = &self.logical_plan
+        else {
+            unreachable!("the plan is converted by collect_schema")
+        };
+        let cached_arenas = self.cached_arena.lock().unwrap();
+        let arenas = cached_arenas
+            .as_ref()
+            .expect("the arenas are cached by collect_schema");
+
+        schema
+            .iter()
+            .map(|(name, dtype)| {
+                let origin =
+                    column_origin(*root, name.clone(), &arenas.lp_arena, &arenas.expr_arena)?;
+                Ok(ColumnInfo {
+                    name: name.clone(),
+                    dtype: dtype.clone(),
+                    origin,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Follow the column `name` of the output of `node` back to where it is created.
+fn column_origin(
+    mut node: Node,
+    mut name: PlSmallStr,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<ColumnOrigin> {
+    let input_contains =
+        |input: Node, name: &str| lp_arena.get(input).schema(lp_arena).contains(name);
+    let node_origin = |node: &str| -> ControlFlow<ColumnOrigin, (Node, PlSmallStr)> {
+        Break(ColumnOrigin::Node {
+            node: node.to_string(),
+        })
+    };
+
+    loop {
+        let ir = lp_arena.get(node);
+        // Either continue with a column of an input, or stop at the origin.
+        let next = match ir {
+            IR::Scan {
+                paths,
+                file_options,
+                ..
+            } => {
+                if file_options
+                    .row_index
+                    .as_ref()
+                    .is_some_and(|ri| ri.name == name)
+                {
+                    node_origin("row_index")
+                } else if file_options.include_file_paths.as_ref() == Some(&name) {
+                    node_origin("file_path")
+                } else {
+                    let source = match paths.first() {
+                        Some(path) => format!("{} {}", ir.name(), path.display()),
+                        None => ir.name().to_string(),
+                    };
+                    Break(ColumnOrigin::Source {
+                        source,
+                        name: name.clone(),
+                    })
+                }
+            },
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => Break(ColumnOrigin::Source {
+                source: ir.name().to_string(),
+                name: name.clone(),
+            }),
+            IR::DataFrameScan { .. } => Break(ColumnOrigin::Source {
+                source: ir.name().to_string(),
+                name: name.clone(),
+            }),
+            IR::Slice { input, .. }
+            | IR::Filter { input, .. }
+            | IR::Sort { input, .. }
+            | IR::Cache { input, .. }
+            | IR::Distinct { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::ExtContext { input, .. }
+            | IR::Sink { input, .. } => Continue((*input, name.clone())),
+            IR::Select { input, expr, .. } => exprs_origin(ir, *input, expr, &name, expr_arena),
+            IR::Reduce { input, exprs, .. } | IR::HStack { input, exprs, .. } => {
+                exprs_origin(ir, *input, exprs, &name, expr_arena)
+            },
+            IR::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                ..
+            } => {
+                if apply.is_some() {
+                    node_origin(ir.name())
+                } else if keys.iter().any(|e| e.output_name() == &name) {
+                    exprs_origin(ir, *input, keys, &name, expr_arena)
+                } else {
+                    exprs_origin(ir, *input, aggs, &name, expr_arena)
+                }
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                options,
+                ..
+            } => {
+                if input_contains(*input_left, &name) {
+                    Continue((*input_left, name.clone()))
+                } else if input_contains(*input_right, &name) {
+                    Continue((*input_right, name.clone()))
+                } else {
+                    match name.strip_suffix(options.args.suffix().as_str()) {
+                        Some(stripped) if input_contains(*input_right, stripped) => {
+                            Continue((*input_right, stripped.into()))
+                        },
+                        _ => node_origin(ir.name()),
+                    }
+                }
+            },
+            IR::MapFunction { input, function } => match function {
+                FunctionIR::Rename { existing, new, .. } => {
+                    match new.iter().position(|new| new == &name) {
+                        Some(i) => Continue((*input, existing[i].clone())),
+                        None => Continue((*input, name.clone())),
+                    }
+                },
+                FunctionIR::RowIndex { name: ri_name, .. } if ri_name == &name => {
+                    node_origin("row_index")
+                },
+                _ if input_contains(*input, &name) => Continue((*input, name.clone())),
+                _ => node_origin(&function.to_string()),
+            },
+            IR::Union { inputs, .. } => Continue((inputs[0], name.clone())),
+            IR::HConcat { inputs, .. } => {
+                match inputs.iter().find(|input| input_contains(**input, &name)) {
+                    Some(input) => Continue((*input, name.clone())),
+                    None => node_origin(ir.name()),
+                }
+            },
+            IR::Invalid => polars_bail!(
+                ComputeError: "cannot resolve the origin of column '{}' in an invalid plan", name
+            ),
+        };
+
+        match next {
+            Continue((input, input_name)) => {
+                node = input;
+                name = input_name;
+            },
+            Break(origin) => return Ok(origin),
+        }
+    }
+}
+
+/// The origin of the column `name` in the output of a node that evaluates `exprs` on `input`.
+/// Columns that are selected unchanged (possibly renamed) are followed into the input.
+fn exprs_origin(
+    ir: &IR,
+    input: Node,
+    exprs: &[ExprIR],
+    name: &PlSmallStr,
+    expr_arena: &Arena<AExpr>,
+) -> ControlFlow<ColumnOrigin, (Node, PlSmallStr)> {
+    match exprs.iter().find(|e| e.output_name() == name) {
+        Some(e) => match expr_arena.get(e.node()) {
+            AExpr::Column(input_name) => Continue((input, input_name.clone())),
+            _ => Break(ColumnOrigin::Expr {
+                node: ir.name(),
+                expr: e.display(expr_arena).to_string(),
+            }),
+        },
+        None => Continue((input, name.clone())),
+    }
+}
@@ -0,0 +1,1496 @@
+//! Conversion between Polars query plans and [Substrait](https://substrait.io) plans.
+//!
+//! Plans are exchanged in the canonical protobuf JSON encoding of the Substrait `Plan` message,
+//! which every Substrait implementation can read and write. The supported subset covers file and
+//! named table reads, filters, projections, equi-joins, aggregations, sorts, slices and unions.
+use std::path::PathBuf;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_io::path_utils::is_cloud_url;
+use polars_ops::frame::JoinCoalesce;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_utils::format_pl_smallstr;
+use serde_json::{json, Map, Value};
+
+use crate::prelude::*;
+
+const SUBSTRAIT_MINOR_VERSION: u32 = 54;
+
+const COMPARISON_URI: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_comparison.yaml";
+const BOOLEAN_URI: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_boolean.yaml";
+const ARITHMETIC_URI: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_arithmetic.yaml";
+const AGGREGATE_GENERIC_URI: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_aggregate_generic.yaml";
+
+/// Keys that may accompany the `oneof` member of a Substrait message.
+const NON_VARIANT_KEYS: [&str; 3] = ["common", "nullable", "typeVariationReference"];
+
+impl LazyFrame {
+    /// Export the optimized query plan as a Substrait plan in its protobuf JSON encoding.
+    ///
+    /// Scans are exported as reads of local files (Parquet and IPC), so in-memory DataFrames and
+    /// other sources cannot be exported. Unsigned integers have no Substrait equivalent and are
+    /// rejected, with the exception of counts, which Substrait defines as `i64`.
+    pub fn to_substrait_json(self) -> PolarsResult<String> {
+        let plan = self.to_alp_optimized()?;
+        let producer = Producer {
+            lp_arena: &plan.lp_arena,
+            expr_arena: &plan.expr_arena,
+            uris: vec![],
+            functions: vec![],
+        };
+        let plan = producer.plan(plan.lp_top)?;
+        serde_json::to_string(&plan).map_err(to_compute_err)
+    }
+
+    /// Create a [`LazyFrame`] from a Substrait plan in its protobuf JSON encoding.
+    ///
+    /// Named table reads are resolved by joining the table names with `.` and looking them up in
+    /// `tables`; local file reads are scanned directly. The output columns are named after the
+    /// names of the plan root.
+    pub fn from_substrait_json(
+        json: &str,
+        tables: &PlHashMap<String, LazyFrame>,
+    ) -> PolarsResult<LazyFrame> {
+        let plan: Value = serde_json::from_str(json).map_err(to_compute_err)?;
+        let mut consumer = Consumer {
+            tables,
+            functions: PlHashMap::new(),
+            counter: 0,
+        };
+        consumer.plan(&plan)
+    }
+}
+
+struct Producer<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+    uris: Vec<&'static str>,
+    /// Declared functions as `(uri index, compound name)`, anchored by position.
+    functions: Vec<(usize, String)>,
+}
+
+impl Producer<'_> {
+    fn plan(mut self, root: Node) -> PolarsResult<Value> {
+        let input = self.rel(root)?;
+        let names = self
+            .schema(root)
+            .iter_names()
+            .map(|name| name.as_str().to_string())
+            .collect::<Vec<_>>();
+
+        let extension_uris = self
+            .uris
+            .iter()
+            .enumerate()
+            .map(|(i, uri)| json!({"extensionUriAnchor": i + 1, "uri": uri}))
+            .collect::<Vec<_>>();
+        let extensions = self
+            .functions
+            .iter()
+            .enumerate()
+            .map(|(i, (uri, name))| {
+                json!({"extensionFunction": {
+                    "extensionUriReference": uri + 1,
+                    "functionAnchor": i + 1,
+                    "name": name,
+                }})
+            })
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "version": {"minorNumber": SUBSTRAIT_MINOR_VERSION, "producer": "polars"},
+            "extensionUris": extension_uris,
+            "extensions": extensions,
+            "relations": [{"root": {"input": input, "names": names}}],
+        }))
+    }
+
+    fn schema(&self, node: Node) -> SchemaRef {
+        self.lp_arena.get(node).schema(self.lp_arena).into_owned()
+    }
+
+    fn dtype(&self, node: Node, schema: &Schema) -> PolarsResult<DataType> {
+        let dtype =
+            self.expr_arena
+                .get(node)
+                .to_dtype(schema, Context::Default, self.expr_arena)?;
+        Ok(match dtype {
+            DataType::Unknown(UnknownKind::Int(v)) if i32::try_from(v).is_ok() => DataType::Int32,
+            DataType::Unknown(UnknownKind::Int(_)) => DataType::Int64,
+            DataType::Unknown(UnknownKind::Float) => DataType::Float64,
+            DataType::Unknown(UnknownKind::Str) => DataType::String,
+            dtype => dtype,
+        })
+    }
+
+    fn function(&mut self, uri: &'static str, name: String) -> usize {
+        let uri = match self.uris.iter().position(|u| *u == uri) {
+            Some(i) => i,
+            None => {
+                self.uris.push(uri);
+                self.uris.len() - 1
+            },
+        };
+        let function = (uri, name);
+        match self.functions.iter().position(|f| *f == function) {
+            Some(i) => i + 1,
+            None => {
+                self.functions.push(function);
+                self.functions.len()
+            },
+        }
+    }
+
+    fn scalar_function(
+        &mut self,
+        uri: &'static str,
+        name: String,
+        arguments: Vec<Value>,
+        output: &DataType,
+    ) -> PolarsResult<Value> {
+        let anchor = self.function(uri, name);
+        let arguments = arguments
+            .into_iter()
+            .map(|value| json!({"value": value}))
+            .collect::<Vec<_>>();
+        Ok(json!({"scalarFunction": {
+            "functionReference": anchor,
+            "arguments": arguments,
+            "outputType": type_to_json(output)?,
+        }}))
+    }
+
+    fn unsupported(&self, node: Node) -> PolarsError {
+        polars_err!(
+            InvalidOperation: "expression '{}' cannot be exported to Substrait",
+            node_to_expr(node, self.expr_arena)
+        )
+    }
+
+    fn rel(&mut self, node: Node) -> PolarsResult<Value> {
+        use IR::*;
+        match self.lp_arena.get(node) {
+            Scan {
+                paths,
+                file_info,
+                predicate,
+                output_schema,
+                scan_type,
+                file_options,
+                ..
+            } => {
+                polars_ensure!(
+                    file_options.row_index.is_none() && file_options.include_file_paths.is_none(),
+                    InvalidOperation: "scans with a row index or file path column cannot be exported to Substrait"
+                );
+                polars_ensure!(
+                    predicate.is_none() || file_options.slice.is_none(),
+                    InvalidOperation: "scans with both a predicate and a slice cannot be exported to Substrait"
+                );
+                let format = match scan_type {
+                    FileScan::Parquet { .. } => "parquet",
+                    FileScan::Ipc { .. } => "arrow",
+                    _ => polars_bail!(
+                        InvalidOperation: "'{}' scans cannot be exported to Substrait",
+                        self.lp_arena.get(node).name()
+                    ),
+                };
+                let items = paths
+                    .iter()
+                    .map(|path| {
+                        let mut item = Map::new();
+                        item.insert("uriFile".into(), path_to_uri(path)?.into());
+                        item.insert(format.into(), json!({}));
+                        Ok(Value::Object(item))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                let schema = &file_info.schema;
+                let mut read = json!({
+                    "baseSchema": named_struct(schema)?,
+                    "localFiles": {"items": items},
+                });
+                if let Some(predicate) = predicate {
+                    read["filter"] = self.expr(predicate.node(), schema, 0)?;
+                }
+                if let Some(output_schema) = output_schema {
+                    let items = output_schema
+                        .iter_names()
+                        .map(|name| Ok(json!({"field": index_of(schema, name)?})))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    read["projection"] = json!({
+                        "select": {"structItems": items},
+                        "maintainSingularStruct": true,
+                    });
+                }
+                with_slice(json!({ "read": read }), file_options.slice)
+            },
+            DataFrameScan { .. } => polars_bail!(
+                InvalidOperation: "in-memory DataFrames cannot be exported to Substrait; scan them from a file instead"
+            ),
+            Filter { input, predicate } => {
+                let schema = self.schema(*input);
+                let condition = self.expr(predicate.node(), &schema, 0)?;
+                let input = self.rel(*input)?;
+                Ok(json!({"filter": {"input": input, "condition": condition}}))
+            },
+            SimpleProjection { input, columns } => {
+                let schema = self.schema(*input);
+                let mapping = columns
+                    .iter_names()
+                    .map(|name| index_of(&schema, name))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let input = self.rel(*input)?;
+                Ok(json!({"project": {
+                    "common": emit(&mapping),
+                    "input": input,
+                    "expressions": [],
+                }}))
+            },
+            Select {
+                input,
+                expr: exprs,
+                schema: output_schema,
+                ..
+            }
+            | Reduce {
+                input,
+                exprs,
+                schema: output_schema,
+            } => {
+                if exprs
+                    .iter()
+                    .all(|e| matches!(self.expr_arena.get(e.node()), AExpr::Agg(_) | AExpr::Len))
+                {
+                    self.aggregate(*input, &[], exprs, output_schema)
+                } else {
+                    self.project(*input, exprs, output_schema)
+                }
+            },
+            HStack {
+                input,
+                exprs,
+                schema: output_schema,
+                ..
+            } => self.project(*input, exprs, output_schema),
+            GroupBy {
+                input,
+                keys,
+                aggs,
+                schema: output_schema,
+                apply,
+                options,
+                ..
+            } => {
+                polars_ensure!(
+                    apply.is_none(),
+                    InvalidOperation: "group_by with a custom function cannot be exported to Substrait"
+                );
+                #[cfg(feature = "dynamic_group_by")]
+                polars_ensure!(
+                    options.dynamic.is_none() && options.rolling.is_none(),
+                    InvalidOperation: "dynamic and rolling group_by cannot be exported to Substrait"
+                );
+                let aggregate = self.aggregate(*input, keys, aggs, output_schema)?;
+                with_slice(aggregate, options.slice)
+            },
+            Join {
+                input_left,
+                input_right,
+                schema: output_schema,
+                left_on,
+                right_on,
+                options,
+            } => self.join(
+                *input_left,
+                *input_right,
+                output_schema,
+                left_on,
+                right_on,
+                &options.args,
+            ),
+            Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => {
+                let schema = self.schema(*input);
+                let flag = |flags: &[bool], i: usize| flags.get(i).or(flags.first()) == Some(&true);
+                let sorts = by_column
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| {
+                        let direction = match (
+                            flag(&sort_options.descending, i),
+                            flag(&sort_options.nulls_last, i),
+                        ) {
+                            (false, false) => "SORT_DIRECTION_ASC_NULLS_FIRST",
+                            (false, true) => "SORT_DIRECTION_ASC_NULLS_LAST",
+                            (true, false) => "SORT_DIRECTION_DESC_NULLS_FIRST",
+                            (true, true) => "SORT_DIRECTION_DESC_NULLS_LAST",
+                        };
+                        Ok(json!({"expr": self.expr(e.node(), &schema, 0)?, "direction": direction}))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let input = self.rel(*input)?;
+                with_slice(json!({"sort": {"input": input, "sorts": sorts}}), *slice)
+            },
+            Slice { input, offset, len } => {
+                let input = self.rel(*input)?;
+                fetch(input, *offset, *len as usize)
+            },
+            Union { inputs, options } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|input| self.rel(*input))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                with_slice(
+                    json!({"set": {"inputs": inputs, "op": "SET_OP_UNION_ALL"}}),
+                    options.slice,
+                )
+            },
+            Cache { input, .. } => self.rel(*input),
+            lp => polars_bail!(
+                InvalidOperation: "'{}' cannot be exported to Substrait", lp.name()
+            ),
+        }
+    }
+
+    fn project(
+        &mut self,
+        input: Node,
+        exprs: &[ExprIR],
+        output_schema: &Schema,
+    ) -> PolarsResult<Value> {
+        let schema = self.schema(input);
+        let expressions = exprs
+            .iter()
+            .map(|e| self.expr(e.node(), &schema, 0))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // A project relation outputs its input followed by the expressions.
+        let mapping = output_schema
+            .iter_names()
+            .map(
+                |name| match exprs.iter().position(|e| e.output_name() == name) {
+                    Some(i) => Ok(schema.len() + i),
+                    None => index_of(&schema, name),
+                },
+            )
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let input = self.rel(input)?;
+        Ok(json!({"project": {
+            "common": emit(&mapping),
+            "input": input,
+            "expressions": expressions,
+        }}))
+    }
+
+    fn aggregate(
+        &mut self,
+        input: Node,
+        keys: &[ExprIR],
+        aggs: &[ExprIR],
+        output_schema: &Schema,
+    ) -> PolarsResult<Value> {
+        let schema = self.schema(input);
+        let grouping_expressions = keys
+            .iter()
+            .map(|e| self.expr(e.node(), &schema, 0))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let measures = aggs
+            .iter()
+            .map(|e| {
+                let dtype = output_schema.try_get(e.output_name())?;
+                self.measure(e.node(), &schema, dtype)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let groupings = if keys.is_empty() {
+            vec![]
+        } else {
+            vec![json!({ "groupingExpressions": grouping_expressions })]
+        };
+        let input = self.rel(input)?;
+        Ok(json!({"aggregate": {
+            "input": input,
+            "groupings": groupings,
+            "measures": measures,
+        }}))
+    }
+
+    fn measure(&mut self, node: Node, schema: &Schema, dtype: &DataType) -> PolarsResult<Value> {
+        let (name, input) = match self.expr_arena.get(node) {
+            AExpr::Agg(IRAggExpr::Sum(input)) => ("sum", Some(*input)),
+            AExpr::Agg(IRAggExpr::Min { input, .. }) => ("min", Some(*input)),
+            AExpr::Agg(IRAggExpr::Max { input, .. }) => ("max", Some(*input)),
+            AExpr::Agg(IRAggExpr::Mean(input)) => ("avg", Some(*input)),
+            AExpr::Agg(IRAggExpr::Count(input, false)) => ("count", Some(*input)),
+            AExpr::Agg(IRAggExpr::Count(_, true)) | AExpr::Len => ("count", None),
+            _ => return Err(self.unsupported(node)),
+        };
+        let (uri, output_type) = if name == "count" {
+            (AGGREGATE_GENERIC_URI, type_to_json(&DataType::Int64)?)
+        } else {
+            (ARITHMETIC_URI, type_to_json(dtype)?)
+        };
+        let (signature, arguments) = match input {
+            Some(input) => {
+                let signature = if name == "count" {
+                    "any"
+                } else {
+                    short_name(&self.dtype(input, schema)?)?
+                };
+                let argument = self.expr(input, schema, 0)?;
+                (signature, vec![json!({ "value": argument })])
+            },
+            None => ("", vec![]),
+        };
+        let anchor = self.function(uri, format!("{name}:{signature}"));
+        Ok(json!({"measure": {
+            "functionReference": anchor,
+            "arguments": arguments,
+            "outputType": output_type,
+            "phase": "AGGREGATION_PHASE_INITIAL_TO_RESULT",
+            "invocation": "AGGREGATION_INVOCATION_ALL",
+        }}))
+    }
+
+    fn join(
+        &mut self,
+        input_left: Node,
+        input_right: Node,
+        output_schema: &Schema,
+        left_on: &[ExprIR],
+        right_on: &[ExprIR],
+        args: &JoinArgs,
+    ) -> PolarsResult<Value> {
+        let left_schema = self.schema(input_left);
+        let right_schema = self.schema(input_right);
+        let (join_type, outputs_right) = match args.how {
+            JoinType::Inner => ("JOIN_TYPE_INNER", true),
+            JoinType::Left => ("JOIN_TYPE_LEFT", true),
+            JoinType::Full if !args.should_coalesce() => ("JOIN_TYPE_OUTER", true),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi => ("JOIN_TYPE_LEFT_SEMI", false),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Anti => ("JOIN_TYPE_LEFT_ANTI", false),
+            ref how => polars_bail!(
+                InvalidOperation: "{} joins cannot be exported to Substrait", how
+            ),
+        };
+        polars_ensure!(
+            !left_on.is_empty(),
+            InvalidOperation: "joins without keys cannot be exported to Substrait"
+        );
+
+        let function = if args.join_nulls {
+            "is_not_distinct_from:any_any"
+        } else {
+            "equal:any_any"
+        };
+        let mut conditions = left_on
+            .iter()
+            .zip(right_on)
+            .map(|(left, right)| {
+                // The join condition refers to the left fields followed by the right fields.
+                let left = self.expr(left.node(), &left_schema, 0)?;
+                let right = self.expr(right.node(), &right_schema, left_schema.len())?;
+                self.scalar_function(
+                    COMPARISON_URI,
+                    function.into(),
+                    vec![left, right],
+                    &DataType::Boolean,
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let expression = if conditions.len() == 1 {
+            conditions.pop().unwrap()
+        } else {
+            self.scalar_function(
+                BOOLEAN_URI,
+                "and:bool".into(),
+                conditions,
+                &DataType::Boolean,
+            )?
+        };
+
+        let mut join = json!({
+            "left": self.rel(input_left)?,
+            "right": self.rel(input_right)?,
+            "expression": expression,
+            "type": join_type,
+        });
+        if outputs_right {
+            let coalesced = if args.should_coalesce() {
+                right_on
+                    .iter()
+                    .map(|e| match self.expr_arena.get(e.node()) {
+                        AExpr::Column(name) => Ok(name.clone()),
+                        _ => Err(self.unsupported(e.node())),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?
+            } else {
+                vec![]
+            };
+            let mapping = (0..left_schema.len())
+                .chain(
+                    right_schema
+                        .iter_names()
+                        .enumerate()
+                        .filter(|(_, name)| !coalesced.contains(name))
+                        .map(|(i, _)| left_schema.len() + i),
+                )
+                .collect::<Vec<_>>();
+            polars_ensure!(
+                mapping.len() == output_schema.len(),
+                ComputeError: "could not map the join output to Substrait"
+            );
+            join["common"] = emit(&mapping);
+        }
+        with_slice(json!({ "join": join }), args.slice)
+    }
+
+    fn expr(&mut self, node: Node, schema: &Schema, offset: usize) -> PolarsResult<Value> {
+        Ok(match self.expr_arena.get(node) {
+            AExpr::Alias(input, _) => return self.expr(*input, schema, offset),
+            AExpr::Column(name) => field_reference(index_of(schema, name)? + offset),
+            AExpr::Literal(value) => json!({ "literal": literal_to_json(value)? }),
+            AExpr::BinaryExpr { left, op, right } => {
+                let (uri, name) = match op {
+                    Operator::Eq => (COMPARISON_URI, "equal"),
+                    Operator::NotEq => (COMPARISON_URI, "not_equal"),
+                    Operator::EqValidity => (COMPARISON_URI, "is_not_distinct_from"),
+                    Operator::NotEqValidity => (COMPARISON_URI, "is_distinct_from"),
+                    Operator::Lt => (COMPARISON_URI, "lt"),
+                    Operator::LtEq => (COMPARISON_URI, "lte"),
+                    Operator::Gt => (COMPARISON_URI, "gt"),
+                    Operator::GtEq => (COMPARISON_URI, "gte"),
+                    Operator::Plus => (ARITHMETIC_URI, "add"),
+                    Operator::Minus => (ARITHMETIC_URI, "subtract"),
+                    Operator::Multiply => (ARITHMETIC_URI, "multiply"),
+                    Operator::TrueDivide => (ARITHMETIC_URI, "divide"),
+                    Operator::And | Operator::LogicalAnd => (BOOLEAN_URI, "and"),
+                    Operator::Or | Operator::LogicalOr => (BOOLEAN_URI, "or"),
+                    Operator::Xor => (BOOLEAN_URI, "xor"),
+                    _ => return Err(self.unsupported(node)),
+                };
+                let mut left_dtype = self.dtype(*left, schema)?;
+                let mut right_dtype = self.dtype(*right, schema)?;
+                let mut left_value = self.expr(*left, schema, offset)?;
+                let mut right_value = self.expr(*right, schema, offset)?;
+                let signature = match uri {
+                    COMPARISON_URI => "any_any".to_string(),
+                    BOOLEAN_URI => {
+                        if left_dtype != DataType::Boolean || right_dtype != DataType::Boolean {
+                            return Err(self.unsupported(node));
+                        }
+                        if name == "xor" { "bool_bool" } else { "bool" }.to_string()
+                    },
+                    _ => {
+                        // Substrait divides integers with truncation, Polars always yields floats.
+                        if *op == Operator::TrueDivide {
+                            for (dtype, value) in [
+                                (&mut left_dtype, &mut left_value),
+                                (&mut right_dtype, &mut right_value),
+                            ] {
+                                if !dtype.is_float() {
+                                    *value = cast(value.take(), &DataType::Float64, true)?;
+                                    *dtype = DataType::Float64;
+                                }
+                            }
+                        }
+                        format!("{}_{}", short_name(&left_dtype)?, short_name(&right_dtype)?)
+                    },
+                };
+                let output = self.dtype(node, schema)?;
+                self.scalar_function(
+                    uri,
+                    format!("{name}:{signature}"),
+                    vec![left_value, right_value],
+                    &output,
+                )?
+            },
+            AExpr::Cast {
+                expr,
+                dtype,
+                options,
+            } => match self.expr_arena.get(*expr) {
+                AExpr::Literal(LiteralValue::Null) => {
+                    json!({"literal": {"null": type_to_json(dtype)?}})
+                },
+                _ => {
+                    let input = self.expr(*expr, schema, offset)?;
+                    cast(input, dtype, options.strict())?
+                },
+            },
+            AExpr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => json!({"ifThen": {
+                "ifs": [{
+                    "if": self.expr(*predicate, schema, offset)?,
+                    "then": self.expr(*truthy, schema, offset)?,
+                }],
+                "else": self.expr(*falsy, schema, offset)?,
+            }}),
+            AExpr::Function {
+                input,
+                function: FunctionExpr::Boolean(function),
+                ..
+            } if input.len() == 1 => {
+                let (uri, name) = match function {
+                    BooleanFunction::Not => (BOOLEAN_URI, "not:bool"),
+                    BooleanFunction::IsNull => (COMPARISON_URI, "is_null:any"),
+                    BooleanFunction::IsNotNull => (COMPARISON_URI, "is_not_null:any"),
+                    _ => return Err(self.unsupported(node)),
+                };
+                let input = self.expr(input[0].node(), schema, offset)?;
+                self.scalar_function(uri, name.into(), vec![input], &DataType::Boolean)?
+            },
+            _ => return Err(self.unsupported(node)),
+        })
+    }
+}
+
+fn index_of(schema: &Schema, name: &str) -> PolarsResult<usize> {
+    schema
+        .index_of(name)
+        .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))
+}
+
+fn path_to_uri(path: &std::path::Path) -> PolarsResult<String> {
+    if is_cloud_url(path) {
+        return Ok(path.to_string_lossy().into_owned());
+    }
+    let path = std::path::absolute(path)?;
+    Ok(format!("file://{}", path.to_string_lossy()))
+}
+
+fn emit(mapping: &[usize]) -> Value {
+    json!({"emit": {"outputMapping": mapping}})
+}
+
+fn fetch(input: Value, offset: i64, len: usize) -> PolarsResult<Value> {
+    polars_ensure!(
+        offset >= 0,
+        InvalidOperation: "slices with a negative offset cannot be exported to Substrait"
+    );
+    let count = len.min(i64::MAX as usize);
+    Ok(json!({"fetch": {
+        "input": input,
+        "offset": offset.to_string(),
+        "count": count.to_string(),
+    }}))
+}
+
+fn with_slice(rel: Value, slice: Option<(i64, usize)>) -> PolarsResult<Value> {
+    match slice {
+        Some((offset, len)) => fetch(rel, offset, len),
+        None => Ok(rel),
+    }
+}
+
+fn field_reference(field: usize) -> Value {
+    json!({"selection": {
+        "directReference": {"structField": {"field": field}},
+        "rootReference": {},
+    }})
+}
+
+fn cast(input: Value, dtype: &DataType, strict: bool) -> PolarsResult<Value> {
+    let failure_behavior = if strict {
+        "FAILURE_BEHAVIOR_THROW_EXCEPTION"
+    } else {
+        "FAILURE_BEHAVIOR_RETURN_NULL"
+    };
+    Ok(json!({"cast": {
+        "type": type_to_json(dtype)?,
+        "input": input,
+        "failureBehavior": failure_behavior,
+    }}))
+}
+
+fn named_struct(schema: &Schema) -> PolarsResult<Value> {
+    let names = schema
+        .iter_names()
+        .map(|name| name.as_str())
+        .collect::<Vec<_>>();
+    let types = schema
+        .iter_values()
+        .map(type_to_json)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(json!({
+        "names": names,
+        "struct": {"types": types, "nullability": "NULLABILITY_REQUIRED"},
+    }))
+}
+
+fn unsupported_dtype(dtype: &DataType) -> PolarsError {
+    polars_err!(InvalidOperation: "dtype {} cannot be exported to Substrait", dtype)
+}
+
+fn precision(time_unit: TimeUnit) -> u32 {
+    match time_unit {
+        TimeUnit::Milliseconds => 3,
+        TimeUnit::Microseconds => 6,
+        TimeUnit::Nanoseconds => 9,
+    }
+}
+
+fn time_unit(precision: u64) -> PolarsResult<TimeUnit> {
+    Ok(match precision {
+        0..=3 => TimeUnit::Milliseconds,
+        4..=6 => TimeUnit::Microseconds,
+        7..=9 => TimeUnit::Nanoseconds,
+        _ => polars_bail!(ComputeError: "unsupported Substrait timestamp precision {}", precision),
+    })
+}
+
+fn type_to_json(dtype: &DataType) -> PolarsResult<Value> {
+    let nullability = "NULLABILITY_NULLABLE";
+    let kind = match dtype {
+        DataType::Boolean => "bool",
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => "i8",
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => "i16",
+        DataType::Int32 => "i32",
+        DataType::Int64 => "i64",
+        DataType::Float32 => "fp32",
+        DataType::Float64 => "fp64",
+        DataType::String => "string",
+        DataType::Binary => "binary",
+        DataType::Date => "date",
+        DataType::Datetime(tu, tz) => {
+            let kind = if tz.is_some() {
+                "precisionTimestampTz"
+            } else {
+                "precisionTimestamp"
+            };
+            let mut value = Map::new();
+            value.insert(
+                kind.into(),
+                json!({"precision": precision(*tu), "nullability": nullability}),
+            );
+            return Ok(Value::Object(value));
+        },
+        dtype => return Err(unsupported_dtype(dtype)),
+    };
+    let mut value = Map::new();
+    value.insert(kind.into(), json!({ "nullability": nullability }));
+    Ok(Value::Object(value))
+}
+
+/// The short name of a type as used in the compound names of Substrait function signatures.
+fn short_name(dtype: &DataType) -> PolarsResult<&'static str> {
+    Ok(match dtype {
+        DataType::Boolean => "bool",
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => "i8",
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => "i16",
+        DataType::Int32 => "i32",
+        DataType::Int64 => "i64",
+        DataType::Float32 => "fp32",
+        DataType::Float64 => "fp64",
+        DataType::String => "str",
+        DataType::Binary => "vbin",
+        DataType::Date => "date",
+        DataType::Datetime(_, None) => "pts",
+        DataType::Datetime(_, Some(_)) => "ptstz",
+        dtype => return Err(unsupported_dtype(dtype)),
+    })
+}
+
+fn float_to_json(v: f64) -> Value {
+    match serde_json::Number::from_f64(v) {
+        Some(v) => Value::Number(v),
+        None if v.is_nan() => "NaN".into(),
+        None if v > 0.0 => "Infinity".into(),
+        None => "-Infinity".into(),
+    }
+}
+
+fn literal_to_json(value: &LiteralValue) -> PolarsResult<Value> {
+    Ok(match value {
+        LiteralValue::Boolean(v) => json!({ "boolean": v }),
+        #[cfg(feature = "dtype-i8")]
+        LiteralValue::Int8(v) => json!({ "i8": v }),
+        #[cfg(feature = "dtype-i16")]
+        LiteralValue::Int16(v) => json!({ "i16": v }),
+        LiteralValue::Int32(v) => json!({ "i32": v }),
+        LiteralValue::Int64(v) => json!({"i64": v.to_string()}),
+        LiteralValue::Int(v) => match (i32::try_from(*v), i64::try_from(*v)) {
+            (Ok(v), _) => json!({ "i32": v }),
+            (_, Ok(v)) => json!({"i64": v.to_string()}),
+            _ => {
+                polars_bail!(InvalidOperation: "integer literal {} cannot be exported to Substrait", v)
+            },
+        },
+        LiteralValue::Float32(v) => json!({"fp32": float_to_json(*v as f64)}),
+        LiteralValue::Float64(v) | LiteralValue::Float(v) => json!({"fp64": float_to_json(*v)}),
+        LiteralValue::String(v) | LiteralValue::StrCat(v) => json!({"string": v.as_str()}),
+        LiteralValue::Date(v) => json!({ "date": v }),
+        LiteralValue::DateTime(v, tu, tz) => {
+            let kind = if tz.is_some() {
+                "precisionTimestampTz"
+            } else {
+                "precisionTimestamp"
+            };
+            let mut value = Map::new();
+            value.insert(
+                kind.into(),
+                json!({"precision": precision(*tu), "value": v.to_string()}),
+            );
+            Value::Object(value)
+        },
+        LiteralValue::Null => polars_bail!(
+            InvalidOperation: "untyped null literals cannot be exported to Substrait; cast them to a concrete type"
+        ),
+        value => polars_bail!(
+            InvalidOperation: "literal {:?} cannot be exported to Substrait", value
+        ),
+    })
+}
+
+struct Consumer<'a> {
+    tables: &'a PlHashMap<String, LazyFrame>,
+    /// Declared function names by anchor, without their signature.
+    functions: PlHashMap<u64, String>,
+    counter: usize,
+}
+
+/// Get the member of a Substrait `oneof` message.
+fn variant<'a>(value: &'a Value, what: &str) -> PolarsResult<(&'a str, &'a Value)> {
+    value
+        .as_object()
+        .and_then(|object| {
+            object
+                .iter()
+                .find(|(key, _)| !NON_VARIANT_KEYS.contains(&key.as_str()))
+        })
+        .map(|(key, value)| (key.as_str(), value))
+        .ok_or_else(|| polars_err!(ComputeError: "invalid Substrait {}: {}", what, value))
+}
+
+fn get<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a Value> {
+    value.get(key).ok_or_else(
+        || polars_err!(ComputeError: "missing '{}' in Substrait message {}", key, value),
+    )
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a [Value]> {
+    match value.get(key) {
+        Some(Value::Array(values)) => Ok(values),
+        None => Ok(&[]),
+        Some(v) => polars_bail!(ComputeError: "expected an array for '{}', got {}", key, v),
+    }
+}
+
+/// Read an integer, which the protobuf JSON encoding writes as a string for 64-bit fields.
+/// Absent fields hold their default of zero.
+fn int(value: &Value, key: &str) -> PolarsResult<i64> {
+    match value.get(key) {
+        None => Some(0),
+        Some(Value::Number(v)) => v.as_i64(),
+        Some(Value::String(v)) => v.parse().ok(),
+        Some(_) => None,
+    }
+    .ok_or_else(|| polars_err!(ComputeError: "invalid integer for '{}' in {}", key, value))
+}
+
+fn float(value: &Value) -> PolarsResult<f64> {
+    match value {
+        Value::Number(v) => v.as_f64(),
+        Value::String(v) => match v.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            v => v.parse().ok(),
+        },
+        _ => None,
+    }
+    .ok_or_else(|| polars_err!(ComputeError: "invalid Substrait float literal {}", value))
+}
+
+/// The input field of a direct field reference, if `value` is one.
+fn field_index(value: &Value) -> Option<usize> {
+    let field = value.pointer("/selection/directReference/structField")?;
+    Some(int(field, "field").ok()? as usize)
+}
+
+fn dtype_from_json(value: &Value) -> PolarsResult<DataType> {
+    let (kind, body) = variant(value, "type")?;
+    Ok(match kind {
+        "bool" => DataType::Boolean,
+        #[cfg(feature = "dtype-i8")]
+        "i8" => DataType::Int8,
+        #[cfg(feature = "dtype-i16")]
+        "i16" => DataType::Int16,
+        "i32" => DataType::Int32,
+        "i64" => DataType::Int64,
+        "fp32" => DataType::Float32,
+        "fp64" => DataType::Float64,
+        "string" | "varchar" | "fixedChar" => DataType::String,
+        "binary" | "fixedBinary" => DataType::Binary,
+        "date" => DataType::Date,
+        "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+        "precisionTimestamp" => {
+            DataType::Datetime(time_unit(int(body, "precision")? as u64)?, None)
+        },
+        "precisionTimestampTz" => DataType::Datetime(
+            time_unit(int(body, "precision")? as u64)?,
+            Some("UTC".into()),
+        ),
+        kind => polars_bail!(ComputeError: "unsupported Substrait type '{}'", kind),
+    })
+}
+
+fn literal_from_json(value: &Value) -> PolarsResult<Expr> {
+    let (kind, body) = variant(value, "literal")?;
+    let invalid = || polars_err!(ComputeError: "invalid Substrait literal {}", value);
+    let integer = || match body {
+        Value::Number(v) => v.as_i64(),
+        Value::String(v) => v.parse().ok(),
+        _ => None,
+    };
+    Ok(match kind {
+        "boolean" => lit(body.as_bool().ok_or_else(invalid)?),
+        #[cfg(feature = "dtype-i8")]
+        "i8" => lit(integer()
+            .and_then(|v| i8::try_from(v).ok())
+            .ok_or_else(invalid)?),
+        #[cfg(feature = "dtype-i16")]
+        "i16" => lit(integer()
+            .and_then(|v| i16::try_from(v).ok())
+            .ok_or_else(invalid)?),
+        "i32" => lit(integer()
+            .and_then(|v| i32::try_from(v).ok())
+            .ok_or_else(invalid)?),
+        "i64" => lit(integer().ok_or_else(invalid)?),
+        "fp32" => lit(float(body)? as f32),
+        "fp64" => lit(float(body)?),
+        "string" | "varChar" | "fixedChar" => {
+            let v = match body {
+                Value::Object(_) => get(body, "value")?,
+                body => body,
+            };
+            lit(v.as_str().ok_or_else(invalid)?)
+        },
+        "date" => Expr::Literal(LiteralValue::Date(
+            integer()
+                .and_then(|v| i32::try_from(v).ok())
+                .ok_or_else(invalid)?,
+        )),
+        "precisionTimestamp" | "precisionTimestampTz" => Expr::Literal(LiteralValue::DateTime(
+            int(body, "value")?,
+            time_unit(int(body, "precision")? as u64)?,
+            (kind == "precisionTimestampTz").then(|| "UTC".into()),
+        )),
+        "null" => lit(NULL).cast(dtype_from_json(body)?),
+        kind => polars_bail!(ComputeError: "unsupported Substrait literal '{}'", kind),
+    })
+}
+
+impl Consumer<'_> {
+    fn fresh_name(&mut self) -> PlSmallStr {
+        self.counter += 1;
+        format_pl_smallstr!("__substrait_{}", self.counter)
+    }
+
+    fn plan(&mut self, plan: &Value) -> PolarsResult<LazyFrame> {
+        for extension in array(plan, "extensions")? {
+            if let Some(function) = extension.get("extensionFunction") {
+                let name = get(function, "name")?.as_str().unwrap_or_default();
+                let name = name.split(':').next().unwrap_or_default();
+                self.functions
+                    .insert(int(function, "functionAnchor")? as u64, name.to_string());
+            }
+        }
+
+        let relations = array(plan, "relations")?;
+        let relation = relations
+            .iter()
+            .find(|relation| relation.get("root").is_some())
+            .or(relations.last())
+            .ok_or_else(|| polars_err!(ComputeError: "Substrait plan has no relations"))?;
+        let (rel, root_names) = match relation.get("root") {
+            Some(root) => (get(root, "input")?, array(root, "names")?),
+            None => (get(relation, "rel")?, &[] as &[Value]),
+        };
+
+        let (lf, names) = self.rel(rel)?;
+        if root_names.is_empty() {
+            return Ok(lf);
+        }
+        polars_ensure!(
+            root_names.len() == names.len(),
+            ComputeError: "Substrait plan names {} columns, but its relation outputs {}",
+            root_names.len(), names.len()
+        );
+        let exprs = names
+            .iter()
+            .zip(root_names)
+            .map(|(name, root_name)| {
+                let root_name = root_name.as_str().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Substrait column name {}", root_name),
+                )?;
+                Ok(col(name.clone()).alias(root_name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(lf.select(exprs))
+    }
+
+    fn rel(&mut self, rel: &Value) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let (kind, body) = variant(rel, "relation")?;
+        let (lf, names) = match kind {
+            "read" => self.read(body)?,
+            "filter" => {
+                let (lf, names) = self.rel(get(body, "input")?)?;
+                let condition = self.expr(get(body, "condition")?, &names)?;
+                (lf.filter(condition), names)
+            },
+            "project" => {
+                let (lf, mut names) = self.rel(get(body, "input")?)?;
+                let mut exprs = vec![];
+                for expression in array(body, "expressions")? {
+                    let name = self.fresh_name();
+                    exprs.push(self.expr(expression, &names)?.alias(name.clone()));
+                    names.push(name);
+                }
+                (lf.with_columns(exprs), names)
+            },
+            "join" => self.join(body)?,
+            "aggregate" => self.aggregate(body)?,
+            "sort" => {
+                let (lf, names) = self.rel(get(body, "input")?)?;
+                let mut exprs = vec![];
+                let mut descending = vec![];
+                let mut nulls_last = vec![];
+                for sort in array(body, "sorts")? {
+                    exprs.push(self.expr(get(sort, "expr")?, &names)?);
+                    let (desc, last) = match sort.get("direction").and_then(|d| d.as_str()) {
+                        Some("SORT_DIRECTION_ASC_NULLS_FIRST") => (false, false),
+                        Some("SORT_DIRECTION_ASC_NULLS_LAST") => (false, true),
+                        Some("SORT_DIRECTION_DESC_NULLS_FIRST") => (true, false),
+                        Some("SORT_DIRECTION_DESC_NULLS_LAST") => (true, true),
+                        _ => polars_bail!(ComputeError: "unsupported Substrait sort {}", sort),
+                    };
+                    descending.push(desc);
+                    nulls_last.push(last);
+                }
+                let options = SortMultipleOptions::default()
+                    .with_order_descending_multi(descending)
+                    .with_nulls_last_multi(nulls_last);
+                (lf.sort_by_exprs(exprs, options), names)
+            },
+            "fetch" => {
+                let (lf, names) = self.rel(get(body, "input")?)?;
+                let count = match body.get("count") {
+                    Some(_) => match int(body, "count")? {
+                        count if count < 0 => IdxSize::MAX,
+                        count => IdxSize::try_from(count).unwrap_or(IdxSize::MAX),
+                    },
+                    None => IdxSize::MAX,
+                };
+                (lf.slice(int(body, "offset")?, count), names)
+            },
+            "set" => {
+                polars_ensure!(
+                    body.get("op").and_then(|op| op.as_str()) == Some("SET_OP_UNION_ALL"),
+                    ComputeError: "unsupported Substrait set operation {}", body.get("op").unwrap_or(&Value::Null)
+                );
+                let mut inputs = vec![];
+                let mut output_names = None;
+                for input in array(body, "inputs")? {
+                    let (lf, names) = self.rel(input)?;
+                    // Set operations are positional, Polars unions match columns by name.
+                    let output_names: &Vec<PlSmallStr> = output_names.get_or_insert(names.clone());
+                    polars_ensure!(
+                        names.len() == output_names.len(),
+                        ComputeError: "Substrait set inputs have differing numbers of columns"
+                    );
+                    let exprs = names
+                        .iter()
+                        .zip(output_names)
+                        .map(|(name, output_name)| col(name.clone()).alias(output_name.clone()))
+                        .collect::<Vec<_>>();
+                    inputs.push(lf.select(exprs));
+                }
+                let names = output_names
+                    .ok_or_else(|| polars_err!(ComputeError: "Substrait set has no inputs"))?;
+                (concat(inputs, UnionArgs::default())?, names)
+            },
+            kind => polars_bail!(ComputeError: "unsupported Substrait relation '{}'", kind),
+        };
+
+        let Some(mapping) = body.pointer("/common/emit/outputMapping") else {
+            return Ok((lf, names));
+        };
+        let mut exprs = vec![];
+        let mut output_names: Vec<PlSmallStr> = vec![];
+        for index in mapping.as_array().map(Vec::as_slice).unwrap_or_default() {
+            let name = index
+                .as_u64()
+                .and_then(|i| names.get(i as usize))
+                .ok_or_else(
+                    || polars_err!(ComputeError: "invalid Substrait output mapping {}", mapping),
+                )?;
+            let output_name = if output_names.contains(name) {
+                self.fresh_name()
+            } else {
+                name.clone()
+            };
+            exprs.push(col(name.clone()).alias(output_name.clone()));
+            output_names.push(output_name);
+        }
+        Ok((lf.select(exprs), output_names))
+    }
+
+    fn read(&mut self, body: &Value) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let names = array(get(body, "baseSchema")?, "names")?
+            .iter()
+            .map(|name| {
+                name.as_str().map(PlSmallStr::from_str).ok_or_else(
+                    || polars_err!(ComputeError: "invalid Substrait column name {}", name),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let lf = if let Some(table) = body.get("namedTable") {
+            let name = array(table, "names")?
+                .iter()
+                .filter_map(|name| name.as_str())
+                .collect::<Vec<_>>()
+                .join(".");
+            self.tables
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| polars_err!(ComputeError: "unknown Substrait table '{}'", name))?
+        } else if let Some(files) = body.get("localFiles") {
+            scan_local_files(files)?
+        } else {
+            polars_bail!(ComputeError: "unsupported Substrait read {}", body)
+        };
+        let mut lf = lf.select(
+            names
+                .iter()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>(),
+        );
+
+        if let Some(filter) = body.get("filter") {
+            lf = lf.filter(self.expr(filter, &names)?);
+        }
+        match body.pointer("/projection/select/structItems") {
+            Some(items) => {
+                let names = items
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|item| {
+                        names.get(int(item, "field")? as usize).cloned().ok_or_else(
+                            || polars_err!(ComputeError: "invalid Substrait projection {}", item),
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let exprs = names
+                    .iter()
+                    .map(|name| col(name.clone()))
+                    .collect::<Vec<_>>();
+                Ok((lf.select(exprs), names))
+            },
+            None => Ok((lf, names)),
+        }
+    }
+
+    fn join(&mut self, body: &Value) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let (left, left_names) = self.rel(get(body, "left")?)?;
+        let (right, right_names) = self.rel(get(body, "right")?)?;
+
+        // Rename colliding right columns up front so the output stays positional.
+        let mut renamed = vec![];
+        let mut names = left_names.clone();
+        for name in &right_names {
+            let output_name = if names.contains(name) {
+                self.fresh_name()
+            } else {
+                name.clone()
+            };
+            renamed.push(col(name.clone()).alias(output_name.clone()));
+            names.push(output_name);
+        }
+        let right = right.select(renamed);
+
+        let how = match body.get("type").and_then(|t| t.as_str()) {
+            Some("JOIN_TYPE_INNER") => JoinType::Inner,
+            Some("JOIN_TYPE_LEFT") => JoinType::Left,
+            Some("JOIN_TYPE_OUTER") => JoinType::Full,
+            #[cfg(feature = "semi_anti_join")]
+            Some("JOIN_TYPE_LEFT_SEMI" | "JOIN_TYPE_SEMI") => JoinType::Semi,
+            #[cfg(feature = "semi_anti_join")]
+            Some("JOIN_TYPE_LEFT_ANTI" | "JOIN_TYPE_ANTI") => JoinType::Anti,
+            t => polars_bail!(
+                ComputeError: "unsupported Substrait join type {}", t.unwrap_or("<none>")
+            ),
+        };
+
+        let mut conditions = vec![get(body, "expression")?];
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        let mut join_nulls = None;
+        while let Some(condition) = conditions.pop() {
+            let invalid =
+                || polars_err!(ComputeError: "unsupported Substrait join condition {}", condition);
+            let function = condition.get("scalarFunction").ok_or_else(invalid)?;
+            let arguments = array(function, "arguments")?;
+            let name = self.function_name(function)?;
+            if name == "and" {
+                conditions.extend(arguments.iter().rev().filter_map(|a| a.get("value")));
+                continue;
+            }
+            let nulls_equal = match name {
+                "equal" => false,
+                "is_not_distinct_from" => true,
+                _ => return Err(invalid()),
+            };
+            polars_ensure!(
+                *join_nulls.get_or_insert(nulls_equal) == nulls_equal,
+                ComputeError: "Substrait join conditions must compare nulls consistently"
+            );
+            let [lhs, rhs] = arguments else {
+                return Err(invalid());
+            };
+            let (lhs, rhs) = (get(lhs, "value")?, get(rhs, "value")?);
+            let (lhs, rhs) = match (
+                side(lhs, left_names.len()).ok_or_else(invalid)?,
+                side(rhs, left_names.len()).ok_or_else(invalid)?,
+            ) {
+                (false, true) => (lhs, rhs),
+                (true, false) => (rhs, lhs),
+                _ => return Err(invalid()),
+            };
+            left_on.push(self.expr(lhs, &names)?);
+            right_on.push(self.expr(rhs, &names)?);
+        }
+
+        let mut args = JoinArgs::new(how).with_coalesce(JoinCoalesce::KeepColumns);
+        args.join_nulls = join_nulls.unwrap_or(false);
+        let names = if matches!(args.how, JoinType::Inner | JoinType::Left | JoinType::Full) {
+            names
+        } else {
+            left_names
+        };
+        Ok((left.join(right, left_on, right_on, args), names))
+    }
+
+    fn aggregate(&mut self, body: &Value) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let (lf, names) = self.rel(get(body, "input")?)?;
+        let groupings = array(body, "groupings")?;
+        polars_ensure!(
+            groupings.len() <= 1,
+            ComputeError: "Substrait aggregates with multiple grouping sets are not supported"
+        );
+
+        let mut output_names = vec![];
+        let mut keys = vec![];
+        if let Some(grouping) = groupings.first() {
+            let mut grouping_expressions = array(grouping, "groupingExpressions")?
+                .iter()
+                .collect::<Vec<_>>();
+            let shared = array(body, "groupingExpressions")?;
+            for reference in array(grouping, "expressionReferences")? {
+                let reference = reference
+                    .as_u64()
+                    .and_then(|i| shared.get(i as usize))
+                    .ok_or_else(|| polars_err!(ComputeError: "invalid Substrait grouping reference {}", reference))?;
+                grouping_expressions.push(reference);
+            }
+            for expression in grouping_expressions {
+                let name = match field_index(expression).and_then(|i| names.get(i)) {
+                    Some(name) if !output_names.contains(name) => name.clone(),
+                    _ => self.fresh_name(),
+                };
+                keys.push(self.expr(expression, &names)?.alias(name.clone()));
+                output_names.push(name);
+            }
+        }
+
+        let mut aggs = vec![];
+        for measure in array(body, "measures")? {
+            let measure = get(measure, "measure")?;
+            let arguments = array(measure, "arguments")?
+                .iter()
+                .map(|argument| self.expr(get(argument, "value")?, &names))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = self.function_name(measure)?;
+            let agg = match (name, arguments.as_slice()) {
+                ("sum", [input]) => input.clone().sum(),
+                ("min", [input]) => input.clone().min(),
+                ("max", [input]) => input.clone().max(),
+                ("avg", [input]) => input.clone().mean(),
+                ("count", [input]) => input.clone().count().cast(DataType::Int64),
+                ("count", []) => len().cast(DataType::Int64),
+                _ => polars_bail!(ComputeError: "unsupported Substrait aggregate {}", measure),
+            };
+            let output_name = self.fresh_name();
+            aggs.push(agg.alias(output_name.clone()));
+            output_names.push(output_name);
+        }
+
+        let lf = if keys.is_empty() {
+            lf.select(aggs)
+        } else {
+            lf.group_by(keys).agg(aggs)
+        };
+        Ok((lf, output_names))
+    }
+
+    fn function_name(&self, function: &Value) -> PolarsResult<&str> {
+        let anchor = int(function, "functionReference")? as u64;
+        self.functions
+            .get(&anchor)
+            .map(String::as_str)
+            .ok_or_else(|| polars_err!(ComputeError: "undeclared Substrait function {}", anchor))
+    }
+
+    fn expr(&mut self, value: &Value, names: &[PlSmallStr]) -> PolarsResult<Expr> {
+        let (kind, body) = variant(value, "expression")?;
+        Ok(match kind {
+            "selection" => {
+                let name = field_index(value).and_then(|i| names.get(i)).ok_or_else(
+                    || polars_err!(ComputeError: "unsupported Substrait field reference {}", body),
+                )?;
+                col(name.clone())
+            },
+            "literal" => literal_from_json(body)?,
+            "cast" => {
+                let dtype = dtype_from_json(get(body, "type")?)?;
+                let input = self.expr(get(body, "input")?, names)?;
+                match body.get("failureBehavior").and_then(|b| b.as_str()) {
+                    Some("FAILURE_BEHAVIOR_RETURN_NULL") => input.cast(dtype),
+                    _ => input.strict_cast(dtype),
+                }
+            },
+            "ifThen" => {
+                let mut expr = match body.get("else") {
+                    Some(otherwise) => self.expr(otherwise, names)?,
+                    None => lit(NULL),
+                };
+                for clause in array(body, "ifs")?.iter().rev() {
+                    let predicate = self.expr(get(clause, "if")?, names)?;
+                    let then = self.expr(get(clause, "then")?, names)?;
+                    expr = when(predicate).then(then).otherwise(expr);
+                }
+                expr
+            },
+            "scalarFunction" => {
+                let mut arguments = array(body, "arguments")?
+                    .iter()
+                    .map(|argument| self.expr(get(argument, "value")?, names))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let output = body.get("outputType").map(dtype_from_json).transpose()?;
+                let name = self.function_name(body)?;
+                let op = match name {
+                    "equal" => Operator::Eq,
+                    "not_equal" => Operator::NotEq,
+                    "is_not_distinct_from" => Operator::EqValidity,
+                    "is_distinct_from" => Operator::NotEqValidity,
+                    "lt" => Operator::Lt,
+                    "lte" => Operator::LtEq,
+                    "gt" => Operator::Gt,
+                    "gte" => Operator::GtEq,
+                    "add" => Operator::Plus,
+                    "subtract" => Operator::Minus,
+                    "multiply" => Operator::Multiply,
+                    "divide" => Operator::TrueDivide,
+                    "xor" => Operator::Xor,
+                    "and" | "or" if !arguments.is_empty() => {
+                        let reduce = if name == "and" { Expr::and } else { Expr::or };
+                        return Ok(arguments.into_iter().reduce(reduce).unwrap());
+                    },
+                    "not" | "is_null" | "is_not_null" if arguments.len() == 1 => {
+                        let input = arguments.pop().unwrap();
+                        return Ok(match name {
+                            "not" => input.not(),
+                            "is_null" => input.is_null(),
+                            _ => input.is_not_null(),
+                        });
+                    },
+                    _ => polars_bail!(ComputeError: "unsupported Substrait function '{}'", name),
+                };
+                let [left, right] = <[Expr; 2]>::try_from(arguments).map_err(
+                    |_| polars_err!(ComputeError: "Substrait function '{}' takes two arguments", name),
+                )?;
+                let expr = binary_expr(left, op, right);
+                match output {
+                    // Substrait truncates integer divisions.
+                    Some(dtype) if op == Operator::TrueDivide && dtype.is_integer() => {
+                        expr.strict_cast(dtype)
+                    },
+                    _ => expr,
+                }
+            },
+            kind => polars_bail!(ComputeError: "unsupported Substrait expression '{}'", kind),
+        })
+    }
+}
+
+/// Whether every field referenced by `value` is on the right side of a join.
+fn side(value: &Value, n_left: usize) -> Option<bool> {
+    fn collect(value: &Value, fields: &mut Vec<usize>) {
+        if let Some(field) = field_index(value) {
+            fields.push(field);
+        }
+        match value {
+            Value::Object(object) => object.values().for_each(|v| collect(v, fields)),
+            Value::Array(values) => values.iter().for_each(|v| collect(v, fields)),
+            _ => {},
+        }
+    }
+    let mut fields = vec![];
+    collect(value, &mut fields);
+    let right = fields.iter().filter(|field| **field >= n_left).count();
+    match right {
+        0 if !fields.is_empty() => Some(false),
+        right if right == fields.len() && right > 0 => Some(true),
+        _ => None,
+    }
+}
+
+fn scan_local_files(files: &Value) -> PolarsResult<LazyFrame> {
+    let mut paths = vec![];
+    let mut format = None;
+    for item in array(files, "items")? {
+        let uri = ["uriFile", "uriPath", "uriPathGlob"]
+            .iter()
+            .find_map(|key| item.get(*key).and_then(|uri| uri.as_str()))
+            .ok_or_else(|| polars_err!(ComputeError: "unsupported Substrait file {}", item))?;
+        paths.push(PathBuf::from(uri.strip_prefix("file://").unwrap_or(uri)));
+        let item_format = ["parquet", "arrow"]
+            .into_iter()
+            .find(|format| item.get(*format).is_some())
+            .ok_or_else(
+                || polars_err!(ComputeError: "unsupported Substrait file format {}", item),
+            )?;
+        polars_ensure!(
+            *format.get_or_insert(item_format) == item_format,
+            ComputeError: "Substrait reads of mixed file formats are not supported"
+        );
+    }
+    let paths = Arc::new(paths);
+    match format {
+        Some("parquet") => LazyFrame::scan_parquet_files(paths, Default::default()),
+        Some("arrow") => LazyFrame::scan_ipc_files(paths, Default::default()),
+        _ => polars_bail!(
+            ComputeError: "unsupported Substrait file format {}", format.unwrap_or("<none>")
+        ),
+    }
+}
@@ -69,6 +69,19 @@ fn process_non_streamable_node(
     state.streamable = false;
 }
 
+/// `group_by_dynamic` and `rolling` maintain the order of the input.
+#[allow(unused_variables)]
+fn is_temporal_group_by(options: &GroupbyOptions) -> bool {
+    #[cfg(feature = "dynamic_group_by")]
+    {
+        options.dynamic.is_some() || options.rolling.is_some()
+    }
+    #[cfg(not(feature = "dynamic_group_by"))]
+    {
+        false
+    }
+}
+
 fn insert_file_sink(mut root: Node, lp_arena: &mut Arena<IR>) -> Node {
     // The pipelines need a final sink, we insert that here.
     // this allows us to split at joins/unions and share a sink
@@ -334,12 +347,12 @@ pub(crate) fn insert_streaming_nodes(
                 input,
                 keys,
                 aggs,
-                maintain_order: false,
+                maintain_order,
                 apply: None,
                 schema: output_schema,
                 options,
                 ..
-            } => {
+            } if !maintain_order || is_temporal_group_by(options) => {
                 #[cfg(feature = "dtype-categorical")]
                 let string_cache = polars_core::using_string_cache();
                 #[cfg(not(feature = "dtype-categorical"))]
@@ -367,10 +380,18 @@ pub(crate) fn insert_streaming_nodes(
                 let input_schema = lp_arena.get(*input).schema(lp_arena);
                 #[allow(unused_mut)]
                 let mut can_stream = true;
+                let temporal = is_temporal_group_by(options);
 
+                // Windows are evaluated on the chunks in the order they arrive. This requires
+                // windows that don't depend on the first row and no `group_by` keys.
                 #[cfg(feature = "dynamic_group_by")]
                 {
-                    if options.rolling.is_some() || options.dynamic.is_some() {
+                    if temporal
+                        && (!keys.is_empty()
+                            || options.dynamic.as_ref().is_some_and(|options| {
+                                options.start_by != polars_time::prelude::StartBy::WindowBound
+                            }))
+                    {
                         can_stream = false
                     }
                 }
@@ -400,7 +421,8 @@ pub(crate) fn insert_streaming_nodes(
                         .all(|dt| allowed_dtype(dt, string_cache))
                 };
 
-                if can_stream && valid_agg() && valid_key() && valid_types() {
+                // Temporal group-bys evaluate the aggregations with the in-memory engine.
+                if can_stream && (temporal || (valid_agg() && valid_key())) && valid_types() {
                     state.streamable = true;
                     state.operators_sinks.push(PipelineNode::Sink(root));
                     stack.push(StackFrame::new(*input, state, current_idx))
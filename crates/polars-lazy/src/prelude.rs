@@ -13,11 +13,13 @@ pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::plans::{
-    AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
-    NULL,
+    AnonymousScan, AnonymousScanArgs, AnonymousScanBatches, AnonymousScanOptions, DslPlan, Literal,
+    LiteralValue, Null, NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{FileType, SinkCallback, SinkType, UnionArgs};
+#[cfg(feature = "dtype-duration")]
+pub use polars_time::prelude::ClockAmbiguity;
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -0,0 +1,97 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// How the columns of scanned files are coerced into the target schema of a scan.
+///
+/// For every target column, the file column with the same name is read, or else the file column
+/// that is an alias of it. Columns of the file that aren't in the target schema are dropped.
+#[derive(Clone, Debug)]
+pub struct CastPolicy {
+    /// Names of file columns mapped to the name of the target column they are read as.
+    pub aliases: PlHashMap<PlSmallStr, PlSmallStr>,
+    /// How columns are cast to the dtype of the target column, `None` raises if the dtypes
+    /// differ.
+    pub cast: Option<CastOptions>,
+    /// Overrides of `cast` for the target columns of a dtype.
+    pub dtype_casts: PlHashMap<DataType, Option<CastOptions>>,
+    /// Values of the target columns that are missing from a file.
+    pub defaults: PlHashMap<PlSmallStr, Expr>,
+    /// Fill target columns that are missing from a file and have no default with nulls, instead
+    /// of raising.
+    pub allow_missing: bool,
+}
+
+impl Default for CastPolicy {
+    fn default() -> Self {
+        Self {
+            aliases: Default::default(),
+            cast: Some(CastOptions::Strict),
+            dtype_casts: Default::default(),
+            defaults: Default::default(),
+            allow_missing: true,
+        }
+    }
+}
+
+impl CastPolicy {
+    /// The expressions that select the columns of `target` from a file with `file_schema`.
+    pub(crate) fn reconcile(
+        &self,
+        file_schema: &Schema,
+        target: &Schema,
+    ) -> PolarsResult<Vec<Expr>> {
+        target
+            .iter()
+            .map(|(name, dtype)| {
+                let source = if file_schema.contains(name) {
+                    Some(name)
+                } else {
+                    let mut aliases = self
+                        .aliases
+                        .iter()
+                        .filter(|(alias, to)| *to == name && file_schema.contains(alias))
+                        .map(|(alias, _)| alias);
+                    let source = aliases.next();
+                    if let Some(other) = aliases.next() {
+                        polars_bail!(
+                            Duplicate: "columns '{}' and '{}' are both aliases of '{}'",
+                            source.unwrap(), other, name
+                        );
+                    }
+                    source
+                };
+                let expr = match source {
+                    Some(source) => {
+                        let source_dtype = file_schema.get(source).unwrap();
+                        let expr = col(source.clone());
+                        if source_dtype == dtype {
+                            expr
+                        } else {
+                            let cast = self.dtype_casts.get(dtype).unwrap_or(&self.cast);
+                            match cast {
+                                Some(options) => expr.cast_with_options(dtype.clone(), *options),
+                                None => polars_bail!(
+                                    SchemaMismatch: "column '{}' has dtype {}, expected {}",
+                                    source, source_dtype, dtype
+                                ),
+                            }
+                        }
+                    },
+                    None => match self.defaults.get(name) {
+                        Some(default) => default.clone().strict_cast(dtype.clone()),
+                        None => {
+                            polars_ensure!(
+                                self.allow_missing,
+                                ColumnNotFound: "column '{}' is missing from the file", name
+                            );
+                            lit(NULL).cast(dtype.clone())
+                        },
+                    },
+                };
+                Ok(expr.alias(name.clone()))
+            })
+            .collect()
+    }
+}
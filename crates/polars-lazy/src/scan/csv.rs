@@ -1,15 +1,19 @@
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
-    infer_file_schema, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
+    infer_file_schema, normalize_fields, CommentPrefix, CsvEncoding, CsvOnError, CsvParseOptions,
+    CsvReadOptions, NullValues,
 };
+use polars_io::mmap::ReaderBytes;
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::get_reader_bytes;
 use polars_io::RowIndex;
 
 use crate::prelude::*;
+use crate::scan::reader::{compressed_local_file, scan_compressed_csv, scan_csv_reader};
 
 #[derive(Clone)]
 #[cfg(feature = "csv")]
@@ -83,6 +87,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set what happens with rows that fail to parse. Lazy scans can raise or skip them, but
+    /// not capture them.
+    #[must_use]
+    pub fn with_on_error(mut self, on_error: CsvOnError) -> Self {
+        self.read_options.on_error = on_error;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -105,6 +117,17 @@ impl LazyCsvReader {
         self
     }
 
+    /// Parse the columns with the given `strftime` formats, by column name, while scanning
+    /// instead of reading them as strings.
+    #[must_use]
+    pub fn with_datetime_formats(
+        mut self,
+        datetime_formats: Vec<(PlSmallStr, PlSmallStr)>,
+    ) -> Self {
+        self.read_options.datetime_formats = datetime_formats;
+        self
+    }
+
     /// Set whether the CSV file has headers
     #[must_use]
     pub fn with_has_header(mut self, has_header: bool) -> Self {
@@ -144,6 +167,20 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_eol_char(eol_char))
     }
 
+    /// Set the `char` that escapes the `char` after it, e.g. an embedded separator or end of
+    /// line. The default is `None`, no escaping.
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
+    /// Set a separator of more than one byte, e.g. `||`, which is used instead of the single byte
+    /// separator.
+    #[must_use]
+    pub fn with_multi_byte_separator(self, multi_byte_separator: Option<PlSmallStr>) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_byte_separator(multi_byte_separator.clone()))
+    }
+
     /// Set values that will be interpreted as missing/ null.
     #[must_use]
     pub fn with_null_values(self, null_values: Option<NullValues>) -> Self {
@@ -229,9 +266,23 @@ impl LazyCsvReader {
 
         let mut file = polars_utils::open_file(path)?;
 
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        let mut reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
         let skip_rows = self.read_options.skip_rows;
         let parse_options = self.read_options.get_parse_options();
+        if let Some(bytes) = normalize_fields(
+            &reader_bytes,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+            parse_options.escape_char,
+            parse_options
+                .multi_byte_separator
+                .as_deref()
+                .map(str::as_bytes),
+            parse_options.comment_prefix.as_ref(),
+        )? {
+            reader_bytes = ReaderBytes::Owned(bytes);
+        }
 
         let (schema, _, _) = infer_file_schema(
             &reader_bytes,
@@ -267,11 +318,32 @@ impl LazyCsvReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Get a [`LazyFrame`] that reads the CSV from `reader`, e.g. stdin, instead of from the
+    /// paths of this reader. The rows are parsed in batches as the query consumes them, so the
+    /// streaming engine doesn't have to buffer the whole input in memory.
+    ///
+    /// The schema is inferred from the first batch. As `reader` can only be read once, the
+    /// [`LazyFrame`] can only be collected once.
+    pub fn finish_with_reader(self, reader: impl Read + Send + 'static) -> PolarsResult<LazyFrame> {
+        scan_csv_reader(self.read_options, Box::new(reader))
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
+        // Read from stdin if the path is "-".
+        if self.paths.len() == 1 && self.paths[0] == Path::new("-") {
+            return self.finish_with_reader(std::io::stdin());
+        }
+        // Decompress a compressed file while it is parsed instead of inflating it up front.
+        if self.include_file_paths.is_none() {
+            if let Some(path) = compressed_local_file(&self.paths)? {
+                return scan_compressed_csv(self.read_options, path);
+            }
+        }
+
         let mut lf: LazyFrame = DslBuilder::scan_csv(
             self.paths,
             self.read_options,
@@ -0,0 +1,636 @@
+//! Scan the result of a SQL query, see [`LazyFrame::scan_database`].
+use std::any::Any;
+use std::fmt::Write;
+
+use polars_core::prelude::*;
+use polars_io::HiveOptions;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_plan::utils::has_expr;
+
+use crate::prelude::*;
+
+/// The alias of the subqueries that the queries of database scans are wrapped in.
+const SUBQUERY_ALIAS: &str = "polars_subquery";
+
+/// A connection to the database that a query is read from, e.g. an ADBC connection.
+pub trait DatabaseSource: Send + Sync {
+    /// Run a SQL query and return its result.
+    fn query(&self, sql: &str) -> PolarsResult<DataFrame>;
+
+    /// The schema of the result of the query `sql`. By default the query is run without rows.
+    fn schema(&self, sql: &str) -> PolarsResult<SchemaRef> {
+        let df = self.query(&format!(
+            "SELECT * FROM ({sql}) AS {SUBQUERY_ALIAS} LIMIT 0"
+        ))?;
+        Ok(Arc::new(df.schema()))
+    }
+}
+
+/// Quote the parts of a, possibly schema qualified, table or column name.
+#[cfg(feature = "streaming")]
+pub(crate) fn quote_identifier(name: &str) -> String {
+    name.split('.')
+        .map(quote_column)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Quote a column name, which, unlike a table name, can contain dots.
+pub(crate) fn quote_column(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub(crate) fn write_sql_literal(sql: &mut String, value: AnyValue) -> PolarsResult<()> {
+    match value {
+        AnyValue::Null => sql.push_str("NULL"),
+        AnyValue::Boolean(v) => sql.push_str(if v { "TRUE" } else { "FALSE" }),
+        AnyValue::Int8(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::Int16(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::Int32(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::Int64(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::UInt8(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::UInt16(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::UInt32(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::UInt64(v) => write!(sql, "{v}").unwrap(),
+        AnyValue::Float32(v) if v.is_finite() => write!(sql, "{v}").unwrap(),
+        AnyValue::Float64(v) if v.is_finite() => write!(sql, "{v}").unwrap(),
+        AnyValue::String(v) => write!(sql, "'{}'", v.replace('\'', "''")).unwrap(),
+        #[cfg(feature = "dtype-date")]
+        v @ AnyValue::Date(_) => write!(sql, "'{v}'").unwrap(),
+        #[cfg(feature = "dtype-time")]
+        v @ AnyValue::Time(_) => write!(sql, "'{v}'").unwrap(),
+        #[cfg(feature = "dtype-datetime")]
+        v @ AnyValue::Datetime(..) => write!(sql, "'{v}'").unwrap(),
+        v => polars_bail!(
+            InvalidOperation: "cannot insert value {} with an INSERT statement, the connection \
+            has to implement `insert`", v
+        ),
+    }
+    Ok(())
+}
+
+/// Translate a row-wise expression to SQL, `None` if it has no SQL equivalent.
+///
+/// Arithmetic is not translated, as its overflow and string semantics differ between databases.
+fn expr_to_sql(expr: &Expr) -> Option<String> {
+    use Operator::*;
+    let sql = match expr {
+        Expr::Alias(expr, _) => return expr_to_sql(expr),
+        Expr::Column(name) => quote_column(name),
+        Expr::Literal(value) => {
+            let mut sql = String::new();
+            write_sql_literal(&mut sql, value.to_any_value()?).ok()?;
+            sql
+        },
+        Expr::BinaryExpr { left, op, right } => {
+            let op = match op {
+                Eq => "=",
+                NotEq => "<>",
+                Lt => "<",
+                LtEq => "<=",
+                Gt => ">",
+                GtEq => ">=",
+                EqValidity => "IS NOT DISTINCT FROM",
+                NotEqValidity => "IS DISTINCT FROM",
+                And | LogicalAnd => "AND",
+                Or | LogicalOr => "OR",
+                _ => return None,
+            };
+            format!("({} {op} {})", expr_to_sql(left)?, expr_to_sql(right)?)
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+            ..
+        } => {
+            let [input] = input.as_slice() else {
+                return None;
+            };
+            let input = expr_to_sql(input)?;
+            match function {
+                BooleanFunction::IsNull => format!("({input} IS NULL)"),
+                BooleanFunction::IsNotNull => format!("({input} IS NOT NULL)"),
+                BooleanFunction::Not => format!("(NOT {input})"),
+                _ => return None,
+            }
+        },
+        Expr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => format!(
+            "CASE WHEN {} THEN {} ELSE {} END",
+            expr_to_sql(predicate)?,
+            expr_to_sql(truthy)?,
+            expr_to_sql(falsy)?
+        ),
+        _ => return None,
+    };
+    Some(sql)
+}
+
+/// Translate an aggregation to SQL, `None` if it has no SQL equivalent.
+fn agg_to_sql(expr: &Expr) -> Option<String> {
+    let sql = match expr {
+        Expr::Alias(expr, _) => return agg_to_sql(expr),
+        Expr::Len | Expr::Agg(AggExpr::Count(_, true)) => "COUNT(*)".to_string(),
+        Expr::Agg(agg) => {
+            let input = expr_to_sql(agg.as_ref())?;
+            match agg {
+                AggExpr::Min {
+                    propagate_nans: false,
+                    ..
+                } => format!("MIN({input})"),
+                AggExpr::Max {
+                    propagate_nans: false,
+                    ..
+                } => format!("MAX({input})"),
+                // The sum of only nulls is zero in Polars.
+                AggExpr::Sum(_) => format!("COALESCE(SUM({input}), 0)"),
+                AggExpr::Mean(_) => format!("AVG({input})"),
+                AggExpr::Count(_, false) => format!("COUNT({input})"),
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+    Some(sql)
+}
+
+/// A `SELECT` statement over a subquery. Its clauses are filled in the order in which SQL
+/// applies them; a clause that comes too late for the statement nests it in a new one.
+#[derive(Clone, Default)]
+struct Query {
+    from: String,
+    items: Vec<String>,
+    filters: Vec<String>,
+    group_by: Vec<String>,
+    order_by: Vec<String>,
+    limit: Option<(usize, usize)>,
+}
+
+impl Query {
+    fn new(sql: String) -> Self {
+        Self {
+            from: sql,
+            ..Default::default()
+        }
+    }
+
+    fn nest(self) -> Self {
+        Self::new(self.to_sql())
+    }
+
+    /// This statement, or a new statement over it if it has a `SELECT` list or clauses that
+    /// are applied after it.
+    fn unprojected(self) -> Self {
+        if self.items.is_empty() && !self.is_ordered() && self.limit.is_none() {
+            self
+        } else {
+            self.nest()
+        }
+    }
+
+    /// Nested statements don't keep the order of their subquery.
+    fn is_ordered(&self) -> bool {
+        !self.order_by.is_empty()
+    }
+
+    fn filter(self, predicate: String) -> Self {
+        let mut query = self.unprojected();
+        query.filters.push(predicate);
+        query
+    }
+
+    fn select(self, items: Vec<String>, group_by: Vec<String>) -> Self {
+        let mut query = self.unprojected();
+        query.items = items;
+        query.group_by = group_by;
+        query
+    }
+
+    /// Select the `columns` of the result of this statement, which has the given `schema`.
+    fn project(self, columns: &[PlSmallStr], schema: &Schema) -> Self {
+        if columns.iter().eq(schema.iter_names()) {
+            return self;
+        }
+        let items = columns.iter().map(|name| quote_column(name)).collect();
+        self.select(items, vec![])
+    }
+
+    fn order_by(self, order_by: Vec<String>) -> Self {
+        let mut query = self.unprojected();
+        query.order_by = order_by;
+        query
+    }
+
+    fn limit(self, offset: usize, len: usize) -> Self {
+        let mut query = if self.limit.is_none() {
+            self
+        } else {
+            self.nest()
+        };
+        query.limit = Some((offset, len));
+        query
+    }
+
+    fn to_sql(&self) -> String {
+        if self.items.is_empty()
+            && self.filters.is_empty()
+            && !self.is_ordered()
+            && self.limit.is_none()
+        {
+            return self.from.clone();
+        }
+        let items = if self.items.is_empty() {
+            "*".to_string()
+        } else {
+            self.items.join(", ")
+        };
+        let mut sql = format!("SELECT {items} FROM ({}) AS {SUBQUERY_ALIAS}", self.from);
+        if !self.filters.is_empty() {
+            write!(sql, " WHERE {}", self.filters.join(" AND ")).unwrap();
+        }
+        if !self.group_by.is_empty() {
+            write!(sql, " GROUP BY {}", self.group_by.join(", ")).unwrap();
+        }
+        if self.is_ordered() {
+            write!(sql, " ORDER BY {}", self.order_by.join(", ")).unwrap();
+        }
+        if let Some((offset, len)) = self.limit {
+            write!(sql, " LIMIT {len}").unwrap();
+            if offset > 0 {
+                write!(sql, " OFFSET {offset}").unwrap();
+            }
+        }
+        sql
+    }
+}
+
+/// Name the result of `sql` after `name`, unless it already is.
+fn select_item(sql: String, name: &str) -> String {
+    let name = quote_column(name);
+    if sql == name {
+        sql
+    } else {
+        format!("{sql} AS {name}")
+    }
+}
+
+struct DatabaseScan {
+    source: Arc<dyn DatabaseSource>,
+    query: String,
+}
+
+impl DatabaseScan {
+    /// Run `query` and cast its result to `schema`, as the types that databases return for
+    /// e.g. aggregations differ.
+    fn run(&self, query: &Query, schema: &Schema) -> PolarsResult<DataFrame> {
+        let df = self.source.query(&query.to_sql())?;
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| df.column(name)?.cast(dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+impl AnonymousScan for DatabaseScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut query = Query::new(self.query.clone());
+        if let Some(n_rows) = scan_opts.n_rows {
+            query = query.limit(0, n_rows);
+        }
+        let schema = scan_opts
+            .output_schema
+            .unwrap_or_else(|| scan_opts.schema.clone());
+        if let Some(predicate) = scan_opts.predicate {
+            match expr_to_sql(&predicate) {
+                Some(sql) => query = query.filter(sql),
+                // Filter the complete result in Polars.
+                None => {
+                    let df = self.run(&query, &scan_opts.schema)?;
+                    let columns = schema.iter_names().cloned().map(Expr::Column);
+                    return df
+                        .lazy()
+                        .filter(predicate)
+                        .select(columns.collect::<Vec<_>>())
+                        .collect();
+                },
+            }
+        }
+        if let Some(columns) = &scan_opts.with_columns {
+            query = query.project(columns, &scan_opts.schema);
+        }
+        self.run(&query, &schema)
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        self.source.schema(&self.query)
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn explain(&self) -> Option<String> {
+        Some(format!("SQL: {}", self.query))
+    }
+}
+
+impl LazyFrame {
+    /// Scan the result of the SQL `query`, which is run by `source` when the query plan is
+    /// executed. If no `schema` is given, it is read from `source`.
+    ///
+    /// Filters, projections and slices are added to the query. With the database pushdown
+    /// optimization, see [`LazyFrame::with_database_pushdown`], the aggregations and sorts on top
+    /// of the scan are added as well, the query that is sent is shown in the explain of the
+    /// optimized plan.
+    pub fn scan_database(
+        source: Arc<dyn DatabaseSource>,
+        query: &str,
+        schema: Option<SchemaRef>,
+    ) -> PolarsResult<Self> {
+        let function = Arc::new(DatabaseScan {
+            source,
+            query: query.to_string(),
+        });
+        let args = ScanArgsAnonymous {
+            schema,
+            name: "DATABASE SCAN",
+            ..Default::default()
+        };
+        Self::anonymous_scan(function, args)
+    }
+}
+
+struct Pushdown<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+}
+
+impl Pushdown<'_> {
+    fn expr(&self, e: &ExprIR) -> Option<String> {
+        expr_to_sql(&node_to_expr(e.node(), self.expr_arena))
+    }
+
+    fn agg(&self, e: &ExprIR) -> Option<String> {
+        agg_to_sql(&node_to_expr(e.node(), self.expr_arena))
+    }
+
+    fn has_column(&self, e: &ExprIR) -> bool {
+        has_expr(&node_to_expr(e.node(), self.expr_arena), |e| {
+            matches!(e, Expr::Column(_))
+        })
+    }
+
+    /// The items of a `SELECT` of `exprs`, with `group_by` if they aggregate.
+    fn select(&self, exprs: &[ExprIR]) -> Option<(Vec<String>, bool)> {
+        let mut row_wise = false;
+        let mut aggregates = false;
+        let items = exprs
+            .iter()
+            .map(|e| {
+                let sql = if let Some(sql) = self.expr(e) {
+                    row_wise |= self.has_column(e);
+                    sql
+                } else {
+                    aggregates = true;
+                    self.agg(e)?
+                };
+                Some(select_item(sql, e.output_name()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        // Aggregations return a single row, which row-wise expressions can't be broadcast to,
+        // and a select of only literals returns a single row as well.
+        if aggregates == row_wise {
+            return None;
+        }
+        Some((items, aggregates))
+    }
+
+    /// The query and scan of the subplan at `node`, `None` if it can't be run by the
+    /// database.
+    fn query(&self, node: Node) -> Option<(Query, &DatabaseScan)> {
+        use IR::*;
+        let (query, scan) = match self.lp_arena.get(node) {
+            Scan {
+                file_info,
+                predicate,
+                scan_type: FileScan::Anonymous { function, .. },
+                file_options,
+                ..
+            } => {
+                let scan = function.as_any().downcast_ref::<DatabaseScan>()?;
+                if file_options.row_index.is_some() {
+                    return None;
+                }
+                let mut query = Query::new(scan.query.clone());
+                if let Some((offset, len)) = file_options.slice {
+                    query = query.limit(usize::try_from(offset).ok()?, len);
+                }
+                if let Some(predicate) = predicate {
+                    query = query.filter(self.expr(predicate)?);
+                }
+                if let Some(columns) = &file_options.with_columns {
+                    query = query.project(columns, &file_info.schema);
+                }
+                return Some((query, scan));
+            },
+            Slice { input, offset, len } => {
+                let (query, scan) = self.query(*input)?;
+                if query.is_ordered() && query.limit.is_some() {
+                    return None;
+                }
+                let offset = usize::try_from(*offset).ok()?;
+                return Some((query.limit(offset, *len as usize), scan));
+            },
+            Filter { input, predicate } => {
+                let (query, scan) = self.query(*input)?;
+                (query.filter(self.expr(predicate)?), scan)
+            },
+            SimpleProjection { input, columns } => {
+                let (query, scan) = self.query(*input)?;
+                let items = columns
+                    .iter_names()
+                    .map(|name| quote_column(name))
+                    .collect();
+                (query.select(items, vec![]), scan)
+            },
+            Select { input, expr, .. } => {
+                let (query, scan) = self.query(*input)?;
+                let (items, _) = self.select(expr)?;
+                (query.select(items, vec![]), scan)
+            },
+            Reduce { input, exprs, .. } => {
+                let (query, scan) = self.query(*input)?;
+                let (items, true) = self.select(exprs)? else {
+                    return None;
+                };
+                (query.select(items, vec![]), scan)
+            },
+            HStack {
+                input,
+                exprs,
+                schema,
+                ..
+            } => {
+                let (query, scan) = self.query(*input)?;
+                let items = schema
+                    .iter_names()
+                    .map(
+                        |name| match exprs.iter().find(|e| e.output_name() == name) {
+                            Some(e) => Some(select_item(self.expr(e)?, name)),
+                            None => Some(quote_column(name)),
+                        },
+                    )
+                    .collect::<Option<Vec<_>>>()?;
+                (query.select(items, vec![]), scan)
+            },
+            GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                maintain_order,
+                options,
+                ..
+            } => {
+                #[cfg(feature = "dynamic_group_by")]
+                if options.dynamic.is_some() || options.rolling.is_some() {
+                    return None;
+                }
+                if apply.is_some() || *maintain_order {
+                    return None;
+                }
+                let (query, scan) = self.query(*input)?;
+                let group_by = keys
+                    .iter()
+                    .map(|e| self.expr(e))
+                    .collect::<Option<Vec<_>>>()?;
+                let mut items = group_by
+                    .iter()
+                    .zip(keys)
+                    .map(|(sql, e)| select_item(sql.clone(), e.output_name()))
+                    .collect::<Vec<_>>();
+                for e in aggs {
+                    items.push(select_item(self.agg(e)?, e.output_name()));
+                }
+                let query = query.select(items, group_by);
+                match options.slice {
+                    Some((offset, len)) => (query.limit(usize::try_from(offset).ok()?, len), scan),
+                    None => (query, scan),
+                }
+            },
+            Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => {
+                // Databases don't sort stable.
+                if sort_options.maintain_order {
+                    return None;
+                }
+                let (query, scan) = self.query(*input)?;
+                let flag = |flags: &[bool], i: usize| flags.get(i).or(flags.first()) == Some(&true);
+                let order_by = by_column
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| {
+                        let direction = if flag(&sort_options.descending, i) {
+                            "DESC"
+                        } else {
+                            "ASC"
+                        };
+                        let nulls = if flag(&sort_options.nulls_last, i) {
+                            "LAST"
+                        } else {
+                            "FIRST"
+                        };
+                        Some(format!("{} {direction} NULLS {nulls}", self.expr(e)?))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                if query.is_ordered() {
+                    return None;
+                }
+                let query = query.order_by(order_by);
+                let query = match slice {
+                    Some((offset, len)) => query.limit(usize::try_from(*offset).ok()?, *len),
+                    None => query,
+                };
+                return Some((query, scan));
+            },
+            _ => return None,
+        };
+        // The order of a subquery is lost in the statements over it.
+        if query.is_ordered() {
+            return None;
+        }
+        Some((query, scan))
+    }
+}
+
+/// Replace the largest subplans that only read from a database scan by a database scan of a
+/// query that computes the subplan, see [`OptFlags::DATABASE_PUSHDOWN`].
+pub(crate) fn database_pushdown(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let pushdown = Pushdown {
+            lp_arena,
+            expr_arena,
+        };
+        let Some((query, scan)) = pushdown.query(node) else {
+            lp_arena.get(node).copy_inputs(&mut stack);
+            continue;
+        };
+        let function = Arc::new(DatabaseScan {
+            source: scan.source.clone(),
+            query: query.to_sql(),
+        });
+        let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+        let file_info = FileInfo::new(schema, None, (None, usize::MAX));
+        let file_options = FileScanOptions {
+            slice: None,
+            with_columns: None,
+            cache: false,
+            row_index: None,
+            rechunk: false,
+            file_counter: Default::default(),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            glob: false,
+            include_file_paths: None,
+        };
+        let scan = IR::Scan {
+            paths: Arc::new(vec![]),
+            file_info,
+            hive_parts: None,
+            predicate: None,
+            output_schema: None,
+            scan_type: FileScan::Anonymous {
+                options: Arc::new(AnonymousScanOptions {
+                    skip_rows: None,
+                    fmt_str: "DATABASE SCAN",
+                }),
+                function,
+            },
+            file_options,
+        };
+        lp_arena.replace(node, scan);
+    }
+}
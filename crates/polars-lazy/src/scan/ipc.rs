@@ -3,9 +3,11 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::ipc::IpcScanOptions;
+use polars_io::path_utils::expand_paths;
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
+use crate::scan::position::FileRows;
 
 #[derive(Clone)]
 pub struct ScanArgsIpc {
@@ -17,6 +19,10 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Start the scan at a row of one of its files instead of at the first row, e.g. to resume
+    /// a scan after a failure. `n_rows` counts from this position and the row index stays the
+    /// index in the whole scan.
+    pub start_at: Option<ScanPosition>,
 }
 
 impl Default for ScanArgsIpc {
@@ -30,6 +36,7 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            start_at: None,
         }
     }
 }
@@ -49,8 +56,49 @@ impl LazyIpcReader {
     }
 }
 
+impl LazyIpcReader {
+    /// The number of rows of every file of the scan.
+    fn file_rows(&self) -> PolarsResult<FileRows<impl Fn(&Path) -> PolarsResult<LazyFrame> + '_>> {
+        let paths = expand_paths(&self.paths, self.glob(), self.cloud_options())?;
+        Ok(FileRows::new(paths, move |path| {
+            let mut reader = self.clone();
+            reader.args.start_at = None;
+            reader.args.n_rows = None;
+            reader.args.row_index = None;
+            reader.args.include_file_paths = None;
+            reader.args.hive_options.enabled = Some(false);
+            reader
+                .with_paths(Arc::new(vec![path.to_path_buf()]))
+                .finish()
+        }))
+    }
+
+    /// Scan from `start_at` and return the position at which the next scan continues.
+    fn finish_resumable(mut self) -> PolarsResult<(LazyFrame, ScanPosition)> {
+        let start = self.args.start_at.take().unwrap_or_default();
+        let n_rows = self.args.n_rows.take();
+        // The row index is added after the slice, as the offset of the slice can be in any file.
+        let row_index = self.args.row_index.take();
+        let (offset, end) = {
+            let mut file_rows = self.file_rows()?;
+            (file_rows.offset(start)?, file_rows.end(start, n_rows)?)
+        };
+        let mut lf = self.finish()?.slice(
+            offset as i64,
+            n_rows.map_or(IdxSize::MAX, |n_rows| n_rows as IdxSize),
+        );
+        if let Some(row_index) = row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset + offset as IdxSize));
+        }
+        Ok((lf, end))
+    }
+}
+
 impl LazyFileListReader for LazyIpcReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
+        if self.args.start_at.is_some() {
+            return self.finish_resumable().map(|(lf, _)| lf);
+        }
         let paths = self.paths;
         let args = self.args;
 
@@ -130,6 +178,17 @@ impl LazyFrame {
             .finish()
     }
 
+    /// Create a LazyFrame from an ipc scan that starts at `args.start_at` and reads at most
+    /// `args.n_rows` rows, and return the position at which the next scan continues.
+    pub fn scan_ipc_resumable(
+        path: impl AsRef<Path>,
+        args: ScanArgsIpc,
+    ) -> PolarsResult<(Self, ScanPosition)> {
+        LazyIpcReader::new(args)
+            .with_paths(Arc::new(vec![path.as_ref().to_path_buf()]))
+            .finish_resumable()
+    }
+
     pub fn scan_ipc_files(paths: Arc<Vec<PathBuf>>, args: ScanArgsIpc) -> PolarsResult<Self> {
         LazyIpcReader::new(args).with_paths(paths).finish()
     }
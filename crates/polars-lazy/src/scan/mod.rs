@@ -1,6 +1,9 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "parquet")]
+pub(super) mod cast_policy;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+pub(crate) mod database;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
@@ -8,3 +11,7 @@ pub(super) mod ipc;
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+pub(super) mod position;
+#[cfg(any(feature = "csv", feature = "json"))]
+mod reader;
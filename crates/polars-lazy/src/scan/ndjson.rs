@@ -1,15 +1,18 @@
+use std::io::Read;
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::ndjson::JsonUnionResolution;
 use polars_io::RowIndex;
 use polars_plan::plans::{DslPlan, FileScan};
 use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
 
 use crate::prelude::LazyFrame;
 use crate::scan::file_list_reader::LazyFileListReader;
+use crate::scan::reader::{compressed_local_file, scan_compressed_ndjson, scan_ndjson_reader};
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -21,6 +24,7 @@ pub struct LazyJsonLineReader {
     pub(crate) schema_overwrite: Option<SchemaRef>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
+    pub(crate) union_resolution: JsonUnionResolution,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
@@ -42,6 +46,7 @@ impl LazyJsonLineReader {
             schema_overwrite: None,
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
+            union_resolution: JsonUnionResolution::default(),
             ignore_errors: false,
             n_rows: None,
             include_file_paths: None,
@@ -77,6 +82,13 @@ impl LazyJsonLineReader {
         self.infer_schema_length = num_rows;
         self
     }
+    /// Set how values whose type differs between rows are resolved when inferring the schema.
+    /// The default widens them to a common type, see [`JsonUnionResolution`].
+    #[must_use]
+    pub fn with_union_resolution(mut self, union_resolution: JsonUnionResolution) -> Self {
+        self.union_resolution = union_resolution;
+        self
+    }
     /// Set the JSON file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -113,10 +125,51 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    fn read_options(&self) -> NDJsonReadOptions {
+        NDJsonReadOptions {
+            n_threads: None,
+            infer_schema_length: self.infer_schema_length,
+            union_resolution: self.union_resolution,
+            chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
+            low_memory: self.low_memory,
+            ignore_errors: self.ignore_errors,
+            schema: self.schema.clone(),
+            schema_overwrite: self.schema_overwrite.clone(),
+        }
+    }
+
+    /// Get a [`LazyFrame`] that reads the NDJSON from `reader`, e.g. stdin, instead of from the
+    /// paths of this reader. The rows are parsed in batches as the query consumes them, so the
+    /// streaming engine doesn't have to buffer the whole input in memory.
+    ///
+    /// Without a schema, it is inferred from the first batch. As `reader` can only be read once,
+    /// the [`LazyFrame`] can only be collected once.
+    pub fn finish_with_reader(self, reader: impl Read + Send + 'static) -> PolarsResult<LazyFrame> {
+        scan_ndjson_reader(
+            self.read_options(),
+            Box::new(reader),
+            self.n_rows,
+            self.row_index,
+        )
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
+        // Read from stdin if the path is "-".
+        if self.paths.len() == 1 && self.paths[0] == Path::new("-") {
+            return self.finish_with_reader(std::io::stdin());
+        }
+        let options = self.read_options();
+
+        // Decompress a compressed file while it is parsed instead of inflating it up front.
+        if self.include_file_paths.is_none() {
+            if let Some(path) = compressed_local_file(&self.paths)? {
+                return scan_compressed_ndjson(options, path, self.n_rows, self.row_index);
+            }
+        }
+
         let paths = Arc::new(Mutex::new((self.paths, false)));
 
         let file_options = FileScanOptions {
@@ -131,16 +184,6 @@ impl LazyFileListReader for LazyJsonLineReader {
             include_file_paths: self.include_file_paths,
         };
 
-        let options = NDJsonReadOptions {
-            n_threads: None,
-            infer_schema_length: self.infer_schema_length,
-            chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
-            low_memory: self.low_memory,
-            ignore_errors: self.ignore_errors,
-            schema: self.schema,
-            schema_overwrite: self.schema_overwrite,
-        };
-
         let scan_type = FileScan::NDJson {
             options,
             cloud_options: self.cloud_options,
@@ -2,10 +2,14 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+#[cfg(feature = "cloud")]
+use polars_io::cloud::{user_store_url, AsyncReaderStore, ObjectStore};
+use polars_io::parquet::read::{ParallelStrategy, PrefetchOptions};
+use polars_io::path_utils::expand_paths;
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
+use crate::scan::position::FileRows;
 
 #[derive(Clone)]
 pub struct ScanArgsParquet {
@@ -15,12 +19,34 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
+    /// Read the metadata of the files from the `_metadata` summary file of the dataset, which is
+    /// looked up in the directory that contains all files or in one of its parents, instead of
+    /// from the footer of every file. Files that are not in the summary are read as usual.
+    pub use_metadata_summary: bool,
+    /// Concurrency and memory budget of the row group downloads from cloud storage.
+    pub prefetch: PrefetchOptions,
+    /// Row groups whose projected columns take more than this many bytes uncompressed are
+    /// decoded in morsels of rows by the streaming engine. Defaults to 64 MiB with `low_memory`.
+    pub large_row_group_bytes: Option<usize>,
+    /// Read the string columns as `Categorical`. Column chunks that are fully
+    /// dictionary-encoded are decoded directly into categoricals that reuse the dictionary, which
+    /// is much cheaper than decoding strings for columns with few distinct values.
+    pub dictionary_as_categorical: bool,
     pub low_memory: bool,
     pub rechunk: bool,
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Schema the files are coerced into with `cast_policy`, instead of the schema of the first
+    /// file. Every file is then scanned separately, without hive partitioning, and its schema is
+    /// read when the scan is created.
+    pub schema: Option<SchemaRef>,
+    pub cast_policy: CastPolicy,
+    /// Start the scan at a row of one of its files instead of at the first row, e.g. to resume
+    /// a scan after a failure. `n_rows` counts from this position and the row index stays the
+    /// index in the whole scan.
+    pub start_at: Option<ScanPosition>,
 }
 
 impl Default for ScanArgsParquet {
@@ -32,11 +58,18 @@ impl Default for ScanArgsParquet {
             cloud_options: None,
             hive_options: Default::default(),
             use_statistics: true,
+            use_metadata_summary: false,
+            prefetch: Default::default(),
+            large_row_group_bytes: None,
+            dictionary_as_categorical: false,
             rechunk: false,
             low_memory: false,
             cache: true,
             glob: true,
             include_file_paths: None,
+            schema: None,
+            cast_policy: Default::default(),
+            start_at: None,
         }
     }
 }
@@ -56,9 +89,100 @@ impl LazyParquetReader {
     }
 }
 
+impl LazyParquetReader {
+    /// Scan every file separately and coerce it into `schema`.
+    fn finish_reconciled(self, schema: SchemaRef) -> PolarsResult<LazyFrame> {
+        let paths = expand_paths(&self.paths, self.args.glob, self.cloud_options())?;
+        polars_ensure!(
+            !paths.is_empty(),
+            ComputeError: "no matching files found in {:?}", self.paths
+        );
+
+        let lfs = paths
+            .iter()
+            .map(|path| {
+                self.scan_reconciled(path, &schema).map_err(|e| {
+                    polars_err!(
+                        ComputeError: "error while reading {}: {}", path.display(), e
+                    )
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut lf = self.concat_impl(lfs)?;
+        if let Some(n_rows) = self.args.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize)
+        };
+        if let Some(row_index) = &self.args.row_index {
+            lf = lf.with_row_index(row_index.name.clone(), Some(row_index.offset))
+        };
+        Ok(lf)
+    }
+
+    /// The number of rows of every file of the scan.
+    fn file_rows(&self) -> PolarsResult<FileRows<impl Fn(&Path) -> PolarsResult<LazyFrame> + '_>> {
+        let paths = expand_paths(&self.paths, self.args.glob, self.cloud_options())?;
+        Ok(FileRows::new(paths, move |path| {
+            let mut reader = self.clone();
+            reader.args.start_at = None;
+            reader.args.n_rows = None;
+            reader.args.row_index = None;
+            reader.args.include_file_paths = None;
+            reader.args.schema = None;
+            reader.args.glob = false;
+            reader.args.hive_options.enabled = Some(false);
+            reader
+                .with_paths(Arc::new(vec![path.to_path_buf()]))
+                .finish()
+        }))
+    }
+
+    /// Scan from `start_at` and return the position at which the next scan continues.
+    fn finish_resumable(mut self) -> PolarsResult<(LazyFrame, ScanPosition)> {
+        let start = self.args.start_at.take().unwrap_or_default();
+        let n_rows = self.args.n_rows.take();
+        // The row index is added after the slice, as the offset of the slice can be in any file.
+        let row_index = self.args.row_index.take();
+        let (offset, end) = {
+            let mut file_rows = self.file_rows()?;
+            (file_rows.offset(start)?, file_rows.end(start, n_rows)?)
+        };
+        let mut lf = self.finish()?.slice(
+            offset as i64,
+            n_rows.map_or(IdxSize::MAX, |n_rows| n_rows as IdxSize),
+        );
+        if let Some(row_index) = row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset + offset as IdxSize));
+        }
+        Ok((lf, end))
+    }
+
+    fn scan_reconciled(&self, path: &Path, schema: &Schema) -> PolarsResult<LazyFrame> {
+        let mut reader = self.clone();
+        reader.args.schema = None;
+        reader.args.n_rows = None;
+        reader.args.row_index = None;
+        reader.args.rechunk = false;
+        reader.args.glob = false;
+        reader.args.hive_options.enabled = Some(false);
+        let mut lf = reader
+            .with_paths(Arc::new(vec![path.to_path_buf()]))
+            .finish()?;
+        let file_schema = lf.collect_schema()?;
+        let exprs = self.args.cast_policy.reconcile(&file_schema, schema)?;
+        Ok(lf.select(exprs))
+    }
+}
+
 impl LazyFileListReader for LazyParquetReader {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
+        if self.args.start_at.is_some() {
+            return self.finish_resumable().map(|(lf, _)| lf);
+        }
+        if let Some(schema) = self.args.schema.clone() {
+            return self.finish_reconciled(schema);
+        }
         let row_index = self.args.row_index;
 
         let mut lf: LazyFrame = DslBuilder::scan_parquet(
@@ -71,6 +195,10 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.use_metadata_summary,
+            self.args.prefetch,
+            self.args.large_row_group_bytes,
+            self.args.dictionary_as_categorical,
             self.args.hive_options,
             self.args.glob,
             self.args.include_file_paths,
@@ -144,6 +272,48 @@ impl LazyFrame {
             .finish()
     }
 
+    /// Create a LazyFrame from a parquet scan that starts at `args.start_at` and reads at most
+    /// `args.n_rows` rows, and return the position at which the next scan continues, e.g. to
+    /// read a large dataset in batches and checkpoint the progress.
+    pub fn scan_parquet_resumable(
+        path: impl AsRef<Path>,
+        args: ScanArgsParquet,
+    ) -> PolarsResult<(Self, ScanPosition)> {
+        LazyParquetReader::new(args)
+            .with_paths(Arc::new(vec![path.as_ref().to_path_buf()]))
+            .finish_resumable()
+    }
+
+    /// Create a LazyFrame from a parquet scan of `path` in `store`, e.g. an object store with a
+    /// storage client of the embedding application. The path can contain globs.
+    ///
+    /// The store replaces the url resolution and the cloud configuration of `args`, its other
+    /// cloud options, like the retries, still apply.
+    #[cfg(feature = "cloud")]
+    pub fn scan_parquet_from_store(
+        store: Arc<dyn ObjectStore>,
+        path: &str,
+        mut args: ScanArgsParquet,
+    ) -> PolarsResult<Self> {
+        let cloud_options = args.cloud_options.take().unwrap_or_default();
+        args.cloud_options = Some(cloud_options.with_object_store(store));
+        Self::scan_parquet(user_store_url(path), args)
+    }
+
+    /// Create a LazyFrame from a parquet scan of an async reader, e.g. a stream of a storage
+    /// client that supports seeking. The reads of the scan are serialized.
+    #[cfg(feature = "cloud")]
+    pub fn scan_parquet_from_reader<R>(reader: R, args: ScanArgsParquet) -> PolarsResult<Self>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin + Send + 'static,
+    {
+        let args = ScanArgsParquet {
+            glob: false,
+            ..args
+        };
+        Self::scan_parquet_from_store(Arc::new(AsyncReaderStore::new(reader)), "reader", args)
+    }
+
     /// Create a LazyFrame directly from a parquet scan.
     pub fn scan_parquet_files(
         paths: Arc<Vec<PathBuf>>,
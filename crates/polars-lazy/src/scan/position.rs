@@ -0,0 +1,105 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A position in a scan of multiple files: row `row` of the `file`-th file, in the order in which
+/// the files are scanned.
+///
+/// A scan can be started at a position with the `start_at` option of its arguments, and the
+/// resumable scans, like [`LazyFrame::scan_parquet_resumable`], return the position at which the
+/// next scan continues. A `file` equal to the number of files is the end of the scan.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScanPosition {
+    pub file: usize,
+    pub row: usize,
+}
+
+impl ScanPosition {
+    pub fn new(file: usize, row: usize) -> Self {
+        Self { file, row }
+    }
+}
+
+/// The number of rows of the files of a scan, which are counted when they are first needed.
+pub(super) struct FileRows<F> {
+    paths: Arc<Vec<PathBuf>>,
+    /// Scan of a single file of the scan.
+    scan_file: F,
+    counts: Vec<Option<usize>>,
+}
+
+impl<F> FileRows<F>
+where
+    F: Fn(&Path) -> PolarsResult<LazyFrame>,
+{
+    pub(super) fn new(paths: Arc<Vec<PathBuf>>, scan_file: F) -> Self {
+        let counts = vec![None; paths.len()];
+        Self {
+            paths,
+            scan_file,
+            counts,
+        }
+    }
+
+    fn count(&mut self, file: usize) -> PolarsResult<usize> {
+        if let Some(count) = self.counts[file] {
+            return Ok(count);
+        }
+        let path = &self.paths[file];
+        let df = (self.scan_file)(path)?.select([len()]).collect()?;
+        let count = df.get_columns()[0]
+            .idx()?
+            .get(0)
+            .map_or(0, |count| count as usize);
+        self.counts[file] = Some(count);
+        Ok(count)
+    }
+
+    /// The row offset of `start` in the concatenation of all files.
+    pub(super) fn offset(&mut self, start: ScanPosition) -> PolarsResult<usize> {
+        let n_files = self.paths.len();
+        polars_ensure!(
+            start.file < n_files || (start.file == n_files && start.row == 0),
+            OutOfBounds: "cannot start a scan of {} files at row {} of file {}",
+            n_files, start.row, start.file
+        );
+        let mut offset = 0;
+        for file in 0..start.file {
+            offset += self.count(file)?;
+        }
+        if start.file < n_files {
+            let count = self.count(start.file)?;
+            polars_ensure!(
+                start.row <= count,
+                OutOfBounds: "cannot start a scan at row {} of {}, which has {} rows",
+                start.row, self.paths[start.file].display(), count
+            );
+        }
+        Ok(offset + start.row)
+    }
+
+    /// The position after reading `n_rows` rows from `start`, or the end of the scan if all rows
+    /// are read. `start` must be valid.
+    pub(super) fn end(
+        &mut self,
+        start: ScanPosition,
+        n_rows: Option<usize>,
+    ) -> PolarsResult<ScanPosition> {
+        let n_files = self.paths.len();
+        let Some(mut remaining) = n_rows else {
+            return Ok(ScanPosition::new(n_files, 0));
+        };
+        let mut row = start.row;
+        for file in start.file..n_files {
+            let available = self.count(file)? - row;
+            if remaining < available {
+                return Ok(ScanPosition::new(file, row + remaining));
+            }
+            remaining -= available;
+            row = 0;
+        }
+        Ok(ScanPosition::new(n_files, 0))
+    }
+}
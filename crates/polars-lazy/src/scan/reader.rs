@@ -0,0 +1,459 @@
+//! Scans that parse their input in batches while it is read: CSV and NDJSON from a reader that
+//! can only be read once, e.g. stdin, see [`LazyCsvReader::finish_with_reader`] and
+//! [`LazyJsonLineReader::finish_with_reader`], and compressed CSV and NDJSON files, which are
+//! decompressed as they are parsed instead of being inflated in memory first.
+use std::any::Any;
+use std::io::{BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+#[cfg(feature = "csv")]
+use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "json")]
+use polars_io::ndjson::core::JsonLineReader;
+use polars_io::utils::{is_compressed, maybe_decompress_reader};
+#[cfg(feature = "json")]
+use polars_io::SerReader;
+#[cfg(feature = "json")]
+use polars_plan::prelude::NDJsonReadOptions;
+use polars_utils::open_file;
+
+use crate::prelude::*;
+
+/// The number of bytes that are read from the input for a batch.
+const BATCH_SIZE: usize = 1 << 22;
+
+/// The row based formats a [`ReaderScan`] can parse.
+trait RowFormat: Send + Sync + 'static {
+    /// The position after the last complete row in `bytes`, 0 if there is none.
+    fn last_row_end(&self, bytes: &[u8]) -> usize;
+
+    /// Parse the first rows of the input, which decide the schema of the scan.
+    fn parse_first(&self, rows: Vec<u8>) -> PolarsResult<DataFrame>;
+
+    /// Parse rows with the schema of the scan. `is_first` is set for the first rows of the input,
+    /// which may contain a header.
+    fn parse(
+        &self,
+        rows: Vec<u8>,
+        is_first: bool,
+        schema: &SchemaRef,
+        args: &AnonymousScanArgs,
+    ) -> PolarsResult<DataFrame>;
+}
+
+#[cfg(feature = "csv")]
+impl RowFormat for CsvReadOptions {
+    fn last_row_end(&self, bytes: &[u8]) -> usize {
+        let parse_options = self.get_parse_options();
+        let mut in_quotes = false;
+        let mut end = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if Some(b) == parse_options.escape_char {
+                // The escaped byte is literal, also if it is a quote or end-of-line character.
+                i += 1;
+            } else if Some(b) == parse_options.quote_char {
+                in_quotes = !in_quotes;
+            } else if b == parse_options.eol_char && !in_quotes {
+                end = i + 1;
+            }
+            i += 1;
+        }
+        end
+    }
+
+    fn parse_first(&self, rows: Vec<u8>) -> PolarsResult<DataFrame> {
+        self.clone()
+            .with_rechunk(false)
+            .with_n_rows(None)
+            .with_row_index(None)
+            .with_columns(None)
+            .into_reader_with_file_handle(Cursor::new(rows))
+            .finish()
+    }
+
+    fn parse(
+        &self,
+        rows: Vec<u8>,
+        is_first: bool,
+        schema: &SchemaRef,
+        args: &AnonymousScanArgs,
+    ) -> PolarsResult<DataFrame> {
+        let mut options = self.clone();
+        if !is_first {
+            options = options
+                .with_has_header(false)
+                .with_skip_rows(0)
+                .with_skip_rows_after_header(0);
+        }
+        options
+            .with_schema(Some(schema.clone()))
+            .with_schema_overwrite(None)
+            .with_dtype_overwrite(None)
+            .with_rechunk(false)
+            .with_n_rows(args.n_rows)
+            .with_row_index(None)
+            .with_columns(args.with_columns.clone())
+            .into_reader_with_file_handle(Cursor::new(rows))
+            .finish()
+    }
+}
+
+#[cfg(feature = "json")]
+impl RowFormat for NDJsonReadOptions {
+    fn last_row_end(&self, bytes: &[u8]) -> usize {
+        memchr::memrchr(b'\n', bytes).map_or(0, |i| i + 1)
+    }
+
+    fn parse_first(&self, rows: Vec<u8>) -> PolarsResult<DataFrame> {
+        let mut reader = JsonLineReader::new(Cursor::new(rows))
+            .infer_schema_len(self.infer_schema_length)
+            .with_union_resolution(self.union_resolution)
+            .with_chunk_size(Some(self.chunk_size))
+            .low_memory(self.low_memory)
+            .with_ignore_errors(self.ignore_errors)
+            .with_rechunk(false);
+        if let Some(schema) = &self.schema {
+            reader = reader.with_schema(schema.clone());
+        }
+        if let Some(schema_overwrite) = &self.schema_overwrite {
+            reader = reader.with_schema_overwrite(schema_overwrite);
+        }
+        reader.finish()
+    }
+
+    fn parse(
+        &self,
+        rows: Vec<u8>,
+        _is_first: bool,
+        schema: &SchemaRef,
+        args: &AnonymousScanArgs,
+    ) -> PolarsResult<DataFrame> {
+        let mut reader = JsonLineReader::new(Cursor::new(rows));
+        // The schema was inferred from the first rows, see `JsonExec`.
+        if self.schema.is_none() {
+            reader = reader.with_union_resolution(self.union_resolution);
+        }
+        reader
+            .with_schema(schema.clone())
+            .with_chunk_size(Some(self.chunk_size))
+            .low_memory(self.low_memory)
+            .with_ignore_errors(self.ignore_errors)
+            .with_rechunk(false)
+            .with_n_rows(args.n_rows)
+            .with_projection(args.with_columns.clone())
+            .finish()
+    }
+}
+
+/// Where a [`ReaderScan`] reads its input from.
+enum ReaderSource {
+    /// A reader that can only be read once.
+    Once(Mutex<Option<Box<dyn Read + Send>>>),
+    /// A file that is opened and decompressed anew for every pass.
+    File(PathBuf),
+}
+
+impl ReaderSource {
+    fn open(&self) -> PolarsResult<Box<dyn Read + Send>> {
+        match self {
+            Self::Once(reader) => reader.lock().unwrap().take().ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "the reader of this scan is exhausted, it can only be collected once"
+                )
+            }),
+            Self::File(path) => maybe_decompress_reader(BufReader::new(open_file(path)?)),
+        }
+    }
+}
+
+/// A pass over the input of a [`ReaderScan`]. Every query, and every streaming source of a
+/// query, has its own pass.
+struct ReaderState {
+    reader: Box<dyn Read + Send>,
+    /// Bytes that are read but not parsed yet. They start at the beginning of a row.
+    pending: Vec<u8>,
+    eof: bool,
+    /// Whether the first rows of the input are parsed yet.
+    started: bool,
+    /// The first rows of the input, which are parsed when the scan is created to infer the schema.
+    first: Option<DataFrame>,
+}
+
+impl ReaderState {
+    fn new(reader: Box<dyn Read + Send>) -> Self {
+        Self {
+            reader,
+            pending: vec![],
+            eof: false,
+            started: false,
+            first: None,
+        }
+    }
+
+    /// Read until at least one complete row is buffered, and split off all complete rows.
+    /// Returns `None` once the input is exhausted.
+    fn next_rows(&mut self, format: &impl RowFormat) -> PolarsResult<Option<Vec<u8>>> {
+        loop {
+            if !self.eof {
+                let n = (&mut self.reader)
+                    .take(BATCH_SIZE as u64)
+                    .read_to_end(&mut self.pending)?;
+                self.eof = n == 0;
+            }
+            let end = if self.eof {
+                self.pending.len()
+            } else {
+                format.last_row_end(&self.pending)
+            };
+            if end > 0 {
+                let rest = self.pending.split_off(end);
+                return Ok(Some(std::mem::replace(&mut self.pending, rest)));
+            } else if self.eof {
+                return Ok(None);
+            }
+        }
+    }
+
+    fn next_batch(
+        &mut self,
+        format: &impl RowFormat,
+        schema: &SchemaRef,
+        args: &AnonymousScanArgs,
+    ) -> PolarsResult<Option<DataFrame>> {
+        if let Some(mut df) = self.first.take() {
+            if let Some(columns) = &args.with_columns {
+                df = df.select(columns.iter().cloned())?;
+            }
+            if let Some(n_rows) = args.n_rows {
+                df = df.head(Some(n_rows));
+            }
+            return Ok(Some(df));
+        }
+        let Some(rows) = self.next_rows(format)? else {
+            return Ok(None);
+        };
+        let is_first = !std::mem::replace(&mut self.started, true);
+        format.parse(rows, is_first, schema, args).map(Some)
+    }
+}
+
+/// The batches of a pass over the input of a [`ReaderScan`], until `args.n_rows` rows are
+/// parsed, the input is exhausted or a batch fails.
+struct ReaderBatches<F: RowFormat> {
+    format: Arc<F>,
+    schema: SchemaRef,
+    state: ReaderState,
+    args: AnonymousScanArgs,
+    done: bool,
+}
+
+impl<F: RowFormat> Iterator for ReaderBatches<F> {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.args.n_rows == Some(0) {
+            return None;
+        }
+        match self
+            .state
+            .next_batch(self.format.as_ref(), &self.schema, &self.args)
+        {
+            Ok(Some(df)) => {
+                self.args.n_rows = self.args.n_rows.map(|n| n.saturating_sub(df.height()));
+                Some(Ok(df))
+            },
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            },
+        }
+    }
+}
+
+/// Parses the rows of its input in batches of about [`BATCH_SIZE`] bytes. Every batch is parsed
+/// in parallel by the reader of the format.
+struct ReaderScan<F: RowFormat> {
+    format: Arc<F>,
+    source: ReaderSource,
+    schema: SchemaRef,
+    /// The pass that inferred the schema, which is continued by the first query.
+    first_pass: Mutex<Option<ReaderState>>,
+}
+
+impl<F: RowFormat> ReaderScan<F> {
+    fn new(format: F, source: ReaderSource) -> PolarsResult<Self> {
+        let mut state = ReaderState::new(source.open()?);
+        let rows = state.next_rows(&format)?.unwrap_or_default();
+        let first = format.parse_first(rows)?;
+        let schema = Arc::new(first.schema());
+        state.started = true;
+        state.first = Some(first);
+
+        Ok(Self {
+            format: Arc::new(format),
+            source,
+            schema,
+            first_pass: Mutex::new(Some(state)),
+        })
+    }
+
+    /// Start a new pass over the input.
+    fn pass(&self, args: AnonymousScanArgs) -> PolarsResult<ReaderBatches<F>> {
+        let first_pass = self.first_pass.lock().unwrap().take();
+        let state = match first_pass {
+            Some(state) => state,
+            None => ReaderState::new(self.source.open()?),
+        };
+        Ok(ReaderBatches {
+            format: self.format.clone(),
+            schema: self.schema.clone(),
+            state,
+            args,
+            done: false,
+        })
+    }
+}
+
+impl<F: RowFormat> AnonymousScan for ReaderScan<F> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut out = DataFrame::empty_with_schema(
+            scan_opts
+                .output_schema
+                .as_deref()
+                .unwrap_or(self.schema.as_ref()),
+        );
+        for df in self.pass(scan_opts)? {
+            out.vstack_mut(&df?)?;
+        }
+        Ok(out)
+    }
+
+    fn batches(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<AnonymousScanBatches>> {
+        Ok(Some(Box::new(self.pass(scan_opts)?)))
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn is_streamable(&self) -> bool {
+        true
+    }
+}
+
+fn scan_reader_source<F: RowFormat>(
+    format: F,
+    source: ReaderSource,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    name: &'static str,
+) -> PolarsResult<LazyFrame> {
+    let function = Arc::new(ReaderScan::new(format, source)?);
+    let args = ScanArgsAnonymous {
+        schema: Some(function.schema.clone()),
+        n_rows,
+        row_index,
+        name,
+        ..Default::default()
+    };
+    LazyFrame::anonymous_scan(function, args)
+}
+
+/// The path of a scan of a single local file whose contents are compressed. Such files are
+/// scanned with [`scan_compressed_csv`] or [`scan_compressed_ndjson`].
+pub(super) fn compressed_local_file(paths: &[PathBuf]) -> PolarsResult<Option<&Path>> {
+    let [path] = paths else {
+        return Ok(None);
+    };
+    if polars_io::path_utils::is_cloud_url(path) || !path.is_file() {
+        return Ok(None);
+    }
+    let mut magic = Vec::with_capacity(4);
+    open_file(path)?.take(4).read_to_end(&mut magic)?;
+    Ok((magic.len() >= 4 && is_compressed(&magic)).then_some(path.as_path()))
+}
+
+/// Get a [`LazyFrame`] that reads the CSV from `reader` with `options`, see
+/// [`LazyCsvReader::finish_with_reader`].
+#[cfg(feature = "csv")]
+pub(super) fn scan_csv_reader(
+    options: CsvReadOptions,
+    reader: Box<dyn Read + Send>,
+) -> PolarsResult<LazyFrame> {
+    let n_rows = options.n_rows;
+    let row_index = options.row_index.clone();
+    let source = ReaderSource::Once(Mutex::new(Some(reader)));
+    scan_reader_source(options, source, n_rows, row_index, "CSV READER SCAN")
+}
+
+/// Get a [`LazyFrame`] that decompresses the CSV file at `path` while it is parsed.
+#[cfg(feature = "csv")]
+pub(super) fn scan_compressed_csv(options: CsvReadOptions, path: &Path) -> PolarsResult<LazyFrame> {
+    let n_rows = options.n_rows;
+    let row_index = options.row_index.clone();
+    let source = ReaderSource::File(path.to_path_buf());
+    scan_reader_source(options, source, n_rows, row_index, "COMPRESSED CSV SCAN")
+}
+
+/// Get a [`LazyFrame`] that reads the NDJSON from `reader` with `options`, see
+/// [`LazyJsonLineReader::finish_with_reader`].
+#[cfg(feature = "json")]
+pub(super) fn scan_ndjson_reader(
+    options: NDJsonReadOptions,
+    reader: Box<dyn Read + Send>,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+) -> PolarsResult<LazyFrame> {
+    let source = ReaderSource::Once(Mutex::new(Some(reader)));
+    scan_reader_source(options, source, n_rows, row_index, "NDJSON READER SCAN")
+}
+
+/// Get a [`LazyFrame`] that decompresses the NDJSON file at `path` while it is parsed.
+#[cfg(feature = "json")]
+pub(super) fn scan_compressed_ndjson(
+    options: NDJsonReadOptions,
+    path: &Path,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+) -> PolarsResult<LazyFrame> {
+    let source = ReaderSource::File(path.to_path_buf());
+    scan_reader_source(options, source, n_rows, row_index, "COMPRESSED NDJSON SCAN")
+}
+
+#[cfg(feature = "csv")]
+impl LazyFrame {
+    /// Scan CSV from `reader` with the default options, see
+    /// [`LazyCsvReader::finish_with_reader`].
+    pub fn scan_csv_from_reader(reader: impl Read + Send + 'static) -> PolarsResult<Self> {
+        LazyCsvReader::new("").finish_with_reader(reader)
+    }
+}
+
+#[cfg(feature = "json")]
+impl LazyFrame {
+    /// Scan NDJSON from `reader` with the default options, see
+    /// [`LazyJsonLineReader::finish_with_reader`].
+    pub fn scan_ndjson_from_reader(reader: impl Read + Send + 'static) -> PolarsResult<Self> {
+        LazyJsonLineReader::new("").finish_with_reader(reader)
+    }
+}
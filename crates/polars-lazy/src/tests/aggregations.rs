@@ -571,3 +571,52 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "funnel")]
+fn test_window_funnel_and_retention() -> PolarsResult<()> {
+    use polars_time::Duration;
+
+    let df = df![
+        "user" => [1, 1, 1, 2, 2, 3],
+        "minute" => [0i64, 10, 50, 5, 100, 20],
+        "event" => ["view", "cart", "buy", "view", "cart", "cart"],
+    ]?
+    .lazy()
+    .with_column(
+        (col("minute") * lit(60_000))
+            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+            .alias("time"),
+    );
+
+    let steps = ["view", "cart", "buy"]
+        .map(|step| col("event").eq(lit(step)))
+        .to_vec();
+    let out = df
+        .clone()
+        .group_by_stable([col("user")])
+        .agg([window_funnel(col("time"), steps, Duration::parse("1h")).alias("level")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("level")?.u32()?),
+        &[Some(3), Some(1), Some(0)]
+    );
+
+    let out = df
+        .select([retention_matrix(
+            col("user"),
+            col("time"),
+            Duration::parse("1h"),
+            2,
+        )])
+        .collect()?;
+    let out = out.column("user")?.struct_()?.clone().unnest();
+    assert_eq!(out.height(), 1);
+    assert_eq!(
+        out.column("cohort")?.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+    assert_eq!(Vec::from(out.column("period_0")?.u32()?), &[Some(3)]);
+    assert_eq!(Vec::from(out.column("period_1")?.u32()?), &[Some(1)]);
+    Ok(())
+}
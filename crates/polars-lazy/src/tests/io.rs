@@ -36,6 +36,62 @@ fn test_parquet_exec() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_scan_cast_policy() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_scan_cast_policy");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let mut a = df![
+        "id" => [1i32, 2],
+        "name" => ["a", "b"],
+        "extra" => [true, false],
+    ]?;
+    let mut b = df![
+        "ID" => [3i64],
+        "score" => ["2.5"],
+    ]?;
+    ParquetWriter::new(std::fs::File::create(dir.join("a.parquet"))?).finish(&mut a)?;
+    ParquetWriter::new(std::fs::File::create(dir.join("b.parquet"))?).finish(&mut b)?;
+
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("id".into(), DataType::Int64),
+        Field::new("name".into(), DataType::String),
+        Field::new("score".into(), DataType::Float64),
+    ]));
+    let mut args = ScanArgsParquet {
+        schema: Some(schema.clone()),
+        ..Default::default()
+    };
+    args.cast_policy.aliases.insert("ID".into(), "id".into());
+    args.cast_policy.defaults.insert("score".into(), lit(1.5));
+    let glob = dir.join("*.parquet");
+
+    let df = LazyFrame::scan_parquet(&glob, args.clone())?.collect()?;
+    assert_eq!(df.schema(), *schema);
+    let expected = df![
+        "id" => [1i64, 2, 3],
+        "name" => [Some("a"), Some("b"), None],
+        "score" => [1.5, 1.5, 2.5],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // Strings can't be cast to floats without a cast.
+    let mut strict = args.clone();
+    strict
+        .cast_policy
+        .dtype_casts
+        .insert(DataType::Float64, None);
+    let err = LazyFrame::scan_parquet(&glob, strict).err().unwrap();
+    assert!(err.to_string().contains("has dtype str, expected f64"));
+
+    let mut required = args;
+    required.cast_policy.allow_missing = false;
+    let err = LazyFrame::scan_parquet(&glob, required).err().unwrap();
+    assert!(err.to_string().contains("'name' is missing"));
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "parquet", feature = "is_between"))]
 fn test_parquet_statistics_no_skip() {
@@ -110,6 +166,79 @@ fn test_parquet_statistics_no_skip() {
     assert_eq!(out.shape(), (27, 4));
 }
 
+#[test]
+#[cfg(all(feature = "parquet", feature = "cloud"))]
+fn test_parquet_estimate_scan_cost() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    use polars_io::cloud::CloudType;
+
+    let estimate = |lf: LazyFrame| {
+        let costs = lf.estimate_scan_cost().unwrap();
+        assert_eq!(costs.len(), 1);
+        assert_eq!(costs[0].0, CloudType::File);
+        costs[0].1
+    };
+
+    // The metadata of the first file is read when the scan is created.
+    let all = estimate(scan_foods_parquet(true));
+    assert_eq!(
+        (all.n_files, all.get_requests, all.head_requests),
+        (1, 1, 0)
+    );
+    assert!(all.bytes > 0);
+
+    let projected = estimate(scan_foods_parquet(true).select([col("category")]));
+    assert_eq!(projected.get_requests, 1);
+    assert!(projected.bytes < all.bytes);
+
+    // The statistics rule out the only row group.
+    let filtered = estimate(scan_foods_parquet(true).filter(col("category").eq(lit("zzz"))));
+    assert_eq!(
+        (filtered.n_files, filtered.get_requests, filtered.bytes),
+        (1, 0, 0)
+    );
+
+    let twice = estimate(concat(
+        [scan_foods_parquet(true), scan_foods_parquet(true)],
+        Default::default(),
+    )?);
+    assert_eq!(twice.n_files, 2);
+    assert_eq!(twice.bytes, 2 * all.bytes);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "cloud"))]
+fn test_scan_parquet_from_reader() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    use polars_io::cloud::AsyncReaderStore;
+
+    let expected = scan_foods_parquet(false).collect()?;
+    let bytes = std::fs::read(FOODS_PARQUET)?;
+
+    let out = LazyFrame::scan_parquet_from_reader(
+        std::io::Cursor::new(bytes.clone()),
+        Default::default(),
+    )?
+    .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let store = Arc::new(AsyncReaderStore::new(std::io::Cursor::new(bytes)));
+    let out = LazyFrame::scan_parquet_from_store(store, "foods1.parquet", Default::default())?
+        .filter(col("category").eq(lit("seafood")))
+        .collect()?;
+    assert_eq!(out.height(), 8);
+
+    // Urls of a user store need the store.
+    let err = LazyFrame::scan_parquet(
+        polars_io::cloud::user_store_url("foods1.parquet"),
+        Default::default(),
+    )
+    .and_then(|lf| lf.collect());
+    assert!(err.is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "parquet", feature = "is_between"))]
 fn test_parquet_statistics() -> PolarsResult<()> {
@@ -421,6 +550,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            start_at: None,
         },
     )?
     .collect()?;
@@ -476,6 +606,83 @@ fn test_csv_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_scan_csv_escape_char() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_escape_char.csv");
+    std::fs::write(&path, "a;b\x1e1;x\\;y\x1e2;z\\\x1ew\x1e")?;
+
+    let lf = LazyCsvReader::new(&path)
+        .with_separator(b';')
+        .with_eol_char(b'\x1e')
+        .with_escape_char(Some(b'\\'))
+        .finish()?;
+    let out = lf.clone().collect()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => ["x;y", "z\x1ew"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let count = lf.select([len()]).collect()?;
+    assert_eq!(count.column("len")?.idx()?.get(0), Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_multi_byte_separator() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_multi_byte_separator.csv");
+    std::fs::write(&path, "a||b||c\n1||x,y||1.5\n2||\"z||w\"||2.5\n")?;
+
+    let lf = LazyCsvReader::new(&path)
+        .with_multi_byte_separator(Some("||".into()))
+        .finish()?;
+    let out = lf.clone().select([col("b"), col("c")]).collect()?;
+    let expected = df![
+        "b" => ["x,y", "z||w"],
+        "c" => [1.5, 2.5],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let count = lf.select([len()]).collect()?;
+    assert_eq!(count.column("len")?.idx()?.get(0), Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_on_error() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_on_error.csv");
+    std::fs::write(&path, "a,b\n1,2\n3,x\n5,6\n7,8,9\n")?;
+
+    let scan = |on_error| {
+        LazyCsvReader::new(&path)
+            .with_infer_schema_length(Some(1))
+            .with_on_error(on_error)
+            .finish()
+    };
+    assert!(scan(CsvOnError::Raise)?.collect().is_err());
+
+    let out = scan(CsvOnError::Skip)?.collect()?;
+    let expected = df![
+        "a" => [1i64, 5],
+        "b" => [2i64, 6],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Rows with a malformed field are skipped even if the field isn't projected.
+    let out = scan(CsvOnError::Skip)?
+        .select([col("a")])
+        .with_streaming(true)
+        .collect()?;
+    assert!(out.equals(&expected.select(["a"])?));
+
+    // The skipped rows aren't counted.
+    let count = scan(CsvOnError::Skip)?.select([len()]).collect()?;
+    assert_eq!(count.column("len")?.idx()?.get(0), Some(2));
+
+    assert!(scan(CsvOnError::Capture)?.collect().is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "json")]
 fn test_ndjson_globbing() -> PolarsResult<()> {
@@ -712,3 +919,291 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "substrait")]
+fn test_substrait_roundtrip() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let roundtrip = |lf: LazyFrame| -> PolarsResult<()> {
+        let json = lf.clone().to_substrait_json()?;
+        let consumed = LazyFrame::from_substrait_json(&json, &PlHashMap::new())?;
+        assert!(lf.collect()?.equals_missing(&consumed.collect()?));
+        Ok(())
+    };
+
+    let foods = || scan_foods_parquet(false);
+    roundtrip(
+        foods()
+            .filter(
+                col("calories")
+                    .gt(lit(50))
+                    .and(col("sugars_g").lt_eq(lit(10))),
+            )
+            .with_columns([(col("fats_g") * lit(2.0)).alias("double_fats")])
+            .select([col("category"), col("double_fats"), col("calories")])
+            .sort(["calories", "category"], Default::default())
+            .slice(2, 10),
+    )?;
+    roundtrip(
+        foods()
+            .group_by([col("category")])
+            .agg([
+                col("calories").sum(),
+                col("fats_g").mean().alias("mean_fats"),
+                col("sugars_g").max(),
+            ])
+            .sort(["category"], Default::default()),
+    )?;
+    roundtrip(
+        foods()
+            .select([col("category"), col("calories")])
+            .join(
+                foods()
+                    .group_by([col("category")])
+                    .agg([col("fats_g").min()]),
+                [col("category")],
+                [col("category")],
+                JoinType::Inner.into(),
+            )
+            .sort(["calories", "category"], Default::default()),
+    )?;
+
+    let err = df!["a" => [1, 2]]?.lazy().to_substrait_json().unwrap_err();
+    assert!(err.to_string().contains("in-memory DataFrames"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "substrait")]
+fn test_substrait_named_tables() -> PolarsResult<()> {
+    let json = r#"{
+        "extensionUris": [{"extensionUriAnchor": 1, "uri": "functions_comparison.yaml"}],
+        "extensions": [{"extensionFunction": {"extensionUriReference": 1, "functionAnchor": 7, "name": "gt:any_any"}}],
+        "relations": [{"root": {
+            "input": {"project": {
+                "common": {"emit": {"outputMapping": [1, 2]}},
+                "input": {"filter": {
+                    "input": {"read": {
+                        "baseSchema": {"names": ["a", "b"], "struct": {"types": [{"i64": {}}, {"string": {}}]}},
+                        "namedTable": {"names": ["db", "t"]}
+                    }},
+                    "condition": {"scalarFunction": {
+                        "functionReference": 7,
+                        "arguments": [
+                            {"value": {"selection": {"directReference": {"structField": {}}, "rootReference": {}}}},
+                            {"value": {"literal": {"i64": "1"}}}
+                        ]
+                    }}
+                }},
+                "expressions": [{"selection": {"directReference": {"structField": {}}, "rootReference": {}}}]
+            }},
+            "names": ["b", "a2"]
+        }}]
+    }"#;
+    let mut tables = PlHashMap::new();
+    tables.insert(
+        "db.t".to_string(),
+        df!["a" => [1i64, 2, 3], "b" => ["x", "y", "z"], "c" => [true, false, true]]?.lazy(),
+    );
+    let out = LazyFrame::from_substrait_json(json, &tables)?.collect()?;
+    let expected = df!["b" => ["y", "z"], "a2" => [2i64, 3]]?;
+    assert!(out.equals(&expected));
+
+    let err = LazyFrame::from_substrait_json(json, &PlHashMap::new())
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("unknown Substrait table 'db.t'"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_scan_csv_from_reader() -> PolarsResult<()> {
+    // More than one batch, with a quoted line break in every row.
+    let n = 200_000;
+    let mut csv = String::from("id,text\n");
+    for i in 0..n {
+        csv.push_str(&format!("{i},\"line\nbreak {}\"\n", i % 7));
+    }
+    let reader = || std::io::Cursor::new(csv.clone().into_bytes());
+
+    let out = LazyFrame::scan_csv_from_reader(reader())?.collect()?;
+    assert_eq!(out.shape(), (n, 2));
+    assert_eq!(out.column("id")?.i64()?.get(n - 1), Some(n as i64 - 1));
+    assert_eq!(out.column("text")?.str()?.get(1), Some("line\nbreak 1"));
+
+    let lf = LazyFrame::scan_csv_from_reader(reader())?
+        .select([col("id").sum()])
+        .with_streaming(true);
+    let out = lf.clone().collect()?;
+    assert_eq!(
+        out.column("id")?.i64()?.get(0),
+        Some((0..n as i64).sum::<i64>())
+    );
+    // The reader is consumed by the first query.
+    assert!(lf.collect().is_err());
+
+    let out = LazyFrame::scan_csv_from_reader(reader())?
+        .select([col("text")])
+        .limit(3)
+        .collect()?;
+    assert_eq!(out.shape(), (3, 1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_scan_csv_from_reader_with_escape_char() -> PolarsResult<()> {
+    // More than one batch, with an escaped quote and an escaped line break in every row.
+    let n = 300_000;
+    let mut csv = String::from("id,quoted,text\n");
+    for i in 0..n {
+        csv.push_str(&format!("{i},\"say \\\"{}\",line\\\nbreak\n", i % 7));
+    }
+
+    let out = LazyCsvReader::new("")
+        .with_escape_char(Some(b'\\'))
+        .finish_with_reader(std::io::Cursor::new(csv.into_bytes()))?
+        .collect()?;
+    assert_eq!(out.shape(), (n, 3));
+    assert_eq!(out.column("id")?.i64()?.get(n - 1), Some(n as i64 - 1));
+    assert_eq!(out.column("quoted")?.str()?.get(1), Some("say \"1"));
+    assert_eq!(out.column("text")?.str()?.get(n - 1), Some("line\nbreak"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson_from_reader() -> PolarsResult<()> {
+    // More than one batch.
+    let n = 200_000;
+    let mut json = String::new();
+    for i in 0..n {
+        json.push_str(&format!("{{\"id\":{i},\"text\":\"row {}\"}}\n", i % 7));
+    }
+    let reader = || std::io::Cursor::new(json.clone().into_bytes());
+
+    let out = LazyFrame::scan_ndjson_from_reader(reader())?.collect()?;
+    assert_eq!(out.shape(), (n, 2));
+    assert_eq!(out.column("id")?.i64()?.get(n - 1), Some(n as i64 - 1));
+    assert_eq!(out.column("text")?.str()?.get(1), Some("row 1"));
+
+    let lf = LazyJsonLineReader::new("-")
+        .with_n_rows(Some(5))
+        .finish_with_reader(reader())?
+        .select([col("id").sum()])
+        .with_streaming(true);
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("id")?.i64()?.get(0), Some(10));
+    // The reader is consumed by the first query.
+    assert!(lf.collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "json"))]
+fn test_scan_multi_member_gzip() -> PolarsResult<()> {
+    use std::io::Write;
+
+    // Write every part as its own gzip member, like bgzf does.
+    let write_gzip = |path: &std::path::Path, parts: &[String]| -> PolarsResult<()> {
+        let mut file = std::fs::File::create(path)?;
+        for part in parts {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+            encoder.write_all(part.as_bytes())?;
+            file.write_all(&encoder.finish()?)?;
+        }
+        Ok(())
+    };
+    let n = 300_000;
+    let dir = std::env::temp_dir();
+
+    let csv_path = dir.join("polars_test_scan_multi_member_gzip.csv.gz");
+    let mut parts = vec!["id,text\n".to_string()];
+    parts.extend((0..n).step_by(100_000).map(|start| {
+        (start..start + 100_000)
+            .map(|i| format!("{i},\"row {i}\"\n"))
+            .collect::<String>()
+    }));
+    write_gzip(&csv_path, &parts)?;
+
+    let lf = LazyCsvReader::new(&csv_path).finish()?;
+    let out = lf.clone().collect()?;
+    assert_eq!(out.shape(), (n, 2));
+    assert_eq!(out.column("text")?.str()?.get(n - 1), Some("row 299999"));
+    // The file is decompressed again for every query.
+    let out = lf
+        .select([col("id").sum()])
+        .with_streaming(true)
+        .collect()?;
+    assert_eq!(
+        out.column("id")?.i64()?.get(0),
+        Some((0..n as i64).sum::<i64>())
+    );
+
+    let json_path = dir.join("polars_test_scan_multi_member_gzip.ndjson.gz");
+    let parts = (0..n).step_by(100_000).map(|start| {
+        (start..start + 100_000)
+            .map(|i| format!("{{\"id\":{i},\"text\":\"row {i}\"}}\n"))
+            .collect::<String>()
+    });
+    write_gzip(&json_path, &parts.collect::<Vec<_>>())?;
+
+    let out = LazyJsonLineReader::new(&json_path)
+        .finish()?
+        .select([col("id")])
+        .tail(1)
+        .collect()?;
+    assert_eq!(out.column("id")?.i64()?.get(0), Some(n as i64 - 1));
+
+    std::fs::remove_file(csv_path)?;
+    std::fs::remove_file(json_path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_scan_compressed_csv_passes() -> PolarsResult<()> {
+    use std::io::Write;
+
+    // More rows than fit in one batch.
+    let n = 500_000;
+    let path = std::env::temp_dir().join("polars_test_scan_compressed_csv_passes.csv.gz");
+    let mut encoder =
+        flate2::write::GzEncoder::new(std::fs::File::create(&path)?, flate2::Compression::fast());
+    writeln!(encoder, "id,text")?;
+    for i in 0..n {
+        writeln!(encoder, "{i},\"row {i}\"")?;
+    }
+    encoder.finish()?;
+
+    let lf = LazyCsvReader::new(&path).finish()?;
+    let sum = Some((0..n as i64).sum::<i64>());
+
+    // A pass that is stopped by the sink before the input is exhausted doesn't affect the next
+    // queries.
+    let out = lf
+        .clone()
+        .filter(col("id").gt(lit(10)))
+        .limit(5)
+        .with_streaming(true)
+        .collect()?;
+    assert_eq!(out.column("id")?.i64()?.get(0), Some(11));
+    let out = lf.clone().collect()?;
+    assert_eq!(out.shape(), (n, 2));
+    assert_eq!(out.column("id")?.i64()?.get(0), Some(0));
+
+    // Every input of a self-union has its own pass.
+    for streaming in [false, true] {
+        let out = concat([lf.clone(), lf.clone()], Default::default())?
+            .select([col("id").sum(), col("id").len().alias("len")])
+            .with_streaming(streaming)
+            .collect()?;
+        assert_eq!(out.column("id")?.i64()?.get(0), sum.map(|sum| 2 * sum));
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(2 * n as IdxSize));
+    }
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
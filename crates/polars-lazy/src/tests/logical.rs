@@ -51,6 +51,87 @@ fn test_duration() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "strings", feature = "temporal", feature = "dtype-duration"))]
+fn test_duration_to_string_and_parse() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("1h 30m"), Some("PT2H"), Some("02:30:00"), Some("-1d 2h 3m 4.5s"), None, Some("foo")],
+    ]?;
+    let options = DurationParseOptions {
+        time_unit: TimeUnit::Milliseconds,
+        strict: false,
+        ..Default::default()
+    };
+
+    let out = df
+        .lazy()
+        .select([col("s").str().to_duration(options)])
+        .with_columns([
+            col("s").dt().to_string("iso").alias("iso"),
+            col("s").dt().to_string("humanized").alias("humanized"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        out.column("s")?.dtype(),
+        &DataType::Duration(TimeUnit::Milliseconds)
+    );
+    let iso = out.column("iso")?.str()?;
+    assert_eq!(
+        Vec::from(iso),
+        &[
+            Some("PT1H30M"),
+            Some("PT2H"),
+            Some("PT2H30M"),
+            Some("-P1DT2H3M4.5S"),
+            None,
+            None
+        ]
+    );
+    let humanized = out.column("humanized")?.str()?;
+    assert_eq!(humanized.get(0), Some("1h 30m"));
+    assert_eq!(humanized.get(3), Some("-1d -2h -3m -4s -500ms"));
+
+    // Both representations parse back into the same durations.
+    for c in ["iso", "humanized"] {
+        let parsed = out
+            .clone()
+            .lazy()
+            .select([col(c).str().to_duration(options)])
+            .collect()?;
+        assert!(parsed.column(c)?.equals_missing(out.column("s")?));
+    }
+
+    // Two clock components are ambiguous unless configured otherwise.
+    let df = df!["s" => ["02:30"]]?;
+    assert!(df
+        .clone()
+        .lazy()
+        .select([col("s").str().to_duration(options)])
+        .collect()
+        .is_err());
+    let out = df
+        .lazy()
+        .select([col("s").str().to_duration(DurationParseOptions {
+            ambiguous: ClockAmbiguity::MinutesSeconds,
+            ..options
+        })])
+        .collect()?;
+    assert_eq!(
+        out.column("s")?.get(0)?,
+        AnyValue::Duration(150_000, TimeUnit::Milliseconds)
+    );
+
+    // Non-duration strings raise when strict.
+    let df = df!["s" => ["1h", "foo"]]?;
+    assert!(df
+        .lazy()
+        .select([col("s").str().to_duration(DurationParseOptions::default())])
+        .collect()
+        .is_err());
+    Ok(())
+}
+
 fn print_plans(lf: &LazyFrame) {
     println!("LOGICAL PLAN\n\n{}\n", lf.describe_plan().unwrap());
     println!(
@@ -158,3 +239,142 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+#[cfg(all(feature = "strings", feature = "temporal"))]
+fn test_localized_strftime_strptime() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("mardi 5 mars 2024"), Some("jeudi 1 août 2024"), None],
+    ]?;
+    let options = StrptimeOptions {
+        format: Some("%A %-d %B %Y".into()),
+        locale: DateLocale::French,
+        ..Default::default()
+    };
+    let out = df
+        .lazy()
+        .select([col("s").str().to_date(options)])
+        .with_columns([
+            col("s")
+                .dt()
+                .to_string_localized("%a %d %b %Y", DateLocale::German)
+                .alias("de"),
+            col("s").dt().to_string("%Y-Q%Q-%j").alias("quarter"),
+        ])
+        .collect()?;
+
+    let dates = out.column("s")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(dates.str()?),
+        &[Some("2024-03-05"), Some("2024-08-01"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("de")?.str()?),
+        &[Some("Di 05 Mär 2024"), Some("Do 01 Aug 2024"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("quarter")?.str()?),
+        &[Some("2024-Q1-065"), Some("2024-Q3-214"), None]
+    );
+
+    // The quarter, day of year and ISO week parse back into the first day they cover.
+    let df = df!["q" => ["2024-Q3"], "j" => ["2024-065"], "w" => ["2024-W10"]]?;
+    let parse = |c: &str, format: &str| {
+        col(c).str().to_date(StrptimeOptions {
+            format: Some(format.into()),
+            ..Default::default()
+        })
+    };
+    let out = df
+        .lazy()
+        .select([
+            parse("q", "%Y-Q%Q"),
+            parse("j", "%Y-%j"),
+            parse("w", "%G-W%V"),
+        ])
+        .collect()?;
+    for (c, expected) in [
+        ("q", "2024-07-01"),
+        ("j", "2024-03-05"),
+        ("w", "2024-03-04"),
+    ] {
+        let date = out.column(c)?.cast(&DataType::String)?;
+        assert_eq!(date.str()?.get(0), Some(expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "range", feature = "dtype-date", feature = "dtype-datetime"))]
+fn test_calendar_date_ranges() -> PolarsResult<()> {
+    use polars_time::{CalendarFrequency, ClosedWindow, CronSchedule};
+
+    let start = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 4, 3).unwrap();
+    let good_friday = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+    let business_days = CalendarFrequency::BusinessDay {
+        n: 1,
+        week_mask: [true, true, true, true, true, false, false],
+        holidays: vec![
+            (good_friday - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32,
+        ],
+    };
+    let cron = CronSchedule::parse("30 9,16 * * mon")?;
+
+    let out = df!["a" => [1]]?
+        .lazy()
+        .select([
+            date_range_calendar(lit(start), lit(end), business_days, ClosedWindow::Right)
+                .alias("business_days")
+                .implode(),
+            date_range_calendar(
+                lit(start),
+                lit(NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()),
+                CalendarFrequency::MonthEnd { n: 1 },
+                ClosedWindow::Both,
+            )
+            .alias("month_ends")
+            .implode(),
+            datetime_range_calendar(
+                lit(start),
+                lit(end),
+                CalendarFrequency::Cron(cron),
+                ClosedWindow::Both,
+                Some(TimeUnit::Milliseconds),
+                None,
+            )
+            .alias("cron")
+            .implode(),
+        ])
+        .collect()?;
+
+    let strings = |name: &str| -> PolarsResult<Vec<Option<String>>> {
+        let s = out.column(name)?.explode()?.cast(&DataType::String)?;
+        Ok(s.str()?.into_iter().map(|s| s.map(String::from)).collect())
+    };
+    assert_eq!(
+        strings("business_days")?,
+        [
+            Some("2024-04-01".into()),
+            Some("2024-04-02".into()),
+            Some("2024-04-03".into())
+        ]
+    );
+    assert_eq!(
+        strings("month_ends")?,
+        [
+            Some("2024-03-31".into()),
+            Some("2024-04-30".into()),
+            Some("2024-05-31".into()),
+            Some("2024-06-30".into())
+        ]
+    );
+    assert_eq!(
+        strings("cron")?,
+        [
+            Some("2024-04-01 09:30:00.000".into()),
+            Some("2024-04-01 16:30:00.000".into())
+        ]
+    );
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::sync::Mutex;
+
 use super::*;
 
 #[cfg(feature = "parquet")]
@@ -551,6 +553,53 @@ fn test_flatten_unions() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_union_pushdown_differing_schemas() -> PolarsResult<()> {
+    let a = df!["a" => [1i32, 2, 3], "b" => ["x", "y", "z"]]?.lazy();
+    let b = df!["a" => [1i64, 5], "c" => [1.0, 2.0]]?.lazy();
+    let args = UnionArgs {
+        to_supertypes: true,
+        diagonal: true,
+        ..Default::default()
+    };
+    let q = concat([a, b], args)?
+        .filter(col("a").gt(lit(1i64)))
+        .select([col("a"), col("b")]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let mut n_scans = 0;
+    for (_, lp) in (&lp_arena).iter(root) {
+        match lp {
+            // The predicate on the cast column reaches both inputs.
+            IR::Filter { .. } => panic!("predicate not pushed down"),
+            IR::DataFrameScan {
+                schema,
+                output_schema,
+                filter,
+                ..
+            } => {
+                n_scans += 1;
+                assert!(filter.is_some());
+                // Only the columns of the input that are selected are read.
+                if schema.contains("c") {
+                    assert_eq!(output_schema.as_ref().unwrap().len(), 1);
+                }
+            },
+            _ => {},
+        }
+    }
+    assert_eq!(n_scans, 2);
+
+    let out = q.collect()?;
+    let expected = df![
+        "a" => [2i64, 3, 5],
+        "b" => [Some("y"), Some("z"), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 fn num_occurrences(s: &str, needle: &str) -> usize {
     let mut i = 0;
     let mut num = 0;
@@ -694,3 +743,233 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// A database that returns a fixed result and records its queries.
+struct RecordingSource {
+    queries: Mutex<Vec<String>>,
+    result: DataFrame,
+}
+
+impl DatabaseSource for RecordingSource {
+    fn query(&self, sql: &str) -> PolarsResult<DataFrame> {
+        self.queries.lock().unwrap().push(sql.to_string());
+        Ok(self.result.clone())
+    }
+}
+
+#[test]
+fn test_database_pushdown() -> PolarsResult<()> {
+    let source = Arc::new(RecordingSource {
+        queries: Default::default(),
+        result: df!("region" => ["north", "south"], "total" => [30i32, 12])?,
+    });
+    let schema = Schema::from_iter([
+        Field::new("region".into(), DataType::String),
+        Field::new("amount".into(), DataType::Int64),
+        Field::new("note".into(), DataType::String),
+    ]);
+    let q = LazyFrame::scan_database(
+        source.clone(),
+        "SELECT * FROM sales",
+        Some(Arc::new(schema)),
+    )?
+    .filter(col("amount").gt(lit(10)).and(col("region").is_not_null()))
+    .group_by([col("region")])
+    .agg([col("amount").sum().alias("total")])
+    .sort(["region"], Default::default())
+    .limit(5);
+
+    // The order of the projected columns and the filters is not fixed.
+    let check_pushed = |sql: &str| {
+        assert!(sql.starts_with(
+            "SELECT * FROM (SELECT \"region\", COALESCE(SUM(\"amount\"), 0) AS \"total\" FROM (SELECT "
+        ));
+        assert!(sql.contains(" FROM (SELECT * FROM sales) AS polars_subquery WHERE (("));
+        assert!(sql.contains("(\"amount\" > 10)") && sql.contains("(\"region\" IS NOT NULL)"));
+        assert!(sql.ends_with(
+            ") AS polars_subquery GROUP BY \"region\") AS polars_subquery \
+            ORDER BY \"region\" ASC NULLS FIRST LIMIT 5"
+        ));
+    };
+    let plan = q.clone().explain(true)?;
+    assert!(!plan.contains("AGGREGATE"));
+    check_pushed(
+        plan.lines()
+            .find_map(|line| line.trim().strip_prefix("SQL: "))
+            .unwrap(),
+    );
+
+    // The result is cast to the schema of the query.
+    let out = q.collect()?;
+    assert_eq!(out.column("total")?.dtype(), &DataType::Int64);
+    let queries = source.queries.lock().unwrap();
+    assert_eq!(queries.len(), 1);
+    check_pushed(&queries[0]);
+
+    // Without the optimization only the filter and projection are sent.
+    let result = df!("region" => ["north"], "amount" => [30i64])?;
+    let source = Arc::new(RecordingSource {
+        queries: Default::default(),
+        result,
+    });
+    let q = LazyFrame::scan_database(source.clone(), "SELECT * FROM sales", None)?
+        .with_database_pushdown(false)
+        .filter(col("amount").gt(lit(10)))
+        .group_by([col("region")])
+        .agg([col("amount").sum()]);
+    assert!(q.clone().explain(true)?.contains("AGGREGATE"));
+    q.collect()?;
+    let queries = source.queries.lock().unwrap();
+    assert_eq!(
+        queries[0],
+        "SELECT * FROM (SELECT * FROM sales) AS polars_subquery LIMIT 0"
+    );
+    assert!(queries[1]
+        .ends_with(" FROM (SELECT * FROM sales) AS polars_subquery WHERE (\"amount\" > 10)"));
+    Ok(())
+}
+
+#[test]
+fn test_database_pushdown_partial() -> PolarsResult<()> {
+    let source = Arc::new(RecordingSource {
+        queries: Default::default(),
+        result: df!("a" => [1i64, 2], "b" => ["x", "y"])?,
+    });
+    let schema = Arc::new(source.result.schema());
+    let scan = LazyFrame::scan_database(source.clone(), "SELECT * FROM t", Some(schema))?;
+
+    // Aggregations of which the database has no equivalent are done by Polars, on the result of
+    // the pushed down filter.
+    let q = scan
+        .clone()
+        .filter(col("b").eq(lit("x")))
+        .group_by([col("b")])
+        .agg([col("a").median()]);
+    let plan = q.explain(true)?;
+    assert!(plan.contains("AGGREGATE"));
+    assert!(plan
+        .contains("SQL: SELECT * FROM (SELECT * FROM t) AS polars_subquery WHERE (\"b\" = 'x')"));
+
+    // A stable sort is not pushed down, but the filter beneath it is.
+    let q = scan.filter(col("a").lt_eq(lit(1))).sort(
+        ["a"],
+        SortMultipleOptions::default().with_maintain_order(true),
+    );
+    let plan = q.explain(true)?;
+    assert!(plan.contains("SORT BY"));
+    assert!(plan.contains("WHERE (\"a\" <= 1)"));
+    Ok(())
+}
+
+#[test]
+fn test_nan_policy() -> PolarsResult<()> {
+    let df = df!(
+        "a" => [1.0, f64::NAN, f64::NAN, 2.0, 1.0],
+        "b" => [1i32, 2, 3, 4, 5]
+    )?;
+    let unequal = NanPolicy {
+        nans_equal: false,
+        ..Default::default()
+    };
+
+    // By default NaN equals NaN.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("a")])
+        .agg([col("b").sum()])
+        .collect()?;
+    assert_eq!(out.height(), 3);
+
+    // Every NaN is a group of its own.
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_policy(unequal)
+        .group_by_stable([col("a")])
+        .agg([col("b").sum()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[6, 2, 3, 4]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_policy(unequal)
+        .select([col("a")])
+        .unique_stable(None, UniqueKeepStrategy::First)
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a"]);
+    assert_eq!(out.height(), 4);
+
+    // NaN keys don't join.
+    let other = df!("a" => [f64::NAN, 1.0], "c" => ["nan", "one"])?.lazy();
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_policy(unequal)
+        .join(other, [col("a")], [col("a")], JoinType::Inner.into())
+        .sort(["b"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 5]
+    );
+
+    // NaN sorts first, in both directions.
+    for descending in [false, true] {
+        let out = df
+            .clone()
+            .lazy()
+            .with_nan_policy(NanPolicy {
+                nan_order: NanOrder::First,
+                ..Default::default()
+            })
+            .sort(
+                ["a"],
+                SortMultipleOptions::default()
+                    .with_order_descending(descending)
+                    .with_maintain_order(true),
+            )
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(
+            out.column("b")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>()[..2],
+            [2, 3]
+        );
+    }
+
+    // NaN becomes null when the data is read.
+    let out = df
+        .clone()
+        .lazy()
+        .with_nan_policy(NanPolicy {
+            nan_to_null: true,
+            ..Default::default()
+        })
+        .select([col("a").null_count()])
+        .collect()?;
+    assert_eq!(out.column("a")?.idx()?.get(0), Some(2));
+
+    // Setting the optimizations keeps the policy.
+    let lf = df.lazy().with_nan_policy(unequal);
+    let nan_policy = |lf: &LazyFrame| NanPolicy::from(lf.get_current_optimizations());
+    assert_eq!(
+        nan_policy(&lf.clone().with_optimizations(OptFlags::default())),
+        unequal
+    );
+    assert_eq!(nan_policy(&lf.without_optimizations()), unequal);
+    Ok(())
+}
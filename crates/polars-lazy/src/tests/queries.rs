@@ -1,3 +1,4 @@
+use polars_core::chunked_array::cast::{CastArgs, CastOverflow, NumberFormat, RoundingMode};
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 
@@ -1933,3 +1934,116 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_expr_json_roundtrip() -> PolarsResult<()> {
+    let df = df![
+        "group" => ["a", "a", "b", "b"],
+        "price" => [1.0, 2.5, 3.0, 4.0],
+        "qty" => [Some(1i32), Some(2), None, Some(4)],
+    ]?;
+    let exprs = [
+        (col("price") * lit(2i32)).sum().alias("total"),
+        when(col("qty").is_null())
+            .then(lit(0))
+            .otherwise(col("qty"))
+            .alias("qty"),
+        col("qty").cast(DataType::Int64).alias("qty64"),
+        col("price")
+            .filter(col("group").eq(lit("a")).not())
+            .mean()
+            .over([col("group")])
+            .alias("b_mean"),
+        col("price").std(0).alias("std"),
+    ];
+    for expr in exprs {
+        let json = expr.to_json()?;
+        let parsed = Expr::from_json(&json)?;
+        assert_eq!(parsed, expr);
+        let expected = df.clone().lazy().select([expr]).collect()?;
+        let out = df.clone().lazy().select([parsed]).collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    let json = r#"{"version": 1, "expr": {"type": "binary", "op": "gt",
+        "left": {"type": "column", "name": "qty"}, "right": {"type": "literal", "value": 1}}}"#;
+    assert_eq!(
+        Expr::from_json(json)?,
+        col("qty").gt(Expr::Literal(LiteralValue::Int(1)))
+    );
+
+    let err = |json: &str| Expr::from_json(json).unwrap_err().to_string();
+    assert!(err(r#"{"expr": {"type": "len"}}"#).contains("unsupported expression version"));
+    assert!(err(r#"{"version": 1, "expr": {"type": "len", "x": 1}}"#).contains("unknown field"));
+    assert!(
+        err(r#"{"version": 1, "expr": {"type": "literal", "value": 300, "dtype": "int8"}}"#)
+            .contains("invalid literal")
+    );
+    assert!(col("a")
+        .map(|s| Ok(Some(s)), GetOutput::same_type())
+        .to_json()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_paginate() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => (0..10).map(|i| i % 3).collect::<Vec<i32>>(),
+    ]?;
+
+    let mut cursor = df.clone().lazy().paginate(4)?;
+    assert_eq!(cursor.height(), 10);
+    assert_eq!(cursor.n_pages(), 3);
+    let heights = cursor
+        .by_ref()
+        .map(|page| page.height())
+        .collect::<Vec<_>>();
+    assert_eq!(heights, [4, 4, 2]);
+    assert!(cursor.next_page().is_none());
+
+    cursor.seek(3)?;
+    assert!(cursor.next_page().unwrap().equals(&df.slice(3, 4)));
+    assert_eq!(cursor.offset(), 7);
+    assert!(cursor.page(2).unwrap().equals(&df.slice(8, 2)));
+    assert!(cursor.page(3).is_none());
+    assert!(cursor.seek(11).is_err());
+
+    let q = df.lazy().group_by([col("b")]).agg([col("a").sum()]);
+    assert!(q.clone().paginate(2).is_err());
+    let mut cursor = q
+        .clone()
+        .sort(["b"], Default::default())
+        .filter(col("a").gt(lit(0)))
+        .paginate(2)?;
+    assert!(cursor
+        .next_page()
+        .unwrap()
+        .equals(&df![ "b" => [0, 1], "a" => [18, 12]]?));
+    assert!(q.group_by_stable([col("b")]).agg([]).paginate(2).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_cast_with_args() -> PolarsResult<()> {
+    let df = df!["a" => ["1.234", "2,5", "-10.000.000.000", "x"]]?;
+    let args = CastArgs::default()
+        .with_overflow(CastOverflow::Saturate)
+        .with_rounding(RoundingMode::HalfEven)
+        .with_number_format(Some(NumberFormat::new(',', Some('.'))));
+    let out = df
+        .lazy()
+        .select([
+            col("a").cast_with_args(DataType::Float64, args),
+            col("a").cast_with_args(DataType::Int32, args).alias("b"),
+        ])
+        .collect()?;
+    let expected = df![
+        "a" => [Some(1234.0), Some(2.5), Some(-1e10), None],
+        "b" => [Some(1234), None, Some(i32::MIN), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
@@ -40,3 +40,61 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_collect_schema_verbose() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2],
+        "b" => ["x", "y"],
+    ]?;
+    let right = df![
+        "a" => [1, 2],
+        "b" => [1.0, 2.0],
+    ]?;
+
+    let mut lf = left
+        .lazy()
+        .inner_join(right.lazy(), col("a"), col("a"))
+        .with_column((col("a") * lit(2)).alias("c"))
+        .rename(["b"], ["name"])
+        .with_row_index("idx", None);
+    let columns = lf.collect_schema_verbose()?;
+
+    let names = columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["idx", "a", "name", "b_right", "c"]);
+    let source = |name: &str| ColumnOrigin::Source {
+        source: "df".to_string(),
+        name: name.into(),
+    };
+    assert_eq!(
+        columns[0].origin,
+        ColumnOrigin::Node {
+            node: "row_index".to_string()
+        }
+    );
+    assert_eq!(columns[1].origin, source("a"));
+    assert_eq!(columns[2].origin, source("b"));
+    assert_eq!(columns[3].origin, source("b"));
+    assert_eq!(columns[3].dtype, DataType::Float64);
+    assert!(matches!(
+        &columns[4].origin,
+        ColumnOrigin::Expr { node: "hstack", expr } if expr.contains("col(\"a\")")
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_collect_schema_map_function_error() {
+    let df = df!["a" => [1, 2]].unwrap();
+    let schema_fn = |_: &Schema| -> PolarsResult<SchemaRef> {
+        polars_bail!(ComputeError: "unknown output schema")
+    };
+    let mut lf = df.lazy().map(
+        Ok,
+        AllowedOptimizations::default(),
+        Some(Arc::new(schema_fn)),
+        Some("my_udf"),
+    );
+    let err = lf.collect_schema().unwrap_err().to_string();
+    assert!(err.contains("my_udf") && err.contains("unknown output schema"));
+}
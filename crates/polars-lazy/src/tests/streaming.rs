@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use polars_ops::frame::JoinCoalesce;
 
 use super::*;
@@ -428,3 +430,530 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_streaming_group_by_dynamic_and_rolling() -> PolarsResult<()> {
+    use polars_time::prelude::{ClosedWindow, Label, StartBy};
+    use polars_time::Duration;
+
+    // Several inputs, so the windows span multiple rounds of chunks.
+    let parts = (0..10)
+        .map(|i| {
+            let t = (i * 100..(i + 1) * 100)
+                .map(|t| t * 3 / 2)
+                .collect::<Vec<i64>>();
+            let v = (0..100).map(|v| (v * 7 % 13) as f64).collect::<Vec<_>>();
+            Ok(df!["t" => t, "v" => v]?.lazy())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let lf = concat(parts, Default::default())?;
+
+    let q = lf
+        .clone()
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("25i"),
+                period: Duration::parse("60i"),
+                offset: Duration::parse("0i"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum().alias("sum"), col("v").max().alias("max")]);
+    assert_streaming_with_default(q, true, false);
+
+    let q = lf
+        .rolling(
+            col("t"),
+            [],
+            RollingGroupOptions {
+                period: Duration::parse("40i"),
+                offset: Duration::parse("-20i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").mean().alias("mean"), col("v").count()]);
+    assert_streaming_with_default(q, true, false);
+
+    // Sparse input, where the rows of later chunks fall into windows that start before them
+    // and after the windows that are emitted already.
+    let parts = (0..50)
+        .map(|i| {
+            let t = [i * 200, i * 200 + 80];
+            let v = [i as f64, (i * 2) as f64];
+            Ok(df!["t" => t, "v" => v]?.lazy())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let lf = concat(parts, Default::default())?;
+    for start_by in [StartBy::WindowBound, StartBy::DataPoint] {
+        let q = lf
+            .clone()
+            .group_by_dynamic(
+                col("t"),
+                [],
+                DynamicGroupOptions {
+                    every: Duration::parse("25i"),
+                    period: Duration::parse("60i"),
+                    offset: Duration::parse("0i"),
+                    include_boundaries: true,
+                    start_by,
+                    ..Default::default()
+                },
+            )
+            .agg([col("v").sum().alias("sum"), col("v").count()]);
+        assert_streaming_with_default(q, true, false);
+    }
+
+    // Rows between the windows, and windows that are labeled by their first row.
+    let q = lf
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("50i"),
+                period: Duration::parse("20i"),
+                offset: Duration::parse("10i"),
+                label: Label::DataPoint,
+                closed_window: ClosedWindow::Right,
+                start_by: StartBy::DataPoint,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum().alias("sum")]);
+    assert_streaming_with_default(q, true, false);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "ipc"))]
+fn test_streaming_sink_multiple() -> PolarsResult<()> {
+    let dir = std::env::temp_dir();
+    let csv_path = dir.join("polars_test_sink_multiple.csv");
+    let ipc_path = dir.join("polars_test_sink_multiple.ipc");
+
+    let q = get_csv_file().filter(col("calories").gt(lit(50)));
+    q.clone().sink_multiple([
+        SinkType::File {
+            path: Arc::new(csv_path.clone()),
+            file_type: FileType::Csv(Default::default()),
+        },
+        SinkType::File {
+            path: Arc::new(ipc_path.clone()),
+            file_type: FileType::Ipc(Default::default()),
+        },
+    ])?;
+
+    let expected = q.collect()?;
+    let csv = LazyCsvReader::new(&csv_path).finish()?.collect()?;
+    let ipc = LazyFrame::scan_ipc(&ipc_path, Default::default())?.collect()?;
+    assert!(csv.equals(&expected));
+    assert!(ipc.equals(&expected));
+
+    std::fs::remove_file(csv_path)?;
+    std::fs::remove_file(ipc_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_batches() -> PolarsResult<()> {
+    let q = get_csv_file().filter(col("calories").gt(lit(50)));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    q.clone().sink_batches(move |df| {
+        sender.send(df).unwrap();
+        Ok(())
+    })?;
+    let batches = receiver.into_iter().collect::<Vec<_>>();
+    let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    assert!(out.equals(&q.clone().collect()?));
+
+    let err = q
+        .sink_batches(|_| polars_bail!(ComputeError: "consumer went away"))
+        .unwrap_err();
+    assert!(err.to_string().contains("consumer went away"));
+    Ok(())
+}
+
+#[derive(Clone, Default)]
+struct RecordingConnection {
+    statements: Arc<Mutex<Vec<String>>>,
+    fail_inserts: bool,
+}
+
+impl DatabaseConnection for RecordingConnection {
+    fn execute(&mut self, sql: &str) -> PolarsResult<()> {
+        self.statements.lock().unwrap().push(sql.to_string());
+        polars_ensure!(
+            !(self.fail_inserts && sql.starts_with("INSERT")),
+            ComputeError: "connection lost"
+        );
+        Ok(())
+    }
+}
+
+#[test]
+fn test_streaming_sink_database() -> PolarsResult<()> {
+    let q = get_csv_file().select([col("category"), col("calories")]);
+    let height = q.clone().collect()?.height();
+
+    let conn = RecordingConnection::default();
+    let options = DatabaseSinkOptions {
+        mode: DatabaseWriteMode::Overwrite,
+        batch_size: 10,
+        ..Default::default()
+    };
+    q.clone().sink_database(conn.clone(), "db.foods", options)?;
+    let statements = conn.statements.lock().unwrap().clone();
+    assert_eq!(
+        statements[..3],
+        [
+            "BEGIN",
+            r#"DROP TABLE IF EXISTS "db"."foods""#,
+            r#"CREATE TABLE "db"."foods" ("category" TEXT, "calories" BIGINT)"#,
+        ]
+    );
+    assert_eq!(statements.last().unwrap(), "COMMIT");
+    let inserts = &statements[3..statements.len() - 1];
+    assert_eq!(inserts.len(), height.div_ceil(10));
+    assert!(inserts[0].starts_with(
+        r#"INSERT INTO "db"."foods" ("category", "calories") VALUES ('vegetables', 45), "#
+    ));
+    let rows = inserts
+        .iter()
+        .map(|sql| sql.matches("), (").count() + 1)
+        .collect::<Vec<_>>();
+    assert!(rows[..rows.len() - 1].iter().all(|n| *n == 10));
+    assert_eq!(rows.iter().sum::<usize>(), height);
+
+    let conn = RecordingConnection::default();
+    let options = DatabaseSinkOptions {
+        mode: DatabaseWriteMode::Upsert {
+            keys: vec!["category".into()],
+        },
+        ..Default::default()
+    };
+    q.clone().sink_database(conn.clone(), "foods", options)?;
+    let statements = conn.statements.lock().unwrap().clone();
+    assert_eq!(
+        statements[1],
+        r#"CREATE TEMPORARY TABLE "polars_staging_foods" ("category" TEXT, "calories" BIGINT)"#
+    );
+    assert_eq!(
+        statements[statements.len() - 3..],
+        [
+            r#"INSERT INTO "foods" ("category", "calories") SELECT "category", "calories" FROM "polars_staging_foods" WHERE true ON CONFLICT ("category") DO UPDATE SET "calories" = excluded."calories""#,
+            r#"DROP TABLE "polars_staging_foods""#,
+            "COMMIT",
+        ]
+    );
+
+    let conn = RecordingConnection {
+        fail_inserts: true,
+        ..Default::default()
+    };
+    let err = q
+        .clone()
+        .sink_database(conn.clone(), "foods", Default::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("connection lost"));
+    assert_eq!(conn.statements.lock().unwrap().last().unwrap(), "ROLLBACK");
+
+    // Dots in column names don't qualify them, unlike in table names.
+    let conn = RecordingConnection::default();
+    let options = DatabaseSinkOptions {
+        mode: DatabaseWriteMode::Upsert {
+            keys: vec!["food.category".into()],
+        },
+        ..Default::default()
+    };
+    q.select([
+        col("category").alias("food.category"),
+        col("calories").alias("food.calories"),
+    ])
+    .sink_database(conn.clone(), "db.foods", options)?;
+    let statements = conn.statements.lock().unwrap().clone();
+    assert_eq!(
+        statements[1],
+        r#"CREATE TEMPORARY TABLE "polars_staging_foods" ("food.category" TEXT, "food.calories" BIGINT)"#
+    );
+    assert!(statements[2].starts_with(
+        r#"INSERT INTO "polars_staging_foods" ("food.category", "food.calories") VALUES "#
+    ));
+    assert!(statements[statements.len() - 3].ends_with(
+        r#"ON CONFLICT ("food.category") DO UPDATE SET "food.calories" = excluded."food.calories""#
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_partitioned() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_partitioned");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let q = get_csv_file();
+    q.clone().sink_partitioned(
+        &dir,
+        [col("calories").gt(lit(100)).alias("high"), col("category")],
+        FileType::Csv(Default::default()),
+        1,
+    )?;
+
+    let all = LazyCsvReader::new(dir.join("*/*/*.csv"))
+        .finish()?
+        .collect()?;
+    assert_eq!(all.height(), q.clone().collect()?.height());
+
+    let fruit = LazyCsvReader::new(dir.join("high=false/category=fruit/*.csv"))
+        .finish()?
+        .collect()?;
+    let expected = q
+        .filter(
+            col("calories")
+                .lt_eq(lit(100))
+                .and(col("category").eq(lit("fruit"))),
+        )
+        .drop(["category"])
+        .collect()?;
+    assert!(fruit.equals(&expected));
+    std::fs::remove_dir_all(&dir)?;
+
+    // A computed key doesn't replace the source column of the same name.
+    q.clone().sink_partitioned(
+        &dir,
+        [col("calories").gt(lit(100)).alias("calories")],
+        FileType::Csv(Default::default()),
+        1,
+    )?;
+    let low = LazyCsvReader::new(dir.join("calories=false/*.csv"))
+        .finish()?
+        .collect()?;
+    let expected = q.filter(col("calories").lt_eq(lit(100))).collect()?;
+    assert!(low.equals(&expected));
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_parquet_partitioned() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_parquet_partitioned");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let n = 1_000_000;
+    let df = df![
+        "key" => (0..n).map(|i| i % 4).collect::<Vec<i32>>(),
+        "value" => (0..n as i64).collect::<Vec<_>>(),
+    ]?;
+    // Large enough inputs to be written in separate batches.
+    let inputs = (0..4)
+        .map(|i| df.slice(i * n as i64 / 4, n as usize / 4).lazy())
+        .collect::<Vec<_>>();
+    concat(inputs, Default::default())?.sink_parquet(
+        &dir,
+        ParquetWriteOptions {
+            partition_by: vec!["key".into()],
+            max_open_files: Some(1),
+            ..Default::default()
+        },
+    )?;
+
+    let mut partitions = std::fs::read_dir(&dir)?
+        .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    partitions.sort();
+    assert_eq!(partitions, ["key=0", "key=1", "key=2", "key=3"]);
+    // Every batch has rows of all partitions, so with a single open file the partitions are
+    // split over multiple files.
+    let n_files = std::fs::read_dir(dir.join("key=1"))?.count();
+    assert!(n_files > 1);
+
+    let out = LazyFrame::scan_parquet(dir.join("key=1/*.parquet"), Default::default())?
+        .sort(["value"], Default::default())
+        .collect()?;
+    let expected = df.clone().lazy().filter(col("key").eq(lit(1))).collect()?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFrame::scan_parquet(dir.join("*/*.parquet"), Default::default())?.collect()?;
+    assert_eq!(out.height(), n as usize);
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_parquet_rolling() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_parquet_rolling");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+        "b" => (0..1000).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+    ]?;
+    df.clone().lazy().sink_parquet(
+        &dir,
+        ParquetWriteOptions {
+            row_group_size: Some(128),
+            max_rows_per_file: Some(300),
+            ..Default::default()
+        },
+    )?;
+
+    let mut files = std::fs::read_dir(&dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    files.sort();
+    let row_groups = files
+        .iter()
+        .map(|path| {
+            let mut reader = ParquetReader::new(std::fs::File::open(path)?);
+            let metadata = reader.get_metadata()?;
+            Ok(metadata
+                .row_groups
+                .iter()
+                .map(|rg| rg.num_rows())
+                .collect::<Vec<_>>())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // Every full file has three row groups of 100 rows instead of two of 128 and one of 44.
+    assert_eq!(
+        row_groups,
+        [
+            vec![100, 100, 100],
+            vec![100, 100, 100],
+            vec![100, 100, 100],
+            vec![100]
+        ]
+    );
+
+    let out = LazyFrame::scan_parquet(dir.join("*.parquet"), Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_dir_all(&dir)?;
+    df.clone().lazy().sink_parquet(
+        &dir,
+        ParquetWriteOptions {
+            target_file_size: Some(4096),
+            ..Default::default()
+        },
+    )?;
+    let n_files = std::fs::read_dir(&dir)?.count();
+    assert!(n_files > 1);
+    let out = LazyFrame::scan_parquet(dir.join("*.parquet"), Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "csv"))]
+fn test_streaming_sink_ipc_csv_rolling() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_sink_ipc_csv_rolling");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+        "b" => (0..1000).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+    ]?;
+    let sorted_files = |dir: &std::path::Path| -> PolarsResult<Vec<std::path::PathBuf>> {
+        let mut files = std::fs::read_dir(dir)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        files.sort();
+        Ok(files)
+    };
+
+    df.clone().lazy().sink_ipc(
+        dir.join("ipc"),
+        IpcWriterOptions {
+            max_rows_per_file: Some(300),
+            ..Default::default()
+        },
+    )?;
+    let heights = sorted_files(&dir.join("ipc"))?
+        .iter()
+        .map(|path| {
+            Ok(IpcReader::new(std::fs::File::open(path)?)
+                .finish()?
+                .height())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(heights, [300, 300, 300, 100]);
+    let out = LazyFrame::scan_ipc(dir.join("ipc/*.ipc"), Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    df.clone().lazy().sink_csv(
+        dir.join("csv"),
+        CsvWriterOptions {
+            target_file_size: Some(4096),
+            ..Default::default()
+        },
+    )?;
+    let files = sorted_files(&dir.join("csv"))?;
+    assert!(files.len() > 1);
+    for path in &files {
+        // Every file has a header.
+        let file = CsvReadOptions::default().try_into_reader_with_file_path(Some(path.clone()))?;
+        assert_eq!(file.finish()?.get_column_names(), ["a", "b"]);
+        assert!(std::fs::metadata(path)?.len() < 2 * 4096);
+    }
+    let out = LazyCsvReader::new(dir.join("csv/*.csv"))
+        .finish()?
+        .collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_streaming_sink_schema_contract() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_sink_schema_contract.ipc");
+    let df = df![
+        "b" => ["x", "y", "z"],
+        "a" => [1i32, 2, 3],
+    ]?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let check = |lf: LazyFrame, contract: &SchemaContract| {
+        let err = lf.with_schema_contract(contract).err().unwrap();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+        err.to_string()
+    };
+
+    let contract = SchemaContract::new(schema.clone());
+    let msg = check(df.clone().lazy(), &contract);
+    assert!(msg.contains("column 'a' is i32, expected i64"));
+
+    // The order is only reported once the columns match.
+    let contract = contract.with_coerce_dtypes(true);
+    let msg = check(df.clone().lazy(), &contract);
+    assert!(msg.contains("columns are in order"));
+
+    let contract = contract.with_coerce_order(true);
+    let lf = df.clone().lazy().with_column(lit(1).alias("c")).drop(["b"]);
+    let msg = check(lf, &contract);
+    assert!(msg.contains("missing column 'b'") && msg.contains("unexpected column 'c'"));
+
+    df.clone()
+        .lazy()
+        .with_schema_contract(&contract)?
+        .sink_ipc(&path, Default::default())?;
+    let out = LazyFrame::scan_ipc(&path, Default::default())?.collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(
+        out.column("a")?.i64()?.to_vec(),
+        [Some(1), Some(2), Some(3)]
+    );
+    assert!(out.column("b")?.equals(df.column("b")?));
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
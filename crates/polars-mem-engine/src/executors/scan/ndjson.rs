@@ -105,7 +105,12 @@ impl JsonExec {
                         Ok(v) => v,
                         Err(e) => return Some(Err(e)),
                     });
-                let reader = JsonLineReader::new(curs);
+                let mut reader = JsonLineReader::new(curs);
+                // The schema was inferred with the union resolution, a given schema keeps values
+                // that aren't strings as null in its string columns.
+                if self.options.schema.is_none() {
+                    reader = reader.with_union_resolution(self.options.union_resolution);
+                }
 
                 let row_index = self.file_scan_options.row_index.as_mut();
 
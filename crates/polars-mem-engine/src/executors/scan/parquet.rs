@@ -7,6 +7,7 @@ use polars_core::config::{get_file_prefetch_size, verbose};
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
+use polars_io::parquet::summary::MetadataSummary;
 use polars_io::path_utils::is_cloud_url;
 use polars_io::utils::slice::split_slice_at_file;
 use polars_io::RowIndex;
@@ -59,6 +60,16 @@ impl ParquetExec {
         };
 
         let mut result = vec![];
+        let summary = if self.options.use_metadata_summary {
+            MetadataSummary::find(&self.paths)?
+        } else {
+            None
+        };
+        let summary_metadata = |path: &PathBuf| {
+            summary
+                .as_ref()
+                .and_then(|summary| summary.get(path).cloned())
+        };
 
         let step = std::cmp::min(POOL.current_num_threads(), 128);
         // Modified if we have a negative slice
@@ -83,7 +94,10 @@ impl ParquetExec {
                         let row_counts = path_indexes
                             .into_par_iter()
                             .map(|i| {
-                                ParquetReader::new(std::fs::File::open(&self.paths[*i])?).num_rows()
+                                let path = &self.paths[*i];
+                                ParquetReader::new(std::fs::File::open(path)?)
+                                    .with_metadata(summary_metadata(path))
+                                    .num_rows()
                             })
                             .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -151,6 +165,8 @@ impl ParquetExec {
                 );
 
                 let mut reader = ParquetReader::new(file)
+                    .with_metadata(summary_metadata(path))
+                    .with_dictionary_as_categorical(self.options.dictionary_as_categorical)
                     .read_parallel(parallel)
                     .set_low_memory(self.options.low_memory)
                     .use_statistics(self.options.use_statistics)
@@ -311,6 +327,7 @@ impl ParquetExec {
         let mut current_offset = 0;
         let base_row_index = self.file_options.row_index.take();
         let mut processed = 0;
+        let dictionary_as_categorical = self.options.dictionary_as_categorical;
 
         for batch_start in (first_file_idx..self.paths.len()).step_by(batch_size) {
             let end = std::cmp::min(batch_start.saturating_add(batch_size), self.paths.len());
@@ -340,7 +357,8 @@ impl ParquetExec {
                 };
                 let mut reader =
                     ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, metadata)
-                        .await?;
+                        .await?
+                        .with_dictionary_as_categorical(dictionary_as_categorical);
 
                 let num_rows = reader.num_rows().await?;
                 PolarsResult::Ok((num_rows, reader))
@@ -366,6 +384,7 @@ impl ParquetExec {
             let file_info = &self.file_info;
             let file_options = &self.file_options;
             let use_statistics = self.options.use_statistics;
+            let prefetch = self.options.prefetch;
             let predicate = &self.predicate;
             let base_row_index_ref = &base_row_index;
             let include_file_paths = self.file_options.include_file_paths.as_ref();
@@ -414,6 +433,7 @@ impl ParquetExec {
                             .check_schema(schema.as_ref())
                             .await?
                             .use_statistics(use_statistics)
+                            .with_prefetch(prefetch)
                             .with_predicate(predicate)
                             .set_rechunk(false)
                             .with_hive_partition_columns(hive_partitions)
@@ -447,6 +467,13 @@ impl ParquetExec {
             .and_then(|_| self.predicate.take())
             .map(phys_expr_to_io_expr);
 
+        // The categoricals of different files can only be combined with a string cache.
+        #[cfg(feature = "dtype-categorical")]
+        let _sc = self
+            .options
+            .dictionary_as_categorical
+            .then(polars_core::StringCacheHolder::hold);
+
         let is_cloud = is_cloud_url(self.paths.first().unwrap());
         let force_async = config::force_async();
 
@@ -217,6 +217,15 @@ fn create_physical_plan_impl(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            SinkType::Partitioned { .. } => {
+                polars_bail!(InvalidOperation: "sink_partitioned not supported in standard engine.")
+            },
+            SinkType::Multiple(_) => {
+                polars_bail!(InvalidOperation: "sink_multiple not supported in standard engine.")
+            },
+            SinkType::Batches(_) => {
+                polars_bail!(InvalidOperation: "sink_batches not supported in standard engine.")
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
@@ -9,6 +9,8 @@ pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
 pub mod peaks;
 mod scatter;
+#[cfg(feature = "sparse")]
+mod sparse;
 pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
@@ -40,6 +42,8 @@ use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
 pub use repeat_by::*;
 pub use scatter::ChunkedSet;
+#[cfg(feature = "sparse")]
+pub use sparse::*;
 pub use strings::*;
 #[cfg(feature = "top_k")]
 pub use top_k::*;
@@ -0,0 +1,430 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use arrow::compute::aggregate::estimated_bytes_size;
+use num_traits::{Num, ToPrimitive, Zero};
+use polars_core::prelude::*;
+use polars_utils::min_max::MinMax;
+use polars_utils::total_ord::TotalEq;
+
+/// A numeric column of which only the values that differ from a fill value are stored, as
+/// pairs of a row index and a value.
+///
+/// The fill value is either a number, such as zero for a one-hot encoded or feature column, or
+/// null, for a column that is mostly missing. Arithmetic and aggregations work on the stored
+/// values and account for the rows with the fill value without materializing them, so their
+/// cost depends on the number of stored values rather than the length of the column.
+#[derive(Clone, Debug)]
+pub struct SparseChunked<T: PolarsNumericType> {
+    len: usize,
+    fill: Option<T::Native>,
+    indices: Vec<IdxSize>,
+    values: ChunkedArray<T>,
+}
+
+impl<T: PolarsNumericType> SparseChunked<T> {
+    /// Create a sparse column of length `len`, where the rows at `indices` hold `values` and all
+    /// other rows hold `fill`. The indices must be strictly increasing and within the column.
+    pub fn new(
+        len: usize,
+        fill: Option<T::Native>,
+        indices: Vec<IdxSize>,
+        values: ChunkedArray<T>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            indices.len() == values.len(),
+            ShapeMismatch: "sparse column has {} indices but {} values", indices.len(), values.len()
+        );
+        polars_ensure!(
+            indices.windows(2).all(|w| w[0] < w[1]),
+            InvalidOperation: "indices of a sparse column must be strictly increasing"
+        );
+        if let Some(&last) = indices.last() {
+            polars_ensure!(
+                (last as usize) < len,
+                OutOfBounds: "index {} is out of bounds for a sparse column of length {}", last, len
+            );
+        }
+        Ok(Self::new_unchecked(len, fill, indices, values))
+    }
+
+    fn new_unchecked(
+        len: usize,
+        fill: Option<T::Native>,
+        indices: Vec<IdxSize>,
+        values: ChunkedArray<T>,
+    ) -> Self {
+        Self {
+            len,
+            fill,
+            indices,
+            values: values.rechunk(),
+        }
+    }
+
+    /// Convert a dense column, storing only the values that differ from `fill`.
+    pub fn from_dense(ca: &ChunkedArray<T>, fill: Option<T::Native>) -> Self {
+        let mut indices = vec![];
+        let mut values = vec![];
+        for (idx, value) in ca.iter().enumerate() {
+            if !is_fill(value, fill) {
+                indices.push(idx as IdxSize);
+                values.push(value);
+            }
+        }
+        let values = ChunkedArray::from_iter_options(ca.name().clone(), values.into_iter());
+        Self::new_unchecked(ca.len(), fill, indices, values)
+    }
+
+    /// Materialize the dense column.
+    pub fn to_dense(&self) -> ChunkedArray<T> {
+        let mut dense = vec![self.fill; self.len];
+        for (&idx, value) in self.indices.iter().zip(self.values.iter()) {
+            dense[idx as usize] = value;
+        }
+        ChunkedArray::from_iter_options(self.name().clone(), dense.into_iter())
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.values.name()
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) {
+        self.values.rename(name)
+    }
+
+    pub fn with_name(mut self, name: PlSmallStr) -> Self {
+        self.rename(name);
+        self
+    }
+
+    /// The length of the column, including the rows with the fill value.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The value of the rows that are not stored, where `None` is null.
+    pub fn fill_value(&self) -> Option<T::Native> {
+        self.fill
+    }
+
+    /// The row indices of the stored values.
+    pub fn indices(&self) -> &[IdxSize] {
+        &self.indices
+    }
+
+    /// The stored values.
+    pub fn values(&self) -> &ChunkedArray<T> {
+        &self.values
+    }
+
+    /// The number of stored values.
+    pub fn stored_len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// The fraction of the rows of which the value is stored.
+    pub fn density(&self) -> f64 {
+        if self.len == 0 {
+            0.0
+        } else {
+            self.stored_len() as f64 / self.len as f64
+        }
+    }
+
+    /// Get the value at row `idx`.
+    ///
+    /// # Panics
+    /// If `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<T::Native> {
+        assert!(
+            idx < self.len,
+            "index {idx} is out of bounds for a sparse column of length {}",
+            self.len
+        );
+        match self.indices.binary_search(&(idx as IdxSize)) {
+            Ok(pos) => self.values.get(pos),
+            Err(_) => self.fill,
+        }
+    }
+
+    /// The estimated size of the column in bytes.
+    pub fn estimated_size(&self) -> usize {
+        let values_size: usize = self
+            .values
+            .chunks()
+            .iter()
+            .map(|arr| estimated_bytes_size(&**arr))
+            .sum();
+        self.indices.len() * std::mem::size_of::<IdxSize>() + values_size
+    }
+
+    /// The number of rows that are not stored.
+    fn fill_len(&self) -> usize {
+        self.len - self.stored_len()
+    }
+
+    pub fn null_count(&self) -> usize {
+        let fill_nulls = if self.fill.is_none() {
+            self.fill_len()
+        } else {
+            0
+        };
+        self.values.null_count() + fill_nulls
+    }
+
+    /// The number of non-null values.
+    pub fn count(&self) -> usize {
+        self.len - self.null_count()
+    }
+
+    /// Apply `op` to the stored values and to the fill value.
+    fn apply_kernel(&self, op: impl Fn(&ChunkedArray<T>) -> ChunkedArray<T>) -> Self {
+        let fill = op(&self.fill_array()).get(0);
+        let values = op(&self.values);
+        Self::new_unchecked(self.len, fill, self.indices.clone(), values)
+    }
+
+    /// Apply `op` to the aligned values of both columns. Only the rows that are stored in either
+    /// column are computed, and those that equal the new fill value are not stored.
+    fn apply_binary_kernel(
+        &self,
+        other: &Self,
+        op: impl Fn(&ChunkedArray<T>, &ChunkedArray<T>) -> ChunkedArray<T>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            self.len == other.len,
+            ShapeMismatch: "cannot combine sparse columns of length {} and {}", self.len, other.len
+        );
+        let fill = op(&self.fill_array(), &other.fill_array()).get(0);
+
+        let capacity = self.stored_len() + other.stored_len();
+        let mut indices = Vec::with_capacity(capacity);
+        let mut lhs = Vec::with_capacity(capacity);
+        let mut rhs = Vec::with_capacity(capacity);
+        let (mut i, mut j) = (0, 0);
+        while i < self.stored_len() || j < other.stored_len() {
+            let left_idx = self.indices.get(i).copied().unwrap_or(IdxSize::MAX);
+            let right_idx = other.indices.get(j).copied().unwrap_or(IdxSize::MAX);
+            if left_idx <= right_idx {
+                indices.push(left_idx);
+                lhs.push(self.values.get(i));
+                i += 1;
+            } else {
+                indices.push(right_idx);
+                lhs.push(self.fill);
+            }
+            if right_idx <= left_idx {
+                rhs.push(other.values.get(j));
+                j += 1;
+            } else {
+                rhs.push(other.fill);
+            }
+        }
+        let lhs = ChunkedArray::<T>::from_iter_options(self.name().clone(), lhs.into_iter());
+        let rhs = ChunkedArray::<T>::from_iter_options(other.name().clone(), rhs.into_iter());
+        let out = op(&lhs, &rhs);
+
+        let (indices, values): (Vec<_>, Vec<_>) = indices
+            .into_iter()
+            .zip(out.iter())
+            .filter(|(_, value)| !is_fill(*value, fill))
+            .unzip();
+        let values = ChunkedArray::from_iter_options(self.name().clone(), values.into_iter());
+        Ok(Self::new_unchecked(self.len, fill, indices, values))
+    }
+
+    fn fill_array(&self) -> ChunkedArray<T> {
+        ChunkedArray::from_slice_options(self.name().clone(), &[self.fill])
+    }
+}
+
+impl<T> SparseChunked<T>
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: ChunkAgg<T::Native>,
+{
+    /// Get the sum of the non-null values.
+    pub fn sum(&self) -> Option<T::Native> {
+        let stored = self.values.sum().unwrap_or_else(T::Native::zero);
+        match self.fill {
+            Some(fill) if !fill.is_zero() => Some(stored + repeated_sum(fill, self.fill_len())),
+            _ => Some(stored),
+        }
+    }
+
+    /// Get the mean of the non-null values.
+    pub fn mean(&self) -> Option<f64> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        let fill_sum = match self.fill {
+            Some(fill) => fill.to_f64().unwrap() * self.fill_len() as f64,
+            None => 0.0,
+        };
+        Some((self.values._sum_as_f64() + fill_sum) / count as f64)
+    }
+
+    /// Get the minimum of the non-null values.
+    pub fn min(&self) -> Option<T::Native> {
+        self.combine_with_fill(self.values.min(), MinMax::min_ignore_nan)
+    }
+
+    /// Get the maximum of the non-null values.
+    pub fn max(&self) -> Option<T::Native> {
+        self.combine_with_fill(self.values.max(), MinMax::max_ignore_nan)
+    }
+
+    fn combine_with_fill(
+        &self,
+        stored: Option<T::Native>,
+        combine: impl Fn(T::Native, T::Native) -> T::Native,
+    ) -> Option<T::Native> {
+        let fill = self.fill.filter(|_| self.fill_len() > 0);
+        match (stored, fill) {
+            (Some(stored), Some(fill)) => Some(combine(stored, fill)),
+            (stored, fill) => stored.or(fill),
+        }
+    }
+}
+
+fn is_fill<N: TotalEq>(value: Option<N>, fill: Option<N>) -> bool {
+    match (value, fill) {
+        (Some(value), Some(fill)) => value.tot_eq(&fill),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// The sum of `n` times `value`, in a logarithmic number of additions.
+fn repeated_sum<N: Num + Copy>(value: N, mut n: usize) -> N {
+    let mut total = N::zero();
+    let mut term = value;
+    while n > 0 {
+        if n & 1 == 1 {
+            total = total + term;
+        }
+        n >>= 1;
+        if n > 0 {
+            term = term + term;
+        }
+    }
+    total
+}
+
+macro_rules! impl_sparse_op {
+    ($op: ident, $trait_method: ident) => {
+        impl<T: PolarsNumericType> $op for &SparseChunked<T> {
+            type Output = PolarsResult<SparseChunked<T>>;
+
+            fn $trait_method(self, rhs: Self) -> Self::Output {
+                self.apply_binary_kernel(rhs, |lhs, rhs| $op::$trait_method(lhs, rhs))
+            }
+        }
+
+        impl<T: PolarsNumericType, N: Num + ToPrimitive + Copy> $op<N> for &SparseChunked<T> {
+            type Output = SparseChunked<T>;
+
+            fn $trait_method(self, rhs: N) -> Self::Output {
+                self.apply_kernel(|ca| $op::$trait_method(ca, rhs))
+            }
+        }
+    };
+}
+
+impl_sparse_op!(Add, add);
+impl_sparse_op!(Sub, sub);
+impl_sparse_op!(Mul, mul);
+impl_sparse_op!(Div, div);
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::SparseChunked;
+
+    #[test]
+    fn test_sparse_roundtrip() {
+        let ca = Int32Chunked::new(
+            "a".into(),
+            &[Some(0), Some(3), Some(0), None, Some(0), Some(5)],
+        );
+        let sparse = SparseChunked::from_dense(&ca, Some(0));
+        assert_eq!(sparse.indices(), &[1, 3, 5]);
+        assert_eq!(sparse.stored_len(), 3);
+        assert_eq!(sparse.get(0), Some(0));
+        assert_eq!(sparse.get(3), None);
+        assert_eq!(sparse.get(5), Some(5));
+        assert_eq!(sparse.null_count(), 1);
+        assert_eq!(sparse.to_dense().to_vec(), ca.to_vec());
+        assert_eq!(sparse.to_dense().name().as_str(), "a");
+
+        let sparse = SparseChunked::from_dense(&ca, None);
+        assert_eq!(sparse.indices(), &[0, 1, 2, 4, 5]);
+        assert_eq!(sparse.to_dense().to_vec(), ca.to_vec());
+    }
+
+    #[test]
+    fn test_sparse_new() {
+        let values = Float64Chunked::from_slice("a".into(), &[1.0, 2.0]);
+        assert!(SparseChunked::new(10, None, vec![2, 7], values.clone()).is_ok());
+        assert!(SparseChunked::new(10, None, vec![7, 2], values.clone()).is_err());
+        assert!(SparseChunked::new(10, None, vec![2, 10], values.clone()).is_err());
+        assert!(SparseChunked::new(10, None, vec![2], values).is_err());
+    }
+
+    #[test]
+    fn test_sparse_aggregations() {
+        let values = Int64Chunked::from_slice("a".into(), &[4, -2]);
+        let sparse = SparseChunked::new(1_000_000, Some(1), vec![10, 500], values).unwrap();
+        assert_eq!(sparse.sum(), Some(999_998 + 2));
+        assert_eq!(sparse.min(), Some(-2));
+        assert_eq!(sparse.max(), Some(4));
+        assert_eq!(sparse.mean(), Some(1_000_000.0 / 1_000_000.0));
+        assert_eq!(sparse.count(), 1_000_000);
+
+        let values = Float64Chunked::new("a".into(), &[Some(1.5), None, Some(2.5)]);
+        let sparse = SparseChunked::new(100, None, vec![0, 1, 99], values).unwrap();
+        assert_eq!(sparse.sum(), Some(4.0));
+        assert_eq!(sparse.mean(), Some(2.0));
+        assert_eq!(sparse.min(), Some(1.5));
+        assert_eq!(sparse.null_count(), 98);
+
+        let sparse = SparseChunked::<Int32Type>::from_dense(
+            &Int32Chunked::from_slice("a".into(), &[0, 0]),
+            Some(0),
+        );
+        assert_eq!(sparse.stored_len(), 0);
+        assert_eq!(sparse.sum(), Some(0));
+        assert_eq!(sparse.max(), Some(0));
+    }
+
+    #[test]
+    fn test_sparse_arithmetic() {
+        let a = Int32Chunked::new("a".into(), &[Some(0), Some(1), Some(0), None, Some(0)]);
+        let b = Int32Chunked::new("b".into(), &[Some(2), Some(-1), Some(0), Some(0), Some(0)]);
+        let sparse_a = SparseChunked::from_dense(&a, Some(0));
+        let sparse_b = SparseChunked::from_dense(&b, Some(0));
+
+        let sum = (&sparse_a + &sparse_b).unwrap();
+        // 1 + -1 equals the fill value and is not stored.
+        assert_eq!(sum.indices(), &[0, 3]);
+        assert_eq!(sum.to_dense().to_vec(), (&a + &b).to_vec());
+
+        let product = (&sparse_a * &sparse_b).unwrap();
+        assert_eq!(product.to_dense().to_vec(), (&a * &b).to_vec());
+        let difference = (&sparse_a - &sparse_b).unwrap();
+        assert_eq!(difference.to_dense().to_vec(), (&a - &b).to_vec());
+
+        let shifted = &sparse_a + 10;
+        assert_eq!(shifted.fill_value(), Some(10));
+        assert_eq!(shifted.to_dense().to_vec(), (&a + 10).to_vec());
+
+        let other = SparseChunked::from_dense(&b.slice(0, 3), Some(0));
+        assert!((&sparse_a + &other).is_err());
+    }
+}
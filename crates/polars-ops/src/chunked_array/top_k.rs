@@ -260,6 +260,11 @@ pub fn top_k_by(s: &[Series], descending: Vec<bool>) -> PolarsResult<Series> {
         return Ok(src.clone());
     }
 
+    polars_ensure!(
+        descending.len() == by.len() || descending.len() == 1,
+        ComputeError: "the length of `descending` ({}) does not match the length of `by` ({}) in `top_k_by`",
+        descending.len(), by.len()
+    );
     for s in by {
         if s.len() != src.len() {
             polars_bail!(ComputeError: "`by` column's ({}) length ({}) should have the same length as the source column length ({}) in `top_k`", s.name(), s.len(), src.len())
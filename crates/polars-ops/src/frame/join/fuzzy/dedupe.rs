@@ -0,0 +1,211 @@
+use super::*;
+
+/// How two values of a column are compared, as a similarity in `[0, 1]`. Nulls have a similarity
+/// of 0 to everything.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Similarity {
+    /// One minus the edit distance divided by the length of the longest string.
+    Levenshtein,
+    /// Jaro-Winkler similarity.
+    JaroWinkler,
+    /// One minus the absolute difference divided by `scale`, values that are `scale` or more
+    /// apart have a similarity of 0.
+    Numeric { scale: f64 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DedupeOptions {
+    /// String column on which rows must match to be compared at all.
+    pub block_on: PlSmallStr,
+    pub blocking: FuzzyMetric,
+    /// Columns on which the candidate pairs are compared.
+    pub columns: Vec<(PlSmallStr, Similarity)>,
+    /// Minimum mean similarity over `columns` of two rows in the same cluster.
+    pub threshold: f64,
+}
+
+impl DedupeOptions {
+    pub fn new(block_on: PlSmallStr, blocking: FuzzyMetric, threshold: f64) -> Self {
+        Self {
+            block_on,
+            blocking,
+            columns: vec![],
+            threshold,
+        }
+    }
+
+    pub fn with_column(mut self, name: PlSmallStr, similarity: Similarity) -> Self {
+        self.columns.push((name, similarity));
+        self
+    }
+}
+
+enum CompareColumn {
+    String(Vec<Option<Vec<char>>>, Similarity),
+    Numeric(Float64Chunked, f64),
+}
+
+impl CompareColumn {
+    fn new(s: &Series, similarity: Similarity) -> PolarsResult<Self> {
+        Ok(match similarity {
+            Similarity::Levenshtein | Similarity::JaroWinkler => {
+                polars_ensure!(
+                    s.dtype() == &DataType::String,
+                    InvalidOperation: "{:?} similarity requires a String column, got {} for column '{}'",
+                    similarity, s.dtype(), s.name()
+                );
+                Self::String(to_chars(s.str().unwrap(), 0), similarity)
+            },
+            Similarity::Numeric { scale } => {
+                polars_ensure!(
+                    s.dtype().is_numeric(),
+                    InvalidOperation: "numeric similarity requires a numeric column, got {} for column '{}'",
+                    s.dtype(), s.name()
+                );
+                polars_ensure!(
+                    scale > 0.0,
+                    InvalidOperation: "numeric similarity scale must be positive, got {}", scale
+                );
+                let s = s.cast(&DataType::Float64)?;
+                Self::Numeric(s.f64().unwrap().rechunk(), scale)
+            },
+        })
+    }
+
+    fn similarity(&self, a: usize, b: usize) -> f64 {
+        match self {
+            Self::String(strings, similarity) => {
+                let (Some(a), Some(b)) = (&strings[a], &strings[b]) else {
+                    return 0.0;
+                };
+                match similarity {
+                    Similarity::JaroWinkler => jaro_winkler(a, b),
+                    _ => {
+                        let len = a.len().max(b.len());
+                        if len == 0 {
+                            return 1.0;
+                        }
+                        let distance = levenshtein_bounded(a, b, len).unwrap();
+                        1.0 - distance as f64 / len as f64
+                    },
+                }
+            },
+            Self::Numeric(values, scale) => match (values.get(a), values.get(b)) {
+                (Some(a), Some(b)) => (1.0 - (a - b).abs() / scale).max(0.0),
+                _ => 0.0,
+            },
+        }
+    }
+}
+
+/// Union-find over the rows, the root of every cluster is its first row.
+struct Clusters {
+    parent: Vec<IdxSize>,
+}
+
+impl Clusters {
+    fn find(&mut self, mut row: IdxSize) -> IdxSize {
+        while self.parent[row as usize] != row {
+            let grandparent = self.parent[self.parent[row as usize] as usize];
+            self.parent[row as usize] = grandparent;
+            row = grandparent;
+        }
+        row
+    }
+
+    fn union(&mut self, a: IdxSize, b: IdxSize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parent[a.max(b) as usize] = a.min(b);
+    }
+}
+
+pub trait DedupeSimilar: IntoDf {
+    /// Assign a cluster id to every row, rows with the same id are considered duplicates.
+    ///
+    /// Candidate pairs are the rows whose `block_on` strings match under the `blocking` metric,
+    /// found with the same q-gram index as [`FuzzyJoin::join_fuzzy`]. A candidate pair is a
+    /// duplicate if its mean similarity over `columns` is at least `threshold`; without columns
+    /// every candidate pair is. Duplicates are clustered transitively. Cluster ids are numbered
+    /// in the order of the first row of every cluster.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn cluster_customers(customers: &DataFrame) -> PolarsResult<IdxCa> {
+    ///     let options = DedupeOptions::new(
+    ///         "name".into(),
+    ///         FuzzyMetric::Levenshtein { max_distance: 3 },
+    ///         0.8,
+    ///     )
+    ///     .with_column("name".into(), Similarity::JaroWinkler)
+    ///     .with_column("zip_code".into(), Similarity::Levenshtein)
+    ///     .with_column("birth_year".into(), Similarity::Numeric { scale: 5.0 });
+    ///     customers.dedupe_similar(&options)
+    /// }
+    /// ```
+    fn dedupe_similar(&self, options: &DedupeOptions) -> PolarsResult<IdxCa> {
+        let df = self.to_df();
+        polars_ensure!(
+            options.threshold >= 0.0 && options.threshold <= 1.0,
+            InvalidOperation: "dedupe threshold must be in [0, 1], got {}", options.threshold
+        );
+        let block_on = df.column(&options.block_on)?;
+        polars_ensure!(
+            block_on.dtype() == &DataType::String,
+            InvalidOperation: "dedupe blocking column must be of type String, got {} for column '{}'",
+            block_on.dtype(), block_on.name()
+        );
+        let columns = options
+            .columns
+            .iter()
+            .map(|(name, similarity)| CompareColumn::new(df.column(name)?, *similarity))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let block_on = block_on.str().unwrap();
+        let candidates = fuzzy_matches(block_on, block_on, &options.blocking, FuzzyKeep::All)?;
+        let is_duplicate = |a: usize, b: usize| {
+            if columns.is_empty() {
+                return true;
+            }
+            let total = columns
+                .iter()
+                .map(|column| column.similarity(a, b))
+                .sum::<f64>();
+            total / columns.len() as f64 >= options.threshold
+        };
+        let duplicates = POOL.install(|| {
+            candidates
+                .into_par_iter()
+                .filter(|(a, b, _)| a < b && is_duplicate(*a as usize, *b as usize))
+                .map(|(a, b, _)| (a, b))
+                .collect::<Vec<_>>()
+        });
+
+        let mut clusters = Clusters {
+            parent: (0..df.height() as IdxSize).collect(),
+        };
+        for (a, b) in duplicates {
+            clusters.union(a, b);
+        }
+
+        let mut ids = vec![0 as IdxSize; df.height()];
+        let mut n_clusters = 0;
+        for row in 0..df.height() {
+            let root = clusters.find(row as IdxSize) as usize;
+            ids[row] = if root == row {
+                n_clusters += 1;
+                n_clusters - 1
+            } else {
+                // The root is the first row of the cluster, so it has an id already.
+                ids[root]
+            };
+        }
+        Ok(IdxCa::from_vec(PlSmallStr::from_static("cluster_id"), ids))
+    }
+}
+
+impl DedupeSimilar for DataFrame {}
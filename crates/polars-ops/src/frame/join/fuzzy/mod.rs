@@ -0,0 +1,541 @@
+//! Joins on approximately equal strings.
+//!
+//! Comparing every pair of strings is too expensive for anything but small frames, so the right
+//! strings are indexed on their q-grams. A pair can only be within the requested distance if the
+//! strings share enough q-grams, only those candidates are compared.
+
+#[cfg(feature = "dedupe_similar")]
+mod dedupe;
+
+#[cfg(feature = "dedupe_similar")]
+pub use dedupe::{DedupeOptions, DedupeSimilar, Similarity};
+use polars_core::POOL;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Padding for the q-grams, so that the characters at the edges are part of as many q-grams as
+/// the other characters.
+const PAD: char = '\u{0}';
+/// Size of the q-grams that are indexed for Levenshtein distances.
+const LEVENSHTEIN_Q: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FuzzyMetric {
+    /// Match strings with at most `max_distance` single character insertions, deletions and
+    /// substitutions between them.
+    Levenshtein { max_distance: usize },
+    /// Match strings with a Jaro-Winkler similarity of at least `threshold`, in `(0, 1]`.
+    JaroWinkler { threshold: f64 },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FuzzyKeep {
+    /// Only keep the closest match of every left row. Ties keep the first right row.
+    #[default]
+    Best,
+    /// Keep every match.
+    All,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuzzyJoinOptions {
+    pub metric: FuzzyMetric,
+    pub keep: FuzzyKeep,
+    /// Add the distance (Levenshtein) or similarity (Jaro-Winkler) of every match as a column
+    /// with this name.
+    pub score_column: Option<PlSmallStr>,
+}
+
+impl FuzzyJoinOptions {
+    pub fn levenshtein(max_distance: usize) -> Self {
+        Self {
+            metric: FuzzyMetric::Levenshtein { max_distance },
+            keep: FuzzyKeep::default(),
+            score_column: None,
+        }
+    }
+
+    pub fn jaro_winkler(threshold: f64) -> Self {
+        Self {
+            metric: FuzzyMetric::JaroWinkler { threshold },
+            keep: FuzzyKeep::default(),
+            score_column: None,
+        }
+    }
+
+    pub fn with_keep(mut self, keep: FuzzyKeep) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    pub fn with_score_column(mut self, name: Option<PlSmallStr>) -> Self {
+        self.score_column = name;
+        self
+    }
+}
+
+impl FuzzyMetric {
+    /// Size of the q-grams that are indexed.
+    fn q(&self) -> usize {
+        match self {
+            Self::Levenshtein { .. } => LEVENSHTEIN_Q,
+            // There is no useful bound on shared bigrams for Jaro, but the matching characters
+            // can't exceed the shared characters.
+            Self::JaroWinkler { .. } => 1,
+        }
+    }
+
+    fn padding(&self) -> usize {
+        match self {
+            Self::Levenshtein { .. } => self.q() - 1,
+            Self::JaroWinkler { .. } => 0,
+        }
+    }
+
+    /// Whether a pair of strings with these lengths and this many shared q-grams could match.
+    fn is_candidate(&self, len_left: usize, len_right: usize, shared: usize) -> bool {
+        match *self {
+            Self::Levenshtein { max_distance } => {
+                len_left.abs_diff(len_right) <= max_distance
+                    && shared >= levenshtein_min_shared(len_left, len_right, max_distance)
+            },
+            Self::JaroWinkler { threshold } => {
+                if shared == 0 || len_left == 0 || len_right == 0 {
+                    return false;
+                }
+                let shared = shared.min(len_left).min(len_right) as f64;
+                let jaro = (shared / len_left as f64 + shared / len_right as f64 + 1.0) / 3.0;
+                // The prefix bonus is at most 4 * 0.1.
+                jaro + 0.4 * (1.0 - jaro) >= threshold
+            },
+        }
+    }
+
+    /// Whether a string of `len_left` characters can match strings of `len_right` characters
+    /// that don't share a single q-gram with it.
+    fn matches_without_shared(&self, len_left: usize, len_right: usize) -> bool {
+        match *self {
+            Self::Levenshtein { max_distance } => {
+                len_left.abs_diff(len_right) <= max_distance
+                    && levenshtein_min_shared(len_left, len_right, max_distance) == 0
+            },
+            // Two empty strings are identical.
+            Self::JaroWinkler { .. } => len_left == 0 && len_right == 0,
+        }
+    }
+
+    /// Score of the pair if it matches.
+    fn score(&self, left: &[char], right: &[char]) -> Option<f64> {
+        match *self {
+            Self::Levenshtein { max_distance } => {
+                levenshtein_bounded(left, right, max_distance).map(|d| d as f64)
+            },
+            Self::JaroWinkler { threshold } => {
+                let sim = jaro_winkler(left, right);
+                (sim >= threshold).then_some(sim)
+            },
+        }
+    }
+
+    fn is_better(&self, score: f64, than: f64) -> bool {
+        match self {
+            Self::Levenshtein { .. } => score < than,
+            Self::JaroWinkler { .. } => score > than,
+        }
+    }
+}
+
+/// Every edit changes at most `q` of the padded q-grams of a string, so this many q-grams are
+/// left in common.
+fn levenshtein_min_shared(len_left: usize, len_right: usize, max_distance: usize) -> usize {
+    (len_left.max(len_right) + LEVENSHTEIN_Q - 1).saturating_sub(max_distance * LEVENSHTEIN_Q)
+}
+
+/// Levenshtein distance between `a` and `b` if it is at most `max_distance`.
+pub(super) fn levenshtein_bounded(a: &[char], b: &[char], max_distance: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + (ca != cb) as usize;
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+            row_min = row_min.min(cur[j + 1]);
+        }
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    let distance = prev[b.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+pub(super) fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter_map(|(c, matched)| matched.then_some(*c));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(ca, cb)| *ca != cb)
+        .count()
+        / 2;
+
+    let m = a_matches.len() as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn to_chars(ca: &StringChunked, padding: usize) -> Vec<Option<Vec<char>>> {
+    ca.iter()
+        .map(|opt_s| {
+            opt_s.map(|s| {
+                let mut chars = vec![PAD; padding];
+                chars.extend(s.chars());
+                chars.resize(chars.len() + padding, PAD);
+                chars
+            })
+        })
+        .collect()
+}
+
+/// Count the q-grams of a string.
+fn qgram_counts(chars: &[char], q: usize) -> PlHashMap<&[char], u32> {
+    let mut counts = PlHashMap::with_capacity(chars.len());
+    for gram in chars.windows(q) {
+        *counts.entry(gram).or_insert(0) += 1;
+    }
+    counts
+}
+
+struct QGramIndex<'a> {
+    /// The rows containing a q-gram, with the number of occurrences.
+    postings: PlHashMap<&'a [char], Vec<(IdxSize, u32)>>,
+    /// The rows per string length.
+    by_length: PlHashMap<usize, Vec<IdxSize>>,
+}
+
+impl<'a> QGramIndex<'a> {
+    fn new(strings: &'a [Option<Vec<char>>], q: usize, padding: usize) -> Self {
+        let mut postings = PlHashMap::<_, Vec<_>>::new();
+        let mut by_length = PlHashMap::<_, Vec<_>>::new();
+        for (idx, chars) in strings.iter().enumerate() {
+            let Some(chars) = chars else {
+                continue;
+            };
+            for (gram, count) in qgram_counts(chars, q) {
+                postings
+                    .entry(gram)
+                    .or_default()
+                    .push((idx as IdxSize, count));
+            }
+            by_length
+                .entry(chars.len() - 2 * padding)
+                .or_default()
+                .push(idx as IdxSize);
+        }
+        Self {
+            postings,
+            by_length,
+        }
+    }
+}
+
+/// Find the matches of a chunk of left strings, as `(left, right, score)`.
+fn match_chunk(
+    left: &[Option<Vec<char>>],
+    offset: usize,
+    right: &[Option<Vec<char>>],
+    index: &QGramIndex,
+    metric: &FuzzyMetric,
+    keep: FuzzyKeep,
+) -> Vec<(IdxSize, IdxSize, f64)> {
+    let q = metric.q();
+    let padding = metric.padding();
+    let right_len = |idx: IdxSize| right[idx as usize].as_ref().unwrap().len() - 2 * padding;
+
+    let mut shared = vec![0u32; right.len()];
+    let mut touched = vec![];
+    let mut matches = vec![];
+    let mut out = vec![];
+
+    for (i, chars) in left.iter().enumerate() {
+        let Some(chars) = chars else {
+            continue;
+        };
+        let left_idx = (offset + i) as IdxSize;
+        let len = chars.len() - 2 * padding;
+
+        for (gram, count) in qgram_counts(chars, q) {
+            if let Some(rows) = index.postings.get(gram) {
+                for (row, right_count) in rows {
+                    let shared = &mut shared[*row as usize];
+                    if *shared == 0 {
+                        touched.push(*row);
+                    }
+                    *shared += count.min(*right_count);
+                }
+            }
+        }
+
+        let mut check = |row: IdxSize| {
+            let right_chars = right[row as usize].as_ref().unwrap();
+            let left_chars = &chars[padding..chars.len() - padding];
+            let right_chars = &right_chars[padding..right_chars.len() - padding];
+            if let Some(score) = metric.score(left_chars, right_chars) {
+                matches.push((row, score));
+            }
+        };
+        for row in touched.drain(..) {
+            let n_shared = std::mem::take(&mut shared[row as usize]) as usize;
+            let len_right = right_len(row);
+            // Rows of these lengths are all checked below.
+            if !metric.matches_without_shared(len, len_right)
+                && metric.is_candidate(len, len_right, n_shared)
+            {
+                check(row)
+            }
+        }
+        for (len_right, rows) in &index.by_length {
+            if metric.matches_without_shared(len, *len_right) {
+                rows.iter().for_each(|row| check(*row));
+            }
+        }
+
+        match keep {
+            FuzzyKeep::All => {
+                matches.sort_unstable_by_key(|(row, _)| *row);
+                out.extend(matches.drain(..).map(|(row, score)| (left_idx, row, score)));
+            },
+            FuzzyKeep::Best => {
+                let best = matches.drain(..).reduce(|best, (row, score)| {
+                    if metric.is_better(score, best.1) || (score == best.1 && row < best.0) {
+                        (row, score)
+                    } else {
+                        best
+                    }
+                });
+                out.extend(best.map(|(row, score)| (left_idx, row, score)));
+            },
+        }
+    }
+    out
+}
+
+/// Find the matching pairs of `left` and `right` strings, as `(left, right, score)` ordered by
+/// the left row.
+fn fuzzy_matches(
+    left: &StringChunked,
+    right: &StringChunked,
+    metric: &FuzzyMetric,
+    keep: FuzzyKeep,
+) -> PolarsResult<Vec<(IdxSize, IdxSize, f64)>> {
+    if let FuzzyMetric::JaroWinkler { threshold } = metric {
+        polars_ensure!(
+            *threshold > 0.0 && *threshold <= 1.0,
+            InvalidOperation: "Jaro-Winkler threshold must be in (0, 1], got {}", threshold
+        );
+    }
+
+    let padding = metric.padding();
+    let (left, right) = POOL.join(|| to_chars(left, padding), || to_chars(right, padding));
+    let index = QGramIndex::new(&right, metric.q(), padding);
+
+    let n_threads = POOL.current_num_threads();
+    let chunk_size = left.len().div_ceil(n_threads).max(1);
+    let matches = POOL.install(|| {
+        left.par_chunks(chunk_size)
+            .enumerate()
+            .map(|(i, chunk)| match_chunk(chunk, i * chunk_size, &right, &index, metric, keep))
+            .collect::<Vec<_>>()
+    });
+    Ok(matches.into_iter().flatten().collect())
+}
+
+pub trait FuzzyJoin: IntoDf {
+    /// Join the rows of `self` and `other` whose `left_on` and `right_on` strings are similar.
+    ///
+    /// This is an inner join: left rows without a match are dropped and, with [`FuzzyKeep::All`],
+    /// left rows with multiple matches are repeated. Null strings never match. The right strings
+    /// are indexed on their q-grams, so only pairs that share enough q-grams to possibly match
+    /// are compared instead of every pair.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn link(customers: &DataFrame, crm: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let options = FuzzyJoinOptions::levenshtein(2).with_score_column(Some("distance".into()));
+    ///     customers.join_fuzzy(crm, "name", "full_name", &options, None)
+    /// }
+    /// ```
+    fn join_fuzzy(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: &FuzzyJoinOptions,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        let metric = &options.metric;
+        let left_key = df.column(left_on)?;
+        let right_key = other.column(right_on)?;
+        for key in [left_key, right_key] {
+            polars_ensure!(
+                key.dtype() == &DataType::String,
+                InvalidOperation: "fuzzy join keys must be of type String, got {} for column '{}'",
+                key.dtype(), key.name()
+            );
+        }
+
+        let matches = fuzzy_matches(
+            left_key.str().unwrap(),
+            right_key.str().unwrap(),
+            metric,
+            options.keep,
+        )?;
+
+        let (left_idx, (right_idx, scores)): (Vec<_>, (Vec<_>, Vec<_>)) = matches
+            .into_iter()
+            .map(|(left, right, score)| (left, (right, score)))
+            .unzip();
+        let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, left_idx);
+        let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, right_idx);
+
+        // SAFETY: the indices are in bounds.
+        let (left_df, mut right_df) = POOL.join(
+            || unsafe { df.take_unchecked(&left_idx) },
+            || unsafe { other.take_unchecked(&right_idx) },
+        );
+        if let Some(name) = &options.score_column {
+            let score = match metric {
+                FuzzyMetric::Levenshtein { .. } => {
+                    let distances = scores.into_iter().map(|score| score as u32).collect();
+                    UInt32Chunked::from_vec(name.clone(), distances).into_series()
+                },
+                FuzzyMetric::JaroWinkler { .. } => {
+                    Float64Chunked::from_vec(name.clone(), scores).into_series()
+                },
+            };
+            right_df.with_column(score)?;
+        }
+        _finish_join(left_df, right_df, suffix)
+    }
+}
+
+impl FuzzyJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_levenshtein_bounded() {
+        assert_eq!(
+            levenshtein_bounded(&chars("kitten"), &chars("sitting"), 3),
+            Some(3)
+        );
+        assert_eq!(
+            levenshtein_bounded(&chars("kitten"), &chars("sitting"), 2),
+            None
+        );
+        assert_eq!(levenshtein_bounded(&chars(""), &chars("ab"), 2), Some(2));
+        assert_eq!(
+            levenshtein_bounded(&chars("héllo"), &chars("hello"), 1),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_join_fuzzy_matches_all_pairs() -> PolarsResult<()> {
+        // Short strings over a small alphabet, so that many pairs are close.
+        let strings = (0..300u32)
+            .map(|i| {
+                let len = (i * 7 % 6) as usize;
+                (0..len)
+                    .map(|j| (b'a' + ((i * 31 + j as u32 * 17) % 4) as u8) as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let left = DataFrame::new(vec![Series::new("a".into(), &strings[..150])])?;
+        let right = DataFrame::new(vec![Series::new("b".into(), &strings[150..])])?;
+
+        for max_distance in 0..4 {
+            let options = FuzzyJoinOptions::levenshtein(max_distance).with_keep(FuzzyKeep::All);
+            let out = left.join_fuzzy(&right, "a", "b", &options, None)?;
+            let expected = strings[..150]
+                .iter()
+                .flat_map(|a| strings[150..].iter().map(move |b| (a, b)))
+                .filter(|(a, b)| levenshtein_bounded(&chars(a), &chars(b), max_distance).is_some())
+                .count();
+            assert_eq!(out.height(), expected);
+        }
+
+        let options = FuzzyJoinOptions::jaro_winkler(0.8).with_keep(FuzzyKeep::All);
+        let out = left.join_fuzzy(&right, "a", "b", &options, None)?;
+        let expected = strings[..150]
+            .iter()
+            .flat_map(|a| strings[150..].iter().map(move |b| (a, b)))
+            .filter(|(a, b)| jaro_winkler(&chars(a), &chars(b)) >= 0.8)
+            .count();
+        assert_eq!(out.height(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let sim = jaro_winkler(&chars("MARTHA"), &chars("MARHTA"));
+        assert!((sim - 0.9611).abs() < 1e-4);
+        let sim = jaro_winkler(&chars("DIXON"), &chars("DICKSONX"));
+        assert!((sim - 0.8133).abs() < 1e-4);
+        assert_eq!(jaro_winkler(&chars("abc"), &chars("xyz")), 0.0);
+    }
+}
@@ -0,0 +1,68 @@
+use polars_utils::pl_str::PlSmallStr;
+
+use super::*;
+
+pub trait ListStructJoin: IntoDf {
+    /// Enrich the structs inside a `List(Struct)` column with the matching rows of `other`.
+    ///
+    /// Every struct element is matched on its `key_field` against the `right_on` column of
+    /// `other`, and the remaining columns of `other` are appended as extra struct fields. Elements
+    /// without a match get `null` values. The frame keeps its height and every list keeps its
+    /// length, so `other` must have unique keys.
+    ///
+    /// Only the key field is joined; the list is not exploded, so the other columns of `self`
+    /// are never repeated per list element.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn enrich_events(events: &DataFrame, users: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     // `events.payload` is a `List(Struct({"user_id": i64, ...}))`.
+    ///     events.join_list_struct(users, "payload", "user_id", "id", None)
+    /// }
+    /// ```
+    fn join_list_struct(
+        &self,
+        other: &DataFrame,
+        list_column: &str,
+        key_field: &str,
+        right_on: &str,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        let list = df.column(list_column)?.list()?;
+        polars_ensure!(
+            matches!(list.inner_dtype(), DataType::Struct(_)),
+            InvalidOperation: "expected a list of structs for column '{}', got {}",
+            list_column, list.dtype()
+        );
+
+        let enriched = list.apply_to_inner(&|values| {
+            let values = values.struct_()?;
+            let key = values.field_by_name(key_field)?;
+            let key_name = key.name().clone();
+
+            let mut args = JoinArgs::new(JoinType::Left);
+            args.validation = JoinValidation::ManyToOne;
+            let matched =
+                DataFrame::new(vec![key])?.join(other, [key_name.clone()], [right_on], args)?;
+            let matched = matched.drop(&key_name)?;
+
+            let fields = DataFrame::new(values.fields_as_series())?;
+            let mut fields = _finish_join(fields, matched, suffix.clone())?;
+            fields.as_single_chunk_par();
+            let validity = values.chunks()[0].validity().cloned();
+            let out = StructChunked::from_series(values.name().clone(), fields.get_columns())?
+                .with_outer_validity(validity);
+            Ok(out.into_series())
+        })?;
+
+        let mut out = df.clone();
+        out.with_column(enriched.into_series())?;
+        Ok(out)
+    }
+}
+
+impl ListStructJoin for DataFrame {}
@@ -5,8 +5,12 @@ mod asof;
 mod checks;
 mod cross_join;
 mod dispatch_left_right;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
+#[cfg(feature = "list_struct_join")]
+mod list_struct;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -23,11 +27,17 @@ pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "dedupe_similar")]
+pub use fuzzy::{DedupeOptions, DedupeSimilar, Similarity};
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::{FuzzyJoin, FuzzyJoinOptions, FuzzyKeep, FuzzyMetric};
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "list_struct_join")]
+pub use list_struct::ListStructJoin;
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
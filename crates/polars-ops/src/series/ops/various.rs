@@ -125,7 +125,19 @@ pub trait SeriesMethods: SeriesSealed {
         if s.dtype().is_numeric() {
             with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                return Ok(is_sorted_ca_num::<$T>(ca, options))
+                let sorted = is_sorted_ca_num::<$T>(ca, options);
+                // Without nulls the order of the nulls doesn't matter, so the verified order
+                // can be cached in the flags, which clones of the column share.
+                if sorted && null_count == 0 {
+                    if let Some(mut md) = ca.metadata_arc().try_write() {
+                        md.set_sorted_flag(if options.descending {
+                            IsSorted::Descending
+                        } else {
+                            IsSorted::Ascending
+                        });
+                    }
+                }
+                return Ok(sorted)
             })
         }
 
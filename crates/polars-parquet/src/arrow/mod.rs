@@ -5,4 +5,5 @@ pub mod write;
 #[cfg_attr(docsrs, doc(cfg(feature = "bloom_filter")))]
 pub use crate::parquet::bloom_filter;
 
-const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The key of the file metadata that holds the serialized Arrow schema.
+pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
@@ -17,6 +17,7 @@ use futures::{AsyncRead, AsyncSeek};
 use polars_error::PolarsResult;
 pub use schema::{infer_schema, FileMetaData};
 
+use crate::parquet::encryption::FileDecryptionProperties;
 use crate::parquet::error::ParquetResult;
 #[cfg(feature = "async")]
 pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
@@ -24,11 +25,13 @@ pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_met
 pub use crate::parquet::{
     error::ParquetError,
     fallible_streaming_iterator,
-    metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData},
+    metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData, SortingColumn},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        decompress, get_column_iterator, read_metadata as _read_metadata, BasicDecompressor,
-        MutStreamingIterator, PageReader, ReadColumnIterator, State,
+        decompress, get_column_iterator, read_column_index, read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption, read_offset_index,
+        recycle_buffer, take_buffer, BasicDecompressor, BoundaryOrder, ColumnIndex,
+        MutStreamingIterator, OffsetIndex, PageLocation, PageReader, ReadColumnIterator, State,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +61,14 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetaDat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting it if the file is encrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: &FileDecryptionProperties,
+) -> PolarsResult<FileMetaData> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -37,6 +37,11 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
         },
         Binary | LargeBinary => dtype = BinaryView,
         Utf8 | LargeUtf8 => dtype = Utf8View,
+        // Extension types other than that of Polars are read as their storage type, their name
+        // and metadata remain in the metadata of the field.
+        Extension(ref name, inner, _) if name.as_str() != "POLARS_EXTENSION_TYPE" => {
+            dtype = convert_dtype(*inner);
+        },
         Dictionary(_, ref mut dtype, _) | Extension(_, ref mut dtype, _) => {
             let dtype = dtype.as_mut();
             *dtype = convert_dtype(std::mem::take(dtype));
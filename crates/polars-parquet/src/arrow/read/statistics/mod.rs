@@ -6,8 +6,10 @@ use arrow::datatypes::{ArrowDataType, Field, IntervalUnit, PhysicalType};
 use arrow::types::i256;
 use arrow::with_match_primitive_type_full;
 use ethnum::I256;
+use parquet_format_safe::Statistics as ThriftStatistics;
 use polars_error::{polars_bail, PolarsResult};
 
+use crate::parquet::read::ColumnIndex;
 use crate::parquet::schema::types::{
     PhysicalType as ParquetPhysicalType, PrimitiveType as ParquetPrimitiveType,
 };
@@ -572,3 +574,37 @@ pub fn deserialize(field: &Field, field_md: &[&ColumnChunkMetaData]) -> PolarsRe
 
     Ok(statistics.into())
 }
+
+/// Deserializes the [`ColumnIndex`] of a flat column into [`Statistics`] with one value per data
+/// page.
+pub fn deserialize_column_index(
+    field: &Field,
+    column: &ColumnChunkMetaData,
+    index: &ColumnIndex,
+) -> PolarsResult<Statistics> {
+    let mut statistics = MutableStatistics::try_new(field)?;
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+
+    for (i, is_null_page) in index.null_pages.iter().enumerate() {
+        let null_count = index.null_counts.as_ref().and_then(|c| c.get(i).copied());
+        let (min_value, max_value) = if *is_null_page {
+            (None, None)
+        } else {
+            (
+                index.min_values.get(i).cloned(),
+                index.max_values.get(i).cloned(),
+            )
+        };
+        let page = ThriftStatistics::new(None, None, null_count, None, max_value, min_value);
+        let page = ParquetStatistics::deserialize(&page, primitive_type.clone())?;
+        push(
+            &mut VecDeque::from([(Some(page), primitive_type.clone())]),
+            statistics.min_value.as_mut(),
+            statistics.max_value.as_mut(),
+            statistics.distinct_count.as_mut(),
+            statistics.null_count.as_mut(),
+        )?;
+    }
+
+    Ok(statistics.into())
+}
@@ -5,7 +5,7 @@ use polars_error::PolarsResult;
 
 use super::super::{utils, WriteOptions};
 use crate::arrow::read::schema::is_nullable;
-use crate::parquet::encoding::{delta_bitpacked, Encoding};
+use crate::parquet::encoding::{delta_bitpacked, delta_byte_array, Encoding};
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::write::utils::invalid_encoding;
@@ -76,6 +76,7 @@ pub fn array_to_page<O: Offset>(
             encode_options,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, encode_options, &mut buffer),
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
@@ -155,6 +156,19 @@ pub(crate) fn encode_delta<O: Offset>(
     )
 }
 
+pub(crate) fn encode_delta_byte_array<O: Offset>(
+    array: &BinaryArray<O>,
+    options: EncodeNullability,
+    buffer: &mut Vec<u8>,
+) {
+    if options.is_optional() && array.validity().is_some() {
+        let values = array.non_null_values_iter().collect::<Vec<_>>();
+        delta_byte_array::encode(values.into_iter(), buffer);
+    } else {
+        delta_byte_array::encode(array.values_iter(), buffer);
+    }
+}
+
 /// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
 /// of statistics.
 #[inline(always)]
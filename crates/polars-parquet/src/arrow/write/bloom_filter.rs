@@ -0,0 +1,108 @@
+use arrow::array::*;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::{NativeType, Offset};
+
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, num_bytes};
+use crate::parquet::schema::types::PhysicalType;
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+fn hash_integers<T, P>(array: &dyn Array) -> Vec<u64>
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array: &PrimitiveArray<T> = array.as_any().downcast_ref().unwrap();
+    array
+        .non_null_values_iter()
+        .map(|v| hash_native(v.as_()))
+        .collect()
+}
+
+fn hash_binary<O: Offset>(array: &dyn Array) -> Vec<u64> {
+    let array: &BinaryArray<O> = array.as_any().downcast_ref().unwrap();
+    array.non_null_values_iter().map(hash_byte).collect()
+}
+
+fn hash_utf8<O: Offset>(array: &dyn Array) -> Vec<u64> {
+    let array: &Utf8Array<O> = array.as_any().downcast_ref().unwrap();
+    array.non_null_values_iter().map(hash_byte).collect()
+}
+
+/// Hashes the non-null values of a flat `array` the way they are stored in a column of the
+/// returned [`PhysicalType`].
+///
+/// Returns `None` for types that have no bloom filter. Floats are not supported as equal
+/// values, such as `0.0` and `-0.0`, can have different representations.
+pub fn hash_array(array: &dyn Array) -> Option<(PhysicalType, Vec<u64>)> {
+    use ArrowDataType as D;
+    let out = match array.dtype().to_logical_type() {
+        D::Int8 => (PhysicalType::Int32, hash_integers::<i8, i32>(array)),
+        D::Int16 => (PhysicalType::Int32, hash_integers::<i16, i32>(array)),
+        D::Int32 | D::Date32 | D::Time32(_) => {
+            (PhysicalType::Int32, hash_integers::<i32, i32>(array))
+        },
+        D::UInt8 => (PhysicalType::Int32, hash_integers::<u8, i32>(array)),
+        D::UInt16 => (PhysicalType::Int32, hash_integers::<u16, i32>(array)),
+        D::UInt32 => (PhysicalType::Int32, hash_integers::<u32, i32>(array)),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            (PhysicalType::Int64, hash_integers::<i64, i64>(array))
+        },
+        D::UInt64 => (PhysicalType::Int64, hash_integers::<u64, i64>(array)),
+        D::Utf8View => {
+            let array: &Utf8ViewArray = array.as_any().downcast_ref().unwrap();
+            let hashes = array.non_null_values_iter().map(hash_byte).collect();
+            (PhysicalType::ByteArray, hashes)
+        },
+        D::BinaryView => {
+            let array: &BinaryViewArray = array.as_any().downcast_ref().unwrap();
+            let hashes = array.non_null_values_iter().map(hash_byte).collect();
+            (PhysicalType::ByteArray, hashes)
+        },
+        D::Utf8 => (PhysicalType::ByteArray, hash_utf8::<i32>(array)),
+        D::LargeUtf8 => (PhysicalType::ByteArray, hash_utf8::<i64>(array)),
+        D::Binary => (PhysicalType::ByteArray, hash_binary::<i32>(array)),
+        D::LargeBinary => (PhysicalType::ByteArray, hash_binary::<i64>(array)),
+        _ => return None,
+    };
+    Some(out)
+}
+
+/// Builds the split block bloom filter bitset of a flat `array` with a false positive
+/// probability of at most `fpp`.
+///
+/// Returns `None` if the type of `array` is not supported, see [`hash_array`].
+pub fn array_to_bloom_filter(array: &dyn Array, fpp: f64) -> Option<Vec<u8>> {
+    let (_, mut hashes) = hash_array(array)?;
+    hashes.sort_unstable();
+    hashes.dedup();
+
+    let mut bitset = vec![0; num_bytes(hashes.len(), fpp)];
+    for hash in hashes {
+        insert(&mut bitset, hash);
+    }
+    Some(bitset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parquet::bloom_filter::is_in_set;
+
+    #[test]
+    fn test_array_to_bloom_filter() {
+        let array = Utf8ViewArray::from_slice([Some("a"), None, Some("b"), Some("a")]);
+        let bitset = array_to_bloom_filter(&array, 0.01).unwrap();
+        assert_eq!(bitset.len(), 32);
+        assert!(is_in_set(&bitset, hash_byte("a")));
+        assert!(is_in_set(&bitset, hash_byte("b")));
+
+        // Small integers are stored and hashed as 32 bit integers.
+        let array = PrimitiveArray::<u8>::from_slice([1, 200]);
+        let (physical_type, hashes) = hash_array(&array).unwrap();
+        assert_eq!(physical_type, PhysicalType::Int32);
+        assert_eq!(hashes, [hash_native(1i32), hash_native(200i32)]);
+
+        assert!(hash_array(&PrimitiveArray::<f64>::from_slice([1.0])).is_none());
+        assert!(hash_array(&BooleanArray::from_slice([true])).is_none());
+    }
+}
@@ -5,7 +5,8 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{to_parquet_schema, ThriftFileMetaData, WriteOptions};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::encryption::FileEncryptionProperties;
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// Attaches [`ArrowSchema`] to `key_value_metadata`
@@ -70,11 +71,41 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Encrypt the file with Parquet modular encryption.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
+    /// Writes key-value metadata to the column chunks of the file, given for each leaf column.
+    pub fn with_column_key_value_metadata(
+        mut self,
+        key_value_metadata: Vec<Option<Vec<KeyValue>>>,
+    ) -> Self {
+        self.writer = self
+            .writer
+            .with_column_key_value_metadata(key_value_metadata);
+        self
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIterColumns<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file together with the bloom filter bitsets of its leaf columns
+    /// and the leaf columns that its rows are sorted by.
+    pub fn write_with_metadata(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_metadata(row_group, bloom_filters, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
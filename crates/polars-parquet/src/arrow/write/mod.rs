@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -30,6 +32,8 @@ mod utils;
 use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::types::{days_ms, i256, NativeType};
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filter, hash_array};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -38,7 +42,7 @@ pub use utils::write_def_levels;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetaData, KeyValue, SchemaDescriptor, ThriftFileMetaData,
+    Descriptor, FileMetaData, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetaData,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::types::PrimitiveType as ParquetPrimitiveType;
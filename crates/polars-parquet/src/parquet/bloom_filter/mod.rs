@@ -5,7 +5,7 @@ mod split_block;
 
 pub use hash::{hash_byte, hash_native};
 pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use split_block::{insert, is_in_set, num_bytes};
 
 #[cfg(test)]
 mod tests {
@@ -50,6 +50,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sizing() {
+        assert_eq!(num_bytes(0, 0.01), 32);
+        assert_eq!(num_bytes(10, 0.01), 32);
+        // ~9.6 bits per value for a 1% false positive rate.
+        assert_eq!(num_bytes(100_000, 0.01), 128 * 1024);
+        assert_eq!(num_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+
+        let mut bitset = vec![0; num_bytes(1000, 0.01)];
+        for a in 0..1000i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+        let false_positives = (1000..11000i64)
+            .filter(|a| is_in_set(&bitset, hash_native(*a)))
+            .count();
+        assert!(false_positives < 200);
+    }
+
     #[test]
     fn binary() {
         let mut bitset = vec![0; 32];
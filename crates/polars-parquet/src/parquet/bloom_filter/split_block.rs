@@ -3,6 +3,20 @@ const SALT: [u32; 8] = [
     1203114875, 1150766481, 2284105051, 2729912477, 1884591559, 770785867, 2667333959, 1550580529,
 ];
 
+/// The smallest bitset, a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The largest bitset, as recommended by the specification.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset holding `ndv` distinct values with a false positive
+/// probability of at most `fpp`.
+pub fn num_bytes(ndv: usize, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    ((num_bits / 8.0).ceil() as usize)
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
 fn hash_to_block_index(hash: u64, len: usize) -> usize {
     let number_of_blocks = len as u64 / 32;
     let low_hash = hash >> 32;
@@ -18,7 +18,8 @@ pub fn encode<'a, I: ExactSizeIterator<Item = &'a [u8]> + Clone>(
                 .enumerate()
                 // find first difference
                 .find_map(|(length, (lhs, rhs))| (lhs != rhs).then_some(length))
-                .unwrap_or(previous.len());
+                // the shorter of the two is a prefix of the other
+                .unwrap_or(previous.len().min(item.len()));
             previous = item;
 
             sum_lengths += item.len() - prefix_length;
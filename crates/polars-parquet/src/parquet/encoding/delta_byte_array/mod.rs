@@ -24,4 +24,24 @@ mod tests {
         assert_eq!(values, b"Helloicopter");
         Ok(())
     }
+
+    #[test]
+    fn prefix_of_previous() -> Result<(), ParquetError> {
+        let data = vec![b"Hello".as_ref(), b"Hell", b"", b"Hello"];
+        let mut buffer = vec![];
+        encode(data.clone().into_iter(), &mut buffer);
+
+        let mut decoder = Decoder::try_new(&buffer)?;
+        let prefixes = decoder.by_ref().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            prefixes,
+            vec![
+                b"Hello".to_vec(),
+                b"Hell".to_vec(),
+                vec![],
+                b"Hello".to_vec()
+            ]
+        );
+        Ok(())
+    }
 }
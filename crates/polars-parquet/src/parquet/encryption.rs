@@ -0,0 +1,628 @@
+//! Parquet modular encryption, see
+//! [the specification](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Only the `AES_GCM_V1` algorithm is supported. Every encrypted module (page, page header,
+//! column metadata, page index and footer) is serialized as
+//! `length (4 bytes, LE) | nonce (12 bytes) | ciphertext | tag (16 bytes)` and authenticated with
+//! an AAD that binds it to its position in the file.
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use parquet_format_safe::{
+    AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde_types")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "encryption"))]
+use crate::parquet::error::Feature;
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::SchemaDescriptor;
+
+pub(crate) const SIZE_LEN: usize = 4;
+#[cfg(feature = "encryption")]
+const NONCE_LEN: usize = 12;
+#[cfg(feature = "encryption")]
+const TAG_LEN: usize = 16;
+#[cfg(feature = "encryption")]
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// The module types of the AAD suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+}
+
+/// An AES key (16 or 32 bytes) together with the metadata stored in the file to identify it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct EncryptionKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+impl EncryptionKey {
+    pub fn new(key: Vec<u8>) -> Self {
+        Self {
+            key,
+            key_metadata: None,
+        }
+    }
+
+    /// Store `key_metadata` in the file so that readers can retrieve the key with a
+    /// [`KeyRetriever`].
+    pub fn with_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    pub fn key_metadata(&self) -> Option<&[u8]> {
+        self.key_metadata.as_deref()
+    }
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("key", &"<redacted>")
+            .field("key_metadata", &self.key_metadata)
+            .finish()
+    }
+}
+
+/// The keys and settings used to write an encrypted parquet file.
+///
+/// The footer is always encrypted with the footer key. If no column keys are given, all columns
+/// are encrypted with the footer key as well. Otherwise only the columns with a key are encrypted,
+/// each with their own key, and the remaining columns are written in plaintext.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct FileEncryptionProperties {
+    footer_key: EncryptionKey,
+    /// Column keys by the dot separated path of the column.
+    column_keys: BTreeMap<String, EncryptionKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    pub fn new(footer_key: EncryptionKey) -> Self {
+        Self {
+            footer_key,
+            column_keys: BTreeMap::new(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    /// Encrypt the column at the dot separated `path` with `key`.
+    pub fn with_column_key(mut self, path: impl Into<String>, key: EncryptionKey) -> Self {
+        self.column_keys.insert(path.into(), key);
+        self
+    }
+
+    /// Bind the file to `aad_prefix`. If `store` is `false`, the prefix is not written to the file
+    /// and readers must supply it.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>, store: bool) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self.store_aad_prefix = store;
+        self
+    }
+
+    pub fn footer_key(&self) -> &EncryptionKey {
+        &self.footer_key
+    }
+
+    pub fn column_keys(&self) -> &BTreeMap<String, EncryptionKey> {
+        &self.column_keys
+    }
+}
+
+/// Retrieves the key of an encrypted file or column from the key metadata stored in the file.
+pub trait KeyRetriever: Send + Sync {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// The keys used to read an encrypted parquet file.
+///
+/// Explicitly set keys take precedence over the [`KeyRetriever`].
+#[derive(Clone, Default)]
+pub struct FileDecryptionProperties {
+    footer_key: Option<Vec<u8>>,
+    column_keys: PlHashMap<String, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_footer_key(mut self, key: Vec<u8>) -> Self {
+        self.footer_key = Some(key);
+        self
+    }
+
+    /// Decrypt the column at the dot separated `path` with `key`.
+    pub fn with_column_key(mut self, path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys.insert(path.into(), key);
+        self
+    }
+
+    pub fn with_key_retriever(mut self, key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(key_retriever);
+        self
+    }
+
+    /// The AAD prefix of files written without storing it.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    fn retrieve(&self, key_metadata: Option<&[u8]>, what: &str) -> ParquetResult<Vec<u8>> {
+        match (&self.key_retriever, key_metadata) {
+            (Some(retriever), Some(key_metadata)) => retriever.retrieve_key(key_metadata),
+            _ => Err(ParquetError::InvalidParameter(format!(
+                "no decryption key available for the {what}"
+            ))),
+        }
+    }
+
+    pub(crate) fn footer_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Vec<u8>> {
+        match &self.footer_key {
+            Some(key) => Ok(key.clone()),
+            None => self.retrieve(key_metadata, "footer"),
+        }
+    }
+
+    pub(crate) fn column_key(
+        &self,
+        path: &str,
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Vec<u8>> {
+        match self.column_keys.get(path) {
+            Some(key) => Ok(key.clone()),
+            None => self.retrieve(key_metadata, &format!("column '{path}'")),
+        }
+    }
+
+    /// Returns the AAD of the file, combining the prefix with the unique part stored in the file.
+    pub(crate) fn file_aad(&self, algorithm: &EncryptionAlgorithm) -> ParquetResult<Vec<u8>> {
+        let EncryptionAlgorithm::AESGCMV1(algorithm) = algorithm else {
+            return Err(ParquetError::not_supported(
+                "the AES_GCM_CTR_V1 encryption algorithm",
+            ));
+        };
+        let aad_prefix = match (&self.aad_prefix, &algorithm.aad_prefix) {
+            (Some(prefix), _) | (None, Some(prefix)) => prefix.as_slice(),
+            (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was written with an AAD prefix that must be supplied".to_string(),
+                ))
+            },
+            (None, None) => &[],
+        };
+        let aad_file_unique = algorithm.aad_file_unique.as_deref().unwrap_or_default();
+        Ok([aad_prefix, aad_file_unique].concat())
+    }
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .field(
+                "footer_key",
+                &self.footer_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field("columns", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix)
+            .finish()
+    }
+}
+
+/// An AES-GCM key used to encrypt and decrypt modules.
+#[derive(Clone)]
+pub(crate) struct Cipher {
+    #[cfg(feature = "encryption")]
+    key: ring::aead::LessSafeKey,
+}
+
+impl Cipher {
+    #[cfg(feature = "encryption")]
+    pub(crate) fn try_new(key: &[u8]) -> ParquetResult<Self> {
+        use ring::aead::{LessSafeKey, UnboundKey, AES_128_GCM, AES_256_GCM};
+
+        let algorithm = match key.len() {
+            16 => &AES_128_GCM,
+            32 => &AES_256_GCM,
+            len => {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "encryption keys must be 16 or 32 bytes long, got {len}"
+                )))
+            },
+        };
+        let key = UnboundKey::new(algorithm, key)
+            .map_err(|_| ParquetError::InvalidParameter("invalid encryption key".to_string()))?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn try_new(_key: &[u8]) -> ParquetResult<Self> {
+        Err(ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "encrypt or decrypt parquet files".to_string(),
+        ))
+    }
+
+    /// Encrypts `plaintext` into a module with a random nonce.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn encrypt(&self, aad: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+        use ring::aead::{Aad, Nonce};
+
+        let nonce: [u8; NONCE_LEN] = random_bytes()?;
+        let len = NONCE_LEN + plaintext.len() + TAG_LEN;
+        let mut module = Vec::with_capacity(SIZE_LEN + len);
+        module.extend_from_slice(&u32::try_from(len)?.to_le_bytes());
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(plaintext);
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut module[SIZE_LEN + NONCE_LEN..],
+            )
+            .map_err(|_| ParquetError::oos("failed to encrypt module"))?;
+        module.extend_from_slice(tag.as_ref());
+        Ok(module)
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn encrypt(&self, _aad: &[u8], _plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+        unreachable!("a cipher cannot be created without the encryption feature")
+    }
+
+    /// Decrypts a module, including its length prefix.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn decrypt(&self, aad: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+        use ring::aead::{Aad, Nonce};
+
+        let len = module_len(module)?;
+        if module.len() != SIZE_LEN + len || len < NONCE_LEN + TAG_LEN {
+            return Err(ParquetError::oos("encrypted module has an invalid length"));
+        }
+        let nonce = Nonce::try_assume_unique_for_key(&module[SIZE_LEN..SIZE_LEN + NONCE_LEN])
+            .map_err(|_| ParquetError::oos("encrypted module has an invalid nonce"))?;
+        let mut buffer = module[SIZE_LEN + NONCE_LEN..].to_vec();
+        let plaintext_len = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut buffer)
+            .map_err(|_| {
+                ParquetError::oos("failed to decrypt module: wrong key or corrupted data")
+            })?
+            .len();
+        buffer.truncate(plaintext_len);
+        Ok(buffer)
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn decrypt(&self, _aad: &[u8], _module: &[u8]) -> ParquetResult<Vec<u8>> {
+        unreachable!("a cipher cannot be created without the encryption feature")
+    }
+
+    /// Verifies the signature (`nonce | tag`) of a plaintext footer.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn verify(&self, aad: &[u8], plaintext: &[u8], signature: &[u8]) -> bool {
+        use ring::aead::{Aad, Nonce};
+        use ring::constant_time::verify_slices_are_equal;
+
+        if signature.len() != NONCE_LEN + TAG_LEN {
+            return false;
+        }
+        let Ok(nonce) = Nonce::try_assume_unique_for_key(&signature[..NONCE_LEN]) else {
+            return false;
+        };
+        let mut buffer = plaintext.to_vec();
+        let Ok(tag) = self
+            .key
+            .seal_in_place_separate_tag(nonce, Aad::from(aad), &mut buffer)
+        else {
+            return false;
+        };
+        // In constant time, so the time it takes doesn't tell how much of a forged tag matches.
+        verify_slices_are_equal(tag.as_ref(), &signature[NONCE_LEN..]).is_ok()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn verify(&self, _aad: &[u8], _plaintext: &[u8], _signature: &[u8]) -> bool {
+        unreachable!("a cipher cannot be created without the encryption feature")
+    }
+}
+
+#[cfg(feature = "encryption")]
+fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    let mut bytes = [0; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| ParquetError::oos("failed to generate random bytes"))?;
+    Ok(bytes)
+}
+
+/// Returns the length of a module, excluding its length prefix.
+pub(crate) fn module_len(module: &[u8]) -> ParquetResult<usize> {
+    let len: [u8; SIZE_LEN] = module
+        .get(..SIZE_LEN)
+        .ok_or_else(|| ParquetError::oos("encrypted module is too short"))?
+        .try_into()?;
+    Ok(u32::from_le_bytes(len) as usize)
+}
+
+pub(crate) fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: i16,
+    column: i16,
+    page: Option<i16>,
+) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if module_type != ModuleType::Footer {
+        aad.extend_from_slice(&row_group.to_le_bytes());
+        aad.extend_from_slice(&column.to_le_bytes());
+        if let Some(page) = page {
+            aad.extend_from_slice(&page.to_le_bytes());
+        }
+    }
+    aad
+}
+
+fn column_path(path: &[PlSmallStr]) -> String {
+    path.iter()
+        .map(|x| x.as_str())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn ordinal(value: usize, what: &str) -> ParquetResult<i16> {
+    value.try_into().map_err(|_| {
+        ParquetError::not_supported(format!("encrypting more than {} {what}", i16::MAX))
+    })
+}
+
+/// Encrypts and decrypts the modules of a single column chunk.
+#[derive(Clone)]
+pub(crate) struct ColumnCipher {
+    cipher: Cipher,
+    file_aad: Arc<[u8]>,
+    row_group: i16,
+    column: i16,
+}
+
+impl ColumnCipher {
+    pub(crate) fn new(cipher: Cipher, file_aad: Arc<[u8]>, row_group: i16, column: i16) -> Self {
+        Self {
+            cipher,
+            file_aad,
+            row_group,
+            column,
+        }
+    }
+
+    fn aad(&self, module_type: ModuleType, page: Option<usize>) -> ParquetResult<Vec<u8>> {
+        let page = page.map(|page| ordinal(page, "pages")).transpose()?;
+        Ok(module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        ))
+    }
+
+    /// Encrypts a module. `page` is the ordinal of the data page for data pages and their headers.
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        self.cipher
+            .encrypt(&self.aad(module_type, page)?, plaintext)
+    }
+
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        self.cipher.decrypt(&self.aad(module_type, page)?, module)
+    }
+}
+
+impl Debug for ColumnCipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnCipher")
+            .field("row_group", &self.row_group)
+            .field("column", &self.column)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The state used to encrypt a file while it is written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    footer: Cipher,
+    columns: PlHashMap<String, Cipher>,
+    aad_file_unique: Vec<u8>,
+    file_aad: Arc<[u8]>,
+}
+
+impl FileEncryptor {
+    #[cfg(feature = "encryption")]
+    pub(crate) fn try_new(properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        let footer = Cipher::try_new(&properties.footer_key.key)?;
+        let columns = properties
+            .column_keys
+            .iter()
+            .map(|(path, key)| Ok((path.clone(), Cipher::try_new(&key.key)?)))
+            .collect::<ParquetResult<_>>()?;
+        let aad_file_unique = random_bytes::<AAD_FILE_UNIQUE_LEN>()?.to_vec();
+        let aad_prefix = properties.aad_prefix.as_deref().unwrap_or_default();
+        let file_aad = [aad_prefix, &aad_file_unique].concat().into();
+        Ok(Self {
+            properties,
+            footer,
+            columns,
+            aad_file_unique,
+            file_aad,
+        })
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn try_new(_properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        Err(ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "encrypt parquet files".to_string(),
+        ))
+    }
+
+    /// Checks that all columns with a key are in `schema`.
+    pub(crate) fn validate(&self, schema: &SchemaDescriptor) -> ParquetResult<()> {
+        let paths = schema
+            .columns()
+            .iter()
+            .map(|column| column_path(&column.path_in_schema))
+            .collect::<Vec<_>>();
+        match self.columns.keys().find(|path| !paths.contains(path)) {
+            Some(path) => Err(ParquetError::InvalidParameter(format!(
+                "cannot encrypt column '{path}' as it is not in the schema"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn is_uniform(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Returns the cipher of a column, or `None` if the column is not encrypted.
+    pub(crate) fn column(
+        &self,
+        path: &[PlSmallStr],
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<Option<ColumnCipher>> {
+        let cipher = if self.is_uniform() {
+            &self.footer
+        } else {
+            match self.columns.get(&column_path(path)) {
+                Some(cipher) => cipher,
+                None => return Ok(None),
+            }
+        };
+        Ok(Some(ColumnCipher::new(
+            cipher.clone(),
+            self.file_aad.clone(),
+            ordinal(row_group, "row groups")?,
+            ordinal(column, "columns")?,
+        )))
+    }
+
+    /// The crypto metadata stored in the footer for an encrypted column.
+    pub(crate) fn column_crypto_metadata(&self, path: &[PlSmallStr]) -> ColumnCryptoMetaData {
+        if self.is_uniform() {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {})
+        } else {
+            let key = &self.properties.column_keys[&column_path(path)];
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                path_in_schema: path.iter().map(|x| x.to_string()).collect(),
+                key_metadata: key.key_metadata.clone(),
+            })
+        }
+    }
+
+    /// Whether the metadata of an encrypted column is encrypted separately from the footer.
+    pub(crate) fn has_column_key(&self) -> bool {
+        !self.is_uniform()
+    }
+
+    pub(crate) fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        let (aad_prefix, supply_aad_prefix) = match &self.properties.aad_prefix {
+            Some(prefix) if self.properties.store_aad_prefix => (Some(prefix.clone()), None),
+            Some(_) => (None, Some(true)),
+            None => (None, None),
+        };
+        FileCryptoMetaData {
+            encryption_algorithm: EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique: Some(self.aad_file_unique.clone()),
+                supply_aad_prefix,
+            }),
+            key_metadata: self.properties.footer_key.key_metadata.clone(),
+        }
+    }
+
+    pub(crate) fn encrypt_footer(&self, plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None);
+        self.footer.encrypt(&aad, plaintext)
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_roundtrip() {
+        let cipher = ColumnCipher::new(
+            Cipher::try_new(&[7; 16]).unwrap(),
+            Arc::from(b"file".as_slice()),
+            1,
+            2,
+        );
+        let module = cipher
+            .encrypt(ModuleType::DataPage, Some(3), b"page")
+            .unwrap();
+        assert_eq!(module.len(), SIZE_LEN + NONCE_LEN + 4 + TAG_LEN);
+        assert_eq!(module_len(&module).unwrap(), module.len() - SIZE_LEN);
+
+        let plaintext = cipher
+            .decrypt(ModuleType::DataPage, Some(3), &module)
+            .unwrap();
+        assert_eq!(plaintext, b"page");
+
+        // The AAD binds the module to its position in the file.
+        assert!(cipher
+            .decrypt(ModuleType::DataPage, Some(4), &module)
+            .is_err());
+        assert!(cipher
+            .decrypt(ModuleType::DictionaryPage, None, &module)
+            .is_err());
+    }
+
+    #[test]
+    fn invalid_key_length() {
+        assert!(Cipher::try_new(&[0; 24]).is_err());
+        assert!(Cipher::try_new(&[0; 32]).is_ok());
+    }
+}
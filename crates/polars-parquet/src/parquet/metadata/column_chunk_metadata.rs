@@ -1,7 +1,8 @@
-use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding};
+use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding, PageType};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnCipher;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -36,6 +37,9 @@ pub struct ColumnChunkMetaData {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// Decrypts the pages of encrypted columns.
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<ColumnCipher>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -73,6 +77,7 @@ impl ColumnChunkMetaData {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -163,6 +168,45 @@ impl ColumnChunkMetaData {
         &self.metadata().encodings
     }
 
+    /// Returns whether all data pages of the column chunk are dictionary-encoded.
+    ///
+    /// This uses the page encoding stats if the writer recorded them. Otherwise the dictionary
+    /// page is assumed to be the only page with `PLAIN` encoding if the data pages are
+    /// `RLE_DICTIONARY` encoded, as written by writers of format version 2.
+    pub fn is_fully_dictionary_encoded(&self) -> bool {
+        let metadata = self.metadata();
+        let is_dictionary = |encoding: &Encoding| {
+            matches!(
+                *encoding,
+                Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+            )
+        };
+
+        if let Some(stats) = &metadata.encoding_stats {
+            return stats
+                .iter()
+                .filter(|stats| stats.page_type != PageType::DICTIONARY_PAGE && stats.count > 0)
+                .all(|stats| is_dictionary(&stats.encoding));
+        }
+
+        let encodings = metadata
+            .encodings
+            .iter()
+            .filter(|encoding| !matches!(**encoding, Encoding::RLE | Encoding::BIT_PACKED))
+            .collect::<Vec<_>>();
+        match encodings.as_slice() {
+            [encoding] => is_dictionary(encoding),
+            [a, b] => {
+                matches!(
+                    (**a, **b),
+                    (Encoding::PLAIN, Encoding::RLE_DICTIONARY)
+                        | (Encoding::RLE_DICTIONARY, Encoding::PLAIN)
+                )
+            },
+            _ => false,
+        }
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> (u64, u64) {
         let start = if let Some(dict_page_offset) = self.dictionary_page_offset() {
@@ -190,6 +234,10 @@ impl ColumnChunkMetaData {
             let _: u64 = meta.data_page_offset.try_into()?;
 
             let _: Compression = meta.codec.try_into()?;
+        } else if column_chunk.encrypted_column_metadata.is_some() {
+            return Err(ParquetError::InvalidParameter(
+                "the column is encrypted, decryption keys are required".to_string(),
+            ));
         } else {
             return Err(ParquetError::oos("Column chunk requires metadata"));
         }
@@ -197,9 +245,19 @@ impl ColumnChunkMetaData {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         })
     }
 
+    /// The decryptor of an encrypted column.
+    pub(crate) fn decryptor(&self) -> Option<&ColumnCipher> {
+        self.decryptor.as_ref()
+    }
+
+    pub(crate) fn set_decryptor(&mut self, decryptor: ColumnCipher) {
+        self.decryptor = Some(decryptor);
+    }
+
     /// Method to convert to Thrift.
     pub fn into_thrift(self) -> ColumnChunk {
         self.column_chunk
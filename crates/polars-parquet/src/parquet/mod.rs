@@ -4,6 +4,7 @@ pub mod error;
 pub mod bloom_filter;
 pub mod compression;
 pub mod encoding;
+pub mod encryption;
 pub mod metadata;
 pub mod page;
 mod parquet_bridge;
@@ -22,6 +23,8 @@ pub use streaming_decompression::{fallible_streaming_iterator, FallibleStreaming
 pub const HEADER_SIZE: u64 = PARQUET_MAGIC.len() as u64;
 pub const FOOTER_SIZE: u64 = 8;
 pub const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+/// The magic of files whose footer is encrypted
+pub const PARQUET_ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
 
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
@@ -149,6 +149,9 @@ impl streaming_decompression::Decompressed for Page {
 /// This decompressor uses an internal [`Vec<u8>`] to perform decompressions which
 /// is reused across pages, so that a single allocation is required.
 /// If the pages are not compressed, the internal buffer is not used.
+/// On drop, the internal buffers are returned to the thread's buffer pool (see [`take_buffer`]).
+///
+/// [`take_buffer`]: super::take_buffer
 pub struct BasicDecompressor {
     reader: PageReader,
     buffer: Vec<u8>,
@@ -169,8 +172,8 @@ impl BasicDecompressor {
     }
 
     /// Returns its internal buffer, consuming itself.
-    pub fn into_inner(self) -> Vec<u8> {
-        self.buffer
+    pub fn into_inner(mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
     }
 
     pub fn read_dict_page(&mut self) -> ParquetResult<Option<DictPage>> {
@@ -203,6 +206,14 @@ impl BasicDecompressor {
     }
 }
 
+impl Drop for BasicDecompressor {
+    /// Hand the scratch buffers back to the pool so the next column chunk can reuse them.
+    fn drop(&mut self) {
+        super::recycle_buffer(std::mem::take(&mut self.buffer));
+        super::recycle_buffer(std::mem::take(&mut self.reader.scratch));
+    }
+}
+
 pub struct DataPageItem {
     page: CompressedDataPage,
 }
@@ -0,0 +1,67 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
+pub use parquet_format_safe::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::ParquetResult;
+use crate::parquet::metadata::ColumnChunkMetaData;
+
+/// Reads `length` bytes at `offset` of `reader`, if both are set.
+fn read_index_bytes<R: Read + Seek>(
+    reader: &mut R,
+    offset: Option<i64>,
+    length: Option<i32>,
+) -> ParquetResult<Option<Vec<u8>>> {
+    let (Some(offset), Some(length)) = (offset, length) else {
+        return Ok(None);
+    };
+    let length: usize = length.try_into()?;
+    reader.seek(SeekFrom::Start(offset.try_into()?))?;
+
+    let mut buffer = vec![];
+    buffer.try_reserve(length)?;
+    reader.take(length as u64).read_to_end(&mut buffer)?;
+    Ok(Some(buffer))
+}
+
+/// Reads the [`ColumnIndex`] of a column chunk, with the statistics of each of its data pages.
+///
+/// Returns `None` if the column chunk has no column index or if the index is encrypted.
+pub fn read_column_index<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> ParquetResult<Option<ColumnIndex>> {
+    let chunk = column.column_chunk();
+    if chunk.crypto_metadata.is_some() {
+        return Ok(None);
+    }
+    let Some(bytes) =
+        read_index_bytes(reader, chunk.column_index_offset, chunk.column_index_length)?
+    else {
+        return Ok(None);
+    };
+    // The index holds at most one min and max value and a few integers per byte.
+    let mut prot = TCompactInputProtocol::new(bytes.as_slice(), bytes.len() * 16);
+    Ok(Some(ColumnIndex::read_from_in_protocol(&mut prot)?))
+}
+
+/// Reads the [`OffsetIndex`] of a column chunk, with the location and first row of each of its
+/// data pages.
+///
+/// Returns `None` if the column chunk has no offset index or if the index is encrypted.
+pub fn read_offset_index<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> ParquetResult<Option<OffsetIndex>> {
+    let chunk = column.column_chunk();
+    if chunk.crypto_metadata.is_some() {
+        return Ok(None);
+    }
+    let Some(bytes) =
+        read_index_bytes(reader, chunk.offset_index_offset, chunk.offset_index_length)?
+    else {
+        return Ok(None);
+    };
+    let mut prot = TCompactInputProtocol::new(bytes.as_slice(), bytes.len() * 16);
+    Ok(Some(OffsetIndex::read_from_in_protocol(&mut prot)?))
+}
@@ -1,11 +1,19 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
 use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
-use parquet_format_safe::FileMetaData as TFileMetaData;
+use parquet_format_safe::{
+    ColumnCryptoMetaData, ColumnMetaData, FileCryptoMetaData, FileMetaData as TFileMetaData,
+};
 
 use super::super::metadata::FileMetaData;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
+use crate::parquet::encryption::{
+    module_aad, Cipher, ColumnCipher, FileDecryptionProperties, ModuleType,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -37,6 +45,24 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetaDa
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetaData> {
+    read_metadata_impl(reader, file_size, None)
+}
+
+/// Reads a [`FileMetaData`] of a file that may be encrypted, decrypting the footer and the
+/// metadata of the encrypted columns.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: &FileDecryptionProperties,
+) -> ParquetResult<FileMetaData> {
+    let file_size = stream_len(reader)?;
+    read_metadata_impl(reader, file_size, Some(decryption))
+}
+
+fn read_metadata_impl<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetaData> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,7 +81,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let encrypted_footer = buffer[default_end_len - 4..] == PARQUET_ENCRYPTED_MAGIC;
+    if !encrypted_footer && buffer[default_end_len - 4..] != PARQUET_MAGIC {
         return Err(ParquetError::oos("The file must end with PAR1"));
     }
 
@@ -88,7 +115,16 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    match decryption {
+        Some(decryption) => {
+            let reader = &reader[..reader.len() - FOOTER_SIZE as usize];
+            deserialize_encrypted_metadata(reader, encrypted_footer, decryption, max_size)
+        },
+        None if encrypted_footer => Err(ParquetError::InvalidParameter(
+            "the footer of the file is encrypted, decryption keys are required".to_string(),
+        )),
+        None => deserialize_metadata(reader, max_size),
+    }
 }
 
 /// Parse loaded metadata bytes
@@ -98,3 +134,89 @@ pub fn deserialize_metadata<R: Read>(reader: R, max_size: usize) -> ParquetResul
 
     FileMetaData::try_from_thrift(metadata)
 }
+
+/// Parse loaded metadata bytes of a file that may be encrypted.
+fn deserialize_encrypted_metadata(
+    mut reader: &[u8],
+    encrypted_footer: bool,
+    decryption: &FileDecryptionProperties,
+    max_size: usize,
+) -> ParquetResult<FileMetaData> {
+    let footer_bytes = reader;
+    let (mut metadata, file_aad, footer) = if encrypted_footer {
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+        let file_aad = decryption.file_aad(&crypto_metadata.encryption_algorithm)?;
+
+        let key = decryption.footer_key(crypto_metadata.key_metadata.as_deref())?;
+        let footer = Cipher::try_new(&key)?;
+        let aad = module_aad(&file_aad, ModuleType::Footer, 0, 0, None);
+        let plaintext = footer.decrypt(&aad, reader)?;
+
+        let mut prot = TCompactInputProtocol::new(plaintext.as_slice(), max_size);
+        let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+        (metadata, file_aad, Some(footer))
+    } else {
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+        let Some(algorithm) = &metadata.encryption_algorithm else {
+            return FileMetaData::try_from_thrift(metadata);
+        };
+        let file_aad = decryption.file_aad(algorithm)?;
+
+        // A plaintext footer is signed with the footer key, which readers of plaintext columns
+        // do not need to have.
+        let footer = decryption
+            .footer_key(metadata.footer_signing_key_metadata.as_deref())
+            .ok()
+            .map(|key| Cipher::try_new(&key))
+            .transpose()?;
+        if let Some(footer) = &footer {
+            let (plaintext, signature) = footer_bytes.split_at(footer_bytes.len() - reader.len());
+            let aad = module_aad(&file_aad, ModuleType::Footer, 0, 0, None);
+            if !footer.verify(&aad, plaintext, signature) {
+                return Err(ParquetError::oos("The signature of the footer is invalid"));
+            }
+        }
+        (metadata, file_aad, footer)
+    };
+
+    let file_aad: Arc<[u8]> = file_aad.into();
+    let mut decryptors = vec![];
+    for (i, row_group) in metadata.row_groups.iter_mut().enumerate() {
+        let ordinal = match row_group.ordinal {
+            Some(ordinal) => ordinal,
+            None => i.try_into()?,
+        };
+        for (j, column) in row_group.columns.iter_mut().enumerate() {
+            let cipher = match &column.crypto_metadata {
+                None => continue,
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                    footer.clone().ok_or_else(|| {
+                        ParquetError::InvalidParameter(
+                            "no decryption key available for the footer".to_string(),
+                        )
+                    })?
+                },
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(key)) => {
+                    let path = key.path_in_schema.join(".");
+                    Cipher::try_new(&decryption.column_key(&path, key.key_metadata.as_deref())?)?
+                },
+            };
+            let cipher = ColumnCipher::new(cipher, file_aad.clone(), ordinal, j.try_into()?);
+
+            if let Some(encrypted) = &column.encrypted_column_metadata {
+                let plaintext = cipher.decrypt(ModuleType::ColumnMetaData, None, encrypted)?;
+                let mut prot = TCompactInputProtocol::new(plaintext.as_slice(), max_size);
+                column.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+            }
+            decryptors.push((i, j, cipher));
+        }
+    }
+
+    let mut metadata = FileMetaData::try_from_thrift(metadata)?;
+    for (i, j, cipher) in decryptors {
+        metadata.row_groups[i].columns_mut()[j].set_decryptor(cipher);
+    }
+    Ok(metadata)
+}
@@ -1,8 +1,10 @@
 mod column;
 mod compression;
+mod indexes;
 pub mod levels;
 mod metadata;
 mod page;
+mod pool;
 #[cfg(feature = "async")]
 mod stream;
 
@@ -10,11 +12,17 @@ use std::io::{Seek, SeekFrom};
 
 pub use column::*;
 pub use compression::{decompress, BasicDecompressor};
-pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size};
+pub use indexes::{
+    read_column_index, read_offset_index, BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation,
+};
+pub use metadata::{
+    deserialize_metadata, read_metadata, read_metadata_with_decryption, read_metadata_with_size,
+};
 #[cfg(feature = "async")]
 pub use page::{get_page_stream, get_page_stream_from_column_start};
 pub use page::{PageIterator, PageMetaData, PageReader};
 use polars_utils::mmap::MemReader;
+pub use pool::{recycle_buffer, take_buffer};
 #[cfg(feature = "async")]
 pub use stream::read_metadata as read_metadata_async;
 
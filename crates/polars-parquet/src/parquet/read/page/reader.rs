@@ -6,6 +6,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 
 use super::PageIterator;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{module_len, ColumnCipher, ModuleType, SIZE_LEN};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetaData, Descriptor};
 use crate::parquet::page::{
@@ -80,6 +81,12 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Decrypts the pages of encrypted columns.
+    decryptor: Option<ColumnCipher>,
+
+    // The number of data pages we have seen so far, part of the AAD of encrypted pages.
+    page_ordinal: usize,
 }
 
 impl PageReader {
@@ -93,7 +100,10 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
-        Self::new_with_page_meta(reader, column.into(), scratch, max_page_size)
+        let mut page_reader =
+            Self::new_with_page_meta(reader, column.into(), scratch, max_page_size);
+        page_reader.decryptor = column.decryptor().cloned();
+        page_reader
     }
 
     /// Create a a new [`PageReader`] with [`PageMetaData`].
@@ -113,6 +123,8 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryptor: None,
+            page_ordinal: 0,
         }
     }
 
@@ -136,7 +148,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -145,6 +157,47 @@ impl PageReader {
             return Ok(None);
         }
 
+        let buffer = self.read_page_data(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+}
+
+impl PageReader {
+    /// Reads the next page header, decrypting it if the column is encrypted.
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = &self.decryptor else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let len = SIZE_LEN + module_len(&self.reader.read_slice(SIZE_LEN))?;
+        if len > self.max_page_size {
+            return Err(ParquetError::WouldOverAllocate);
+        }
+        self.reader
+            .seek(std::io::SeekFrom::Current(-(SIZE_LEN as i64)))?;
+        let module = self.reader.read_slice(len);
+
+        // Only the first page can be a dictionary page, whose header has a different AAD.
+        let header = if self.page_ordinal == 0 {
+            decryptor
+                .decrypt(ModuleType::DictionaryPageHeader, None, &module)
+                .or_else(|_| decryptor.decrypt(ModuleType::DataPageHeader, Some(0), &module))?
+        } else {
+            decryptor.decrypt(ModuleType::DataPageHeader, Some(self.page_ordinal), &module)?
+        };
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Reads the data of the page of `page_header`, decrypting it if the column is encrypted.
+    fn read_page_data(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<MemSlice> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -159,13 +212,18 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        let Some(decryptor) = &self.decryptor else {
+            return Ok(buffer);
+        };
+        let buffer = if matches!(page_header.type_.try_into()?, PageType::DictionaryPage) {
+            decryptor.decrypt(ModuleType::DictionaryPage, None, &buffer)?
+        } else {
+            let buffer =
+                decryptor.decrypt(ModuleType::DataPage, Some(self.page_ordinal), &buffer)?;
+            self.page_ordinal += 1;
+            buffer
+        };
+        Ok(MemSlice::from_vec(buffer))
     }
 }
 
@@ -209,23 +267,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page_data(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
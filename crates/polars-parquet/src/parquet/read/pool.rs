@@ -0,0 +1,73 @@
+//! A per-thread pool of byte buffers used while decoding column chunks.
+//!
+//! Every column chunk of every row group needs scratch space to read and decompress its pages.
+//! On files with many row groups, allocating that space anew for each chunk dominates the decode
+//! time, so the buffers of a finished chunk are returned here and handed to the next one.
+use std::cell::RefCell;
+
+/// The maximum number of buffers kept per thread.
+const MAX_POOLED_BUFFERS: usize = 16;
+/// Buffers larger than this are released instead of being kept around.
+const MAX_POOLED_CAPACITY: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    static BUFFER_POOL: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Take an empty buffer from the pool of the current thread, or allocate a new one if the pool
+/// is empty.
+pub fn take_buffer() -> Vec<u8> {
+    BUFFER_POOL
+        .with(|pool| pool.borrow_mut().pop())
+        .unwrap_or_default()
+}
+
+/// Return a buffer to the pool of the current thread so that its allocation can be reused.
+pub fn recycle_buffer(mut buffer: Vec<u8>) {
+    if buffer.capacity() == 0 || buffer.capacity() > MAX_POOLED_CAPACITY {
+        return;
+    }
+    buffer.clear();
+
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < MAX_POOLED_BUFFERS {
+            pool.push(buffer);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_is_reused() {
+        let mut buffer = take_buffer();
+        buffer.extend_from_slice(&[1, 2, 3]);
+        let ptr = buffer.as_ptr();
+        let capacity = buffer.capacity();
+        recycle_buffer(buffer);
+
+        let buffer = take_buffer();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_pool_is_bounded() {
+        for _ in 0..2 * MAX_POOLED_BUFFERS {
+            recycle_buffer(Vec::with_capacity(8));
+        }
+        recycle_buffer(Vec::with_capacity(MAX_POOLED_CAPACITY + 1));
+        recycle_buffer(Vec::new());
+
+        let mut taken = 0;
+        while BUFFER_POOL.with(|pool| !pool.borrow().is_empty()) {
+            assert_eq!(take_buffer().capacity(), 8);
+            taken += 1;
+        }
+        assert_eq!(taken, MAX_POOLED_BUFFERS);
+    }
+}
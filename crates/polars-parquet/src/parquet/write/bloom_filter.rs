@@ -0,0 +1,23 @@
+use std::io::Write;
+
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+use parquet_format_safe::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// Writes a split block bloom filter, its header followed by the `bitset`.
+pub fn write_bloom_filter<W: Write>(writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader::new(
+        bitset.len().try_into()?,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+    Ok(header_len + bitset.len() as u64)
+}
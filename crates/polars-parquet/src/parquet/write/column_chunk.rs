@@ -10,11 +10,12 @@ use polars_utils::aliases::PlHashSet;
 
 #[cfg(feature = "async")]
 use super::page::write_page_async;
-use super::page::{write_page, PageWriteSpec};
+use super::page::{is_data_page, write_encrypted_page, write_page, PageWriteSpec};
 use super::statistics::reduce;
 use super::DynStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnCipher;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -25,6 +26,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    cipher: Option<&ColumnCipher>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +38,27 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut page_ordinal = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = match cipher {
+            Some(cipher) => {
+                write_encrypted_page(writer, offset, compressed_page, cipher, page_ordinal)?
+            },
+            None => write_page(writer, offset, compressed_page)?,
+        };
+        page_ordinal += is_data_page(&spec) as usize;
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    // The metadata of encrypted columns is only written (encrypted) in the footer.
+    if cipher.is_some() {
+        column_chunk.file_offset = 0;
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
@@ -3,21 +3,53 @@ use std::io::Write;
 use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 use parquet_format_safe::RowGroup;
 
-use super::indexes::{write_column_index, write_offset_index};
+use super::bloom_filter::write_bloom_filter;
+use super::indexes::{
+    has_column_index_statistics, write_column_index, write_encrypted_column_index,
+    write_encrypted_offset_index, write_offset_index,
+};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    ColumnCipher, FileEncryptionProperties, FileEncryptor, ModuleType,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetaData};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetaData};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Writes the footer of a file with an encrypted footer.
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetaData,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut footer = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut footer);
+    encryptor
+        .file_crypto_metadata()
+        .write_to_out_protocol(&mut protocol)?;
+
+    let mut plaintext = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut plaintext);
+    metadata.write_to_out_protocol(&mut protocol)?;
+    footer.extend(encryptor.encrypt_footer(&plaintext)?);
+
+    let footer_len: i32 = footer.len().try_into()?;
+    writer.write_all(&footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetaData,
@@ -64,10 +96,15 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bloom filter bitsets of each column of each row group, written before the indexes
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
+    /// The key-value metadata of the column chunks of each column
+    column_key_value_metadata: Vec<Option<Vec<KeyValue>>>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetaData>,
+    encryptor: Option<FileEncryptor>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -123,8 +160,52 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
+            column_key_value_metadata: vec![],
             state: State::Initialised,
             metadata: None,
+            encryptor: None,
+        }
+    }
+
+    /// Encrypt the file with Parquet modular encryption.
+    ///
+    /// # Errors
+    /// Returns an error if a key is invalid, a column key refers to a column not in the schema or
+    /// the `encryption` feature is not active.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        let encryptor = FileEncryptor::try_new(properties)?;
+        encryptor.validate(&self.schema)?;
+        self.encryptor = Some(encryptor);
+        Ok(self)
+    }
+
+    /// Writes key-value metadata to the column chunks of the file, given for each leaf column.
+    ///
+    /// The metadata is written to the footer only, not to the metadata written after the pages
+    /// of each column chunk.
+    pub fn with_column_key_value_metadata(
+        mut self,
+        key_value_metadata: Vec<Option<Vec<KeyValue>>>,
+    ) -> Self {
+        self.column_key_value_metadata = key_value_metadata;
+        self
+    }
+
+    /// Returns the cipher of the column `column` in the row group `row_group`, if the column is
+    /// encrypted.
+    fn column_cipher(
+        &self,
+        row_group: usize,
+        column: usize,
+    ) -> ParquetResult<Option<ColumnCipher>> {
+        match &self.encryptor {
+            Some(encryptor) => encryptor.column(
+                &self.schema.columns()[column].path_in_schema,
+                row_group,
+                column,
+            ),
+            None => Ok(None),
         }
     }
 
@@ -136,7 +217,12 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = if self.encryptor.is_some() {
+                self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                PARQUET_ENCRYPTED_MAGIC.len() as u64
+            } else {
+                start_file(&mut self.writer)?
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -150,6 +236,25 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_metadata(row_group, vec![], None)
+    }
+
+    /// Writes a row group to the file together with the bloom filter bitsets of its columns and
+    /// the columns that its rows are sorted by.
+    ///
+    /// `bloom_filters` holds the split block bitset of each leaf column, missing entries mean no
+    /// bloom filter. The bitsets are written when the file is ended. Bloom filters of encrypted
+    /// columns are not written.
+    pub fn write_with_metadata<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -158,16 +263,29 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
+        for (column, key_value_metadata) in group
+            .columns
+            .iter_mut()
+            .zip(&self.column_key_value_metadata)
+        {
+            if let Some(meta_data) = &mut column.meta_data {
+                meta_data.key_value_metadata = key_value_metadata.clone();
+            }
+        }
+        group.sorting_columns =
+            sorting_columns.map(|columns| columns.into_iter().map(|c| c.into_thrift()).collect());
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -186,50 +304,90 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        for (i, bloom_filters) in std::mem::take(&mut self.bloom_filters)
+            .into_iter()
+            .enumerate()
+        {
+            for (j, bitset) in bloom_filters.into_iter().enumerate() {
+                let Some(bitset) = bitset else {
+                    continue;
+                };
+                if self.column_cipher(i, j)?.is_some() {
+                    continue;
+                }
+                let offset = self.offset;
+                self.offset += write_bloom_filter(&mut self.writer, &bitset)?;
+                if let Some(meta_data) = &mut self.row_groups[i].columns[j].meta_data {
+                    meta_data.bloom_filter_offset = Some(offset as i64);
+                }
+            }
+        }
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
-            self.row_groups
-                .iter_mut()
-                .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
-                            let offset = self.offset;
-                            column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
-                            let length = self.offset - offset;
-                            column.column_index_length = Some(length as i32);
-                            ParquetResult::Ok(())
+            for i in 0..self.row_groups.len() {
+                for j in 0..self.row_groups[i].columns.len() {
+                    let pages = &self.page_specs[i][j];
+                    if !has_column_index_statistics(pages) {
+                        continue;
+                    }
+                    let offset = self.offset;
+                    self.offset += match self.column_cipher(i, j)? {
+                        Some(cipher) => {
+                            write_encrypted_column_index(&mut self.writer, pages, &cipher)?
                         },
-                    )?;
-                    ParquetResult::Ok(())
-                })?;
+                        None => write_column_index(&mut self.writer, pages)?,
+                    };
+                    let column = &mut self.row_groups[i].columns[j];
+                    column.column_index_offset = Some(offset as i64);
+                    column.column_index_length = Some((self.offset - offset) as i32);
+                }
+            }
         };
 
         // write offset index
-        self.row_groups
-            .iter_mut()
-            .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
-                group
-                    .columns
-                    .iter_mut()
-                    .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
-                        let offset = self.offset;
-                        column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
-                        column.offset_index_length = Some((self.offset - offset) as i32);
-                        ParquetResult::Ok(())
-                    })?;
-                ParquetResult::Ok(())
-            })?;
+        for i in 0..self.row_groups.len() {
+            for j in 0..self.row_groups[i].columns.len() {
+                let pages = &self.page_specs[i][j];
+                let offset = self.offset;
+                self.offset += match self.column_cipher(i, j)? {
+                    Some(cipher) => write_encrypted_offset_index(&mut self.writer, pages, &cipher)?,
+                    None => write_offset_index(&mut self.writer, pages)?,
+                };
+                let column = &mut self.row_groups[i].columns[j];
+                column.offset_index_offset = Some(offset as i64);
+                column.offset_index_length = Some((self.offset - offset) as i32);
+            }
+        }
+
+        // The footer only contains the encrypted metadata of columns with their own key.
+        let mut row_groups = self.row_groups.clone();
+        if let Some(encryptor) = &self.encryptor {
+            for (i, group) in row_groups.iter_mut().enumerate() {
+                for (j, column) in group.columns.iter_mut().enumerate() {
+                    let Some(cipher) = self.column_cipher(i, j)? else {
+                        continue;
+                    };
+                    let path = &self.schema.columns()[j].path_in_schema;
+                    column.crypto_metadata = Some(encryptor.column_crypto_metadata(path));
+                    if encryptor.has_column_key() {
+                        let mut buffer = vec![];
+                        let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+                        let meta_data = column.meta_data.take().unwrap();
+                        meta_data.write_to_out_protocol(&mut protocol)?;
+                        column.encrypted_column_metadata =
+                            Some(cipher.encrypt(ModuleType::ColumnMetaData, None, &buffer)?);
+                    }
+                }
+            }
+        }
 
         let metadata = ThriftFileMetaData::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
             num_rows,
-            self.row_groups.clone(),
+            row_groups,
             key_value_metadata,
             self.created_by.clone(),
             Some(create_column_orders(&self.schema)),
@@ -237,7 +395,10 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => end_encrypted_file(&mut self.writer, &metadata, encryptor)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -1,8 +1,82 @@
 use parquet_format_safe::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
 
 use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::{
+    IntegerType, PrimitiveConvertedType, PrimitiveLogicalType, PrimitiveType,
+};
+use crate::parquet::statistics::Statistics;
 use crate::parquet::write::page::{is_data_page, PageWriteSpec};
 
+/// Whether values of `primitive_type` sort the same as the physical values in the statistics.
+fn is_physically_ordered(primitive_type: &PrimitiveType) -> bool {
+    use {IntegerType as I, PrimitiveConvertedType as C, PrimitiveLogicalType as L};
+
+    let is_unsigned = matches!(
+        primitive_type.converted_type,
+        Some(C::Uint8 | C::Uint16 | C::Uint32 | C::Uint64)
+    ) || matches!(
+        primitive_type.logical_type,
+        Some(L::Integer(I::UInt8 | I::UInt16 | I::UInt32 | I::UInt64))
+    );
+    let is_decimal = matches!(primitive_type.converted_type, Some(C::Decimal(_, _)))
+        || matches!(primitive_type.logical_type, Some(L::Decimal(_, _)));
+    !is_unsigned && !is_decimal
+}
+
+fn order_of<T: PartialOrd>(bounds: impl Iterator<Item = (T, T)>) -> BoundaryOrder {
+    let (mut ascending, mut descending) = (true, true);
+    let mut previous: Option<(T, T)> = None;
+    for (min, max) in bounds {
+        if let Some((previous_min, previous_max)) = &previous {
+            ascending &= *previous_min <= min && *previous_max <= max;
+            descending &= *previous_min >= min && *previous_max >= max;
+        }
+        previous = Some((min, max));
+    }
+    match (ascending, descending) {
+        (true, _) => BoundaryOrder::ASCENDING,
+        (false, true) => BoundaryOrder::DESCENDING,
+        (false, false) => BoundaryOrder::UNORDERED,
+    }
+}
+
+/// The order of the min and max values of consecutive pages, skipping the pages with only nulls.
+fn boundary_order(statistics: &[&Statistics]) -> BoundaryOrder {
+    use Statistics as S;
+
+    macro_rules! order {
+        ($expect:ident) => {
+            order_of(statistics.iter().filter_map(|s| {
+                let s = s.$expect();
+                Some((s.min_value.as_ref()?, s.max_value.as_ref()?))
+            }))
+        };
+    }
+
+    match statistics.first() {
+        Some(S::Boolean(_)) => order!(expect_as_boolean),
+        Some(S::Int32(s)) if is_physically_ordered(&s.primitive_type) => order!(expect_as_int32),
+        Some(S::Int64(s)) if is_physically_ordered(&s.primitive_type) => order!(expect_as_int64),
+        Some(S::Float(_)) => order!(expect_as_float),
+        Some(S::Double(_)) => order!(expect_as_double),
+        // Byte arrays sort as unsigned bytes, except for decimals.
+        Some(S::Binary(s)) if is_physically_ordered(&s.primitive_type) => {
+            order!(expect_as_binary)
+        },
+        _ => BoundaryOrder::UNORDERED,
+    }
+}
+
+/// Whether every data page of a column chunk has the statistics that its column index requires,
+/// which is not the case for columns that are written without (null count) statistics.
+pub fn has_column_index_statistics(pages: &[PageWriteSpec]) -> bool {
+    pages.iter().filter(|x| is_data_page(x)).all(|spec| {
+        spec.statistics
+            .as_ref()
+            .is_some_and(|stats| stats.serialize().null_count.is_some())
+    })
+}
+
 pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIndex> {
     let mut null_pages = Vec::with_capacity(pages.len());
     let mut min_values = Vec::with_capacity(pages.len());
@@ -42,11 +116,16 @@ pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIn
                 ))
             }
         })?;
+    let statistics = pages
+        .iter()
+        .filter(|x| is_data_page(x))
+        .filter_map(|spec| spec.statistics.as_ref())
+        .collect::<Vec<_>>();
     Ok(ColumnIndex {
         null_pages,
         min_values,
         max_values,
-        boundary_order: BoundaryOrder::UNORDERED,
+        boundary_order: boundary_order(&statistics),
         null_counts: Some(null_counts),
     })
 }
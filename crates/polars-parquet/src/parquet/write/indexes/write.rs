@@ -6,7 +6,9 @@ use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 #[cfg(feature = "async")]
 use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 
+pub use super::serialize::has_column_index_statistics;
 use super::serialize::{serialize_column_index, serialize_offset_index};
+use crate::parquet::encryption::{ColumnCipher, ModuleType};
 use crate::parquet::error::ParquetResult;
 use crate::parquet::write::page::PageWriteSpec;
 
@@ -33,6 +35,32 @@ pub fn write_offset_index<W: Write>(writer: &mut W, pages: &[PageWriteSpec]) ->
     Ok(index.write_to_out_protocol(&mut protocol)? as u64)
 }
 
+/// Writes the column index of an encrypted column.
+pub fn write_encrypted_column_index<W: Write>(
+    writer: &mut W,
+    pages: &[PageWriteSpec],
+    cipher: &ColumnCipher,
+) -> ParquetResult<u64> {
+    let mut buffer = vec![];
+    write_column_index(&mut buffer, pages)?;
+    let module = cipher.encrypt(ModuleType::ColumnIndex, None, &buffer)?;
+    writer.write_all(&module)?;
+    Ok(module.len() as u64)
+}
+
+/// Writes the offset index of an encrypted column.
+pub fn write_encrypted_offset_index<W: Write>(
+    writer: &mut W,
+    pages: &[PageWriteSpec],
+    cipher: &ColumnCipher,
+) -> ParquetResult<u64> {
+    let mut buffer = vec![];
+    write_offset_index(&mut buffer, pages)?;
+    let module = cipher.encrypt(ModuleType::OffsetIndex, None, &buffer)?;
+    writer.write_all(&module)?;
+    Ok(module.len() as u64)
+}
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub async fn write_offset_index_async<W: AsyncWrite + Unpin + Send>(
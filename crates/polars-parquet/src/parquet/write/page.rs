@@ -8,6 +8,7 @@ use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 use parquet_format_safe::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -96,6 +97,73 @@ pub fn write_page<W: Write>(
     })
 }
 
+/// Writes a page of an encrypted column. `page_ordinal` is the number of data pages written
+/// before this page.
+pub fn write_encrypted_page<W: Write>(
+    writer: &mut W,
+    offset: u64,
+    compressed_page: &CompressedPage,
+    cipher: &ColumnCipher,
+    page_ordinal: usize,
+) -> ParquetResult<PageWriteSpec> {
+    let num_values = compressed_page.num_values();
+    let num_rows = compressed_page
+        .num_rows()
+        .expect("We should have num_rows when we are writing");
+
+    let (mut header, buffer) = match &compressed_page {
+        CompressedPage::Data(compressed_page) => {
+            let buffer = cipher.encrypt(
+                ModuleType::DataPage,
+                Some(page_ordinal),
+                &compressed_page.buffer,
+            )?;
+            (assemble_data_page_header(compressed_page)?, buffer)
+        },
+        CompressedPage::Dict(compressed_page) => {
+            let buffer =
+                cipher.encrypt(ModuleType::DictionaryPage, None, &compressed_page.buffer)?;
+            (assemble_dict_page_header(compressed_page)?, buffer)
+        },
+    };
+    // SPEC: the compressed page size of encrypted pages includes the encryption overhead
+    header.compressed_page_size = maybe_bytes(0, buffer.len())?.1;
+
+    let mut header_buffer = vec![];
+    write_page_header(&mut header_buffer, &header)?;
+    let header_buffer = match &compressed_page {
+        CompressedPage::Data(_) => cipher.encrypt(
+            ModuleType::DataPageHeader,
+            Some(page_ordinal),
+            &header_buffer,
+        )?,
+        CompressedPage::Dict(_) => {
+            cipher.encrypt(ModuleType::DictionaryPageHeader, None, &header_buffer)?
+        },
+    };
+
+    writer.write_all(&header_buffer)?;
+    writer.write_all(&buffer)?;
+    let header_size = header_buffer.len() as u64;
+    let bytes_written = header_size + buffer.len() as u64;
+
+    let statistics = match &compressed_page {
+        CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
+        CompressedPage::Dict(_) => None,
+    };
+
+    Ok(PageWriteSpec {
+        header,
+        header_size,
+        offset,
+        bytes_written,
+        compression: compressed_page.compression(),
+        statistics,
+        num_values,
+        num_rows,
+    })
+}
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
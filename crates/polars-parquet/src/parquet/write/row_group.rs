@@ -9,6 +9,7 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{is_data_page, PageWriteSpec};
 use super::{DynIter, DynStreamingIterator};
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetaData, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -76,19 +77,24 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    encryptor: Option<&FileEncryptor>,
 ) -> ParquetResult<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
     ParquetError: From<E>,
     E: std::error::Error,
 {
-    let column_iter = descriptors.iter().zip(columns);
+    let column_iter = descriptors.iter().zip(columns).enumerate();
 
     let initial = offset;
     let columns = column_iter
-        .map(|(descriptor, page_iter)| {
+        .map(|(i, (descriptor, page_iter))| {
+            let cipher = encryptor
+                .map(|e| e.column(&descriptor.path_in_schema, ordinal, i))
+                .transpose()?
+                .flatten();
             let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+                write_column_chunk(writer, offset, descriptor, page_iter?, cipher.as_ref())?;
             offset += size;
             Ok((column, page_specs))
         })
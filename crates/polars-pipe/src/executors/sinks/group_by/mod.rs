@@ -4,6 +4,8 @@ mod ooc;
 mod ooc_state;
 mod primitive;
 mod string;
+#[cfg(feature = "dynamic_group_by")]
+mod temporal;
 mod utils;
 
 pub(crate) use generic::GenericGroupby2;
@@ -12,6 +14,8 @@ use polars_core::prelude::*;
 use polars_core::using_string_cache;
 pub(crate) use primitive::*;
 pub(crate) use string::*;
+#[cfg(feature = "dynamic_group_by")]
+pub(crate) use temporal::*;
 
 pub(super) fn physical_agg_to_logical(cols: &mut [Series], output_schema: &Schema) {
     for (s, (name, dtype)) in cols.iter_mut().zip(output_schema.iter()) {
@@ -0,0 +1,402 @@
+//! `group_by_dynamic` and `rolling` over input that is sorted by the index column.
+//!
+//! Only the rows of windows that are not yet complete are kept in memory. A window is complete
+//! once a row past its end has been seen, the input being sorted guarantees no later row can
+//! still fall into it.
+//!
+//! The windows of `group_by_dynamic` are on a grid that is determined by the first row of its
+//! input. Once rows are dropped, the lower boundary of the first window that is not emitted is
+//! kept as the start of the grid, the later windows are `every` apart from it.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_expr::prelude::PhysicalExpr;
+use polars_expr::state::ExecutionState;
+use polars_time::prelude::{Label, StartBy};
+use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
+use polars_utils::IdxSize;
+use rayon::prelude::*;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::morsels_per_sink;
+
+#[derive(Clone)]
+pub(crate) enum TemporalWindows {
+    Dynamic(DynamicGroupOptions),
+    Rolling(RollingGroupOptions),
+}
+
+impl TemporalWindows {
+    fn index_column(&self) -> &str {
+        match self {
+            Self::Dynamic(options) => &options.index_column,
+            Self::Rolling(options) => &options.index_column,
+        }
+    }
+}
+
+struct WindowState {
+    windows: TemporalWindows,
+    include_boundaries: bool,
+    aggs: Vec<Arc<dyn PhysicalExpr>>,
+    /// Rows that are part of a window that is not emitted yet.
+    buffer: DataFrame,
+    /// Last value of the index column, to check the chunks arrive sorted.
+    last_time: Option<i64>,
+    /// Lower boundary of the first window that is not emitted, in the dtype of the index column.
+    /// `None` until rows are dropped (dynamic).
+    start: Option<Scalar>,
+    /// Number of leading rows of `buffer` whose window is already emitted (rolling).
+    n_emitted: usize,
+    output: Vec<DataFrame>,
+}
+
+impl WindowState {
+    fn push(&mut self, chunks: Vec<DataFrame>, state: &ExecutionState) -> PolarsResult<()> {
+        for chunk in chunks {
+            if chunk.height() == 0 {
+                continue;
+            }
+            let time = chunk
+                .column(self.windows.index_column())?
+                .to_physical_repr()
+                .cast(&DataType::Int64)?;
+            let time = time.i64()?;
+            if let (Some(last), Some(first)) = (self.last_time, time.get(0)) {
+                polars_ensure!(
+                    last <= first,
+                    InvalidOperation: "streaming group_by_dynamic/rolling requires the input to be sorted by '{}'",
+                    self.windows.index_column()
+                );
+            }
+            self.last_time = time.last();
+            self.buffer.vstack_mut(&chunk)?;
+        }
+        self.evaluate(false, state)
+    }
+
+    /// Emit the windows that are complete and drop the rows that are no longer needed. If
+    /// `finish` is set all remaining windows are emitted.
+    fn evaluate(&mut self, finish: bool, state: &ExecutionState) -> PolarsResult<()> {
+        let n = self.buffer.height();
+        if n == 0 {
+            return Ok(());
+        }
+        self.buffer.as_single_chunk_par();
+        let is_complete = |[first, len]: &[IdxSize; 2]| ((first + len) as usize) < n;
+
+        let offset = match &self.windows {
+            TemporalWindows::Dynamic(options) => {
+                let (time_key, keys, groups) = self.group_by_dynamic(options)?;
+                let GroupsProxy::Slice { groups: slices, .. } = &groups else {
+                    unreachable!()
+                };
+                if slices.is_empty() && !finish {
+                    // None of the rows is in a window. They are dropped once the grid is known,
+                    // until then the first row determines it.
+                    if self.start.is_some() {
+                        self.buffer = self.buffer.clear();
+                    }
+                    return Ok(());
+                }
+
+                // The last window is kept until the end, its lower boundary is the start of the
+                // grid of the next windows.
+                let end = if finish {
+                    slices.len()
+                } else {
+                    slices.partition_point(is_complete).min(slices.len() - 1)
+                };
+                if end > 0 {
+                    let keys = if self.include_boundaries {
+                        keys.as_slice()
+                    } else {
+                        &[]
+                    };
+                    let out = self.emit(&groups, 0, end, keys, &time_key, state)?;
+                    self.output.push(out);
+                }
+                match slices.get(end) {
+                    Some([first, _]) => {
+                        let dtype = self.buffer.column(&options.index_column)?.dtype().clone();
+                        let lower = keys[0].cast(&dtype)?.get(end)?.into_static()?;
+                        self.start = Some(Scalar::new(dtype, lower));
+                        *first as usize
+                    },
+                    None => n,
+                }
+            },
+            TemporalWindows::Rolling(options) => {
+                let (time_key, _, groups) = self.buffer.rolling(vec![], options)?;
+                let GroupsProxy::Slice { groups: slices, .. } = &groups else {
+                    unreachable!()
+                };
+
+                // Every row has its own window.
+                let start = self.n_emitted;
+                let end = if finish {
+                    n
+                } else {
+                    slices.partition_point(is_complete)
+                }
+                .max(start);
+
+                if end > start {
+                    let out = self.emit(&groups, start, end, &[], &time_key, state)?;
+                    self.output.push(out);
+                }
+                let offset = slices
+                    .get(end)
+                    .map_or(n, |[first, _]| (*first as usize).min(end));
+                self.n_emitted = end - offset;
+                offset
+            },
+        };
+
+        self.buffer = self.buffer.slice(offset as i64, n - offset);
+        Ok(())
+    }
+
+    /// The windows of `group_by_dynamic` over the buffer, on the grid that starts at `start`.
+    ///
+    /// From `start` on the windows are like the ones of `StartBy::DataPoint` with `start` as the
+    /// first row. It is therefore put in front of the index column, and removed from the windows
+    /// again.
+    fn group_by_dynamic(
+        &self,
+        options: &DynamicGroupOptions,
+    ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)> {
+        let Some(start) = &self.start else {
+            return self.buffer.group_by_dynamic(vec![], options);
+        };
+        let index = self.buffer.column(&options.index_column)?;
+        let mut anchored = start.clone().into_series(index.name().clone());
+        anchored.append(index)?;
+        let options = DynamicGroupOptions {
+            start_by: StartBy::DataPoint,
+            ..options.clone()
+        };
+        let (time_key, keys, groups) =
+            DataFrame::new(vec![anchored])?.group_by_dynamic(vec![], &options)?;
+        let GroupsProxy::Slice { groups, rolling } = groups else {
+            unreachable!()
+        };
+
+        let mut keep = Vec::with_capacity(groups.len());
+        let groups = groups
+            .into_iter()
+            .filter_map(|[first, len]| {
+                let group = match first {
+                    0 => [0, len - 1],
+                    _ => [first - 1, len],
+                };
+                keep.push(group[1] > 0);
+                (group[1] > 0).then_some(group)
+            })
+            .collect::<Vec<_>>();
+        let keep = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+        let keys = keys
+            .iter()
+            .map(|key| key.filter(&keep))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let time_key = if options.label == Label::DataPoint {
+            // The first row of a window is its label, which is not `start`.
+            let first = groups.iter().map(|[first, _]| *first).collect::<Vec<_>>();
+            index.take_slice(&first)?
+        } else {
+            time_key.filter(&keep)?
+        };
+        Ok((time_key, keys, GroupsProxy::Slice { groups, rolling }))
+    }
+
+    fn emit(
+        &self,
+        groups: &GroupsProxy,
+        start: usize,
+        end: usize,
+        keys: &[Series],
+        time_key: &Series,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let GroupsProxy::Slice { groups, rolling } = groups else {
+            unreachable!()
+        };
+        let groups = GroupsProxy::Slice {
+            groups: groups[start..end].to_vec(),
+            rolling: *rolling,
+        };
+        let len = end - start;
+
+        let agg_columns = POOL.install(|| {
+            self.aggs
+                .par_iter()
+                .map(|expr| {
+                    let agg = expr
+                        .evaluate_on_groups(&self.buffer, &groups, state)?
+                        .finalize();
+                    polars_ensure!(agg.len() == len, agg_len = agg.len(), len);
+                    Ok(agg)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut columns = Vec::with_capacity(keys.len() + 1 + agg_columns.len());
+        columns.extend(keys.iter().map(|s| s.slice(start as i64, len)));
+        columns.push(time_key.slice(start as i64, len));
+        columns.extend(agg_columns);
+        DataFrame::new(columns)
+    }
+}
+
+struct Shared {
+    /// Chunks that may still be preceded by chunks that are not sunk yet.
+    pending: BTreeMap<IdxSize, Vec<DataFrame>>,
+    max_chunk_index: Option<IdxSize>,
+    /// `None` while a thread is evaluating windows. This is done outside of the lock as the
+    /// evaluation runs on the thread pool, which may call into this sink again.
+    windows: Option<WindowState>,
+}
+
+/// Sink for `group_by_dynamic` and `rolling` without `group_by` keys.
+///
+/// The sinks of all threads share their state, chunks are evaluated in the order of the source.
+#[derive(Clone)]
+pub struct TemporalGroupBySink {
+    shared: Arc<Mutex<Shared>>,
+    output_schema: SchemaRef,
+    slice: Option<(i64, usize)>,
+}
+
+impl TemporalGroupBySink {
+    pub(crate) fn new(
+        windows: TemporalWindows,
+        aggs: Vec<Arc<dyn PhysicalExpr>>,
+        input_schema: &Schema,
+        output_schema: SchemaRef,
+        slice: Option<(i64, usize)>,
+    ) -> Self {
+        let (windows, include_boundaries) = match windows {
+            // The lower boundaries are needed to know which windows are emitted.
+            TemporalWindows::Dynamic(options) => (
+                TemporalWindows::Dynamic(DynamicGroupOptions {
+                    include_boundaries: true,
+                    ..options.clone()
+                }),
+                options.include_boundaries,
+            ),
+            windows => (windows, false),
+        };
+        let windows = WindowState {
+            windows,
+            include_boundaries,
+            aggs,
+            buffer: DataFrame::empty_with_schema(input_schema),
+            last_time: None,
+            start: None,
+            n_emitted: 0,
+            output: vec![],
+        };
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                pending: Default::default(),
+                max_chunk_index: None,
+                windows: Some(windows),
+            })),
+            output_schema,
+            slice,
+        }
+    }
+
+    /// Evaluate the pending chunks that can no longer be preceded by other chunks. The sinks of
+    /// one round receive at most `morsels_per_sink` consecutive chunk indices and rounds don't
+    /// overlap, so every chunk that is that far behind the newest chunk is complete.
+    fn process(&self, state: &ExecutionState) -> PolarsResult<()> {
+        loop {
+            let (mut windows, chunks) = {
+                let mut shared = self.shared.lock().unwrap();
+                let ready = match shared.max_chunk_index {
+                    Some(max) if max as usize >= morsels_per_sink() => {
+                        let watermark = max - morsels_per_sink() as IdxSize;
+                        let later = shared.pending.split_off(&(watermark + 1));
+                        std::mem::replace(&mut shared.pending, later)
+                    },
+                    _ => return Ok(()),
+                };
+                if ready.is_empty() {
+                    return Ok(());
+                }
+                let Some(windows) = shared.windows.take() else {
+                    // Another thread is evaluating, it will pick these up.
+                    shared.pending.extend(ready);
+                    return Ok(());
+                };
+                (windows, ready.into_values().flatten().collect::<Vec<_>>())
+            };
+
+            windows.push(chunks, state)?;
+            self.shared.lock().unwrap().windows = Some(windows);
+        }
+    }
+}
+
+impl Sink for TemporalGroupBySink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        {
+            let mut shared = self.shared.lock().unwrap();
+            shared.max_chunk_index = shared.max_chunk_index.max(Some(chunk.chunk_index));
+            shared
+                .pending
+                .entry(chunk.chunk_index)
+                .or_default()
+                .push(chunk.data);
+        }
+        self.process(&context.execution_state)?;
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // no-op, the state is shared
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let state = &context.execution_state;
+        let (mut windows, chunks) = {
+            let mut shared = self.shared.lock().unwrap();
+            let windows = shared.windows.take().ok_or_else(
+                || polars_err!(ComputeError: "temporal group_by is finalized while it is evaluated"),
+            )?;
+            let pending = std::mem::take(&mut shared.pending);
+            (windows, pending.into_values().flatten().collect::<Vec<_>>())
+        };
+        windows.push(chunks, state)?;
+        windows.evaluate(true, state)?;
+        let output = windows.output;
+
+        let df = if output.is_empty() {
+            DataFrame::empty_with_schema(&self.output_schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(output)
+        };
+        Ok(FinalizedSink::Finished(match self.slice {
+            Some((offset, len)) => df.slice(offset, len),
+            None => df,
+        }))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "temporal_group_by"
+    }
+}
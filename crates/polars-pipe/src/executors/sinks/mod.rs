@@ -7,22 +7,18 @@ mod output;
 mod reproject;
 mod slice;
 mod sort;
+mod tee;
 mod utils;
 
 use std::sync::OnceLock;
 
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 pub(crate) use output::*;
 pub(crate) use reproject::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
+pub(crate) use tee::*;
 
 // We must strike a balance between cache coherence and resizing costs.
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
@@ -0,0 +1,44 @@
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_plan::prelude::SinkCallback;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+struct CallbackWriter(SinkCallback);
+
+impl SinkWriter for CallbackWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.0.call(df.clone())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+/// Passes the batches to a user callback, in order. The channel to the callback thread is
+/// bounded, so a slow callback blocks the query instead of buffering its output.
+pub struct CallbackSink {}
+impl CallbackSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(callback: SinkCallback) -> FilesSink {
+        let writer = Box::new(CallbackWriter(callback)) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        FilesSink {
+            sender,
+            io_thread_handle,
+        }
+    }
+}
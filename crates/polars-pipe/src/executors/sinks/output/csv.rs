@@ -2,33 +2,38 @@ use std::path::Path;
 
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
-use polars_io::csv::write::{CsvWriter, CsvWriterOptions};
+use polars_io::csv::write::{BatchedWriter, CsvWriter, CsvWriterOptions};
 use polars_io::SerWriter;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::rolling::rolling_files_sink;
 use crate::pipeline::morsels_per_sink;
 
 pub struct CsvSink {}
 impl CsvSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: CsvWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = CsvWriter::new(file)
-            .include_bom(options.include_bom)
-            .include_header(options.include_header)
-            .with_separator(options.serialize_options.separator)
-            .with_line_terminator(options.serialize_options.line_terminator)
-            .with_quote_char(options.serialize_options.quote_char)
-            .with_batch_size(options.batch_size)
-            .with_datetime_format(options.serialize_options.datetime_format)
-            .with_date_format(options.serialize_options.date_format)
-            .with_time_format(options.serialize_options.time_format)
-            .with_float_scientific(options.serialize_options.float_scientific)
-            .with_float_precision(options.serialize_options.float_precision)
-            .with_null_value(options.serialize_options.null)
-            .with_quote_style(options.serialize_options.quote_style)
-            .n_threads(1)
-            .batched(schema)?;
+        if options.is_multi_file() {
+            let (target_file_size, max_rows_per_file, maintain_order) = (
+                options.target_file_size,
+                options.max_rows_per_file,
+                options.maintain_order,
+            );
+            let schema = schema.clone();
+            let create_writer = move |path: &Path| {
+                let writer = create_csv_writer(path, &options, &schema)?;
+                Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+            };
+            return rolling_files_sink(
+                path,
+                "csv",
+                Box::new(create_writer),
+                target_file_size,
+                max_rows_per_file,
+                maintain_order,
+            );
+        }
+        let writer = create_csv_writer(path, &options, schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
 
@@ -50,7 +55,34 @@ impl CsvSink {
     }
 }
 
-impl SinkWriter for polars_io::csv::write::BatchedWriter<std::fs::File> {
+pub(super) fn create_csv_writer(
+    path: &Path,
+    options: &CsvWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<std::fs::File>> {
+    let file = std::fs::File::create(path)?;
+    let options = options.clone();
+    CsvWriter::new(file)
+        .include_bom(options.include_bom)
+        .include_header(options.include_header)
+        .with_separator(options.serialize_options.separator)
+        .with_multi_byte_separator(options.serialize_options.multi_byte_separator)
+        .with_line_terminator(options.serialize_options.line_terminator)
+        .with_quote_char(options.serialize_options.quote_char)
+        .with_batch_size(options.batch_size)
+        .with_datetime_format(options.serialize_options.datetime_format)
+        .with_date_format(options.serialize_options.date_format)
+        .with_time_format(options.serialize_options.time_format)
+        .with_float_scientific(options.serialize_options.float_scientific)
+        .with_float_precision(options.serialize_options.float_precision)
+        .with_null_value(options.serialize_options.null)
+        .with_quote_style(options.serialize_options.quote_style)
+        .with_escape_char(options.serialize_options.escape_char)
+        .n_threads(1)
+        .batched(schema)
+}
+
+impl SinkWriter for BatchedWriter<std::fs::File> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
@@ -83,8 +83,9 @@ pub struct FilesSink {
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
-        if chunk.data.height() > 0 {
-            self.sender.send(Some(chunk)).unwrap();
+        if chunk.data.height() > 0 && self.sender.send(Some(chunk)).is_err() {
+            // The writer stopped on an error, which is returned in `finalize`.
+            return Ok(SinkResult::Finished);
         };
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -98,7 +99,8 @@ impl Sink for FilesSink {
     }
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        // This fails if the writer already stopped on an error.
+        let _ = self.sender.send(None);
 
         // wait until all files written
         // some unwrap/mut kung-fu to get a hold of `self`
@@ -6,16 +6,29 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::prelude::*;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::rolling::rolling_files_sink;
 use crate::pipeline::morsels_per_sink;
 
 pub struct IpcSink {}
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
+        if options.is_multi_file() {
+            let schema = schema.clone();
+            let create_writer = move |path: &Path| {
+                let writer = create_ipc_writer(path, &options, &schema)?;
+                Ok(Box::new(writer) as Box<dyn SinkWriter + Send>)
+            };
+            return rolling_files_sink(
+                path,
+                "ipc",
+                Box::new(create_writer),
+                options.target_file_size,
+                options.max_rows_per_file,
+                options.maintain_order,
+            );
+        }
+        let writer = create_ipc_writer(path, &options, schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
@@ -37,6 +50,17 @@ impl IpcSink {
     }
 }
 
+pub(super) fn create_ipc_writer(
+    path: &Path,
+    options: &IpcWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<polars_io::ipc::BatchedWriter<std::fs::File>> {
+    let file = std::fs::File::create(path)?;
+    IpcWriter::new(file)
+        .with_compression(options.compression)
+        .batched(schema)
+}
+
 #[cfg(feature = "cloud")]
 pub struct IpcCloudSink {}
 #[cfg(feature = "cloud")]
@@ -48,6 +72,10 @@ impl IpcCloudSink {
         ipc_options: IpcWriterOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            !ipc_options.is_multi_file(),
+            InvalidOperation: "'target_file_size' and 'max_rows_per_file' are not supported for cloud sinks"
+        );
         polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
             let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
             let writer = IpcWriter::new(cloud_writer)
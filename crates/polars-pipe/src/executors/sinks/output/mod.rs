@@ -1,11 +1,6 @@
+mod callback;
 #[cfg(feature = "csv")]
 mod csv;
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 mod file_sink;
 #[cfg(feature = "ipc")]
 mod ipc;
@@ -13,7 +8,17 @@ mod ipc;
 mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+mod partitioned;
+#[cfg(any(feature = "ipc", feature = "csv"))]
+mod rolling;
 
+pub use callback::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
@@ -22,3 +27,10 @@ pub use ipc::*;
 pub use json::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use partitioned::*;
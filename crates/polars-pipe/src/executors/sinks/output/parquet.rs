@@ -1,18 +1,19 @@
 use std::any::Any;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_io::parquet::write::{
-    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
+    BatchedWriter, EncodedRowGroup, ParquetWriteOptions, ParquetWriter,
 };
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
+type RowGroups = Vec<EncodedRowGroup>;
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
@@ -51,6 +52,28 @@ pub(super) fn init_row_group_writer_thread(
     })
 }
 
+pub(super) fn parquet_writer<W: std::io::Write>(
+    writer: W,
+    options: ParquetWriteOptions,
+) -> ParquetWriter<W> {
+    ParquetWriter::new(writer)
+        .with_compression(options.compression)
+        .with_data_page_size(options.data_page_size)
+        .with_statistics(options.statistics)
+        .with_row_group_size(options.row_group_size)
+        .with_row_group_bytes(options.row_group_bytes)
+        .with_encryption(options.encryption)
+        .with_bloom_filter(options.bloom_filter)
+        .with_encoding(options.encoding)
+        .with_key_value_metadata(options.key_value_metadata)
+        .with_field_metadata(options.field_metadata)
+        .with_sorting_columns(options.sorting_columns)
+        .with_column_options(options.column_options)
+        // This is important! Otherwise we will deadlock
+        // See: #7074
+        .set_parallel(false)
+}
+
 #[derive(Clone)]
 pub struct ParquetSink {
     writer: Arc<BatchedWriter<std::fs::File>>,
@@ -61,15 +84,7 @@ impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
         let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_page_size)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
+        let writer = parquet_writer(file, options).batched(schema)?;
 
         let writer = Arc::new(writer);
         let morsels_per_sink = morsels_per_sink();
@@ -150,17 +165,17 @@ impl ParquetCloudSink {
         parquet_options: ParquetWriteOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            !parquet_options.is_multi_file(),
+            InvalidOperation: "'target_file_size' and 'max_rows_per_file' are not supported for cloud sinks"
+        );
+        polars_ensure!(
+            !parquet_options.is_partitioned(),
+            InvalidOperation: "'partition_by' is not supported for cloud sinks"
+        );
         polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
             let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-            let writer = ParquetWriter::new(cloud_writer)
-                .with_compression(parquet_options.compression)
-                .with_data_page_size(parquet_options.data_page_size)
-                .with_statistics(parquet_options.statistics)
-                .with_row_group_size(parquet_options.row_group_size)
-                // This is important! Otherwise we will deadlock
-                // See: #7074
-                .set_parallel(false)
-                .batched(schema)?;
+            let writer = parquet_writer(cloud_writer, parquet_options).batched(schema)?;
 
             let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
@@ -183,6 +198,188 @@ impl ParquetCloudSink {
     }
 }
 
+struct RollingFile {
+    writer: BatchedWriter<std::fs::File>,
+    /// Handle to the same file, to get the number of bytes written so far.
+    file: std::fs::File,
+    /// Number of rows of the file, including the buffered ones.
+    rows: usize,
+    /// Number of rows after which the file is finished.
+    capacity: usize,
+    row_group_size: usize,
+    buffered: Vec<DataFrame>,
+    buffered_rows: usize,
+}
+
+impl RollingFile {
+    fn flush_row_group(
+        &mut self,
+        n_rows: usize,
+        options: &ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        let buffered = accumulate_dataframes_vertical_unchecked(self.buffered.drain(..));
+        let mut row_group = buffered.slice(0, n_rows);
+        let rest = buffered.slice(n_rows as i64, usize::MAX);
+        if rest.height() > 0 {
+            self.buffered.push(rest);
+        }
+        self.buffered_rows -= row_group.height();
+        // Every chunk is written as a row group.
+        row_group.as_single_chunk();
+        self.writer.write_batch(&row_group)?;
+
+        // Correct the capacity with the actual (compressed) size of the rows written so far.
+        if let Some(target_file_size) = options.target_file_size {
+            let bytes = self.file.metadata()?.len() as f64;
+            let written = (self.rows - self.buffered_rows) as f64;
+            let remaining = (target_file_size as f64 - bytes).max(0.0) * written / bytes;
+            self.capacity = ((written + remaining) as usize)
+                .max(self.rows)
+                .min(options.max_rows_per_file.unwrap_or(usize::MAX));
+        }
+        Ok(())
+    }
+}
+
+/// Writes a directory of parquet files, rolling over to a new file once the current one has
+/// `max_rows_per_file` rows or about `target_file_size` bytes.
+struct RollingParquetWriter {
+    dir: PathBuf,
+    options: ParquetWriteOptions,
+    schema: Schema,
+    n_files: usize,
+    file: Option<RollingFile>,
+    /// Rows and bytes of the finished files, to estimate the size of the next ones.
+    written_rows: usize,
+    written_bytes: u64,
+}
+
+impl RollingParquetWriter {
+    fn open_file(&mut self, df: &DataFrame) -> PolarsResult<RollingFile> {
+        let mut capacity = self.options.max_rows_per_file.unwrap_or(usize::MAX);
+        if let Some(target_file_size) = self.options.target_file_size {
+            let bytes_per_row = if self.written_rows > 0 {
+                self.written_bytes as f64 / self.written_rows as f64
+            } else {
+                // Nothing is written yet, the in-memory size is an upper bound.
+                df.estimated_size() as f64 / df.height() as f64
+            };
+            capacity = capacity.min(((target_file_size as f64 / bytes_per_row) as usize).max(1));
+        }
+        // Spread the rows of the file evenly over its row groups, instead of ending every file
+        // with a small one.
+        let max_row_group_size = self
+            .options
+            .max_row_group_rows(df.estimated_size() as f64 / df.height().max(1) as f64);
+        let n_row_groups = capacity.div_ceil(max_row_group_size);
+        let row_group_size = capacity.div_ceil(n_row_groups);
+
+        // Use a fixed-width file name so that it sorts properly.
+        let path = self.dir.join(format!("{:08x}.parquet", self.n_files));
+        self.n_files += 1;
+        let file = std::fs::File::create(path)?;
+        let writer =
+            parquet_writer(file.try_clone()?, self.options.clone()).batched(&self.schema)?;
+        Ok(RollingFile {
+            writer,
+            file,
+            rows: 0,
+            capacity,
+            row_group_size,
+            buffered: vec![],
+            buffered_rows: 0,
+        })
+    }
+
+    fn finish_file(&mut self, mut file: RollingFile) -> PolarsResult<()> {
+        if file.buffered_rows > 0 {
+            file.flush_row_group(file.buffered_rows, &self.options)?;
+        }
+        self.written_bytes += file.writer.finish()?;
+        self.written_rows += file.rows;
+        Ok(())
+    }
+}
+
+impl SinkWriter for RollingParquetWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut offset = 0;
+        while offset < df.height() {
+            let mut file = match self.file.take() {
+                Some(file) => file,
+                None => self.open_file(df)?,
+            };
+            let n_rows = (file.capacity - file.rows).min(df.height() - offset);
+            file.buffered.push(df.slice(offset as i64, n_rows));
+            file.buffered_rows += n_rows;
+            file.rows += n_rows;
+            offset += n_rows;
+
+            while file.buffered_rows >= file.row_group_size {
+                file.flush_row_group(file.row_group_size, &self.options)?;
+            }
+            if file.rows >= file.capacity {
+                self.finish_file(file)?;
+            } else {
+                self.file = Some(file);
+            }
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        if let Some(file) = self.file.take() {
+            self.finish_file(file)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a directory of parquet files, see [`ParquetWriteOptions::target_file_size`] and
+/// [`ParquetWriteOptions::max_rows_per_file`].
+pub struct ParquetFilesSink {}
+impl ParquetFilesSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        options: ParquetWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            options.target_file_size != Some(0) && options.max_rows_per_file != Some(0),
+            InvalidOperation: "'target_file_size' and 'max_rows_per_file' must be positive"
+        );
+        std::fs::create_dir_all(path)?;
+        let maintain_order = options.maintain_order;
+        let writer = RollingParquetWriter {
+            dir: path.to_path_buf(),
+            options,
+            schema: schema.clone(),
+            n_files: 0,
+            file: None,
+            written_rows: 0,
+            written_bytes: 0,
+        };
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
 impl<W: std::io::Write> SinkWriter for polars_io::parquet::write::BatchedWriter<W> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
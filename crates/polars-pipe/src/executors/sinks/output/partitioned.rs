@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_io::utils::hive_partition_path;
+use polars_plan::prelude::{FileType, PartitionedSinkOptions};
+use polars_utils::aliases::PlIndexMap;
+
+#[cfg(feature = "csv")]
+use super::csv::create_csv_writer;
+use super::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+#[cfg(feature = "ipc")]
+use super::ipc::create_ipc_writer;
+#[cfg(feature = "parquet")]
+use super::parquet::parquet_writer;
+use crate::pipeline::morsels_per_sink;
+
+type BoxedWriter = Box<dyn SinkWriter + Send>;
+
+/// Writes every batch into the files of its hive partitions. A file is opened per partition and
+/// kept open for the next batches, up to `max_open_files`, after which the least recently written
+/// file is finished.
+struct PartitionedWriter {
+    root: PathBuf,
+    file_type: FileType,
+    partition_by: Vec<PlSmallStr>,
+    /// Names of the keys of `partition_by` in the directory names.
+    key_names: Vec<PlSmallStr>,
+    /// Columns that are not written to the files, see
+    /// [`PartitionedWriter::keep_partition_columns`].
+    dropped: Vec<PlSmallStr>,
+    max_open_files: usize,
+    /// Schema of the files, without the `dropped` columns.
+    file_schema: Schema,
+    /// Open files by partition directory, the least recently written first.
+    open_files: PlIndexMap<String, BoxedWriter>,
+    /// Number of files created per partition directory.
+    n_files: PlHashMap<String, usize>,
+}
+
+impl PartitionedWriter {
+    fn extension(&self) -> &'static str {
+        match self.file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(_) => "parquet",
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(_) => "ipc",
+            #[cfg(feature = "csv")]
+            FileType::Csv(_) => "csv",
+            #[cfg(feature = "json")]
+            FileType::Json(_) => "jsonl",
+        }
+    }
+
+    /// Like the eager partitioned writer, the partition columns are only written into parquet
+    /// files, where they are cheap to store and give the hive schema for free. The temporary
+    /// columns of computed keys are never written.
+    fn keep_partition_columns(&self) -> bool {
+        match self.file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(_) => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn create_writer(&mut self, partition: &str) -> PolarsResult<BoxedWriter> {
+        let dir = self.root.join(partition);
+        std::fs::create_dir_all(&dir)?;
+
+        let extension = self.extension();
+        let n_files = self.n_files.entry(partition.to_string()).or_insert(0);
+        // Use a fixed-width file name so that it sorts properly.
+        let path = dir.join(format!("{:08x}.{}", *n_files, extension));
+        *n_files += 1;
+
+        #[allow(unused_variables)]
+        let schema = &self.file_schema;
+        let writer: BoxedWriter = match &self.file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(options) => Box::new(
+                parquet_writer(std::fs::File::create(path)?, options.clone()).batched(schema)?,
+            ),
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(options) => Box::new(create_ipc_writer(&path, options, schema)?),
+            #[cfg(feature = "csv")]
+            FileType::Csv(options) => Box::new(create_csv_writer(&path, options, schema)?),
+            #[cfg(feature = "json")]
+            FileType::Json(_) => Box::new(polars_io::json::BatchedWriter::new(
+                std::fs::File::create(path)?,
+            )),
+        };
+        Ok(writer)
+    }
+}
+
+impl SinkWriter for PartitionedWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for part in df.partition_by_stable(self.partition_by.iter().cloned(), true)? {
+            let keys = self
+                .partition_by
+                .iter()
+                .zip(&self.key_names)
+                .map(|(name, key_name)| Ok(part.column(name)?.clone().with_name(key_name.clone())))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let partition = hive_partition_path(&keys);
+            let part = part.drop_many(self.dropped.iter().cloned());
+
+            let mut writer = match self.open_files.shift_remove(&partition) {
+                Some(writer) => writer,
+                None => {
+                    if self.open_files.len() == self.max_open_files {
+                        let (_, mut evicted) = self.open_files.shift_remove_index(0).unwrap();
+                        evicted._finish()?;
+                    }
+                    self.create_writer(&partition)?
+                },
+            };
+            writer._write_batch(&part)?;
+            self.open_files.insert(partition, writer);
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for (_, mut writer) in self.open_files.drain(..) {
+            writer._finish()?;
+        }
+        Ok(())
+    }
+}
+
+pub struct PartitionedSink {}
+impl PartitionedSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        file_type: &FileType,
+        options: &PartitionedSinkOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            options.max_open_files > 0,
+            InvalidOperation: "'max_open_files' must be at least 1"
+        );
+        for name in &options.partition_by {
+            schema.try_index_of(name)?;
+        }
+        match file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(options) => {
+                polars_ensure!(
+                    !options.is_multi_file(),
+                    InvalidOperation: "'target_file_size' and 'max_rows_per_file' are not supported for partitioned sinks"
+                );
+                polars_ensure!(
+                    !options.is_partitioned(),
+                    InvalidOperation: "'partition_by' of the parquet options is not supported in sink_partitioned, pass the keys to sink_partitioned instead"
+                );
+            },
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(options) => polars_ensure!(
+                !options.is_multi_file(),
+                InvalidOperation: "'target_file_size' and 'max_rows_per_file' are not supported for partitioned sinks"
+            ),
+            #[cfg(feature = "csv")]
+            FileType::Csv(options) => polars_ensure!(
+                !options.is_multi_file(),
+                InvalidOperation: "'target_file_size' and 'max_rows_per_file' are not supported for partitioned sinks"
+            ),
+            #[allow(unreachable_patterns)]
+            _ => {},
+        }
+
+        let mut writer = PartitionedWriter {
+            root: path.to_path_buf(),
+            file_type: file_type.clone(),
+            partition_by: options.partition_by.clone(),
+            key_names: options.key_names.clone(),
+            dropped: vec![],
+            max_open_files: options.max_open_files,
+            file_schema: schema.clone(),
+            open_files: Default::default(),
+            n_files: Default::default(),
+        };
+        let keep_partition_columns = writer.keep_partition_columns();
+        writer.dropped = options
+            .partition_by
+            .iter()
+            .zip(&options.key_names)
+            .filter(|(name, key_name)| !keep_partition_columns || name != key_name)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in &writer.dropped {
+            writer.file_schema.shift_remove(name);
+        }
+        let writer = Box::new(writer) as BoxedWriter;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
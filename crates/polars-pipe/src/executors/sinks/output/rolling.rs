@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+
+use super::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+type BoxedWriter = Box<dyn SinkWriter + Send>;
+type CreateWriter = Box<dyn FnMut(&Path) -> PolarsResult<BoxedWriter> + Send>;
+
+struct RollingFile {
+    writer: BoxedWriter,
+    path: PathBuf,
+    rows: usize,
+    /// Number of rows after which the file is finished.
+    capacity: usize,
+}
+
+/// Writes a directory of files, rolling over to a new file once the current one has
+/// `max_rows_per_file` rows or about `target_file_size` bytes. The writers must write every batch
+/// through to the file, so that its size is known.
+struct RollingWriter {
+    dir: PathBuf,
+    extension: &'static str,
+    create_writer: CreateWriter,
+    target_file_size: Option<usize>,
+    max_rows_per_file: Option<usize>,
+    n_files: usize,
+    file: Option<RollingFile>,
+    /// Rows and bytes of the finished files, to estimate the size of the next ones.
+    written_rows: usize,
+    written_bytes: u64,
+}
+
+impl RollingWriter {
+    fn open_file(&mut self, df: &DataFrame) -> PolarsResult<RollingFile> {
+        let mut capacity = self.max_rows_per_file.unwrap_or(usize::MAX);
+        if let Some(target_file_size) = self.target_file_size {
+            let bytes_per_row = if self.written_rows > 0 {
+                self.written_bytes as f64 / self.written_rows as f64
+            } else {
+                // Nothing is written yet, the in-memory size is a first guess.
+                df.estimated_size() as f64 / df.height() as f64
+            };
+            capacity = capacity.min(((target_file_size as f64 / bytes_per_row) as usize).max(1));
+        }
+
+        // Use a fixed-width file name so that it sorts properly.
+        let path = self
+            .dir
+            .join(format!("{:08x}.{}", self.n_files, self.extension));
+        self.n_files += 1;
+        let writer = (self.create_writer)(&path)?;
+        Ok(RollingFile {
+            writer,
+            path,
+            rows: 0,
+            capacity,
+        })
+    }
+
+    /// Corrects the capacity of `file` with the actual size of the rows written so far.
+    fn update_capacity(&self, file: &mut RollingFile) -> PolarsResult<()> {
+        let Some(target_file_size) = self.target_file_size else {
+            return Ok(());
+        };
+        let bytes = std::fs::metadata(&file.path)?.len() as f64;
+        if bytes == 0.0 {
+            return Ok(());
+        }
+        let remaining = (target_file_size as f64 - bytes).max(0.0) * file.rows as f64 / bytes;
+        file.capacity = (file.rows + remaining as usize)
+            .min(self.max_rows_per_file.unwrap_or(usize::MAX))
+            .max(file.rows);
+        Ok(())
+    }
+
+    fn finish_file(&mut self, mut file: RollingFile) -> PolarsResult<()> {
+        file.writer._finish()?;
+        self.written_bytes += std::fs::metadata(&file.path)?.len();
+        self.written_rows += file.rows;
+        Ok(())
+    }
+}
+
+impl SinkWriter for RollingWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut offset = 0;
+        while offset < df.height() {
+            let mut file = match self.file.take() {
+                Some(file) => file,
+                None => self.open_file(df)?,
+            };
+            let n_rows = (file.capacity - file.rows).min(df.height() - offset);
+            file.writer._write_batch(&df.slice(offset as i64, n_rows))?;
+            file.rows += n_rows;
+            offset += n_rows;
+
+            self.update_capacity(&mut file)?;
+            if file.rows >= file.capacity {
+                self.finish_file(file)?;
+            } else {
+                self.file = Some(file);
+            }
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        if let Some(file) = self.file.take() {
+            self.finish_file(file)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns a sink that writes a directory of files with the writers of `create_writer`, see
+/// [`RollingWriter`].
+pub(super) fn rolling_files_sink(
+    dir: &Path,
+    extension: &'static str,
+    create_writer: CreateWriter,
+    target_file_size: Option<usize>,
+    max_rows_per_file: Option<usize>,
+    maintain_order: bool,
+) -> PolarsResult<FilesSink> {
+    polars_ensure!(
+        target_file_size != Some(0) && max_rows_per_file != Some(0),
+        InvalidOperation: "'target_file_size' and 'max_rows_per_file' must be positive"
+    );
+    std::fs::create_dir_all(dir)?;
+    let writer = RollingWriter {
+        dir: dir.to_path_buf(),
+        extension,
+        create_writer,
+        target_file_size,
+        max_rows_per_file,
+        n_files: 0,
+        file: None,
+        written_rows: 0,
+        written_bytes: 0,
+    };
+    let writer = Box::new(writer) as BoxedWriter;
+
+    let morsels_per_sink = morsels_per_sink();
+    let backpressure = morsels_per_sink * 2;
+    let (sender, receiver) = bounded(backpressure);
+
+    let io_thread_handle = Arc::new(Some(init_writer_thread(
+        receiver,
+        writer,
+        maintain_order,
+        morsels_per_sink,
+    )));
+
+    Ok(FilesSink {
+        sender,
+        io_thread_handle,
+    })
+}
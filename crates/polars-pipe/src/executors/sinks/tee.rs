@@ -0,0 +1,72 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Passes every chunk to all of its sinks, so the output of a query is written to multiple
+/// destinations in a single pass.
+pub struct TeeSink {
+    sinks: Vec<Box<dyn Sink>>,
+    finished: Vec<bool>,
+}
+
+impl TeeSink {
+    pub(crate) fn new(sinks: Vec<Box<dyn Sink>>) -> Self {
+        let finished = vec![false; sinks.len()];
+        Self { sinks, finished }
+    }
+}
+
+impl Sink for TeeSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        for (sink, finished) in self.sinks.iter_mut().zip(self.finished.iter_mut()) {
+            if *finished {
+                continue;
+            }
+            if let SinkResult::Finished = sink.sink(context, chunk.clone())? {
+                *finished = true;
+            }
+        }
+
+        if self.finished.iter().all(|finished| *finished) {
+            Ok(SinkResult::Finished)
+        } else {
+            Ok(SinkResult::CanHaveMoreInput)
+        }
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        for (sink, other) in self.sinks.iter_mut().zip(other.sinks.iter_mut()) {
+            sink.combine(other.as_mut())
+        }
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            sinks: self
+                .sinks
+                .iter()
+                .map(|sink| sink.split(thread_no))
+                .collect(),
+            finished: self.finished.clone(),
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        for sink in self.sinks.iter_mut() {
+            sink.finalize(context)?;
+        }
+        // return a dummy dataframe, like the file sinks
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "tee_sink"
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::schema::SchemaRef;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, AnonymousScanBatches};
+use polars_plan::prelude::FileScanOptions;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Streams the batches of an [`AnonymousScan`] that supports streaming, see
+/// [`AnonymousScan::is_streamable`].
+pub struct AnonymousSource {
+    function: Arc<dyn AnonymousScan>,
+    n_rows: Option<usize>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
+    n_rows_read: usize,
+    /// The pass of this source over the scan, if the scan has passes.
+    batches: Option<AnonymousScanBatches>,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        file_options: FileScanOptions,
+        schema: SchemaRef,
+        output_schema: Option<SchemaRef>,
+    ) -> PolarsResult<Self> {
+        let mut source = Self {
+            function,
+            n_rows: file_options.slice.map(|x| {
+                assert_eq!(x.0, 0);
+                x.1
+            }),
+            with_columns: file_options.with_columns,
+            schema,
+            output_schema,
+            n_rows_read: 0,
+            batches: None,
+        };
+        source.batches = source.function.batches(source.args(source.n_rows))?;
+        Ok(source)
+    }
+
+    fn args(&self, n_rows: Option<usize>) -> AnonymousScanArgs {
+        AnonymousScanArgs {
+            n_rows,
+            with_columns: self.with_columns.clone(),
+            schema: self.schema.clone(),
+            output_schema: self.output_schema.clone(),
+            predicate: None,
+        }
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let n_rows = self.n_rows.map(|n| n.saturating_sub(self.n_rows_read));
+        if n_rows == Some(0) {
+            return Ok(SourceResult::Finished);
+        }
+        let batch = match &mut self.batches {
+            Some(batches) => batches.next().transpose()?,
+            None => self.function.next_batch(self.args(n_rows))?,
+        };
+
+        match batch {
+            Some(mut data) => {
+                if let Some(n_rows) = n_rows {
+                    data = data.head(Some(n_rows));
+                }
+                self.n_rows_read += data.height();
+                let chunk = DataChunk {
+                    chunk_index: get_source_index(1) as IdxSize,
+                    data,
+                };
+                Ok(SourceResult::GotMoreData(vec![chunk]))
+            },
+            None => Ok(SourceResult::Finished),
+        }
+    }
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
@@ -12,6 +12,7 @@ use polars_core::POOL;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
 use polars_io::parquet::read::{BatchedParquetReader, ParquetOptions, ParquetReader};
+use polars_io::parquet::summary::MetadataSummary;
 use polars_io::path_utils::is_cloud_url;
 use polars_io::pl_async::get_runtime;
 use polars_io::predicates::PhysicalIoExpr;
@@ -42,12 +43,17 @@ pub struct ParquetSource {
     #[allow(dead_code)]
     cloud_options: Option<CloudOptions>,
     metadata: Option<FileMetaDataRef>,
+    /// The summary of the dataset that the local files are read from
+    summary: Option<MetadataSummary>,
     file_info: FileInfo,
     hive_parts: Option<Arc<Vec<HivePartitions>>>,
     verbose: bool,
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    /// The categoricals of different files can only be combined with a string cache.
+    #[cfg(feature = "dtype-categorical")]
+    _string_cache: Option<polars_core::StringCacheHolder>,
 }
 
 impl ParquetSource {
@@ -130,7 +136,13 @@ impl ParquetSource {
         let batched_reader = {
             let file = std::fs::File::open(path).unwrap();
             let mut reader = ParquetReader::new(file)
+                .with_metadata(
+                    self.summary
+                        .as_ref()
+                        .and_then(|summary| summary.get(path).cloned()),
+                )
                 .with_projection(projection)
+                .with_dictionary_as_categorical(options.dictionary_as_categorical)
                 .check_schema(
                     self.file_info
                         .reader_schema
@@ -168,7 +180,9 @@ impl ParquetSource {
             });
 
             reader = reader.with_slice(slice);
-            reader.batched(chunk_size)?
+            reader
+                .batched(chunk_size)?
+                .with_large_row_group_bytes(options.large_row_group_bytes())
         };
         self.finish_init_reader(batched_reader)?;
         Ok(())
@@ -201,6 +215,7 @@ impl ParquetSource {
                     .await?
                     .with_row_index(file_options.row_index)
                     .with_projection(projection)
+                    .with_dictionary_as_categorical(options.dictionary_as_categorical)
                     .check_schema(
                         self.file_info
                             .reader_schema
@@ -212,6 +227,7 @@ impl ParquetSource {
                     .await?
                     .with_predicate(predicate.clone())
                     .use_statistics(options.use_statistics)
+                    .with_prefetch(options.prefetch)
                     .with_hive_partition_columns(hive_partitions)
                     .with_include_file_path(
                         self.file_options
@@ -237,7 +253,11 @@ impl ParquetSource {
                 )
             });
 
-            async_reader.with_slice(slice).batched(chunk_size).await?
+            async_reader
+                .with_slice(slice)
+                .batched(chunk_size)
+                .await?
+                .with_large_row_group_bytes(options.large_row_group_bytes())
         };
         Ok(batched_reader)
     }
@@ -264,6 +284,11 @@ impl ParquetSource {
             eprintln!("POLARS PREFETCH_SIZE: {}", prefetch_size)
         }
         let run_async = paths.first().map(is_cloud_url).unwrap_or(false) || config::force_async();
+        let summary = if options.use_metadata_summary && !run_async {
+            MetadataSummary::find(&paths)?
+        } else {
+            None
+        };
 
         let mut source = ParquetSource {
             batched_readers: VecDeque::new(),
@@ -276,12 +301,17 @@ impl ParquetSource {
             paths,
             cloud_options,
             metadata,
+            summary,
             file_info,
             hive_parts,
             verbose,
             run_async,
             prefetch_size,
             predicate,
+            #[cfg(feature = "dtype-categorical")]
+            _string_cache: options
+                .dictionary_as_categorical
+                .then(polars_core::StringCacheHolder::hold),
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
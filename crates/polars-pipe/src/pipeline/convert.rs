@@ -4,6 +4,10 @@ use std::rc::Rc;
 use hashbrown::hash_map::Entry;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "dynamic_group_by")]
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+#[cfg(feature = "parquet")]
+use polars_io::parquet::write::{ParquetWriteOptions, DEFAULT_MAX_OPEN_FILES};
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
 use polars_ops::prelude::JoinType;
@@ -156,6 +160,16 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { function, .. } => {
+                    let src = sources::AnonymousSource::new(
+                        function,
+                        file_options,
+                        file_info.schema,
+                        output_schema,
+                    )?;
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -163,6 +177,131 @@ where
     }
 }
 
+fn get_payload_sink(
+    payload: &SinkType,
+    input_schema: &SchemaRef,
+) -> PolarsResult<Box<dyn SinkTrait>> {
+    let out = match payload {
+        SinkType::Memory => Box::new(OrderedSink::new(input_schema.clone())) as Box<dyn SinkTrait>,
+        #[allow(unused_variables)]
+        SinkType::File {
+            path, file_type, ..
+        } => {
+            let path = path.as_ref().as_path();
+            match &file_type {
+                #[cfg(feature = "parquet")]
+                FileType::Parquet(options) if options.is_partitioned() => {
+                    let partitioned_options = PartitionedSinkOptions {
+                        partition_by: options.partition_by.clone(),
+                        key_names: options.partition_by.clone(),
+                        max_open_files: options.max_open_files.unwrap_or(DEFAULT_MAX_OPEN_FILES),
+                    };
+                    let file_type = FileType::Parquet(ParquetWriteOptions {
+                        partition_by: vec![],
+                        ..options.clone()
+                    });
+                    Box::new(PartitionedSink::new(
+                        path,
+                        &file_type,
+                        &partitioned_options,
+                        input_schema.as_ref(),
+                    )?) as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "parquet")]
+                FileType::Parquet(options) if options.is_multi_file() => Box::new(
+                    ParquetFilesSink::new(path, options.clone(), input_schema.as_ref())?,
+                )
+                    as Box<dyn SinkTrait>,
+                #[cfg(feature = "parquet")]
+                FileType::Parquet(options) => Box::new(ParquetSink::new(
+                    path,
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn SinkTrait>,
+                #[cfg(feature = "ipc")]
+                FileType::Ipc(options) => {
+                    Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "csv")]
+                FileType::Csv(options) => {
+                    Box::new(CsvSink::new(path, options.clone(), input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "json")]
+                FileType::Json(options) => {
+                    Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[allow(unreachable_patterns)]
+                _ => unreachable!(),
+            }
+        },
+        #[cfg(feature = "cloud")]
+        SinkType::Cloud {
+            #[cfg(any(feature = "parquet", feature = "ipc"))]
+            uri,
+            file_type,
+            #[cfg(any(feature = "parquet", feature = "ipc"))]
+            cloud_options,
+            ..
+        } => match &file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
+                uri.as_ref().as_str(),
+                cloud_options.as_ref(),
+                parquet_options.clone(),
+                input_schema.as_ref(),
+            )?) as Box<dyn SinkTrait>,
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
+                uri.as_ref().as_str(),
+                cloud_options.as_ref(),
+                *ipc_options,
+                input_schema.as_ref(),
+            )?) as Box<dyn SinkTrait>,
+            #[allow(unreachable_patterns)]
+            other_file_type => {
+                todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported.")
+            },
+        },
+        #[cfg(any(
+            feature = "parquet",
+            feature = "ipc",
+            feature = "csv",
+            feature = "json"
+        ))]
+        SinkType::Partitioned {
+            path,
+            file_type,
+            options,
+        } => Box::new(PartitionedSink::new(
+            path.as_ref().as_path(),
+            file_type,
+            options,
+            input_schema.as_ref(),
+        )?) as Box<dyn SinkTrait>,
+        #[cfg(not(any(
+            feature = "parquet",
+            feature = "ipc",
+            feature = "csv",
+            feature = "json"
+        )))]
+        SinkType::Partitioned { .. } => unreachable!(),
+        SinkType::Multiple(sinks) => {
+            let sinks = sinks
+                .iter()
+                .map(|payload| get_payload_sink(payload, input_schema))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Box::new(TeeSink::new(sinks)) as Box<dyn SinkTrait>
+        },
+        SinkType::Batches(callback) => {
+            Box::new(CallbackSink::new(callback.clone())) as Box<dyn SinkTrait>
+        },
+    };
+    Ok(out)
+}
+
 pub fn get_sink<F>(
     node: Node,
     lp_arena: &Arena<IR>,
@@ -176,72 +315,8 @@ where
     use IR::*;
     let out = match lp_arena.get(node) {
         Sink { input, payload } => {
-            let input_schema = lp_arena.get(*input).schema(lp_arena);
-            match payload {
-                SinkType::Memory => {
-                    Box::new(OrderedSink::new(input_schema.into_owned())) as Box<dyn SinkTrait>
-                },
-                #[allow(unused_variables)]
-                SinkType::File {
-                    path, file_type, ..
-                } => {
-                    let path = path.as_ref().as_path();
-                    match &file_type {
-                        #[cfg(feature = "parquet")]
-                        FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "ipc")]
-                        FileType::Ipc(options) => {
-                            Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "csv")]
-                        FileType::Csv(options) => {
-                            Box::new(CsvSink::new(path, options.clone(), input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "json")]
-                        FileType::Json(options) => {
-                            Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[allow(unreachable_patterns)]
-                        _ => unreachable!(),
-                    }
-                },
-                #[cfg(feature = "cloud")]
-                SinkType::Cloud {
-                    #[cfg(any(feature = "parquet", feature = "ipc"))]
-                    uri,
-                    file_type,
-                    #[cfg(any(feature = "parquet", feature = "ipc"))]
-                    cloud_options,
-                    ..
-                } => {
-                    match &file_type {
-                        #[cfg(feature = "parquet")]
-                        FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
-                            uri.as_ref().as_str(),
-                            cloud_options.as_ref(),
-                            *parquet_options,
-                            lp_arena.get(*input).schema(lp_arena).as_ref(),
-                        )?)
-                            as Box<dyn SinkTrait>,
-                        #[cfg(feature = "ipc")]
-                        FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
-                            uri.as_ref().as_str(),
-                            cloud_options.as_ref(),
-                            *ipc_options,
-                            lp_arena.get(*input).schema(lp_arena).as_ref(),
-                        )?)
-                            as Box<dyn SinkTrait>,
-                        #[allow(unreachable_patterns)]
-                        other_file_type => todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported."),
-                    }
-                },
-            }
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            get_payload_sink(payload, &input_schema)?
         },
         Join {
             input_left,
@@ -481,6 +556,41 @@ where
             options,
             ..
         } => {
+            #[cfg(feature = "dynamic_group_by")]
+            if let Some(windows) = options
+                .dynamic
+                .clone()
+                .map(group_by::TemporalWindows::Dynamic)
+                .or_else(|| {
+                    options
+                        .rolling
+                        .clone()
+                        .map(group_by::TemporalWindows::Rolling)
+                })
+            {
+                let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+                let mut state = ExpressionConversionState::new(true, 0);
+                let aggs = aggs
+                    .iter()
+                    .map(|e| {
+                        create_physical_expr(
+                            e,
+                            Context::Aggregation,
+                            expr_arena,
+                            Some(&input_schema),
+                            &mut state,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                return Ok(Box::new(group_by::TemporalGroupBySink::new(
+                    windows,
+                    aggs,
+                    &input_schema,
+                    output_schema.clone(),
+                    options.slice,
+                )));
+            }
+
             let input_schema = lp_arena.get(*input).schema(lp_arena).as_ref().clone();
             let key_columns = Arc::new(exprs_to_physical(
                 keys,
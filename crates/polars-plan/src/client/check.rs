@@ -20,7 +20,7 @@ pub(super) fn assert_cloud_eligible(dsl: &DslPlan) -> PolarsResult<()> {
                 ..
             } => return ineligible_error("contains anonymous scan"),
             DslPlan::Sink { payload, .. } => {
-                if !matches!(payload, SinkType::Cloud { .. }) {
+                if !is_cloud_sink(payload) {
                     return ineligible_error("contains sink to non-cloud location");
                 }
             },
@@ -30,6 +30,13 @@ pub(super) fn assert_cloud_eligible(dsl: &DslPlan) -> PolarsResult<()> {
     Ok(())
 }
 
+fn is_cloud_sink(payload: &SinkType) -> bool {
+    match payload {
+        SinkType::Multiple(sinks) => sinks.iter().all(is_cloud_sink),
+        payload => matches!(payload, SinkType::Cloud { .. }),
+    }
+}
+
 fn ineligible_error(message: &str) -> PolarsResult<()> {
     Err(polars_err!(
         InvalidOperation:
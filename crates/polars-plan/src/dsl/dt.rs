@@ -27,11 +27,20 @@ impl DateLikeNameSpace {
 
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// Duration takes `"iso"` (e.g. `"P1DT2H"`) or `"humanized"` (e.g. `"1d 2h"`) as format.
     pub fn to_string(self, format: &str) -> Expr {
+        self.to_string_localized(format, DateLocale::default())
+    }
+
+    /// Convert from Date/Datetime into String with the given format, writing month and weekday
+    /// names (`%B`, `%b`, `%A` and `%a`) in `locale`. Besides the chrono directives, `%Q` is
+    /// replaced by the quarter.
+    pub fn to_string_localized(self, format: &str, locale: DateLocale) -> Expr {
         let format = format.to_string();
         self.0
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::ToString(
-                format,
+                format, locale,
             )))
     }
 
@@ -44,7 +44,7 @@ pub enum TemporalFunction {
     TotalMilliseconds,
     TotalMicroseconds,
     TotalNanoseconds,
-    ToString(String),
+    ToString(String, DateLocale),
     CastTimeUnit(TimeUnit),
     WithTimeUnit(TimeUnit),
     #[cfg(feature = "timezones")]
@@ -84,7 +84,7 @@ impl TemporalFunction {
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
             TotalDays | TotalHours | TotalMinutes | TotalSeconds | TotalMilliseconds
             | TotalMicroseconds | TotalNanoseconds => mapper.with_dtype(DataType::Int64),
-            ToString(_) => mapper.with_dtype(DataType::String),
+            ToString(_, _) => mapper.with_dtype(DataType::String),
             WithTimeUnit(_) => mapper.with_same_dtype(),
             CastTimeUnit(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Duration(_) => Ok(DataType::Duration(*tu)),
@@ -169,7 +169,7 @@ impl Display for TemporalFunction {
             TotalMilliseconds => "total_milliseconds",
             TotalMicroseconds => "total_microseconds",
             TotalNanoseconds => "total_nanoseconds",
-            ToString(_) => "to_string",
+            ToString(_, _) => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
@@ -342,8 +342,8 @@ pub(super) fn total_nanoseconds(s: &Series) -> PolarsResult<Series> {
 pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     s.timestamp(tu).map(|ca| ca.into_series())
 }
-pub(super) fn to_string(s: &Series, format: &str) -> PolarsResult<Series> {
-    TemporalMethods::to_string(s, format)
+pub(super) fn to_string(s: &Series, format: &str, locale: DateLocale) -> PolarsResult<Series> {
+    TemporalMethods::to_string_localized(s, format, locale)
 }
 
 #[cfg(feature = "timezones")]
@@ -0,0 +1,64 @@
+use polars_time::Duration;
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+use crate::map_as_slice;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum FunnelFunction {
+    WindowFunnel { window: Duration },
+    RetentionMatrix { every: Duration, periods: usize },
+}
+
+impl FunnelFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use FunnelFunction::*;
+        match self {
+            WindowFunnel { .. } => mapper.with_dtype(DataType::UInt32),
+            RetentionMatrix { periods, .. } => {
+                let mut fields = vec![Field::new("cohort".into(), mapper.args()[1].dtype.clone())];
+                fields
+                    .extend((0..*periods).map(|k| {
+                        Field::new(format_pl_smallstr!("period_{}", k), DataType::UInt32)
+                    }));
+                mapper.with_dtype(DataType::Struct(fields))
+            },
+        }
+    }
+}
+
+impl Display for FunnelFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use FunnelFunction::*;
+        let s = match self {
+            WindowFunnel { .. } => "window_funnel",
+            RetentionMatrix { .. } => "retention_matrix",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<FunnelFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: FunnelFunction) -> Self {
+        use FunnelFunction::*;
+        match func {
+            WindowFunnel { window } => map_as_slice!(window_funnel, window),
+            RetentionMatrix { every, periods } => map_as_slice!(retention_matrix, every, periods),
+        }
+    }
+}
+
+pub(super) fn window_funnel(s: &[Series], window: Duration) -> PolarsResult<Series> {
+    let time = &s[0];
+    let level = polars_time::funnel_level(time, &s[1..], window)?;
+    Ok(Series::new(time.name().clone(), [level]))
+}
+
+pub(super) fn retention_matrix(
+    s: &[Series],
+    every: Duration,
+    periods: usize,
+) -> PolarsResult<Series> {
+    polars_time::cohort_retention_matrix(&s[0], &s[1], every, periods)
+}
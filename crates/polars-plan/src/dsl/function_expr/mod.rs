@@ -28,6 +28,8 @@ mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
 mod fill_null;
+#[cfg(feature = "funnel")]
+mod funnel;
 #[cfg(feature = "fused")]
 mod fused;
 mod list;
@@ -93,6 +95,8 @@ pub(super) use self::business::BusinessFunction;
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::TemporalFunction;
+#[cfg(feature = "funnel")]
+pub use self::funnel::FunnelFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
@@ -129,6 +133,8 @@ pub enum FunctionExpr {
     Boolean(BooleanFunction),
     #[cfg(feature = "business")]
     Business(BusinessFunction),
+    #[cfg(feature = "funnel")]
+    Funnel(FunnelFunction),
     #[cfg(feature = "abs")]
     Abs,
     Negate,
@@ -377,6 +383,8 @@ impl Hash for FunctionExpr {
             Boolean(f) => f.hash(state),
             #[cfg(feature = "business")]
             Business(f) => f.hash(state),
+            #[cfg(feature = "funnel")]
+            Funnel(f) => f.hash(state),
             Pow(f) => f.hash(state),
             #[cfg(feature = "search_sorted")]
             SearchSorted(f) => f.hash(state),
@@ -605,6 +613,8 @@ impl Display for FunctionExpr {
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "business")]
             Business(func) => return write!(f, "{func}"),
+            #[cfg(feature = "funnel")]
+            Funnel(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
@@ -873,6 +883,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Boolean(func) => func.into(),
             #[cfg(feature = "business")]
             Business(func) => func.into(),
+            #[cfg(feature = "funnel")]
+            Funnel(func) => func.into(),
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
             Negate => map!(dispatch::negate),
@@ -1,6 +1,8 @@
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
-use polars_time::{datetime_range_impl, ClosedWindow, Duration};
+use polars_time::{
+    calendar_date_range, datetime_range_impl, CalendarFrequency, ClosedWindow, Duration,
+};
 
 use super::utils::{
     ensure_range_bounds_contain_exactly_one_value, temporal_ranges_impl_broadcast,
@@ -47,6 +49,36 @@ pub(super) fn date_range(
     out.cast(&to_type)
 }
 
+pub(super) fn date_range_calendar(
+    s: &[Series],
+    frequency: &CalendarFrequency,
+    closed: ClosedWindow,
+) -> PolarsResult<Series> {
+    let start = &s[0];
+    let end = &s[1];
+
+    ensure_range_bounds_contain_exactly_one_value(start, end)?;
+    let start = start.strict_cast(&DataType::Date)?;
+    let end = end.strict_cast(&DataType::Date)?;
+
+    let name = start.name().clone();
+    let start = start
+        .date()?
+        .as_date_iter()
+        .next()
+        .flatten()
+        .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
+    let end = end
+        .date()?
+        .as_date_iter()
+        .next()
+        .flatten()
+        .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
+
+    let out = calendar_date_range(name, start, end, frequency, closed)?;
+    Ok(out.into_series())
+}
+
 pub(super) fn date_ranges(
     s: &[Series],
     interval: Duration,
@@ -1,7 +1,9 @@
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
-use polars_time::{datetime_range_impl, ClosedWindow, Duration};
+use polars_time::{
+    calendar_datetime_range, datetime_range_impl, CalendarFrequency, ClosedWindow, Duration,
+};
 
 use super::utils::{
     ensure_range_bounds_contain_exactly_one_value, temporal_ranges_impl_broadcast,
@@ -11,13 +13,14 @@ use crate::dsl::function_expr::FieldsMapper;
 
 const CAPACITY_FACTOR: usize = 5;
 
-pub(super) fn datetime_range(
+/// Returns the name, the physical `start` and `end` values and the output dtype of a datetime
+/// range.
+fn datetime_range_bounds(
     s: &[Series],
-    interval: Duration,
-    closed: ClosedWindow,
+    nanoseconds: bool,
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
-) -> PolarsResult<Series> {
+) -> PolarsResult<(PlSmallStr, i64, i64, DataType)> {
     let mut start = s[0].clone();
     let mut end = s[1].clone();
 
@@ -30,7 +33,7 @@ pub(super) fn datetime_range(
         (DataType::Date, time_unit) => {
             if let Some(tu) = time_unit {
                 DataType::Datetime(tu, None)
-            } else if interval.nanoseconds() % 1_000 != 0 {
+            } else if nanoseconds {
                 DataType::Datetime(TimeUnit::Nanoseconds, None)
             } else {
                 DataType::Datetime(TimeUnit::Microseconds, None)
@@ -82,11 +85,46 @@ pub(super) fn datetime_range(
         _ => (start.cast(&dtype)?, end.cast(&dtype)?),
     };
 
-    let name = start.name();
+    let name = start.name().clone();
     let start = temporal_series_to_i64_scalar(&start)
         .ok_or_else(|| polars_err!(ComputeError: "start is an out-of-range time."))?;
     let end = temporal_series_to_i64_scalar(&end)
         .ok_or_else(|| polars_err!(ComputeError: "end is an out-of-range time."))?;
+    Ok((name, start, end, dtype))
+}
+
+pub(super) fn datetime_range(
+    s: &[Series],
+    interval: Duration,
+    closed: ClosedWindow,
+    time_unit: Option<TimeUnit>,
+    time_zone: Option<TimeZone>,
+) -> PolarsResult<Series> {
+    let (name, start, end, dtype) =
+        datetime_range_bounds(s, interval.nanoseconds() % 1_000 != 0, time_unit, time_zone)?;
+
+    let result = match dtype {
+        DataType::Datetime(tu, ref tz) => {
+            let tz = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => Some(parse_time_zone(tz)?),
+                _ => None,
+            };
+            datetime_range_impl(name, start, end, interval, closed, tu, tz.as_ref())?
+        },
+        _ => unimplemented!(),
+    };
+    Ok(result.cast(&dtype).unwrap().into_series())
+}
+
+pub(super) fn datetime_range_calendar(
+    s: &[Series],
+    frequency: &CalendarFrequency,
+    closed: ClosedWindow,
+    time_unit: Option<TimeUnit>,
+    time_zone: Option<TimeZone>,
+) -> PolarsResult<Series> {
+    let (name, start, end, dtype) = datetime_range_bounds(s, false, time_unit, time_zone)?;
 
     let result = match dtype {
         DataType::Datetime(tu, ref tz) => {
@@ -95,7 +133,7 @@ pub(super) fn datetime_range(
                 Some(tz) => Some(parse_time_zone(tz)?),
                 _ => None,
             };
-            datetime_range_impl(name.clone(), start, end, interval, closed, tu, tz.as_ref())?
+            calendar_datetime_range(name, start, end, frequency, closed, tu, tz.as_ref())?
         },
         _ => unimplemented!(),
     };
@@ -11,7 +11,7 @@ use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
 #[cfg(feature = "temporal")]
-use polars_time::{ClosedWindow, Duration};
+use polars_time::{CalendarFrequency, ClosedWindow, Duration};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -38,6 +38,11 @@ pub enum RangeFunction {
         interval: Duration,
         closed: ClosedWindow,
     },
+    #[cfg(feature = "dtype-date")]
+    DateRangeCalendar {
+        frequency: CalendarFrequency,
+        closed: ClosedWindow,
+    },
     #[cfg(feature = "dtype-datetime")]
     DatetimeRange {
         interval: Duration,
@@ -52,6 +57,13 @@ pub enum RangeFunction {
         time_unit: Option<TimeUnit>,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "dtype-datetime")]
+    DatetimeRangeCalendar {
+        frequency: CalendarFrequency,
+        closed: ClosedWindow,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+    },
     #[cfg(feature = "dtype-time")]
     TimeRange {
         interval: Duration,
@@ -74,6 +86,8 @@ impl RangeFunction {
             DateRange { .. } => mapper.with_dtype(DataType::Date),
             #[cfg(feature = "dtype-date")]
             DateRanges { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::Date))),
+            #[cfg(feature = "dtype-date")]
+            DateRangeCalendar { .. } => mapper.with_dtype(DataType::Date),
             #[cfg(feature = "dtype-datetime")]
             DatetimeRange {
                 interval: _,
@@ -98,6 +112,16 @@ impl RangeFunction {
                     mapper.map_to_datetime_range_dtype(time_unit.as_ref(), time_zone.as_ref())?;
                 mapper.with_dtype(DataType::List(Box::new(inner_dtype)))
             },
+            #[cfg(feature = "dtype-datetime")]
+            DatetimeRangeCalendar {
+                time_unit,
+                time_zone,
+                ..
+            } => {
+                let dtype =
+                    mapper.map_to_datetime_range_dtype(time_unit.as_ref(), time_zone.as_ref())?;
+                mapper.with_dtype(dtype)
+            },
             #[cfg(feature = "dtype-time")]
             TimeRange { .. } => mapper.with_dtype(DataType::Time),
             #[cfg(feature = "dtype-time")]
@@ -118,8 +142,12 @@ impl Display for RangeFunction {
             DateRanges { .. } => "date_ranges",
             #[cfg(feature = "dtype-datetime")]
             DatetimeRange { .. } => "datetime_range",
+            #[cfg(feature = "dtype-date")]
+            DateRangeCalendar { .. } => "date_range_calendar",
             #[cfg(feature = "dtype-datetime")]
             DatetimeRanges { .. } => "datetime_ranges",
+            #[cfg(feature = "dtype-datetime")]
+            DatetimeRangeCalendar { .. } => "datetime_range_calendar",
             #[cfg(feature = "dtype-time")]
             TimeRange { .. } => "time_range",
             #[cfg(feature = "dtype-time")]
@@ -177,6 +205,25 @@ impl From<RangeFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     time_zone.clone()
                 )
             },
+            #[cfg(feature = "dtype-date")]
+            DateRangeCalendar { frequency, closed } => {
+                map_as_slice!(date_range::date_range_calendar, &frequency, closed)
+            },
+            #[cfg(feature = "dtype-datetime")]
+            DatetimeRangeCalendar {
+                frequency,
+                closed,
+                time_unit,
+                time_zone,
+            } => {
+                map_as_slice!(
+                    datetime_range::datetime_range_calendar,
+                    &frequency,
+                    closed,
+                    time_unit,
+                    time_zone.clone()
+                )
+            },
             #[cfg(feature = "dtype-time")]
             TimeRange { interval, closed } => {
                 map_as_slice!(time_range::time_range, interval, closed)
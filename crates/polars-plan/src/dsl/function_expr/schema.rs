@@ -34,6 +34,8 @@ impl FunctionExpr {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
             },
+            #[cfg(feature = "funnel")]
+            Funnel(func) => func.get_field(mapper),
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
@@ -112,6 +112,8 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration(DurationParseOptions),
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -167,6 +169,8 @@ impl StringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration(options) => mapper.with_dtype(DataType::Duration(options.time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -276,6 +280,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration(_) => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -374,6 +380,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Decode(strict) => map!(strings::base64_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration(options) => map!(strings::to_duration, &options),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
@@ -661,13 +669,20 @@ pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     let ca = s.str()?;
+    let (values, format) = match options.format.as_deref() {
+        Some(format) => {
+            let (values, format) = ca.delocalize_dates(format, options.locale);
+            (values, Some(format))
+        },
+        None => (Cow::Borrowed(ca), None),
+    };
     let out = {
         if options.exact {
-            ca.as_date(options.format.as_deref(), options.cache)?
+            values
+                .as_date(format.as_deref(), options.cache)?
                 .into_series()
         } else {
-            ca.as_date_not_exact(options.format.as_deref())?
-                .into_series()
+            values.as_date_not_exact(format.as_deref())?.into_series()
         }
     };
 
@@ -695,10 +710,17 @@ fn to_datetime(
     if let Some(time_zone) = time_zone {
         validate_time_zone(time_zone)?;
     }
+    let (values, format) = match options.format.as_deref() {
+        Some(format) => {
+            let (values, format) = datetime_strings.delocalize_dates(format, options.locale);
+            (values, Some(format))
+        },
+        None => (Cow::Borrowed(*datetime_strings), None),
+    };
     let out = if options.exact {
-        datetime_strings
+        values
             .as_datetime(
-                options.format.as_deref(),
+                format.as_deref(),
                 *time_unit,
                 options.cache,
                 tz_aware,
@@ -707,9 +729,9 @@ fn to_datetime(
             )?
             .into_series()
     } else {
-        datetime_strings
+        values
             .as_datetime_not_exact(
-                options.format.as_deref(),
+                format.as_deref(),
                 *time_unit,
                 tz_aware,
                 time_zone,
@@ -741,6 +763,19 @@ fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Series, options: &DurationParseOptions) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca
+        .as_duration(options.time_unit, options.ambiguous)?
+        .into_series();
+
+    if options.strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn join(s: &Series, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Series> {
     let str_s = s.cast(&DataType::String)?;
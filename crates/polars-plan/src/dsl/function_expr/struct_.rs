@@ -13,7 +13,7 @@ pub enum StructFunction {
     PrefixFields(PlSmallStr),
     SuffixFields(PlSmallStr),
     #[cfg(feature = "json")]
-    JsonEncode,
+    JsonEncode(JsonEncodeOptions),
     WithFields,
     MultipleFields(Arc<[PlSmallStr]>),
 }
@@ -92,7 +92,7 @@ impl StructFunction {
                 _ => polars_bail!(op = "suffix_fields", got = dt, expected = "Struct"),
             }),
             #[cfg(feature = "json")]
-            JsonEncode => mapper.with_dtype(DataType::String),
+            JsonEncode(_) => mapper.with_dtype(DataType::String),
             WithFields => {
                 let args = mapper.args();
                 let struct_ = &args[0];
@@ -135,7 +135,7 @@ impl Display for StructFunction {
             PrefixFields(_) => write!(f, "name.prefix_fields"),
             SuffixFields(_) => write!(f, "name.suffixFields"),
             #[cfg(feature = "json")]
-            JsonEncode => write!(f, "struct.to_json"),
+            JsonEncode(_) => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
             MultipleFields(_) => write!(f, "multiple_fields"),
         }
@@ -152,7 +152,7 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             PrefixFields(prefix) => map!(prefix_fields, prefix.as_str()),
             SuffixFields(suffix) => map!(suffix_fields, suffix.as_str()),
             #[cfg(feature = "json")]
-            JsonEncode => map!(to_json),
+            JsonEncode(options) => map!(to_json, &options),
             WithFields => map_as_slice!(with_fields),
             MultipleFields(_) => unimplemented!(),
         }
@@ -216,16 +216,23 @@ pub(super) fn suffix_fields(s: &Series, suffix: &str) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "json")]
-pub(super) fn to_json(s: &Series) -> PolarsResult<Series> {
+pub(super) fn to_json(s: &Series, options: &JsonEncodeOptions) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     let dtype = ca.dtype().to_arrow(CompatLevel::newest());
+    let options = polars_json::json::write::SerializeOptions {
+        sort_fields: options.sort_fields,
+        strip_nulls: options.strip_nulls,
+        date_format: options.date_format.as_ref().map(|f| f.to_string()),
+        decimal_as_number: options.decimal_as_number,
+        decimal_trim_zeros: options.decimal_trim_zeros,
+    };
 
     let iter = ca.chunks().iter().map(|arr| {
         let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
-        polars_json::json::write::serialize_to_utf8(arr.as_ref())
+        polars_json::json::write::serialize_to_utf8_with_options(arr.as_ref(), &options)
     });
 
-    Ok(StringChunked::from_chunk_iter(ca.name().clone(), iter).into_series())
+    Ok(StringChunked::try_from_chunk_iter(ca.name().clone(), iter)?.into_series())
 }
 
 pub(super) fn with_fields(args: &[Series]) -> PolarsResult<Series> {
@@ -33,7 +33,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             TotalMilliseconds => map!(datetime::total_milliseconds),
             TotalMicroseconds => map!(datetime::total_microseconds),
             TotalNanoseconds => map!(datetime::total_nanoseconds),
-            ToString(format) => map!(datetime::to_string, &format),
+            ToString(format, locale) => map!(datetime::to_string, &format, locale),
             TimeStamp(tu) => map!(datetime::timestamp, tu),
             #[cfg(feature = "timezones")]
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
@@ -0,0 +1,43 @@
+use polars_time::Duration;
+
+use super::*;
+
+/// Count the consecutive `steps` of a funnel that the events reach within `window`.
+///
+/// `time` is the date or datetime of each event and each step a boolean expression that is
+/// `true` for the events of that step. A funnel starts at an event of the first step, and every
+/// next step must happen after the previous one and within `window` of the start. Use it in an
+/// aggregation, such as `group_by("user").agg(window_funnel(...))`, to get the level that each
+/// user reached as a `UInt32`.
+pub fn window_funnel(time: Expr, steps: Vec<Expr>, window: Duration) -> Expr {
+    let mut input = Vec::with_capacity(steps.len() + 1);
+    input.push(time);
+    input.extend(steps);
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Funnel(FunnelFunction::WindowFunnel { window }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default() | FunctionFlags::RETURNS_SCALAR,
+            ..Default::default()
+        },
+    }
+}
+
+/// Compute the cohort retention matrix of the activity of `user` at `time`.
+///
+/// Users belong to the cohort of the first period of length `every` in which they are active.
+/// The result has a struct row per cohort with the start of the cohort in the field `cohort`
+/// and the number of its users that are active `k` periods later in the fields `period_0` to
+/// `period_{periods - 1}`.
+pub fn retention_matrix(user: Expr, time: Expr, every: Duration, periods: usize) -> Expr {
+    Expr::Function {
+        input: vec![user, time],
+        function: FunctionExpr::Funnel(FunnelFunction::RetentionMatrix { every, periods }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            ..Default::default()
+        },
+    }
+}
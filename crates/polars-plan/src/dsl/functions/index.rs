@@ -14,6 +14,35 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
         .alias(name)
 }
 
+/// Find the indexes of the `k` largest rows by the `by` columns, in order.
+///
+/// Like [`arg_sort_by`] followed by a slice of `k`, but the rows are selected without sorting all
+/// of them. `descending` reverses the order of each column, like in [`Expr::top_k_by`].
+#[cfg(all(feature = "range", feature = "top_k"))]
+pub fn arg_top_k_by<K: Into<Expr>, E: AsRef<[Expr]>>(k: K, by: E, descending: Vec<bool>) -> Expr {
+    let e = &by.as_ref()[0];
+    let name = expr_output_name(e).unwrap();
+    int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE)
+        .top_k_by(k, by, descending)
+        .alias(name)
+}
+
+/// Find the indexes of the `k` smallest rows by the `by` columns, in order.
+///
+/// See [`arg_top_k_by`].
+#[cfg(all(feature = "range", feature = "top_k"))]
+pub fn arg_bottom_k_by<K: Into<Expr>, E: AsRef<[Expr]>>(
+    k: K,
+    by: E,
+    descending: Vec<bool>,
+) -> Expr {
+    let e = &by.as_ref()[0];
+    let name = expr_output_name(e).unwrap();
+    int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE)
+        .bottom_k_by(k, by, descending)
+        .alias(name)
+}
+
 #[cfg(feature = "arg_where")]
 /// Get the indices where `condition` evaluates `true`.
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
@@ -9,6 +9,8 @@ mod coerce;
 mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
+#[cfg(feature = "funnel")]
+mod funnel;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
@@ -28,6 +30,8 @@ pub use coerce::*;
 pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
+#[cfg(feature = "funnel")]
+pub use funnel::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
@@ -125,6 +125,57 @@ pub fn datetime_ranges(
     }
 }
 
+/// Create a date range from a `start` and `stop` expression with a calendar anchored `frequency`.
+#[cfg(feature = "dtype-date")]
+pub fn date_range_calendar(
+    start: Expr,
+    end: Expr,
+    frequency: CalendarFrequency,
+    closed: ClosedWindow,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Range(RangeFunction::DateRangeCalendar { frequency, closed }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Create a datetime range from a `start` and `stop` expression with a calendar anchored
+/// `frequency`.
+#[cfg(feature = "dtype-datetime")]
+pub fn datetime_range_calendar(
+    start: Expr,
+    end: Expr,
+    frequency: CalendarFrequency,
+    closed: ClosedWindow,
+    time_unit: Option<TimeUnit>,
+    time_zone: Option<TimeZone>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Range(RangeFunction::DatetimeRangeCalendar {
+            frequency,
+            closed,
+            time_unit,
+            time_zone,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            cast_to_supertypes: Some(Default::default()),
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
 /// Generate a time range.
 #[cfg(feature = "dtype-time")]
 pub fn time_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
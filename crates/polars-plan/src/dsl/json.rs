@@ -0,0 +1,571 @@
+//! A stable, versioned JSON format of [`Expr`].
+//!
+//! The serde derives of [`Expr`] mirror its variants and change between releases, this format is
+//! meant to be written by clients that don't link the same version of polars, e.g. remote clients
+//! and query builders. It covers the common expressions, others fail to serialize.
+
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The version of the format that is written, and the only one that is read.
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExprDocumentV1 {
+    version: Option<u32>,
+    expr: ExprV1,
+}
+
+/// Version 1 of the format.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum ExprV1 {
+    Column {
+        name: String,
+    },
+    All {},
+    Len {},
+    Literal {
+        value: serde_json::Value,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dtype: Option<String>,
+    },
+    Alias {
+        expr: Box<ExprV1>,
+        name: String,
+    },
+    Binary {
+        op: String,
+        left: Box<ExprV1>,
+        right: Box<ExprV1>,
+    },
+    Not {
+        expr: Box<ExprV1>,
+    },
+    IsNull {
+        expr: Box<ExprV1>,
+    },
+    IsNotNull {
+        expr: Box<ExprV1>,
+    },
+    Cast {
+        expr: Box<ExprV1>,
+        dtype: String,
+        #[serde(default = "default_true")]
+        strict: bool,
+    },
+    Agg {
+        op: String,
+        expr: Box<ExprV1>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ddof: Option<u8>,
+    },
+    When {
+        when: Box<ExprV1>,
+        then: Box<ExprV1>,
+        otherwise: Box<ExprV1>,
+    },
+    Sort {
+        expr: Box<ExprV1>,
+        #[serde(default)]
+        descending: bool,
+        #[serde(default)]
+        nulls_last: bool,
+    },
+    Filter {
+        expr: Box<ExprV1>,
+        by: Box<ExprV1>,
+    },
+    Over {
+        expr: Box<ExprV1>,
+        partition_by: Vec<ExprV1>,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+const OPERATORS: [(&str, Operator); 20] = [
+    ("eq", Operator::Eq),
+    ("eq_missing", Operator::EqValidity),
+    ("neq", Operator::NotEq),
+    ("neq_missing", Operator::NotEqValidity),
+    ("lt", Operator::Lt),
+    ("lt_eq", Operator::LtEq),
+    ("gt", Operator::Gt),
+    ("gt_eq", Operator::GtEq),
+    ("add", Operator::Plus),
+    ("sub", Operator::Minus),
+    ("mul", Operator::Multiply),
+    ("div", Operator::Divide),
+    ("true_div", Operator::TrueDivide),
+    ("floor_div", Operator::FloorDivide),
+    ("mod", Operator::Modulus),
+    ("and", Operator::And),
+    ("or", Operator::Or),
+    ("xor", Operator::Xor),
+    ("logical_and", Operator::LogicalAnd),
+    ("logical_or", Operator::LogicalOr),
+];
+
+fn unsupported<T>(what: impl std::fmt::Debug) -> PolarsResult<T> {
+    polars_bail!(InvalidOperation: "{:?} has no representation in the JSON format of expressions", what)
+}
+
+fn dtype_name(dtype: &DataType) -> PolarsResult<String> {
+    let name = match dtype {
+        DataType::Boolean => "boolean",
+        DataType::UInt8 => "uint8",
+        DataType::UInt16 => "uint16",
+        DataType::UInt32 => "uint32",
+        DataType::UInt64 => "uint64",
+        DataType::Int8 => "int8",
+        DataType::Int16 => "int16",
+        DataType::Int32 => "int32",
+        DataType::Int64 => "int64",
+        DataType::Float32 => "float32",
+        DataType::Float64 => "float64",
+        DataType::String => "string",
+        DataType::Binary => "binary",
+        DataType::Null => "null",
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => "date",
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => "time",
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, None) => return Ok(format!("datetime[{}]", time_unit_name(*tu))),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => return Ok(format!("duration[{}]", time_unit_name(*tu))),
+        dt => return unsupported(dt),
+    };
+    Ok(name.to_string())
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn time_unit_name(tu: TimeUnit) -> &'static str {
+    match tu {
+        TimeUnit::Milliseconds => "ms",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Nanoseconds => "ns",
+    }
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn parse_time_unit(name: &str, unit: &str) -> PolarsResult<TimeUnit> {
+    Ok(match unit {
+        "ms" => TimeUnit::Milliseconds,
+        "us" => TimeUnit::Microseconds,
+        "ns" => TimeUnit::Nanoseconds,
+        _ => polars_bail!(ComputeError: "unknown dtype '{}'", name),
+    })
+}
+
+fn parse_dtype(name: &str) -> PolarsResult<DataType> {
+    Ok(match name {
+        "boolean" => DataType::Boolean,
+        "uint8" => DataType::UInt8,
+        "uint16" => DataType::UInt16,
+        "uint32" => DataType::UInt32,
+        "uint64" => DataType::UInt64,
+        "int8" => DataType::Int8,
+        "int16" => DataType::Int16,
+        "int32" => DataType::Int32,
+        "int64" => DataType::Int64,
+        "float32" => DataType::Float32,
+        "float64" => DataType::Float64,
+        "string" => DataType::String,
+        "binary" => DataType::Binary,
+        "null" => DataType::Null,
+        #[cfg(feature = "dtype-date")]
+        "date" => DataType::Date,
+        #[cfg(feature = "dtype-time")]
+        "time" => DataType::Time,
+        #[cfg(feature = "dtype-datetime")]
+        _ if name.starts_with("datetime[") && name.ends_with(']') => {
+            DataType::Datetime(parse_time_unit(name, &name[9..name.len() - 1])?, None)
+        },
+        #[cfg(feature = "dtype-duration")]
+        _ if name.starts_with("duration[") && name.ends_with(']') => {
+            DataType::Duration(parse_time_unit(name, &name[9..name.len() - 1])?)
+        },
+        _ => polars_bail!(ComputeError: "unknown dtype '{}'", name),
+    })
+}
+
+fn literal_to_v1(value: &LiteralValue) -> PolarsResult<ExprV1> {
+    use serde_json::Value;
+
+    let float = |v: f64| match serde_json::Number::from_f64(v) {
+        Some(v) => Ok(Value::Number(v)),
+        None => unsupported(value),
+    };
+    let (value, dtype) = match value {
+        LiteralValue::Null => (Value::Null, None),
+        LiteralValue::Boolean(v) => (Value::Bool(*v), None),
+        LiteralValue::String(v) => (Value::String(v.to_string()), None),
+        LiteralValue::Int(v) => match i64::try_from(*v) {
+            Ok(v) => (v.into(), None),
+            Err(_) => return unsupported(value),
+        },
+        LiteralValue::Float(v) => (float(*v)?, None),
+        #[cfg(feature = "dtype-u8")]
+        LiteralValue::UInt8(v) => ((*v).into(), Some(DataType::UInt8)),
+        #[cfg(feature = "dtype-u16")]
+        LiteralValue::UInt16(v) => ((*v).into(), Some(DataType::UInt16)),
+        LiteralValue::UInt32(v) => ((*v).into(), Some(DataType::UInt32)),
+        LiteralValue::UInt64(v) => ((*v).into(), Some(DataType::UInt64)),
+        #[cfg(feature = "dtype-i8")]
+        LiteralValue::Int8(v) => ((*v).into(), Some(DataType::Int8)),
+        #[cfg(feature = "dtype-i16")]
+        LiteralValue::Int16(v) => ((*v).into(), Some(DataType::Int16)),
+        LiteralValue::Int32(v) => ((*v).into(), Some(DataType::Int32)),
+        LiteralValue::Int64(v) => ((*v).into(), Some(DataType::Int64)),
+        LiteralValue::Float32(v) => (float(*v as f64)?, Some(DataType::Float32)),
+        LiteralValue::Float64(v) => (float(*v)?, Some(DataType::Float64)),
+        #[cfg(feature = "dtype-date")]
+        LiteralValue::Date(v) => (
+            Value::String(arrow::temporal_conversions::date32_to_date(*v).to_string()),
+            Some(DataType::Date),
+        ),
+        _ => return unsupported(value),
+    };
+    Ok(ExprV1::Literal {
+        value,
+        dtype: dtype.as_ref().map(dtype_name).transpose()?,
+    })
+}
+
+fn literal_from_v1(value: serde_json::Value, dtype: Option<String>) -> PolarsResult<Expr> {
+    use serde_json::Value;
+
+    let invalid = |value: &Value, dtype: &Option<String>| {
+        polars_err!(
+            ComputeError: "invalid literal {} of dtype {}", value, dtype.as_deref().unwrap_or("<none>")
+        )
+    };
+    let Some(dtype_name) = &dtype else {
+        return Ok(match &value {
+            Value::Null => lit(NULL),
+            Value::Bool(v) => lit(*v),
+            Value::String(v) => lit(v.as_str()),
+            Value::Number(v) => match v.as_i64() {
+                Some(v) => Expr::Literal(LiteralValue::Int(v as i128)),
+                None => Expr::Literal(LiteralValue::Float(v.as_f64().unwrap())),
+            },
+            _ => return Err(invalid(&value, &dtype)),
+        });
+    };
+    let int = |value: &Value| value.as_i64().ok_or_else(|| invalid(value, &dtype));
+    let uint = |value: &Value| value.as_u64().ok_or_else(|| invalid(value, &dtype));
+    let float = |value: &Value| value.as_f64().ok_or_else(|| invalid(value, &dtype));
+    macro_rules! narrow {
+        ($v:expr) => {
+            $v.try_into().map_err(|_| invalid(&value, &dtype))?
+        };
+    }
+    let lv = match parse_dtype(dtype_name)? {
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => LiteralValue::UInt8(narrow!(uint(&value)?)),
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => LiteralValue::UInt16(narrow!(uint(&value)?)),
+        DataType::UInt32 => LiteralValue::UInt32(narrow!(uint(&value)?)),
+        DataType::UInt64 => LiteralValue::UInt64(uint(&value)?),
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => LiteralValue::Int8(narrow!(int(&value)?)),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => LiteralValue::Int16(narrow!(int(&value)?)),
+        DataType::Int32 => LiteralValue::Int32(narrow!(int(&value)?)),
+        DataType::Int64 => LiteralValue::Int64(int(&value)?),
+        DataType::Float32 => LiteralValue::Float32(float(&value)? as f32),
+        DataType::Float64 => LiteralValue::Float64(float(&value)?),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            let date = value
+                .as_str()
+                .and_then(|v| chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d").ok())
+                .ok_or_else(|| invalid(&value, &dtype))?;
+            let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            LiteralValue::Date(narrow!((date - epoch).num_days()))
+        },
+        _ => polars_bail!(ComputeError: "literals of dtype {} are not supported", dtype_name),
+    };
+    Ok(Expr::Literal(lv))
+}
+
+fn boxed(expr: &Expr) -> PolarsResult<Box<ExprV1>> {
+    Ok(Box::new(expr_to_v1(expr)?))
+}
+
+fn expr_to_v1(expr: &Expr) -> PolarsResult<ExprV1> {
+    Ok(match expr {
+        Expr::Column(name) => ExprV1::Column {
+            name: name.to_string(),
+        },
+        Expr::Wildcard => ExprV1::All {},
+        Expr::Len => ExprV1::Len {},
+        Expr::Literal(value) => literal_to_v1(value)?,
+        Expr::Alias(expr, name) => ExprV1::Alias {
+            expr: boxed(expr)?,
+            name: name.to_string(),
+        },
+        Expr::BinaryExpr { left, op, right } => ExprV1::Binary {
+            op: OPERATORS
+                .iter()
+                .find(|(_, o)| o == op)
+                .map(|(name, _)| name.to_string())
+                .unwrap(),
+            left: boxed(left)?,
+            right: boxed(right)?,
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+            ..
+        } if input.len() == 1 => {
+            let expr = boxed(&input[0])?;
+            match function {
+                BooleanFunction::Not => ExprV1::Not { expr },
+                BooleanFunction::IsNull => ExprV1::IsNull { expr },
+                BooleanFunction::IsNotNull => ExprV1::IsNotNull { expr },
+                _ => return unsupported(function),
+            }
+        },
+        Expr::Cast {
+            expr,
+            dtype,
+            options,
+        } => ExprV1::Cast {
+            expr: boxed(expr)?,
+            dtype: dtype_name(dtype)?,
+            strict: match options {
+                CastOptions::Strict => true,
+                CastOptions::NonStrict => false,
+                CastOptions::Overflowing => return unsupported(options),
+            },
+        },
+        Expr::Agg(agg) => {
+            let (op, ddof) = match agg {
+                AggExpr::Min {
+                    propagate_nans: false,
+                    ..
+                } => ("min", None),
+                AggExpr::Max {
+                    propagate_nans: false,
+                    ..
+                } => ("max", None),
+                AggExpr::Sum(_) => ("sum", None),
+                AggExpr::Mean(_) => ("mean", None),
+                AggExpr::Median(_) => ("median", None),
+                AggExpr::First(_) => ("first", None),
+                AggExpr::Last(_) => ("last", None),
+                AggExpr::NUnique(_) => ("n_unique", None),
+                AggExpr::Count(_, false) => ("count", None),
+                AggExpr::Count(_, true) => ("len", None),
+                AggExpr::Std(_, ddof) => ("std", Some(*ddof)),
+                AggExpr::Var(_, ddof) => ("var", Some(*ddof)),
+                _ => return unsupported(expr),
+            };
+            ExprV1::Agg {
+                op: op.to_string(),
+                expr: boxed(agg.as_ref())?,
+                ddof,
+            }
+        },
+        Expr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => ExprV1::When {
+            when: boxed(predicate)?,
+            then: boxed(truthy)?,
+            otherwise: boxed(falsy)?,
+        },
+        Expr::Sort { expr, options } => ExprV1::Sort {
+            expr: boxed(expr)?,
+            descending: options.descending,
+            nulls_last: options.nulls_last,
+        },
+        Expr::Filter { input, by } => ExprV1::Filter {
+            expr: boxed(input)?,
+            by: boxed(by)?,
+        },
+        Expr::Window {
+            function,
+            partition_by,
+            order_by: None,
+            options: WindowType::Over(WindowMapping::GroupsToRows),
+        } => ExprV1::Over {
+            expr: boxed(function)?,
+            partition_by: partition_by
+                .iter()
+                .map(expr_to_v1)
+                .collect::<PolarsResult<_>>()?,
+        },
+        _ => return unsupported(expr),
+    })
+}
+
+fn expr_from_v1(expr: ExprV1) -> PolarsResult<Expr> {
+    let arc = |expr: Box<ExprV1>| expr_from_v1(*expr).map(Arc::new);
+    Ok(match expr {
+        ExprV1::Column { name } => col(name),
+        ExprV1::All {} => Expr::Wildcard,
+        ExprV1::Len {} => Expr::Len,
+        ExprV1::Literal { value, dtype } => literal_from_v1(value, dtype)?,
+        ExprV1::Alias { expr, name } => expr_from_v1(*expr)?.alias(name),
+        ExprV1::Binary { op, left, right } => {
+            let Some((_, op)) = OPERATORS.iter().find(|(name, _)| *name == op) else {
+                polars_bail!(ComputeError: "unknown operator '{}'", op)
+            };
+            binary_expr(expr_from_v1(*left)?, *op, expr_from_v1(*right)?)
+        },
+        ExprV1::Not { expr } => expr_from_v1(*expr)?.not(),
+        ExprV1::IsNull { expr } => expr_from_v1(*expr)?.is_null(),
+        ExprV1::IsNotNull { expr } => expr_from_v1(*expr)?.is_not_null(),
+        ExprV1::Cast {
+            expr,
+            dtype,
+            strict,
+        } => {
+            let expr = expr_from_v1(*expr)?;
+            let dtype = parse_dtype(&dtype)?;
+            if strict {
+                expr.strict_cast(dtype)
+            } else {
+                expr.cast(dtype)
+            }
+        },
+        ExprV1::Agg { op, expr, ddof } => {
+            let input = arc(expr)?;
+            polars_ensure!(
+                ddof.is_none() || op == "std" || op == "var",
+                ComputeError: "'ddof' is only allowed for 'std' and 'var'"
+            );
+            let agg = match op.as_str() {
+                "min" => AggExpr::Min {
+                    input,
+                    propagate_nans: false,
+                },
+                "max" => AggExpr::Max {
+                    input,
+                    propagate_nans: false,
+                },
+                "sum" => AggExpr::Sum(input),
+                "mean" => AggExpr::Mean(input),
+                "median" => AggExpr::Median(input),
+                "first" => AggExpr::First(input),
+                "last" => AggExpr::Last(input),
+                "n_unique" => AggExpr::NUnique(input),
+                "count" => AggExpr::Count(input, false),
+                "len" => AggExpr::Count(input, true),
+                "std" => AggExpr::Std(input, ddof.unwrap_or(1)),
+                "var" => AggExpr::Var(input, ddof.unwrap_or(1)),
+                _ => polars_bail!(ComputeError: "unknown aggregation '{}'", op),
+            };
+            Expr::Agg(agg)
+        },
+        ExprV1::When {
+            when,
+            then,
+            otherwise,
+        } => Expr::Ternary {
+            predicate: arc(when)?,
+            truthy: arc(then)?,
+            falsy: arc(otherwise)?,
+        },
+        ExprV1::Sort {
+            expr,
+            descending,
+            nulls_last,
+        } => expr_from_v1(*expr)?.sort(
+            SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(nulls_last),
+        ),
+        ExprV1::Filter { expr, by } => expr_from_v1(*expr)?.filter(expr_from_v1(*by)?),
+        ExprV1::Over { expr, partition_by } => {
+            polars_ensure!(
+                !partition_by.is_empty(),
+                ComputeError: "'over' requires at least one partition expression"
+            );
+            let partition_by = partition_by
+                .into_iter()
+                .map(expr_from_v1)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            expr_from_v1(*expr)?.over(partition_by)
+        },
+    })
+}
+
+impl Expr {
+    /// Serialize the expression to the format of [`Expr::from_json`].
+    ///
+    /// Fails for expressions that can't be represented in it, e.g. user defined functions.
+    pub fn to_json(&self) -> PolarsResult<String> {
+        let document = ExprDocumentV1 {
+            version: Some(FORMAT_VERSION),
+            expr: expr_to_v1(self)?,
+        };
+        serde_json::to_string(&document).map_err(to_compute_err)
+    }
+
+    /// Deserialize an expression from a JSON document in the stable format, e.g.
+    ///
+    /// ```json
+    /// {
+    ///     "version": 1,
+    ///     "expr": {
+    ///         "type": "alias",
+    ///         "name": "total",
+    ///         "expr": {
+    ///             "type": "agg",
+    ///             "op": "sum",
+    ///             "expr": {
+    ///                 "type": "binary",
+    ///                 "op": "mul",
+    ///                 "left": {"type": "column", "name": "price"},
+    ///                 "right": {"type": "literal", "value": 2, "dtype": "int32"}
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// `version` is required and must be `1`. Every expression is an object with a `type`:
+    ///
+    /// - `column` (`name`), `all` and `len`.
+    /// - `literal`: a JSON `value` and an optional `dtype`. Without dtype, numbers are dynamically
+    ///   typed like the literals of the python API. Dates are `YYYY-MM-DD` strings.
+    /// - `alias` (`expr`, `name`), `not`, `is_null` and `is_not_null` (`expr`).
+    /// - `binary`: `left` and `right` with an `op`, one of `eq`, `eq_missing`, `neq`,
+    ///   `neq_missing`, `lt`, `lt_eq`, `gt`, `gt_eq`, `add`, `sub`, `mul`, `div`, `true_div`,
+    ///   `floor_div`, `mod`, `and`, `or`, `xor`, `logical_and` and `logical_or`.
+    /// - `cast`: `expr` to `dtype`, with `strict` defaulting to `true`.
+    /// - `agg`: `expr` aggregated with an `op`, one of `min`, `max`, `sum`, `mean`, `median`,
+    ///   `first`, `last`, `n_unique`, `count`, `len`, `std` and `var`, the latter two with an
+    ///   optional `ddof` (default `1`).
+    /// - `when`: `then` where `when` is true, `otherwise` elsewhere.
+    /// - `sort` (`expr`, optional `descending` and `nulls_last`), `filter` (`expr`, `by`) and
+    ///   `over` (`expr`, `partition_by`).
+    ///
+    /// The dtypes are `boolean`, `int8` to `int64`, `uint8` to `uint64`, `float32`, `float64`,
+    /// `string`, `binary`, `null`, `date`, `time`, `datetime[<unit>]` and `duration[<unit>]`,
+    /// with a unit of `ms`, `us` or `ns`.
+    ///
+    /// Unknown fields are rejected, new fields are only added with a new version.
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        let document: ExprDocumentV1 = serde_json::from_str(json).map_err(to_compute_err)?;
+        polars_ensure!(
+            document.version == Some(FORMAT_VERSION),
+            ComputeError: "unsupported expression version: {:?}, expected {}",
+            document.version, FORMAT_VERSION
+        );
+        expr_from_v1(document.expr)
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+
+/// How returns compound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ReturnsMode {
+    /// Simple returns, `price / previous - 1`, which compound by multiplying `1 + return`.
+    #[default]
+    Linear,
+    /// Log returns, `ln(price / previous)`, which compound by adding them.
+    Log,
+}
+
+/// Expressions on market data, such as turning trades into price bars and analyzing returns.
+///
+/// They expect the rows, or those of each group, to be in time order. The aggregations are meant
+/// for contexts such as `group_by_dynamic` over the time of the trades, and the other
+/// expressions can be applied per group with `over`.
+pub struct MarketNameSpace(pub(crate) Expr);
+
+impl MarketNameSpace {
+    /// Get the open, high, low and close price as a struct with the fields `open`, `high`,
+    /// `low` and `close`. Null prices are ignored.
+    ///
+    /// ```rust,no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_plan::prelude::*;
+    /// fn example(trades: LazyFrame) -> LazyFrame {
+    ///     trades
+    ///         .group_by_dynamic(col("time"), [], DynamicGroupOptions::default())
+    ///         .agg([col("price").market().ohlc()])
+    /// }
+    /// ```
+    pub fn ohlc(self) -> Expr {
+        let price = self.0;
+        as_struct(vec![
+            price.clone().drop_nulls().first().alias("open"),
+            price.clone().max().alias("high"),
+            price.clone().min().alias("low"),
+            price.drop_nulls().last().alias("close"),
+        ])
+        .name()
+        .keep()
+    }
+
+    /// Get the volume weighted average price, `sum(price * volume) / sum(volume)`. Trades with
+    /// a null price or volume are ignored.
+    pub fn vwap(self, volume: Expr) -> Expr {
+        let price = self.0.cast(DataType::Float64);
+        let volume = when(price.clone().is_not_null())
+            .then(volume.cast(DataType::Float64))
+            .otherwise(lit(Null {}));
+        (price * volume.clone()).sum() / volume.sum()
+    }
+
+    /// Get the time weighted average price, in which every price is weighted by the time until
+    /// the next trade at `time`. A null price is replaced by the price before it, and if all
+    /// trades are at the same time the last price is returned.
+    pub fn twap(self, time: Expr) -> Expr {
+        let price = self.0.cast(DataType::Float64);
+        let time = time.cast(DataType::Int64);
+        let previous = price.clone().forward_fill(None).shift(lit(1));
+        let weight = when(previous.clone().is_not_null())
+            .then((time.clone() - time.shift(lit(1))).cast(DataType::Float64))
+            .otherwise(lit(Null {}));
+        let total = weight.clone().sum();
+        when(total.clone().gt(lit(0.0)))
+            .then((previous * weight).sum() / total)
+            .otherwise(price.drop_nulls().last())
+    }
+
+    /// Get the cumulative return of the returns of every period, such that the return up to a
+    /// row is `(1 + r_1) * ... * (1 + r_n) - 1` for linear returns and `exp(r_1 + ... + r_n) - 1`
+    /// for log returns. Null returns are skipped and stay null.
+    pub fn cum_return(self, mode: ReturnsMode) -> Expr {
+        let returns = self.0.cast(DataType::Float64);
+        let growth = match mode {
+            ReturnsMode::Linear => (lit(1.0) + returns).cum_prod(false),
+            ReturnsMode::Log => returns.cum_sum(false).exp(),
+        };
+        growth - lit(1.0)
+    }
+
+    /// Get the drawdown of a price or equity curve, the relative loss since its running maximum,
+    /// which is zero at every new high and negative otherwise.
+    pub fn drawdown(self) -> Expr {
+        let price = self.0.cast(DataType::Float64);
+        price.clone() / price.cum_max(false) - lit(1.0)
+    }
+
+    /// Get the maximum drawdown of a price or equity curve, the largest relative loss from a
+    /// high to a later low, as a negative fraction.
+    pub fn max_drawdown(self) -> Expr {
+        self.drawdown().min()
+    }
+
+    /// Get the Sharpe ratio of the returns in a rolling window, the mean divided by the standard
+    /// deviation of the returns and annualized with the square root of `periods_per_year`.
+    ///
+    /// The returns should be in excess of the risk-free rate, if any.
+    pub fn rolling_sharpe(self, options: RollingOptionsFixedWindow, periods_per_year: f64) -> Expr {
+        let returns = self.0.cast(DataType::Float64);
+        returns.clone().rolling_mean(options.clone()) / returns.rolling_std(options)
+            * lit(periods_per_year.sqrt())
+    }
+
+    /// Get the Sortino ratio of the returns in a rolling window, like
+    /// [`rolling_sharpe`](Self::rolling_sharpe) but divided by the downside deviation, the root
+    /// mean square of the negative returns, where positive returns count as zero.
+    pub fn rolling_sortino(
+        self,
+        options: RollingOptionsFixedWindow,
+        periods_per_year: f64,
+    ) -> Expr {
+        let returns = self.0.cast(DataType::Float64);
+        let downside = when(returns.clone().gt(lit(0.0)))
+            .then(lit(0.0))
+            .otherwise(returns.clone());
+        let downside_deviation = (downside.clone() * downside)
+            .rolling_mean(options.clone())
+            .sqrt();
+        returns.rolling_mean(options) / downside_deviation * lit(periods_per_year.sqrt())
+    }
+}
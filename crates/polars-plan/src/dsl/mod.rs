@@ -23,7 +23,11 @@ mod expr_dyn_fn;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "serde")]
+mod json;
 mod list;
+#[cfg(feature = "market")]
+mod market;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -52,11 +56,13 @@ pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "market")]
+pub use market::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
 pub use options::*;
-use polars_core::chunked_array::cast::CastOptions;
+use polars_core::chunked_array::cast::{CastArgs, CastOptions};
 use polars_core::error::feature_gated;
 use polars_core::prelude::*;
 #[cfg(feature = "diff")]
@@ -417,6 +423,17 @@ impl Expr {
         }
     }
 
+    /// Cast expression to another data type, with control over overflow, rounding and the
+    /// parsing of numbers, see [`Series::cast_with_args`].
+    pub fn cast_with_args(self, dtype: DataType, args: CastArgs) -> Self {
+        let output_type = GetOutput::from_type(dtype.clone());
+        self.map(
+            move |s| s.cast_with_args(&dtype, &args).map(Some),
+            output_type,
+        )
+        .with_fmt("cast_with_args")
+    }
+
     /// Take the values by idx.
     pub fn gather<E: Into<Expr>>(self, idx: E) -> Self {
         Expr::Gather {
@@ -479,7 +496,15 @@ impl Expr {
         self.apply_many_private(FunctionExpr::TopK { descending: false }, &[k], false, false)
     }
 
-    /// Returns the `k` largest rows by given column.
+    /// Returns the `k` largest rows by given columns, in order.
+    ///
+    /// The rows are compared by the first column of `by`, then by the next one for ties and so
+    /// on. `descending` reverses the order of each column, or of all of them if it has a single
+    /// value. The rows are selected in one pass over the encoded columns, without sorting all of
+    /// them.
+    ///
+    /// In a window, the result has `k` rows per group, so it needs
+    /// [`WindowMapping::Explode`], e.g. for the leaderboard of each group.
     ///
     /// For single column, use [`Expr::top_k`].
     #[cfg(feature = "top_k")]
@@ -502,9 +527,9 @@ impl Expr {
         self.apply_many_private(FunctionExpr::TopK { descending: true }, &[k], false, false)
     }
 
-    /// Returns the `k` smallest rows by given column.
+    /// Returns the `k` smallest rows by given columns, in order.
     ///
-    /// For single column, use [`Expr::bottom_k`].
+    /// See [`Expr::top_k_by`]. For single column, use [`Expr::bottom_k`].
     // #[cfg(feature = "top_k")]
     #[cfg(feature = "top_k")]
     pub fn bottom_k_by<K: Into<Expr>, E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
@@ -1906,6 +1931,12 @@ impl Expr {
         dt::DateLikeNameSpace(self)
     }
 
+    /// Get the [`market::MarketNameSpace`]
+    #[cfg(feature = "market")]
+    pub fn market(self) -> market::MarketNameSpace {
+        market::MarketNameSpace(self)
+    }
+
     /// Get the [`list::ListNameSpace`]
     pub fn list(self) -> list::ListNameSpace {
         list::ListNameSpace(self)
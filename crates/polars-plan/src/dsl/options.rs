@@ -1,4 +1,10 @@
+#[cfg(feature = "temporal")]
+use polars_core::prelude::DateLocale;
+#[cfg(feature = "dtype-duration")]
+use polars_core::prelude::TimeUnit;
 use polars_ops::prelude::{JoinArgs, JoinType};
+#[cfg(feature = "dtype-duration")]
+use polars_time::prelude::ClockAmbiguity;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
 use polars_utils::pl_str::PlSmallStr;
@@ -28,6 +34,9 @@ pub struct StrptimeOptions {
     pub exact: bool,
     /// use a cache of unique, converted dates to apply the datetime conversion.
     pub cache: bool,
+    /// The language of the month and weekday names in the values.
+    #[cfg(feature = "temporal")]
+    pub locale: DateLocale,
 }
 
 impl Default for StrptimeOptions {
@@ -37,10 +46,57 @@ impl Default for StrptimeOptions {
             strict: true,
             exact: true,
             cache: true,
+            #[cfg(feature = "temporal")]
+            locale: DateLocale::default(),
         }
     }
 }
 
+#[cfg(feature = "dtype-duration")]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DurationParseOptions {
+    /// Time unit of the parsed durations
+    pub time_unit: TimeUnit,
+    /// How to read clock durations with two components, e.g. "02:30"
+    pub ambiguous: ClockAmbiguity,
+    /// If set then polars will return an error if any duration parsing fails
+    pub strict: bool,
+}
+
+#[cfg(feature = "dtype-duration")]
+impl Default for DurationParseOptions {
+    fn default() -> Self {
+        DurationParseOptions {
+            time_unit: TimeUnit::Microseconds,
+            ambiguous: ClockAmbiguity::default(),
+            strict: true,
+        }
+    }
+}
+
+/// Options of [`StructNameSpace::json_encode`](crate::dsl::StructNameSpace::json_encode).
+///
+/// The defaults write the fields in schema order, nulls as `null`, dates as `%Y-%m-%d` and
+/// decimals as strings.
+#[cfg(feature = "json")]
+#[derive(Clone, PartialEq, Debug, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonEncodeOptions {
+    /// Write the fields of structs, including nested ones, ordered by name, so that the output
+    /// doesn't depend on the order of the fields in the schema.
+    pub sort_fields: bool,
+    /// Leave out the fields whose value is null instead of writing `null`.
+    pub strip_nulls: bool,
+    /// The chrono format string of dates.
+    pub date_format: Option<PlSmallStr>,
+    /// Write decimals as JSON numbers instead of strings.
+    pub decimal_as_number: bool,
+    /// Whether trailing zeros of decimals are removed. Follows the global setting if not set,
+    /// set it for output that doesn't depend on the configuration.
+    pub decimal_trim_zeros: Option<bool>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JoinOptions {
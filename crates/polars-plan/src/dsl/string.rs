@@ -319,6 +319,16 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column into a Duration column.
+    ///
+    /// Accepts humanized durations such as "1h 30m", ISO 8601 durations such as "PT2H" and
+    /// clock durations such as "02:30:00".
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, options: DurationParseOptions) -> Expr {
+        self.0
+            .map_private(StringFunction::ToDuration(options).into())
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -69,10 +69,13 @@ impl StructNameSpace {
             )))
     }
 
+    /// Serialize every struct to a JSON string, formatted according to `options`.
     #[cfg(feature = "json")]
-    pub fn json_encode(self) -> Expr {
+    pub fn json_encode(self, options: JsonEncodeOptions) -> Expr {
         self.0
-            .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
+            .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode(
+                options,
+            )))
     }
 
     pub fn with_fields(self, fields: Vec<Expr>) -> PolarsResult<Expr> {
@@ -33,6 +33,17 @@ bitflags! {
         const ROW_ESTIMATE = 1 << 13;
         /// Replace simple projections with a faster inlined projection that skips the expression engine.
         const FAST_PROJECTION = 1 << 14;
+        /// Rewrite the filters, projections, aggregations, sorts and slices on top of a database
+        /// scan into the query that is sent to the database.
+        const DATABASE_PUSHDOWN = 1 << 15;
+        /// Replace NaN with null in the float columns of every scan, see [`NanPolicy`].
+        const NAN_TO_NULL = 1 << 16;
+        /// NaN doesn't equal NaN in join keys, group keys and `unique`.
+        const NANS_UNEQUAL = 1 << 17;
+        /// Sort NaN before every other value.
+        const NANS_FIRST = 1 << 18;
+        /// Sort NaN after every other value.
+        const NANS_LAST = 1 << 19;
     }
 }
 
@@ -47,6 +58,78 @@ impl Default for OptFlags {
         Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::STREAMING & !Self::EAGER
             // will be toggled by a scan operation such as csv scan or parquet scan
             & !Self::FILE_CACHING
+            // NaN semantics are not optimizations, they are set with a `NanPolicy`.
+            & !Self::nan_policy_flags()
+    }
+}
+
+/// Where NaN sorts relative to the other values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NanOrder {
+    /// NaN sorts as the greatest value, so first when descending and last when ascending.
+    #[default]
+    Greatest,
+    /// NaN sorts before every other value, regardless of the sort direction.
+    First,
+    /// NaN sorts after every other value, regardless of the sort direction.
+    Last,
+}
+
+/// How a query treats floating point NaN.
+///
+/// The default keeps the semantics of the kernels: NaN equals NaN and sorts as the greatest value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NanPolicy {
+    /// Replace NaN with null in the float columns of every scan.
+    pub nan_to_null: bool,
+    /// Whether NaN equals NaN in join keys, group keys and `unique`. If `false`, every row
+    /// with a NaN key is a group of its own and doesn't join with any other row. Joins then
+    /// compare NaN keys as null, so they can't be combined with `join_nulls`.
+    pub nans_equal: bool,
+    /// Where NaN sorts.
+    pub nan_order: NanOrder,
+}
+
+impl Default for NanPolicy {
+    fn default() -> Self {
+        Self {
+            nan_to_null: false,
+            nans_equal: true,
+            nan_order: NanOrder::Greatest,
+        }
+    }
+}
+
+impl OptFlags {
+    /// The flags that store the [`NanPolicy`] of a query.
+    pub fn nan_policy_flags() -> Self {
+        Self::NAN_TO_NULL | Self::NANS_UNEQUAL | Self::NANS_FIRST | Self::NANS_LAST
+    }
+
+    /// Replace the [`NanPolicy`] stored in these flags.
+    pub fn set_nan_policy(&mut self, policy: NanPolicy) {
+        self.remove(Self::nan_policy_flags());
+        self.set(Self::NAN_TO_NULL, policy.nan_to_null);
+        self.set(Self::NANS_UNEQUAL, !policy.nans_equal);
+        self.set(Self::NANS_FIRST, policy.nan_order == NanOrder::First);
+        self.set(Self::NANS_LAST, policy.nan_order == NanOrder::Last);
+    }
+}
+
+impl From<OptFlags> for NanPolicy {
+    fn from(flags: OptFlags) -> Self {
+        let nan_order = if flags.contains(OptFlags::NANS_FIRST) {
+            NanOrder::First
+        } else if flags.contains(OptFlags::NANS_LAST) {
+            NanOrder::Last
+        } else {
+            NanOrder::Greatest
+        };
+        Self {
+            nan_to_null: flags.contains(OptFlags::NAN_TO_NULL),
+            nans_equal: !flags.contains(OptFlags::NANS_UNEQUAL),
+            nan_order,
+        }
     }
 }
 
@@ -14,6 +14,9 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+/// The batches of a pass over an [`AnonymousScan`], see [`AnonymousScan::batches`].
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
@@ -25,6 +28,17 @@ pub trait AnonymousScan: Send + Sync {
         self.scan(scan_opts).map(Some)
     }
 
+    /// Start a pass over the batches of the scan. Every streaming source of the scan starts its
+    /// own pass, which is dropped when the source stops, so scans whose batches depend on the
+    /// previous ones don't need to keep that state themselves. `scan_opts.n_rows` applies to the
+    /// whole pass.
+    ///
+    /// Defaults to `None`, in which case the batches are produced with
+    /// [`AnonymousScan::next_batch`].
+    fn batches(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<Option<AnonymousScanBatches>> {
+        Ok(None)
+    }
+
     /// function to supply the schema.
     /// Allows for an optional infer schema argument for data sources with dynamic schemas
     fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
@@ -48,6 +62,19 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if [`AnonymousScan::batches`] or [`AnonymousScan::next_batch`] produce the data
+    /// in batches until they return `None`, so that the streaming engine can consume the scan
+    /// incrementally.
+    ///
+    /// Defaults to `false`
+    fn is_streamable(&self) -> bool {
+        false
+    }
+    /// Details of the scan that are shown in the explain of the plan, e.g. the query of a
+    /// database scan.
+    fn explain(&self) -> Option<String> {
+        None
+    }
 }
 
 impl Debug for dyn AnonymousScan {
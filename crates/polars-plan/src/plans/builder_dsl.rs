@@ -2,6 +2,7 @@
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex, RwLock};
 
+use either::Either;
 use polars_core::prelude::*;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
@@ -42,7 +43,11 @@ impl DslBuilder {
             None => function.schema(infer_schema_length)?,
         };
 
-        let file_info = FileInfo::new(schema.clone(), None, (n_rows, n_rows.unwrap_or(usize::MAX)));
+        let file_info = FileInfo::new(
+            schema.clone(),
+            Some(Either::Right(schema.clone())),
+            (n_rows, n_rows.unwrap_or(usize::MAX)),
+        );
         let file_options = FileScanOptions {
             slice: n_rows.map(|x| (0, x)),
             with_columns: None,
@@ -88,6 +93,10 @@ impl DslBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        use_metadata_summary: bool,
+        prefetch: polars_io::parquet::read::PrefetchOptions,
+        large_row_group_bytes: Option<usize>,
+        dictionary_as_categorical: bool,
         hive_options: HiveOptions,
         glob: bool,
         include_file_paths: Option<PlSmallStr>,
@@ -116,6 +125,10 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    use_metadata_summary,
+                    prefetch,
+                    large_row_group_bytes,
+                    dictionary_as_categorical,
                 },
                 cloud_options,
                 metadata: None,
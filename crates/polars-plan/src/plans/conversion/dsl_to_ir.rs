@@ -129,13 +129,17 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut ConversionContext) -> PolarsResult<No
                 match &mut scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet {
+                        options,
                         cloud_options,
                         metadata,
-                        ..
                     } => {
-                        let (file_info, md) =
-                            scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                        let (file_info, md) = scans::parquet_file_info(
+                            &paths,
+                            &file_options,
+                            options,
+                            cloud_options.as_ref(),
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -822,6 +826,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut ConversionContext) -> PolarsResult<No
                 },
                 _ => {
                     let function = function.into_function_ir(&input_schema)?;
+                    // Resolve the output schema here, so that a failing schema function is an
+                    // error of this node instead of a panic when the schema of the plan is used.
+                    function
+                        .schema(&input_schema)
+                        .map_err(|e| e.context(failed_here!(map_function)))?;
                     IR::MapFunction { input, function }
                 },
             }
@@ -40,6 +40,7 @@ fn prepare_schemas(mut schema: Schema, row_index: Option<&RowIndex>) -> (SchemaR
 pub(super) fn parquet_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
+    options: &ParquetOptions,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetaDataRef>)> {
     let path = get_first_path(paths)?;
@@ -52,7 +53,9 @@ pub(super) fn parquet_file_info(
         {
             let uri = path.to_string_lossy();
             get_runtime().block_on(async {
-                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None).await?;
+                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None)
+                    .await?
+                    .with_dictionary_as_categorical(options.dictionary_as_categorical);
                 let reader_schema = reader.schema().await?;
                 let num_rows = reader.num_rows().await?;
                 let metadata = reader.get_metadata().await?.clone();
@@ -66,7 +69,8 @@ pub(super) fn parquet_file_info(
         }
     } else {
         let file = polars_utils::open_file(path)?;
-        let mut reader = ParquetReader::new(file);
+        let mut reader = ParquetReader::new(file)
+            .with_dictionary_as_categorical(options.dictionary_as_categorical);
         let reader_schema = reader.schema()?;
         let schema = prepare_output_schema(
             Schema::from_arrow_schema(reader_schema.as_ref()),
@@ -339,8 +343,11 @@ pub(super) fn ndjson_file_info(
             )
         }
     } else {
-        let schema =
-            polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
+        let schema = polars_io::ndjson::infer_schema(
+            &mut reader,
+            ndjson_options.infer_schema_length,
+            ndjson_options.union_resolution,
+        )?;
         prepare_schemas(schema, file_options.row_index.as_ref())
     };
 
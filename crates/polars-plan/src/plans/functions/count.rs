@@ -52,6 +52,11 @@ pub fn count_rows(paths: &Arc<Vec<PathBuf>>, scan_type: &FileScan) -> PolarsResu
                             parse_options.quote_char,
                             parse_options.comment_prefix.as_ref(),
                             parse_options.eol_char,
+                            parse_options.escape_char,
+                            parse_options
+                                .multi_byte_separator
+                                .as_deref()
+                                .map(str::as_bytes),
                             options.has_header,
                         )
                     })
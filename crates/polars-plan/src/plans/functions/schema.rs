@@ -29,10 +29,17 @@ impl FunctionIR {
     ) -> PolarsResult<Cow<'a, SchemaRef>> {
         use FunctionIR::*;
         match self {
-            Opaque { schema, .. } => match schema {
+            Opaque {
+                schema, fmt_str, ..
+            } => match schema {
                 None => Ok(Cow::Borrowed(input_schema)),
                 Some(schema_fn) => {
-                    let output_schema = schema_fn.get_schema(input_schema)?;
+                    let output_schema = schema_fn.get_schema(input_schema).map_err(|e| {
+                        e.context(
+                            format!("failed to resolve the schema of map function '{fmt_str}'")
+                                .into(),
+                        )
+                    })?;
                     Ok(Cow::Owned(output_schema))
                 },
             },
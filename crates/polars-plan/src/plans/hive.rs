@@ -172,6 +172,7 @@ pub fn hive_partitions_from_paths(
         None,
         polars_io::prelude::CsvEncoding::Utf8,
         false,
+        &[],
     )?;
 
     for path in paths {
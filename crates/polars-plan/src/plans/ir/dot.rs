@@ -321,6 +321,9 @@ impl<'a> IRDotDisplay<'a> {
                         SinkType::File { .. } => "SINK (FILE)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
+                        SinkType::Partitioned { .. } => "SINK (PARTITIONED)",
+                        SinkType::Multiple(_) => "SINK (MULTIPLE)",
+                        SinkType::Batches(_) => "SINK (BATCHES)",
                     })
                 })?;
             },
@@ -246,7 +246,13 @@ impl<'a> IRDisplay<'a> {
                     &predicate,
                     file_options.slice,
                     file_options.row_index.as_ref(),
-                )
+                )?;
+                if let FileScan::Anonymous { function, .. } = scan_type {
+                    if let Some(details) = function.explain() {
+                        write!(f, "\n{:indent$}{details}", "")?;
+                    }
+                }
+                Ok(())
             },
             Filter { predicate, input } => {
                 let predicate = self.display_expr(predicate);
@@ -370,6 +376,9 @@ impl<'a> IRDisplay<'a> {
                     SinkType::File { .. } => "SINK (file)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
+                    SinkType::Partitioned { .. } => "SINK (partitioned)",
+                    SinkType::Multiple(_) => "SINK (multiple)",
+                    SinkType::Batches(_) => "SINK (batches)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 self.with_root(*input)._format(f, sub_indent)
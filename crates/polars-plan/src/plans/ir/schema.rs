@@ -41,6 +41,9 @@ impl IR {
                 SinkType::File { .. } => "sink (file)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
+                SinkType::Partitioned { .. } => "sink (partitioned)",
+                SinkType::Multiple(_) => "sink (multiple)",
+                SinkType::Batches(_) => "sink (batches)",
             },
             SimpleProjection { .. } => "simple_projection",
             Invalid => "invalid",
@@ -358,6 +358,9 @@ impl<'a> TreeFmtNode<'a> {
                                 SinkType::File { .. } => "SINK (file)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
+                                SinkType::Partitioned { .. } => "SINK (partitioned)",
+                                SinkType::Multiple(_) => "SINK (multiple)",
+                                SinkType::Batches(_) => "SINK (batches)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
@@ -96,12 +96,16 @@ fn visit_logical_plan_for_scan_paths(
         },
         IR::Scan {
             scan_type, paths, ..
-        } if !matches!(scan_type, FileScan::Anonymous { .. }) => Some(CountStarExpr {
-            paths: paths.clone(),
-            scan_type: scan_type.clone(),
-            node,
-            alias: None,
-        }),
+        } if !matches!(scan_type, FileScan::Anonymous { .. })
+            && !skips_malformed_rows(scan_type) =>
+        {
+            Some(CountStarExpr {
+                paths: paths.clone(),
+                scan_type: scan_type.clone(),
+                node,
+                alias: None,
+            })
+        },
         // A union can insert a simple projection to ensure all projections align.
         // We can ignore that if we are inside a count star.
         IR::SimpleProjection { input, .. } if inside_union => {
@@ -125,6 +129,18 @@ fn visit_logical_plan_for_scan_paths(
     }
 }
 
+/// Whether the scan leaves out rows that fail to parse, which counting the rows of the files
+/// doesn't.
+fn skips_malformed_rows(scan_type: &FileScan) -> bool {
+    match scan_type {
+        #[cfg(feature = "csv")]
+        FileScan::Csv { options, .. } => {
+            options.on_error != polars_io::csv::read::CsvOnError::Raise
+        },
+        _ => false,
+    }
+}
+
 fn is_valid_count_expr(e: &ExprIR, expr_arena: &Arena<AExpr>) -> (bool, Option<PlSmallStr>) {
     match expr_arena.get(e.node()) {
         AExpr::Len => (true, e.get_alias().cloned()),
@@ -15,6 +15,7 @@ mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
 mod join_utils;
+mod nan_policy;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -34,7 +35,7 @@ use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
-pub use crate::frame::{AllowedOptimizations, OptFlags};
+pub use crate::frame::{AllowedOptimizations, NanOrder, NanPolicy, OptFlags};
 pub use crate::plans::conversion::type_coercion::TypeCoercionRule;
 use crate::plans::optimizer::count_star::CountStar;
 #[cfg(feature = "cse")]
@@ -80,6 +81,7 @@ pub fn optimize(
         opt_state &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
     }
     let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena, &mut opt_state)?;
+    nan_policy::apply_nan_policy(lp_top, lp_arena, expr_arena, opt_state)?;
 
     // get toggle values
     let cluster_with_columns = opt_state.contains(OptFlags::CLUSTER_WITH_COLUMNS);
@@ -0,0 +1,342 @@
+//! Rewrites the plan to follow the NaN policy of a query, see [`NanPolicy`].
+//!
+//! The kernels treat NaN as a value that is equal to itself and that sorts above every other
+//! number. A different policy is applied by rewriting the nodes that compare or order values
+//! before the other optimizations run, so that they see the rewritten plan.
+use super::*;
+
+/// The row index that tells rows with NaN keys apart.
+const ROW_INDEX: &str = "__POLARS_NAN_ROW_INDEX";
+/// The extra key that puts every row with a NaN key in a group of its own.
+const NAN_KEY: &str = "__POLARS_NAN_KEY";
+
+pub(super) fn apply_nan_policy(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    opt_state: OptFlags,
+) -> PolarsResult<()> {
+    let policy = NanPolicy::from(opt_state);
+    if policy == NanPolicy::default() {
+        return Ok(());
+    }
+
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        // Inputs can be shared through caches.
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = lp_arena.get(node);
+        ir.copy_inputs(&mut stack);
+
+        match ir {
+            IR::Scan { .. } | IR::DataFrameScan { .. } if policy.nan_to_null => {
+                nan_to_null(node, lp_arena, expr_arena)?
+            },
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } if policy.nan_to_null => nan_to_null(node, lp_arena, expr_arena)?,
+            IR::Join { .. } if !policy.nans_equal => unequal_join_keys(node, lp_arena, expr_arena)?,
+            IR::GroupBy { .. } if !policy.nans_equal => {
+                unequal_group_keys(node, lp_arena, expr_arena)?
+            },
+            IR::Distinct { .. } if !policy.nans_equal => {
+                unequal_distinct_rows(node, lp_arena, expr_arena)?
+            },
+            IR::Sort { .. } if policy.nan_order != NanOrder::Greatest => {
+                order_nans(node, policy.nan_order, lp_arena, expr_arena)?
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+/// The float dtype of `e` evaluated on `schema`, if it has one.
+fn float_dtype(e: &ExprIR, schema: &Schema, expr_arena: &Arena<AExpr>) -> Option<DataType> {
+    let dtype = expr_arena
+        .get(e.node())
+        .get_type(schema, Context::Default, expr_arena)
+        .ok()?;
+    dtype.is_float().then_some(dtype)
+}
+
+fn fill_nan_with_null(e: Expr, dtype: DataType) -> Expr {
+    e.fill_nan(lit(NULL).cast(dtype))
+}
+
+/// Replace NaN with null in the float columns of the scan at `node`.
+fn nan_to_null(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+    let exprs = schema
+        .iter()
+        .filter(|(_, dtype)| dtype.is_float())
+        .map(|(name, dtype)| {
+            to_expr_ir(
+                fill_nan_with_null(col(name.clone()), dtype.clone()),
+                expr_arena,
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if exprs.is_empty() {
+        return Ok(());
+    }
+
+    let scan = lp_arena.take(node);
+    let scan = lp_arena.add(scan);
+    let lp = IRBuilder::new(scan, expr_arena, lp_arena)
+        .with_columns(exprs, ProjectionOptions::default())
+        .build();
+    lp_arena.replace(node, lp);
+    Ok(())
+}
+
+/// Make NaN keys never match by joining on null instead.
+fn unequal_join_keys(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        unreachable!()
+    };
+    match options.args.how {
+        JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => {},
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => {},
+        _ => return Ok(()),
+    }
+
+    let schema_left = lp_arena.get(*input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(*input_right).schema(lp_arena);
+    let float_keys = left_on
+        .iter()
+        .zip(right_on)
+        .map(|(l, r)| {
+            float_dtype(l, &schema_left, expr_arena)
+                .or_else(|| float_dtype(r, &schema_right, expr_arena))
+        })
+        .collect::<Vec<_>>();
+    if float_keys.iter().all(Option::is_none) {
+        return Ok(());
+    }
+    polars_ensure!(
+        !options.args.join_nulls,
+        InvalidOperation: "cannot join on float keys with `join_nulls` when NaNs are unequal"
+    );
+
+    let rewrite = |keys: &[ExprIR], expr_arena: &mut Arena<AExpr>| {
+        keys.iter()
+            .zip(&float_keys)
+            .map(|(e, dtype)| match dtype {
+                Some(dtype) => {
+                    let e = fill_nan_with_null(e.to_expr(expr_arena), dtype.clone());
+                    to_expr_ir(e, expr_arena)
+                },
+                None => Ok(e.clone()),
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    };
+    let new_left_on = rewrite(left_on, expr_arena)?;
+    let new_right_on = rewrite(right_on, expr_arena)?;
+
+    let IR::Join {
+        left_on, right_on, ..
+    } = lp_arena.get_mut(node)
+    else {
+        unreachable!()
+    };
+    *left_on = new_left_on;
+    *right_on = new_right_on;
+    Ok(())
+}
+
+/// Add a row index and a column to `input` that is null, unless one of `nan_exprs` is NaN, in
+/// which case it is the index of the row.
+fn with_nan_key(
+    input: Node,
+    nan_exprs: Vec<Expr>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    let condition = nan_exprs
+        .into_iter()
+        .map(Expr::is_nan)
+        .reduce(Expr::or)
+        .unwrap();
+    let nan_key = when(condition)
+        .then(col(ROW_INDEX))
+        .otherwise(lit(NULL).cast(IDX_DTYPE))
+        .alias(NAN_KEY);
+    let nan_key = to_expr_ir(nan_key, expr_arena)?;
+
+    Ok(IRBuilder::new(input, expr_arena, lp_arena)
+        .row_index(ROW_INDEX.into(), None)
+        .with_columns(vec![nan_key], ProjectionOptions::default())
+        .node())
+}
+
+/// Put the rows with NaN keys in groups of their own.
+fn unequal_group_keys(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::GroupBy {
+        input,
+        keys,
+        aggs,
+        apply,
+        maintain_order,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        unreachable!()
+    };
+    #[cfg(feature = "dynamic_group_by")]
+    if options.dynamic.is_some() || options.rolling.is_some() {
+        return Ok(());
+    }
+    if apply.is_some() {
+        return Ok(());
+    }
+
+    let input_schema = lp_arena.get(*input).schema(lp_arena);
+    let nan_exprs = keys
+        .iter()
+        .filter(|e| float_dtype(e, &input_schema, expr_arena).is_some())
+        .map(|e| e.to_expr(expr_arena))
+        .collect::<Vec<_>>();
+    if nan_exprs.is_empty() {
+        return Ok(());
+    }
+    let (input, mut keys, aggs, maintain_order, options) = (
+        *input,
+        keys.clone(),
+        aggs.clone(),
+        *maintain_order,
+        options.clone(),
+    );
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+
+    let input = with_nan_key(input, nan_exprs, lp_arena, expr_arena)?;
+    keys.push(to_expr_ir(col(NAN_KEY), expr_arena)?);
+    let lp = IRBuilder::new(input, expr_arena, lp_arena)
+        .group_by(keys, aggs, None, maintain_order, options)
+        .project_simple(schema.iter_names().cloned())?
+        .build();
+    lp_arena.replace(node, lp);
+    Ok(())
+}
+
+/// Keep every row with a NaN in the subset of a `unique`, as it doesn't equal any other row.
+fn unequal_distinct_rows(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Distinct { input, options } = lp_arena.get(node) else {
+        unreachable!()
+    };
+    let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+    let subset = match &options.subset {
+        Some(subset) => subset.to_vec(),
+        None => input_schema.iter_names().cloned().collect(),
+    };
+    let nan_exprs = subset
+        .iter()
+        .filter(|name| input_schema.get(name).is_some_and(|dtype| dtype.is_float()))
+        .map(|name| col(name.clone()))
+        .collect::<Vec<_>>();
+    if nan_exprs.is_empty() {
+        return Ok(());
+    }
+    let (input, mut options) = (*input, options.clone());
+
+    let input = with_nan_key(input, nan_exprs, lp_arena, expr_arena)?;
+    options.subset = Some(subset.into_iter().chain([NAN_KEY.into()]).collect());
+    let lp = IRBuilder::from_lp(IR::Distinct { input, options }, expr_arena, lp_arena)
+        .project_simple(input_schema.iter_names().cloned())?
+        .build();
+    lp_arena.replace(node, lp);
+    Ok(())
+}
+
+/// Sort NaNs first or last by sorting on whether every float key is NaN before the key itself.
+fn order_nans(
+    node: Node,
+    nan_order: NanOrder,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Sort {
+        input,
+        by_column,
+        sort_options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        unreachable!()
+    };
+    let input_schema = lp_arena.get(*input).schema(lp_arena);
+    let is_float = by_column
+        .iter()
+        .map(|e| float_dtype(e, &input_schema, expr_arena).is_some())
+        .collect::<Vec<_>>();
+    if !is_float.contains(&true) {
+        return Ok(());
+    }
+
+    let n = by_column.len();
+    let broadcast = |flags: &[bool], i: usize| if flags.len() == 1 { flags[0] } else { flags[i] };
+    let nans_first = nan_order == NanOrder::First;
+    let mut new_by_column = Vec::with_capacity(2 * n);
+    let mut descending = Vec::with_capacity(2 * n);
+    let mut nulls_last = Vec::with_capacity(2 * n);
+    for (i, e) in by_column.iter().enumerate() {
+        let e_nulls_last = broadcast(&sort_options.nulls_last, i);
+        if is_float[i] {
+            let is_nan = e
+                .to_expr(expr_arena)
+                .is_nan()
+                .alias(format_pl_smallstr!("{NAN_KEY}_{i}"));
+            new_by_column.push(is_nan);
+            descending.push(nans_first);
+            nulls_last.push(e_nulls_last);
+        }
+        new_by_column.push(e.to_expr(expr_arena));
+        descending.push(broadcast(&sort_options.descending, i));
+        nulls_last.push(e_nulls_last);
+    }
+    let new_by_column = new_by_column
+        .into_iter()
+        .map(|e| to_expr_ir(e, expr_arena))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let IR::Sort {
+        by_column,
+        sort_options,
+        ..
+    } = lp_arena.get_mut(node)
+    else {
+        unreachable!()
+    };
+    *by_column = new_by_column;
+    sort_options.descending = descending;
+    sort_options.nulls_last = nulls_last;
+    Ok(())
+}
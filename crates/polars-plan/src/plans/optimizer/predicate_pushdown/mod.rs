@@ -89,8 +89,14 @@ impl<'a> PredicatePushDown<'a> {
             }
             let input = inputs[inputs.len() - 1];
 
-            let (eligibility, alias_rename_map) =
-                pushdown_eligibility(&exprs, &[], &acc_predicates, expr_arena)?;
+            let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+            let (eligibility, alias_rename_map, inlined_projections) = pushdown_eligibility(
+                &exprs,
+                Some(&input_schema),
+                &[],
+                &acc_predicates,
+                expr_arena,
+            )?;
 
             let local_predicates = match eligibility {
                 PushdownEligibility::Full => vec![],
@@ -106,13 +112,14 @@ impl<'a> PredicatePushDown<'a> {
                 },
             };
 
-            if !alias_rename_map.is_empty() {
+            if !alias_rename_map.is_empty() || !inlined_projections.is_empty() {
                 for (_, e) in acc_predicates.iter_mut() {
                     let mut needs_rename = false;
 
                     for (_, ae) in (&*expr_arena).iter(e.node()) {
                         if let AExpr::Column(name) = ae {
-                            needs_rename |= alias_rename_map.contains_key(name);
+                            needs_rename |= alias_rename_map.contains_key(name)
+                                || inlined_projections.contains_key(name);
 
                             if needs_rename {
                                 break;
@@ -127,6 +134,8 @@ impl<'a> PredicatePushDown<'a> {
                             Expr::Column(name) => {
                                 if let Some(rename_to) = alias_rename_map.get(&*name) {
                                     Expr::Column(rename_to.clone())
+                                } else if let Some(node) = inlined_projections.get(&*name) {
+                                    node_to_expr(*node, expr_arena)
                                 } else {
                                     Expr::Column(name)
                                 }
@@ -267,6 +276,7 @@ impl<'a> PredicatePushDown<'a> {
 
                 let local_predicates = match pushdown_eligibility(
                     &[],
+                    None,
                     &[(tmp_key.clone(), predicate.clone())],
                     &acc_predicates,
                     expr_arena,
@@ -1,4 +1,5 @@
 use polars_core::prelude::*;
+use polars_core::utils::get_supertype;
 
 use super::keys::*;
 use crate::prelude::*;
@@ -225,6 +226,37 @@ fn get_maybe_aliased_projection_to_input_name_map(
     }
 }
 
+/// Whether the projection `e` is a non-strict cast of a numeric column to a wider numeric type,
+/// e.g. the casts that unify the schemas of the inputs of a union. Predicates on such a
+/// projection can refer to the cast of the input column instead. The cast preserves the order of
+/// the values, so it doesn't affect which row groups the statistics of a scan rule out. Strict
+/// casts are not inlined, as they could raise on rows that a predicate further down removes.
+fn is_inlinable_cast(e: &ExprIR, input_schema: Option<&Schema>, expr_arena: &Arena<AExpr>) -> bool {
+    let (
+        AExpr::Cast {
+            expr,
+            dtype,
+            options,
+        },
+        Some(input_schema),
+    ) = (expr_arena.get(e.node()), input_schema)
+    else {
+        return false;
+    };
+    let AExpr::Column(name) = expr_arena.get(*expr) else {
+        return false;
+    };
+    match input_schema.get(name) {
+        Some(input_dtype) => {
+            !options.strict()
+                && input_dtype.is_numeric()
+                && dtype.is_numeric()
+                && get_supertype(input_dtype, dtype).as_ref() == Some(dtype)
+        },
+        None => false,
+    }
+}
+
 pub enum PushdownEligibility {
     Full,
     // Partial can happen when there are window exprs.
@@ -232,18 +264,26 @@ pub enum PushdownEligibility {
     NoPushdown,
 }
 
+/// Returns the eligibility, the columns that are aliases of input columns and the columns that
+/// are computed by expressions that can be inlined into the predicates.
 #[allow(clippy::type_complexity)]
 pub fn pushdown_eligibility(
     projection_nodes: &[ExprIR],
+    input_schema: Option<&Schema>,
     new_predicates: &[(PlSmallStr, ExprIR)],
     acc_predicates: &PlHashMap<PlSmallStr, ExprIR>,
     expr_arena: &mut Arena<AExpr>,
-) -> PolarsResult<(PushdownEligibility, PlHashMap<PlSmallStr, PlSmallStr>)> {
+) -> PolarsResult<(
+    PushdownEligibility,
+    PlHashMap<PlSmallStr, PlSmallStr>,
+    PlHashMap<PlSmallStr, Node>,
+)> {
     let mut ae_nodes_stack = Vec::<Node>::with_capacity(4);
 
     let mut alias_to_col_map =
         optimizer::init_hashmap::<PlSmallStr, PlSmallStr>(Some(projection_nodes.len()));
     let mut col_to_alias_map = alias_to_col_map.clone();
+    let mut inlined_projections = optimizer::init_hashmap::<PlSmallStr, Node>(None);
 
     let mut modified_projection_columns =
         PlHashSet::<PlSmallStr>::with_capacity(projection_nodes.len());
@@ -333,6 +373,10 @@ pub fn pushdown_eligibility(
             }
             continue;
         }
+        if is_inlinable_cast(e, input_schema, expr_arena) {
+            inlined_projections.insert(e.output_name().clone(), e.node());
+            continue;
+        }
 
         modified_projection_columns.insert(e.output_name().clone());
 
@@ -344,7 +388,11 @@ pub fn pushdown_eligibility(
             &mut has_window,
             &mut common_window_inputs,
         ) {
-            return Ok((PushdownEligibility::NoPushdown, alias_to_col_map));
+            return Ok((
+                PushdownEligibility::NoPushdown,
+                alias_to_col_map,
+                inlined_projections,
+            ));
         }
     }
 
@@ -366,7 +414,11 @@ pub fn pushdown_eligibility(
         }
 
         if new.is_empty() {
-            return Ok((PushdownEligibility::NoPushdown, alias_to_col_map));
+            return Ok((
+                PushdownEligibility::NoPushdown,
+                alias_to_col_map,
+                inlined_projections,
+            ));
         }
 
         common_window_inputs = new;
@@ -381,15 +433,28 @@ pub fn pushdown_eligibility(
             &mut has_window,
             &mut common_window_inputs,
         ) {
-            return Ok((PushdownEligibility::NoPushdown, alias_to_col_map));
+            return Ok((
+                PushdownEligibility::NoPushdown,
+                alias_to_col_map,
+                inlined_projections,
+            ));
         }
     }
 
     // Should have returned early.
     debug_assert!(!common_window_inputs.is_empty() || !has_window);
 
+    if has_window {
+        // Predicates are only pushed if they refer to the window inputs.
+        modified_projection_columns.extend(inlined_projections.drain().map(|(name, _)| name));
+    }
+
     if !has_window && projection_nodes.is_empty() {
-        return Ok((PushdownEligibility::Full, alias_to_col_map));
+        return Ok((
+            PushdownEligibility::Full,
+            alias_to_col_map,
+            inlined_projections,
+        ));
     }
 
     // Note: has_window is constant.
@@ -435,11 +500,10 @@ pub fn pushdown_eligibility(
         })
         .collect::<Vec<_>>();
 
-    match to_local.len() {
-        0 => Ok((PushdownEligibility::Full, alias_to_col_map)),
-        len if len == acc_predicates.len() => {
-            Ok((PushdownEligibility::NoPushdown, alias_to_col_map))
-        },
-        _ => Ok((PushdownEligibility::Partial { to_local }, alias_to_col_map)),
-    }
+    let eligibility = match to_local.len() {
+        0 => PushdownEligibility::Full,
+        len if len == acc_predicates.len() => PushdownEligibility::NoPushdown,
+        _ => PushdownEligibility::Partial { to_local },
+    };
+    Ok((eligibility, alias_to_col_map, inlined_projections))
 }
@@ -81,8 +81,15 @@ fn split_acc_projections(
     expr_arena: &Arena<AExpr>,
     expands_schema: bool,
 ) -> (Vec<ColumnNode>, Vec<ColumnNode>, PlHashSet<PlSmallStr>) {
-    // If node above has as many columns as the projection there is nothing to pushdown.
-    if !expands_schema && down_schema.len() == acc_projections.len() {
+    // If node above has as many columns as the projection there is nothing to pushdown. The
+    // projections can still differ from the columns of the input, e.g. above a `with_columns`
+    // that adds the columns missing from an input of a diagonal concat.
+    if !expands_schema
+        && down_schema.len() == acc_projections.len()
+        && acc_projections
+            .iter()
+            .all(|expr| check_input_column_node(*expr, down_schema, expr_arena))
+    {
         let local_projections = acc_projections;
         (vec![], local_projections, PlHashSet::new())
     } else {
@@ -1,6 +1,7 @@
 #[cfg(feature = "json")]
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 use bitflags::bitflags;
 use polars_core::prelude::*;
@@ -11,6 +12,8 @@ use polars_io::csv::write::CsvWriterOptions;
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "json")]
+use polars_io::ndjson::JsonUnionResolution;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
 use polars_io::{HiveOptions, RowIndex};
@@ -307,6 +310,68 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Write the output into a hive partitioned directory tree at `path`.
+    Partitioned {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        options: PartitionedSinkOptions,
+    },
+    /// Write the same output to all of these sinks in a single pass.
+    Multiple(Vec<SinkType>),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Batches(SinkCallback),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartitionedSinkOptions {
+    /// Columns whose values determine the directory of every row, e.g. `year=2024/month=1`.
+    pub partition_by: Vec<PlSmallStr>,
+    /// Name of every key of `partition_by` in the directory names. A key that is computed by an
+    /// expression is in a temporary column with another name, which is not written to the files.
+    pub key_names: Vec<PlSmallStr>,
+    /// Maximum number of files that are open at the same time. If a partition needs a file while
+    /// this many are open, the least recently written one is finished. Later rows of that
+    /// partition are written to a new file in the same directory.
+    pub max_open_files: usize,
+}
+
+type SinkCallbackFn = dyn FnMut(DataFrame) -> PolarsResult<()> + Send;
+
+/// User code that receives the output of a streaming query batch by batch.
+///
+/// Callbacks are compared by identity and can't be serialized.
+#[derive(Clone)]
+pub struct SinkCallback(Arc<Mutex<SinkCallbackFn>>);
+
+impl SinkCallback {
+    pub fn new(f: impl FnMut(DataFrame) -> PolarsResult<()> + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(f)))
+    }
+
+    pub fn call(&self, df: DataFrame) -> PolarsResult<()> {
+        (self.0.lock().unwrap())(df)
+    }
+}
+
+impl PartialEq for SinkCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SinkCallback {}
+
+impl std::hash::Hash for SinkCallback {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const () as usize).hash(state)
+    }
+}
+
+impl std::fmt::Debug for SinkCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SinkCallback")
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -403,6 +468,7 @@ impl From<UnionArgs> for UnionOptions {
 pub struct NDJsonReadOptions {
     pub n_threads: Option<usize>,
     pub infer_schema_length: Option<NonZeroUsize>,
+    pub union_resolution: JsonUnionResolution,
     pub chunk_size: NonZeroUsize,
     pub low_memory: bool,
     pub ignore_errors: bool,
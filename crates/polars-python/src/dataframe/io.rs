@@ -406,8 +406,20 @@ impl PyDataFrame {
                     compression,
                     statistics: statistics.0,
                     row_group_size,
+                    row_group_bytes: None,
                     data_page_size,
                     maintain_order: true,
+                    target_file_size: None,
+                    max_rows_per_file: None,
+                    encryption: None,
+                    bloom_filter: None,
+                    encoding: None,
+                    key_value_metadata: None,
+                    field_metadata: None,
+                    sorting_columns: None,
+                    column_options: vec![],
+                    partition_by: vec![],
+                    max_open_files: None,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
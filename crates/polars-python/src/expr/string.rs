@@ -24,6 +24,7 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            locale: Default::default(),
         };
         self.inner.clone().str().to_date(options).into()
     }
@@ -47,6 +48,7 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            locale: Default::default(),
         };
         self.inner
             .clone()
@@ -69,6 +71,7 @@ impl PyExpr {
             strict,
             cache,
             exact: true,
+            locale: Default::default(),
         };
         self.inner.clone().str().to_time(options).into()
     }
@@ -1,3 +1,5 @@
+#[cfg(feature = "json")]
+use polars::prelude::JsonEncodeOptions;
 use pyo3::prelude::*;
 
 use crate::error::PyPolarsErr;
@@ -23,8 +25,23 @@ impl PyExpr {
     }
 
     #[cfg(feature = "json")]
-    fn struct_json_encode(&self) -> Self {
-        self.inner.clone().struct_().json_encode().into()
+    #[pyo3(signature = (sort_fields, strip_nulls, date_format, decimal_as_number, decimal_trim_zeros))]
+    fn struct_json_encode(
+        &self,
+        sort_fields: bool,
+        strip_nulls: bool,
+        date_format: Option<String>,
+        decimal_as_number: bool,
+        decimal_trim_zeros: Option<bool>,
+    ) -> Self {
+        let options = JsonEncodeOptions {
+            sort_fields,
+            strip_nulls,
+            date_format: date_format.map(|f| f.into()),
+            decimal_as_number,
+            decimal_trim_zeros,
+        };
+        self.inner.clone().struct_().json_encode(options).into()
     }
 
     fn struct_with_fields(&self, fields: Vec<PyExpr>) -> PyResult<Self> {
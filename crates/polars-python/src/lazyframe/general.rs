@@ -317,9 +317,16 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            use_metadata_summary: false,
+            prefetch: Default::default(),
+            large_row_group_bytes: None,
+            dictionary_as_categorical: false,
             hive_options,
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            schema: None,
+            cast_policy: Default::default(),
+            start_at: None,
         };
 
         let lf = if path.is_some() {
@@ -399,6 +406,7 @@ impl PyLazyFrame {
             cloud_options,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            start_at: None,
         };
 
         let lf = if let Some(path) = &path {
@@ -651,7 +659,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_page_size, maintain_order, target_file_size, max_rows_per_file))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -662,6 +670,8 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
         maintain_order: bool,
+        target_file_size: Option<usize>,
+        max_rows_per_file: Option<usize>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -669,8 +679,20 @@ impl PyLazyFrame {
             compression,
             statistics: statistics.0,
             row_group_size,
+            row_group_bytes: None,
             data_page_size,
             maintain_order,
+            target_file_size,
+            max_rows_per_file,
+            encryption: None,
+            bloom_filter: None,
+            encoding: None,
+            key_value_metadata: None,
+            field_metadata: None,
+            sorting_columns: None,
+            column_options: vec![],
+            partition_by: vec![],
+            max_open_files: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -694,6 +716,8 @@ impl PyLazyFrame {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
+            target_file_size: None,
+            max_rows_per_file: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -736,10 +760,12 @@ impl PyLazyFrame {
             float_scientific,
             float_precision,
             separator,
+            multi_byte_separator: None,
             quote_char,
             null: null_value,
             line_terminator,
             quote_style,
+            escape_char: None,
         };
 
         let options = CsvWriterOptions {
@@ -748,6 +774,8 @@ impl PyLazyFrame {
             maintain_order,
             batch_size,
             serialize_options,
+            target_file_size: None,
+            max_rows_per_file: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -968,7 +968,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TotalNanoseconds => {
                         (PyTemporalFunction::TotalNanoseconds,).into_py(py)
                     },
-                    TemporalFunction::ToString(format) => {
+                    TemporalFunction::ToString(format, _) => {
                         (PyTemporalFunction::ToString, format).into_py(py)
                     },
                     TemporalFunction::CastTimeUnit(time_unit) => {
@@ -1299,6 +1299,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"))
                 },
+                FunctionExpr::Funnel(_) => return Err(PyNotImplementedError::new_err("funnel")),
                 #[cfg(feature = "top_k")]
                 FunctionExpr::TopKBy { descending } => ("top_k_by", descending).to_object(py),
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
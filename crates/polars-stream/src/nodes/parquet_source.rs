@@ -617,6 +617,7 @@ impl ParquetSourceNode {
             }
         };
 
+        let dictionary_as_categorical = self.options.dictionary_as_categorical;
         let process_metadata_bytes = {
             move |handle: task_handles_ext::AbortOnDropHandle<
                 PolarsResult<(usize, Arc<DynByteSource>, MemSlice)>,
@@ -635,6 +636,7 @@ impl ParquetSourceNode {
                     ensure_metadata_has_projected_fields(
                         projected_arrow_fields.as_ref(),
                         &metadata,
+                        dictionary_as_categorical,
                     )?;
 
                     let file_max_row_group_height = if needs_max_row_group_height_calc {
@@ -1789,9 +1791,12 @@ fn get_row_group_byte_ranges_for_projection<'a>(
 
 /// Ensures that a parquet file has all the necessary columns for a projection with the correct
 /// dtype. There are no ordering requirements and extra columns are permitted.
+///
+/// With `dictionary_as_categorical` the string columns of the file are read as categoricals.
 fn ensure_metadata_has_projected_fields(
     projected_fields: &[polars_core::prelude::ArrowField],
     metadata: &FileMetaData,
+    dictionary_as_categorical: bool,
 ) -> PolarsResult<()> {
     let schema = polars_parquet::arrow::read::infer_schema(metadata)?;
 
@@ -1811,7 +1816,10 @@ fn ensure_metadata_has_projected_fields(
 
         let expected_dtype = DataType::from_arrow(&field.dtype, true);
 
-        if dtype != expected_dtype {
+        let is_categorical_string = dictionary_as_categorical
+            && dtype == DataType::String
+            && expected_dtype.is_categorical();
+        if dtype != expected_dtype && !is_categorical_string {
             polars_bail!(SchemaMismatch: "data type mismatch for column {}: found: {}, expected: {}",
                 &field.name, dtype, expected_dtype
             )
@@ -0,0 +1,389 @@
+//! Calendar anchored frequencies for date and datetime ranges.
+use std::str::FromStr;
+
+use arrow::legacy::time_zone::Tz;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use polars_utils::format_pl_smallstr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "timezones")]
+use crate::utils::{try_localize_datetime, unlocalize_datetime};
+use crate::ClosedWindow;
+
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A cron expression with the five fields minute, hour, day of month, month and day of week.
+///
+/// Fields take `*`, values, ranges (`1-5`), lists (`1,15`) and steps (`*/15` or `0-30/10`).
+/// Months and weekdays also take their English three letter names, and both 0 and 7 are
+/// Sunday. As in cron, a day matches if either its day of month or its day of week matches
+/// when both fields are restricted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    weekdays: u8,
+    restricted_days_of_month: bool,
+    restricted_weekdays: bool,
+}
+
+fn parse_cron_field(
+    expr: &str,
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    name_offset: u32,
+) -> PolarsResult<u64> {
+    let invalid = || polars_err!(ComputeError: "invalid cron expression '{}': cannot parse field '{}'", expr, field);
+    let value = |s: &str| -> PolarsResult<u32> {
+        match s.parse::<u32>() {
+            Ok(v) => Ok(v),
+            Err(_) => names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(s))
+                .map(|i| i as u32 + name_offset)
+                .ok_or_else(invalid),
+        }
+    };
+
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step.parse::<u32>().ok().filter(|step| *step > 0);
+                (range, Some(step.ok_or_else(invalid)?))
+            },
+            None => (part, None),
+        };
+        let (lo, hi) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((lo, hi)) => (value(lo)?, value(hi)?),
+            // A step from a single value runs to the end of the field, e.g. `5/15`.
+            None if step.is_some() => (value(range)?, max),
+            None => (value(range)?, value(range)?),
+        };
+        polars_ensure!(min <= lo && lo <= hi && hi <= max, ComputeError: "invalid cron expression '{}': field '{}' must be within {}-{}", expr, field, min, max);
+        for v in (lo..=hi).step_by(step.unwrap_or(1) as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> PolarsResult<Self> {
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day_of_month, month, weekday] = fields[..] else {
+            polars_bail!(ComputeError: "invalid cron expression '{}': expected 5 fields, got {}", expr, fields.len());
+        };
+        let weekdays = parse_cron_field(expr, weekday, 0, 7, &WEEKDAY_NAMES, 0)?;
+        Ok(Self {
+            minutes: parse_cron_field(expr, minute, 0, 59, &[], 0)?,
+            hours: parse_cron_field(expr, hour, 0, 23, &[], 0)? as u32,
+            days_of_month: parse_cron_field(expr, day_of_month, 1, 31, &[], 0)? as u32,
+            months: parse_cron_field(expr, month, 1, 12, &MONTH_NAMES, 1)? as u16,
+            // Both 0 and 7 are Sunday.
+            weekdays: ((weekdays | (weekdays >> 7)) & 0x7f) as u8,
+            restricted_days_of_month: !day_of_month.starts_with('*'),
+            restricted_weekdays: !weekday.starts_with('*'),
+        })
+    }
+
+    /// Whether the schedule fires at any time on `date`.
+    pub fn matches_date(&self, date: NaiveDate) -> bool {
+        let month = self.months & (1 << date.month()) != 0;
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        let day = if self.restricted_days_of_month && self.restricted_weekdays {
+            day_of_month || weekday
+        } else {
+            day_of_month && weekday
+        };
+        month && day
+    }
+
+    /// The times of day at which the schedule fires, in order.
+    fn times(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (0..24u32)
+            .filter(|h| self.hours & (1 << h) != 0)
+            .flat_map(|h| {
+                (0..60u32)
+                    .filter(|m| self.minutes & (1 << m) != 0)
+                    .map(move |m| (h, m))
+            })
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        Self::parse(s)
+    }
+}
+
+/// A frequency that is anchored to the calendar instead of to the start of a range.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CalendarFrequency {
+    /// Every `n`-th business day. Business days are the days in `week_mask`, which starts at
+    /// Monday, that are not in `holidays`, given as days since the Unix epoch.
+    BusinessDay {
+        n: u32,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    /// The last day of every `n`-th month.
+    MonthEnd { n: u32 },
+    /// The last day of every `n`-th quarter, i.e. of March, June, September and December.
+    QuarterEnd { n: u32 },
+    /// The times at which a [`CronSchedule`] fires.
+    Cron(CronSchedule),
+}
+
+fn last_day_of_month(months_since_year_zero: i64) -> Option<NaiveDate> {
+    let year = months_since_year_zero.div_euclid(12) as i32;
+    let month = months_since_year_zero.rem_euclid(12) as u32 + 1;
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()
+}
+
+fn is_in_window<T: PartialOrd>(t: &T, start: &T, end: &T, closed: ClosedWindow) -> bool {
+    match closed {
+        ClosedWindow::Both => start <= t && t <= end,
+        ClosedWindow::Left => start <= t && t < end,
+        ClosedWindow::Right => start < t && t <= end,
+        ClosedWindow::None => start < t && t < end,
+    }
+}
+
+impl CalendarFrequency {
+    /// The dates from `start` to `end`, both inclusive, on which the frequency fires.
+    pub fn dates(&self, start: NaiveDate, end: NaiveDate) -> PolarsResult<Vec<NaiveDate>> {
+        let mut out = vec![];
+        if start > end {
+            return Ok(out);
+        }
+        let days = start.iter_days().take_while(|date| *date <= end);
+        match self {
+            Self::BusinessDay {
+                n,
+                week_mask,
+                holidays,
+            } => {
+                polars_ensure!(*n > 0, ComputeError: "`n` of a business day frequency must be positive");
+                polars_ensure!(
+                    week_mask.iter().any(|&x| x),
+                    ComputeError: "`week_mask` must have at least one business day"
+                );
+                let mut holidays = holidays.clone();
+                holidays.sort_unstable();
+                let is_business_day = |date: &NaiveDate| {
+                    let days_since_epoch = date.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE;
+                    week_mask[date.weekday().num_days_from_monday() as usize]
+                        && holidays.binary_search(&days_since_epoch).is_err()
+                };
+                out.extend(days.filter(is_business_day).step_by(*n as usize));
+            },
+            Self::MonthEnd { n } | Self::QuarterEnd { n } => {
+                polars_ensure!(*n > 0, ComputeError: "`n` of a month or quarter end frequency must be positive");
+                let (first, step) = match self {
+                    Self::MonthEnd { .. } => (start.month0(), *n),
+                    _ => (start.month0() / 3 * 3 + 2, *n * 3),
+                };
+                let mut month = start.year() as i64 * 12 + first as i64;
+                while let Some(date) = last_day_of_month(month).filter(|date| *date <= end) {
+                    out.push(date);
+                    month += step as i64;
+                }
+            },
+            Self::Cron(schedule) => out.extend(days.filter(|date| schedule.matches_date(*date))),
+        }
+        Ok(out)
+    }
+}
+
+/// Create a [`DateChunked`] with the dates from `start` to `end` on which `frequency` fires.
+#[cfg(feature = "dtype-date")]
+pub fn calendar_date_range(
+    name: PlSmallStr,
+    start: NaiveDate,
+    end: NaiveDate,
+    frequency: &CalendarFrequency,
+    closed: ClosedWindow,
+) -> PolarsResult<DateChunked> {
+    let dates = frequency
+        .dates(start, end)?
+        .into_iter()
+        .filter(|date| is_in_window(date, &start, &end, closed))
+        .map(|date| date.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE)
+        .collect();
+    let mut out = Int32Chunked::new_vec(name, dates).into_date();
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+/// Create a [`DatetimeChunked`] with the timestamps from `start` to `end` on which `frequency`
+/// fires, as for [`datetime_range_impl`](crate::datetime_range_impl).
+///
+/// Frequencies of days or longer fire at the time of day of `start`. Calendar dates and times
+/// are taken in `tz`, where times that do not exist are skipped and ambiguous times resolve to
+/// the earliest.
+#[cfg(feature = "dtype-datetime")]
+pub fn calendar_datetime_range(
+    name: PlSmallStr,
+    start: i64,
+    end: i64,
+    frequency: &CalendarFrequency,
+    closed: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+) -> PolarsResult<DatetimeChunked> {
+    let (timestamp_to_datetime, datetime_to_timestamp): (
+        fn(i64) -> NaiveDateTime,
+        fn(NaiveDateTime) -> i64,
+    ) = match tu {
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+    };
+    let to_local = |t: i64| match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
+        _ => timestamp_to_datetime(t),
+    };
+    let (start, end) = (to_local(start), to_local(end));
+
+    let local = match frequency {
+        CalendarFrequency::Cron(schedule) => frequency
+            .dates(start.date(), end.date())?
+            .into_iter()
+            .flat_map(|date| {
+                schedule
+                    .times()
+                    .filter_map(move |(h, m)| date.and_hms_opt(h, m, 0))
+            })
+            .collect::<Vec<_>>(),
+        _ => frequency
+            .dates(start.date(), end.date())?
+            .into_iter()
+            .map(|date| date.and_time(start.time()))
+            .collect(),
+    };
+
+    let mut timestamps = Vec::with_capacity(local.len());
+    for ndt in local {
+        if !is_in_window(&ndt, &start, &end, closed) {
+            continue;
+        }
+        let ndt = match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => {
+                match try_localize_datetime(ndt, tz, Ambiguous::Earliest, NonExistent::Null)? {
+                    Some(ndt) => ndt,
+                    None => continue,
+                }
+            },
+            _ => ndt,
+        };
+        timestamps.push(datetime_to_timestamp(ndt));
+    }
+
+    let out = Int64Chunked::new_vec(name, timestamps);
+    let mut out = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => out.into_datetime(tu, Some(format_pl_smallstr!("{}", tz))),
+        _ => out.into_datetime(tu, None),
+    };
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_dates() {
+        // Thursday 2024-02-29 up to Tuesday 2024-03-12, with Friday 2024-03-08 as holiday.
+        let (start, end) = (date(2024, 2, 29), date(2024, 3, 12));
+        let holiday = date(2024, 3, 8).num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE;
+        let business_days = CalendarFrequency::BusinessDay {
+            n: 2,
+            week_mask: [true, true, true, true, true, false, false],
+            holidays: vec![holiday],
+        };
+        assert_eq!(
+            business_days.dates(start, end).unwrap(),
+            [
+                date(2024, 2, 29),
+                date(2024, 3, 4),
+                date(2024, 3, 6),
+                date(2024, 3, 11)
+            ]
+        );
+
+        let (start, end) = (date(2023, 11, 15), date(2024, 6, 30));
+        let month_ends = CalendarFrequency::MonthEnd { n: 3 };
+        assert_eq!(
+            month_ends.dates(start, end).unwrap(),
+            [date(2023, 11, 30), date(2024, 2, 29), date(2024, 5, 31)]
+        );
+        let quarter_ends = CalendarFrequency::QuarterEnd { n: 1 };
+        assert_eq!(
+            quarter_ends.dates(start, end).unwrap(),
+            [date(2023, 12, 31), date(2024, 3, 31), date(2024, 6, 30)]
+        );
+    }
+
+    #[test]
+    fn test_cron_schedule() {
+        // At 09:00 and 09:30 on weekdays in January.
+        let schedule: CronSchedule = "0,30 9 * jan mon-fri".parse().unwrap();
+        assert!(schedule.matches_date(date(2024, 1, 5)));
+        assert!(!schedule.matches_date(date(2024, 1, 6)));
+        assert!(!schedule.matches_date(date(2024, 2, 5)));
+        assert_eq!(schedule.times().collect::<Vec<_>>(), [(9, 0), (9, 30)]);
+
+        // Day of month and day of week match either when both are restricted.
+        let schedule = CronSchedule::parse("0 0 1 * 0").unwrap();
+        assert!(schedule.matches_date(date(2024, 2, 1)));
+        assert!(schedule.matches_date(date(2024, 2, 4)));
+        assert!(!schedule.matches_date(date(2024, 2, 5)));
+        assert_eq!(
+            CronSchedule::parse("0 0 * * 7").unwrap(),
+            CronSchedule::parse("0 0 * * sun").unwrap()
+        );
+
+        let schedule = CronSchedule::parse("*/20 0-23/12 * * *").unwrap();
+        assert_eq!(schedule.times().count(), 6);
+
+        assert!(CronSchedule::parse("0 0 * *").is_err());
+        assert!(CronSchedule::parse("60 0 * * *").is_err());
+        assert!(CronSchedule::parse("*/0 0 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * foo *").is_err());
+    }
+}
@@ -9,6 +9,8 @@ mod kernels;
 #[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
 mod rolling_window;
 pub mod string;
+#[cfg(feature = "dtype-duration")]
+pub use string::ClockAmbiguity;
 #[cfg(feature = "dtype-time")]
 mod time;
 
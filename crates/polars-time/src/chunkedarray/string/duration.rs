@@ -0,0 +1,368 @@
+use polars_core::prelude::arity::try_unary_elementwise;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::windows::calendar::{
+    NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
+};
+
+/// How to read a clock duration with only two components, e.g. `"02:30"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClockAmbiguity {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Read the components as hours and minutes.
+    HoursMinutes,
+    /// Read the components as minutes and seconds.
+    MinutesSeconds,
+}
+
+/// A decimal number split into its integral and fractional digits.
+struct Number<'a> {
+    int: &'a str,
+    frac: &'a str,
+}
+
+impl Number<'_> {
+    /// Multiply by `unit` nanoseconds, truncating anything below a nanosecond.
+    fn scale(&self, unit: i64) -> Option<i128> {
+        let int = if self.int.is_empty() {
+            0
+        } else {
+            self.int.parse::<i128>().ok()?
+        };
+        let mut frac = 0i128;
+        let mut div = 1i128;
+        for b in self.frac.bytes().take(18) {
+            frac = frac * 10 + (b - b'0') as i128;
+            div *= 10;
+        }
+        int.checked_mul(unit as i128)?
+            .checked_add(frac * unit as i128 / div)
+    }
+}
+
+fn split_number(s: &str) -> Option<(Number<'_>, &str)> {
+    let int_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (int, rest) = s.split_at(int_end);
+    let (frac, rest) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let frac_end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            rest.split_at(frac_end)
+        },
+        None => ("", rest),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    Some((Number { int, frac }, rest))
+}
+
+/// Returns whether a sign was given and whether it is negative.
+fn split_sign(s: &str) -> (Option<bool>, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (Some(true), rest)
+    } else if let Some(rest) = s.strip_prefix('+') {
+        (Some(false), rest)
+    } else {
+        (None, s)
+    }
+}
+
+fn bail_calendar_unit(unit: &str) -> PolarsResult<Option<i64>> {
+    polars_bail!(
+        InvalidOperation: "cannot parse '{}' into a duration: months and years do not have a fixed length",
+        unit
+    )
+}
+
+fn humanized_unit(unit: &str) -> PolarsResult<Option<i64>> {
+    let ns = match unit.to_lowercase().as_str() {
+        "ns" | "nanosecond" | "nanoseconds" => 1,
+        "us" | "µs" | "μs" | "microsecond" | "microseconds" => NS_MICROSECOND,
+        "ms" | "millisecond" | "milliseconds" => NS_MILLISECOND,
+        "s" | "sec" | "secs" | "second" | "seconds" => NS_SECOND,
+        "m" | "min" | "mins" | "minute" | "minutes" => NS_MINUTE,
+        "h" | "hr" | "hrs" | "hour" | "hours" => NS_HOUR,
+        "d" | "day" | "days" => NS_DAY,
+        "w" | "week" | "weeks" => NS_WEEK,
+        "mo" | "month" | "months" | "y" | "yr" | "year" | "years" => {
+            return bail_calendar_unit(unit)
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(ns))
+}
+
+/// Parse `"1h 30m"`, `"-1d -2h"` or `"1 hour, 30 minutes"`.
+///
+/// A component without a sign takes the sign of the first component, so `"-1h 30m"` is
+/// ninety minutes back.
+fn parse_humanized(mut s: &str) -> PolarsResult<Option<i128>> {
+    let mut total = 0i128;
+    let mut inherited_negative = false;
+    let mut first = true;
+    loop {
+        s = s.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if s.is_empty() {
+            break;
+        }
+        let (sign, rest) = split_sign(s);
+        let Some((number, rest)) = split_number(rest) else {
+            return Ok(None);
+        };
+        let rest = rest.trim_start();
+        let unit_end = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let (unit, rest) = rest.split_at(unit_end);
+        let Some(unit) = humanized_unit(unit)? else {
+            return Ok(None);
+        };
+        let Some(value) = number.scale(unit) else {
+            return Ok(None);
+        };
+        let negative = sign.unwrap_or(inherited_negative);
+        if first {
+            inherited_negative = negative;
+            first = false;
+        }
+        let value = if negative { -value } else { value };
+        let Some(sum) = total.checked_add(value) else {
+            return Ok(None);
+        };
+        total = sum;
+        s = rest;
+    }
+    Ok((!first).then_some(total))
+}
+
+fn iso_date_unit(designator: u8) -> PolarsResult<Option<i64>> {
+    match designator {
+        b'W' => Ok(Some(NS_WEEK)),
+        b'D' => Ok(Some(NS_DAY)),
+        b'Y' => bail_calendar_unit("Y"),
+        b'M' => bail_calendar_unit("M"),
+        _ => Ok(None),
+    }
+}
+
+fn iso_time_unit(designator: u8) -> PolarsResult<Option<i64>> {
+    Ok(match designator {
+        b'H' => Some(NS_HOUR),
+        b'M' => Some(NS_MINUTE),
+        b'S' => Some(NS_SECOND),
+        _ => None,
+    })
+}
+
+fn sum_iso_components(
+    mut s: &str,
+    unit: fn(u8) -> PolarsResult<Option<i64>>,
+) -> PolarsResult<Option<i128>> {
+    let mut total = 0i128;
+    while !s.is_empty() {
+        let Some((number, rest)) = split_number(s) else {
+            return Ok(None);
+        };
+        let Some(designator) = rest.bytes().next() else {
+            return Ok(None);
+        };
+        let Some(unit) = unit(designator.to_ascii_uppercase())? else {
+            return Ok(None);
+        };
+        let Some(sum) = number.scale(unit).and_then(|v| total.checked_add(v)) else {
+            return Ok(None);
+        };
+        total = sum;
+        s = &rest[1..];
+    }
+    Ok(Some(total))
+}
+
+/// Parse the part of an ISO 8601 duration after the `P`, e.g. `"1DT2H3M4.5S"`.
+fn parse_iso(s: &str) -> PolarsResult<Option<i128>> {
+    let (date, time) = match s.find(['T', 't']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    if s.is_empty() || time == Some("") {
+        return Ok(None);
+    }
+    let Some(date) = sum_iso_components(date, iso_date_unit)? else {
+        return Ok(None);
+    };
+    let time = match time {
+        Some(time) => match sum_iso_components(time, iso_time_unit)? {
+            Some(time) => time,
+            None => return Ok(None),
+        },
+        None => 0,
+    };
+    Ok(date.checked_add(time))
+}
+
+/// Parse `"HH:MM:SS[.f]"`, or two components read according to `ambiguous`.
+fn parse_clock(s: &str, ambiguous: ClockAmbiguity) -> PolarsResult<Option<i128>> {
+    let n_parts = s.split(':').count();
+    let units: &[i64] = match n_parts {
+        3 => &[NS_HOUR, NS_MINUTE, NS_SECOND],
+        2 => match ambiguous {
+            ClockAmbiguity::HoursMinutes => &[NS_HOUR, NS_MINUTE],
+            ClockAmbiguity::MinutesSeconds => &[NS_MINUTE, NS_SECOND],
+            ClockAmbiguity::Raise => polars_bail!(
+                InvalidOperation: "duration '{}' is ambiguous: it can be read as hours and minutes or as minutes and seconds; \
+                set `ambiguous` to choose one", s
+            ),
+        },
+        _ => return Ok(None),
+    };
+    let mut total = 0i128;
+    for (i, (part, unit)) in s.split(':').zip(units).enumerate() {
+        let last = i == n_parts - 1;
+        let number = match split_number(part) {
+            Some((number, "")) if !number.int.is_empty() && (last || number.frac.is_empty()) => {
+                number
+            },
+            _ => return Ok(None),
+        };
+        // Only the leading component may exceed its natural range.
+        if i > 0 && (number.int.len() > 2 || number.int.parse::<u8>().map_or(true, |v| v >= 60)) {
+            return Ok(None);
+        }
+        let Some(sum) = number.scale(*unit).and_then(|v| total.checked_add(v)) else {
+            return Ok(None);
+        };
+        total = sum;
+    }
+    Ok(Some(total))
+}
+
+/// Parse a duration into nanoseconds.
+///
+/// The accepted forms are:
+/// - humanized: `"1h 30m"`, `"1d -2h"`, `"1.5 hours"`, with units from `ns` up to `w`;
+/// - ISO 8601: `"PT2H"`, `"-P1DT2H3M4.5S"`, `"P2W"`;
+/// - clock: `"02:30:00"`, `"-1:05:07.25"`; two components such as `"02:30"` are read
+///   according to `ambiguous`.
+///
+/// Returns `None` if the string is not a valid duration and an error if it is well-formed but
+/// cannot be converted, e.g. if it is given in months or years.
+pub fn parse_duration(s: &str, ambiguous: ClockAmbiguity) -> PolarsResult<Option<i128>> {
+    let s = s.trim();
+    let (sign, unsigned) = split_sign(s);
+    let negate = |v: Option<i128>| match sign {
+        Some(true) => v.map(|v| -v),
+        _ => v,
+    };
+    if let Some(iso) = unsigned.strip_prefix(['P', 'p']) {
+        parse_iso(iso).map(negate)
+    } else if unsigned.contains(':') {
+        parse_clock(unsigned, ambiguous).map(negate)
+    } else {
+        parse_humanized(s)
+    }
+}
+
+pub(super) fn as_duration(
+    ca: &StringChunked,
+    tu: TimeUnit,
+    ambiguous: ClockAmbiguity,
+) -> PolarsResult<DurationChunked> {
+    let unit = match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => NS_MICROSECOND,
+        TimeUnit::Milliseconds => NS_MILLISECOND,
+    } as i128;
+    let out: Int64Chunked = try_unary_elementwise(ca, |opt_s| -> PolarsResult<_> {
+        let Some(s) = opt_s else {
+            return Ok(None);
+        };
+        Ok(parse_duration(s, ambiguous)?.and_then(|ns| i64::try_from(ns / unit).ok()))
+    })?;
+    Ok(out.with_name(ca.name().clone()).into_duration(tu))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(s: &str) -> Option<i128> {
+        parse_duration(s, ClockAmbiguity::Raise).unwrap()
+    }
+
+    #[test]
+    fn test_parse_humanized() {
+        let ns = |v: i64| Some(v as i128);
+        assert_eq!(parse("1h 30m"), ns(90 * NS_MINUTE));
+        assert_eq!(parse("1h30m"), ns(90 * NS_MINUTE));
+        assert_eq!(parse("1 hour, 30 minutes"), ns(90 * NS_MINUTE));
+        assert_eq!(parse("1.5h"), ns(90 * NS_MINUTE));
+        assert_eq!(parse("-1h 30m"), ns(-90 * NS_MINUTE));
+        assert_eq!(
+            parse("-1d -2h -3m"),
+            ns(-(NS_DAY + 2 * NS_HOUR + 3 * NS_MINUTE))
+        );
+        assert_eq!(parse("1d -12h"), ns(12 * NS_HOUR));
+        assert_eq!(
+            parse("2w 250µs 7ns"),
+            ns(2 * NS_WEEK + 250 * NS_MICROSECOND + 7)
+        );
+        assert_eq!(parse("0ns"), ns(0));
+
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("90"), None);
+        assert_eq!(parse("1 fortnight"), None);
+        assert!(parse_duration("1mo", ClockAmbiguity::Raise).is_err());
+    }
+
+    #[test]
+    fn test_parse_iso() {
+        let ns = |v: i64| Some(v as i128);
+        assert_eq!(parse("PT2H"), ns(2 * NS_HOUR));
+        assert_eq!(parse("PT0S"), ns(0));
+        assert_eq!(
+            parse("-P1DT2H3M4.5S"),
+            ns(-(NS_DAY + 2 * NS_HOUR + 3 * NS_MINUTE + 4 * NS_SECOND + NS_SECOND / 2))
+        );
+        assert_eq!(parse("P2W"), ns(2 * NS_WEEK));
+        assert_eq!(parse("PT1M"), ns(NS_MINUTE));
+
+        assert_eq!(parse("P"), None);
+        assert_eq!(parse("P1DT"), None);
+        assert_eq!(parse("PT1D"), None);
+        assert_eq!(parse("PT1H junk"), None);
+        assert!(parse_duration("P1M", ClockAmbiguity::Raise).is_err());
+        assert!(parse_duration("P1Y", ClockAmbiguity::Raise).is_err());
+    }
+
+    #[test]
+    fn test_parse_clock() {
+        let ns = |v: i64| Some(v as i128);
+        assert_eq!(parse("02:30:00"), ns(150 * NS_MINUTE));
+        assert_eq!(
+            parse("-1:05:07.25"),
+            ns(-(NS_HOUR + 5 * NS_MINUTE + 7_250 * NS_MILLISECOND))
+        );
+        assert_eq!(parse("100:00:00"), ns(100 * NS_HOUR));
+
+        assert_eq!(parse("02:60:00"), None);
+        assert_eq!(parse("02:3.5:00"), None);
+        assert_eq!(parse("1:2:3:4"), None);
+
+        assert!(parse_duration("02:30", ClockAmbiguity::Raise).is_err());
+        assert_eq!(
+            parse_duration("02:30", ClockAmbiguity::HoursMinutes).unwrap(),
+            Some((150 * NS_MINUTE) as i128)
+        );
+        assert_eq!(
+            parse_duration("02:30", ClockAmbiguity::MinutesSeconds).unwrap(),
+            Some((150 * NS_SECOND) as i128)
+        );
+    }
+}
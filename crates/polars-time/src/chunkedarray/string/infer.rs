@@ -333,6 +333,123 @@ where
     }
 }
 
+static TZ_AWARE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(%z)|(%:z)|(%::z)|(%:::z)|(%#z)|(^%\+$)").unwrap());
+
+/// The dtype of values parsed with the format `fmt`: a `Date` if `fmt` has no time directives,
+/// a `Datetime` in UTC if it has a UTC offset directive and a naive `Datetime` otherwise.
+pub fn format_dtype(fmt: &str, time_unit: TimeUnit) -> PolarsResult<DataType> {
+    let compiled = strptime::compile_fmt(fmt)?;
+    let dtype = if TZ_AWARE_RE.is_match(fmt) {
+        DataType::Datetime(time_unit, Some(PlSmallStr::from_static("UTC")))
+    } else if strptime::HOUR_PATTERN.is_match(&compiled)
+        || compiled.contains("%s")
+        || compiled.contains("%c")
+    {
+        DataType::Datetime(time_unit, None)
+    } else {
+        DataType::Date
+    };
+    Ok(dtype)
+}
+
+/// Parses values with a fixed format instead of inferring it from the values. Uses the fast
+/// parser if it supports the format and falls back to chrono otherwise.
+#[derive(Clone)]
+pub struct DatetimeFormat<T: PolarsNumericType> {
+    fmt: String,
+    fmt_len: Option<u16>,
+    transform: fn(&str, &str) -> Option<T::Native>,
+    transform_naive: fn(NaiveDateTime) -> T::Native,
+    transform_bytes: StrpTimeState,
+    pub logical_type: DataType,
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl TryFromWithUnit<&str> for DatetimeFormat<Int64Type> {
+    type Error = PolarsError;
+
+    fn try_from_with_unit(fmt: &str, time_unit: Option<TimeUnit>) -> PolarsResult<Self> {
+        let time_unit = time_unit.expect("time_unit must be provided for datetime");
+        let tz_aware = TZ_AWARE_RE.is_match(fmt);
+        let fmt = strptime::compile_fmt(fmt)?;
+
+        let transform = match (time_unit, tz_aware) {
+            (TimeUnit::Milliseconds, true) => transform_tzaware_datetime_ms,
+            (TimeUnit::Milliseconds, false) => transform_datetime_ms,
+            (TimeUnit::Microseconds, true) => transform_tzaware_datetime_us,
+            (TimeUnit::Microseconds, false) => transform_datetime_us,
+            (TimeUnit::Nanoseconds, true) => transform_tzaware_datetime_ns,
+            (TimeUnit::Nanoseconds, false) => transform_datetime_ns,
+        };
+        let transform_naive = match time_unit {
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        };
+        // Offsets are not supported by the fast parser.
+        let fmt_len = if tz_aware {
+            None
+        } else {
+            strptime::fmt_len(fmt.as_bytes())
+        };
+
+        let time_zone = tz_aware.then(|| PlSmallStr::from_static("UTC"));
+
+        Ok(DatetimeFormat {
+            fmt,
+            fmt_len,
+            transform,
+            transform_naive,
+            transform_bytes: StrpTimeState::default(),
+            logical_type: DataType::Datetime(time_unit, time_zone),
+        })
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl TryFromWithUnit<&str> for DatetimeFormat<Int32Type> {
+    type Error = PolarsError;
+
+    fn try_from_with_unit(fmt: &str, _time_unit: Option<TimeUnit>) -> PolarsResult<Self> {
+        let fmt = strptime::compile_fmt(fmt)?;
+        let fmt_len = strptime::fmt_len(fmt.as_bytes());
+
+        Ok(DatetimeFormat {
+            fmt,
+            fmt_len,
+            transform: transform_date,
+            transform_naive: |ndt| naive_date_to_date(ndt.date()),
+            transform_bytes: StrpTimeState::default(),
+            logical_type: DataType::Date,
+        })
+    }
+}
+
+impl<T: PolarsNumericType> DatetimeFormat<T> {
+    pub fn format(&self) -> &str {
+        &self.fmt
+    }
+}
+
+impl<T: PolarsNumericType> StrpTimeParser<T::Native> for DatetimeFormat<T> {
+    fn parse_bytes(&mut self, val: &[u8], _time_unit: Option<TimeUnit>) -> Option<T::Native> {
+        if let Some(fmt_len) = self.fmt_len {
+            // SAFETY: fmt_len is correct, it was computed with this `fmt` str.
+            let parsed = unsafe {
+                self.transform_bytes
+                    .parse(val, self.fmt.as_bytes(), fmt_len)
+            };
+            if let Some(ndt) = parsed {
+                return Some((self.transform_naive)(ndt));
+            }
+        }
+        // Fallback to chrono.
+        let val = std::str::from_utf8(val).ok()?;
+        (self.transform)(val, &self.fmt)
+    }
+}
+
 #[cfg(feature = "dtype-date")]
 fn transform_date(val: &str, fmt: &str) -> Option<i32> {
     NaiveDate::parse_from_str(val, fmt)
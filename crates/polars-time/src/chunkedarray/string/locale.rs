@@ -0,0 +1,179 @@
+//! Rewrites date strings so that the English-only parsers can read them.
+use std::borrow::Cow;
+
+use polars_core::chunked_array::temporal::{date_name_directives, DateNameDirective};
+use polars_core::prelude::arity::unary_elementwise;
+
+use super::*;
+
+/// The names that one directive may match, lowercase and longest first, with their English
+/// replacement.
+struct NameTable {
+    names: Vec<(String, &'static str)>,
+}
+
+impl NameTable {
+    fn new(directive: DateNameDirective, locale: DateLocale) -> Option<Self> {
+        let en = DateLocale::English;
+        let (english, full, abbrev): (&[&str], &[&str], &[&str]) = match directive {
+            DateNameDirective::Month => (en.months(), locale.months(), locale.months_abbrev()),
+            DateNameDirective::MonthAbbrev => {
+                (en.months_abbrev(), locale.months(), locale.months_abbrev())
+            },
+            DateNameDirective::Weekday => {
+                (en.weekdays(), locale.weekdays(), locale.weekdays_abbrev())
+            },
+            DateNameDirective::WeekdayAbbrev => (
+                en.weekdays_abbrev(),
+                locale.weekdays(),
+                locale.weekdays_abbrev(),
+            ),
+            DateNameDirective::Quarter => return None,
+        };
+        let mut names = full
+            .iter()
+            .zip(english)
+            .chain(abbrev.iter().zip(english))
+            .map(|(name, english)| (name.to_lowercase(), *english))
+            .collect::<Vec<_>>();
+        names.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+        Some(Self { names })
+    }
+
+    /// Returns the length of the name that `s` starts with and its replacement.
+    fn find(&self, s: &str) -> Option<(usize, &'static str)> {
+        self.names.iter().find_map(|(name, english)| {
+            let candidate = s.get(..name.len())?;
+            let is_word = !s[name.len()..].starts_with(char::is_alphabetic);
+            (is_word && candidate.to_lowercase() == *name).then_some((name.len(), *english))
+        })
+    }
+}
+
+/// Whether `fmt` has an ISO week (`%V`), but no weekday to resolve it to a date.
+fn is_week_without_weekday(fmt: &str) -> bool {
+    let mut chars = fmt.chars();
+    let (mut week, mut weekday) = (false, false);
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            match chars.next() {
+                Some('V') => week = true,
+                Some('a' | 'A' | 'u' | 'w') => weekday = true,
+                _ => {},
+            }
+        }
+    }
+    week && !weekday
+}
+
+pub(super) struct DateRewriter {
+    tables: Vec<NameTable>,
+    append_weekday: bool,
+}
+
+impl DateRewriter {
+    /// Returns `None` if the values can be parsed with `fmt` as they are.
+    pub(super) fn new(fmt: &str, locale: DateLocale) -> Option<Self> {
+        let tables = if locale == DateLocale::English {
+            vec![]
+        } else {
+            date_name_directives(fmt)
+                .filter_map(|(_, directive)| NameTable::new(directive, locale))
+                .collect()
+        };
+        let append_weekday = is_week_without_weekday(fmt);
+        (!tables.is_empty() || append_weekday).then_some(Self {
+            tables,
+            append_weekday,
+        })
+    }
+
+    /// The format of the rewritten values.
+    pub(super) fn format(&self, fmt: &str) -> String {
+        if self.append_weekday {
+            format!("{fmt} %u")
+        } else {
+            fmt.to_string()
+        }
+    }
+
+    /// Replaces the localized names by their English name in the order of the directives in the
+    /// format. Words that are not a name, such as the "de" in "5 de marzo", are kept.
+    pub(super) fn rewrite(&self, val: &str) -> String {
+        let mut out = String::with_capacity(val.len() + 2);
+        let mut tables = self.tables.iter().peekable();
+        let mut rest = val;
+        while let Some(c) = rest.chars().next() {
+            if !c.is_alphabetic() {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            if let Some((len, english)) = tables.peek().and_then(|table| table.find(rest)) {
+                out.push_str(english);
+                rest = &rest[len..];
+                tables.next();
+                continue;
+            }
+            let end = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+        if self.append_weekday {
+            // The first day of an ISO week is a Monday.
+            out.push_str(" 1");
+        }
+        out
+    }
+}
+
+/// Rewrites `ca` and `fmt` with a [`DateRewriter`], if needed.
+pub(super) fn rewrite_dates<'a>(
+    ca: &'a StringChunked,
+    fmt: &'a str,
+    locale: DateLocale,
+) -> (Cow<'a, StringChunked>, Cow<'a, str>) {
+    match DateRewriter::new(fmt, locale) {
+        Some(rewriter) => {
+            let out: StringChunked =
+                unary_elementwise(ca, |opt_s| opt_s.map(|s| rewriter.rewrite(s)));
+            (
+                Cow::Owned(out.with_name(ca.name().clone())),
+                Cow::Owned(rewriter.format(fmt)),
+            )
+        },
+        None => (Cow::Borrowed(ca), Cow::Borrowed(fmt)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rewrite() {
+        let rewriter = DateRewriter::new("%d de %B de %Y", DateLocale::Spanish).unwrap();
+        assert_eq!(rewriter.rewrite("5 de Marzo de 2024"), "5 de March de 2024");
+
+        // "mar" is both an abbreviated weekday and month in Spanish.
+        let rewriter = DateRewriter::new("%a %d %b %Y", DateLocale::Spanish).unwrap();
+        assert_eq!(rewriter.rewrite("mar 05 mar 2024"), "Tue 05 Mar 2024");
+
+        let rewriter = DateRewriter::new("%A, %d. %B %Y", DateLocale::German).unwrap();
+        assert_eq!(
+            rewriter.rewrite("Dienstag, 05. März 2024"),
+            "Tuesday, 05. March 2024"
+        );
+        let rewriter = DateRewriter::new("%A %d/%m", DateLocale::Portuguese).unwrap();
+        assert_eq!(rewriter.rewrite("terça-feira 05/03"), "Tuesday 05/03");
+
+        let rewriter = DateRewriter::new("%G-W%V", DateLocale::English).unwrap();
+        assert_eq!(rewriter.format("%G-W%V"), "%G-W%V %u");
+        assert_eq!(rewriter.rewrite("2024-W10"), "2024-W10 1");
+
+        assert!(DateRewriter::new("%d %B %Y", DateLocale::English).is_none());
+        assert!(DateRewriter::new("%Y-%m-%d", DateLocale::French).is_none());
+    }
+}
@@ -1,5 +1,12 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
+use std::borrow::Cow;
+
 use chrono::DateTime;
+#[cfg(feature = "dtype-duration")]
+pub use duration::{parse_duration, ClockAmbiguity};
+mod locale;
 mod patterns;
 mod strptime;
 use chrono::format::ParseErrorKind;
@@ -90,6 +97,29 @@ fn sniff_fmt_time(ca_string: &StringChunked) -> PolarsResult<&'static str> {
 }
 
 pub trait StringMethods: AsString {
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values and return a [`DurationChunked`]
+    /// See [`parse_duration`] for the accepted forms, values that are not a duration become null.
+    fn as_duration(
+        &self,
+        tu: TimeUnit,
+        ambiguous: ClockAmbiguity,
+    ) -> PolarsResult<DurationChunked> {
+        duration::as_duration(self.as_string(), tu, ambiguous)
+    }
+
+    /// Rewrites the month and weekday names of `fmt` from `locale` to English, so that the
+    /// values can be parsed by [`StringMethods::as_date`] and [`StringMethods::as_datetime`]
+    /// with the returned format. ISO week dates without a weekday, e.g. `%G-W%V`, are
+    /// completed to the Monday of that week.
+    fn delocalize_dates<'a>(
+        &'a self,
+        fmt: &'a str,
+        locale: DateLocale,
+    ) -> (Cow<'a, StringChunked>, Cow<'a, str>) {
+        locale::rewrite_dates(self.as_string(), fmt, locale)
+    }
+
     #[cfg(feature = "dtype-time")]
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
@@ -8,11 +8,12 @@ use regex::Regex;
 
 use crate::chunkedarray::{polars_bail, PolarsResult};
 
-static HOUR_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?[HkIl]").unwrap());
+pub(super) static HOUR_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?[HkIl]").unwrap());
 static MINUTE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?M").unwrap());
 static SECOND_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?S").unwrap());
 static TWELVE_HOUR_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?[Il]").unwrap());
 static MERIDIEM_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"%[_-]?[pP]").unwrap());
+static QUARTER_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"(^|[^%])(%%)*%Q").unwrap());
 
 #[inline]
 fn update_and_parse<T: atoi::FromRadix10>(
@@ -72,12 +73,18 @@ pub(super) fn compile_fmt(fmt: &str) -> PolarsResult<String> {
             Please either specify both 12-hour directive and meridiem directive, or neither.");
     }
 
-    Ok(fmt
+    let fmt = fmt
         .replace("%D", "%m/%d/%y")
         .replace("%R", "%H:%M")
         .replace("%T", "%H:%M:%S")
         .replace("%X", "%H:%M:%S")
-        .replace("%F", "%Y-%m-%d"))
+        .replace("%F", "%Y-%m-%d");
+    // Chrono has no quarter directive, only the fast parser can handle it.
+    if QUARTER_PATTERN.is_match(&fmt) && fmt_len(fmt.as_bytes()).is_none() {
+        polars_bail!(ComputeError: "Invalid format string: \
+            The quarter directive %Q can only be combined with %Y, %y, %m, %b, %d, %j, %H, %M, %S and %f.");
+    }
+    Ok(fmt)
 }
 
 #[derive(Default, Clone)]
@@ -113,6 +120,7 @@ impl StrpTimeState {
         let mut min: u32 = 0;
         let mut sec: u32 = 0;
         let mut nano: u32 = 0;
+        let mut ordinal: Option<u32> = None;
 
         let mut fmt_iter = fmt.iter();
 
@@ -142,6 +150,19 @@ impl StrpTimeState {
                     b'd' => {
                         (day, offset) = update_and_parse(2, offset, val)?;
                     },
+                    b'j' => {
+                        let day_of_year;
+                        (day_of_year, offset) = update_and_parse(3, offset, val)?;
+                        ordinal = Some(day_of_year);
+                    },
+                    b'Q' => {
+                        let quarter: u32;
+                        (quarter, offset) = update_and_parse(1, offset, val)?;
+                        if !(1..=4).contains(&quarter) {
+                            return None;
+                        }
+                        month = (quarter - 1) * 3 + 1;
+                    },
                     b'H' => {
                         (hour, offset) = update_and_parse(2, offset, val)?;
                     },
@@ -193,8 +214,11 @@ impl StrpTimeState {
         }
         // all values processed
         if offset == val.len() {
-            NaiveDate::from_ymd_opt(year, month, day)
-                .and_then(|nd| nd.and_hms_nano_opt(hour, min, sec, nano))
+            match ordinal {
+                Some(ordinal) => NaiveDate::from_yo_opt(year, ordinal),
+                None => NaiveDate::from_ymd_opt(year, month, day),
+            }
+            .and_then(|nd| nd.and_hms_nano_opt(hour, min, sec, nano))
         }
         // remaining values did not match pattern
         else {
@@ -213,6 +237,8 @@ pub(super) fn fmt_len(fmt: &[u8]) -> Option<u16> {
                 b'Y' => cnt += 4,
                 b'y' => cnt += 2,
                 b'd' => cnt += 2,
+                b'j' => cnt += 3,
+                b'Q' => cnt += 1,
                 b'm' => cnt += 2,
                 b'b' => cnt += 3,
                 b'H' => cnt += 2,
@@ -316,6 +342,29 @@ mod test {
                         .unwrap(),
                 ),
             ),
+            (
+                "2024-Q3",
+                "%Y-Q%Q",
+                7,
+                Some(
+                    NaiveDate::from_ymd_opt(2024, 7, 1)
+                        .unwrap()
+                        .and_hms_nano_opt(0, 0, 0, 0)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "2024-219 07:45",
+                "%Y-%j %H:%M",
+                14,
+                Some(
+                    NaiveDate::from_ymd_opt(2024, 8, 6)
+                        .unwrap()
+                        .and_hms_nano_opt(7, 45, 0, 0)
+                        .unwrap(),
+                ),
+            ),
+            ("2024-Q5", "%Y-Q%Q", 7, None),
         ];
 
         for (val, fmt, len, expected) in patterns {
@@ -0,0 +1,275 @@
+//! Funnel and cohort retention analysis of event data.
+use std::collections::BTreeMap;
+
+use arrow::legacy::time_zone::Tz;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use chrono::{Datelike, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+/// Returns the time of each event as a timestamp in the time unit of `time`, where dates are
+/// in milliseconds.
+fn event_times(time: &Series) -> PolarsResult<(Int64Chunked, TimeUnit)> {
+    match time.dtype() {
+        DataType::Date => {
+            let time = time.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            Ok((time.datetime()?.physical().clone(), TimeUnit::Milliseconds))
+        },
+        DataType::Datetime(tu, _) => Ok((time.datetime()?.physical().clone(), *tu)),
+        dt => {
+            polars_bail!(InvalidOperation: "expected the event times to be a date or datetime, got {}", dt)
+        },
+    }
+}
+
+type TruncateFn = fn(&Window, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+fn duration_in(duration: &Duration, tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => duration.duration_ns(),
+        TimeUnit::Microseconds => duration.duration_us(),
+        TimeUnit::Milliseconds => duration.duration_ms(),
+    }
+}
+
+/// Returns the number of consecutive steps of a funnel that the events reach within `window`.
+///
+/// `time` holds the time of each event and `steps` whether each event is a step of the funnel.
+/// A chain starts at an event of the first step, and every next step must be reached by a
+/// later event within `window` of the start of the chain. An event advances a chain by at most
+/// one step, events with a null time are ignored and null steps count as `false`.
+pub fn funnel_level(time: &Series, steps: &[Series], window: Duration) -> PolarsResult<u32> {
+    polars_ensure!(
+        !window.negative() && window.months() == 0,
+        InvalidOperation: "funnel window must be a positive duration without months, got {}", window
+    );
+    let (times, tu) = event_times(time)?;
+    let window = duration_in(&window, tu);
+    let steps = steps
+        .iter()
+        .map(|s| {
+            polars_ensure!(
+                s.len() == time.len(),
+                ShapeMismatch: "funnel step '{}' has length {}, expected {}", s.name(), s.len(), time.len()
+            );
+            Ok(s.bool()?.iter().map(|v| v == Some(true)).collect::<Vec<_>>())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut events = times
+        .iter()
+        .enumerate()
+        .filter_map(|(i, t)| Some((t?, i)))
+        .collect::<Vec<_>>();
+    events.sort_by_key(|(t, _)| *t);
+
+    // The latest start of a chain that reached each step, as a later start leaves more of the
+    // window for the next steps.
+    let mut starts: Vec<Option<i64>> = vec![None; steps.len()];
+    for (t, i) in events {
+        // Walk the steps backwards so that this event cannot advance the chain it just extended.
+        for k in (0..steps.len()).rev() {
+            if !steps[k][i] {
+                continue;
+            }
+            if k == 0 {
+                starts[0] = Some(t);
+            } else if let Some(start) = starts[k - 1] {
+                if t - start <= window {
+                    starts[k] = starts[k].max(Some(start));
+                }
+            }
+        }
+    }
+    Ok(starts.iter().take_while(|start| start.is_some()).count() as u32)
+}
+
+/// Computes the cohort retention matrix of the activity of `user` at `time`.
+///
+/// Every user belongs to the cohort of the first period of length `every` in which they are
+/// active. The result is a struct with a row per cohort, holding the start of the cohort period
+/// in the field `cohort` and the number of its users that are active `k` periods later in the
+/// fields `period_0` to `period_{periods - 1}`. Periods start at the same boundaries as
+/// `truncate(every)` and events with a null user or time are ignored.
+pub fn cohort_retention_matrix(
+    user: &Series,
+    time: &Series,
+    every: Duration,
+    periods: usize,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        user.len() == time.len(),
+        ShapeMismatch: "retention users have length {}, but the event times have length {}", user.len(), time.len()
+    );
+    polars_ensure!(
+        !every.negative() && !every.is_zero(),
+        InvalidOperation: "retention period must be a positive duration, got {}", every
+    );
+    polars_ensure!(
+        every.months() == 0 || every.months_only(),
+        InvalidOperation: "retention period cannot mix months with other units, got {}", every
+    );
+    polars_ensure!(periods > 0, InvalidOperation: "retention matrix needs at least one period");
+
+    let (times, tu) = event_times(time)?;
+    let tz = match time.dtype() {
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(tz)) => tz.parse::<Tz>().ok(),
+        _ => None,
+    };
+    let window = Window::new(every, every, Duration::new(0));
+    let (truncate, to_datetime): (TruncateFn, fn(i64) -> NaiveDateTime) = match tu {
+        TimeUnit::Nanoseconds => (Window::truncate_ns, timestamp_ns_to_datetime),
+        TimeUnit::Microseconds => (Window::truncate_us, timestamp_us_to_datetime),
+        TimeUnit::Milliseconds => (Window::truncate_ms, timestamp_ms_to_datetime),
+    };
+    let period_starts = times
+        .iter()
+        .map(|t| t.map(|t| truncate(&window, t, tz.as_ref())).transpose())
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // The number of periods between the starts of two periods.
+    let step = duration_in(&every, tu);
+    let offset = |cohort: i64, start: i64| -> usize {
+        if every.months_only() {
+            // A period start in a time zone is less than a day away from the start of its
+            // month in UTC, so the middle of the month is in the same month in any time zone.
+            let half_month = 15 * duration_in(&Duration::parse("1d"), tu);
+            let month = |t: i64| {
+                let dt = to_datetime(t + half_month);
+                dt.year() as i64 * 12 + dt.month0() as i64
+            };
+            ((month(start) - month(cohort)) / every.months()) as usize
+        } else {
+            // Rounding absorbs days that are shorter or longer due to daylight saving time.
+            ((start - cohort) as f64 / step as f64).round() as usize
+        }
+    };
+
+    let null_users = user.is_null();
+    let groups = user.group_tuples(true, false)?.into_idx();
+    let mut matrix = BTreeMap::<i64, Vec<u32>>::new();
+    let mut active = vec![false; periods];
+    for (first, idx) in groups.iter() {
+        if null_users.get(first as usize) == Some(true) {
+            continue;
+        }
+        let Some(cohort) = idx.iter().filter_map(|i| period_starts[*i as usize]).min() else {
+            continue;
+        };
+        active.fill(false);
+        for start in idx.iter().filter_map(|i| period_starts[*i as usize]) {
+            if let Some(active) = active.get_mut(offset(cohort, start)) {
+                *active = true;
+            }
+        }
+        let counts = matrix.entry(cohort).or_insert_with(|| vec![0; periods]);
+        for (count, active) in counts.iter_mut().zip(&active) {
+            *count += *active as u32;
+        }
+    }
+
+    let cohorts = Int64Chunked::from_iter_values("cohort".into(), matrix.keys().copied());
+    let cohorts = match time.dtype() {
+        DataType::Datetime(tu, tz) => cohorts.into_datetime(*tu, tz.clone()).into_series(),
+        _ => cohorts
+            .into_datetime(tu, None)
+            .into_series()
+            .cast(&DataType::Date)?,
+    };
+    let mut fields = vec![cohorts];
+    fields.extend((0..periods).map(|k| {
+        UInt32Chunked::from_iter_values(
+            format_pl_smallstr!("period_{}", k),
+            matrix.values().map(|counts| counts[k]),
+        )
+        .into_series()
+    }));
+    Ok(StructChunked::from_series(user.name().clone(), &fields)?.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn datetimes(name: &str, values: &[&str]) -> Series {
+        let timestamps = values.iter().map(|v| {
+            NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M")
+                .unwrap()
+                .and_utc()
+                .timestamp_millis()
+        });
+        Int64Chunked::from_iter_values(name.into(), timestamps)
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series()
+    }
+
+    #[test]
+    fn test_window_funnel() {
+        let time = datetimes(
+            "time",
+            &[
+                "2024-01-01 10:00",
+                "2024-01-01 10:05",
+                "2024-01-01 10:20",
+                "2024-01-01 11:30",
+                "2024-01-01 11:40",
+            ],
+        );
+        let event = Series::new("event".into(), ["view", "cart", "view", "buy", "cart"]);
+        let steps = ["view", "cart", "buy"]
+            .map(|step| event.equal(step).unwrap().into_series())
+            .to_vec();
+
+        // The purchase is more than an hour after the first view, and the cart after the second
+        // view comes after the purchase.
+        let level = funnel_level(&time, &steps, Duration::parse("1h")).unwrap();
+        assert_eq!(level, 2);
+        let level = funnel_level(&time, &steps, Duration::parse("2h")).unwrap();
+        assert_eq!(level, 3);
+        // The order of the rows does not matter.
+        let reversed = steps.iter().map(|s| s.reverse()).collect::<Vec<_>>();
+        let level = funnel_level(&time.reverse(), &reversed, Duration::parse("2h")).unwrap();
+        assert_eq!(level, 3);
+        assert!(funnel_level(&time, &steps, Duration::parse("1mo")).is_err());
+    }
+
+    #[test]
+    fn test_retention_matrix() {
+        let user = Series::new("user".into(), [1, 1, 2, 2, 2, 3, 1]);
+        let time = datetimes(
+            "time",
+            &[
+                "2024-01-01 10:00",
+                "2024-01-09 10:00",
+                "2024-01-02 10:00",
+                "2024-01-20 10:00",
+                "2024-01-03 10:00",
+                "2024-01-10 10:00",
+                "2024-01-15 10:00",
+            ],
+        );
+        let out = cohort_retention_matrix(&user, &time, Duration::parse("1w"), 3).unwrap();
+        let out = out.struct_().unwrap().clone().unnest();
+        let expected = DataFrame::new(vec![
+            datetimes("cohort", &["2024-01-01 00:00", "2024-01-08 00:00"]),
+            Series::new("period_0".into(), [2u32, 1]),
+            Series::new("period_1".into(), [1u32, 0]),
+            Series::new("period_2".into(), [2u32, 0]),
+        ])
+        .unwrap();
+        assert!(out.equals(&expected));
+
+        let out = cohort_retention_matrix(&user, &time, Duration::parse("1mo"), 1).unwrap();
+        let out = out.struct_().unwrap().clone().unnest();
+        assert_eq!(out.height(), 1);
+        assert_eq!(
+            out.column("period_0").unwrap().u32().unwrap().get(0),
+            Some(3)
+        );
+    }
+}
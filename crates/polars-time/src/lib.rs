@@ -1,10 +1,13 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #[cfg(feature = "timezones")]
 mod base_utc_offset;
+mod calendar;
 pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+#[cfg(feature = "funnel")]
+mod funnel;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -22,9 +25,12 @@ mod windows;
 
 #[cfg(feature = "timezones")]
 pub use base_utc_offset::*;
+pub use calendar::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+#[cfg(feature = "funnel")]
+pub use funnel::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
@@ -253,17 +253,33 @@ pub trait TemporalMethods: AsSeries {
 
     /// Convert Time into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// Durations take `"iso"` or `"humanized"` as format instead.
     fn to_string(&self, format: &str) -> PolarsResult<Series> {
+        self.to_string_localized(format, DateLocale::default())
+    }
+
+    /// Convert Time into String with the given format, writing month and weekday names in
+    /// `locale`. Besides the chrono directives, `%Q` is replaced by the quarter.
+    ///
+    /// Durations take `"iso"` or `"humanized"` as format instead.
+    fn to_string_localized(&self, format: &str, locale: DateLocale) -> PolarsResult<Series> {
         let s = self.as_series();
         match s.dtype() {
             #[cfg(feature = "dtype-date")]
-            DataType::Date => s.date().map(|ca| Ok(ca.to_string(format)?.into_series()))?,
+            DataType::Date => s
+                .date()
+                .map(|ca| Ok(ca.to_string_localized(format, locale)?.into_series()))?,
             #[cfg(feature = "dtype-datetime")]
             DataType::Datetime(_, _) => s
                 .datetime()
-                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
+                .map(|ca| Ok(ca.to_string_localized(format, locale)?.into_series()))?,
             #[cfg(feature = "dtype-time")]
             DataType::Time => s.time().map(|ca| ca.to_string(format).into_series()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => s
+                .duration()
+                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             dt => polars_bail!(opq = to_string, dt),
         }
     }
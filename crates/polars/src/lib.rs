@@ -183,7 +183,8 @@
 //! * `sql` - Pass SQL queries to polars.
 //! * `streaming` - Be able to process datasets that are larger than RAM.
 //! * `random` - Generate arrays with randomly sampled values
-//! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
+//! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/), and compute Gram
+//!   matrices and matrix products of [`DataFrame`]s.
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
@@ -204,6 +205,7 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write Parquet files with modular encryption
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
@@ -224,7 +226,10 @@
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
+//!     - `fuzzy_join` - Join on approximately equal strings, for record linkage.
+//!     - `dedupe_similar` - Cluster the rows of a [`DataFrame`] that are similar to each other.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
+//!     - `list_struct_join` - Join on a key nested inside a `List(Struct)` column.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
@@ -273,6 +278,11 @@
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
 //!     - `find_many` - Find/replace multiple string patterns at once.
+//!     - `funnel` - Window funnel and cohort retention analysis of event data.
+//!     - `market` - OHLC, VWAP and TWAP aggregations, drawdowns, cumulative returns and rolling
+//!       Sharpe and Sortino ratios of market data.
+//!     - `sparse` - [Sparse numeric columns](polars_ops::chunked_array::SparseChunked) that only
+//!       store the values that differ from a fill value.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
 //!
@@ -634,3 +634,142 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "list_struct_join")]
+fn test_join_list_struct() -> PolarsResult<()> {
+    let events = |user_ids: &[i64], amounts: &[i64]| {
+        StructChunked::from_series(
+            "".into(),
+            &[
+                Series::new("user_id".into(), user_ids),
+                Series::new("amount".into(), amounts),
+            ],
+        )
+        .map(|ca| ca.into_series())
+    };
+    let df = df![
+        "session" => [1, 2],
+        "payload" => [events(&[1, 3, 2], &[10, 20, 30])?, events(&[2], &[40])?],
+    ]?;
+    let users = df![
+        "id" => [1i64, 2],
+        "name" => ["alice", "bob"],
+    ]?;
+
+    let out = df.join_list_struct(&users, "payload", "user_id", "id", None)?;
+    assert_eq!(out.shape(), (2, 2));
+
+    let payload = out.column("payload")?.explode()?;
+    let payload = payload.struct_()?;
+    assert_eq!(
+        Vec::from(payload.field_by_name("name")?.str()?),
+        &[Some("alice"), None, Some("bob"), Some("bob")]
+    );
+    assert_eq!(
+        Vec::from(payload.field_by_name("amount")?.i64()?),
+        &[Some(10), Some(20), Some(30), Some(40)]
+    );
+
+    // Duplicate keys on the right would change the list lengths.
+    let users = df![
+        "id" => [1i64, 1],
+        "name" => ["alice", "bob"],
+    ]?;
+    assert!(df
+        .join_list_struct(&users, "payload", "user_id", "id", None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "fuzzy_join")]
+fn test_join_fuzzy() -> PolarsResult<()> {
+    let customers = df![
+        "id" => [1, 2, 3, 4],
+        "name" => [Some("Jonathan Smith"), Some("Maria Garcia"), Some("Bob"), None],
+    ]?;
+    let crm = df![
+        "name" => ["Jon Smith", "Marla Garcia", "Jonathon Smith", "Maria Garcia", "Robert"],
+    ]?;
+
+    let options = FuzzyJoinOptions::levenshtein(2).with_score_column(Some("distance".into()));
+    let out = customers.join_fuzzy(&crm, "name", "name", &options, None)?;
+    assert_eq!(
+        out.get_column_names(),
+        &["id", "name", "name_right", "distance"]
+    );
+    assert_eq!(
+        Vec::from(out.column("name_right")?.str()?),
+        &[Some("Jonathon Smith"), Some("Maria Garcia")]
+    );
+    assert_eq!(
+        Vec::from(out.column("distance")?.u32()?),
+        &[Some(1), Some(0)]
+    );
+
+    let options = options.with_keep(FuzzyKeep::All);
+    let out = customers.join_fuzzy(&crm, "name", "name", &options, None)?;
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(1), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("name_right")?.str()?),
+        &[
+            Some("Jonathon Smith"),
+            Some("Marla Garcia"),
+            Some("Maria Garcia")
+        ]
+    );
+
+    let options = FuzzyJoinOptions::jaro_winkler(0.9);
+    let out = customers.join_fuzzy(&crm, "name", "name", &options, None)?;
+    assert_eq!(
+        Vec::from(out.column("name_right")?.str()?),
+        &[Some("Jonathon Smith"), Some("Maria Garcia")]
+    );
+
+    assert!(customers
+        .join_fuzzy(&crm, "id", "name", &options, None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dedupe_similar")]
+fn test_dedupe_similar() -> PolarsResult<()> {
+    let df = df![
+        "name" => [Some("Jonathan Smith"), Some("Maria Garcia"), Some("Jonathon Smith"), Some("Jon Smyth"), None, Some("Marla Garcia")],
+        "birth_year" => [1980, 1975, 1981, 1980, 1990, 1960],
+    ]?;
+
+    let options = DedupeOptions::new(
+        "name".into(),
+        FuzzyMetric::Levenshtein { max_distance: 6 },
+        0.0,
+    );
+    let clusters = df.dedupe_similar(&options)?;
+    assert_eq!(
+        Vec::from(&clusters),
+        &[Some(0), Some(1), Some(0), Some(0), Some(2), Some(1)]
+    );
+
+    // The birth years of Maria and Marla are too far apart.
+    let options = DedupeOptions::new(
+        "name".into(),
+        FuzzyMetric::Levenshtein { max_distance: 6 },
+        0.85,
+    )
+    .with_column("name".into(), Similarity::JaroWinkler)
+    .with_column("birth_year".into(), Similarity::Numeric { scale: 5.0 });
+    let clusters = df.dedupe_similar(&options)?;
+    assert_eq!(
+        Vec::from(&clusters),
+        &[Some(0), Some(1), Some(0), Some(0), Some(2), Some(3)]
+    );
+
+    let options = options.with_column("name".into(), Similarity::Numeric { scale: 1.0 });
+    assert!(df.dedupe_similar(&options).is_err());
+    Ok(())
+}
@@ -1278,6 +1278,275 @@ fn test_escaping_quotes() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_escaped_record_separators() -> PolarsResult<()> {
+    // Records end with `\x1e` and may contain escaped separators and record separators.
+    let mut csv = String::from("id,text\x1e");
+    let mut expected = vec![];
+    for i in 0..5000 {
+        let text = match i % 3 {
+            0 => format!("line {i}\\\x1enext"),
+            1 => format!("a\\,b \\\\ {i}"),
+            _ => format!("\"quoted\\\" {i}\""),
+        };
+        csv.push_str(&format!("{i},{text}\x1e"));
+        expected.push(match i % 3 {
+            0 => format!("line {i}\x1enext"),
+            1 => format!("a,b \\ {i}"),
+            _ => format!("quoted\" {i}"),
+        });
+    }
+
+    for n_threads in [1, 4] {
+        let df = CsvReadOptions::default()
+            .with_n_threads(Some(n_threads))
+            .with_chunk_size(64)
+            .map_parse_options(|opts| opts.with_eol_char(b'\x1e').with_escape_char(Some(b'\\')))
+            .into_reader_with_file_handle(Cursor::new(csv.as_bytes()))
+            .finish()?;
+        assert_eq!(df.shape(), (5000, 2));
+        assert_eq!(df.column("id")?.dtype(), &DataType::Int64);
+        let text = df.column("text")?.str()?;
+        assert!(text
+            .into_no_null_iter()
+            .eq(expected.iter().map(|s| s.as_str())));
+    }
+
+    // Without an escape character the escaped record separators split the records.
+    let df = CsvReadOptions::default()
+        .with_ignore_errors(true)
+        .map_parse_options(|opts| opts.with_eol_char(b'\x1e').with_truncate_ragged_lines(true))
+        .into_reader_with_file_handle(Cursor::new(csv.as_bytes()))
+        .finish()?;
+    assert_ne!(df.height(), 5000);
+    Ok(())
+}
+
+#[test]
+fn test_write_escape_char() -> PolarsResult<()> {
+    let mut df = df![
+        "id" => [1i64, 2],
+        "text" => ["say \"hi\"", r"C:\dir"],
+    ]?;
+
+    let mut buf = vec![];
+    CsvWriter::new(&mut buf)
+        .with_quote_style(QuoteStyle::Always)
+        .with_escape_char(Some(b'\\'))
+        .finish(&mut df)?;
+    let csv = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        csv,
+        "\"id\",\"text\"\n\"1\",\"say \\\"hi\\\"\"\n\"2\",\"C:\\\\dir\"\n"
+    );
+
+    let mut df = df![
+        "id" => [1i64, 2, 3],
+        "text" => ["a,b", "line\nbreak", r"C:\dir"],
+    ]?;
+    let mut buf = vec![];
+    CsvWriter::new(&mut buf)
+        .with_quote_style(QuoteStyle::Never)
+        .with_escape_char(Some(b'\\'))
+        .finish(&mut df)?;
+    let csv = String::from_utf8(buf).unwrap();
+    assert_eq!(csv, "id,text\n1,a\\,b\n2,line\\\nbreak\n3,C:\\\\dir\n");
+
+    let out = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_escape_char(Some(b'\\')))
+        .into_reader_with_file_handle(Cursor::new(csv.as_bytes()))
+        .finish()?;
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_multi_byte_separator() -> PolarsResult<()> {
+    let mut df = df![
+        "id" => [1i64, 2, 3],
+        "text" => ["a,b", "pipe | in text", "x||y"],
+        "value" => [Some(1.5), None, Some(-2.0)],
+    ]?;
+
+    let mut buf = vec![];
+    CsvWriter::new(&mut buf)
+        .with_multi_byte_separator(Some("||".into()))
+        .finish(&mut df)?;
+    let csv = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        csv,
+        "id||text||value\n1||a,b||1.5\n2||\"pipe | in text\"||\n3||\"x||y\"||-2.0\n"
+    );
+
+    for n_threads in [1, 4] {
+        let out = CsvReadOptions::default()
+            .with_n_threads(Some(n_threads))
+            .with_chunk_size(16)
+            .map_parse_options(|opts| opts.with_multi_byte_separator(Some("||".into())))
+            .into_reader_with_file_handle(Cursor::new(csv.as_bytes()))
+            .finish()?;
+        assert!(out.equals_missing(&df));
+    }
+
+    let csv = "a\t|\tb\n1\t|\t\tx\n2\t|\ty|\n";
+    let out = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_multi_byte_separator(Some("\t|\t".into())))
+        .into_reader_with_file_handle(Cursor::new(csv.as_bytes()))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => ["\tx", "y|"],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_on_error() -> PolarsResult<()> {
+    let csv = "a,b,c\n1,x,1.5\n2,y,oops\n3,z,2.5,extra\nbad,w,3.0\n5,v,4.0\n";
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+        Field::new("c".into(), DataType::Float64),
+    ]);
+    let read = |on_error| {
+        CsvReadOptions::default()
+            .with_schema(Some(Arc::new(schema.clone())))
+            .with_on_error(on_error)
+            .into_reader_with_file_handle(Cursor::new(csv))
+    };
+
+    assert!(read(CsvOnError::Raise).finish().is_err());
+    assert!(matches!(
+        read(CsvOnError::Capture).finish(),
+        Err(PolarsError::InvalidOperation(_))
+    ));
+
+    let df = read(CsvOnError::Skip).finish()?;
+    assert_eq!(df.column("a")?.i64()?.to_vec(), &[Some(1), Some(5)]);
+
+    let (df, bad_rows) = read(CsvOnError::Capture).finish_with_bad_rows()?;
+    assert_eq!(df.column("a")?.i64()?.to_vec(), &[Some(1), Some(5)]);
+    assert_eq!(df.column("c")?.f64()?.to_vec(), &[Some(1.5), Some(4.0)]);
+    assert_eq!(
+        bad_rows.column("line_number")?.idx()?.to_vec(),
+        &[Some(3), Some(4), Some(5)]
+    );
+    let lines = bad_rows.column("line")?.str()?;
+    assert!(lines
+        .into_no_null_iter()
+        .eq(["2,y,oops", "3,z,2.5,extra", "bad,w,3.0"]));
+    let errors = bad_rows.column("error")?.str()?;
+    assert!(errors.get(0).unwrap().contains("could not parse `oops`"));
+    assert!(errors.get(1).unwrap().contains("more fields than defined"));
+    assert!(errors.get(2).unwrap().contains("could not parse `bad`"));
+    Ok(())
+}
+
+#[test]
+fn test_on_error_capture_parallel() -> PolarsResult<()> {
+    let mut csv = String::from("id,value\r\n");
+    for i in 0..10_000 {
+        if i % 7 == 3 {
+            csv.push_str(&format!("{i},\"not a number\"\r\n"));
+        } else {
+            csv.push_str(&format!("{i},{}\r\n", i * 2));
+        }
+    }
+
+    let (df, bad_rows) = CsvReadOptions::default()
+        .with_n_threads(Some(4))
+        .with_chunk_size(100)
+        .with_infer_schema_length(Some(3))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 0,
+        }))
+        .with_on_error(CsvOnError::Capture)
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish_with_bad_rows()?;
+
+    let bad_ids = (0..10_000).filter(|i| i % 7 == 3).collect::<Vec<_>>();
+    assert_eq!(df.height(), 10_000 - bad_ids.len());
+    let index = df.column("index")?.idx()?;
+    assert!(index.into_no_null_iter().eq(0..df.height() as IdxSize));
+    let ids = df.column("id")?.i64()?;
+    assert!(ids.into_no_null_iter().all(|id| id % 7 != 3));
+
+    let line_numbers = bad_rows.column("line_number")?.idx()?;
+    assert!(line_numbers
+        .into_no_null_iter()
+        .eq(bad_ids.iter().map(|&i| i as IdxSize + 2)));
+    let lines = bad_rows.column("line")?.str()?;
+    assert_eq!(lines.get(0), Some("3,\"not a number\""));
+    Ok(())
+}
+
+#[test]
+fn test_infer_schema_only() -> PolarsResult<()> {
+    let csv = "a,b,c,d\n1,x,1.5,\n2,y,2,true\n\"z\",3,,false\n";
+    let inference = CsvReadOptions::default()
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "d".into(),
+            DataType::String,
+        )]))))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .infer_schema_only()?;
+
+    assert_eq!(inference.rows_read, 3);
+    let dtypes = inference.schema.iter_values().cloned().collect::<Vec<_>>();
+    assert_eq!(
+        dtypes,
+        &[
+            DataType::String,
+            DataType::String,
+            DataType::Float64,
+            DataType::String
+        ]
+    );
+
+    let [a, b, c, d] = inference.columns.as_slice() else {
+        panic!("expected a report per column")
+    };
+    fn samples(column: &ColumnInference) -> Vec<(DataType, &str, usize, usize)> {
+        column
+            .samples
+            .iter()
+            .map(|s| (s.dtype.clone(), s.value.as_str(), s.line_number, s.count))
+            .collect()
+    }
+    assert_eq!(
+        samples(a),
+        &[(DataType::Int64, "1", 2, 2), (DataType::String, "z", 4, 1)]
+    );
+    assert_eq!(a.conflicts().len(), 2);
+    assert_eq!(
+        samples(b),
+        &[(DataType::String, "x", 2, 2), (DataType::Int64, "3", 4, 1)]
+    );
+    // Integers and floats don't conflict.
+    assert_eq!(
+        samples(c),
+        &[
+            (DataType::Float64, "1.5", 2, 1),
+            (DataType::Int64, "2", 3, 1)
+        ]
+    );
+    assert!(c.conflicts().is_empty());
+    assert_eq!(c.null_count, 1);
+    assert!(d.overwritten && !c.overwritten);
+    assert_eq!(d.samples.len(), 1);
+    assert!(d.conflicts().is_empty());
+
+    let inference = CsvReadOptions::default()
+        .with_path(Some(FOODS_CSV))
+        .infer_schema_only()?;
+    let expected = CsvReader::new(std::fs::File::open(FOODS_CSV)?).finish()?;
+    assert_eq!(*inference.schema, expected.schema());
+    assert!(CsvReadOptions::default().infer_schema_only().is_err());
+    Ok(())
+}
+
 #[test]
 fn test_header_only() -> PolarsResult<()> {
     let csv = "a,b,c";
@@ -1364,6 +1633,66 @@ fn test_try_parse_dates_3380() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_formats() -> PolarsResult<()> {
+    use polars_core::export::chrono;
+
+    let csv = "day,compact,ts,name
+31/01/2021,20210131,2021-01-31 10:15,a
+01/02/2021,20210201,2021-02-01 23:59,b
+,20210202,,c
+";
+    let read = |schema_overwrite: Option<SchemaRef>, ignore_errors: bool, csv: &str| {
+        CsvReadOptions::default()
+            .with_chunk_size(16)
+            .with_ignore_errors(ignore_errors)
+            .with_schema_overwrite(schema_overwrite)
+            .with_datetime_formats(vec![
+                ("day".into(), "%d/%m/%Y".into()),
+                ("compact".into(), "%Y%m%d".into()),
+                ("ts".into(), "%Y-%m-%d %H:%M".into()),
+            ])
+            .into_reader_with_file_handle(Cursor::new(csv.to_string()))
+            .finish()
+    };
+
+    let df = read(None, false, csv)?;
+    let date = |d| chrono::NaiveDate::from_ymd_opt(2021, 1, 31).unwrap() + chrono::Days::new(d);
+    let datetime = |d, h, m| date(d).and_hms_opt(h, m, 0).unwrap();
+    let ts = Series::new(
+        "ts".into(),
+        [Some(datetime(0, 10, 15)), Some(datetime(1, 23, 59)), None],
+    );
+    let expected = DataFrame::new(vec![
+        Series::new("day".into(), [Some(date(0)), Some(date(1)), None]),
+        Series::new("compact".into(), [date(0), date(1), date(2)]),
+        ts.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+        Series::new("name".into(), ["a", "b", "c"]),
+    ])?;
+    assert!(df.equals_missing(&expected));
+
+    // The dtype in the schema overwrite is kept.
+    let schema_overwrite = Schema::from_iter([Field::new(
+        "ts".into(),
+        DataType::Datetime(TimeUnit::Milliseconds, None),
+    )]);
+    let df = read(Some(Arc::new(schema_overwrite)), false, csv)?;
+    assert_eq!(
+        df.column("ts")?,
+        &ts.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+    );
+    let schema_overwrite = Schema::from_iter([Field::new("day".into(), DataType::Int64)]);
+    assert!(read(Some(Arc::new(schema_overwrite)), false, csv).is_err());
+
+    let csv = "day,compact,ts\n31/01/2021,2021-01-31,2021-01-31 10:15\n";
+    assert!(read(None, false, csv).is_err());
+    let df = read(None, true, csv)?;
+    assert_eq!(df.column("compact")?.dtype(), &DataType::Date);
+    assert_eq!(df.column("compact")?.null_count(), 1);
+    Ok(())
+}
+
 #[test]
 fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     let csv = r#"
@@ -1396,3 +1725,32 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+fn test_write_wide_frame_parallel() -> PolarsResult<()> {
+    let columns = (0..200i64)
+        .map(|i| {
+            let values = (0..50)
+                .map(|j| (j % 3 != 0).then_some(i * j))
+                .collect::<Vec<_>>();
+            Series::new(format!("c{i}").into(), values)
+        })
+        .collect::<Vec<_>>();
+    let mut df = DataFrame::new(columns)?;
+
+    let write = |df: &mut DataFrame, n_threads| -> PolarsResult<Vec<u8>> {
+        let mut buf = vec![];
+        CsvWriter::new(&mut buf).n_threads(n_threads).finish(df)?;
+        Ok(buf)
+    };
+    let expected = write(&mut df, 1)?;
+    for n_threads in [2, 8, 64] {
+        assert_eq!(write(&mut df, n_threads)?, expected);
+    }
+
+    let out = CsvReadOptions::default()
+        .into_reader_with_file_handle(Cursor::new(expected))
+        .finish()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
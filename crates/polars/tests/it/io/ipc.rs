@@ -151,3 +151,109 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_freeze() -> PolarsResult<()> {
+    let df = df![
+        "id" => (0..10_000).collect::<Vec<i64>>(),
+        "label" => (0..10_000).map(|i| format!("label {}", i % 7)).collect::<Vec<_>>(),
+        "value" => (0..10_000).map(|i| (i % 3 != 0).then_some(i as f64)).collect::<Vec<_>>(),
+    ]?;
+
+    for compression in [IpcCompression::LZ4, IpcCompression::ZSTD] {
+        let mut frozen = df.freeze(compression)?;
+        assert_eq!(frozen.height(), df.height());
+        assert_eq!(frozen.width(), 3);
+        assert!(frozen.compressed_size() < df.estimated_size());
+        assert_eq!(frozen.estimated_size(), frozen.compressed_size());
+
+        assert!(frozen.column("label")?.equals(df.column("label")?));
+        assert!(frozen.estimated_size() > frozen.compressed_size());
+        assert!(frozen.column("missing").is_err());
+        let selected = frozen.select(["value", "id"])?;
+        assert!(selected.equals_missing(&df.select(["value", "id"])?));
+
+        frozen.release();
+        assert_eq!(frozen.estimated_size(), frozen.compressed_size());
+        assert!(frozen.thaw()?.equals_missing(&df));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ooc_dataframe() -> PolarsResult<()> {
+    let df = df![
+        "id" => (0..1000).collect::<Vec<i64>>(),
+        "qty" => (0..1000).map(|i| (i % 5 != 0).then_some(i % 200)).collect::<Vec<_>>(),
+        "label" => (0..1000).map(|i| format!("label {}", i % 7)).collect::<Vec<_>>(),
+    ]?;
+    let batches = (0..4).map(|i| Ok(df.slice(i * 250, 250)));
+    let options = OocOptions::default()
+        .with_chunk_size(100)
+        .with_cache_size(4096);
+    let ooc = OocDataFrame::from_batches(batches, options)?;
+    assert_eq!(ooc.height(), 1000);
+    assert_eq!(ooc.width(), 3);
+    // Each batch of 250 rows is split into chunks of 100, 100 and 50 rows.
+    assert_eq!(ooc.n_chunks(), 12);
+
+    assert!(ooc.collect()?.equals_missing(&df));
+    assert!(ooc.slice(180, 300)?.equals_missing(&df.slice(180, 300)));
+    assert!(ooc.slice(-20, 50)?.equals_missing(&df.tail(Some(20))));
+    assert!(ooc.head(5)?.equals_missing(&df.head(Some(5))));
+    assert!(ooc.tail(5)?.equals_missing(&df.tail(Some(5))));
+
+    let selected = ooc.select(["label", "id"])?;
+    assert_eq!(selected.get_column_names(), ["label", "id"]);
+    assert!(selected
+        .chunk(3)?
+        .equals(&df.select(["label", "id"])?.slice(250, 100)));
+    assert!(ooc.select(["missing"]).is_err());
+    assert!(ooc.chunk(12).is_err());
+
+    let qty = df.column("qty")?;
+    assert_eq!(ooc.null_count("qty")?, qty.null_count());
+    assert_eq!(ooc.min("qty")?.value(), &qty.min_reduce()?.as_any_value());
+    assert_eq!(ooc.max("label")?.value(), &AnyValue::from("label 6"));
+    assert_eq!(ooc.sum("id")?.value(), &AnyValue::Int64(499500));
+    assert_eq!(ooc.mean("id")?, Some(499.5));
+
+    let filtered = ooc.filter(|chunk| Ok(chunk.column("qty")?.i32()?.gt(150)))?;
+    let expected = df.filter(&qty.i32()?.gt(150))?;
+    assert_eq!(filtered.height(), expected.height());
+    assert!(filtered.collect()?.equals_missing(&expected));
+    let empty = ooc.filter(|chunk| Ok(chunk.column("id")?.i64()?.lt(0)))?;
+    assert_eq!(empty.height(), 0);
+    assert_eq!(empty.schema(), ooc.schema());
+    assert_eq!(empty.mean("id")?, None);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_ipc_resumable() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars_ipc_resumable_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    for (i, range) in [0..3, 3..8].into_iter().enumerate() {
+        let mut df = df!["id" => range.collect::<Vec<i64>>()]?;
+        IpcWriter::new(std::fs::File::create(dir.join(format!("part-{i}.ipc")))?)
+            .finish(&mut df)?;
+    }
+
+    let args = ScanArgsIpc {
+        n_rows: Some(3),
+        start_at: Some(ScanPosition::new(0, 2)),
+        ..Default::default()
+    };
+    let (lf, end) = LazyFrame::scan_ipc_resumable(dir.join("*.ipc"), args)?;
+    assert_eq!(end, ScanPosition::new(1, 2));
+    let out = lf.collect()?;
+    assert_eq!(
+        out.column("id")?.i64()?.into_iter().collect::<Vec<_>>(),
+        [Some(2), Some(3), Some(4)]
+    );
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -185,3 +185,155 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_read_ndjson_union_resolution() {
+    let jsonlines = r#"
+    {"a": 1, "b": {"x": 1}, "c": [1]}
+    {"a": "x", "b": {"y": true}, "c": 2}
+    {"a": 2.5, "b": null, "c": null}
+    "#;
+
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .finish()
+        .unwrap();
+    // Only the string fallback keeps the values that aren't strings as JSON text.
+    let expected = df![
+        "a" => [None, Some("x"), None],
+        "c" => [Some(Series::new("".into(), [1i64])), Some(Series::new("".into(), [2i64])), None],
+    ]
+    .unwrap();
+    assert!(df.select(["a", "c"]).unwrap().equals_missing(&expected));
+    assert_eq!(
+        df.column("b").unwrap().dtype(),
+        &DataType::Struct(vec![
+            Field::new("x".into(), DataType::Int64),
+            Field::new("y".into(), DataType::Boolean),
+        ])
+    );
+
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_union_resolution(JsonUnionResolution::StringFallback)
+        .finish()
+        .unwrap();
+    let expected = df![
+        "a" => [Some("1"), Some("x"), Some("2.5")],
+        "c" => [Some("[1]"), Some("2"), None],
+    ]
+    .unwrap();
+    assert!(df.select(["a", "c"]).unwrap().equals_missing(&expected));
+
+    let err = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_union_resolution(JsonUnionResolution::Error)
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("'a'"));
+}
+
+#[test]
+fn test_json_schema_roundtrip() -> PolarsResult<()> {
+    let schema = Schema::from_iter([
+        Field::new("id".into(), DataType::UInt32),
+        Field::new("name".into(), DataType::String),
+        Field::new("score".into(), DataType::Float32),
+        Field::new("day".into(), DataType::Date),
+        Field::new(
+            "ts".into(),
+            DataType::Datetime(TimeUnit::Microseconds, None),
+        ),
+        Field::new("payload".into(), DataType::Binary),
+        Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+        Field::new(
+            "point".into(),
+            DataType::Struct(vec![
+                Field::new("y".into(), DataType::Float64),
+                Field::new("x".into(), DataType::Float64),
+            ]),
+        ),
+    ]);
+
+    let json_schema = schema_to_json_schema(&schema)?;
+    assert!(json_schema.contains(r#""id":{"type":["integer","null"],"format":"uint32"}"#));
+    assert!(
+        json_schema.contains(r#""payload":{"type":["string","null"],"contentEncoding":"base64"}"#)
+    );
+    assert_eq!(json_schema_to_schema(&json_schema)?, schema);
+
+    let json_schema = r#"{
+        "type": "object",
+        "required": ["b"],
+        "properties": {
+            "b": {"type": "integer", "description": "an id"},
+            "a": {"type": ["number", "null"]},
+            "c": {"type": "string", "format": "time"}
+        }
+    }"#;
+    let expected = Schema::from_iter([
+        Field::new("b".into(), DataType::Int64),
+        Field::new("a".into(), DataType::Float64),
+        Field::new("c".into(), DataType::Time),
+    ]);
+    assert_eq!(json_schema_to_schema(json_schema)?, expected);
+
+    assert!(json_schema_to_schema(r#"{"type": "array", "items": {"type": "integer"}}"#).is_err());
+    assert!(json_schema_to_schema(
+        r#"{"type": "object", "properties": {"a": {"type": ["integer", "string"]}}}"#
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_json_schema_enum() -> PolarsResult<()> {
+    let categories = arrow::array::Utf8ViewArray::from_slice_values(["low", "high"]);
+    let schema = Schema::from_iter([Field::new("level".into(), create_enum_dtype(categories))]);
+
+    let json_schema = schema_to_json_schema(&schema)?;
+    assert!(json_schema.contains(r#""enum":["low","high",null]"#));
+    assert_eq!(json_schema_to_schema(&json_schema)?, schema);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_read_ndjson_string_fallback_keeps_json_text() {
+    // With the string fallback, values that aren't strings are kept as their JSON text in the
+    // string column of a conflicting field.
+    let jsonlines = r#"
+    {"a": "x"}
+    {"a": 1}
+    {"a": 2.5}
+    {"a": true}
+    {"a": {"b": [1, null]}}
+    {"a": null}
+    "#;
+
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_union_resolution(JsonUnionResolution::StringFallback)
+        .finish()
+        .unwrap();
+    let expected = df![
+        "a" => [Some("x"), Some("1"), Some("2.5"), Some("true"), Some(r#"{"b":[1,null]}"#), None],
+    ]
+    .unwrap();
+    assert!(df.equals_missing(&expected));
+
+    // Otherwise they are read as null, also into the string column of a given schema.
+    let expected = df![
+        "a" => [Some("x"), None, None, None, None, None],
+    ]
+    .unwrap();
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .finish()
+        .unwrap();
+    assert!(df.equals_missing(&expected));
+
+    let schema = Schema::from_iter([Field::new("a".into(), DataType::String)]);
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_schema(schema.into())
+        .finish()
+        .unwrap();
+    assert!(df.equals_missing(&expected));
+}
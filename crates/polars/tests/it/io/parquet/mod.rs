@@ -7,6 +7,7 @@ mod write;
 use std::io::Cursor;
 use std::path::PathBuf;
 
+use polars::io::parquet::metadata::FileMetaDataRef;
 use polars::prelude::*;
 
 // The dynamic representation of values in native Rust. This is not exhaustive.
@@ -190,6 +191,43 @@ pub fn alltypes_statistics(column: &str) -> Statistics {
     }
 }
 
+/// A frame of 1000 rows with an integer, a string and a nullable float column.
+pub(crate) fn test_df() -> DataFrame {
+    df![
+        "a" => (0..1000).map(|i| i * 2).collect::<Vec<i32>>(),
+        "b" => (0..1000).map(|i| format!("value {}", i % 13)).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| (i % 3 != 0).then_some(i as f64)).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+/// Create an empty directory for the files of the test `name`.
+pub(crate) fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("polars_parquet_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Write `df` to a parquet file in memory, with the writer options that `options` sets.
+pub(crate) fn write_parquet(
+    df: &mut DataFrame,
+    options: impl FnOnce(ParquetWriter<&mut Vec<u8>>) -> ParquetWriter<&mut Vec<u8>>,
+) -> PolarsResult<Vec<u8>> {
+    let mut buf = vec![];
+    options(ParquetWriter::new(&mut buf)).finish(df)?;
+    Ok(buf)
+}
+
+pub(crate) fn read_parquet(buf: &[u8]) -> PolarsResult<DataFrame> {
+    ParquetReader::new(Cursor::new(buf)).finish()
+}
+
+pub(crate) fn parquet_metadata(buf: &[u8]) -> FileMetaDataRef {
+    let mut reader = ParquetReader::new(Cursor::new(buf));
+    reader.get_metadata().unwrap().clone()
+}
+
 #[test]
 fn test_vstack_empty_3220() -> PolarsResult<()> {
     let df1 = df! {
@@ -0,0 +1,87 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use polars::prelude::*;
+
+use crate::io::parquet::{temp_dir, write_parquet};
+
+fn df(offset: usize) -> DataFrame {
+    df![
+        "id" => (offset..offset + 1000).map(|i| i as i64).collect::<Vec<_>>(),
+        "label" => (0..1000).map(|i| (i % 7 != 0).then(|| format!("label {}", i % 4))).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+fn write(path: &Path, df: &mut DataFrame, dictionary: bool) -> PolarsResult<()> {
+    let buf = write_parquet(df, |writer| {
+        writer
+            .with_row_group_size(Some(300))
+            .with_column_options(vec![(
+                "label".into(),
+                ParquetColumnOptions::default().with_dictionary(dictionary),
+            )])
+    })?;
+    std::fs::write(path, buf)?;
+    Ok(())
+}
+
+fn dataset_dir() -> PolarsResult<PathBuf> {
+    let dir = temp_dir("dictionary_as_categorical");
+    write(&dir.join("part-0.parquet"), &mut df(0), true)?;
+    write(&dir.join("part-1.parquet"), &mut df(1000), false)?;
+    Ok(dir)
+}
+
+fn label_encodings(path: &Path) -> PolarsResult<Vec<bool>> {
+    let mut reader = ParquetReader::new(File::open(path)?);
+    let metadata = reader.get_metadata()?;
+    Ok(metadata
+        .row_groups
+        .iter()
+        .map(|row_group| row_group.columns()[1].is_fully_dictionary_encoded())
+        .collect())
+}
+
+#[test]
+fn test_scan_dictionary_as_categorical() -> PolarsResult<()> {
+    let dir = dataset_dir()?;
+    assert_eq!(label_encodings(&dir.join("part-0.parquet"))?, [true; 3]);
+    assert_eq!(label_encodings(&dir.join("part-1.parquet"))?, [false; 3]);
+
+    let glob = dir.join("*.parquet");
+    let args = ScanArgsParquet {
+        dictionary_as_categorical: true,
+        ..Default::default()
+    };
+    let mut lf = LazyFrame::scan_parquet(&glob, args.clone())?;
+    assert!(lf.collect_schema()?.get("label").unwrap().is_categorical());
+
+    let out = lf.collect()?;
+    assert!(out.column("label")?.dtype().is_categorical());
+    let expected = LazyFrame::scan_parquet(&glob, Default::default())?.collect()?;
+    let out = out
+        .lazy()
+        .with_column(col("label").cast(DataType::String))
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    #[cfg(feature = "streaming")]
+    {
+        let out = LazyFrame::scan_parquet(&glob, args)?
+            .with_streaming(true)
+            .with_column(col("label").cast(DataType::String))
+            .collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    // The categories of the directly decoded row groups are their dictionary.
+    let mut reader = ParquetReader::new(File::open(dir.join("part-0.parquet"))?)
+        .with_dictionary_as_categorical(true)
+        .with_columns(Some(vec!["label".into()]));
+    let label = reader.finish()?.column("label")?.categorical()?.clone();
+    assert_eq!(label.get_rev_map().len(), 4);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars::io::RowIndex;
+use polars::prelude::*;
+use polars_io::pl_async::get_runtime;
+
+use crate::io::parquet::{temp_dir, write_parquet};
+
+fn df() -> DataFrame {
+    df![
+        "id" => (0..100_000).collect::<Vec<i64>>(),
+        "value" => (0..100_000).map(|i| (i % 7 != 0).then_some(i as f64)).collect::<Vec<_>>(),
+        "label" => (0..100_000).map(|i| format!("label {}", i % 13)).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+/// Write `df` as a file with a single row group.
+fn write(name: &str, df: &mut DataFrame) -> PolarsResult<PathBuf> {
+    let path = temp_dir(&format!("large_row_group_{name}")).join("data.parquet");
+    let height = df.height();
+    let buf = write_parquet(df, |writer| writer.with_row_group_size(Some(height)))?;
+    std::fs::write(&path, buf)?;
+    Ok(path)
+}
+
+fn read_batches(
+    path: &PathBuf,
+    chunk_size: usize,
+    large_row_group_bytes: Option<usize>,
+) -> PolarsResult<Vec<DataFrame>> {
+    let mut reader = ParquetReader::new(File::open(path)?)
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .batched(chunk_size)?
+        .with_large_row_group_bytes(large_row_group_bytes);
+    let mut out = vec![];
+    while let Some(batches) = get_runtime().block_on(reader.next_batches(2))? {
+        out.extend(batches);
+    }
+    Ok(out)
+}
+
+#[test]
+fn test_large_row_group_morsels() -> PolarsResult<()> {
+    let mut df = df();
+    let path = write("morsels", &mut df)?;
+
+    // The row group is split after it is decoded.
+    let batches = read_batches(&path, 30_000, None)?;
+    assert_eq!(batches.len(), 3);
+    // The row group is decoded in morsels of at most `chunk_size` rows.
+    let batches = read_batches(&path, 30_000, Some(1 << 16))?;
+    let heights = batches.iter().map(|df| df.height()).collect::<Vec<_>>();
+    assert_eq!(heights, [30_000, 30_000, 30_000, 10_000]);
+    let mut out = batches[0].clone();
+    for batch in &batches[1..] {
+        out.vstack_mut(batch)?;
+    }
+    assert!(out.equals_missing(&df.with_row_index("index".into(), Some(10))?));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_large_row_group_streaming_scan() -> PolarsResult<()> {
+    let mut df = df();
+    let path = write("scan", &mut df)?;
+
+    let scan = |large_row_group_bytes| {
+        let args = ScanArgsParquet {
+            large_row_group_bytes,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&path, args).map(|lf| {
+            lf.filter(col("value").is_not_null())
+                .select([col("label"), col("value")])
+                .with_streaming(true)
+        })
+    };
+    let expected = scan(None)?.collect()?;
+    let out = scan(Some(1 << 16))?.collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let expected = scan(None)?.slice(45_000, 20_000).collect()?;
+    let out = scan(Some(1 << 16))?.slice(45_000, 20_000).collect()?;
+    assert_eq!(out.height(), 20_000);
+    assert!(out.equals_missing(&expected));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -4,12 +4,20 @@ mod binary;
 /// but OTOH it has no external dependencies and is very familiar to Rust developers.
 mod boolean;
 mod dictionary;
+#[cfg(feature = "dtype-categorical")]
+mod dictionary_as_categorical;
 pub(crate) mod file;
 mod fixed_binary;
+mod large_row_group;
+mod page_index;
 mod primitive;
 mod primitive_nested;
+mod resumable;
 pub(crate) mod row_group;
 mod struct_;
+#[cfg(feature = "dtype-struct")]
+mod struct_statistics;
+mod summary;
 mod utils;
 
 use std::fs::File;
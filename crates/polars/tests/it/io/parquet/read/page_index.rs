@@ -0,0 +1,107 @@
+use std::io::Cursor;
+
+use polars::io::RowIndex;
+use polars::prelude::*;
+use polars_parquet::read::{read_column_index, read_offset_index, BoundaryOrder};
+
+use crate::io::parquet::{parquet_metadata, temp_dir, write_parquet};
+
+fn df() -> DataFrame {
+    df![
+        "a" => (0..20_000).collect::<Vec<i64>>(),
+        "b" => (0..20_000).map(|i| format!("value {}", i % 7)).collect::<Vec<_>>(),
+        "c" => (0..20_000).rev().map(|i| i as f64).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+fn write(df: &mut DataFrame) -> Vec<u8> {
+    write_parquet(df, |writer| writer.with_data_page_size(Some(4096))).unwrap()
+}
+
+#[test]
+fn test_page_index_roundtrip() {
+    let buf = write(&mut df());
+    let metadata = parquet_metadata(&buf);
+    let columns = metadata.row_groups[0].columns();
+
+    let mut reader = Cursor::new(&buf);
+    let column_index = read_column_index(&columns[0], &mut reader)
+        .unwrap()
+        .unwrap();
+    let offset_index = read_offset_index(&columns[0], &mut reader)
+        .unwrap()
+        .unwrap();
+    assert!(offset_index.page_locations.len() > 1);
+    assert_eq!(
+        column_index.null_pages.len(),
+        offset_index.page_locations.len()
+    );
+    assert_eq!(offset_index.page_locations[0].first_row_index, 0);
+    assert_eq!(column_index.boundary_order, BoundaryOrder::ASCENDING);
+
+    let column_index = read_column_index(&columns[2], &mut reader)
+        .unwrap()
+        .unwrap();
+    assert_eq!(column_index.boundary_order, BoundaryOrder::DESCENDING);
+}
+
+#[test]
+fn test_page_index_pruning() -> PolarsResult<()> {
+    let dir = temp_dir("page_index_pruning");
+    let path = dir.join("data.parquet");
+    let df = df();
+    std::fs::write(&path, write(&mut df.clone()))?;
+
+    for parallel in [
+        ParallelStrategy::None,
+        ParallelStrategy::RowGroups,
+        ParallelStrategy::Prefiltered,
+    ] {
+        let scan = |predicate: Expr| -> PolarsResult<DataFrame> {
+            let args = ScanArgsParquet {
+                parallel,
+                ..Default::default()
+            };
+            LazyFrame::scan_parquet(&path, args)?
+                .filter(predicate)
+                .collect()
+        };
+
+        let out = scan(
+            col("a")
+                .gt_eq(lit(12_345i64))
+                .and(col("a").lt(lit(12_400i64))),
+        )?;
+        assert!(out.equals(&df.slice(12_345, 55)));
+
+        let out = scan(col("c").lt(lit(10.0)).and(col("b").eq(lit("value 1"))))?;
+        assert_eq!(out.column("a")?.i64()?.to_vec(), [Some(19_993)]);
+
+        assert_eq!(scan(col("a").eq(lit(-1i64)))?.height(), 0);
+        assert_eq!(scan(col("a").gt(lit(10i64)))?.height(), 19_989);
+    }
+
+    // The row index counts the rows in skipped pages.
+    let args = ScanArgsParquet {
+        row_index: Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(&path, args)?
+        .filter(
+            col("a")
+                .gt_eq(lit(12_345i64))
+                .and(col("a").lt(lit(12_400i64))),
+        )
+        .collect()?;
+    let expected = df
+        .slice(12_345, 55)
+        .with_row_index("index".into(), Some(12_355))?;
+    assert!(out.equals(&expected));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use polars::io::RowIndex;
+use polars::prelude::*;
+
+use crate::io::parquet::{temp_dir, write_parquet};
+
+/// A dataset of files of 5, 0 and 7 rows.
+fn dataset_dir() -> PolarsResult<PathBuf> {
+    let dir = temp_dir("resumable");
+    for (i, range) in [0..5, 5..5, 5..12].into_iter().enumerate() {
+        let mut df = df!["id" => range.collect::<Vec<i64>>()]?;
+        let buf = write_parquet(&mut df, |writer| writer)?;
+        std::fs::write(dir.join(format!("part-{i}.parquet")), buf)?;
+    }
+    Ok(dir)
+}
+
+#[test]
+fn test_scan_parquet_resumable() -> PolarsResult<()> {
+    let dir = dataset_dir()?;
+    let glob = dir.join("*.parquet");
+
+    let mut position = ScanPosition::default();
+    let mut positions = vec![];
+    let mut batches = vec![];
+    while position.file < 3 {
+        let args = ScanArgsParquet {
+            n_rows: Some(4),
+            row_index: Some(RowIndex {
+                name: "index".into(),
+                offset: 0,
+            }),
+            start_at: Some(position),
+            ..Default::default()
+        };
+        let (lf, end) = LazyFrame::scan_parquet_resumable(&glob, args)?;
+        batches.push(lf.collect()?);
+        positions.push(end);
+        position = end;
+    }
+    assert_eq!(
+        positions,
+        [
+            ScanPosition::new(0, 4),
+            ScanPosition::new(2, 3),
+            ScanPosition::new(3, 0),
+        ]
+    );
+    let mut out = batches[0].clone();
+    for batch in &batches[1..] {
+        out.vstack_mut(batch)?;
+    }
+    let expected: Vec<_> = (0..12).map(Some).collect();
+    assert_eq!(
+        out.column("id")?.i64()?.into_iter().collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        out.column("index")?.idx()?.into_iter().collect::<Vec<_>>(),
+        (0..12).map(Some).collect::<Vec<_>>()
+    );
+
+    // Without `n_rows` the scan reads the rest of the dataset.
+    let args = ScanArgsParquet {
+        start_at: Some(ScanPosition::new(2, 5)),
+        ..Default::default()
+    };
+    let (lf, end) = LazyFrame::scan_parquet_resumable(&glob, args)?;
+    assert_eq!(end, ScanPosition::new(3, 0));
+    assert_eq!(lf.collect()?.column("id")?.i64()?.get(0), Some(10));
+
+    for position in [
+        ScanPosition::new(0, 6),
+        ScanPosition::new(3, 1),
+        ScanPosition::new(4, 0),
+    ] {
+        let args = ScanArgsParquet {
+            start_at: Some(position),
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_parquet(&glob, args);
+        assert!(matches!(out, Err(PolarsError::OutOfBounds(_))));
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
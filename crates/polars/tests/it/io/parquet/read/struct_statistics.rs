@@ -0,0 +1,45 @@
+use polars::prelude::*;
+
+use crate::io::parquet::{temp_dir, write_parquet};
+
+fn df() -> PolarsResult<DataFrame> {
+    let country = Series::new(
+        "country".into(),
+        (0..1000)
+            .map(|i| ["BE", "DE", "FR", "NL"][i / 250])
+            .collect::<Vec<_>>(),
+    );
+    let id = Series::new("id".into(), (0..1000).collect::<Vec<i64>>());
+    let meta = StructChunked::from_series("meta".into(), &[country, id])?.into_series();
+    DataFrame::new(vec![
+        Series::new("a".into(), (0..1000).collect::<Vec<i32>>()),
+        meta,
+    ])
+}
+
+#[test]
+fn test_struct_field_statistics_pruning() -> PolarsResult<()> {
+    let path = temp_dir("struct_field_statistics").join("data.parquet");
+    let mut df = df()?;
+    let buf = write_parquet(&mut df, |writer| writer.with_row_group_size(Some(250)))?;
+    std::fs::write(&path, buf)?;
+
+    let scan = |predicate: Expr| -> PolarsResult<DataFrame> {
+        LazyFrame::scan_parquet(&path, Default::default())?
+            .filter(predicate)
+            .collect()
+    };
+    let field = |name: &str| col("meta").struct_().field_by_name(name);
+
+    let out = scan(field("country").eq(lit("NL")))?;
+    assert!(out.equals(&df.slice(750, 250)));
+    let out = scan(lit("DE").eq(field("country")))?;
+    assert!(out.equals(&df.slice(250, 250)));
+    assert_eq!(scan(field("country").eq(lit("US")))?.height(), 0);
+    let out = scan(field("id").gt_eq(lit(990i64)).and(col("a").lt(lit(995))))?;
+    assert!(out.equals(&df.slice(990, 5)));
+    assert_eq!(scan(field("id").lt(lit(0i64)))?.height(), 0);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
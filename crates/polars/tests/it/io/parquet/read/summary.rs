@@ -0,0 +1,81 @@
+use std::fs::File;
+use std::path::Path;
+
+use polars::prelude::*;
+
+use crate::io::parquet::{temp_dir, write_parquet};
+
+fn write_file(path: &Path, df: &mut DataFrame) -> PolarsResult<()> {
+    let buf = write_parquet(df, |writer| writer.with_row_group_size(Some(50)))?;
+    std::fs::write(path, buf)?;
+    Ok(())
+}
+
+#[test]
+fn test_metadata_summary() -> PolarsResult<()> {
+    let dir = temp_dir("metadata_summary");
+    let mut dfs = (0..3)
+        .map(|i| {
+            df![
+                "id" => (i * 120..(i + 1) * 120).collect::<Vec<i64>>(),
+                "name" => (0..120).map(|j| format!("name {j}")).collect::<Vec<_>>(),
+            ]
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    for (i, df) in dfs.iter_mut().enumerate() {
+        write_file(&dir.join(format!("part-{i}.parquet")), df)?;
+    }
+
+    assert_eq!(write_metadata_summary(&dir)?, 3);
+    let summary = MetadataSummary::read(&dir)?;
+    assert_eq!(summary.len(), 3);
+    assert_eq!(summary.num_rows(), 360);
+    let path = dir.join("part-1.parquet");
+    let metadata = summary.get(&path).unwrap();
+    let footer = ParquetReader::new(File::open(&path)?)
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.num_rows, 120);
+    assert_eq!(metadata.row_groups.len(), footer.row_groups.len());
+    assert_eq!(
+        metadata.row_groups[1].columns()[0].data_page_offset(),
+        footer.row_groups[1].columns()[0].data_page_offset()
+    );
+    assert!(summary.get(&dir.join("part-3.parquet")).is_none());
+
+    let schema = read_summary_schema(&dir)?;
+    assert_eq!(schema.len(), 2);
+    assert_eq!(schema.get_at_index(0).unwrap().0, "id");
+
+    let expected = df![
+        "id" => (230..250).collect::<Vec<i64>>(),
+    ]?;
+    for use_metadata_summary in [false, true] {
+        let args = ScanArgsParquet {
+            use_metadata_summary,
+            ..Default::default()
+        };
+        // The summary files are not read as data files of the directory.
+        let lf = LazyFrame::scan_parquet(&dir, args)?
+            .filter(col("id").gt_eq(lit(230)).and(col("id").lt(lit(250))))
+            .select([col("id")]);
+        assert!(lf.clone().collect()?.equals(&expected));
+        #[cfg(feature = "streaming")]
+        assert!(lf.with_streaming(true).collect()?.equals(&expected));
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_metadata_summary_schema_mismatch() -> PolarsResult<()> {
+    let dir = temp_dir("metadata_summary_schema_mismatch");
+    write_file(&dir.join("part-0.parquet"), &mut df!["a" => [1i64, 2]]?)?;
+    write_file(&dir.join("part-1.parquet"), &mut df!["a" => ["x", "y"]]?)?;
+    assert!(write_metadata_summary(&dir).is_err());
+    assert!(MetadataSummary::read(&dir).is_err());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -3,6 +3,7 @@ use std::io::Cursor;
 use arrow::array::{ArrayRef, Utf8ViewArray};
 use arrow::datatypes::{ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
+use polars::prelude::*;
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -11,6 +12,7 @@ use polars_parquet::write::{
 };
 
 use crate::io::parquet::read::file::FileReader;
+use crate::io::parquet::write_parquet;
 
 fn round_trip(
     array: &ArrayRef,
@@ -79,3 +81,163 @@ fn roundtrip_binview() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+fn key_value_df() -> DataFrame {
+    let b = df![
+        "x" => [1, 2, 3],
+        "y" => ["a", "b", "c"],
+    ]
+    .unwrap()
+    .into_struct("b".into())
+    .into_series();
+    df![
+        "a" => [1i64, 2, 3],
+        "b" => b,
+        "c" => [1.0, 2.0, 3.0],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_key_value_metadata_roundtrip() -> PolarsResult<()> {
+    let mut df = key_value_df();
+    let metadata = KeyValueMetadata::default()
+        .with_file("lineage", "orders_v2")
+        .with_file("owner", "data-eng")
+        .with_column("a", "unit", "cents")
+        .with_column("b", "source", "events")
+        .with_column("a", "pii", "false");
+
+    let buf = write_parquet(&mut df, |writer| {
+        writer
+            .with_row_group_size(Some(2))
+            .with_key_value_metadata(Some(metadata.clone()))
+    })?;
+
+    let mut reader = ParquetReader::new(Cursor::new(buf));
+    let out = reader.key_value_metadata()?;
+    assert_eq!(out, metadata);
+    assert_eq!(out.get("lineage"), Some("orders_v2"));
+    assert_eq!(out.get_column("a", "pii"), Some("false"));
+    assert_eq!(out.get_column("c", "unit"), None);
+    // The metadata does not change how the data is read.
+    assert!(reader.finish()?.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_key_value_metadata_invalid() {
+    let mut df = key_value_df();
+    let write = |df: &mut DataFrame, metadata: KeyValueMetadata| {
+        write_parquet(df, |writer| writer.with_key_value_metadata(Some(metadata)))
+    };
+    let metadata = KeyValueMetadata::default().with_column("d", "unit", "cents");
+    assert!(matches!(
+        write(&mut df, metadata),
+        Err(PolarsError::ColumnNotFound(_))
+    ));
+    let metadata = KeyValueMetadata::default().with_file("ARROW:schema", "");
+    assert!(matches!(
+        write(&mut df, metadata),
+        Err(PolarsError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_sink_key_value_metadata() -> PolarsResult<()> {
+    let dir = crate::io::parquet::temp_dir("sink_key_value_metadata");
+    let path = dir.join("data.parquet");
+    let metadata = KeyValueMetadata::default()
+        .with_file("lineage", "orders_v2")
+        .with_column("c", "unit", "cents");
+    key_value_df().lazy().sink_parquet(
+        &path,
+        ParquetWriteOptions {
+            key_value_metadata: Some(metadata.clone()),
+            ..Default::default()
+        },
+    )?;
+
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+    assert_eq!(reader.key_value_metadata()?, metadata);
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+fn field_metadata_df() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "geometry" => [b"\x01\x01".as_slice(), b"\x01\x02", b"\x01\x03"],
+        "price" => [1.0, 2.0, 3.0],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_field_metadata_roundtrip() -> PolarsResult<()> {
+    let mut df = field_metadata_df();
+    let metadata = FieldMetadata::default()
+        .with_extension(
+            "geometry",
+            "geoarrow.wkb",
+            Some(r#"{"crs":"EPSG:4326"}"#.into()),
+        )
+        .with_column("price", "unit", "cents");
+    let buf = write_parquet(&mut df, |writer| {
+        writer.with_field_metadata(Some(metadata.clone()))
+    })?;
+
+    let mut reader = ParquetReader::new(Cursor::new(buf));
+    let out = reader.field_metadata()?;
+    assert_eq!(out, metadata);
+    assert_eq!(out.extension_name("geometry"), Some("geoarrow.wkb"));
+    assert_eq!(out.extension_name("price"), None);
+    assert_eq!(out.get_column("id"), None);
+
+    // The metadata is on the fields of the schema, and the extension type is read as its
+    // storage type.
+    let schema = reader.schema()?;
+    let geometry = schema.get("geometry").unwrap();
+    assert_eq!(
+        geometry
+            .metadata
+            .get("ARROW:extension:metadata")
+            .map(|v| v.as_str()),
+        Some(r#"{"crs":"EPSG:4326"}"#)
+    );
+    assert!(reader.finish()?.equals(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_sink_field_metadata() -> PolarsResult<()> {
+    let dir = crate::io::parquet::temp_dir("sink_field_metadata");
+    let path = dir.join("data.parquet");
+    let metadata = FieldMetadata::default().with_extension("geometry", "geoarrow.wkb", None);
+    field_metadata_df().lazy().sink_parquet(
+        &path,
+        ParquetWriteOptions {
+            field_metadata: Some(metadata.clone()),
+            ..Default::default()
+        },
+    )?;
+
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+    assert_eq!(reader.field_metadata()?, metadata);
+    let out = LazyFrame::scan_parquet(&path, Default::default())?.collect()?;
+    assert!(out.equals(&field_metadata_df()));
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_field_metadata_missing_column() {
+    let metadata = FieldMetadata::default().with_column("missing", "unit", "cents");
+    let err = write_parquet(&mut field_metadata_df(), |writer| {
+        writer.with_field_metadata(Some(metadata))
+    })
+    .unwrap_err();
+    assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+}
@@ -0,0 +1,90 @@
+use std::io::Cursor;
+
+use polars::prelude::*;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set, read};
+
+use crate::io::parquet::{parquet_metadata, read_parquet, temp_dir, test_df, write_parquet};
+
+fn write(df: &mut DataFrame, bloom_filter: BloomFilterOptions) -> PolarsResult<Vec<u8>> {
+    write_parquet(df, |writer| {
+        writer
+            .with_row_group_size(Some(300))
+            .with_bloom_filter(Some(bloom_filter))
+    })
+}
+
+#[test]
+fn test_bloom_filter_roundtrip() {
+    let mut df = test_df();
+    let bloom_filter = BloomFilterOptions::default()
+        .with_column("a")
+        .with_column_fpp("b", 0.01);
+    let buf = write(&mut df, bloom_filter).unwrap();
+
+    let metadata = parquet_metadata(&buf);
+    assert!(metadata.row_groups.len() > 1);
+
+    let mut cursor = Cursor::new(&buf);
+    let mut bitset = vec![];
+    let mut offset = 0;
+    for row_group in &metadata.row_groups {
+        let columns = row_group.columns();
+        assert!(columns[2].metadata().bloom_filter_offset.is_none());
+
+        read(&columns[0], &mut cursor, &mut bitset).unwrap();
+        let rows = offset..offset + row_group.num_rows() as i32;
+        assert!(rows.clone().all(|i| is_in_set(&bitset, hash_native(i * 2))));
+        offset = rows.end;
+
+        read(&columns[1], &mut cursor, &mut bitset).unwrap();
+        assert!((0..13).all(|i| is_in_set(&bitset, hash_byte(format!("value {i}")))));
+    }
+
+    let out = read_parquet(&buf).unwrap();
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_bloom_filter_pruning() -> PolarsResult<()> {
+    let dir = temp_dir("bloom_filter_pruning");
+    let path = dir.join("data.parquet");
+    let mut df = test_df();
+    let buf = write(
+        &mut df,
+        BloomFilterOptions::default()
+            .with_column("a")
+            .with_column("b"),
+    )?;
+    std::fs::write(&path, buf)?;
+
+    let scan = |predicate: Expr| -> PolarsResult<DataFrame> {
+        LazyFrame::scan_parquet(&path, Default::default())?
+            .filter(predicate)
+            .collect()
+    };
+
+    // Odd values pass the min/max statistics but are never written.
+    assert_eq!(scan(col("a").eq(lit(301)))?.height(), 0);
+    assert_eq!(scan(col("a").eq(lit(300)))?.height(), 1);
+    assert_eq!(scan(col("b").eq(lit("value 14")))?.height(), 0);
+    assert_eq!(scan(col("b").eq(lit("value 3")))?.height(), 77);
+    #[cfg(feature = "is_in")]
+    assert_eq!(
+        scan(col("a").is_in(lit(Series::new("".into(), [301, 303, 304]))))?.height(),
+        1
+    );
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_bloom_filter_invalid_options() {
+    let result = write(
+        &mut test_df(),
+        BloomFilterOptions::default().with_column("d"),
+    );
+    assert!(result.is_err());
+    let result = write(&mut test_df(), BloomFilterOptions::default().with_fpp(1.0));
+    assert!(result.is_err());
+}
@@ -0,0 +1,171 @@
+use polars::prelude::*;
+use polars_parquet::parquet::compression::Compression;
+use polars_parquet::parquet::encoding::Encoding;
+use polars_parquet::parquet::metadata::ColumnChunkMetaData;
+
+use crate::io::parquet::{parquet_metadata, read_parquet, temp_dir, write_parquet};
+
+fn df() -> DataFrame {
+    df![
+        "id" => (0..1000).map(|i| format!("id {i:05}")).collect::<Vec<_>>(),
+        "feature_a" => (0..1000).map(|i| i % 10).collect::<Vec<i64>>(),
+        "feature_b" => (0..1000).map(|i| (i % 3 != 0).then_some(i % 5)).collect::<Vec<_>>(),
+        "label" => (0..1000).map(|i| format!("label {}", i % 4)).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+fn write(
+    df: &mut DataFrame,
+    column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+) -> PolarsResult<Vec<u8>> {
+    write_parquet(df, |writer| {
+        writer
+            .with_compression(ParquetCompression::Snappy)
+            .with_column_options(column_options)
+    })
+}
+
+fn columns(buf: &[u8]) -> Vec<ColumnChunkMetaData> {
+    parquet_metadata(buf).row_groups[0].columns().to_vec()
+}
+
+fn is_dictionary_encoded(column: &ColumnChunkMetaData) -> bool {
+    column
+        .column_encoding()
+        .iter()
+        .any(|encoding| Encoding::try_from(*encoding).unwrap() == Encoding::RleDictionary)
+}
+
+#[test]
+fn test_column_options() -> PolarsResult<()> {
+    let mut df = df();
+    let buf = write(
+        &mut df,
+        vec![
+            (
+                "id".into(),
+                ParquetColumnOptions::default()
+                    .with_dictionary(false)
+                    .with_statistics(StatisticsOptions::empty()),
+            ),
+            (
+                "^feature_.*$".into(),
+                ParquetColumnOptions::default().with_compression(ParquetCompression::Zstd(None)),
+            ),
+            // Later options override the settings of earlier ones.
+            (
+                "feature_b".into(),
+                ParquetColumnOptions::default().with_compression(ParquetCompression::Uncompressed),
+            ),
+        ],
+    )?;
+
+    let columns = columns(&buf);
+    let compressions = columns
+        .iter()
+        .map(|column| column.compression())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        compressions,
+        [
+            Compression::Snappy,
+            Compression::Zstd,
+            Compression::Uncompressed,
+            Compression::Snappy
+        ]
+    );
+    let dictionary = columns
+        .iter()
+        .map(is_dictionary_encoded)
+        .collect::<Vec<_>>();
+    assert_eq!(dictionary, [false, true, true, true]);
+    let statistics = columns
+        .iter()
+        .map(|column| column.statistics().is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(statistics, [false, true, true, true]);
+    // Only the columns with statistics get a column index.
+    let column_indexes = columns
+        .iter()
+        .map(|column| column.column_chunk().column_index_offset.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(column_indexes, [false, true, true, true]);
+
+    let out = read_parquet(&buf)?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn test_column_options_binary_blob() -> PolarsResult<()> {
+    let blobs = (0..100u8).map(|i| vec![i; 4096]).collect::<Vec<_>>();
+    let mut df = df![
+        "id" => (0..100i64).collect::<Vec<_>>(),
+        "blob" => blobs.iter().map(|blob| blob.as_slice()).collect::<Vec<_>>(),
+    ]?;
+
+    let level = ZstdLevel::try_new(9)?;
+    let buf = write_parquet(&mut df, |writer| {
+        writer
+            .with_compression(ParquetCompression::Zstd(Some(level)))
+            .with_statistics(StatisticsOptions::full())
+            .with_column_options(vec![(
+                "blob".into(),
+                ParquetColumnOptions::default()
+                    .with_compression(ParquetCompression::Uncompressed)
+                    .with_statistics(StatisticsOptions::empty())
+                    .with_dictionary(false),
+            )])
+    })?;
+
+    let columns = columns(&buf);
+    assert_eq!(columns[0].compression(), Compression::Zstd);
+    assert!(columns[0].statistics().is_some());
+    let blob = &columns[1];
+    assert_eq!(blob.compression(), Compression::Uncompressed);
+    assert!(blob.statistics().is_none());
+    assert!(!is_dictionary_encoded(blob));
+    assert_eq!(blob.compressed_size(), blob.uncompressed_size());
+
+    let out = read_parquet(&buf)?;
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_column_options_errors() {
+    let mut df = df();
+    let options = ParquetColumnOptions::default().with_dictionary(false);
+    assert!(write(&mut df, vec![("missing".into(), options.clone())]).is_err());
+    assert!(write(&mut df, vec![("^feature_($".into(), options)]).is_err());
+}
+
+#[test]
+fn test_sink_column_options() -> PolarsResult<()> {
+    let dir = temp_dir("sink_column_options");
+    let path = dir.join("data.parquet");
+    let df = df();
+    df.clone().lazy().sink_parquet(
+        &path,
+        ParquetWriteOptions {
+            column_options: vec![(
+                "label".into(),
+                ParquetColumnOptions::default()
+                    .with_dictionary(false)
+                    .with_compression(ParquetCompression::Uncompressed),
+            )],
+            ..Default::default()
+        },
+    )?;
+
+    let buf = std::fs::read(&path)?;
+    let columns = columns(&buf);
+    let label = &columns[3];
+    assert_eq!(label.compression(), Compression::Uncompressed);
+    assert!(!is_dictionary_encoded(label));
+    let out = read_parquet(&buf)?;
+    assert!(out.equals_missing(&df));
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use polars::prelude::*;
+use polars_parquet::parquet::encoding::Encoding;
+
+use crate::io::parquet::{parquet_metadata, read_parquet, write_parquet};
+
+fn df() -> DataFrame {
+    df![
+        "id" => (0..1000).collect::<Vec<i64>>(),
+        "value" => (0..1000).map(|i| (i * 7) % 10).collect::<Vec<i32>>(),
+        "name" => (0..1000).map(|i| (i % 7 != 0).then(|| format!("key {i:05}"))).collect::<Vec<_>>(),
+        "float" => (0..1000).map(|i| i as f64).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+fn write(df: &mut DataFrame, encoding: ParquetEncodingOptions) -> PolarsResult<Vec<u8>> {
+    write_parquet(df, |writer| {
+        writer
+            .with_row_group_size(Some(250))
+            .with_encoding(Some(encoding))
+    })
+}
+
+/// Returns the encodings of the values of each column in each row group.
+fn value_encodings(buf: &[u8]) -> Vec<Vec<Encoding>> {
+    parquet_metadata(buf)
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            row_group
+                .columns()
+                .iter()
+                .map(|column| {
+                    let encodings = column
+                        .column_encoding()
+                        .iter()
+                        .map(|encoding| Encoding::try_from(*encoding).unwrap())
+                        .filter(|encoding| *encoding != Encoding::Rle)
+                        .collect::<Vec<_>>();
+                    // Dictionary encoded pages also list the encoding of the dictionary page.
+                    match encodings.as_slice() {
+                        [encoding] => *encoding,
+                        _ if encodings.contains(&Encoding::RleDictionary) => {
+                            Encoding::RleDictionary
+                        },
+                        _ => panic!("unexpected encodings {encodings:?}"),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn assert_roundtrip(buf: &[u8], df: &DataFrame) {
+    let out = read_parquet(buf).unwrap();
+    assert!(out.equals_missing(df));
+}
+
+#[test]
+fn test_delta_encoding() {
+    let mut df = df();
+    let encoding = ParquetEncodingOptions::default().with_default(ParquetEncoding::Delta);
+    let buf = write(&mut df, encoding).unwrap();
+    for encodings in value_encodings(&buf) {
+        assert_eq!(
+            encodings,
+            [
+                Encoding::DeltaBinaryPacked,
+                Encoding::DeltaBinaryPacked,
+                Encoding::DeltaByteArray,
+                Encoding::Plain
+            ]
+        );
+    }
+    assert_roundtrip(&buf, &df);
+}
+
+#[test]
+fn test_column_encoding() {
+    let mut df = df();
+    let encoding = ParquetEncodingOptions::default()
+        .with_column("name", ParquetEncoding::Delta)
+        .with_column("value", ParquetEncoding::Plain);
+    let buf = write(&mut df, encoding).unwrap();
+    for encodings in value_encodings(&buf) {
+        assert_eq!(
+            encodings,
+            [
+                Encoding::RleDictionary,
+                Encoding::Plain,
+                Encoding::DeltaByteArray,
+                Encoding::Plain
+            ]
+        );
+    }
+    assert_roundtrip(&buf, &df);
+}
+
+#[test]
+fn test_auto_encoding() {
+    let mut df = df();
+    // The third row group of `id` is not sorted.
+    df.with_column(Series::new(
+        "id".into(),
+        (0..1000)
+            .map(|i| if i == 700 { 0 } else { i })
+            .collect::<Vec<i64>>(),
+    ))
+    .unwrap();
+    let encoding = ParquetEncodingOptions::default().with_default(ParquetEncoding::Auto);
+    let buf = write(&mut df, encoding).unwrap();
+    let encodings = value_encodings(&buf);
+    assert_eq!(encodings.len(), 4);
+    for (i, encodings) in encodings.iter().enumerate() {
+        assert_eq!(encodings[0] == Encoding::DeltaBinaryPacked, i != 2);
+        assert_eq!(
+            encodings[1..],
+            [
+                Encoding::RleDictionary,
+                Encoding::DeltaByteArray,
+                Encoding::Plain
+            ]
+        );
+    }
+    assert_roundtrip(&buf, &df);
+}
+
+#[test]
+fn test_invalid_encoding() {
+    let result = write(
+        &mut df(),
+        ParquetEncodingOptions::default().with_column("float", ParquetEncoding::Delta),
+    );
+    assert!(result.is_err());
+    let result = write(
+        &mut df(),
+        ParquetEncodingOptions::default().with_column("missing", ParquetEncoding::Plain),
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,121 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use polars::prelude::*;
+use polars_parquet::parquet::encryption::{
+    EncryptionKey, FileDecryptionProperties, FileEncryptionProperties, KeyRetriever,
+};
+use polars_parquet::parquet::error::{ParquetError, ParquetResult};
+
+use crate::io::parquet::{test_df, write_parquet};
+
+const FOOTER_KEY: [u8; 16] = *b"0123456789012345";
+const COLUMN_KEY: [u8; 32] = *b"01234567890123456789012345678901";
+
+fn write(df: &mut DataFrame, encryption: FileEncryptionProperties) -> Vec<u8> {
+    write_parquet(df, |writer| {
+        writer
+            .with_row_group_size(Some(300))
+            .with_encryption(Some(encryption))
+    })
+    .unwrap()
+}
+
+fn read(buf: &[u8], decryption: Option<FileDecryptionProperties>) -> PolarsResult<DataFrame> {
+    ParquetReader::new(Cursor::new(buf))
+        .with_decryption(decryption)
+        .finish()
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip() {
+    let mut df = test_df();
+    let buf = write(
+        &mut df,
+        FileEncryptionProperties::new(EncryptionKey::new(FOOTER_KEY.to_vec())),
+    );
+    assert_eq!(&buf[..4], b"PARE");
+    assert_eq!(&buf[buf.len() - 4..], b"PARE");
+
+    let decryption = FileDecryptionProperties::new().with_footer_key(FOOTER_KEY.to_vec());
+    let out = read(&buf, Some(decryption)).unwrap();
+    assert!(out.equals_missing(&df));
+
+    assert!(read(&buf, None).is_err());
+    let wrong_key = FileDecryptionProperties::new().with_footer_key(vec![0; 16]);
+    assert!(read(&buf, Some(wrong_key)).is_err());
+}
+
+struct Retriever;
+
+impl KeyRetriever for Retriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        match key_metadata {
+            b"footer" => Ok(FOOTER_KEY.to_vec()),
+            b"column" => Ok(COLUMN_KEY.to_vec()),
+            _ => Err(ParquetError::InvalidParameter("unknown key".to_string())),
+        }
+    }
+}
+
+#[test]
+fn test_column_encryption_roundtrip() {
+    let mut df = test_df();
+    let encryption = FileEncryptionProperties::new(
+        EncryptionKey::new(FOOTER_KEY.to_vec()).with_key_metadata(b"footer".to_vec()),
+    )
+    .with_column_key(
+        "b",
+        EncryptionKey::new(COLUMN_KEY.to_vec()).with_key_metadata(b"column".to_vec()),
+    );
+    let buf = write(&mut df, encryption);
+
+    let decryption = FileDecryptionProperties::new().with_key_retriever(Arc::new(Retriever));
+    let out = read(&buf, Some(decryption)).unwrap();
+    assert!(out.equals_missing(&df));
+
+    // The column key is required even if the footer key is given.
+    let decryption = FileDecryptionProperties::new().with_footer_key(FOOTER_KEY.to_vec());
+    assert!(read(&buf, Some(decryption)).is_err());
+    let decryption = FileDecryptionProperties::new()
+        .with_footer_key(FOOTER_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec());
+    let out = read(&buf, Some(decryption)).unwrap();
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_encryption_aad_prefix() {
+    let mut df = test_df();
+    let encryption = FileEncryptionProperties::new(EncryptionKey::new(FOOTER_KEY.to_vec()))
+        .with_aad_prefix(b"table/file.parquet".to_vec(), false);
+    let buf = write(&mut df, encryption);
+
+    let decryption = FileDecryptionProperties::new().with_footer_key(FOOTER_KEY.to_vec());
+    assert!(read(&buf, Some(decryption.clone())).is_err());
+    assert!(read(
+        &buf,
+        Some(
+            decryption
+                .clone()
+                .with_aad_prefix(b"other/file.parquet".to_vec())
+        )
+    )
+    .is_err());
+    let out = read(
+        &buf,
+        Some(decryption.with_aad_prefix(b"table/file.parquet".to_vec())),
+    )
+    .unwrap();
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_encryption_unknown_column() {
+    let encryption = FileEncryptionProperties::new(EncryptionKey::new(FOOTER_KEY.to_vec()))
+        .with_column_key("d", EncryptionKey::new(COLUMN_KEY.to_vec()));
+    let result = write_parquet(&mut test_df(), |writer| {
+        writer.with_encryption(Some(encryption))
+    });
+    assert!(result.is_err());
+}
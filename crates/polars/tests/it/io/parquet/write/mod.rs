@@ -1,6 +1,13 @@
 mod binary;
+mod bloom_filter;
+mod column_options;
+mod encoding;
+#[cfg(feature = "parquet_encryption")]
+mod encryption;
 mod primitive;
+mod row_group_bytes;
 mod sidecar;
+mod sorting_columns;
 
 use std::io::{Cursor, Read, Seek};
 
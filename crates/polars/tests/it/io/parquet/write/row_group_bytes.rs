@@ -0,0 +1,78 @@
+use polars::prelude::*;
+
+use crate::io::parquet::{parquet_metadata, read_parquet, write_parquet};
+
+fn row_group_rows(buf: &[u8]) -> Vec<usize> {
+    let metadata = parquet_metadata(buf);
+    metadata.row_groups.iter().map(|rg| rg.num_rows()).collect()
+}
+
+#[test]
+fn test_row_group_bytes() -> PolarsResult<()> {
+    let mut narrow = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+    ]?;
+    let mut wide = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).collect::<Vec<i64>>(),
+        "c" => (0..1000).collect::<Vec<i64>>(),
+        "d" => (0..1000).collect::<Vec<i64>>(),
+    ]?;
+
+    let write = |df: &mut DataFrame, rows: Option<usize>| {
+        write_parquet(df, |writer| {
+            writer
+                .with_row_group_size(rows)
+                .with_row_group_bytes(Some(1600))
+        })
+    };
+
+    // The same number of bytes holds four times as many narrow rows as wide rows.
+    let buf = write(&mut narrow, None)?;
+    assert_eq!(row_group_rows(&buf), [200; 5]);
+    assert!(read_parquet(&buf)?.equals(&narrow));
+    let buf = write(&mut wide, None)?;
+    assert_eq!(row_group_rows(&buf), [50; 20]);
+    assert!(read_parquet(&buf)?.equals(&wide));
+
+    // The smaller of the row and byte targets wins.
+    let buf = write(&mut narrow, Some(100))?;
+    assert_eq!(row_group_rows(&buf), [100; 10]);
+    let buf = write(&mut wide, Some(100))?;
+    assert_eq!(row_group_rows(&buf), [50; 20]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_sink_row_group_bytes() -> PolarsResult<()> {
+    let dir = crate::io::parquet::temp_dir("sink_row_group_bytes");
+
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).collect::<Vec<i64>>(),
+    ]?;
+    df.clone().lazy().sink_parquet(
+        &dir,
+        ParquetWriteOptions {
+            row_group_bytes: Some(1600),
+            max_rows_per_file: Some(500),
+            ..Default::default()
+        },
+    )?;
+
+    let mut files = std::fs::read_dir(&dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    files.sort();
+    assert_eq!(files.len(), 2);
+    for path in &files {
+        let buf = std::fs::read(path)?;
+        assert_eq!(row_group_rows(&buf), [100; 5]);
+    }
+    let out = LazyFrame::scan_parquet(dir.join("*.parquet"), Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
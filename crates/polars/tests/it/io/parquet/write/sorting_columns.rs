@@ -0,0 +1,101 @@
+use polars::prelude::*;
+use polars_core::series::IsSorted;
+use polars_parquet::read::SortingColumn;
+
+use crate::io::parquet::{parquet_metadata, read_parquet, write_parquet};
+
+fn write(df: &mut DataFrame, sorting_columns: Option<Vec<ParquetSortingColumn>>) -> Vec<u8> {
+    write_parquet(df, |writer| {
+        writer
+            .with_row_group_size(Some(100))
+            .with_sorting_columns(sorting_columns)
+    })
+    .unwrap()
+}
+
+fn sorting_columns(buf: &[u8]) -> Vec<Option<Vec<SortingColumn>>> {
+    parquet_metadata(buf)
+        .row_groups
+        .iter()
+        .map(|rg| rg.sorting_columns().map(|columns| columns.to_vec()))
+        .collect()
+}
+
+#[test]
+fn test_sorting_columns_from_sorted_flag() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..300).map(|i| (i * 7) % 300).collect::<Vec<i32>>(),
+        "b" => (0..300).map(|i| format!("{i:03}")).collect::<Vec<_>>(),
+    ]?
+    .sort(["b"], Default::default())?;
+    assert_eq!(df.column("b")?.is_sorted_flag(), IsSorted::Ascending);
+
+    let buf = write(&mut df, None);
+    let expected = SortingColumn {
+        column_idx: 1,
+        descending: false,
+        nulls_first: false,
+    };
+    assert_eq!(sorting_columns(&buf), vec![Some(vec![expected]); 3]);
+
+    let out = read_parquet(&buf)?;
+    assert!(out.equals(&df));
+    assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Not);
+    assert_eq!(out.column("b")?.is_sorted_flag(), IsSorted::Ascending);
+
+    // Without a sorted column no sorting columns are written.
+    let mut df = df.sample_n_literal(300, false, true, Some(0))?;
+    let buf = write(&mut df, None);
+    assert_eq!(sorting_columns(&buf), vec![None; 3]);
+    Ok(())
+}
+
+#[test]
+fn test_declared_sorting_columns() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..250).map(|i| (i < 200).then_some(250 - i)).collect::<Vec<_>>(),
+        "b" => (0..250).collect::<Vec<i64>>(),
+    ]?;
+    let declared = vec![
+        ParquetSortingColumn::new("a")
+            .with_descending(true)
+            .with_nulls_last(true),
+        ParquetSortingColumn::new("b"),
+    ];
+    let buf = write(&mut df, Some(declared));
+    let expected = vec![
+        SortingColumn {
+            column_idx: 0,
+            descending: true,
+            nulls_first: false,
+        },
+        SortingColumn {
+            column_idx: 1,
+            descending: false,
+            nulls_first: true,
+        },
+    ];
+    assert_eq!(sorting_columns(&buf), vec![Some(expected); 2]);
+
+    // Only the first sorting column is sorted by itself.
+    let out = read_parquet(&buf)?;
+    assert!(out.equals_missing(&df));
+    assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Descending);
+    assert_eq!(out.column("b")?.is_sorted_flag(), IsSorted::Not);
+    Ok(())
+}
+
+#[test]
+fn test_declared_sorting_columns_invalid() {
+    let s = StructChunked::from_series("c".into(), &[Series::new("x".into(), [1, 2])])
+        .unwrap()
+        .into_series();
+    let mut df = DataFrame::new(vec![Series::new("a".into(), [1, 2]), s]).unwrap();
+    let mut write = |name: &str| {
+        write_parquet(&mut df, |writer| {
+            writer.with_sorting_columns(Some(vec![ParquetSortingColumn::new(name)]))
+        })
+    };
+    assert!(matches!(write("b"), Err(PolarsError::ColumnNotFound(_))));
+    assert!(matches!(write("c"), Err(PolarsError::InvalidOperation(_))));
+}
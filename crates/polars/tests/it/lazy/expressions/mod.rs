@@ -6,6 +6,8 @@ mod filter;
 mod is_in;
 mod literals;
 mod slice;
+#[cfg(feature = "top_k")]
+mod top_k;
 mod window;
 
 use super::*;
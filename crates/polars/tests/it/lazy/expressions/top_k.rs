@@ -0,0 +1,90 @@
+use super::*;
+
+fn scores() -> DataFrame {
+    df![
+        "g" => ["a", "a", "b", "a", "b", "b", "a"],
+        "score" => [Some(3), Some(5), Some(1), None, Some(7), Some(7), Some(5)],
+        "time" => [1, 2, 3, 4, 5, 6, 7],
+        "name" => ["p", "q", "r", "s", "t", "u", "v"],
+    ]
+    .unwrap()
+}
+
+fn names(df: &DataFrame) -> Vec<Option<&str>> {
+    df.column("name")
+        .unwrap()
+        .str()
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_top_k_by_multiple_keys() -> PolarsResult<()> {
+    // The highest score first, and the earliest time first for ties.
+    let out = scores()
+        .lazy()
+        .select([col("name").top_k_by(lit(3), [col("score"), col("time")], vec![false, true])])
+        .collect()?;
+    assert_eq!(names(&out), [Some("t"), Some("u"), Some("q")]);
+
+    let out = scores()
+        .lazy()
+        .select([col("name").bottom_k_by(lit(3), [col("score"), col("time")], vec![false, true])])
+        .collect()?;
+    assert_eq!(names(&out), [Some("r"), Some("p"), Some("v")]);
+
+    let out = scores()
+        .lazy()
+        .select([col("name").top_k_by(
+            lit(1),
+            [col("score"), col("time")],
+            vec![false, true, true],
+        )])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+    Ok(())
+}
+
+#[test]
+fn test_top_k_by_over_groups() -> PolarsResult<()> {
+    let top_2 = || col("name").top_k_by(lit(2), [col("score"), col("time")], vec![false, true]);
+
+    let out = scores()
+        .lazy()
+        .select([top_2().over_with_options([col("g")], None, WindowMapping::Explode)])
+        .collect()?;
+    assert_eq!(names(&out), [Some("q"), Some("v"), Some("t"), Some("u")]);
+
+    let out = scores()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([top_2()])
+        .explode([col("name")])
+        .collect()?;
+    assert_eq!(names(&out), [Some("q"), Some("v"), Some("t"), Some("u")]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_arg_top_k_by() -> PolarsResult<()> {
+    let out = scores()
+        .lazy()
+        .select([
+            arg_top_k_by(lit(2), [col("score"), col("time")], vec![false, true]).alias("top"),
+            arg_bottom_k_by(lit(2), [col("score"), col("time")], vec![false, true]).alias("bottom"),
+        ])
+        .collect()?;
+    let idx = |name: &str| -> Vec<Option<IdxSize>> {
+        out.column(name)
+            .unwrap()
+            .idx()
+            .unwrap()
+            .into_iter()
+            .collect()
+    };
+    assert_eq!(idx("top"), [Some(4), Some(5)]);
+    assert_eq!(idx("bottom"), [Some(2), Some(0)]);
+    Ok(())
+}
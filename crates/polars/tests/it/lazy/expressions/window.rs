@@ -399,3 +399,99 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "market")]
+fn test_market_returns_over() -> PolarsResult<()> {
+    let df = df![
+        "asset" => ["a", "a", "a", "a", "b", "b", "b", "b"],
+        "price" => [100.0, 110.0, 99.0, 121.0, 10.0, 8.0, 9.0, 6.0],
+    ]?;
+    let returns = col("price") / col("price").shift(lit(1)) - lit(1.0);
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        min_periods: 2,
+        ..Default::default()
+    };
+
+    let out = df
+        .lazy()
+        .with_column(returns.over([col("asset")]).alias("return"))
+        .select([
+            col("return")
+                .market()
+                .cum_return(ReturnsMode::Linear)
+                .over([col("asset")])
+                .alias("cum_return"),
+            col("return")
+                .log1p()
+                .market()
+                .cum_return(ReturnsMode::Log)
+                .over([col("asset")])
+                .alias("cum_log_return"),
+            col("price")
+                .market()
+                .drawdown()
+                .over([col("asset")])
+                .alias("drawdown"),
+            col("price")
+                .market()
+                .max_drawdown()
+                .over([col("asset")])
+                .alias("max_drawdown"),
+            col("return")
+                .market()
+                .rolling_sharpe(options.clone(), 4.0)
+                .over([col("asset")])
+                .alias("sharpe"),
+            col("return")
+                .market()
+                .rolling_sortino(options, 4.0)
+                .over([col("asset")])
+                .alias("sortino"),
+        ])
+        .collect()?;
+
+    let values = |name: &str| -> PolarsResult<Vec<Option<f64>>> {
+        Ok(out
+            .column(name)?
+            .f64()?
+            .iter()
+            .map(|v| v.map(|v| (v * 1e6).round() / 1e6))
+            .collect())
+    };
+    let cum_return = vec![
+        None,
+        Some(0.1),
+        Some(-0.01),
+        Some(0.21),
+        None,
+        Some(-0.2),
+        Some(-0.1),
+        Some(-0.4),
+    ];
+    assert_eq!(values("cum_return")?, cum_return);
+    assert_eq!(values("cum_log_return")?, cum_return);
+    assert_eq!(
+        values("drawdown")?,
+        [0.0, 0.0, -0.1, 0.0, 0.0, -0.2, -0.1, -0.4].map(Some)
+    );
+    assert_eq!(
+        values("max_drawdown")?,
+        [-0.1, -0.1, -0.1, -0.1, -0.4, -0.4, -0.4, -0.4].map(Some)
+    );
+
+    // The last window of "a" holds the returns 0.1, -0.1 and 2/9, annualized with sqrt(4).
+    let round = |v: f64| Some((v * 1e6).round() / 1e6);
+    let returns = [0.1, -0.1, 2.0 / 9.0];
+    let mean = returns.iter().sum::<f64>() / 3.0;
+    let std = (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / 2.0).sqrt();
+    let downside = (0.01f64 / 3.0).sqrt();
+    let sharpe = values("sharpe")?;
+    let sortino = values("sortino")?;
+    assert_eq!(sharpe[..2], [None, None]);
+    assert_eq!(sharpe[2], Some(0.0));
+    assert_eq!(sharpe[3], round(mean / std * 2.0));
+    assert_eq!(sortino[3], round(mean / downside * 2.0));
+    Ok(())
+}
@@ -61,3 +61,56 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dynamic_group_by", feature = "market"))]
+fn test_group_by_dynamic_market() -> PolarsResult<()> {
+    let df = df![
+        "minute" => [0i64, 1, 3, 4, 5, 7, 9, 12],
+        "price" => [Some(10), Some(12), None, Some(9), Some(11), Some(13), Some(12), Some(20)],
+        "volume" => [1, 2, 1, 3, 1, 1, 2, 5],
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_column(
+            (col("minute") * lit(60_000))
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                .alias("time"),
+        )
+        .group_by_dynamic(
+            col("time"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("5m"),
+                period: Duration::parse("5m"),
+                offset: Duration::parse("0m"),
+                ..Default::default()
+            },
+        )
+        .agg([
+            col("price").market().ohlc(),
+            col("price").market().vwap(col("volume")).alias("vwap"),
+            col("price").market().twap(col("time")).alias("twap"),
+        ])
+        .collect()?;
+
+    let ohlc = out.column("price")?.struct_()?.clone().unnest();
+    let expected = df![
+        "open" => [10, 11, 20],
+        "high" => [12, 13, 20],
+        "low" => [9, 11, 20],
+        "close" => [9, 12, 20],
+    ]?;
+    assert!(ohlc.equals(&expected));
+    assert_eq!(
+        Vec::from(out.column("vwap")?.f64()?),
+        &[Some(61.0 / 6.0), Some(12.0), Some(20.0)]
+    );
+    // A price holds until the next trade and the null price is replaced by the one before it.
+    assert_eq!(
+        Vec::from(out.column("twap")?.f64()?),
+        &[Some(11.5), Some(12.0), Some(20.0)]
+    );
+    Ok(())
+}
@@ -214,10 +214,34 @@ class ExprStructNameSpace:
         """
         return wrap_expr(self._pyexpr.struct_rename_fields(names))
 
-    def json_encode(self) -> Expr:
+    def json_encode(
+        self,
+        *,
+        sort_fields: bool = False,
+        strip_nulls: bool = False,
+        date_format: str | None = None,
+        decimal_as_number: bool = False,
+        decimal_trim_zeros: bool | None = None,
+    ) -> Expr:
         """
         Convert this struct to a string column with json values.
 
+        Parameters
+        ----------
+        sort_fields
+            Write the fields of structs, including nested ones, ordered by name instead
+            of in schema order.
+        strip_nulls
+            Leave out fields whose value is null instead of writing `null`.
+        date_format
+            A chrono format string for dates. Dates are written as `%Y-%m-%d` by
+            default.
+        decimal_as_number
+            Write decimals as JSON numbers instead of strings.
+        decimal_trim_zeros
+            Whether to remove trailing zeros of decimals. Follows the global config if
+            not set.
+
         Examples
         --------
         >>> pl.DataFrame(
@@ -233,7 +257,15 @@ class ExprStructNameSpace:
         │ {[9, 1, 3],null} ┆ {"a":[9,1,3],"b":null} │
         └──────────────────┴────────────────────────┘
         """
-        return wrap_expr(self._pyexpr.struct_json_encode())
+        return wrap_expr(
+            self._pyexpr.struct_json_encode(
+                sort_fields,
+                strip_nulls,
+                date_format,
+                decimal_as_number,
+                decimal_trim_zeros,
+            )
+        )
 
     def with_fields(
         self,
@@ -2248,6 +2248,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_page_size: int | None = None,
         maintain_order: bool = True,
+        target_file_size: int | None = None,
+        max_rows_per_file: int | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2307,6 +2309,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        target_file_size
+            Roll over to a new file once a file reaches about this many bytes. If
+            this or `max_rows_per_file` is set, `path` is a directory to which
+            numbered files are written.
+        max_rows_per_file
+            Roll over to a new file once a file has this many rows. If this or
+            `target_file_size` is set, `path` is a directory to which numbered
+            files are written.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2363,6 +2373,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_page_size=data_page_size,
             maintain_order=maintain_order,
+            target_file_size=target_file_size,
+            max_rows_per_file=max_rows_per_file,
         )
 
     @unstable()
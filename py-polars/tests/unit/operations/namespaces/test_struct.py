@@ -2,6 +2,9 @@ from __future__ import annotations
 
 import datetime
 from collections import OrderedDict
+from decimal import Decimal
+
+import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
@@ -64,6 +67,35 @@ def test_struct_json_encode_logical_type() -> None:
     }
 
 
+def test_struct_json_encode_options() -> None:
+    df = pl.DataFrame(
+        {
+            "z": [1, None],
+            "d": [datetime.date(2024, 1, 31), None],
+            "y": [None, None],
+            "x": [Decimal("1"), None],
+        },
+        schema_overrides={"y": pl.Decimal(10, 2), "x": pl.Decimal(10, 2)},
+    ).select(pl.struct("z", "d", pl.struct("y", "x").alias("n")).alias("a"))
+
+    out = df.select(
+        pl.col("a").struct.json_encode(
+            sort_fields=True,
+            strip_nulls=True,
+            date_format="%d/%m/%Y",
+            decimal_as_number=True,
+            decimal_trim_zeros=False,
+        )
+    )
+    assert out["a"].to_list() == [
+        '{"d":"31/01/2024","n":{"x":1.00},"z":1}',
+        '{"n":{}}',
+    ]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="date format"):
+        df.select(pl.col("a").struct.json_encode(date_format="%Q"))
+
+
 def test_map_fields() -> None:
     df = pl.DataFrame({"x": {"a": 1, "b": 2}})
     assert df.schema == OrderedDict([("x", pl.Struct({"a": pl.Int64, "b": pl.Int64}))])